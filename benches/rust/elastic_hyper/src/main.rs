@@ -45,7 +45,7 @@ fn main() {
 
         let res: SearchResponse<BenchDoc> = serde_json::de::from_reader(
             elastic::search::post_index_type(
-        		&mut client, &params,
+        		&mut client, &params, &elastic::search::Params::default(),
                 "bench_index", "bench_doc",
         		json_lit!({
         			query: {
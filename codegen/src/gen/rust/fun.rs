@@ -43,6 +43,16 @@ impl Fn {
 		self
 	}
 
+	/// Append a collection of generic parameters to the function generics.
+	pub fn add_generic_params<I>(mut self, params: I) -> Fn
+		where I: IntoIterator<Item=TyParam> {
+			let mut ty_params = self.generics.ty_params.to_vec();
+			ty_params.extend(params);
+
+			self.generics.ty_params = P::from_vec(ty_params);
+			self
+	}
+
 	/// Add an argument to the function signature.
 	pub fn add_arg(mut self, arg: Arg) -> Fn {
 		self.decl.inputs.push(arg);
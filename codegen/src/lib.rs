@@ -8,6 +8,7 @@
 //! - Analysing the API tree to get URLs and their required parameters for API endpoints
 //! - Generating source code from the annotated syntax tree
 //! - Emitting the results to some destination
+//! - Running the parsed YAML tests against an API client
 //! 
 //! A consumer of this library can take advantage of any layer and those below it for their desired level of abstraction.
 //! For example, currently only Rust codegen helpers are included through the `libsyntax` crate, but other languages could be added on top of the same API AST.
@@ -29,6 +30,8 @@
 extern crate serde;
 extern crate serde_json;
 extern crate syntax;
+extern crate yaml_rust;
+extern crate regex;
 
 pub mod api;
 pub mod test;
@@ -1,14 +1,265 @@
 //! Test Spec Abstract Syntax Tree
-//! 
+//!
 //! Contains Rust structures for the YAML Tests.
-//! Structs in this module are designed for inspecting after instantiation by `serde`, rather than constructing directly.
+//! Structs in this module are built by the `parse` module, and values within them are represented as `serde_json` values.
+//!
+//! A test file contains an optional `setup` and `teardown`, and any number of named `Test`s.
+//! Each `Test` is a sequence of `Step`s, which either call the API or make an assertion about the last response.
+
+use std::collections::BTreeMap;
+use std::cmp::Ordering;
+use serde_json::Value;
+
+/// A YAML test file.
+///
+/// The `setup` steps are run before each test, and the `teardown` steps are run after each test.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TestFile {
+	/// The name of the file, relative to the test spec directory.
+	pub name: Option<String>,
+	/// Steps to run before each test.
+	pub setup: Vec<Step>,
+	/// Steps to run after each test.
+	pub teardown: Vec<Step>,
+	/// The tests in the file.
+	pub tests: Vec<Test>
+}
+
+/// A named test.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Test {
+	/// The name of the test.
+	pub name: String,
+	/// The steps to run, in order.
+	pub steps: Vec<Step>
+}
+
+/// A single step in a test.
+///
+/// Assertions take a dot path into the body of the last response, like `hits.hits.0._id`.
+/// An empty path or `$body` refers to the whole body.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Step {
+	/// Call an API endpoint.
+	Do(Do),
+	/// Stash the value at the path in the last response under the given name.
+	///
+	/// Stashed values can be used in later steps as `$name`.
+	Set(String, String),
+	/// Assert the value at the path equals the given value.
+	///
+	/// If the expected value is a string surrounded by `/`, it's treated as a regular expression.
+	Match(String, Value),
+	/// Assert the array or object at the path has the given length.
+	Length(String, Value),
+	/// Assert the value at the path is set and not `false`, `0` or empty.
+	IsTrue(String),
+	/// Assert the value at the path is unset, `false`, `0` or empty.
+	IsFalse(String),
+	/// Assert the value at the path is greater than the given value.
+	Gt(String, Value),
+	/// Assert the value at the path is greater than or equal to the given value.
+	Gte(String, Value),
+	/// Assert the value at the path is less than the given value.
+	Lt(String, Value),
+	/// Assert the value at the path is less than or equal to the given value.
+	Lte(String, Value),
+	/// Skip the test for some versions of Elasticsearch or unsupported features.
+	Skip(Skip)
+}
 
 /// Do expression.
-/// 
+///
 /// Represents an action to take that involves the Elasticsearch API.
-pub struct Do<'a> {
-	/// The action to perform.
-	pub action: &'a str,
+#[derive(Debug, Clone, PartialEq)]
+pub struct Do {
+	/// The name of the API endpoint to call, like `cluster.health`.
+	pub api: String,
 	/// The arguments to use.
-	pub args: Vec<&'a str>
-}
\ No newline at end of file
+	///
+	/// These are a mix of url parts and url parameters.
+	/// An `ignore` argument lists response statuses that shouldn't be treated as errors.
+	pub args: BTreeMap<String, Value>,
+	/// The body of the request, if any.
+	pub body: Option<Value>,
+	/// Headers to send with the request.
+	pub headers: BTreeMap<String, String>,
+	/// The error the call is expected to fail with, if any.
+	pub catch: Option<Catch>
+}
+
+/// An expected error for a `Do` step.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Catch {
+	/// A `404` response.
+	Missing,
+	/// A `409` response.
+	Conflict,
+	/// A `403` response.
+	Forbidden,
+	/// A `408` response.
+	RequestTimeout,
+	/// Any `4xx` or `5xx` response.
+	Request,
+	/// An error in the parameters, raised by the client before the request is sent.
+	Param,
+	/// A response error whose body matches a regular expression.
+	Regex(String)
+}
+
+impl Catch {
+	/// Parses a catch from its value in the test spec.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use elastic_codegen::test::ast::Catch;
+	///
+	/// assert_eq!(Catch::Missing, Catch::parse("missing"));
+	/// assert_eq!(Catch::Regex("routing_missing_exception".to_owned()), Catch::parse("/routing_missing_exception/"));
+	/// ```
+	pub fn parse(catch: &str) -> Catch {
+		let catch = catch.trim();
+
+		match catch {
+			"missing" => Catch::Missing,
+			"conflict" => Catch::Conflict,
+			"forbidden" => Catch::Forbidden,
+			"request_timeout" => Catch::RequestTimeout,
+			"param" => Catch::Param,
+			c if c.len() > 1 && c.starts_with('/') && c.ends_with('/') => Catch::Regex(c[1..c.len() - 1].to_owned()),
+			_ => Catch::Request
+		}
+	}
+}
+
+/// Skip a test.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Skip {
+	/// The range of versions to skip the test for.
+	pub version: Option<VersionRange>,
+	/// The features the test needs.
+	///
+	/// The test is skipped if the runner doesn't support all of them.
+	pub features: Vec<String>,
+	/// Why the test is skipped.
+	pub reason: Option<String>
+}
+
+/// An Elasticsearch version, like `2.3.1`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Version(pub Vec<u32>);
+
+impl Version {
+	/// Parses a version from a string.
+	///
+	/// Any suffix on the version, like `-SNAPSHOT`, is ignored.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use elastic_codegen::test::ast::Version;
+	///
+	/// let version = Version::parse("2.3.1-SNAPSHOT").unwrap();
+	/// assert_eq!(Version(vec![2, 3, 1]), version);
+	/// ```
+	pub fn parse(version: &str) -> Result<Version, String> {
+		let version = version.trim().split('-').next().unwrap_or("");
+
+		let parts: Result<Vec<u32>, String> = version
+			.split('.')
+			.map(|p| p.parse::<u32>().map_err(|_| format!("'{}' is not a valid version", version)))
+			.collect();
+
+		parts.map(Version)
+	}
+}
+
+impl PartialOrd for Version {
+	fn partial_cmp(&self, other: &Version) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for Version {
+	fn cmp(&self, other: &Version) -> Ordering {
+		//Missing parts are treated as 0, so 1.0 == 1.0.0
+		let len = ::std::cmp::max(self.0.len(), other.0.len());
+
+		for i in 0..len {
+			let a = self.0.get(i).cloned().unwrap_or(0);
+			let b = other.0.get(i).cloned().unwrap_or(0);
+
+			match a.cmp(&b) {
+				Ordering::Equal => continue,
+				ord => return ord
+			}
+		}
+
+		Ordering::Equal
+	}
+}
+
+/// An inclusive range of versions, like `0.90.0 - 1.99.99`.
+///
+/// Either end of the range may be open.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VersionRange {
+	/// The lowest version in the range.
+	pub from: Option<Version>,
+	/// The highest version in the range.
+	pub to: Option<Version>
+}
+
+impl VersionRange {
+	/// Parses a version range from a string.
+	///
+	/// The range `all` contains every version.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use elastic_codegen::test::ast::{ Version, VersionRange };
+	///
+	/// let range = VersionRange::parse(" - 1.99.99").unwrap();
+	///
+	/// assert!(range.contains(&Version(vec![1, 7, 0])));
+	/// assert!(!range.contains(&Version(vec![2, 0, 0])));
+	/// ```
+	pub fn parse(range: &str) -> Result<VersionRange, String> {
+		let range = range.trim();
+
+		if range == "all" {
+			return Ok(VersionRange {
+				from: None,
+				to: None
+			});
+		}
+
+		let mut parts = range.splitn(2, '-');
+		let from = parts.next().unwrap_or("").trim();
+		let to = try!(parts.next().ok_or(format!("'{}' is not a valid version range", range))).trim();
+
+		let from = match from {
+			"" => None,
+			v => Some(try!(Version::parse(v)))
+		};
+		let to = match to {
+			"" => None,
+			v => Some(try!(Version::parse(v)))
+		};
+
+		Ok(VersionRange {
+			from: from,
+			to: to
+		})
+	}
+
+	/// Whether or not the version is within the range.
+	pub fn contains(&self, version: &Version) -> bool {
+		let above = self.from.as_ref().map(|from| version >= from).unwrap_or(true);
+		let below = self.to.as_ref().map(|to| version <= to).unwrap_or(true);
+
+		above && below
+	}
+}
@@ -1,5 +1,8 @@
 //! Elasticsearch Test Spec Parser
 //! 
-//! Utilities for parsing the Elasticsearch API Test spec to a common format for code generation.
+//! Utilities for parsing the Elasticsearch API Test spec to a common format for code generation,
+//! and running the parsed tests against an API client.
 
-pub mod ast;
\ No newline at end of file
+pub mod ast;
+pub mod parse;
+pub mod run;
\ No newline at end of file
@@ -0,0 +1,371 @@
+//! Test Spec Parser
+//!
+//! A simple parser that buffers YAML test files into memory and uses `yaml_rust` to read the documents in them.
+//!
+//! Each document in a test file is a map with a single key.
+//! The key is either `setup`, `teardown` or the name of a test, and the value is a list of steps.
+
+use std::error;
+use std::fmt;
+use std::io::Read;
+use std::fs::File;
+use std::fs::read_dir;
+use std::path::Path;
+use std::collections::BTreeMap;
+use serde_json::Value;
+use yaml_rust::{ Yaml, YamlLoader };
+use super::ast::*;
+
+use std::io::Error as IoError;
+use yaml_rust::ScanError;
+
+#[derive(Debug)]
+enum ParseErrorKind {
+	Io(IoError),
+	Parse(ScanError),
+	Other(String)
+}
+
+/// Represents an error encountered during parsing.
+///
+/// This could include errors while reading the file, reading the YAML or interpreting the steps in a test.
+#[derive(Debug)]
+pub struct ParseError {
+	kind: ParseErrorKind
+}
+
+impl fmt::Display for ParseError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self.kind {
+			ParseErrorKind::Io(ref err) => write!(f, "IO error: {}", err),
+			ParseErrorKind::Parse(ref err) => write!(f, "Parse error: {}", err),
+			ParseErrorKind::Other(ref err) => write!(f, "Error: {}", err)
+		}
+	}
+}
+
+impl error::Error for ParseError {
+	fn description(&self) -> &str {
+		match self.kind {
+			ParseErrorKind::Io(ref err) => err.description(),
+			ParseErrorKind::Parse(ref err) => err.description(),
+			ParseErrorKind::Other(ref err) => &err[..]
+		}
+	}
+
+	fn cause(&self) -> Option<&error::Error> {
+		match self.kind {
+			ParseErrorKind::Io(ref err) => Some(err),
+			ParseErrorKind::Parse(ref err) => Some(err),
+			ParseErrorKind::Other(_) => None
+		}
+	}
+}
+
+impl From<IoError> for ParseError {
+	fn from(err: IoError) -> ParseError {
+		ParseError {
+			kind: ParseErrorKind::Io(err)
+		}
+	}
+}
+
+impl From<ScanError> for ParseError {
+	fn from(err: ScanError) -> ParseError {
+		ParseError {
+			kind: ParseErrorKind::Parse(err)
+		}
+	}
+}
+
+impl From<String> for ParseError {
+	fn from(err: String) -> ParseError {
+		ParseError {
+			kind: ParseErrorKind::Other(err)
+		}
+	}
+}
+
+/// The result of parsing a test spec.
+pub type ParseResult<T> = Result<T, ParseError>;
+
+/// Parses a Reader from an Elasticsearch YAML test spec to a TestFile.
+///
+/// Anything that implements `Read` can be used as a source for the spec.
+///
+/// # Examples
+///
+/// Parse from a file:
+///
+/// ```
+/// use std::fs::File;
+/// use elastic_codegen::test::parse;
+///
+/// let mut f = File::open("spec/test/get/10_basic.yaml").unwrap();
+/// let parsed = parse::from_reader(&mut f).unwrap();
+/// ```
+pub fn from_reader<R>(rdr: &mut R) -> ParseResult<TestFile> where R: Read {
+	//Read the file to string
+	let mut yaml = String::new();
+	let _ = try!(rdr.read_to_string(&mut yaml));
+
+	from_str(&yaml)
+}
+
+/// Parses a string containing an Elasticsearch YAML test spec to a TestFile.
+///
+/// # Examples
+///
+/// ```
+/// use elastic_codegen::test::parse;
+///
+/// let parsed = parse::from_str(r#"
+/// "Ping":
+///   - do:
+///       ping: {}
+///   - is_true: ''
+/// "#).unwrap();
+///
+/// assert_eq!(1, parsed.tests.len());
+/// ```
+pub fn from_str(yaml: &str) -> ParseResult<TestFile> {
+	let docs = try!(YamlLoader::load_from_str(yaml));
+
+	let mut file = TestFile::default();
+
+	for doc in docs {
+		let (name, steps) = match doc {
+			Yaml::Hash(ref hash) => try!(
+				hash.iter()
+				.next()
+				.ok_or("unexpected format: a test document is empty".to_owned())
+			),
+			//Empty documents, like a trailing `---`, are ignored
+			Yaml::Null => continue,
+			_ => return Err(ParseError::from("unexpected format: expected a test document to be a map".to_owned()))
+		};
+
+		let name = try!(as_key(name));
+		let steps = try!(parse_steps(steps).map_err(|e| format!("{} in '{}'", e, name)));
+
+		match name.as_str() {
+			"setup" => file.setup = steps,
+			"teardown" => file.teardown = steps,
+			_ => file.tests.push(Test {
+				name: name,
+				steps: steps
+			})
+		}
+	}
+
+	Ok(file)
+}
+
+/// Parses all Elasticsearch YAML test files in a directory to TestFiles.
+///
+/// Subdirectories are searched recursively, so this can be called on the root of the test spec.
+/// The name of each file is set to its path relative to `path`.
+///
+/// # Examples
+///
+/// ```
+/// use elastic_codegen::test::parse;
+///
+/// let parsed = parse::from_dir("spec/test/get").unwrap();
+/// ```
+pub fn from_dir(path: &str) -> ParseResult<Vec<TestFile>> {
+	let mut all_parsed: Vec<TestFile> = Vec::new();
+
+	try!(parse_dir(Path::new(path), Path::new(path), &mut all_parsed));
+
+	Ok(all_parsed)
+}
+
+fn parse_dir(root: &Path, dir: &Path, all_parsed: &mut Vec<TestFile>) -> ParseResult<()> {
+	for entry in try!(read_dir(dir)) {
+		let p = try!(entry).path();
+
+		if p.is_dir() {
+			try!(parse_dir(root, &p, all_parsed));
+		}
+		else if p.extension().and_then(|e| e.to_str()) == Some("yaml") {
+			let mut f = try!(File::open(&p));
+			let mut parsed = try!(from_reader(&mut f).map_err(|e| format!("{} in {}", e, p.display())));
+
+			let name = p.strip_prefix(root).unwrap_or(&p);
+			parsed.name = Some(name.to_string_lossy().replace('\\', "/"));
+
+			all_parsed.push(parsed);
+		}
+	}
+
+	Ok(())
+}
+
+fn parse_steps(steps: &Yaml) -> Result<Vec<Step>, String> {
+	match *steps {
+		Yaml::Array(ref steps) => steps.iter().map(parse_step).collect(),
+		Yaml::Null => Ok(Vec::new()),
+		_ => Err("expected a list of steps".to_owned())
+	}
+}
+
+fn parse_step(step: &Yaml) -> Result<Step, String> {
+	let (kind, body) = match *step {
+		Yaml::Hash(ref hash) => try!(hash.iter().next().ok_or("a step is empty".to_owned())),
+		_ => return Err("expected a step to be a map".to_owned())
+	};
+
+	let kind = try!(as_key(kind));
+
+	match kind.as_str() {
+		"do" => parse_do(body).map(Step::Do),
+		"skip" => parse_skip(body).map(Step::Skip),
+		"set" => {
+			let (path, name) = try!(parse_assertion(&kind, body));
+
+			match name {
+				Value::String(name) => Ok(Step::Set(path, name)),
+				_ => Err("expected the name in a `set` to be a string".to_owned())
+			}
+		},
+		"is_true" => Ok(Step::IsTrue(try!(parse_path(body)))),
+		"is_false" => Ok(Step::IsFalse(try!(parse_path(body)))),
+		"match" => parse_assertion(&kind, body).map(|(path, value)| Step::Match(path, value)),
+		"length" => parse_assertion(&kind, body).map(|(path, value)| Step::Length(path, value)),
+		"gt" => parse_assertion(&kind, body).map(|(path, value)| Step::Gt(path, value)),
+		"gte" => parse_assertion(&kind, body).map(|(path, value)| Step::Gte(path, value)),
+		"lt" => parse_assertion(&kind, body).map(|(path, value)| Step::Lt(path, value)),
+		"lte" => parse_assertion(&kind, body).map(|(path, value)| Step::Lte(path, value)),
+		_ => Err(format!("unknown step `{}`", kind))
+	}
+}
+
+fn parse_do(body: &Yaml) -> Result<Do, String> {
+	let hash = match *body {
+		Yaml::Hash(ref hash) => hash,
+		_ => return Err("expected a `do` to be a map".to_owned())
+	};
+
+	let mut api = None;
+	let mut catch = None;
+	let mut headers = BTreeMap::new();
+
+	for (key, value) in hash.iter() {
+		match try!(as_key(key)).as_str() {
+			"catch" => catch = Some(Catch::parse(&try!(as_key(value)))),
+			"headers" => {
+				if let Value::Object(values) = try!(to_value(value)) {
+					for (header, value) in values {
+						let value = match value {
+							Value::String(value) => value,
+							value => value.to_string()
+						};
+
+						headers.insert(header, value);
+					}
+				}
+			},
+			name => api = Some((name.to_owned(), value))
+		}
+	}
+
+	let (api, args) = try!(api.ok_or("expected a `do` to call an api".to_owned()));
+
+	let mut args = match try!(to_value(args)) {
+		Value::Object(args) => args,
+		Value::Null => BTreeMap::new(),
+		_ => return Err(format!("expected the arguments for `{}` to be a map", api))
+	};
+
+	let body = args.remove("body");
+
+	Ok(Do {
+		api: api,
+		args: args,
+		body: body,
+		headers: headers,
+		catch: catch
+	})
+}
+
+fn parse_skip(body: &Yaml) -> Result<Skip, String> {
+	let hash = match *body {
+		Yaml::Hash(ref hash) => hash,
+		_ => return Err("expected a `skip` to be a map".to_owned())
+	};
+
+	let mut skip = Skip::default();
+
+	for (key, value) in hash.iter() {
+		match try!(as_key(key)).as_str() {
+			"version" => skip.version = Some(try!(VersionRange::parse(&try!(as_key(value))))),
+			"reason" => skip.reason = Some(try!(as_key(value))),
+			"features" => skip.features = match *value {
+				Yaml::Array(ref features) => try!(features.iter().map(as_key).collect()),
+				ref feature => vec![try!(as_key(feature))]
+			},
+			key => return Err(format!("unknown key `{}` in `skip`", key))
+		}
+	}
+
+	Ok(skip)
+}
+
+//Parse a `path: value` assertion
+fn parse_assertion(kind: &str, body: &Yaml) -> Result<(String, Value), String> {
+	let (path, value) = match *body {
+		Yaml::Hash(ref hash) => try!(hash.iter().next().ok_or(format!("a `{}` is empty", kind))),
+		_ => return Err(format!("expected a `{}` to be a map", kind))
+	};
+
+	Ok((try!(as_key(path)), try!(to_value(value))))
+}
+
+fn parse_path(body: &Yaml) -> Result<String, String> {
+	match *body {
+		Yaml::Null => Ok(String::new()),
+		ref path => as_key(path)
+	}
+}
+
+//Get a scalar as a string, like a map key
+fn as_key(yaml: &Yaml) -> Result<String, String> {
+	match *yaml {
+		Yaml::String(ref s) => Ok(s.to_owned()),
+		Yaml::Real(ref s) => Ok(s.to_owned()),
+		Yaml::Integer(i) => Ok(i.to_string()),
+		Yaml::Boolean(b) => Ok(b.to_string()),
+		Yaml::Null => Ok(String::new()),
+		_ => Err(format!("expected a scalar, but got {:?}", yaml))
+	}
+}
+
+/// Converts a YAML value to json.
+///
+/// Map keys are converted to strings.
+pub fn to_value(yaml: &Yaml) -> Result<Value, String> {
+	match *yaml {
+		Yaml::String(ref s) => Ok(Value::String(s.to_owned())),
+		Yaml::Integer(i) if i >= 0 => Ok(Value::U64(i as u64)),
+		Yaml::Integer(i) => Ok(Value::I64(i)),
+		Yaml::Real(ref s) => s.parse::<f64>().map(Value::F64).map_err(|_| format!("'{}' is not a valid number", s)),
+		Yaml::Boolean(b) => Ok(Value::Bool(b)),
+		Yaml::Null => Ok(Value::Null),
+		Yaml::Array(ref values) => {
+			let values: Result<Vec<Value>, String> = values.iter().map(to_value).collect();
+
+			values.map(Value::Array)
+		},
+		Yaml::Hash(ref hash) => {
+			let mut map = BTreeMap::new();
+
+			for (key, value) in hash.iter() {
+				map.insert(try!(as_key(key)), try!(to_value(value)));
+			}
+
+			Ok(Value::Object(map))
+		},
+		_ => Err(format!("unsupported value {:?}", yaml))
+	}
+}
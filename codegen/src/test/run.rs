@@ -0,0 +1,608 @@
+//! Test Spec Runner
+//!
+//! Executes parsed YAML tests against an Elasticsearch API client.
+//!
+//! The runner doesn't know how to send requests itself.
+//! Instead, it hands each `Do` step to an implementation of `Client`, and checks assertions against the `Response` it returns.
+//! This means the same tests can be run against any client, like the generated `elastic_hyper` functions.
+//!
+//! # Examples
+//!
+//! Run a test file against a stand-in client:
+//!
+//! ```
+//! # extern crate serde_json;
+//! # extern crate elastic_codegen;
+//! # fn main() {
+//! use serde_json::Value;
+//! use elastic_codegen::test::parse;
+//! use elastic_codegen::test::run::*;
+//!
+//! struct Ping;
+//!
+//! impl Client for Ping {
+//! 	fn call(&mut self, _: &Request) -> Result<Response, String> {
+//! 		Ok(Response {
+//! 			status: 200,
+//! 			body: Value::Null
+//! 		})
+//! 	}
+//! }
+//!
+//! let file = parse::from_str(r#"
+//! "Ping":
+//!   - do:
+//!       ping: {}
+//!   - is_true: ''
+//! "#).unwrap();
+//!
+//! let results = Runner::new(Ping).run(&file);
+//!
+//! assert!(results.iter().all(|r| r.outcome == Outcome::Passed));
+//! # }
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+use serde_json;
+use serde_json::Value;
+use regex::Regex;
+use super::ast::*;
+
+/// The features the runner supports.
+///
+/// Tests that need any other feature are skipped.
+pub const SUPPORTED_FEATURES: &'static [&'static str] = &[
+	"regex",
+	"stash_in_path",
+	"headers"
+];
+
+/// A request to an API endpoint.
+///
+/// Stashed values have already been substituted into the arguments and body.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Request {
+	/// The name of the API endpoint to call, like `cluster.health`.
+	pub api: String,
+	/// The url parts and url parameters for the request.
+	///
+	/// List arguments are joined with `,`.
+	pub args: BTreeMap<String, String>,
+	/// The body of the request, if any.
+	///
+	/// Bodies that are a list, like for `bulk`, are sent as newline-delimited json.
+	pub body: Option<String>,
+	/// Headers to send with the request.
+	pub headers: BTreeMap<String, String>
+}
+
+/// A response from an API endpoint.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Response {
+	/// The HTTP status code.
+	pub status: u16,
+	/// The body of the response.
+	///
+	/// Bodies that aren't json, like from the `cat` endpoints, should be returned as a `Value::String`.
+	/// Responses without a body, like for `HEAD` requests, should be returned as `Value::Null`.
+	pub body: Value
+}
+
+/// A client that can send requests to an API endpoint.
+pub trait Client {
+	/// Send a request and return the response.
+	///
+	/// Responses with an error status code should be returned as `Ok`.
+	/// An `Err` is treated as an error in the parameters of the request, which satisfies a `catch: param`.
+	fn call(&mut self, req: &Request) -> Result<Response, String>;
+}
+
+/// The outcome of running a test.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+	/// All steps in the test passed.
+	Passed,
+	/// The test was skipped, for the given reason.
+	Skipped(String),
+	/// A step in the test failed, for the given reason.
+	Failed(String)
+}
+
+/// The result of running a test.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestResult {
+	/// The name of the test.
+	pub name: String,
+	/// The outcome of the test.
+	pub outcome: Outcome
+}
+
+impl fmt::Display for TestResult {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self.outcome {
+			Outcome::Passed => write!(f, "{}: passed", self.name),
+			Outcome::Skipped(ref reason) => write!(f, "{}: skipped ({})", self.name, reason),
+			Outcome::Failed(ref reason) => write!(f, "{}: failed ({})", self.name, reason)
+		}
+	}
+}
+
+/// Values stashed by `set` steps.
+///
+/// Stashed values are referred to in later steps as `$name`.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Stash {
+	vars: BTreeMap<String, Value>
+}
+
+impl Stash {
+	/// Create a new, empty stash.
+	pub fn new() -> Stash {
+		Stash::default()
+	}
+
+	/// Stash a value under the given name.
+	pub fn set<I: Into<String>>(&mut self, name: I, value: Value) {
+		self.vars.insert(name.into(), value);
+	}
+
+	/// Get the value stashed under the given name.
+	///
+	/// The name may be given with or without the leading `$`.
+	pub fn get(&self, name: &str) -> Option<&Value> {
+		self.vars.get(name.trim_left_matches('$'))
+	}
+
+	/// Replace any stashed variables in a value with the values they refer to.
+	///
+	/// Only strings that are exactly a variable name, like `"$id"`, are replaced.
+	/// Arrays and objects are resolved recursively.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # extern crate serde_json;
+	/// # extern crate elastic_codegen;
+	/// # fn main() {
+	/// use serde_json::Value;
+	/// use elastic_codegen::test::run::Stash;
+	///
+	/// let mut stash = Stash::new();
+	/// stash.set("id", Value::String("1".to_owned()));
+	///
+	/// assert_eq!(Value::String("1".to_owned()), stash.resolve(&Value::String("$id".to_owned())));
+	/// # }
+	/// ```
+	pub fn resolve(&self, value: &Value) -> Value {
+		match *value {
+			Value::String(ref s) if s.starts_with('$') => {
+				self.get(s).cloned().unwrap_or(value.clone())
+			},
+			Value::Array(ref values) => Value::Array(values.iter().map(|v| self.resolve(v)).collect()),
+			Value::Object(ref values) => Value::Object(values.iter().map(|(k, v)| (k.clone(), self.resolve(v))).collect()),
+			ref v => v.clone()
+		}
+	}
+}
+
+/// Runs YAML tests against a `Client`.
+pub struct Runner<C: Client> {
+	client: C,
+	version: Option<Version>,
+	features: Vec<String>
+}
+
+impl <C: Client> Runner<C> {
+	/// Create a new runner for the given client.
+	///
+	/// By default, the runner supports the `SUPPORTED_FEATURES` and doesn't skip any versions.
+	pub fn new(client: C) -> Runner<C> {
+		Runner {
+			client: client,
+			version: None,
+			features: SUPPORTED_FEATURES.iter().map(|f| f.to_string()).collect()
+		}
+	}
+
+	/// Set the version of Elasticsearch the tests are run against.
+	///
+	/// Tests with a `skip` for a range containing the version are skipped.
+	pub fn version(mut self, version: Version) -> Runner<C> {
+		self.version = Some(version);
+		self
+	}
+
+	/// Set the features the client supports.
+	pub fn features<I>(mut self, features: I) -> Runner<C> where
+	I: IntoIterator<Item=String> {
+		self.features = features.into_iter().collect();
+		self
+	}
+
+	/// Get a mutable reference to the client.
+	pub fn client(&mut self) -> &mut C {
+		&mut self.client
+	}
+
+	/// Run all of the tests in a file.
+	///
+	/// The `setup` steps are run before each test and the `teardown` steps are run after each test.
+	pub fn run(&mut self, file: &TestFile) -> Vec<TestResult> {
+		file.tests.iter().map(|test| self.run_test(file, test)).collect()
+	}
+
+	/// Run a single test from a file.
+	pub fn run_test(&mut self, file: &TestFile, test: &Test) -> TestResult {
+		let mut ctx = Context::default();
+
+		let outcome = match self.run_steps(&mut ctx, &file.setup) {
+			Ok(None) => match self.run_steps(&mut ctx, &test.steps) {
+				Ok(None) => Outcome::Passed,
+				Ok(Some(reason)) => Outcome::Skipped(reason),
+				Err(e) => Outcome::Failed(e)
+			},
+			Ok(Some(reason)) => Outcome::Skipped(reason),
+			Err(e) => Outcome::Failed(format!("setup: {}", e))
+		};
+
+		//Teardown is always run, but only fails a test that would otherwise pass
+		let outcome = match (outcome, self.run_steps(&mut ctx, &file.teardown)) {
+			(Outcome::Passed, Err(e)) => Outcome::Failed(format!("teardown: {}", e)),
+			(outcome, _) => outcome
+		};
+
+		TestResult {
+			name: match file.name {
+				Some(ref file) => format!("{}: {}", file, test.name),
+				None => test.name.clone()
+			},
+			outcome: outcome
+		}
+	}
+
+	//Run steps until one fails or skips the test
+	fn run_steps(&mut self, ctx: &mut Context, steps: &[Step]) -> Result<Option<String>, String> {
+		for step in steps {
+			if let Some(reason) = try!(self.run_step(ctx, step)) {
+				return Ok(Some(reason));
+			}
+		}
+
+		Ok(None)
+	}
+
+	fn run_step(&mut self, ctx: &mut Context, step: &Step) -> Result<Option<String>, String> {
+		match *step {
+			Step::Skip(ref skip) => return Ok(self.skip_reason(skip)),
+			Step::Do(ref action) => try!(self.run_do(ctx, action)),
+			Step::Set(ref path, ref name) => {
+				let value = try!(ctx.lookup(path).ok_or(format!("`set` path `{}` not found", path)));
+				ctx.stash.set(name.as_str(), value);
+			},
+			Step::Match(ref path, ref expected) => {
+				let expected = ctx.stash.resolve(expected);
+				let actual = ctx.lookup(path);
+
+				if !is_match(actual.as_ref(), &expected) {
+					return Err(format!("expected `{}` to match {}, but got {}", path, expected, fmt_actual(actual.as_ref())));
+				}
+			},
+			Step::Length(ref path, ref expected) => {
+				let expected = try!(ctx.stash.resolve(expected).as_u64().ok_or(format!("`length` for `{}` is not a number", path)));
+				let actual = ctx.lookup(path);
+
+				let len = match actual {
+					Some(Value::Array(ref values)) => Some(values.len() as u64),
+					Some(Value::Object(ref values)) => Some(values.len() as u64),
+					Some(Value::String(ref s)) => Some(s.chars().count() as u64),
+					_ => None
+				};
+
+				if len != Some(expected) {
+					return Err(format!("expected `{}` to have length {}, but got {}", path, expected, fmt_actual(actual.as_ref())));
+				}
+			},
+			Step::IsTrue(ref path) => {
+				if !ctx.is_true(path) {
+					return Err(format!("expected `{}` to be true", path));
+				}
+			},
+			Step::IsFalse(ref path) => {
+				if ctx.is_true(path) {
+					return Err(format!("expected `{}` to be false", path));
+				}
+			},
+			Step::Gt(ref path, ref expected) => try!(ctx.compare(path, expected, "gt", |a, b| a > b)),
+			Step::Gte(ref path, ref expected) => try!(ctx.compare(path, expected, "gte", |a, b| a >= b)),
+			Step::Lt(ref path, ref expected) => try!(ctx.compare(path, expected, "lt", |a, b| a < b)),
+			Step::Lte(ref path, ref expected) => try!(ctx.compare(path, expected, "lte", |a, b| a <= b))
+		}
+
+		Ok(None)
+	}
+
+	fn skip_reason(&self, skip: &Skip) -> Option<String> {
+		let reason = skip.reason.clone().unwrap_or(String::new());
+
+		if let (Some(range), Some(version)) = (skip.version.as_ref(), self.version.as_ref()) {
+			if range.contains(version) {
+				return Some(reason);
+			}
+		}
+
+		for feature in &skip.features {
+			if !self.features.contains(feature) {
+				return Some(format!("feature `{}` is not supported", feature));
+			}
+		}
+
+		None
+	}
+
+	fn run_do(&mut self, ctx: &mut Context, action: &Do) -> Result<(), String> {
+		let req = try!(ctx.request(action));
+
+		//Statuses listed in an `ignore` arg aren't treated as errors
+		let ignore: Vec<u64> = match action.args.get("ignore") {
+			Some(&Value::Array(ref statuses)) => statuses.iter().filter_map(|s| s.as_u64()).collect(),
+			Some(status) => status.as_u64().into_iter().collect(),
+			None => Vec::new()
+		};
+
+		match (self.client.call(&req), action.catch.as_ref()) {
+			//Errors in the parameters are only expected for `catch: param`
+			(Err(_), Some(&Catch::Param)) => {
+				ctx.response = None;
+				Ok(())
+			},
+			(Err(e), _) => Err(format!("`{}` failed: {}", action.api, e)),
+			(Ok(res), None) => {
+				let status = res.status;
+				let body = res.body.clone();
+
+				ctx.response = Some(res);
+
+				match status {
+					s if s >= 400 && !ignore.contains(&(s as u64)) => Err(format!("`{}` returned {}: {}", action.api, status, body)),
+					_ => Ok(())
+				}
+			},
+			(Ok(res), Some(catch)) => {
+				let caught = match *catch {
+					Catch::Missing => res.status == 404,
+					Catch::Conflict => res.status == 409,
+					Catch::Forbidden => res.status == 403,
+					Catch::RequestTimeout => res.status == 408,
+					Catch::Request => res.status >= 400,
+					Catch::Param => false,
+					Catch::Regex(ref pattern) => res.status >= 400 && is_regex_match(pattern, &fmt_body(&res.body))
+				};
+
+				let status = res.status;
+				let body = res.body.clone();
+
+				ctx.response = Some(res);
+
+				match caught {
+					true => Ok(()),
+					false => Err(format!("`{}` expected to catch {:?}, but returned {}: {}", action.api, catch, status, body))
+				}
+			}
+		}
+	}
+}
+
+//The state of a single test
+#[derive(Default)]
+struct Context {
+	stash: Stash,
+	response: Option<Response>
+}
+
+impl Context {
+	fn request(&self, action: &Do) -> Result<Request, String> {
+		let mut args = BTreeMap::new();
+
+		for (name, value) in action.args.iter().filter(|&(name, _)| name != "ignore") {
+			let value = match self.stash.resolve(value) {
+				Value::Array(values) => values.iter().map(fmt_arg).collect::<Vec<String>>().join(","),
+				value => fmt_arg(&value)
+			};
+
+			args.insert(name.clone(), value);
+		}
+
+		let body = match action.body.as_ref().map(|body| self.stash.resolve(body)) {
+			//A list body is sent as one json document per line
+			Some(Value::Array(lines)) => {
+				let mut body = String::new();
+				for line in lines {
+					body.push_str(&fmt_body(&line));
+					body.push('\n');
+				}
+
+				Some(body)
+			},
+			Some(body) => Some(fmt_body(&body)),
+			None => None
+		};
+
+		Ok(Request {
+			api: action.api.clone(),
+			args: args,
+			body: body,
+			headers: action.headers.clone()
+		})
+	}
+
+	//Find the value at a dot path in the last response body
+	fn lookup(&self, path: &str) -> Option<Value> {
+		let body = match self.response {
+			Some(ref res) => &res.body,
+			None => return None
+		};
+
+		if path == "" || path == "$body" {
+			return Some(body.clone());
+		}
+
+		let mut current = body;
+		for part in split_path(path) {
+			//Parts may be stashed values, like `nodes.$master`
+			let part = match part.starts_with('$') {
+				true => self.stash.get(&part).map(fmt_arg).unwrap_or(part),
+				false => part
+			};
+
+			current = match *current {
+				Value::Object(ref values) => match values.get(&part) {
+					Some(value) => value,
+					None => return None
+				},
+				Value::Array(ref values) => match part.parse::<usize>().ok().and_then(|i| values.get(i)) {
+					Some(value) => value,
+					None => return None
+				},
+				_ => return None
+			};
+		}
+
+		Some(current.clone())
+	}
+
+	fn is_true(&self, path: &str) -> bool {
+		//Responses without a body, like for `HEAD` requests, are true if they succeeded
+		if path == "" {
+			if let Some(Response { status, body: Value::Null }) = self.response {
+				return status < 400;
+			}
+		}
+
+		match self.lookup(path) {
+			None | Some(Value::Null) | Some(Value::Bool(false)) => false,
+			Some(Value::String(ref s)) => !(s == "" || s == "false" || s == "0"),
+			Some(ref v) => v.as_f64().map(|n| n != 0.0).unwrap_or(true)
+		}
+	}
+
+	fn compare<F>(&self, path: &str, expected: &Value, kind: &str, cmp: F) -> Result<(), String> where
+	F: Fn(f64, f64) -> bool {
+		let expected = self.stash.resolve(expected);
+		let actual = self.lookup(path);
+
+		let passed = match (actual.as_ref().and_then(as_number), as_number(&expected)) {
+			(Some(a), Some(b)) => cmp(a, b),
+			_ => false
+		};
+
+		match passed {
+			true => Ok(()),
+			false => Err(format!("expected `{}` to be {} {}, but got {}", path, kind, expected, fmt_actual(actual.as_ref())))
+		}
+	}
+}
+
+/// Splits a dot path into its parts.
+///
+/// A `.` can be escaped as `\.` to include it in a part.
+///
+/// # Examples
+///
+/// ```
+/// use elastic_codegen::test::run::split_path;
+///
+/// let parts = split_path(r"nodes.$master.settings.index\.number_of_shards");
+///
+/// assert_eq!(vec!["nodes", "$master", "settings", "index.number_of_shards"], parts);
+/// ```
+pub fn split_path(path: &str) -> Vec<String> {
+	let mut parts = Vec::new();
+	let mut part = String::new();
+	let mut chars = path.chars().peekable();
+
+	while let Some(c) = chars.next() {
+		match c {
+			'\\' if chars.peek() == Some(&'.') => {
+				part.push('.');
+				let _ = chars.next();
+			},
+			'.' => parts.push(::std::mem::replace(&mut part, String::new())),
+			c => part.push(c)
+		}
+	}
+
+	parts.push(part);
+
+	parts
+}
+
+//Check an actual value against an expected value from a `match`
+fn is_match(actual: Option<&Value>, expected: &Value) -> bool {
+	match (actual, expected) {
+		(Some(actual), &Value::String(ref s)) if is_regex(s) => {
+			let s = s.trim();
+			is_regex_match(&s[1..s.len() - 1], &fmt_body(actual))
+		},
+		(Some(actual), expected) => values_eq(actual, expected),
+		(None, &Value::Null) => true,
+		(None, _) => false
+	}
+}
+
+//Compare values, treating numbers as equal if they have the same value
+fn values_eq(a: &Value, b: &Value) -> bool {
+	match (a, b) {
+		(&Value::Array(ref a), &Value::Array(ref b)) => {
+			a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| values_eq(a, b))
+		},
+		(&Value::Object(ref a), &Value::Object(ref b)) => {
+			a.len() == b.len() && a.iter().all(|(k, a)| b.get(k).map(|b| values_eq(a, b)).unwrap_or(false))
+		},
+		(a, b) => match (as_number(a), as_number(b)) {
+			(Some(a), Some(b)) if a.is_finite() => a == b,
+			_ => a == b
+		}
+	}
+}
+
+fn is_regex(s: &str) -> bool {
+	let s = s.trim();
+
+	s.len() > 1 && s.starts_with('/') && s.ends_with('/')
+}
+
+//Patterns in the tests may span multiple lines and contain comments
+fn is_regex_match(pattern: &str, value: &str) -> bool {
+	Regex::new(&format!("(?x){}", pattern))
+		.map(|re| re.is_match(value))
+		.unwrap_or(false)
+}
+
+fn as_number(value: &Value) -> Option<f64> {
+	match *value {
+		Value::String(ref s) => s.parse::<f64>().ok(),
+		ref v => v.as_f64()
+	}
+}
+
+fn fmt_arg(value: &Value) -> String {
+	match *value {
+		Value::String(ref s) => s.clone(),
+		ref v => v.to_string()
+	}
+}
+
+fn fmt_body(value: &Value) -> String {
+	match *value {
+		Value::String(ref s) => s.clone(),
+		ref v => serde_json::to_string(v).unwrap_or(String::new())
+	}
+}
+
+fn fmt_actual(actual: Option<&Value>) -> String {
+	match actual {
+		Some(value) => value.to_string(),
+		None => "nothing".to_owned()
+	}
+}
@@ -9,4 +9,6 @@ extern crate elastic_codegen;
 pub mod api_parse;
 pub mod api_gen;
 pub mod rust_gen;
-pub mod rust_emit;
\ No newline at end of file
+pub mod rust_emit;
+pub mod test_parse;
+pub mod test_run;
@@ -0,0 +1,163 @@
+extern crate elastic_codegen;
+extern crate serde_json;
+
+use std::fs::File;
+use serde_json::Value;
+use elastic_codegen::test::ast::*;
+use elastic_codegen::test::parse;
+
+#[test]
+fn can_parse_from_file() {
+	let mut f = File::open("spec/test/ping/10_ping.yaml").unwrap();
+	let parsed = parse::from_reader(&mut f).unwrap();
+
+	assert_eq!(1, parsed.tests.len());
+	assert_eq!("Ping", parsed.tests[0].name);
+}
+
+#[test]
+fn can_parse_all_in_dir() {
+	let parsed = parse::from_dir("spec/test").unwrap();
+
+	assert!(parsed.len() > 0);
+	assert!(parsed.iter().all(|f| f.name.is_some()));
+}
+
+#[test]
+fn can_parse_setup_and_teardown() {
+	let parsed = parse::from_str(r#"
+---
+setup:
+  - do:
+      indices.create:
+        index: test_1
+---
+teardown:
+  - do:
+      indices.delete:
+        index: test_1
+---
+"Test":
+  - do:
+      ping: {}
+"#).unwrap();
+
+	assert_eq!(1, parsed.setup.len());
+	assert_eq!(1, parsed.teardown.len());
+	assert_eq!(1, parsed.tests.len());
+}
+
+#[test]
+fn can_parse_do() {
+	let parsed = parse::from_str(r#"
+"Test":
+  - do:
+      catch: missing
+      headers:
+        Content-Type: application/yaml
+      get:
+        index: test_1
+        type: test
+        id: 1
+        body: { foo: bar }
+"#).unwrap();
+
+	let expected = Do {
+		api: "get".to_owned(),
+		args: vec![
+			("index".to_owned(), Value::String("test_1".to_owned())),
+			("type".to_owned(), Value::String("test".to_owned())),
+			("id".to_owned(), Value::U64(1))
+		].into_iter().collect(),
+		body: Some(serde_json::from_str(r#"{"foo":"bar"}"#).unwrap()),
+		headers: vec![
+			("Content-Type".to_owned(), "application/yaml".to_owned())
+		].into_iter().collect(),
+		catch: Some(Catch::Missing)
+	};
+
+	assert_eq!(vec![Step::Do(expected)], parsed.tests[0].steps);
+}
+
+#[test]
+fn can_parse_assertions() {
+	let parsed = parse::from_str(r#"
+"Test":
+  - set:      { _id: id }
+  - match:    { _source.foo: bar }
+  - length:   { hits.hits: 2 }
+  - is_true:  found
+  - is_false: timed_out
+  - gt:       { hits.total: 0 }
+  - gte:      { hits.total: 1 }
+  - lt:       { took: 1000 }
+  - lte:      { took: 999.5 }
+"#).unwrap();
+
+	let expected = vec![
+		Step::Set("_id".to_owned(), "id".to_owned()),
+		Step::Match("_source.foo".to_owned(), Value::String("bar".to_owned())),
+		Step::Length("hits.hits".to_owned(), Value::U64(2)),
+		Step::IsTrue("found".to_owned()),
+		Step::IsFalse("timed_out".to_owned()),
+		Step::Gt("hits.total".to_owned(), Value::U64(0)),
+		Step::Gte("hits.total".to_owned(), Value::U64(1)),
+		Step::Lt("took".to_owned(), Value::U64(1000)),
+		Step::Lte("took".to_owned(), Value::F64(999.5))
+	];
+
+	assert_eq!(expected, parsed.tests[0].steps);
+}
+
+#[test]
+fn can_parse_skip() {
+	let parsed = parse::from_str(r#"
+"Test":
+  - skip:
+      version: "0.90.0 - 1.99.99"
+      features: [regex, groovy_scripting]
+      reason: not supported
+"#).unwrap();
+
+	let expected = Skip {
+		version: Some(VersionRange {
+			from: Some(Version(vec![0, 90, 0])),
+			to: Some(Version(vec![1, 99, 99]))
+		}),
+		features: vec!["regex".to_owned(), "groovy_scripting".to_owned()],
+		reason: Some("not supported".to_owned())
+	};
+
+	assert_eq!(vec![Step::Skip(expected)], parsed.tests[0].steps);
+}
+
+#[test]
+fn can_parse_catch() {
+	assert_eq!(Catch::Missing, Catch::parse("missing"));
+	assert_eq!(Catch::Conflict, Catch::parse("conflict"));
+	assert_eq!(Catch::Forbidden, Catch::parse("forbidden"));
+	assert_eq!(Catch::RequestTimeout, Catch::parse("request_timeout"));
+	assert_eq!(Catch::Param, Catch::parse("param"));
+	assert_eq!(Catch::Request, Catch::parse("request"));
+	assert_eq!(Catch::Regex("Validation|Invalid".to_owned()), Catch::parse("/Validation|Invalid/"));
+}
+
+#[test]
+fn can_parse_version_range() {
+	let all = VersionRange::parse("all").unwrap();
+	let upper = VersionRange::parse(" - 2.0.0").unwrap();
+
+	assert!(all.contains(&Version(vec![5, 0, 0])));
+	assert!(upper.contains(&Version(vec![2, 0])));
+	assert!(!upper.contains(&Version(vec![2, 0, 1])));
+}
+
+#[test]
+fn unknown_step_is_err() {
+	let parsed = parse::from_str(r#"
+"Test":
+  - not_a_step: { a: b }
+"#);
+
+	assert!(parsed.is_err());
+}
@@ -0,0 +1,283 @@
+extern crate elastic_codegen;
+extern crate serde_json;
+
+use std::collections::BTreeMap;
+use serde_json::Value;
+use elastic_codegen::test::ast::Version;
+use elastic_codegen::test::parse;
+use elastic_codegen::test::run::*;
+
+//A client that returns canned responses and records the requests it gets
+struct MockClient {
+	requests: Vec<Request>,
+	responses: BTreeMap<String, Response>
+}
+
+impl MockClient {
+	fn new(responses: Vec<(&str, u16, &str)>) -> MockClient {
+		MockClient {
+			requests: Vec::new(),
+			responses: responses.into_iter().map(|(api, status, body)| {
+				(api.to_owned(), Response {
+					status: status,
+					body: serde_json::from_str(body).unwrap()
+				})
+			}).collect()
+		}
+	}
+}
+
+impl Client for MockClient {
+	fn call(&mut self, req: &Request) -> Result<Response, String> {
+		self.requests.push(req.clone());
+
+		self.responses.get(&req.api).cloned().ok_or(format!("unknown api {}", req.api))
+	}
+}
+
+fn run(client: MockClient, yaml: &str) -> (Vec<TestResult>, MockClient) {
+	let file = parse::from_str(yaml).unwrap();
+	let mut runner = Runner::new(client);
+
+	let results = runner.run(&file);
+	let requests = runner.client().requests.clone();
+	let responses = runner.client().responses.clone();
+
+	(results, MockClient { requests: requests, responses: responses })
+}
+
+#[test]
+fn passing_assertions_pass() {
+	let client = MockClient::new(vec![
+		("search", 200, r#"{"took":5,"timed_out":false,"hits":{"total":2,"hits":[{"_id":"1"},{"_id":"2"}]}}"#)
+	]);
+
+	let (results, _) = run(client, r#"
+"Search":
+  - do:
+      search:
+        index: test_1
+  - match:    { hits.hits.0._id: "1" }
+  - match:    { hits.total: 2.0 }
+  - length:   { hits.hits: 2 }
+  - is_true:  hits
+  - is_false: timed_out
+  - gt:       { hits.total: 1 }
+  - lte:      { took: 5 }
+  - match:    { hits.hits.1._id: /^\d+$/ }
+"#);
+
+	assert_eq!(Outcome::Passed, results[0].outcome);
+}
+
+#[test]
+fn failing_assertion_fails() {
+	let client = MockClient::new(vec![
+		("get", 200, r#"{"_id":"1","found":true}"#)
+	]);
+
+	let (results, _) = run(client, r#"
+"Get":
+  - do:
+      get:
+        id: 1
+  - match: { _id: "2" }
+"#);
+
+	match results[0].outcome {
+		Outcome::Failed(_) => (),
+		ref o => panic!("expected failure, got {:?}", o)
+	}
+}
+
+#[test]
+fn stashed_values_are_used_in_later_steps() {
+	let client = MockClient::new(vec![
+		("index", 201, r#"{"_id":"AVX1"}"#),
+		("get", 200, r#"{"_id":"AVX1","nodes":{"AVX1":{"name":"node"}}}"#)
+	]);
+
+	let (results, client) = run(client, r#"
+"Stash":
+  - do:
+      index:
+        index: test_1
+  - set:   { _id: id }
+  - do:
+      get:
+        id: $id
+  - match: { _id: $id }
+  - match: { nodes.$id.name: node }
+"#);
+
+	assert_eq!(Outcome::Passed, results[0].outcome);
+	assert_eq!(Some(&"AVX1".to_owned()), client.requests[1].args.get("id"));
+}
+
+#[test]
+fn expected_errors_are_caught() {
+	let client = MockClient::new(vec![
+		("get", 404, r#"{"found":false}"#),
+		("index", 409, r#"{"error":"version_conflict_engine_exception"}"#)
+	]);
+
+	let (results, _) = run(client, r#"
+"Catch":
+  - do:
+      catch: missing
+      get:
+        id: 1
+  - do:
+      catch: /version_conflict/
+      index:
+        id: 1
+  - do:
+      catch: param
+      unknown_api: {}
+"#);
+
+	assert_eq!(Outcome::Passed, results[0].outcome);
+}
+
+#[test]
+fn unexpected_errors_fail() {
+	let client = MockClient::new(vec![
+		("get", 404, r#"{"found":false}"#)
+	]);
+
+	let (results, _) = run(client, r#"
+"Error":
+  - do:
+      get:
+        id: 1
+"#);
+
+	match results[0].outcome {
+		Outcome::Failed(_) => (),
+		ref o => panic!("expected failure, got {:?}", o)
+	}
+}
+
+#[test]
+fn setup_and_teardown_run_for_each_test() {
+	let client = MockClient::new(vec![
+		("indices.create", 200, r#"{}"#),
+		("indices.delete", 200, r#"{}"#),
+		("ping", 200, r#"null"#)
+	]);
+
+	let (results, client) = run(client, r#"
+---
+setup:
+  - do:
+      indices.create:
+        index: test_1
+---
+teardown:
+  - do:
+      indices.delete:
+        index: test_1
+---
+"First":
+  - do:
+      ping: {}
+  - is_true: ''
+---
+"Second":
+  - do:
+      ping: {}
+"#);
+
+	let apis: Vec<&str> = client.requests.iter().map(|r| r.api.as_str()).collect();
+
+	assert_eq!(2, results.len());
+	assert_eq!(vec!["indices.create", "ping", "indices.delete", "indices.create", "ping", "indices.delete"], apis);
+}
+
+#[test]
+fn skipped_versions_and_features_are_skipped() {
+	let yaml = r#"
+---
+"Old":
+  - skip:
+      version: " - 1.99.99"
+      reason: not in 1.x
+  - do:
+      ping: {}
+---
+"Feature":
+  - skip:
+      features: benchmark
+  - do:
+      ping: {}
+"#;
+
+	let file = parse::from_str(yaml).unwrap();
+	let mut runner = Runner::new(MockClient::new(vec![])).version(Version(vec![1, 7, 0]));
+
+	let results = runner.run(&file);
+
+	assert_eq!(Outcome::Skipped("not in 1.x".to_owned()), results[0].outcome);
+	assert_eq!(Outcome::Skipped("feature `benchmark` is not supported".to_owned()), results[1].outcome);
+	assert_eq!(0, runner.client().requests.len());
+}
+
+#[test]
+fn list_args_and_bodies_are_formatted() {
+	let client = MockClient::new(vec![
+		("bulk", 200, r#"{"errors":false}"#)
+	]);
+
+	let (_, client) = run(client, r#"
+"Bulk":
+  - do:
+      bulk:
+        index: [test_1, test_2]
+        refresh: true
+        body:
+          - { index: { _id: 1 } }
+          - { foo: bar }
+"#);
+
+	let req = &client.requests[0];
+
+	assert_eq!(Some(&"test_1,test_2".to_owned()), req.args.get("index"));
+	assert_eq!(Some(&"true".to_owned()), req.args.get("refresh"));
+	assert_eq!(Some("{\"index\":{\"_id\":1}}\n{\"foo\":\"bar\"}\n".to_owned()), req.body);
+}
+
+#[test]
+fn can_split_escaped_paths() {
+	assert_eq!(vec!["a", "b.c", "0"], split_path(r"a.b\.c.0"));
+	assert_eq!(vec!["a"], split_path("a"));
+}
+
+#[test]
+fn stash_resolves_nested_values() {
+	let mut stash = Stash::new();
+	stash.set("id", Value::U64(1));
+
+	let value: Value = serde_json::from_str(r#"{"ids":["$id", "2"]}"#).unwrap();
+	let expected: Value = serde_json::from_str(r#"{"ids":[1, "2"]}"#).unwrap();
+
+	assert_eq!(expected, stash.resolve(&value));
+}
+
+#[test]
+fn ignored_statuses_are_not_errors() {
+	let client = MockClient::new(vec![
+		("get", 404, r#"{"found":false}"#)
+	]);
+
+	let (results, client) = run(client, r#"
+"Ignore":
+  - do:
+      get:
+        id: 1
+        ignore: 404
+  - is_false: found
+"#);
+
+	assert_eq!(Outcome::Passed, results[0].outcome);
+	assert_eq!(None, client.requests[0].args.get("ignore"));
+}
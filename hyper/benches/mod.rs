@@ -20,7 +20,8 @@ fn search_post_index_type(b: &mut Bencher) {
 	b.iter(|| {
 		let mut client = hyper::Client::new();
 		elastic::search::post_index_type(
-			&mut client, "http://localhost:9200", "bench_index", "docs", 
+			&mut client, &elastic::RequestParams::default(), &elastic::search::Params::default(),
+			"bench_index", "docs",
 			json!({
 				query: {
 					query_string: {
@@ -4,6 +4,7 @@ extern crate syntax;
 extern crate aster;
 extern crate elastic_codegen;
 extern crate walkdir;
+extern crate serde_json;
 
 use std::error::Error;
 use std::fs;
@@ -23,6 +24,7 @@ use elastic_codegen::api::gen::parse::{ parse_path_parts, parse_path_params };
 use elastic_codegen::emit::*;
 use elastic_codegen::emit::rust::*;
 use walkdir::WalkDir;
+use serde_json::Value;
 
 use std::env;
 
@@ -49,11 +51,19 @@ fn main() {
 	let _ = args.next().unwrap();
 	let indir = args.next().unwrap();
 	let outdir = args.next().unwrap();
+	let testout = args.next();
 
 	println!("spec: {}", indir);
 	println!("output: {}", outdir);
 
 	gen_from_source(&indir, &outdir).unwrap();
+
+	//Optionally emit the api dispatcher for the YAML test runner
+	if let Some(testout) = testout {
+		println!("test output: {}", testout);
+
+		gen_test_api_from_source(&indir, &testout).unwrap();
+	}
 }
 
 fn gen_from_source(source_dir: &str, dest_dir: &str) -> Result<(), String> {
@@ -78,18 +88,19 @@ fn gen_from_source(source_dir: &str, dest_dir: &str) -> Result<(), String> {
 	println!("parsing source spec files...");
 	let parsed = try!(from_dir(source_dir).map_err(|e| e.description().to_string()));
 
+	//Work out which optional imports each file needs before any headers are emitted
+	let mut file_imports: HashMap<String, FileImports> = HashMap::new();
+	for endpoint in &parsed {
+		let (_, file_path, _) = try!(endpoint_file(endpoint, dest_dir));
+		let imports = try!(endpoint_imports(endpoint));
+
+		file_imports.entry(file_path).or_insert_with(FileImports::default).add(&imports);
+	}
+
 	for endpoint in parsed {
 		//1. Get the path for the generated source
 		println!("building path for {}...", endpoint.get_name());
-		let mut path = try!(endpoint.get_mod_path().map_err(|_| format!("Error parsing path for {}", endpoint.get_name())));
-		let (file, file_is_mod) = match path.len() {
-			0 => ("mod".to_string(), true),
-			1 => ("mod".to_string(), true),
-			_ => (try!(path.pop().ok_or(format!("Error parsing path filename for {}", endpoint.get_name()))), false)
-		};
-
-		let dir_path = format!("{}/{}", dest_dir, path.join("/"));
-		let file_path = format!("{}/{}.rs", dir_path, file);
+		let (dir_path, file_path, file_is_mod) = try!(endpoint_file(&endpoint, dest_dir));
 
 		//Ensure the path exists
 		try!(fs::create_dir_all(&dir_path).map_err(|e| e.description().to_string()));
@@ -113,23 +124,50 @@ fn gen_from_source(source_dir: &str, dest_dir: &str) -> Result<(), String> {
 
 			try!(emitter.emit_str(&"//Autogenerated\n\n", &mut src_file).map_err(|e| e.description().to_string()));
 
+			let imports = file_imports.get(&file_path).cloned().unwrap_or(FileImports::default());
+
 			try!(emitter.emit(&quote_stmt!(&mut cx, use hyper::client::Client;), &mut src_file).map_err(|e| e.description().to_string()));
 			try!(emitter.emit_str(&"\n", &mut src_file).map_err(|e| e.description().to_string()));
 
-			try!(emitter.emit(&quote_stmt!(&mut cx,
-				#[allow(unused_imports)]
-				use hyper::client::Body;
-			), &mut src_file).map_err(|e| e.description().to_string()));
-			try!(emitter.emit_str(&"\n", &mut src_file).map_err(|e| e.description().to_string()));
+			if imports.body {
+				try!(emitter.emit(&quote_stmt!(&mut cx, use hyper::client::Body;), &mut src_file).map_err(|e| e.description().to_string()));
+				try!(emitter.emit_str(&"\n", &mut src_file).map_err(|e| e.description().to_string()));
+			}
 
 			try!(emitter.emit(&quote_stmt!(&mut cx, use hyper::client::response::Response;), &mut src_file).map_err(|e| e.description().to_string()));
 			try!(emitter.emit_str(&"\n", &mut src_file).map_err(|e| e.description().to_string()));
 
 			try!(emitter.emit(&quote_stmt!(&mut cx, use hyper::error::Result;), &mut src_file).map_err(|e| e.description().to_string()));
+			try!(emitter.emit_str(&"\n", &mut src_file).map_err(|e| e.description().to_string()));
+
+			try!(emitter.emit(&quote_stmt!(&mut cx, use hyper::method::Method;), &mut src_file).map_err(|e| e.description().to_string()));
 			try!(emitter.emit_str(&"\n\n", &mut src_file).map_err(|e| e.description().to_string()));
 
 			try!(emitter.emit(&quote_stmt!(&mut cx, use ::RequestParams;), &mut src_file).map_err(|e| e.description().to_string()));
-			try!(emitter.emit_str(&"\n\n", &mut src_file).map_err(|e| e.description().to_string()));
+			try!(emitter.emit_str(&"\n", &mut src_file).map_err(|e| e.description().to_string()));
+
+			try!(emitter.emit(&quote_stmt!(&mut cx, use ::UrlParams;), &mut src_file).map_err(|e| e.description().to_string()));
+			try!(emitter.emit_str(&"\n", &mut src_file).map_err(|e| e.description().to_string()));
+
+			try!(emitter.emit(&quote_stmt!(&mut cx, use ::HttpRequest;), &mut src_file).map_err(|e| e.description().to_string()));
+			try!(emitter.emit_str(&"\n", &mut src_file).map_err(|e| e.description().to_string()));
+
+			if imports.list_part {
+				try!(emitter.emit(&quote_stmt!(&mut cx, use ::ListPart;), &mut src_file).map_err(|e| e.description().to_string()));
+				try!(emitter.emit_str(&"\n", &mut src_file).map_err(|e| e.description().to_string()));
+			}
+
+			if imports.encode_path_part {
+				try!(emitter.emit(&quote_stmt!(&mut cx, use ::encode_path_part;), &mut src_file).map_err(|e| e.description().to_string()));
+				try!(emitter.emit_str(&"\n", &mut src_file).map_err(|e| e.description().to_string()));
+			}
+
+			try!(emitter.emit_str(&"\n", &mut src_file).map_err(|e| e.description().to_string()));
+
+			//Emit the typed url params for the endpoint
+			println!("emitting params for {}...", endpoint.get_name());
+			try!(emitter.emit_str(&gen_params(&endpoint), &mut src_file).map_err(|e| e.description().to_string()));
+
 
 			try!(src_file.sync_all().map_err(|e| e.description().to_string()));
 		}
@@ -149,6 +187,7 @@ fn gen_from_source(source_dir: &str, dest_dir: &str) -> Result<(), String> {
 			let client = token::str_to_ident(_client);
 
 			let req = token::str_to_ident("req");
+			let url_params = token::str_to_ident("params");
 			let base = token::str_to_ident("base");
 			let body = token::str_to_ident("body");
 			let qry = token::str_to_ident("url_qry");
@@ -172,24 +211,72 @@ fn gen_from_source(source_dir: &str, dest_dir: &str) -> Result<(), String> {
 			//Get the push statements
 			let (url_ident, url_stmts) = url_push_decl(base, parts.iter().map(|p| p.as_str()), params.to_vec());
 
+			//The request value fn builds the same url, so it needs its own copy of the statements
+			let (_, req_url_stmts) = url_push_decl(base, parts.iter().map(|p| p.as_str()), params.to_vec());
+
 			//Remove the query string param so it's not included in fn signature
 			let _ = params.pop();
 
+			//List parts take any `ListPart`, like a slice of indices, and other parts take a `&str`
+			let list_params: Vec<(Ident, Option<String>)> = params
+				.iter()
+				.map(|p| {
+					let name = p.name.as_str().to_string();
+					let part = match name.as_str() {
+						"_type" => "type",
+						s => s
+					};
+
+					let is_list = match endpoint.url.parts.get(part) {
+						Some(url_part) => url_part.get_type() == Type::List,
+						None => false
+					};
+
+					(p.clone(), match is_list {
+						true => Some(camel_case(&name)),
+						false => None
+					})
+				})
+				.collect();
+
+			//Percent-encode the parts before they're pushed to the url
+			let encode_stmts: Vec<Stmt> = list_params
+				.iter()
+				.map(|&(ref p, ref generic)| match *generic {
+					Some(_) => quote_stmt!(&mut cx, let $p = &$p.to_path_part();).unwrap(),
+					None => quote_stmt!(&mut cx, let $p = &encode_path_part($p);).unwrap()
+				})
+				.collect();
+
+			let part_generics = || list_params
+				.iter()
+				.filter_map(|&(_, ref generic)| generic.as_ref())
+				.map(|generic| build_ty_param(generic, vec!["ListPart"]))
+				.collect::<Vec<TyParam>>();
+
+			let part_args = || list_params
+				.iter()
+				.map(|&(ref p, ref generic)| match *generic {
+					Some(ref generic) => build_arg_ident(p.clone(), build_ty(generic)),
+					None => build_arg_ident(p.clone(), build_ty_ptr("str", Mutability::Immutable, Some(lifetime)))
+				})
+				.collect::<Vec<Arg>>();
+
 			//Function signature from params
 			let mut rs_fun = build_fn(&fun.name, vec![
 				build_arg(_client, build_ty_ptr("Client", Mutability::Mutable, Some(lifetime))),
-				build_arg_ident(req, build_ty_ptr("RequestParams", Mutability::Immutable, Some(lifetime)))
+				build_arg_ident(req, build_ty_ptr("RequestParams", Mutability::Immutable, Some(lifetime))),
+				build_arg_ident(url_params, build_ty_ptr("Params", Mutability::Immutable, Some(lifetime)))
 			])
-			.add_args(params
-				.iter()
-				.map(|p: &Ident| build_arg_ident(p.clone(), build_ty_ptr("str", Mutability::Immutable, Some(lifetime))))
-			)
+			.add_args(part_args())
 			.add_lifetime(lifetime)
+			.add_generic_params(part_generics())
 			.set_return_ty(build_ty("Result<Response>"))
 			.add_body_block(quote_block!(&mut cx, {
-				let $qry = &$req.get_url_qry();
+				let $qry = &$req.get_url_qry_with($url_params);
 				let $base = &$req.base_url;
 			}))
+			.add_body_stmts(encode_stmts.to_vec())
 			.add_body_stmts(url_stmts);
 
 			match *fun.method {
@@ -222,7 +309,7 @@ fn gen_from_source(source_dir: &str, dest_dir: &str) -> Result<(), String> {
 				},
 				HttpVerb::Post => {
 					rs_fun = rs_fun
-					.set_generic_params(vec![
+					.add_generic_params(vec![
 						build_ty_param(generic, vec![
 							"Into<Body<'a>>"
 						])
@@ -238,7 +325,7 @@ fn gen_from_source(source_dir: &str, dest_dir: &str) -> Result<(), String> {
 				},
 				HttpVerb::Put => {
 					rs_fun = rs_fun
-					.set_generic_params(vec![
+					.add_generic_params(vec![
 						build_ty_param(generic, vec![
 							"Into<Body<'a>>"
 						])
@@ -254,7 +341,7 @@ fn gen_from_source(source_dir: &str, dest_dir: &str) -> Result<(), String> {
 				},
 				HttpVerb::Patch => {
 					rs_fun = rs_fun
-					.set_generic_params(vec![
+					.add_generic_params(vec![
 						build_ty_param(generic, vec![
 							"Into<Body<'a>>"
 						])
@@ -272,6 +359,57 @@ fn gen_from_source(source_dir: &str, dest_dir: &str) -> Result<(), String> {
 
 			try!(emitter.emit(&rs_fun, &mut src_file).map_err(|e| e.description().to_string()));
 			try!(emitter.emit_str(&"\n", &mut src_file).map_err(|e| e.description().to_string()));
+
+			//Emit a fn that returns the request as a value instead of sending it
+			println!("emitting fn {}_request", &fun.name);
+
+			let method = token::str_to_ident(match *fun.method {
+				HttpVerb::Head => "Head",
+				HttpVerb::Get => "Get",
+				HttpVerb::Delete => "Delete",
+				HttpVerb::Post => "Post",
+				HttpVerb::Put => "Put",
+				HttpVerb::Patch => "Patch"
+			});
+
+			let mut rs_req_fun = build_fn(&format!("{}_request", fun.name), vec![
+				build_arg_ident(req, build_ty_ptr("RequestParams", Mutability::Immutable, Some(lifetime))),
+				build_arg_ident(url_params, build_ty_ptr("Params", Mutability::Immutable, Some(lifetime)))
+			])
+			.add_args(part_args())
+			.add_lifetime(lifetime)
+			.add_generic_params(part_generics())
+			.set_return_ty(build_ty("HttpRequest"))
+			.add_body_block(quote_block!(&mut cx, {
+				let $qry = &$req.get_url_qry_with($url_params);
+				let $base = &$req.base_url;
+			}))
+			.add_body_stmts(encode_stmts)
+			.add_body_stmts(req_url_stmts);
+
+			match *fun.method {
+				HttpVerb::Post | HttpVerb::Put | HttpVerb::Patch => {
+					rs_req_fun = rs_req_fun
+					.add_generic_params(vec![
+						build_ty_param("B", vec![
+							"AsRef<[u8]>"
+						])
+					])
+					.add_arg(build_arg_ident(body, build_ty("B")))
+					.add_body_block(quote_block!(&mut cx, {
+						HttpRequest::new(Method::$method, $url_ident, $req.headers.to_owned()).body($body)
+					}));
+				},
+				_ => {
+					rs_req_fun = rs_req_fun
+					.add_body_block(quote_block!(&mut cx, {
+						HttpRequest::new(Method::$method, $url_ident, $req.headers.to_owned())
+					}));
+				}
+			};
+
+			try!(emitter.emit(&rs_req_fun, &mut src_file).map_err(|e| e.description().to_string()));
+			try!(emitter.emit_str(&"\n", &mut src_file).map_err(|e| e.description().to_string()));
 			try!(src_file.sync_all().map_err(|e| e.description().to_string()));
 		}
 
@@ -316,3 +454,368 @@ fn gen_from_source(source_dir: &str, dest_dir: &str) -> Result<(), String> {
 
 	Ok(())
 }
+
+//Get the directory and file that the source for an endpoint is emitted to, and whether the file is a `mod.rs`
+fn endpoint_file(endpoint: &Endpoint, dest_dir: &str) -> Result<(String, String, bool), String> {
+	let mut path = try!(endpoint.get_mod_path().map_err(|_| format!("Error parsing path for {}", endpoint.get_name())));
+	let (file, file_is_mod) = match path.len() {
+		0 => ("mod".to_string(), true),
+		1 => ("mod".to_string(), true),
+		_ => (try!(path.pop().ok_or(format!("Error parsing path filename for {}", endpoint.get_name()))), false)
+	};
+
+	let dir_path = format!("{}/{}", dest_dir, path.join("/"));
+	let file_path = format!("{}/{}.rs", dir_path, file);
+
+	Ok((dir_path, file_path, file_is_mod))
+}
+
+//Imports that are only emitted for files with fns that use them
+#[derive(Debug, Default, Clone, Copy)]
+struct FileImports {
+	body: bool,
+	list_part: bool,
+	encode_path_part: bool
+}
+
+impl FileImports {
+	fn add(&mut self, other: &FileImports) {
+		self.body = self.body || other.body;
+		self.list_part = self.list_part || other.list_part;
+		self.encode_path_part = self.encode_path_part || other.encode_path_part;
+	}
+}
+
+//Find the imports used by the fns for an endpoint.
+//Fns with a body take `Into<Body>`, list parts are bound by `ListPart` and other parts are encoded with `encode_path_part`.
+fn endpoint_imports(endpoint: &Endpoint) -> Result<FileImports, String> {
+	let mut imports = FileImports::default();
+
+	for fun in try!(endpoint.get_fns().map_err(|e| e.description().to_string())) {
+		match *fun.method {
+			HttpVerb::Post | HttpVerb::Put | HttpVerb::Patch => imports.body = true,
+			_ => ()
+		}
+
+		for param in try!(parse_path_params(&fun.path)) {
+			let is_list = match endpoint.url.parts.get(param.as_str()) {
+				Some(url_part) => url_part.get_type() == Type::List,
+				None => false
+			};
+
+			match is_list {
+				true => imports.list_part = true,
+				false => imports.encode_path_part = true
+			}
+		}
+	}
+
+	Ok(imports)
+}
+
+fn gen_test_api_from_source(source_dir: &str, dest_file: &str) -> Result<(), String> {
+	println!("parsing source spec files...");
+	let mut parsed = try!(from_dir(source_dir).map_err(|e| e.description().to_string()));
+	parsed.sort_by(|a, b| a.get_name().cmp(b.get_name()));
+
+	println!("emitting test api dispatcher...");
+	let src = try!(gen_test_api(&parsed));
+
+	let mut dest = try!(File::create(dest_file).map_err(|e| e.description().to_string()));
+	try!(dest.write_all(src.as_bytes()).map_err(|e| e.description().to_string()));
+	try!(dest.sync_all().map_err(|e| e.description().to_string()));
+
+	Ok(())
+}
+
+//Generate a fn that calls the endpoint fn for an api by name, for the YAML test runner
+//
+//The url parts in the args are used to pick the path, and the rest of the args are sent as url params
+fn gen_test_api(endpoints: &[Endpoint]) -> Result<String, String> {
+	let mut src = String::new();
+
+	src.push_str("//! Call the generated endpoint functions by api name.\n\n");
+	src.push_str("//Autogenerated\n\n");
+	src.push_str("use std::collections::BTreeMap;\n");
+	src.push_str("use hyper::client::Client;\n");
+	src.push_str("use hyper::client::response::Response;\n");
+	src.push_str("use hyper::error::Result;\n");
+	src.push_str("use elastic_hyper::*;\n\n");
+
+	src.push_str("/// Call the endpoint function for an api, like `cluster.health`.\n");
+	src.push_str("///\n");
+	src.push_str("/// Args that are url parts pick the function to call, and the rest are sent as url params.\n");
+	src.push_str("/// Returns `Err` if the api or a url param isn't known, or if no url takes the given parts.\n");
+	src.push_str("pub fn call(client: &mut Client, req: &RequestParams, api: &str, args: &BTreeMap<String, String>, body: Option<&str>) -> ::std::result::Result<Result<Response>, String> {\n");
+	src.push_str("    match api {\n");
+
+	for endpoint in endpoints {
+		let name = endpoint.get_name();
+		let mod_path = try!(endpoint.get_mod_path().map_err(|_| format!("Error parsing path for {}", name))).join("::");
+		let all_parts: Vec<String> = endpoint.url.parts.keys().map(|p| format!("\"{}\"", p)).collect();
+
+		src.push_str(&format!("        \"{}\" => {{\n", name));
+		src.push_str(&format!("            let (parts, req) = try!(split_args(req, args, &[{}]));\n", all_parts.join(", ")));
+		src.push_str(&format!("            let params = &{}::Params::default();\n\n", mod_path));
+
+		//Prefer paths with more parts, so the most specific url is used
+		let mut paths = endpoint.url.paths.clone();
+		paths.sort_by(|a, b| {
+			let a = parse_path_params(a).map(|p| p.len()).unwrap_or(0);
+			let b = parse_path_params(b).map(|p| p.len()).unwrap_or(0);
+			b.cmp(&a)
+		});
+		paths.dedup();
+
+		for path in &paths {
+			let path_params = try!(parse_path_params(path).map_err(|_| format!("Error parsing path {}", path)));
+			let part_names: Vec<String> = path_params.iter().map(|p| format!("\"{}\"", p)).collect();
+			let part_args: String = path_params.iter().map(|p| format!(", &parts[\"{}\"]", p)).collect();
+
+			let with_body = test_api_call(&mod_path, path_params.as_slice(), &part_args, &endpoint.methods, true);
+			let without_body = test_api_call(&mod_path, path_params.as_slice(), &part_args, &endpoint.methods, false);
+
+			src.push_str(&format!("            if has_parts(&parts, &[{}]) {{\n", part_names.join(", ")));
+			//Endpoints that only take a body, or never take one, use the same fn either way
+			if with_body == without_body {
+				src.push_str(&format!("                return Ok({});\n", with_body));
+			}
+			else {
+				src.push_str("                return Ok(match body {\n");
+				src.push_str(&format!("                    Some(body) => {},\n", with_body));
+				src.push_str(&format!("                    None => {}\n", without_body));
+				src.push_str("                });\n");
+			}
+			src.push_str("            }\n");
+		}
+
+		src.push_str(&format!("\n            Err(format!(\"no url for `{}` takes the parts {{:?}}\", parts.keys().collect::<Vec<_>>()))\n", name));
+		src.push_str("        },\n");
+	}
+
+	src.push_str("        _ => Err(format!(\"unknown api `{}`\", api))\n");
+	src.push_str("    }\n");
+	src.push_str("}\n\n");
+
+	//Map url param names to static strings for the RequestParams
+	let mut params: Vec<&str> = endpoints
+		.iter()
+		.flat_map(|e| e.url.params.keys().map(|p| p.as_str()))
+		.chain(COMMON_PARAMS.iter().cloned())
+		.collect();
+	params.sort();
+	params.dedup();
+
+	src.push_str("fn param_name(name: &str) -> Option<&'static str> {\n");
+	src.push_str("    match name {\n");
+	for param in params {
+		src.push_str(&format!("        \"{}\" => Some(\"{}\"),\n", param, param));
+	}
+	src.push_str("        _ => None\n");
+	src.push_str("    }\n");
+	src.push_str("}\n\n");
+
+	src.push_str("fn split_args(req: &RequestParams, args: &BTreeMap<String, String>, part_names: &[&str]) -> ::std::result::Result<(BTreeMap<String, String>, RequestParams), String> {\n");
+	src.push_str("    let mut parts = BTreeMap::new();\n");
+	src.push_str("    let mut req = req.clone();\n\n");
+	src.push_str("    for (name, value) in args {\n");
+	src.push_str("        if part_names.contains(&name.as_str()) {\n");
+	src.push_str("            parts.insert(name.clone(), value.clone());\n");
+	src.push_str("        }\n");
+	src.push_str("        else {\n");
+	src.push_str("            let param = try!(param_name(name).ok_or(format!(\"unknown param `{}`\", name)));\n");
+	src.push_str("            req.url_params.insert(param, value.clone());\n");
+	src.push_str("        }\n");
+	src.push_str("    }\n\n");
+	src.push_str("    Ok((parts, req))\n");
+	src.push_str("}\n\n");
+
+	src.push_str("fn has_parts(parts: &BTreeMap<String, String>, names: &[&str]) -> bool {\n");
+	src.push_str("    parts.len() == names.len() && names.iter().all(|name| parts.contains_key(*name))\n");
+	src.push_str("}\n");
+
+	Ok(src)
+}
+
+//Url params that are accepted by every endpoint
+const COMMON_PARAMS: &'static [&'static str] = &[
+	"error_trace",
+	"filter_path",
+	"human",
+	"pretty",
+	"source"
+];
+
+//Build a call to the endpoint fn for a path, with or without a body
+//
+//Requests with a body use the first method that takes one, and requests without use the first that doesn't.
+//If there's no method that matches, the request is sent with an empty body, or without its body.
+fn test_api_call(mod_path: &str, path_params: &[String], part_args: &str, methods: &[HttpVerb], has_body: bool) -> String {
+	let takes_body = |m: &HttpVerb| match *m {
+		HttpVerb::Post | HttpVerb::Put | HttpVerb::Patch => true,
+		_ => false
+	};
+
+	let method = methods
+		.iter()
+		.find(|m| takes_body(*m) == has_body)
+		.or(methods.first())
+		.cloned()
+		.unwrap_or(HttpVerb::Get);
+
+	let method_name = match method {
+		HttpVerb::Head => "head",
+		HttpVerb::Post => "post",
+		HttpVerb::Put => "put",
+		HttpVerb::Patch => "patch",
+		HttpVerb::Delete => "delete",
+		HttpVerb::Get => "get"
+	};
+
+	let path_name = match path_params.len() {
+		0 => String::new(),
+		_ => format!("_{}", path_params.join("_"))
+	};
+
+	let body_arg = match (takes_body(&method), has_body) {
+		(true, true) => ", body",
+		(true, false) => ", \"\"",
+		(false, _) => ""
+	};
+
+	format!("{}::{}{}(client, &req, params{}{})", mod_path, method_name, path_name, part_args, body_arg)
+}
+
+//Generate the typed url params struct and option enums for an endpoint
+//
+//These are emitted as plain source because the number of fields depends on the spec
+fn gen_params(endpoint: &Endpoint) -> String {
+	let params = &endpoint.url.params;
+	let mut src = String::new();
+
+	src.push_str(&format!("/// Url parameters for the `{}` endpoint.\n", endpoint.get_name()));
+	src.push_str("#[derive(Debug, Default, Clone)]\n");
+
+	if params.len() == 0 {
+		src.push_str("pub struct Params;\n\n");
+		src.push_str("impl UrlParams for Params {\n");
+		src.push_str("    fn url_params(&self) -> Vec<(&'static str, String)> {\n");
+		src.push_str("        Vec::new()\n");
+		src.push_str("    }\n");
+		src.push_str("}\n\n");
+
+		return src;
+	}
+
+	//The params struct
+	src.push_str("pub struct Params {\n");
+	for (name, param) in params {
+		let description = param.description.trim();
+		if description.len() > 0 {
+			src.push_str(&format!("    /// {}\n", description));
+		}
+		if let Some(default) = param.get_default::<Value>() {
+			src.push_str(&format!("    /// Defaults to `{}`.\n", fmt_default(&default)));
+		}
+
+		src.push_str(&format!("    pub {}: Option<{}>,\n", param_field(name), param_ty(name, param)));
+	}
+	src.push_str("}\n\n");
+
+	//Convert set params into key-value pairs
+	src.push_str("impl UrlParams for Params {\n");
+	src.push_str("    fn url_params(&self) -> Vec<(&'static str, String)> {\n");
+	src.push_str("        let mut params = Vec::new();\n");
+	for (name, param) in params {
+		let field = param_field(name);
+
+		src.push_str(&format!("        if let Some(ref {}) = self.{} {{\n", field, field));
+		src.push_str(&format!("            params.push((\"{}\", {}));\n", name, param_value(&field, param)));
+		src.push_str("        }\n");
+	}
+	src.push_str("        params\n");
+	src.push_str("    }\n");
+	src.push_str("}\n\n");
+
+	//An enum for each param with a set of options
+	for (name, param) in params {
+		if let Type::Enum(&Some(ref opts)) = param.get_type() {
+			let ty = param_ty(name, param);
+
+			src.push_str(&format!("/// Options for the `{}` url parameter.\n", name));
+			src.push_str("#[derive(Debug, Clone, Copy, PartialEq)]\n");
+			src.push_str(&format!("pub enum {} {{\n", ty));
+			for opt in opts {
+				src.push_str(&format!("    /// `{}`\n", opt));
+				src.push_str(&format!("    {},\n", camel_case(opt)));
+			}
+			src.push_str("}\n\n");
+
+			src.push_str(&format!("impl {} {{\n", ty));
+			src.push_str("    /// Get the value of the option as it's sent in the url.\n");
+			src.push_str("    pub fn as_str(&self) -> &'static str {\n");
+			src.push_str("        match *self {\n");
+			for opt in opts {
+				src.push_str(&format!("            {}::{} => \"{}\",\n", ty, camel_case(opt), opt));
+			}
+			src.push_str("        }\n");
+			src.push_str("    }\n");
+			src.push_str("}\n\n");
+		}
+	}
+
+	src
+}
+
+fn param_field(name: &str) -> String {
+	match name {
+		"type" => "_type".to_string(),
+		s => s.to_string()
+	}
+}
+
+fn param_ty(name: &str, param: &Param) -> String {
+	match param.get_type() {
+		Type::Bool => "bool".to_string(),
+		Type::Number(NumberKind::Int) => "i32".to_string(),
+		Type::Number(NumberKind::Short) => "i16".to_string(),
+		Type::Number(NumberKind::Byte) => "i8".to_string(),
+		Type::Number(NumberKind::Double) => "f64".to_string(),
+		Type::Number(NumberKind::Float) => "f32".to_string(),
+		Type::Number(_) => "i64".to_string(),
+		Type::List => "Vec<String>".to_string(),
+		Type::Enum(&Some(_)) => camel_case(name),
+		_ => "String".to_string()
+	}
+}
+
+fn param_value(field: &str, param: &Param) -> String {
+	match param.get_type() {
+		Type::List => format!("{}.join(\",\")", field),
+		Type::Enum(&Some(_)) => format!("{}.as_str().to_owned()", field),
+		Type::Bool | Type::Number(_) => format!("{}.to_string()", field),
+		_ => format!("{}.to_owned()", field)
+	}
+}
+
+fn fmt_default(default: &Value) -> String {
+	match *default {
+		Value::String(ref s) => s.to_owned(),
+		Value::Array(ref a) => a.iter().map(fmt_default).collect::<Vec<String>>().join(","),
+		ref v => v.to_string()
+	}
+}
+
+fn camel_case(s: &str) -> String {
+	s.split(|c| c == '_' || c == '.' || c == ' ')
+		.filter(|p| p.len() > 0)
+		.map(|p| {
+			let p = p.to_lowercase();
+			let mut chars = p.chars();
+			match chars.next() {
+				Some(c) => c.to_uppercase().chain(chars).collect::<String>(),
+				None => String::new()
+			}
+		})
+		.collect()
+}
@@ -20,7 +20,7 @@ fn main() {
 
 	//Execute a HTTP Post search request. Other variants include post_index, post_index_type
 	let mut res = elastic::search::post(
-		&mut client, &params,
+		&mut client, &params, &elastic::search::Params::default(),
 		&json_str!({
 			query: {
 				query_string: {
@@ -3,19 +3,92 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
 use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::encode_path_part;
+
+/// Url parameters for the `bulk` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// Explicit write consistency setting for the operation
+    pub consistency: Option<Consistency>,
+    /// Default comma-separated list of fields to return in the response for updates
+    pub fields: Option<Vec<String>>,
+    /// The pipeline id to preprocess incoming documents with
+    pub pipeline: Option<String>,
+    /// Refresh the index after performing the operation
+    pub refresh: Option<bool>,
+    /// Specific routing value
+    pub routing: Option<String>,
+    /// Explicit operation timeout
+    pub timeout: Option<String>,
+    /// Default document type for items which don't provide one
+    pub _type: Option<String>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref consistency) = self.consistency {
+            params.push(("consistency", consistency.as_str().to_owned()));
+        }
+        if let Some(ref fields) = self.fields {
+            params.push(("fields", fields.join(",")));
+        }
+        if let Some(ref pipeline) = self.pipeline {
+            params.push(("pipeline", pipeline.to_owned()));
+        }
+        if let Some(ref refresh) = self.refresh {
+            params.push(("refresh", refresh.to_string()));
+        }
+        if let Some(ref routing) = self.routing {
+            params.push(("routing", routing.to_owned()));
+        }
+        if let Some(ref timeout) = self.timeout {
+            params.push(("timeout", timeout.to_owned()));
+        }
+        if let Some(ref _type) = self._type {
+            params.push(("type", _type.to_owned()));
+        }
+        params
+    }
+}
+
+/// Options for the `consistency` url parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Consistency {
+    /// `one`
+    One,
+    /// `quorum`
+    Quorum,
+    /// `all`
+    All,
+}
+
+impl Consistency {
+    /// Get the value of the option as it's sent in the url.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Consistency::One => "one",
+            Consistency::Quorum => "quorum",
+            Consistency::All => "all",
+        }
+    }
+}
 
 pub fn post_index<'a,
               I: Into<Body<'a>>>(client: &'a mut Client,
-                                 req: &'a RequestParams, index: &'a str,
+                                 req: &'a RequestParams, params: &'a Params, index: &'a str,
                                  body: I) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &encode_path_part(index);
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 6 + index.len() +
                                   url_qry.len());
@@ -28,13 +101,31 @@ pub fn post_index<'a,
         client.post(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
+pub fn post_index_request<'a, B: AsRef<[u8]>>(req: &'a RequestParams, params: &'a Params,
+                                              index: &'a str, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &encode_path_part(index);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 6 + index.len() +
+                                  url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/_bulk");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Post, url_fmtd, req.headers.to_owned()).body(body)
+}
 pub fn put_index_type<'a,
                   I: Into<Body<'a>>>(client: &'a mut Client,
-                                     req: &'a RequestParams, index: &'a str,
+                                     req: &'a RequestParams, params: &'a Params, index: &'a str,
                                      _type: &'a str, body: I)
  -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &encode_path_part(index);
+    let _type = &encode_path_part(_type);
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 1 + 6 + index.len() +
                                   _type.len() + url_qry.len());
@@ -49,13 +140,34 @@ pub fn put_index_type<'a,
         client.put(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
+pub fn put_index_type_request<'a, B: AsRef<[u8]>>(req: &'a RequestParams, params: &'a Params,
+                                                  index: &'a str, _type: &'a str, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &encode_path_part(index);
+    let _type = &encode_path_part(_type);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 1 + 6 + index.len() +
+                                  _type.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(_type);
+    url_fmtd.push_str("/_bulk");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Put, url_fmtd, req.headers.to_owned()).body(body)
+}
 pub fn post_index_type<'a,
                    I: Into<Body<'a>>>(client: &'a mut Client,
-                                      req: &'a RequestParams, index: &'a str,
+                                      req: &'a RequestParams, params: &'a Params, index: &'a str,
                                       _type: &'a str, body: I)
  -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &encode_path_part(index);
+    let _type = &encode_path_part(_type);
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 1 + 6 + index.len() +
                                   _type.len() + url_qry.len());
@@ -70,10 +182,29 @@ pub fn post_index_type<'a,
         client.post(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
+pub fn post_index_type_request<'a, B: AsRef<[u8]>>(req: &'a RequestParams, params: &'a Params,
+                                                   index: &'a str, _type: &'a str, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &encode_path_part(index);
+    let _type = &encode_path_part(_type);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 1 + 6 + index.len() +
+                                  _type.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(_type);
+    url_fmtd.push_str("/_bulk");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Post, url_fmtd, req.headers.to_owned()).body(body)
+}
 pub fn post<'a,
-        I: Into<Body<'a>>>(client: &'a mut Client, req: &'a RequestParams,
+        I: Into<Body<'a>>>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params,
                            body: I) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
     let mut url_fmtd = String::with_capacity(base.len() + 6 + url_qry.len());
     url_fmtd.push_str(base);
@@ -83,10 +214,20 @@ pub fn post<'a,
         client.post(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
+pub fn post_request<'a, B: AsRef<[u8]>>(req: &'a RequestParams, params: &'a Params, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let mut url_fmtd = String::with_capacity(base.len() + 6 + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_bulk");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Post, url_fmtd, req.headers.to_owned()).body(body)
+}
 pub fn put<'a,
-       I: Into<Body<'a>>>(client: &'a mut Client, req: &'a RequestParams,
+       I: Into<Body<'a>>>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params,
                           body: I) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
     let mut url_fmtd = String::with_capacity(base.len() + 6 + url_qry.len());
     url_fmtd.push_str(base);
@@ -96,12 +237,23 @@ pub fn put<'a,
         client.put(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
+pub fn put_request<'a, B: AsRef<[u8]>>(req: &'a RequestParams, params: &'a Params, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let mut url_fmtd = String::with_capacity(base.len() + 6 + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_bulk");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Put, url_fmtd, req.headers.to_owned()).body(body)
+}
 pub fn put_index<'a,
              I: Into<Body<'a>>>(client: &'a mut Client,
-                                req: &'a RequestParams, index: &'a str,
+                                req: &'a RequestParams, params: &'a Params, index: &'a str,
                                 body: I) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &encode_path_part(index);
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 6 + index.len() +
                                   url_qry.len());
@@ -114,3 +266,19 @@ pub fn put_index<'a,
         client.put(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
+pub fn put_index_request<'a, B: AsRef<[u8]>>(req: &'a RequestParams, params: &'a Params,
+                                             index: &'a str, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &encode_path_part(index);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 6 + index.len() +
+                                  url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/_bulk");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Put, url_fmtd, req.headers.to_owned()).body(body)
+}
@@ -3,15 +3,96 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
-use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::ListPart;
 
-pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+/// Url parameters for the `cat.fielddata` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// The unit in which to display byte values
+    pub bytes: Option<Bytes>,
+    /// A comma-separated list of fields to return in the output
+    pub fields: Option<Vec<String>>,
+    /// a short version of the Accept header, e.g. json, yaml
+    pub format: Option<String>,
+    /// Comma-separated list of column names to display
+    pub h: Option<Vec<String>>,
+    /// Return help information
+    /// Defaults to `false`.
+    pub help: Option<bool>,
+    /// Return local information, do not retrieve the state from master node (default: false)
+    pub local: Option<bool>,
+    /// Explicit operation timeout for connection to master node
+    pub master_timeout: Option<String>,
+    /// Verbose mode. Display column headers
+    /// Defaults to `false`.
+    pub v: Option<bool>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref bytes) = self.bytes {
+            params.push(("bytes", bytes.as_str().to_owned()));
+        }
+        if let Some(ref fields) = self.fields {
+            params.push(("fields", fields.join(",")));
+        }
+        if let Some(ref format) = self.format {
+            params.push(("format", format.to_owned()));
+        }
+        if let Some(ref h) = self.h {
+            params.push(("h", h.join(",")));
+        }
+        if let Some(ref help) = self.help {
+            params.push(("help", help.to_string()));
+        }
+        if let Some(ref local) = self.local {
+            params.push(("local", local.to_string()));
+        }
+        if let Some(ref master_timeout) = self.master_timeout {
+            params.push(("master_timeout", master_timeout.to_owned()));
+        }
+        if let Some(ref v) = self.v {
+            params.push(("v", v.to_string()));
+        }
+        params
+    }
+}
+
+/// Options for the `bytes` url parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Bytes {
+    /// `b`
+    B,
+    /// `k`
+    K,
+    /// `m`
+    M,
+    /// `g`
+    G,
+}
+
+impl Bytes {
+    /// Get the value of the option as it's sent in the url.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Bytes::B => "b",
+            Bytes::K => "k",
+            Bytes::M => "m",
+            Bytes::G => "g",
+        }
+    }
+}
+
+pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params) -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
     let mut url_fmtd = String::with_capacity(base.len() + 15 + url_qry.len());
     url_fmtd.push_str(base);
@@ -20,10 +101,21 @@ pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams) -> Result<Respons
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
-pub fn get_fields<'a>(client: &'a mut Client, req: &'a RequestParams,
-                  fields: &'a str) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+pub fn get_request<'a>(req: &'a RequestParams, params: &'a Params) -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let mut url_fmtd = String::with_capacity(base.len() + 15 + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_cat/fielddata");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
+pub fn get_fields<'a, Fields: ListPart>(client: &'a mut Client, req: &'a RequestParams,
+                                        params: &'a Params, fields: Fields)
+ -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let fields = &fields.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 16 + fields.len() + url_qry.len());
     url_fmtd.push_str(base);
@@ -33,3 +125,17 @@ pub fn get_fields<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
+pub fn get_fields_request<'a, Fields: ListPart>(req: &'a RequestParams, params: &'a Params,
+                                                fields: Fields)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let fields = &fields.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 16 + fields.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_cat/fielddata/");
+    url_fmtd.push_str(fields);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
@@ -3,15 +3,66 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
-use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
 
-pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+/// Url parameters for the `cat.health` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// a short version of the Accept header, e.g. json, yaml
+    pub format: Option<String>,
+    /// Comma-separated list of column names to display
+    pub h: Option<Vec<String>>,
+    /// Return help information
+    /// Defaults to `false`.
+    pub help: Option<bool>,
+    /// Return local information, do not retrieve the state from master node (default: false)
+    pub local: Option<bool>,
+    /// Explicit operation timeout for connection to master node
+    pub master_timeout: Option<String>,
+    /// Set to false to disable timestamping
+    /// Defaults to `true`.
+    pub ts: Option<bool>,
+    /// Verbose mode. Display column headers
+    /// Defaults to `false`.
+    pub v: Option<bool>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref format) = self.format {
+            params.push(("format", format.to_owned()));
+        }
+        if let Some(ref h) = self.h {
+            params.push(("h", h.join(",")));
+        }
+        if let Some(ref help) = self.help {
+            params.push(("help", help.to_string()));
+        }
+        if let Some(ref local) = self.local {
+            params.push(("local", local.to_string()));
+        }
+        if let Some(ref master_timeout) = self.master_timeout {
+            params.push(("master_timeout", master_timeout.to_owned()));
+        }
+        if let Some(ref ts) = self.ts {
+            params.push(("ts", ts.to_string()));
+        }
+        if let Some(ref v) = self.v {
+            params.push(("v", v.to_string()));
+        }
+        params
+    }
+}
+
+pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params) -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
     let mut url_fmtd = String::with_capacity(base.len() + 12 + url_qry.len());
     url_fmtd.push_str(base);
@@ -20,3 +71,12 @@ pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams) -> Result<Respons
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
+pub fn get_request<'a>(req: &'a RequestParams, params: &'a Params) -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let mut url_fmtd = String::with_capacity(base.len() + 12 + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_cat/health");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
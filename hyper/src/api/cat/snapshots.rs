@@ -3,17 +3,66 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
-use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::ListPart;
 
-pub fn get_repository<'a>(client: &'a mut Client, req: &'a RequestParams,
-                      repository: &'a str) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+/// Url parameters for the `cat.snapshots` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// a short version of the Accept header, e.g. json, yaml
+    pub format: Option<String>,
+    /// Comma-separated list of column names to display
+    pub h: Option<Vec<String>>,
+    /// Return help information
+    /// Defaults to `false`.
+    pub help: Option<bool>,
+    /// Set to true to ignore unavailable snapshots
+    /// Defaults to `false`.
+    pub ignore_unavailable: Option<bool>,
+    /// Explicit operation timeout for connection to master node
+    pub master_timeout: Option<String>,
+    /// Verbose mode. Display column headers
+    /// Defaults to `false`.
+    pub v: Option<bool>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref format) = self.format {
+            params.push(("format", format.to_owned()));
+        }
+        if let Some(ref h) = self.h {
+            params.push(("h", h.join(",")));
+        }
+        if let Some(ref help) = self.help {
+            params.push(("help", help.to_string()));
+        }
+        if let Some(ref ignore_unavailable) = self.ignore_unavailable {
+            params.push(("ignore_unavailable", ignore_unavailable.to_string()));
+        }
+        if let Some(ref master_timeout) = self.master_timeout {
+            params.push(("master_timeout", master_timeout.to_owned()));
+        }
+        if let Some(ref v) = self.v {
+            params.push(("v", v.to_string()));
+        }
+        params
+    }
+}
+
+pub fn get_repository<'a, Repository: ListPart>(client: &'a mut Client, req: &'a RequestParams,
+                                                params: &'a Params, repository: Repository)
+ -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let repository = &repository.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 16 + repository.len() +
                                   url_qry.len());
@@ -24,3 +73,19 @@ pub fn get_repository<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
+pub fn get_repository_request<'a, Repository: ListPart>(req: &'a RequestParams,
+                                                        params: &'a Params,
+                                                        repository: Repository)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let repository = &repository.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 16 + repository.len() +
+                                  url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_cat/snapshots/");
+    url_fmtd.push_str(repository);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
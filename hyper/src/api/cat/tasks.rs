@@ -3,15 +3,75 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
-use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
 
-pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+/// Url parameters for the `cat.tasks` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// A comma-separated list of actions that should be returned. Leave empty to return all.
+    pub actions: Option<Vec<String>>,
+    /// Return detailed task information (default: false)
+    pub detailed: Option<bool>,
+    /// a short version of the Accept header, e.g. json, yaml
+    pub format: Option<String>,
+    /// Comma-separated list of column names to display
+    pub h: Option<Vec<String>>,
+    /// Return help information
+    /// Defaults to `false`.
+    pub help: Option<bool>,
+    /// A comma-separated list of node IDs or names to limit the returned information; use `_local` to return information from the node you're connecting to, leave empty to get information from all nodes
+    pub node_id: Option<Vec<String>>,
+    /// Return tasks with specified parent node.
+    pub parent_node: Option<String>,
+    /// Return tasks with specified parent task id. Set to -1 to return all.
+    pub parent_task: Option<i64>,
+    /// Verbose mode. Display column headers
+    /// Defaults to `false`.
+    pub v: Option<bool>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref actions) = self.actions {
+            params.push(("actions", actions.join(",")));
+        }
+        if let Some(ref detailed) = self.detailed {
+            params.push(("detailed", detailed.to_string()));
+        }
+        if let Some(ref format) = self.format {
+            params.push(("format", format.to_owned()));
+        }
+        if let Some(ref h) = self.h {
+            params.push(("h", h.join(",")));
+        }
+        if let Some(ref help) = self.help {
+            params.push(("help", help.to_string()));
+        }
+        if let Some(ref node_id) = self.node_id {
+            params.push(("node_id", node_id.join(",")));
+        }
+        if let Some(ref parent_node) = self.parent_node {
+            params.push(("parent_node", parent_node.to_owned()));
+        }
+        if let Some(ref parent_task) = self.parent_task {
+            params.push(("parent_task", parent_task.to_string()));
+        }
+        if let Some(ref v) = self.v {
+            params.push(("v", v.to_string()));
+        }
+        params
+    }
+}
+
+pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params) -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
     let mut url_fmtd = String::with_capacity(base.len() + 11 + url_qry.len());
     url_fmtd.push_str(base);
@@ -20,3 +80,12 @@ pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams) -> Result<Respons
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
+pub fn get_request<'a>(req: &'a RequestParams, params: &'a Params) -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let mut url_fmtd = String::with_capacity(base.len() + 11 + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_cat/tasks");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
@@ -3,17 +3,31 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
-use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::ListPart;
 
-pub fn delete_scroll_id<'a>(client: &'a mut Client, req: &'a RequestParams,
-                        scroll_id: &'a str) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+/// Url parameters for the `clear_scroll` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params;
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+}
+
+pub fn delete_scroll_id<'a, ScrollId: ListPart>(client: &'a mut Client, req: &'a RequestParams,
+                                                params: &'a Params, scroll_id: ScrollId)
+ -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let scroll_id = &scroll_id.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 16 + scroll_id.len() +
                                   url_qry.len());
@@ -24,9 +38,24 @@ pub fn delete_scroll_id<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.delete(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
-pub fn delete<'a>(client: &'a mut Client, req: &'a RequestParams)
+pub fn delete_scroll_id_request<'a, ScrollId: ListPart>(req: &'a RequestParams,
+                                                        params: &'a Params, scroll_id: ScrollId)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let scroll_id = &scroll_id.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 16 + scroll_id.len() +
+                                  url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_search/scroll/");
+    url_fmtd.push_str(scroll_id);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Delete, url_fmtd, req.headers.to_owned())
+}
+pub fn delete<'a>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params)
  -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
     let mut url_fmtd = String::with_capacity(base.len() + 15 + url_qry.len());
     url_fmtd.push_str(base);
@@ -35,3 +64,12 @@ pub fn delete<'a>(client: &'a mut Client, req: &'a RequestParams)
     let res = client.delete(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
+pub fn delete_request<'a>(req: &'a RequestParams, params: &'a Params) -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let mut url_fmtd = String::with_capacity(base.len() + 15 + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_search/scroll");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Delete, url_fmtd, req.headers.to_owned())
+}
@@ -3,17 +3,36 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
 use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+
+/// Url parameters for the `cluster.allocation_explain` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// Return 'YES' decisions in explanation (default: false)
+    pub include_yes_decisions: Option<bool>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref include_yes_decisions) = self.include_yes_decisions {
+            params.push(("include_yes_decisions", include_yes_decisions.to_string()));
+        }
+        params
+    }
+}
 
 pub fn post<'a,
-        I: Into<Body<'a>>>(client: &'a mut Client, req: &'a RequestParams,
+        I: Into<Body<'a>>>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params,
                            body: I) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
     let mut url_fmtd = String::with_capacity(base.len() + 28 + url_qry.len());
     url_fmtd.push_str(base);
@@ -23,8 +42,18 @@ pub fn post<'a,
         client.post(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
-pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+pub fn post_request<'a, B: AsRef<[u8]>>(req: &'a RequestParams, params: &'a Params, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let mut url_fmtd = String::with_capacity(base.len() + 28 + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_cluster/allocation/explain");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Post, url_fmtd, req.headers.to_owned()).body(body)
+}
+pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params) -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
     let mut url_fmtd = String::with_capacity(base.len() + 28 + url_qry.len());
     url_fmtd.push_str(base);
@@ -33,3 +62,12 @@ pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams) -> Result<Respons
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
+pub fn get_request<'a>(req: &'a RequestParams, params: &'a Params) -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let mut url_fmtd = String::with_capacity(base.len() + 28 + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_cluster/allocation/explain");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
@@ -3,15 +3,49 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
-use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
 
-pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+/// Url parameters for the `cluster.get_settings` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// Return settings in flat format (default: false)
+    pub flat_settings: Option<bool>,
+    /// Whether to return all default clusters setting.
+    /// Defaults to `false`.
+    pub include_defaults: Option<bool>,
+    /// Explicit operation timeout for connection to master node
+    pub master_timeout: Option<String>,
+    /// Explicit operation timeout
+    pub timeout: Option<String>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref flat_settings) = self.flat_settings {
+            params.push(("flat_settings", flat_settings.to_string()));
+        }
+        if let Some(ref include_defaults) = self.include_defaults {
+            params.push(("include_defaults", include_defaults.to_string()));
+        }
+        if let Some(ref master_timeout) = self.master_timeout {
+            params.push(("master_timeout", master_timeout.to_owned()));
+        }
+        if let Some(ref timeout) = self.timeout {
+            params.push(("timeout", timeout.to_owned()));
+        }
+        params
+    }
+}
+
+pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params) -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
     let mut url_fmtd = String::with_capacity(base.len() + 18 + url_qry.len());
     url_fmtd.push_str(base);
@@ -20,3 +54,12 @@ pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams) -> Result<Respons
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
+pub fn get_request<'a>(req: &'a RequestParams, params: &'a Params) -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let mut url_fmtd = String::with_capacity(base.len() + 18 + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_cluster/settings");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
@@ -3,17 +3,118 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
-use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::ListPart;
 
-pub fn get_index<'a>(client: &'a mut Client, req: &'a RequestParams,
-                 index: &'a str) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+/// Url parameters for the `cluster.health` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// Specify the level of detail for returned information
+    /// Defaults to `cluster`.
+    pub level: Option<Level>,
+    /// Return local information, do not retrieve the state from master node (default: false)
+    pub local: Option<bool>,
+    /// Explicit operation timeout for connection to master node
+    pub master_timeout: Option<String>,
+    /// Explicit operation timeout
+    pub timeout: Option<String>,
+    /// Wait until the specified number of shards is active
+    pub wait_for_active_shards: Option<i64>,
+    /// Wait until the specified number of nodes is available
+    pub wait_for_nodes: Option<String>,
+    /// Wait until the specified number of relocating shards is finished
+    pub wait_for_relocating_shards: Option<i64>,
+    /// Wait until cluster is in a specific state
+    pub wait_for_status: Option<WaitForStatus>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref level) = self.level {
+            params.push(("level", level.as_str().to_owned()));
+        }
+        if let Some(ref local) = self.local {
+            params.push(("local", local.to_string()));
+        }
+        if let Some(ref master_timeout) = self.master_timeout {
+            params.push(("master_timeout", master_timeout.to_owned()));
+        }
+        if let Some(ref timeout) = self.timeout {
+            params.push(("timeout", timeout.to_owned()));
+        }
+        if let Some(ref wait_for_active_shards) = self.wait_for_active_shards {
+            params.push(("wait_for_active_shards", wait_for_active_shards.to_string()));
+        }
+        if let Some(ref wait_for_nodes) = self.wait_for_nodes {
+            params.push(("wait_for_nodes", wait_for_nodes.to_owned()));
+        }
+        if let Some(ref wait_for_relocating_shards) = self.wait_for_relocating_shards {
+            params.push(("wait_for_relocating_shards", wait_for_relocating_shards.to_string()));
+        }
+        if let Some(ref wait_for_status) = self.wait_for_status {
+            params.push(("wait_for_status", wait_for_status.as_str().to_owned()));
+        }
+        params
+    }
+}
+
+/// Options for the `level` url parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Level {
+    /// `cluster`
+    Cluster,
+    /// `indices`
+    Indices,
+    /// `shards`
+    Shards,
+}
+
+impl Level {
+    /// Get the value of the option as it's sent in the url.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Level::Cluster => "cluster",
+            Level::Indices => "indices",
+            Level::Shards => "shards",
+        }
+    }
+}
+
+/// Options for the `wait_for_status` url parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WaitForStatus {
+    /// `green`
+    Green,
+    /// `yellow`
+    Yellow,
+    /// `red`
+    Red,
+}
+
+impl WaitForStatus {
+    /// Get the value of the option as it's sent in the url.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            WaitForStatus::Green => "green",
+            WaitForStatus::Yellow => "yellow",
+            WaitForStatus::Red => "red",
+        }
+    }
+}
+
+pub fn get_index<'a, Index: ListPart>(client: &'a mut Client, req: &'a RequestParams,
+                                      params: &'a Params, index: Index)
+ -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &index.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 17 + index.len() + url_qry.len());
     url_fmtd.push_str(base);
@@ -23,8 +124,22 @@ pub fn get_index<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
-pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+pub fn get_index_request<'a, Index: ListPart>(req: &'a RequestParams, params: &'a Params,
+                                              index: Index)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &index.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 17 + index.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_cluster/health/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
+pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params) -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
     let mut url_fmtd = String::with_capacity(base.len() + 16 + url_qry.len());
     url_fmtd.push_str(base);
@@ -33,3 +148,12 @@ pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams) -> Result<Respons
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
+pub fn get_request<'a>(req: &'a RequestParams, params: &'a Params) -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let mut url_fmtd = String::with_capacity(base.len() + 16 + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_cluster/health");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
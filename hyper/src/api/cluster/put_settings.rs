@@ -3,17 +3,46 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
 use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+
+/// Url parameters for the `cluster.put_settings` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// Return settings in flat format (default: false)
+    pub flat_settings: Option<bool>,
+    /// Explicit operation timeout for connection to master node
+    pub master_timeout: Option<String>,
+    /// Explicit operation timeout
+    pub timeout: Option<String>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref flat_settings) = self.flat_settings {
+            params.push(("flat_settings", flat_settings.to_string()));
+        }
+        if let Some(ref master_timeout) = self.master_timeout {
+            params.push(("master_timeout", master_timeout.to_owned()));
+        }
+        if let Some(ref timeout) = self.timeout {
+            params.push(("timeout", timeout.to_owned()));
+        }
+        params
+    }
+}
 
 pub fn put<'a,
-       I: Into<Body<'a>>>(client: &'a mut Client, req: &'a RequestParams,
+       I: Into<Body<'a>>>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params,
                           body: I) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
     let mut url_fmtd = String::with_capacity(base.len() + 18 + url_qry.len());
     url_fmtd.push_str(base);
@@ -23,3 +52,13 @@ pub fn put<'a,
         client.put(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
+pub fn put_request<'a, B: AsRef<[u8]>>(req: &'a RequestParams, params: &'a Params, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let mut url_fmtd = String::with_capacity(base.len() + 18 + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_cluster/settings");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Put, url_fmtd, req.headers.to_owned()).body(body)
+}
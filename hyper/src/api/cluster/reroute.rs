@@ -3,17 +3,56 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
 use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+
+/// Url parameters for the `cluster.reroute` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// Simulate the operation only and return the resulting state
+    pub dry_run: Option<bool>,
+    /// Return an explanation of why the commands can or cannot be executed
+    pub explain: Option<bool>,
+    /// Explicit operation timeout for connection to master node
+    pub master_timeout: Option<String>,
+    /// Limit the information returned to the specified metrics. Defaults to all but metadata
+    pub metric: Option<Vec<String>>,
+    /// Explicit operation timeout
+    pub timeout: Option<String>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref dry_run) = self.dry_run {
+            params.push(("dry_run", dry_run.to_string()));
+        }
+        if let Some(ref explain) = self.explain {
+            params.push(("explain", explain.to_string()));
+        }
+        if let Some(ref master_timeout) = self.master_timeout {
+            params.push(("master_timeout", master_timeout.to_owned()));
+        }
+        if let Some(ref metric) = self.metric {
+            params.push(("metric", metric.join(",")));
+        }
+        if let Some(ref timeout) = self.timeout {
+            params.push(("timeout", timeout.to_owned()));
+        }
+        params
+    }
+}
 
 pub fn post<'a,
-        I: Into<Body<'a>>>(client: &'a mut Client, req: &'a RequestParams,
+        I: Into<Body<'a>>>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params,
                            body: I) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
     let mut url_fmtd = String::with_capacity(base.len() + 17 + url_qry.len());
     url_fmtd.push_str(base);
@@ -23,3 +62,13 @@ pub fn post<'a,
         client.post(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
+pub fn post_request<'a, B: AsRef<[u8]>>(req: &'a RequestParams, params: &'a Params, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let mut url_fmtd = String::with_capacity(base.len() + 17 + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_cluster/reroute");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Post, url_fmtd, req.headers.to_owned()).body(body)
+}
@@ -3,17 +3,89 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
-use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::ListPart;
 
-pub fn get_metric<'a>(client: &'a mut Client, req: &'a RequestParams,
-                  metric: &'a str) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+/// Url parameters for the `cluster.state` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// Whether to ignore if a wildcard indices expression resolves into no concrete indices. (This includes `_all` string or when no indices have been specified)
+    pub allow_no_indices: Option<bool>,
+    /// Whether to expand wildcard expression to concrete indices that are open, closed or both.
+    /// Defaults to `open`.
+    pub expand_wildcards: Option<ExpandWildcards>,
+    /// Return settings in flat format (default: false)
+    pub flat_settings: Option<bool>,
+    /// Whether specified concrete indices should be ignored when unavailable (missing or closed)
+    pub ignore_unavailable: Option<bool>,
+    /// Return local information, do not retrieve the state from master node (default: false)
+    pub local: Option<bool>,
+    /// Specify timeout for connection to master
+    pub master_timeout: Option<String>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref allow_no_indices) = self.allow_no_indices {
+            params.push(("allow_no_indices", allow_no_indices.to_string()));
+        }
+        if let Some(ref expand_wildcards) = self.expand_wildcards {
+            params.push(("expand_wildcards", expand_wildcards.as_str().to_owned()));
+        }
+        if let Some(ref flat_settings) = self.flat_settings {
+            params.push(("flat_settings", flat_settings.to_string()));
+        }
+        if let Some(ref ignore_unavailable) = self.ignore_unavailable {
+            params.push(("ignore_unavailable", ignore_unavailable.to_string()));
+        }
+        if let Some(ref local) = self.local {
+            params.push(("local", local.to_string()));
+        }
+        if let Some(ref master_timeout) = self.master_timeout {
+            params.push(("master_timeout", master_timeout.to_owned()));
+        }
+        params
+    }
+}
+
+/// Options for the `expand_wildcards` url parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExpandWildcards {
+    /// `open`
+    Open,
+    /// `closed`
+    Closed,
+    /// `none`
+    None,
+    /// `all`
+    All,
+}
+
+impl ExpandWildcards {
+    /// Get the value of the option as it's sent in the url.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            ExpandWildcards::Open => "open",
+            ExpandWildcards::Closed => "closed",
+            ExpandWildcards::None => "none",
+            ExpandWildcards::All => "all",
+        }
+    }
+}
+
+pub fn get_metric<'a, Metric: ListPart>(client: &'a mut Client, req: &'a RequestParams,
+                                        params: &'a Params, metric: Metric)
+ -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let metric = &metric.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 16 + metric.len() + url_qry.len());
     url_fmtd.push_str(base);
@@ -23,8 +95,22 @@ pub fn get_metric<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
-pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+pub fn get_metric_request<'a, Metric: ListPart>(req: &'a RequestParams, params: &'a Params,
+                                                metric: Metric)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let metric = &metric.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 16 + metric.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_cluster/state/");
+    url_fmtd.push_str(metric);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
+pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params) -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
     let mut url_fmtd = String::with_capacity(base.len() + 15 + url_qry.len());
     url_fmtd.push_str(base);
@@ -33,10 +119,25 @@ pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams) -> Result<Respons
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
-pub fn get_metric_index<'a>(client: &'a mut Client, req: &'a RequestParams,
-                        metric: &'a str, index: &'a str) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+pub fn get_request<'a>(req: &'a RequestParams, params: &'a Params) -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let mut url_fmtd = String::with_capacity(base.len() + 15 + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_cluster/state");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
+pub fn get_metric_index<'a,
+                        Metric: ListPart, Index: ListPart>(client: &'a mut Client,
+                                                           req: &'a RequestParams,
+                                                           params: &'a Params, metric: Metric,
+                                                           index: Index)
+ -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let metric = &metric.to_path_part();
+    let index = &index.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 16 + 1 + metric.len() + index.len()
                                   + url_qry.len());
@@ -49,3 +150,23 @@ pub fn get_metric_index<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
+pub fn get_metric_index_request<'a,
+                                Metric: ListPart, Index: ListPart>(req: &'a RequestParams,
+                                                                   params: &'a Params,
+                                                                   metric: Metric, index: Index)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let metric = &metric.to_path_part();
+    let index = &index.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 16 + 1 + metric.len() + index.len()
+                                  + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_cluster/state/");
+    url_fmtd.push_str(metric);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
@@ -3,18 +3,132 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
 use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::encode_path_part;
 
-pub fn get_index_type_id<'a>(client: &'a mut Client, req: &'a RequestParams,
+/// Url parameters for the `count_percolate` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// Whether to ignore if a wildcard indices expression resolves into no concrete indices. (This includes `_all` string or when no indices have been specified)
+    pub allow_no_indices: Option<bool>,
+    /// Whether to expand wildcard expression to concrete indices that are open, closed or both.
+    /// Defaults to `open`.
+    pub expand_wildcards: Option<ExpandWildcards>,
+    /// Whether specified concrete indices should be ignored when unavailable (missing or closed)
+    pub ignore_unavailable: Option<bool>,
+    /// The index to count percolate the document into. Defaults to index.
+    pub percolate_index: Option<String>,
+    /// The type to count percolate document into. Defaults to type.
+    pub percolate_type: Option<String>,
+    /// Specify the node or shard the operation should be performed on (default: random)
+    pub preference: Option<String>,
+    /// A comma-separated list of specific routing values
+    pub routing: Option<Vec<String>>,
+    /// Explicit version number for concurrency control
+    pub version: Option<i64>,
+    /// Specific version type
+    pub version_type: Option<VersionType>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref allow_no_indices) = self.allow_no_indices {
+            params.push(("allow_no_indices", allow_no_indices.to_string()));
+        }
+        if let Some(ref expand_wildcards) = self.expand_wildcards {
+            params.push(("expand_wildcards", expand_wildcards.as_str().to_owned()));
+        }
+        if let Some(ref ignore_unavailable) = self.ignore_unavailable {
+            params.push(("ignore_unavailable", ignore_unavailable.to_string()));
+        }
+        if let Some(ref percolate_index) = self.percolate_index {
+            params.push(("percolate_index", percolate_index.to_owned()));
+        }
+        if let Some(ref percolate_type) = self.percolate_type {
+            params.push(("percolate_type", percolate_type.to_owned()));
+        }
+        if let Some(ref preference) = self.preference {
+            params.push(("preference", preference.to_owned()));
+        }
+        if let Some(ref routing) = self.routing {
+            params.push(("routing", routing.join(",")));
+        }
+        if let Some(ref version) = self.version {
+            params.push(("version", version.to_string()));
+        }
+        if let Some(ref version_type) = self.version_type {
+            params.push(("version_type", version_type.as_str().to_owned()));
+        }
+        params
+    }
+}
+
+/// Options for the `expand_wildcards` url parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExpandWildcards {
+    /// `open`
+    Open,
+    /// `closed`
+    Closed,
+    /// `none`
+    None,
+    /// `all`
+    All,
+}
+
+impl ExpandWildcards {
+    /// Get the value of the option as it's sent in the url.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            ExpandWildcards::Open => "open",
+            ExpandWildcards::Closed => "closed",
+            ExpandWildcards::None => "none",
+            ExpandWildcards::All => "all",
+        }
+    }
+}
+
+/// Options for the `version_type` url parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VersionType {
+    /// `internal`
+    Internal,
+    /// `external`
+    External,
+    /// `external_gte`
+    ExternalGte,
+    /// `force`
+    Force,
+}
+
+impl VersionType {
+    /// Get the value of the option as it's sent in the url.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            VersionType::Internal => "internal",
+            VersionType::External => "external",
+            VersionType::ExternalGte => "external_gte",
+            VersionType::Force => "force",
+        }
+    }
+}
+
+pub fn get_index_type_id<'a>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params,
                          index: &'a str, _type: &'a str, id: &'a str)
  -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &encode_path_part(index);
+    let _type = &encode_path_part(_type);
+    let id = &encode_path_part(id);
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 1 + 1 + 17 + index.len() +
                                   _type.len() + id.len() + url_qry.len());
@@ -30,10 +144,34 @@ pub fn get_index_type_id<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
-pub fn get_index_type<'a>(client: &'a mut Client, req: &'a RequestParams,
+pub fn get_index_type_id_request<'a>(req: &'a RequestParams, params: &'a Params, index: &'a str,
+                                     _type: &'a str, id: &'a str)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &encode_path_part(index);
+    let _type = &encode_path_part(_type);
+    let id = &encode_path_part(id);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 1 + 1 + 17 + index.len() +
+                                  _type.len() + id.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(_type);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(id);
+    url_fmtd.push_str("/_percolate/count");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
+pub fn get_index_type<'a>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params,
                       index: &'a str, _type: &'a str) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &encode_path_part(index);
+    let _type = &encode_path_part(_type);
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 1 + 17 + index.len() +
                                   _type.len() + url_qry.len());
@@ -47,13 +185,34 @@ pub fn get_index_type<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
+pub fn get_index_type_request<'a>(req: &'a RequestParams, params: &'a Params, index: &'a str,
+                                  _type: &'a str)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &encode_path_part(index);
+    let _type = &encode_path_part(_type);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 1 + 17 + index.len() +
+                                  _type.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(_type);
+    url_fmtd.push_str("/_percolate/count");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
 pub fn post_index_type<'a,
                    I: Into<Body<'a>>>(client: &'a mut Client,
-                                      req: &'a RequestParams, index: &'a str,
+                                      req: &'a RequestParams, params: &'a Params, index: &'a str,
                                       _type: &'a str, body: I)
  -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &encode_path_part(index);
+    let _type = &encode_path_part(_type);
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 1 + 17 + index.len() +
                                   _type.len() + url_qry.len());
@@ -68,14 +227,36 @@ pub fn post_index_type<'a,
         client.post(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
+pub fn post_index_type_request<'a, B: AsRef<[u8]>>(req: &'a RequestParams, params: &'a Params,
+                                                   index: &'a str, _type: &'a str, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &encode_path_part(index);
+    let _type = &encode_path_part(_type);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 1 + 17 + index.len() +
+                                  _type.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(_type);
+    url_fmtd.push_str("/_percolate/count");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Post, url_fmtd, req.headers.to_owned()).body(body)
+}
 pub fn post_index_type_id<'a,
                       I: Into<Body<'a>>>(client: &'a mut Client,
-                                         req: &'a RequestParams,
+                                         req: &'a RequestParams, params: &'a Params,
                                          index: &'a str, _type: &'a str,
                                          id: &'a str, body: I)
  -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &encode_path_part(index);
+    let _type = &encode_path_part(_type);
+    let id = &encode_path_part(id);
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 1 + 1 + 17 + index.len() +
                                   _type.len() + id.len() + url_qry.len());
@@ -92,3 +273,26 @@ pub fn post_index_type_id<'a,
         client.post(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
+pub fn post_index_type_id_request<'a, B: AsRef<[u8]>>(req: &'a RequestParams,
+                                                      params: &'a Params, index: &'a str,
+                                                      _type: &'a str, id: &'a str, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &encode_path_part(index);
+    let _type = &encode_path_part(_type);
+    let id = &encode_path_part(id);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 1 + 1 + 17 + index.len() +
+                                  _type.len() + id.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(_type);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(id);
+    url_fmtd.push_str("/_percolate/count");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Post, url_fmtd, req.headers.to_owned()).body(body)
+}
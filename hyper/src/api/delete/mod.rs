@@ -3,18 +3,117 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
-use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::encode_path_part;
 
-pub fn delete_index_type_id<'a>(client: &'a mut Client, req: &'a RequestParams,
+/// Url parameters for the `delete` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// Specific write consistency setting for the operation
+    pub consistency: Option<Consistency>,
+    /// ID of parent document
+    pub parent: Option<String>,
+    /// Refresh the index after performing the operation
+    pub refresh: Option<bool>,
+    /// Specific routing value
+    pub routing: Option<String>,
+    /// Explicit operation timeout
+    pub timeout: Option<String>,
+    /// Explicit version number for concurrency control
+    pub version: Option<i64>,
+    /// Specific version type
+    pub version_type: Option<VersionType>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref consistency) = self.consistency {
+            params.push(("consistency", consistency.as_str().to_owned()));
+        }
+        if let Some(ref parent) = self.parent {
+            params.push(("parent", parent.to_owned()));
+        }
+        if let Some(ref refresh) = self.refresh {
+            params.push(("refresh", refresh.to_string()));
+        }
+        if let Some(ref routing) = self.routing {
+            params.push(("routing", routing.to_owned()));
+        }
+        if let Some(ref timeout) = self.timeout {
+            params.push(("timeout", timeout.to_owned()));
+        }
+        if let Some(ref version) = self.version {
+            params.push(("version", version.to_string()));
+        }
+        if let Some(ref version_type) = self.version_type {
+            params.push(("version_type", version_type.as_str().to_owned()));
+        }
+        params
+    }
+}
+
+/// Options for the `consistency` url parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Consistency {
+    /// `one`
+    One,
+    /// `quorum`
+    Quorum,
+    /// `all`
+    All,
+}
+
+impl Consistency {
+    /// Get the value of the option as it's sent in the url.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Consistency::One => "one",
+            Consistency::Quorum => "quorum",
+            Consistency::All => "all",
+        }
+    }
+}
+
+/// Options for the `version_type` url parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VersionType {
+    /// `internal`
+    Internal,
+    /// `external`
+    External,
+    /// `external_gte`
+    ExternalGte,
+    /// `force`
+    Force,
+}
+
+impl VersionType {
+    /// Get the value of the option as it's sent in the url.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            VersionType::Internal => "internal",
+            VersionType::External => "external",
+            VersionType::ExternalGte => "external_gte",
+            VersionType::Force => "force",
+        }
+    }
+}
+
+pub fn delete_index_type_id<'a>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params,
                             index: &'a str, _type: &'a str, id: &'a str)
  -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &encode_path_part(index);
+    let _type = &encode_path_part(_type);
+    let id = &encode_path_part(id);
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 1 + 1 + index.len() +
                                   _type.len() + id.len() + url_qry.len());
@@ -29,3 +128,24 @@ pub fn delete_index_type_id<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.delete(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
+pub fn delete_index_type_id_request<'a>(req: &'a RequestParams, params: &'a Params,
+                                        index: &'a str, _type: &'a str, id: &'a str)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &encode_path_part(index);
+    let _type = &encode_path_part(_type);
+    let id = &encode_path_part(id);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 1 + 1 + index.len() +
+                                  _type.len() + id.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(_type);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(id);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Delete, url_fmtd, req.headers.to_owned())
+}
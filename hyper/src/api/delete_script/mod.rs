@@ -3,17 +3,68 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
-use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::encode_path_part;
 
-pub fn delete_lang_id<'a>(client: &'a mut Client, req: &'a RequestParams,
+/// Url parameters for the `delete_script` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// Explicit version number for concurrency control
+    pub version: Option<i64>,
+    /// Specific version type
+    pub version_type: Option<VersionType>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref version) = self.version {
+            params.push(("version", version.to_string()));
+        }
+        if let Some(ref version_type) = self.version_type {
+            params.push(("version_type", version_type.as_str().to_owned()));
+        }
+        params
+    }
+}
+
+/// Options for the `version_type` url parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VersionType {
+    /// `internal`
+    Internal,
+    /// `external`
+    External,
+    /// `external_gte`
+    ExternalGte,
+    /// `force`
+    Force,
+}
+
+impl VersionType {
+    /// Get the value of the option as it's sent in the url.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            VersionType::Internal => "internal",
+            VersionType::External => "external",
+            VersionType::ExternalGte => "external_gte",
+            VersionType::Force => "force",
+        }
+    }
+}
+
+pub fn delete_lang_id<'a>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params,
                       lang: &'a str, id: &'a str) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let lang = &encode_path_part(lang);
+    let id = &encode_path_part(id);
     let mut url_fmtd =
         String::with_capacity(base.len() + 10 + 1 + lang.len() + id.len() +
                                   url_qry.len());
@@ -26,3 +77,21 @@ pub fn delete_lang_id<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.delete(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
+pub fn delete_lang_id_request<'a>(req: &'a RequestParams, params: &'a Params, lang: &'a str,
+                                  id: &'a str)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let lang = &encode_path_part(lang);
+    let id = &encode_path_part(id);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 10 + 1 + lang.len() + id.len() +
+                                  url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_scripts/");
+    url_fmtd.push_str(lang);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(id);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Delete, url_fmtd, req.headers.to_owned())
+}
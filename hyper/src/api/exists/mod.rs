@@ -3,18 +3,60 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
-use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::encode_path_part;
 
-pub fn head_index_type_id<'a>(client: &'a mut Client, req: &'a RequestParams,
+/// Url parameters for the `exists` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// The ID of the parent document
+    pub parent: Option<String>,
+    /// Specify the node or shard the operation should be performed on (default: random)
+    pub preference: Option<String>,
+    /// Specify whether to perform the operation in realtime or search mode
+    pub realtime: Option<bool>,
+    /// Refresh the shard containing the document before performing the operation
+    pub refresh: Option<bool>,
+    /// Specific routing value
+    pub routing: Option<String>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref parent) = self.parent {
+            params.push(("parent", parent.to_owned()));
+        }
+        if let Some(ref preference) = self.preference {
+            params.push(("preference", preference.to_owned()));
+        }
+        if let Some(ref realtime) = self.realtime {
+            params.push(("realtime", realtime.to_string()));
+        }
+        if let Some(ref refresh) = self.refresh {
+            params.push(("refresh", refresh.to_string()));
+        }
+        if let Some(ref routing) = self.routing {
+            params.push(("routing", routing.to_owned()));
+        }
+        params
+    }
+}
+
+pub fn head_index_type_id<'a>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params,
                           index: &'a str, _type: &'a str, id: &'a str)
  -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &encode_path_part(index);
+    let _type = &encode_path_part(_type);
+    let id = &encode_path_part(id);
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 1 + 1 + index.len() +
                                   _type.len() + id.len() + url_qry.len());
@@ -29,3 +71,24 @@ pub fn head_index_type_id<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.head(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
+pub fn head_index_type_id_request<'a>(req: &'a RequestParams, params: &'a Params,
+                                      index: &'a str, _type: &'a str, id: &'a str)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &encode_path_part(index);
+    let _type = &encode_path_part(_type);
+    let id = &encode_path_part(id);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 1 + 1 + index.len() +
+                                  _type.len() + id.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(_type);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(id);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Head, url_fmtd, req.headers.to_owned())
+}
@@ -3,18 +3,126 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
 use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::encode_path_part;
 
-pub fn get_index_type_id<'a>(client: &'a mut Client, req: &'a RequestParams,
+/// Url parameters for the `explain` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// True or false to return the _source field or not, or a list of fields to return
+    pub _source: Option<Vec<String>>,
+    /// A list of fields to exclude from the returned _source field
+    pub _source_exclude: Option<Vec<String>>,
+    /// A list of fields to extract and return from the _source field
+    pub _source_include: Option<Vec<String>>,
+    /// Specify whether wildcards and prefix queries in the query string query should be analyzed (default: false)
+    pub analyze_wildcard: Option<bool>,
+    /// The analyzer for the query string query
+    pub analyzer: Option<String>,
+    /// The default operator for query string query (AND or OR)
+    /// Defaults to `OR`.
+    pub default_operator: Option<DefaultOperator>,
+    /// The default field for query string query (default: _all)
+    pub df: Option<String>,
+    /// A comma-separated list of fields to return in the response
+    pub fields: Option<Vec<String>>,
+    /// Specify whether format-based query failures (such as providing text to a numeric field) should be ignored
+    pub lenient: Option<bool>,
+    /// Specify whether query terms should be lowercased
+    pub lowercase_expanded_terms: Option<bool>,
+    /// The ID of the parent document
+    pub parent: Option<String>,
+    /// Specify the node or shard the operation should be performed on (default: random)
+    pub preference: Option<String>,
+    /// Query in the Lucene query string syntax
+    pub q: Option<String>,
+    /// Specific routing value
+    pub routing: Option<String>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref _source) = self._source {
+            params.push(("_source", _source.join(",")));
+        }
+        if let Some(ref _source_exclude) = self._source_exclude {
+            params.push(("_source_exclude", _source_exclude.join(",")));
+        }
+        if let Some(ref _source_include) = self._source_include {
+            params.push(("_source_include", _source_include.join(",")));
+        }
+        if let Some(ref analyze_wildcard) = self.analyze_wildcard {
+            params.push(("analyze_wildcard", analyze_wildcard.to_string()));
+        }
+        if let Some(ref analyzer) = self.analyzer {
+            params.push(("analyzer", analyzer.to_owned()));
+        }
+        if let Some(ref default_operator) = self.default_operator {
+            params.push(("default_operator", default_operator.as_str().to_owned()));
+        }
+        if let Some(ref df) = self.df {
+            params.push(("df", df.to_owned()));
+        }
+        if let Some(ref fields) = self.fields {
+            params.push(("fields", fields.join(",")));
+        }
+        if let Some(ref lenient) = self.lenient {
+            params.push(("lenient", lenient.to_string()));
+        }
+        if let Some(ref lowercase_expanded_terms) = self.lowercase_expanded_terms {
+            params.push(("lowercase_expanded_terms", lowercase_expanded_terms.to_string()));
+        }
+        if let Some(ref parent) = self.parent {
+            params.push(("parent", parent.to_owned()));
+        }
+        if let Some(ref preference) = self.preference {
+            params.push(("preference", preference.to_owned()));
+        }
+        if let Some(ref q) = self.q {
+            params.push(("q", q.to_owned()));
+        }
+        if let Some(ref routing) = self.routing {
+            params.push(("routing", routing.to_owned()));
+        }
+        params
+    }
+}
+
+/// Options for the `default_operator` url parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DefaultOperator {
+    /// `AND`
+    And,
+    /// `OR`
+    Or,
+}
+
+impl DefaultOperator {
+    /// Get the value of the option as it's sent in the url.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            DefaultOperator::And => "AND",
+            DefaultOperator::Or => "OR",
+        }
+    }
+}
+
+pub fn get_index_type_id<'a>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params,
                          index: &'a str, _type: &'a str, id: &'a str)
  -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &encode_path_part(index);
+    let _type = &encode_path_part(_type);
+    let id = &encode_path_part(id);
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 1 + 1 + 9 + index.len() +
                                   _type.len() + id.len() + url_qry.len());
@@ -30,14 +138,39 @@ pub fn get_index_type_id<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
+pub fn get_index_type_id_request<'a>(req: &'a RequestParams, params: &'a Params, index: &'a str,
+                                     _type: &'a str, id: &'a str)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &encode_path_part(index);
+    let _type = &encode_path_part(_type);
+    let id = &encode_path_part(id);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 1 + 1 + 9 + index.len() +
+                                  _type.len() + id.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(_type);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(id);
+    url_fmtd.push_str("/_explain");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
 pub fn post_index_type_id<'a,
                       I: Into<Body<'a>>>(client: &'a mut Client,
-                                         req: &'a RequestParams,
+                                         req: &'a RequestParams, params: &'a Params,
                                          index: &'a str, _type: &'a str,
                                          id: &'a str, body: I)
  -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &encode_path_part(index);
+    let _type = &encode_path_part(_type);
+    let id = &encode_path_part(id);
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 1 + 1 + 9 + index.len() +
                                   _type.len() + id.len() + url_qry.len());
@@ -54,3 +187,26 @@ pub fn post_index_type_id<'a,
         client.post(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
+pub fn post_index_type_id_request<'a, B: AsRef<[u8]>>(req: &'a RequestParams,
+                                                      params: &'a Params, index: &'a str,
+                                                      _type: &'a str, id: &'a str, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &encode_path_part(index);
+    let _type = &encode_path_part(_type);
+    let id = &encode_path_part(id);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 1 + 1 + 9 + index.len() +
+                                  _type.len() + id.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(_type);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(id);
+    url_fmtd.push_str("/_explain");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Post, url_fmtd, req.headers.to_owned()).body(body)
+}
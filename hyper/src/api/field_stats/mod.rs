@@ -3,17 +3,103 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
 use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::ListPart;
+
+/// Url parameters for the `field_stats` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// Whether to ignore if a wildcard indices expression resolves into no concrete indices. (This includes `_all` string or when no indices have been specified)
+    pub allow_no_indices: Option<bool>,
+    /// Whether to expand wildcard expression to concrete indices that are open, closed or both.
+    /// Defaults to `open`.
+    pub expand_wildcards: Option<ExpandWildcards>,
+    /// A comma-separated list of fields for to get field statistics for (min value, max value, and more)
+    pub fields: Option<Vec<String>>,
+    /// Whether specified concrete indices should be ignored when unavailable (missing or closed)
+    pub ignore_unavailable: Option<bool>,
+    /// Defines if field stats should be returned on a per index level or on a cluster wide level
+    /// Defaults to `cluster`.
+    pub level: Option<Level>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref allow_no_indices) = self.allow_no_indices {
+            params.push(("allow_no_indices", allow_no_indices.to_string()));
+        }
+        if let Some(ref expand_wildcards) = self.expand_wildcards {
+            params.push(("expand_wildcards", expand_wildcards.as_str().to_owned()));
+        }
+        if let Some(ref fields) = self.fields {
+            params.push(("fields", fields.join(",")));
+        }
+        if let Some(ref ignore_unavailable) = self.ignore_unavailable {
+            params.push(("ignore_unavailable", ignore_unavailable.to_string()));
+        }
+        if let Some(ref level) = self.level {
+            params.push(("level", level.as_str().to_owned()));
+        }
+        params
+    }
+}
+
+/// Options for the `expand_wildcards` url parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExpandWildcards {
+    /// `open`
+    Open,
+    /// `closed`
+    Closed,
+    /// `none`
+    None,
+    /// `all`
+    All,
+}
+
+impl ExpandWildcards {
+    /// Get the value of the option as it's sent in the url.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            ExpandWildcards::Open => "open",
+            ExpandWildcards::Closed => "closed",
+            ExpandWildcards::None => "none",
+            ExpandWildcards::All => "all",
+        }
+    }
+}
+
+/// Options for the `level` url parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Level {
+    /// `indices`
+    Indices,
+    /// `cluster`
+    Cluster,
+}
+
+impl Level {
+    /// Get the value of the option as it's sent in the url.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Level::Indices => "indices",
+            Level::Cluster => "cluster",
+        }
+    }
+}
 
 pub fn post<'a,
-        I: Into<Body<'a>>>(client: &'a mut Client, req: &'a RequestParams,
+        I: Into<Body<'a>>>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params,
                            body: I) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
     let mut url_fmtd = String::with_capacity(base.len() + 13 + url_qry.len());
     url_fmtd.push_str(base);
@@ -23,12 +109,24 @@ pub fn post<'a,
         client.post(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
+pub fn post_request<'a, B: AsRef<[u8]>>(req: &'a RequestParams, params: &'a Params, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let mut url_fmtd = String::with_capacity(base.len() + 13 + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_field_stats");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Post, url_fmtd, req.headers.to_owned()).body(body)
+}
 pub fn post_index<'a,
-              I: Into<Body<'a>>>(client: &'a mut Client,
-                                 req: &'a RequestParams, index: &'a str,
-                                 body: I) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+                  Index: ListPart, I: Into<Body<'a>>>(client: &'a mut Client,
+                                                      req: &'a RequestParams,
+                                                      params: &'a Params, index: Index, body: I)
+ -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &index.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 13 + index.len() +
                                   url_qry.len());
@@ -41,8 +139,26 @@ pub fn post_index<'a,
         client.post(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
-pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+pub fn post_index_request<'a,
+                          Index: ListPart, B: AsRef<[u8]>>(req: &'a RequestParams,
+                                                           params: &'a Params, index: Index,
+                                                           body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &index.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 13 + index.len() +
+                                  url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/_field_stats");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Post, url_fmtd, req.headers.to_owned()).body(body)
+}
+pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params) -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
     let mut url_fmtd = String::with_capacity(base.len() + 13 + url_qry.len());
     url_fmtd.push_str(base);
@@ -51,10 +167,21 @@ pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams) -> Result<Respons
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
-pub fn get_index<'a>(client: &'a mut Client, req: &'a RequestParams,
-                 index: &'a str) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+pub fn get_request<'a>(req: &'a RequestParams, params: &'a Params) -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let mut url_fmtd = String::with_capacity(base.len() + 13 + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_field_stats");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
+pub fn get_index<'a, Index: ListPart>(client: &'a mut Client, req: &'a RequestParams,
+                                      params: &'a Params, index: Index)
+ -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &index.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 13 + index.len() +
                                   url_qry.len());
@@ -66,3 +193,19 @@ pub fn get_index<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
+pub fn get_index_request<'a, Index: ListPart>(req: &'a RequestParams, params: &'a Params,
+                                              index: Index)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &index.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 13 + index.len() +
+                                  url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/_field_stats");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
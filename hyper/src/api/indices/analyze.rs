@@ -3,19 +3,110 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
 use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::encode_path_part;
+
+/// Url parameters for the `indices.analyze` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// The name of the analyzer to use
+    pub analyzer: Option<String>,
+    /// A comma-separated list of token attributes to output, this parameter works only with `explain=true`
+    pub attributes: Option<Vec<String>>,
+    /// A comma-separated list of character filters to use for the analysis
+    pub char_filter: Option<Vec<String>>,
+    /// With `true`, outputs more advanced details. (default: false)
+    pub explain: Option<bool>,
+    /// Use the analyzer configured for this field (instead of passing the analyzer name)
+    pub field: Option<String>,
+    /// A comma-separated list of filters to use for the analysis
+    pub filter: Option<Vec<String>>,
+    /// Format of the output
+    /// Defaults to `detailed`.
+    pub format: Option<Format>,
+    /// The name of the index to scope the operation
+    pub index: Option<String>,
+    /// With `true`, specify that a local shard should be used if available, with `false`, use a random shard (default: true)
+    pub prefer_local: Option<bool>,
+    /// The text on which the analysis should be performed (when request body is not used)
+    pub text: Option<Vec<String>>,
+    /// The name of the tokenizer to use for the analysis
+    pub tokenizer: Option<String>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref analyzer) = self.analyzer {
+            params.push(("analyzer", analyzer.to_owned()));
+        }
+        if let Some(ref attributes) = self.attributes {
+            params.push(("attributes", attributes.join(",")));
+        }
+        if let Some(ref char_filter) = self.char_filter {
+            params.push(("char_filter", char_filter.join(",")));
+        }
+        if let Some(ref explain) = self.explain {
+            params.push(("explain", explain.to_string()));
+        }
+        if let Some(ref field) = self.field {
+            params.push(("field", field.to_owned()));
+        }
+        if let Some(ref filter) = self.filter {
+            params.push(("filter", filter.join(",")));
+        }
+        if let Some(ref format) = self.format {
+            params.push(("format", format.as_str().to_owned()));
+        }
+        if let Some(ref index) = self.index {
+            params.push(("index", index.to_owned()));
+        }
+        if let Some(ref prefer_local) = self.prefer_local {
+            params.push(("prefer_local", prefer_local.to_string()));
+        }
+        if let Some(ref text) = self.text {
+            params.push(("text", text.join(",")));
+        }
+        if let Some(ref tokenizer) = self.tokenizer {
+            params.push(("tokenizer", tokenizer.to_owned()));
+        }
+        params
+    }
+}
+
+/// Options for the `format` url parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    /// `detailed`
+    Detailed,
+    /// `text`
+    Text,
+}
+
+impl Format {
+    /// Get the value of the option as it's sent in the url.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Format::Detailed => "detailed",
+            Format::Text => "text",
+        }
+    }
+}
 
 pub fn post_index<'a,
               I: Into<Body<'a>>>(client: &'a mut Client,
-                                 req: &'a RequestParams, index: &'a str,
+                                 req: &'a RequestParams, params: &'a Params, index: &'a str,
                                  body: I) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &encode_path_part(index);
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 9 + index.len() +
                                   url_qry.len());
@@ -28,10 +119,26 @@ pub fn post_index<'a,
         client.post(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
+pub fn post_index_request<'a, B: AsRef<[u8]>>(req: &'a RequestParams, params: &'a Params,
+                                              index: &'a str, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &encode_path_part(index);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 9 + index.len() +
+                                  url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/_analyze");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Post, url_fmtd, req.headers.to_owned()).body(body)
+}
 pub fn post<'a,
-        I: Into<Body<'a>>>(client: &'a mut Client, req: &'a RequestParams,
+        I: Into<Body<'a>>>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params,
                            body: I) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
     let mut url_fmtd = String::with_capacity(base.len() + 9 + url_qry.len());
     url_fmtd.push_str(base);
@@ -41,8 +148,18 @@ pub fn post<'a,
         client.post(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
-pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+pub fn post_request<'a, B: AsRef<[u8]>>(req: &'a RequestParams, params: &'a Params, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let mut url_fmtd = String::with_capacity(base.len() + 9 + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_analyze");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Post, url_fmtd, req.headers.to_owned()).body(body)
+}
+pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params) -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
     let mut url_fmtd = String::with_capacity(base.len() + 9 + url_qry.len());
     url_fmtd.push_str(base);
@@ -51,10 +168,20 @@ pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams) -> Result<Respons
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
-pub fn get_index<'a>(client: &'a mut Client, req: &'a RequestParams,
+pub fn get_request<'a>(req: &'a RequestParams, params: &'a Params) -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let mut url_fmtd = String::with_capacity(base.len() + 9 + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_analyze");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
+pub fn get_index<'a>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params,
                  index: &'a str) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &encode_path_part(index);
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 9 + index.len() +
                                   url_qry.len());
@@ -66,3 +193,18 @@ pub fn get_index<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
+pub fn get_index_request<'a>(req: &'a RequestParams, params: &'a Params, index: &'a str)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &encode_path_part(index);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 9 + index.len() +
+                                  url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/_analyze");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
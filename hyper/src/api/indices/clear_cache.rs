@@ -3,19 +3,112 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
 use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::ListPart;
+
+/// Url parameters for the `indices.clear_cache` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// Whether to ignore if a wildcard indices expression resolves into no concrete indices. (This includes `_all` string or when no indices have been specified)
+    pub allow_no_indices: Option<bool>,
+    /// Whether to expand wildcard expression to concrete indices that are open, closed or both.
+    /// Defaults to `open`.
+    pub expand_wildcards: Option<ExpandWildcards>,
+    /// Clear field data
+    pub field_data: Option<bool>,
+    /// Clear field data
+    pub fielddata: Option<bool>,
+    /// A comma-separated list of fields to clear when using the `field_data` parameter (default: all)
+    pub fields: Option<Vec<String>>,
+    /// Whether specified concrete indices should be ignored when unavailable (missing or closed)
+    pub ignore_unavailable: Option<bool>,
+    /// A comma-separated list of index name to limit the operation
+    pub index: Option<Vec<String>>,
+    /// Clear query caches
+    pub query: Option<bool>,
+    /// Clear the recycler cache
+    pub recycler: Option<bool>,
+    /// Clear request cache
+    pub request: Option<bool>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref allow_no_indices) = self.allow_no_indices {
+            params.push(("allow_no_indices", allow_no_indices.to_string()));
+        }
+        if let Some(ref expand_wildcards) = self.expand_wildcards {
+            params.push(("expand_wildcards", expand_wildcards.as_str().to_owned()));
+        }
+        if let Some(ref field_data) = self.field_data {
+            params.push(("field_data", field_data.to_string()));
+        }
+        if let Some(ref fielddata) = self.fielddata {
+            params.push(("fielddata", fielddata.to_string()));
+        }
+        if let Some(ref fields) = self.fields {
+            params.push(("fields", fields.join(",")));
+        }
+        if let Some(ref ignore_unavailable) = self.ignore_unavailable {
+            params.push(("ignore_unavailable", ignore_unavailable.to_string()));
+        }
+        if let Some(ref index) = self.index {
+            params.push(("index", index.join(",")));
+        }
+        if let Some(ref query) = self.query {
+            params.push(("query", query.to_string()));
+        }
+        if let Some(ref recycler) = self.recycler {
+            params.push(("recycler", recycler.to_string()));
+        }
+        if let Some(ref request) = self.request {
+            params.push(("request", request.to_string()));
+        }
+        params
+    }
+}
+
+/// Options for the `expand_wildcards` url parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExpandWildcards {
+    /// `open`
+    Open,
+    /// `closed`
+    Closed,
+    /// `none`
+    None,
+    /// `all`
+    All,
+}
+
+impl ExpandWildcards {
+    /// Get the value of the option as it's sent in the url.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            ExpandWildcards::Open => "open",
+            ExpandWildcards::Closed => "closed",
+            ExpandWildcards::None => "none",
+            ExpandWildcards::All => "all",
+        }
+    }
+}
 
 pub fn post_index<'a,
-              I: Into<Body<'a>>>(client: &'a mut Client,
-                                 req: &'a RequestParams, index: &'a str,
-                                 body: I) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+                  Index: ListPart, I: Into<Body<'a>>>(client: &'a mut Client,
+                                                      req: &'a RequestParams,
+                                                      params: &'a Params, index: Index, body: I)
+ -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &index.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 13 + index.len() +
                                   url_qry.len());
@@ -28,8 +121,26 @@ pub fn post_index<'a,
         client.post(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
-pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+pub fn post_index_request<'a,
+                          Index: ListPart, B: AsRef<[u8]>>(req: &'a RequestParams,
+                                                           params: &'a Params, index: Index,
+                                                           body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &index.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 13 + index.len() +
+                                  url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/_cache/clear");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Post, url_fmtd, req.headers.to_owned()).body(body)
+}
+pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params) -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
     let mut url_fmtd = String::with_capacity(base.len() + 13 + url_qry.len());
     url_fmtd.push_str(base);
@@ -38,10 +149,19 @@ pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams) -> Result<Respons
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
+pub fn get_request<'a>(req: &'a RequestParams, params: &'a Params) -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let mut url_fmtd = String::with_capacity(base.len() + 13 + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_cache/clear");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
 pub fn post<'a,
-        I: Into<Body<'a>>>(client: &'a mut Client, req: &'a RequestParams,
+        I: Into<Body<'a>>>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params,
                            body: I) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
     let mut url_fmtd = String::with_capacity(base.len() + 13 + url_qry.len());
     url_fmtd.push_str(base);
@@ -51,10 +171,22 @@ pub fn post<'a,
         client.post(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
-pub fn get_index<'a>(client: &'a mut Client, req: &'a RequestParams,
-                 index: &'a str) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+pub fn post_request<'a, B: AsRef<[u8]>>(req: &'a RequestParams, params: &'a Params, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let mut url_fmtd = String::with_capacity(base.len() + 13 + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_cache/clear");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Post, url_fmtd, req.headers.to_owned()).body(body)
+}
+pub fn get_index<'a, Index: ListPart>(client: &'a mut Client, req: &'a RequestParams,
+                                      params: &'a Params, index: Index)
+ -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &index.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 13 + index.len() +
                                   url_qry.len());
@@ -66,3 +198,19 @@ pub fn get_index<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
+pub fn get_index_request<'a, Index: ListPart>(req: &'a RequestParams, params: &'a Params,
+                                              index: Index)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &index.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 13 + index.len() +
+                                  url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/_cache/clear");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
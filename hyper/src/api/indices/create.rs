@@ -3,19 +3,50 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
 use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::encode_path_part;
+
+/// Url parameters for the `indices.create` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// Specify timeout for connection to master
+    pub master_timeout: Option<String>,
+    /// Explicit operation timeout
+    pub timeout: Option<String>,
+    /// Whether to update the mapping for all fields with the same name across all types or not
+    pub update_all_types: Option<bool>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref master_timeout) = self.master_timeout {
+            params.push(("master_timeout", master_timeout.to_owned()));
+        }
+        if let Some(ref timeout) = self.timeout {
+            params.push(("timeout", timeout.to_owned()));
+        }
+        if let Some(ref update_all_types) = self.update_all_types {
+            params.push(("update_all_types", update_all_types.to_string()));
+        }
+        params
+    }
+}
 
 pub fn post_index<'a,
               I: Into<Body<'a>>>(client: &'a mut Client,
-                                 req: &'a RequestParams, index: &'a str,
+                                 req: &'a RequestParams, params: &'a Params, index: &'a str,
                                  body: I) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &encode_path_part(index);
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + index.len() + url_qry.len());
     url_fmtd.push_str(base);
@@ -26,12 +57,27 @@ pub fn post_index<'a,
         client.post(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
+pub fn post_index_request<'a, B: AsRef<[u8]>>(req: &'a RequestParams, params: &'a Params,
+                                              index: &'a str, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &encode_path_part(index);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + index.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Post, url_fmtd, req.headers.to_owned()).body(body)
+}
 pub fn put_index<'a,
              I: Into<Body<'a>>>(client: &'a mut Client,
-                                req: &'a RequestParams, index: &'a str,
+                                req: &'a RequestParams, params: &'a Params, index: &'a str,
                                 body: I) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &encode_path_part(index);
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + index.len() + url_qry.len());
     url_fmtd.push_str(base);
@@ -42,3 +88,17 @@ pub fn put_index<'a,
         client.put(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
+pub fn put_index_request<'a, B: AsRef<[u8]>>(req: &'a RequestParams, params: &'a Params,
+                                             index: &'a str, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &encode_path_part(index);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + index.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Put, url_fmtd, req.headers.to_owned()).body(body)
+}
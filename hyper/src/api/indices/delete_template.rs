@@ -3,17 +3,42 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
-use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::encode_path_part;
 
-pub fn delete_name<'a>(client: &'a mut Client, req: &'a RequestParams,
+/// Url parameters for the `indices.delete_template` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// Specify timeout for connection to master
+    pub master_timeout: Option<String>,
+    /// Explicit operation timeout
+    pub timeout: Option<String>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref master_timeout) = self.master_timeout {
+            params.push(("master_timeout", master_timeout.to_owned()));
+        }
+        if let Some(ref timeout) = self.timeout {
+            params.push(("timeout", timeout.to_owned()));
+        }
+        params
+    }
+}
+
+pub fn delete_name<'a>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params,
                    name: &'a str) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let name = &encode_path_part(name);
     let mut url_fmtd =
         String::with_capacity(base.len() + 11 + name.len() + url_qry.len());
     url_fmtd.push_str(base);
@@ -23,3 +48,16 @@ pub fn delete_name<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.delete(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
+pub fn delete_name_request<'a>(req: &'a RequestParams, params: &'a Params, name: &'a str)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let name = &encode_path_part(name);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 11 + name.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_template/");
+    url_fmtd.push_str(name);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Delete, url_fmtd, req.headers.to_owned())
+}
@@ -3,17 +3,83 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
-use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::ListPart;
 
-pub fn head_index_name<'a>(client: &'a mut Client, req: &'a RequestParams,
-                       index: &'a str, name: &'a str) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+/// Url parameters for the `indices.exists_alias` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// Whether to ignore if a wildcard indices expression resolves into no concrete indices. (This includes `_all` string or when no indices have been specified)
+    pub allow_no_indices: Option<bool>,
+    /// Whether to expand wildcard expression to concrete indices that are open, closed or both.
+    /// Defaults to `open,closed`.
+    pub expand_wildcards: Option<ExpandWildcards>,
+    /// Whether specified concrete indices should be ignored when unavailable (missing or closed)
+    pub ignore_unavailable: Option<bool>,
+    /// Return local information, do not retrieve the state from master node (default: false)
+    pub local: Option<bool>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref allow_no_indices) = self.allow_no_indices {
+            params.push(("allow_no_indices", allow_no_indices.to_string()));
+        }
+        if let Some(ref expand_wildcards) = self.expand_wildcards {
+            params.push(("expand_wildcards", expand_wildcards.as_str().to_owned()));
+        }
+        if let Some(ref ignore_unavailable) = self.ignore_unavailable {
+            params.push(("ignore_unavailable", ignore_unavailable.to_string()));
+        }
+        if let Some(ref local) = self.local {
+            params.push(("local", local.to_string()));
+        }
+        params
+    }
+}
+
+/// Options for the `expand_wildcards` url parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExpandWildcards {
+    /// `open`
+    Open,
+    /// `closed`
+    Closed,
+    /// `none`
+    None,
+    /// `all`
+    All,
+}
+
+impl ExpandWildcards {
+    /// Get the value of the option as it's sent in the url.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            ExpandWildcards::Open => "open",
+            ExpandWildcards::Closed => "closed",
+            ExpandWildcards::None => "none",
+            ExpandWildcards::All => "all",
+        }
+    }
+}
+
+pub fn head_index_name<'a,
+                       Index: ListPart, Name: ListPart>(client: &'a mut Client,
+                                                        req: &'a RequestParams,
+                                                        params: &'a Params, index: Index,
+                                                        name: Name)
+ -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &index.to_path_part();
+    let name = &name.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 8 + index.len() + name.len() +
                                   url_qry.len());
@@ -26,10 +92,32 @@ pub fn head_index_name<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.head(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
-pub fn head_index<'a>(client: &'a mut Client, req: &'a RequestParams,
-                  index: &'a str) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+pub fn head_index_name_request<'a,
+                               Index: ListPart, Name: ListPart>(req: &'a RequestParams,
+                                                                params: &'a Params,
+                                                                index: Index, name: Name)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &index.to_path_part();
+    let name = &name.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 8 + index.len() + name.len() +
+                                  url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/_alias/");
+    url_fmtd.push_str(name);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Head, url_fmtd, req.headers.to_owned())
+}
+pub fn head_index<'a, Index: ListPart>(client: &'a mut Client, req: &'a RequestParams,
+                                       params: &'a Params, index: Index)
+ -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &index.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 7 + index.len() +
                                   url_qry.len());
@@ -41,10 +129,28 @@ pub fn head_index<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.head(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
-pub fn head_name<'a>(client: &'a mut Client, req: &'a RequestParams,
-                 name: &'a str) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+pub fn head_index_request<'a, Index: ListPart>(req: &'a RequestParams, params: &'a Params,
+                                               index: Index)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &index.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 7 + index.len() +
+                                  url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/_alias");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Head, url_fmtd, req.headers.to_owned())
+}
+pub fn head_name<'a, Name: ListPart>(client: &'a mut Client, req: &'a RequestParams,
+                                     params: &'a Params, name: Name)
+ -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let name = &name.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 8 + name.len() + url_qry.len());
     url_fmtd.push_str(base);
@@ -54,3 +160,17 @@ pub fn head_name<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.head(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
+pub fn head_name_request<'a, Name: ListPart>(req: &'a RequestParams, params: &'a Params,
+                                             name: Name)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let name = &name.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 8 + name.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_alias/");
+    url_fmtd.push_str(name);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Head, url_fmtd, req.headers.to_owned())
+}
@@ -3,17 +3,96 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
-use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::ListPart;
 
-pub fn get_index<'a>(client: &'a mut Client, req: &'a RequestParams,
-                 index: &'a str) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+/// Url parameters for the `indices.get` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// Ignore if a wildcard expression resolves to no concrete indices (default: false)
+    pub allow_no_indices: Option<bool>,
+    /// Whether wildcard expressions should get expanded to open or closed indices (default: open)
+    /// Defaults to `open`.
+    pub expand_wildcards: Option<ExpandWildcards>,
+    /// Return settings in flat format (default: false)
+    pub flat_settings: Option<bool>,
+    /// Whether to return version and creation date values in human-readable format.
+    /// Defaults to `false`.
+    pub human: Option<bool>,
+    /// Ignore unavailable indexes (default: false)
+    pub ignore_unavailable: Option<bool>,
+    /// Whether to return all default setting for each of the indices.
+    /// Defaults to `false`.
+    pub include_defaults: Option<bool>,
+    /// Return local information, do not retrieve the state from master node (default: false)
+    pub local: Option<bool>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref allow_no_indices) = self.allow_no_indices {
+            params.push(("allow_no_indices", allow_no_indices.to_string()));
+        }
+        if let Some(ref expand_wildcards) = self.expand_wildcards {
+            params.push(("expand_wildcards", expand_wildcards.as_str().to_owned()));
+        }
+        if let Some(ref flat_settings) = self.flat_settings {
+            params.push(("flat_settings", flat_settings.to_string()));
+        }
+        if let Some(ref human) = self.human {
+            params.push(("human", human.to_string()));
+        }
+        if let Some(ref ignore_unavailable) = self.ignore_unavailable {
+            params.push(("ignore_unavailable", ignore_unavailable.to_string()));
+        }
+        if let Some(ref include_defaults) = self.include_defaults {
+            params.push(("include_defaults", include_defaults.to_string()));
+        }
+        if let Some(ref local) = self.local {
+            params.push(("local", local.to_string()));
+        }
+        params
+    }
+}
+
+/// Options for the `expand_wildcards` url parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExpandWildcards {
+    /// `open`
+    Open,
+    /// `closed`
+    Closed,
+    /// `none`
+    None,
+    /// `all`
+    All,
+}
+
+impl ExpandWildcards {
+    /// Get the value of the option as it's sent in the url.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            ExpandWildcards::Open => "open",
+            ExpandWildcards::Closed => "closed",
+            ExpandWildcards::None => "none",
+            ExpandWildcards::All => "all",
+        }
+    }
+}
+
+pub fn get_index<'a, Index: ListPart>(client: &'a mut Client, req: &'a RequestParams,
+                                      params: &'a Params, index: Index)
+ -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &index.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + index.len() + url_qry.len());
     url_fmtd.push_str(base);
@@ -23,10 +102,30 @@ pub fn get_index<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
-pub fn get_index_feature<'a>(client: &'a mut Client, req: &'a RequestParams,
-                         index: &'a str, feature: &'a str) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+pub fn get_index_request<'a, Index: ListPart>(req: &'a RequestParams, params: &'a Params,
+                                              index: Index)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &index.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + index.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
+pub fn get_index_feature<'a,
+                         Index: ListPart, Feature: ListPart>(client: &'a mut Client,
+                                                             req: &'a RequestParams,
+                                                             params: &'a Params, index: Index,
+                                                             feature: Feature)
+ -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &index.to_path_part();
+    let feature = &feature.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 1 + index.len() + feature.len()
                                   + url_qry.len());
@@ -39,3 +138,24 @@ pub fn get_index_feature<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
+pub fn get_index_feature_request<'a,
+                                 Index: ListPart, Feature: ListPart>(req: &'a RequestParams,
+                                                                     params: &'a Params,
+                                                                     index: Index,
+                                                                     feature: Feature)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &index.to_path_part();
+    let feature = &feature.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 1 + index.len() + feature.len()
+                                  + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(feature);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
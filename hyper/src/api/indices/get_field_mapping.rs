@@ -3,17 +3,84 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
-use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::ListPart;
 
-pub fn get_fields<'a>(client: &'a mut Client, req: &'a RequestParams,
-                  fields: &'a str) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+/// Url parameters for the `indices.get_field_mapping` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// Whether to ignore if a wildcard indices expression resolves into no concrete indices. (This includes `_all` string or when no indices have been specified)
+    pub allow_no_indices: Option<bool>,
+    /// Whether to expand wildcard expression to concrete indices that are open, closed or both.
+    /// Defaults to `open`.
+    pub expand_wildcards: Option<ExpandWildcards>,
+    /// Whether specified concrete indices should be ignored when unavailable (missing or closed)
+    pub ignore_unavailable: Option<bool>,
+    /// Whether the default mapping values should be returned as well
+    pub include_defaults: Option<bool>,
+    /// Return local information, do not retrieve the state from master node (default: false)
+    pub local: Option<bool>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref allow_no_indices) = self.allow_no_indices {
+            params.push(("allow_no_indices", allow_no_indices.to_string()));
+        }
+        if let Some(ref expand_wildcards) = self.expand_wildcards {
+            params.push(("expand_wildcards", expand_wildcards.as_str().to_owned()));
+        }
+        if let Some(ref ignore_unavailable) = self.ignore_unavailable {
+            params.push(("ignore_unavailable", ignore_unavailable.to_string()));
+        }
+        if let Some(ref include_defaults) = self.include_defaults {
+            params.push(("include_defaults", include_defaults.to_string()));
+        }
+        if let Some(ref local) = self.local {
+            params.push(("local", local.to_string()));
+        }
+        params
+    }
+}
+
+/// Options for the `expand_wildcards` url parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExpandWildcards {
+    /// `open`
+    Open,
+    /// `closed`
+    Closed,
+    /// `none`
+    None,
+    /// `all`
+    All,
+}
+
+impl ExpandWildcards {
+    /// Get the value of the option as it's sent in the url.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            ExpandWildcards::Open => "open",
+            ExpandWildcards::Closed => "closed",
+            ExpandWildcards::None => "none",
+            ExpandWildcards::All => "all",
+        }
+    }
+}
+
+pub fn get_fields<'a, Fields: ListPart>(client: &'a mut Client, req: &'a RequestParams,
+                                        params: &'a Params, fields: Fields)
+ -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let fields = &fields.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 16 + fields.len() + url_qry.len());
     url_fmtd.push_str(base);
@@ -23,11 +90,33 @@ pub fn get_fields<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
-pub fn get_index_type_fields<'a>(client: &'a mut Client, req: &'a RequestParams,
-                             index: &'a str, _type: &'a str, fields: &'a str)
+pub fn get_fields_request<'a, Fields: ListPart>(req: &'a RequestParams, params: &'a Params,
+                                                fields: Fields)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let fields = &fields.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 16 + fields.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_mapping/field/");
+    url_fmtd.push_str(fields);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
+pub fn get_index_type_fields<'a,
+                             Index: ListPart, Type: ListPart, Fields: ListPart>(client: &'a mut Client,
+                                                                                req: &'a RequestParams,
+                                                                                params: &'a Params,
+                                                                                index: Index,
+                                                                                _type: Type,
+                                                                                fields: Fields)
  -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &index.to_path_part();
+    let _type = &_type.to_path_part();
+    let fields = &fields.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 10 + 7 + index.len() +
                                   _type.len() + fields.len() + url_qry.len());
@@ -42,10 +131,41 @@ pub fn get_index_type_fields<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
-pub fn get_type_fields<'a>(client: &'a mut Client, req: &'a RequestParams,
-                       _type: &'a str, fields: &'a str) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+pub fn get_index_type_fields_request<'a,
+                                     Index: ListPart, Type: ListPart, Fields: ListPart>(req: &'a RequestParams,
+                                                                                        params: &'a Params,
+                                                                                        index: Index,
+                                                                                        _type: Type,
+                                                                                        fields: Fields)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &index.to_path_part();
+    let _type = &_type.to_path_part();
+    let fields = &fields.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 10 + 7 + index.len() +
+                                  _type.len() + fields.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/_mapping/");
+    url_fmtd.push_str(_type);
+    url_fmtd.push_str("/field/");
+    url_fmtd.push_str(fields);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
+pub fn get_type_fields<'a,
+                       Type: ListPart, Fields: ListPart>(client: &'a mut Client,
+                                                         req: &'a RequestParams,
+                                                         params: &'a Params, _type: Type,
+                                                         fields: Fields)
+ -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let _type = &_type.to_path_part();
+    let fields = &fields.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 10 + 7 + _type.len() + fields.len()
                                   + url_qry.len());
@@ -58,10 +178,36 @@ pub fn get_type_fields<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
-pub fn get_index_fields<'a>(client: &'a mut Client, req: &'a RequestParams,
-                        index: &'a str, fields: &'a str) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+pub fn get_type_fields_request<'a,
+                               Type: ListPart, Fields: ListPart>(req: &'a RequestParams,
+                                                                 params: &'a Params,
+                                                                 _type: Type, fields: Fields)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let _type = &_type.to_path_part();
+    let fields = &fields.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 10 + 7 + _type.len() + fields.len()
+                                  + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_mapping/");
+    url_fmtd.push_str(_type);
+    url_fmtd.push_str("/field/");
+    url_fmtd.push_str(fields);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
+pub fn get_index_fields<'a,
+                        Index: ListPart, Fields: ListPart>(client: &'a mut Client,
+                                                           req: &'a RequestParams,
+                                                           params: &'a Params, index: Index,
+                                                           fields: Fields)
+ -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &index.to_path_part();
+    let fields = &fields.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 16 + index.len() + fields.len()
                                   + url_qry.len());
@@ -74,3 +220,23 @@ pub fn get_index_fields<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
+pub fn get_index_fields_request<'a,
+                                Index: ListPart, Fields: ListPart>(req: &'a RequestParams,
+                                                                   params: &'a Params,
+                                                                   index: Index, fields: Fields)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &index.to_path_part();
+    let fields = &fields.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 16 + index.len() + fields.len()
+                                  + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/_mapping/field/");
+    url_fmtd.push_str(fields);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
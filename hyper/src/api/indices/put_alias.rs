@@ -3,20 +3,49 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
 use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::ListPart;
+use ::encode_path_part;
+
+/// Url parameters for the `indices.put_alias` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// Specify timeout for connection to master
+    pub master_timeout: Option<String>,
+    /// Explicit timestamp for the document
+    pub timeout: Option<String>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref master_timeout) = self.master_timeout {
+            params.push(("master_timeout", master_timeout.to_owned()));
+        }
+        if let Some(ref timeout) = self.timeout {
+            params.push(("timeout", timeout.to_owned()));
+        }
+        params
+    }
+}
 
 pub fn post_index_name<'a,
-                   I: Into<Body<'a>>>(client: &'a mut Client,
-                                      req: &'a RequestParams, index: &'a str,
-                                      name: &'a str, body: I)
+                       Index: ListPart, I: Into<Body<'a>>>(client: &'a mut Client,
+                                                           req: &'a RequestParams,
+                                                           params: &'a Params, index: Index,
+                                                           name: &'a str, body: I)
  -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &index.to_path_part();
+    let name = &encode_path_part(name);
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 10 + index.len() + name.len() +
                                   url_qry.len());
@@ -30,13 +59,37 @@ pub fn post_index_name<'a,
         client.post(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
+pub fn post_index_name_request<'a,
+                               Index: ListPart, B: AsRef<[u8]>>(req: &'a RequestParams,
+                                                                params: &'a Params,
+                                                                index: Index, name: &'a str,
+                                                                body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &index.to_path_part();
+    let name = &encode_path_part(name);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 10 + index.len() + name.len() +
+                                  url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/_aliases/");
+    url_fmtd.push_str(name);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Post, url_fmtd, req.headers.to_owned()).body(body)
+}
 pub fn put_index_name<'a,
-                  I: Into<Body<'a>>>(client: &'a mut Client,
-                                     req: &'a RequestParams, index: &'a str,
-                                     name: &'a str, body: I)
+                      Index: ListPart, I: Into<Body<'a>>>(client: &'a mut Client,
+                                                          req: &'a RequestParams,
+                                                          params: &'a Params, index: Index,
+                                                          name: &'a str, body: I)
  -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &index.to_path_part();
+    let name = &encode_path_part(name);
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 10 + index.len() + name.len() +
                                   url_qry.len());
@@ -50,3 +103,23 @@ pub fn put_index_name<'a,
         client.put(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
+pub fn put_index_name_request<'a,
+                              Index: ListPart, B: AsRef<[u8]>>(req: &'a RequestParams,
+                                                               params: &'a Params, index: Index,
+                                                               name: &'a str, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &index.to_path_part();
+    let name = &encode_path_part(name);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 10 + index.len() + name.len() +
+                                  url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/_aliases/");
+    url_fmtd.push_str(name);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Put, url_fmtd, req.headers.to_owned()).body(body)
+}
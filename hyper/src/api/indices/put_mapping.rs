@@ -3,20 +3,95 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
 use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::ListPart;
+use ::encode_path_part;
+
+/// Url parameters for the `indices.put_mapping` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// Whether to ignore if a wildcard indices expression resolves into no concrete indices. (This includes `_all` string or when no indices have been specified)
+    pub allow_no_indices: Option<bool>,
+    /// Whether to expand wildcard expression to concrete indices that are open, closed or both.
+    /// Defaults to `open`.
+    pub expand_wildcards: Option<ExpandWildcards>,
+    /// Whether specified concrete indices should be ignored when unavailable (missing or closed)
+    pub ignore_unavailable: Option<bool>,
+    /// Specify timeout for connection to master
+    pub master_timeout: Option<String>,
+    /// Explicit operation timeout
+    pub timeout: Option<String>,
+    /// Whether to update the mapping for all fields with the same name across all types or not
+    pub update_all_types: Option<bool>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref allow_no_indices) = self.allow_no_indices {
+            params.push(("allow_no_indices", allow_no_indices.to_string()));
+        }
+        if let Some(ref expand_wildcards) = self.expand_wildcards {
+            params.push(("expand_wildcards", expand_wildcards.as_str().to_owned()));
+        }
+        if let Some(ref ignore_unavailable) = self.ignore_unavailable {
+            params.push(("ignore_unavailable", ignore_unavailable.to_string()));
+        }
+        if let Some(ref master_timeout) = self.master_timeout {
+            params.push(("master_timeout", master_timeout.to_owned()));
+        }
+        if let Some(ref timeout) = self.timeout {
+            params.push(("timeout", timeout.to_owned()));
+        }
+        if let Some(ref update_all_types) = self.update_all_types {
+            params.push(("update_all_types", update_all_types.to_string()));
+        }
+        params
+    }
+}
+
+/// Options for the `expand_wildcards` url parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExpandWildcards {
+    /// `open`
+    Open,
+    /// `closed`
+    Closed,
+    /// `none`
+    None,
+    /// `all`
+    All,
+}
+
+impl ExpandWildcards {
+    /// Get the value of the option as it's sent in the url.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            ExpandWildcards::Open => "open",
+            ExpandWildcards::Closed => "closed",
+            ExpandWildcards::None => "none",
+            ExpandWildcards::All => "all",
+        }
+    }
+}
 
 pub fn put_index_type<'a,
-                  I: Into<Body<'a>>>(client: &'a mut Client,
-                                     req: &'a RequestParams, index: &'a str,
-                                     _type: &'a str, body: I)
+                      Index: ListPart, I: Into<Body<'a>>>(client: &'a mut Client,
+                                                          req: &'a RequestParams,
+                                                          params: &'a Params, index: Index,
+                                                          _type: &'a str, body: I)
  -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &index.to_path_part();
+    let _type = &encode_path_part(_type);
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 11 + index.len() + _type.len()
                                   + url_qry.len());
@@ -30,11 +105,32 @@ pub fn put_index_type<'a,
         client.put(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
+pub fn put_index_type_request<'a,
+                              Index: ListPart, B: AsRef<[u8]>>(req: &'a RequestParams,
+                                                               params: &'a Params, index: Index,
+                                                               _type: &'a str, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &index.to_path_part();
+    let _type = &encode_path_part(_type);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 11 + index.len() + _type.len()
+                                  + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/_mappings/");
+    url_fmtd.push_str(_type);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Put, url_fmtd, req.headers.to_owned()).body(body)
+}
 pub fn put_type<'a,
-            I: Into<Body<'a>>>(client: &'a mut Client, req: &'a RequestParams,
+            I: Into<Body<'a>>>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params,
                                _type: &'a str, body: I) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let _type = &encode_path_part(_type);
     let mut url_fmtd =
         String::with_capacity(base.len() + 11 + _type.len() + url_qry.len());
     url_fmtd.push_str(base);
@@ -45,13 +141,30 @@ pub fn put_type<'a,
         client.put(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
+pub fn put_type_request<'a, B: AsRef<[u8]>>(req: &'a RequestParams, params: &'a Params,
+                                            _type: &'a str, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let _type = &encode_path_part(_type);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 11 + _type.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_mappings/");
+    url_fmtd.push_str(_type);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Put, url_fmtd, req.headers.to_owned()).body(body)
+}
 pub fn post_index_type<'a,
-                   I: Into<Body<'a>>>(client: &'a mut Client,
-                                      req: &'a RequestParams, index: &'a str,
-                                      _type: &'a str, body: I)
+                       Index: ListPart, I: Into<Body<'a>>>(client: &'a mut Client,
+                                                           req: &'a RequestParams,
+                                                           params: &'a Params, index: Index,
+                                                           _type: &'a str, body: I)
  -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &index.to_path_part();
+    let _type = &encode_path_part(_type);
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 11 + index.len() + _type.len()
                                   + url_qry.len());
@@ -65,12 +178,34 @@ pub fn post_index_type<'a,
         client.post(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
+pub fn post_index_type_request<'a,
+                               Index: ListPart, B: AsRef<[u8]>>(req: &'a RequestParams,
+                                                                params: &'a Params,
+                                                                index: Index, _type: &'a str,
+                                                                body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &index.to_path_part();
+    let _type = &encode_path_part(_type);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 11 + index.len() + _type.len()
+                                  + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/_mappings/");
+    url_fmtd.push_str(_type);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Post, url_fmtd, req.headers.to_owned()).body(body)
+}
 pub fn post_type<'a,
              I: Into<Body<'a>>>(client: &'a mut Client,
-                                req: &'a RequestParams, _type: &'a str,
+                                req: &'a RequestParams, params: &'a Params, _type: &'a str,
                                 body: I) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let _type = &encode_path_part(_type);
     let mut url_fmtd =
         String::with_capacity(base.len() + 11 + _type.len() + url_qry.len());
     url_fmtd.push_str(base);
@@ -81,3 +216,17 @@ pub fn post_type<'a,
         client.post(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
+pub fn post_type_request<'a, B: AsRef<[u8]>>(req: &'a RequestParams, params: &'a Params,
+                                             _type: &'a str, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let _type = &encode_path_part(_type);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 11 + _type.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_mappings/");
+    url_fmtd.push_str(_type);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Post, url_fmtd, req.headers.to_owned()).body(body)
+}
@@ -3,18 +3,60 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
 use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::encode_path_part;
+
+/// Url parameters for the `indices.put_template` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// Whether the index template should only be added if new or can also replace an existing one
+    /// Defaults to `false`.
+    pub create: Option<bool>,
+    /// Return settings in flat format (default: false)
+    pub flat_settings: Option<bool>,
+    /// Specify timeout for connection to master
+    pub master_timeout: Option<String>,
+    /// The order for this template when merging multiple matching ones (higher numbers are merged later, overriding the lower numbers)
+    pub order: Option<i64>,
+    /// Explicit operation timeout
+    pub timeout: Option<String>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref create) = self.create {
+            params.push(("create", create.to_string()));
+        }
+        if let Some(ref flat_settings) = self.flat_settings {
+            params.push(("flat_settings", flat_settings.to_string()));
+        }
+        if let Some(ref master_timeout) = self.master_timeout {
+            params.push(("master_timeout", master_timeout.to_owned()));
+        }
+        if let Some(ref order) = self.order {
+            params.push(("order", order.to_string()));
+        }
+        if let Some(ref timeout) = self.timeout {
+            params.push(("timeout", timeout.to_owned()));
+        }
+        params
+    }
+}
 
 pub fn put_name<'a,
-            I: Into<Body<'a>>>(client: &'a mut Client, req: &'a RequestParams,
+            I: Into<Body<'a>>>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params,
                                name: &'a str, body: I) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let name = &encode_path_part(name);
     let mut url_fmtd =
         String::with_capacity(base.len() + 11 + name.len() + url_qry.len());
     url_fmtd.push_str(base);
@@ -25,12 +67,27 @@ pub fn put_name<'a,
         client.put(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
+pub fn put_name_request<'a, B: AsRef<[u8]>>(req: &'a RequestParams, params: &'a Params,
+                                            name: &'a str, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let name = &encode_path_part(name);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 11 + name.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_template/");
+    url_fmtd.push_str(name);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Put, url_fmtd, req.headers.to_owned()).body(body)
+}
 pub fn post_name<'a,
              I: Into<Body<'a>>>(client: &'a mut Client,
-                                req: &'a RequestParams, name: &'a str,
+                                req: &'a RequestParams, params: &'a Params, name: &'a str,
                                 body: I) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let name = &encode_path_part(name);
     let mut url_fmtd =
         String::with_capacity(base.len() + 11 + name.len() + url_qry.len());
     url_fmtd.push_str(base);
@@ -41,3 +98,17 @@ pub fn post_name<'a,
         client.post(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
+pub fn post_name_request<'a, B: AsRef<[u8]>>(req: &'a RequestParams, params: &'a Params,
+                                             name: &'a str, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let name = &encode_path_part(name);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 11 + name.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_template/");
+    url_fmtd.push_str(name);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Post, url_fmtd, req.headers.to_owned()).body(body)
+}
@@ -3,17 +3,92 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
-use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::ListPart;
 
-pub fn get_index<'a>(client: &'a mut Client, req: &'a RequestParams,
-                 index: &'a str) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+/// Url parameters for the `indices.stats` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// A comma-separated list of fields for `fielddata` and `suggest` index metric (supports wildcards)
+    pub completion_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields for `fielddata` index metric (supports wildcards)
+    pub fielddata_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields for `fielddata` and `completion` index metric (supports wildcards)
+    pub fields: Option<Vec<String>>,
+    /// A comma-separated list of search groups for `search` index metric
+    pub groups: Option<Vec<String>>,
+    /// Whether to return time and byte values in human-readable format.
+    /// Defaults to `false`.
+    pub human: Option<bool>,
+    /// Return stats aggregated at cluster, index or shard level
+    /// Defaults to `indices`.
+    pub level: Option<Level>,
+    /// A comma-separated list of document types for the `indexing` index metric
+    pub types: Option<Vec<String>>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref completion_fields) = self.completion_fields {
+            params.push(("completion_fields", completion_fields.join(",")));
+        }
+        if let Some(ref fielddata_fields) = self.fielddata_fields {
+            params.push(("fielddata_fields", fielddata_fields.join(",")));
+        }
+        if let Some(ref fields) = self.fields {
+            params.push(("fields", fields.join(",")));
+        }
+        if let Some(ref groups) = self.groups {
+            params.push(("groups", groups.join(",")));
+        }
+        if let Some(ref human) = self.human {
+            params.push(("human", human.to_string()));
+        }
+        if let Some(ref level) = self.level {
+            params.push(("level", level.as_str().to_owned()));
+        }
+        if let Some(ref types) = self.types {
+            params.push(("types", types.join(",")));
+        }
+        params
+    }
+}
+
+/// Options for the `level` url parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Level {
+    /// `cluster`
+    Cluster,
+    /// `indices`
+    Indices,
+    /// `shards`
+    Shards,
+}
+
+impl Level {
+    /// Get the value of the option as it's sent in the url.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Level::Cluster => "cluster",
+            Level::Indices => "indices",
+            Level::Shards => "shards",
+        }
+    }
+}
+
+pub fn get_index<'a, Index: ListPart>(client: &'a mut Client, req: &'a RequestParams,
+                                      params: &'a Params, index: Index)
+ -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &index.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 7 + index.len() +
                                   url_qry.len());
@@ -25,8 +100,24 @@ pub fn get_index<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
-pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+pub fn get_index_request<'a, Index: ListPart>(req: &'a RequestParams, params: &'a Params,
+                                              index: Index)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &index.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 7 + index.len() +
+                                  url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/_stats");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
+pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params) -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
     let mut url_fmtd = String::with_capacity(base.len() + 7 + url_qry.len());
     url_fmtd.push_str(base);
@@ -35,10 +126,21 @@ pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams) -> Result<Respons
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
-pub fn get_metric<'a>(client: &'a mut Client, req: &'a RequestParams,
-                  metric: &'a str) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+pub fn get_request<'a>(req: &'a RequestParams, params: &'a Params) -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let mut url_fmtd = String::with_capacity(base.len() + 7 + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_stats");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
+pub fn get_metric<'a, Metric: ListPart>(client: &'a mut Client, req: &'a RequestParams,
+                                        params: &'a Params, metric: Metric)
+ -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let metric = &metric.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 8 + metric.len() + url_qry.len());
     url_fmtd.push_str(base);
@@ -48,10 +150,30 @@ pub fn get_metric<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
-pub fn get_index_metric<'a>(client: &'a mut Client, req: &'a RequestParams,
-                        index: &'a str, metric: &'a str) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+pub fn get_metric_request<'a, Metric: ListPart>(req: &'a RequestParams, params: &'a Params,
+                                                metric: Metric)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let metric = &metric.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 8 + metric.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_stats/");
+    url_fmtd.push_str(metric);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
+pub fn get_index_metric<'a,
+                        Index: ListPart, Metric: ListPart>(client: &'a mut Client,
+                                                           req: &'a RequestParams,
+                                                           params: &'a Params, index: Index,
+                                                           metric: Metric)
+ -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &index.to_path_part();
+    let metric = &metric.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 8 + index.len() + metric.len()
                                   + url_qry.len());
@@ -64,3 +186,23 @@ pub fn get_index_metric<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
+pub fn get_index_metric_request<'a,
+                                Index: ListPart, Metric: ListPart>(req: &'a RequestParams,
+                                                                   params: &'a Params,
+                                                                   index: Index, metric: Metric)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &index.to_path_part();
+    let metric = &metric.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 8 + index.len() + metric.len()
+                                  + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/_stats/");
+    url_fmtd.push_str(metric);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
@@ -3,17 +3,145 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
 use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::ListPart;
 
-pub fn get_index<'a>(client: &'a mut Client, req: &'a RequestParams,
-                 index: &'a str) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+/// Url parameters for the `indices.validate_query` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// Whether to ignore if a wildcard indices expression resolves into no concrete indices. (This includes `_all` string or when no indices have been specified)
+    pub allow_no_indices: Option<bool>,
+    /// Specify whether wildcard and prefix queries should be analyzed (default: false)
+    pub analyze_wildcard: Option<bool>,
+    /// The analyzer to use for the query string
+    pub analyzer: Option<String>,
+    /// The default operator for query string query (AND or OR)
+    /// Defaults to `OR`.
+    pub default_operator: Option<DefaultOperator>,
+    /// The field to use as default where no field prefix is given in the query string
+    pub df: Option<String>,
+    /// Whether to expand wildcard expression to concrete indices that are open, closed or both.
+    /// Defaults to `open`.
+    pub expand_wildcards: Option<ExpandWildcards>,
+    /// Return detailed information about the error
+    pub explain: Option<bool>,
+    /// Whether specified concrete indices should be ignored when unavailable (missing or closed)
+    pub ignore_unavailable: Option<bool>,
+    /// Specify whether format-based query failures (such as providing text to a numeric field) should be ignored
+    pub lenient: Option<bool>,
+    /// Specify whether query terms should be lowercased
+    pub lowercase_expanded_terms: Option<bool>,
+    /// TODO: ?
+    pub operation_threading: Option<String>,
+    /// Query in the Lucene query string syntax
+    pub q: Option<String>,
+    /// Provide a more detailed explanation showing the actual Lucene query that will be executed.
+    pub rewrite: Option<bool>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref allow_no_indices) = self.allow_no_indices {
+            params.push(("allow_no_indices", allow_no_indices.to_string()));
+        }
+        if let Some(ref analyze_wildcard) = self.analyze_wildcard {
+            params.push(("analyze_wildcard", analyze_wildcard.to_string()));
+        }
+        if let Some(ref analyzer) = self.analyzer {
+            params.push(("analyzer", analyzer.to_owned()));
+        }
+        if let Some(ref default_operator) = self.default_operator {
+            params.push(("default_operator", default_operator.as_str().to_owned()));
+        }
+        if let Some(ref df) = self.df {
+            params.push(("df", df.to_owned()));
+        }
+        if let Some(ref expand_wildcards) = self.expand_wildcards {
+            params.push(("expand_wildcards", expand_wildcards.as_str().to_owned()));
+        }
+        if let Some(ref explain) = self.explain {
+            params.push(("explain", explain.to_string()));
+        }
+        if let Some(ref ignore_unavailable) = self.ignore_unavailable {
+            params.push(("ignore_unavailable", ignore_unavailable.to_string()));
+        }
+        if let Some(ref lenient) = self.lenient {
+            params.push(("lenient", lenient.to_string()));
+        }
+        if let Some(ref lowercase_expanded_terms) = self.lowercase_expanded_terms {
+            params.push(("lowercase_expanded_terms", lowercase_expanded_terms.to_string()));
+        }
+        if let Some(ref operation_threading) = self.operation_threading {
+            params.push(("operation_threading", operation_threading.to_owned()));
+        }
+        if let Some(ref q) = self.q {
+            params.push(("q", q.to_owned()));
+        }
+        if let Some(ref rewrite) = self.rewrite {
+            params.push(("rewrite", rewrite.to_string()));
+        }
+        params
+    }
+}
+
+/// Options for the `default_operator` url parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DefaultOperator {
+    /// `AND`
+    And,
+    /// `OR`
+    Or,
+}
+
+impl DefaultOperator {
+    /// Get the value of the option as it's sent in the url.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            DefaultOperator::And => "AND",
+            DefaultOperator::Or => "OR",
+        }
+    }
+}
+
+/// Options for the `expand_wildcards` url parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExpandWildcards {
+    /// `open`
+    Open,
+    /// `closed`
+    Closed,
+    /// `none`
+    None,
+    /// `all`
+    All,
+}
+
+impl ExpandWildcards {
+    /// Get the value of the option as it's sent in the url.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            ExpandWildcards::Open => "open",
+            ExpandWildcards::Closed => "closed",
+            ExpandWildcards::None => "none",
+            ExpandWildcards::All => "all",
+        }
+    }
+}
+
+pub fn get_index<'a, Index: ListPart>(client: &'a mut Client, req: &'a RequestParams,
+                                      params: &'a Params, index: Index)
+ -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &index.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 16 + index.len() +
                                   url_qry.len());
@@ -25,13 +153,33 @@ pub fn get_index<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
+pub fn get_index_request<'a, Index: ListPart>(req: &'a RequestParams, params: &'a Params,
+                                              index: Index)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &index.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 16 + index.len() +
+                                  url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/_validate/query");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
 pub fn post_index_type<'a,
-                   I: Into<Body<'a>>>(client: &'a mut Client,
-                                      req: &'a RequestParams, index: &'a str,
-                                      _type: &'a str, body: I)
+                       Index: ListPart, Type: ListPart, I: Into<Body<'a>>>(client: &'a mut Client,
+                                                                           req: &'a RequestParams,
+                                                                           params: &'a Params,
+                                                                           index: Index,
+                                                                           _type: Type, body: I)
  -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &index.to_path_part();
+    let _type = &_type.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 1 + 16 + index.len() +
                                   _type.len() + url_qry.len());
@@ -46,8 +194,31 @@ pub fn post_index_type<'a,
         client.post(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
-pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+pub fn post_index_type_request<'a,
+                               Index: ListPart, Type: ListPart, B: AsRef<[u8]>>(req: &'a RequestParams,
+                                                                                params: &'a Params,
+                                                                                index: Index,
+                                                                                _type: Type,
+                                                                                body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &index.to_path_part();
+    let _type = &_type.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 1 + 16 + index.len() +
+                                  _type.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(_type);
+    url_fmtd.push_str("/_validate/query");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Post, url_fmtd, req.headers.to_owned()).body(body)
+}
+pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params) -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
     let mut url_fmtd = String::with_capacity(base.len() + 16 + url_qry.len());
     url_fmtd.push_str(base);
@@ -56,10 +227,19 @@ pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams) -> Result<Respons
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
+pub fn get_request<'a>(req: &'a RequestParams, params: &'a Params) -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let mut url_fmtd = String::with_capacity(base.len() + 16 + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_validate/query");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
 pub fn post<'a,
-        I: Into<Body<'a>>>(client: &'a mut Client, req: &'a RequestParams,
+        I: Into<Body<'a>>>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params,
                            body: I) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
     let mut url_fmtd = String::with_capacity(base.len() + 16 + url_qry.len());
     url_fmtd.push_str(base);
@@ -69,12 +249,24 @@ pub fn post<'a,
         client.post(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
+pub fn post_request<'a, B: AsRef<[u8]>>(req: &'a RequestParams, params: &'a Params, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let mut url_fmtd = String::with_capacity(base.len() + 16 + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_validate/query");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Post, url_fmtd, req.headers.to_owned()).body(body)
+}
 pub fn post_index<'a,
-              I: Into<Body<'a>>>(client: &'a mut Client,
-                                 req: &'a RequestParams, index: &'a str,
-                                 body: I) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+                  Index: ListPart, I: Into<Body<'a>>>(client: &'a mut Client,
+                                                      req: &'a RequestParams,
+                                                      params: &'a Params, index: Index, body: I)
+ -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &index.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 16 + index.len() +
                                   url_qry.len());
@@ -87,10 +279,34 @@ pub fn post_index<'a,
         client.post(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
-pub fn get_index_type<'a>(client: &'a mut Client, req: &'a RequestParams,
-                      index: &'a str, _type: &'a str) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+pub fn post_index_request<'a,
+                          Index: ListPart, B: AsRef<[u8]>>(req: &'a RequestParams,
+                                                           params: &'a Params, index: Index,
+                                                           body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &index.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 16 + index.len() +
+                                  url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/_validate/query");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Post, url_fmtd, req.headers.to_owned()).body(body)
+}
+pub fn get_index_type<'a,
+                      Index: ListPart, Type: ListPart>(client: &'a mut Client,
+                                                       req: &'a RequestParams,
+                                                       params: &'a Params, index: Index,
+                                                       _type: Type)
+ -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &index.to_path_part();
+    let _type = &_type.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 1 + 16 + index.len() +
                                   _type.len() + url_qry.len());
@@ -104,3 +320,24 @@ pub fn get_index_type<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
+pub fn get_index_type_request<'a,
+                              Index: ListPart, Type: ListPart>(req: &'a RequestParams,
+                                                               params: &'a Params, index: Index,
+                                                               _type: Type)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &index.to_path_part();
+    let _type = &_type.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 1 + 16 + index.len() +
+                                  _type.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(_type);
+    url_fmtd.push_str("/_validate/query");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
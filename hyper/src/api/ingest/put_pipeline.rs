@@ -3,18 +3,44 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
 use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::encode_path_part;
+
+/// Url parameters for the `ingest.put_pipeline` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// Explicit operation timeout for connection to master node
+    pub master_timeout: Option<String>,
+    /// Explicit operation timeout
+    pub timeout: Option<String>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref master_timeout) = self.master_timeout {
+            params.push(("master_timeout", master_timeout.to_owned()));
+        }
+        if let Some(ref timeout) = self.timeout {
+            params.push(("timeout", timeout.to_owned()));
+        }
+        params
+    }
+}
 
 pub fn put_id<'a,
-          I: Into<Body<'a>>>(client: &'a mut Client, req: &'a RequestParams,
+          I: Into<Body<'a>>>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params,
                              id: &'a str, body: I) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let id = &encode_path_part(id);
     let mut url_fmtd =
         String::with_capacity(base.len() + 18 + id.len() + url_qry.len());
     url_fmtd.push_str(base);
@@ -25,3 +51,17 @@ pub fn put_id<'a,
         client.put(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
+pub fn put_id_request<'a, B: AsRef<[u8]>>(req: &'a RequestParams, params: &'a Params,
+                                          id: &'a str, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let id = &encode_path_part(id);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 18 + id.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_ingest/pipeline/");
+    url_fmtd.push_str(id);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Put, url_fmtd, req.headers.to_owned()).body(body)
+}
@@ -3,19 +3,70 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
 use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::encode_path_part;
+
+/// Url parameters for the `mget` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// True or false to return the _source field or not, or a list of fields to return
+    pub _source: Option<Vec<String>>,
+    /// A list of fields to exclude from the returned _source field
+    pub _source_exclude: Option<Vec<String>>,
+    /// A list of fields to extract and return from the _source field
+    pub _source_include: Option<Vec<String>>,
+    /// A comma-separated list of fields to return in the response
+    pub fields: Option<Vec<String>>,
+    /// Specify the node or shard the operation should be performed on (default: random)
+    pub preference: Option<String>,
+    /// Specify whether to perform the operation in realtime or search mode
+    pub realtime: Option<bool>,
+    /// Refresh the shard containing the document before performing the operation
+    pub refresh: Option<bool>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref _source) = self._source {
+            params.push(("_source", _source.join(",")));
+        }
+        if let Some(ref _source_exclude) = self._source_exclude {
+            params.push(("_source_exclude", _source_exclude.join(",")));
+        }
+        if let Some(ref _source_include) = self._source_include {
+            params.push(("_source_include", _source_include.join(",")));
+        }
+        if let Some(ref fields) = self.fields {
+            params.push(("fields", fields.join(",")));
+        }
+        if let Some(ref preference) = self.preference {
+            params.push(("preference", preference.to_owned()));
+        }
+        if let Some(ref realtime) = self.realtime {
+            params.push(("realtime", realtime.to_string()));
+        }
+        if let Some(ref refresh) = self.refresh {
+            params.push(("refresh", refresh.to_string()));
+        }
+        params
+    }
+}
 
 pub fn post_index<'a,
               I: Into<Body<'a>>>(client: &'a mut Client,
-                                 req: &'a RequestParams, index: &'a str,
+                                 req: &'a RequestParams, params: &'a Params, index: &'a str,
                                  body: I) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &encode_path_part(index);
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 6 + index.len() +
                                   url_qry.len());
@@ -28,13 +79,31 @@ pub fn post_index<'a,
         client.post(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
+pub fn post_index_request<'a, B: AsRef<[u8]>>(req: &'a RequestParams, params: &'a Params,
+                                              index: &'a str, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &encode_path_part(index);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 6 + index.len() +
+                                  url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/_mget");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Post, url_fmtd, req.headers.to_owned()).body(body)
+}
 pub fn post_index_type<'a,
                    I: Into<Body<'a>>>(client: &'a mut Client,
-                                      req: &'a RequestParams, index: &'a str,
+                                      req: &'a RequestParams, params: &'a Params, index: &'a str,
                                       _type: &'a str, body: I)
  -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &encode_path_part(index);
+    let _type = &encode_path_part(_type);
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 1 + 6 + index.len() +
                                   _type.len() + url_qry.len());
@@ -49,10 +118,30 @@ pub fn post_index_type<'a,
         client.post(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
-pub fn get_index<'a>(client: &'a mut Client, req: &'a RequestParams,
+pub fn post_index_type_request<'a, B: AsRef<[u8]>>(req: &'a RequestParams, params: &'a Params,
+                                                   index: &'a str, _type: &'a str, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &encode_path_part(index);
+    let _type = &encode_path_part(_type);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 1 + 6 + index.len() +
+                                  _type.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(_type);
+    url_fmtd.push_str("/_mget");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Post, url_fmtd, req.headers.to_owned()).body(body)
+}
+pub fn get_index<'a>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params,
                  index: &'a str) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &encode_path_part(index);
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 6 + index.len() +
                                   url_qry.len());
@@ -64,10 +153,25 @@ pub fn get_index<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
+pub fn get_index_request<'a>(req: &'a RequestParams, params: &'a Params, index: &'a str)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &encode_path_part(index);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 6 + index.len() +
+                                  url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/_mget");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
 pub fn post<'a,
-        I: Into<Body<'a>>>(client: &'a mut Client, req: &'a RequestParams,
+        I: Into<Body<'a>>>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params,
                            body: I) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
     let mut url_fmtd = String::with_capacity(base.len() + 6 + url_qry.len());
     url_fmtd.push_str(base);
@@ -77,10 +181,22 @@ pub fn post<'a,
         client.post(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
-pub fn get_index_type<'a>(client: &'a mut Client, req: &'a RequestParams,
+pub fn post_request<'a, B: AsRef<[u8]>>(req: &'a RequestParams, params: &'a Params, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let mut url_fmtd = String::with_capacity(base.len() + 6 + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_mget");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Post, url_fmtd, req.headers.to_owned()).body(body)
+}
+pub fn get_index_type<'a>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params,
                       index: &'a str, _type: &'a str) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &encode_path_part(index);
+    let _type = &encode_path_part(_type);
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 1 + 6 + index.len() +
                                   _type.len() + url_qry.len());
@@ -94,8 +210,27 @@ pub fn get_index_type<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
-pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+pub fn get_index_type_request<'a>(req: &'a RequestParams, params: &'a Params, index: &'a str,
+                                  _type: &'a str)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &encode_path_part(index);
+    let _type = &encode_path_part(_type);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 1 + 6 + index.len() +
+                                  _type.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(_type);
+    url_fmtd.push_str("/_mget");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
+pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params) -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
     let mut url_fmtd = String::with_capacity(base.len() + 6 + url_qry.len());
     url_fmtd.push_str(base);
@@ -104,3 +239,12 @@ pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams) -> Result<Respons
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
+pub fn get_request<'a>(req: &'a RequestParams, params: &'a Params) -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let mut url_fmtd = String::with_capacity(base.len() + 6 + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_mget");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
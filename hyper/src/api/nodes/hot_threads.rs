@@ -3,17 +3,85 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
-use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::ListPart;
 
-pub fn get_node_id<'a>(client: &'a mut Client, req: &'a RequestParams,
-                   node_id: &'a str) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+/// Url parameters for the `nodes.hot_threads` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// Don't show threads that are in known-idle places, such as waiting on a socket select or pulling from an empty task queue (default: true)
+    pub ignore_idle_threads: Option<bool>,
+    /// The interval for the second sampling of threads
+    pub interval: Option<String>,
+    /// Number of samples of thread stacktrace (default: 10)
+    pub snapshots: Option<i64>,
+    /// Specify the number of threads to provide information for (default: 3)
+    pub threads: Option<i64>,
+    /// Explicit operation timeout
+    pub timeout: Option<String>,
+    /// The type to sample (default: cpu)
+    pub _type: Option<Type>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref ignore_idle_threads) = self.ignore_idle_threads {
+            params.push(("ignore_idle_threads", ignore_idle_threads.to_string()));
+        }
+        if let Some(ref interval) = self.interval {
+            params.push(("interval", interval.to_owned()));
+        }
+        if let Some(ref snapshots) = self.snapshots {
+            params.push(("snapshots", snapshots.to_string()));
+        }
+        if let Some(ref threads) = self.threads {
+            params.push(("threads", threads.to_string()));
+        }
+        if let Some(ref timeout) = self.timeout {
+            params.push(("timeout", timeout.to_owned()));
+        }
+        if let Some(ref _type) = self._type {
+            params.push(("type", _type.as_str().to_owned()));
+        }
+        params
+    }
+}
+
+/// Options for the `type` url parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Type {
+    /// `cpu`
+    Cpu,
+    /// `wait`
+    Wait,
+    /// `block`
+    Block,
+}
+
+impl Type {
+    /// Get the value of the option as it's sent in the url.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Type::Cpu => "cpu",
+            Type::Wait => "wait",
+            Type::Block => "block",
+        }
+    }
+}
+
+pub fn get_node_id<'a, NodeId: ListPart>(client: &'a mut Client, req: &'a RequestParams,
+                                         params: &'a Params, node_id: NodeId)
+ -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let node_id = &node_id.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 8 + 12 + node_id.len() +
                                   url_qry.len());
@@ -25,8 +93,24 @@ pub fn get_node_id<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
-pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+pub fn get_node_id_request<'a, NodeId: ListPart>(req: &'a RequestParams, params: &'a Params,
+                                                 node_id: NodeId)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let node_id = &node_id.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 8 + 12 + node_id.len() +
+                                  url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_nodes/");
+    url_fmtd.push_str(node_id);
+    url_fmtd.push_str("/hot_threads");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
+pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params) -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
     let mut url_fmtd = String::with_capacity(base.len() + 19 + url_qry.len());
     url_fmtd.push_str(base);
@@ -35,3 +119,12 @@ pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams) -> Result<Respons
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
+pub fn get_request<'a>(req: &'a RequestParams, params: &'a Params) -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let mut url_fmtd = String::with_capacity(base.len() + 19 + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_nodes/hot_threads");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
@@ -3,17 +3,49 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
-use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::ListPart;
 
-pub fn get_metric<'a>(client: &'a mut Client, req: &'a RequestParams,
-                  metric: &'a str) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+/// Url parameters for the `nodes.info` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// Return settings in flat format (default: false)
+    pub flat_settings: Option<bool>,
+    /// Whether to return time and byte values in human-readable format.
+    /// Defaults to `false`.
+    pub human: Option<bool>,
+    /// Explicit operation timeout
+    pub timeout: Option<String>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref flat_settings) = self.flat_settings {
+            params.push(("flat_settings", flat_settings.to_string()));
+        }
+        if let Some(ref human) = self.human {
+            params.push(("human", human.to_string()));
+        }
+        if let Some(ref timeout) = self.timeout {
+            params.push(("timeout", timeout.to_owned()));
+        }
+        params
+    }
+}
+
+pub fn get_metric<'a, Metric: ListPart>(client: &'a mut Client, req: &'a RequestParams,
+                                        params: &'a Params, metric: Metric)
+ -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let metric = &metric.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 8 + metric.len() + url_qry.len());
     url_fmtd.push_str(base);
@@ -23,8 +55,22 @@ pub fn get_metric<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
-pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+pub fn get_metric_request<'a, Metric: ListPart>(req: &'a RequestParams, params: &'a Params,
+                                                metric: Metric)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let metric = &metric.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 8 + metric.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_nodes/");
+    url_fmtd.push_str(metric);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
+pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params) -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
     let mut url_fmtd = String::with_capacity(base.len() + 7 + url_qry.len());
     url_fmtd.push_str(base);
@@ -33,10 +79,21 @@ pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams) -> Result<Respons
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
-pub fn get_node_id<'a>(client: &'a mut Client, req: &'a RequestParams,
-                   node_id: &'a str) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+pub fn get_request<'a>(req: &'a RequestParams, params: &'a Params) -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let mut url_fmtd = String::with_capacity(base.len() + 7 + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_nodes");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
+pub fn get_node_id<'a, NodeId: ListPart>(client: &'a mut Client, req: &'a RequestParams,
+                                         params: &'a Params, node_id: NodeId)
+ -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let node_id = &node_id.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 8 + node_id.len() + url_qry.len());
     url_fmtd.push_str(base);
@@ -46,11 +103,30 @@ pub fn get_node_id<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
-pub fn get_node_id_metric<'a>(client: &'a mut Client, req: &'a RequestParams,
-                          node_id: &'a str, metric: &'a str)
+pub fn get_node_id_request<'a, NodeId: ListPart>(req: &'a RequestParams, params: &'a Params,
+                                                 node_id: NodeId)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let node_id = &node_id.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 8 + node_id.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_nodes/");
+    url_fmtd.push_str(node_id);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
+pub fn get_node_id_metric<'a,
+                          NodeId: ListPart, Metric: ListPart>(client: &'a mut Client,
+                                                              req: &'a RequestParams,
+                                                              params: &'a Params,
+                                                              node_id: NodeId, metric: Metric)
  -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let node_id = &node_id.to_path_part();
+    let metric = &metric.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 8 + 1 + node_id.len() +
                                   metric.len() + url_qry.len());
@@ -63,3 +139,24 @@ pub fn get_node_id_metric<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
+pub fn get_node_id_metric_request<'a,
+                                  NodeId: ListPart, Metric: ListPart>(req: &'a RequestParams,
+                                                                      params: &'a Params,
+                                                                      node_id: NodeId,
+                                                                      metric: Metric)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let node_id = &node_id.to_path_part();
+    let metric = &metric.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 8 + 1 + node_id.len() +
+                                  metric.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_nodes/");
+    url_fmtd.push_str(node_id);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(metric);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
@@ -3,15 +3,93 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
-use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::ListPart;
 
-pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+/// Url parameters for the `nodes.stats` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// A comma-separated list of fields for `fielddata` and `suggest` index metric (supports wildcards)
+    pub completion_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields for `fielddata` index metric (supports wildcards)
+    pub fielddata_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields for `fielddata` and `completion` index metric (supports wildcards)
+    pub fields: Option<Vec<String>>,
+    /// A comma-separated list of search groups for `search` index metric
+    pub groups: Option<bool>,
+    /// Whether to return time and byte values in human-readable format.
+    /// Defaults to `false`.
+    pub human: Option<bool>,
+    /// Return indices stats aggregated at node, index or shard level
+    /// Defaults to `node`.
+    pub level: Option<Level>,
+    /// Explicit operation timeout
+    pub timeout: Option<String>,
+    /// A comma-separated list of document types for the `indexing` index metric
+    pub types: Option<Vec<String>>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref completion_fields) = self.completion_fields {
+            params.push(("completion_fields", completion_fields.join(",")));
+        }
+        if let Some(ref fielddata_fields) = self.fielddata_fields {
+            params.push(("fielddata_fields", fielddata_fields.join(",")));
+        }
+        if let Some(ref fields) = self.fields {
+            params.push(("fields", fields.join(",")));
+        }
+        if let Some(ref groups) = self.groups {
+            params.push(("groups", groups.to_string()));
+        }
+        if let Some(ref human) = self.human {
+            params.push(("human", human.to_string()));
+        }
+        if let Some(ref level) = self.level {
+            params.push(("level", level.as_str().to_owned()));
+        }
+        if let Some(ref timeout) = self.timeout {
+            params.push(("timeout", timeout.to_owned()));
+        }
+        if let Some(ref types) = self.types {
+            params.push(("types", types.join(",")));
+        }
+        params
+    }
+}
+
+/// Options for the `level` url parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Level {
+    /// `node`
+    Node,
+    /// `indices`
+    Indices,
+    /// `shards`
+    Shards,
+}
+
+impl Level {
+    /// Get the value of the option as it's sent in the url.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Level::Node => "node",
+            Level::Indices => "indices",
+            Level::Shards => "shards",
+        }
+    }
+}
+
+pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params) -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
     let mut url_fmtd = String::with_capacity(base.len() + 13 + url_qry.len());
     url_fmtd.push_str(base);
@@ -20,11 +98,26 @@ pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams) -> Result<Respons
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
-pub fn get_metric_index_metric<'a>(client: &'a mut Client, req: &'a RequestParams,
-                               metric: &'a str, index_metric: &'a str)
+pub fn get_request<'a>(req: &'a RequestParams, params: &'a Params) -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let mut url_fmtd = String::with_capacity(base.len() + 13 + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_nodes/stats");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
+pub fn get_metric_index_metric<'a,
+                               Metric: ListPart, IndexMetric: ListPart>(client: &'a mut Client,
+                                                                        req: &'a RequestParams,
+                                                                        params: &'a Params,
+                                                                        metric: Metric,
+                                                                        index_metric: IndexMetric)
  -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let metric = &metric.to_path_part();
+    let index_metric = &index_metric.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 14 + 1 + metric.len() +
                                   index_metric.len() + url_qry.len());
@@ -37,10 +130,33 @@ pub fn get_metric_index_metric<'a>(client: &'a mut Client, req: &'a RequestParam
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
-pub fn get_metric<'a>(client: &'a mut Client, req: &'a RequestParams,
-                  metric: &'a str) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+pub fn get_metric_index_metric_request<'a,
+                                       Metric: ListPart, IndexMetric: ListPart>(req: &'a RequestParams,
+                                                                                params: &'a Params,
+                                                                                metric: Metric,
+                                                                                index_metric: IndexMetric)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let metric = &metric.to_path_part();
+    let index_metric = &index_metric.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 14 + 1 + metric.len() +
+                                  index_metric.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_nodes/stats/");
+    url_fmtd.push_str(metric);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index_metric);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
+pub fn get_metric<'a, Metric: ListPart>(client: &'a mut Client, req: &'a RequestParams,
+                                        params: &'a Params, metric: Metric)
+ -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let metric = &metric.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 14 + metric.len() + url_qry.len());
     url_fmtd.push_str(base);
@@ -50,10 +166,26 @@ pub fn get_metric<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
-pub fn get_node_id<'a>(client: &'a mut Client, req: &'a RequestParams,
-                   node_id: &'a str) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+pub fn get_metric_request<'a, Metric: ListPart>(req: &'a RequestParams, params: &'a Params,
+                                                metric: Metric)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let metric = &metric.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 14 + metric.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_nodes/stats/");
+    url_fmtd.push_str(metric);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
+pub fn get_node_id<'a, NodeId: ListPart>(client: &'a mut Client, req: &'a RequestParams,
+                                         params: &'a Params, node_id: NodeId)
+ -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let node_id = &node_id.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 8 + 6 + node_id.len() +
                                   url_qry.len());
@@ -65,11 +197,32 @@ pub fn get_node_id<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
-pub fn get_node_id_metric<'a>(client: &'a mut Client, req: &'a RequestParams,
-                          node_id: &'a str, metric: &'a str)
+pub fn get_node_id_request<'a, NodeId: ListPart>(req: &'a RequestParams, params: &'a Params,
+                                                 node_id: NodeId)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let node_id = &node_id.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 8 + 6 + node_id.len() +
+                                  url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_nodes/");
+    url_fmtd.push_str(node_id);
+    url_fmtd.push_str("/stats");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
+pub fn get_node_id_metric<'a,
+                          NodeId: ListPart, Metric: ListPart>(client: &'a mut Client,
+                                                              req: &'a RequestParams,
+                                                              params: &'a Params,
+                                                              node_id: NodeId, metric: Metric)
  -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let node_id = &node_id.to_path_part();
+    let metric = &metric.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 8 + 7 + node_id.len() +
                                   metric.len() + url_qry.len());
@@ -82,13 +235,40 @@ pub fn get_node_id_metric<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
-pub fn get_node_id_metric_index_metric<'a>(client: &'a mut Client,
-                                       req: &'a RequestParams,
-                                       node_id: &'a str, metric: &'a str,
-                                       index_metric: &'a str)
+pub fn get_node_id_metric_request<'a,
+                                  NodeId: ListPart, Metric: ListPart>(req: &'a RequestParams,
+                                                                      params: &'a Params,
+                                                                      node_id: NodeId,
+                                                                      metric: Metric)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let node_id = &node_id.to_path_part();
+    let metric = &metric.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 8 + 7 + node_id.len() +
+                                  metric.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_nodes/");
+    url_fmtd.push_str(node_id);
+    url_fmtd.push_str("/stats/");
+    url_fmtd.push_str(metric);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
+pub fn get_node_id_metric_index_metric<'a,
+                                       NodeId: ListPart, Metric: ListPart, IndexMetric: ListPart>(client: &'a mut Client,
+                                                                                                  req: &'a RequestParams,
+                                                                                                  params: &'a Params,
+                                                                                                  node_id: NodeId,
+                                                                                                  metric: Metric,
+                                                                                                  index_metric: IndexMetric)
  -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let node_id = &node_id.to_path_part();
+    let metric = &metric.to_path_part();
+    let index_metric = &index_metric.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 8 + 7 + 1 + node_id.len() +
                                   metric.len() + index_metric.len() +
@@ -104,3 +284,29 @@ pub fn get_node_id_metric_index_metric<'a>(client: &'a mut Client,
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
+pub fn get_node_id_metric_index_metric_request<'a,
+                                               NodeId: ListPart, Metric: ListPart, IndexMetric: ListPart>(req: &'a RequestParams,
+                                                                                                          params: &'a Params,
+                                                                                                          node_id: NodeId,
+                                                                                                          metric: Metric,
+                                                                                                          index_metric: IndexMetric)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let node_id = &node_id.to_path_part();
+    let metric = &metric.to_path_part();
+    let index_metric = &index_metric.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 8 + 7 + 1 + node_id.len() +
+                                  metric.len() + index_metric.len() +
+                                  url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_nodes/");
+    url_fmtd.push_str(node_id);
+    url_fmtd.push_str("/stats/");
+    url_fmtd.push_str(metric);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index_metric);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
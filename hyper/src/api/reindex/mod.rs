@@ -3,17 +3,81 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
 use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+
+/// Url parameters for the `reindex` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// Explicit write consistency setting for the operation
+    pub consistency: Option<Consistency>,
+    /// Should the effected indexes be refreshed?
+    pub refresh: Option<bool>,
+    /// The throttle for this request in sub-requests per second. 0 means set no throttle.
+    /// Defaults to `0`.
+    pub requests_per_second: Option<f32>,
+    /// Time each individual bulk request should wait for shards that are unavailable.
+    /// Defaults to `1m`.
+    pub timeout: Option<String>,
+    /// Should the request should block until the reindex is complete.
+    /// Defaults to `false`.
+    pub wait_for_completion: Option<bool>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref consistency) = self.consistency {
+            params.push(("consistency", consistency.as_str().to_owned()));
+        }
+        if let Some(ref refresh) = self.refresh {
+            params.push(("refresh", refresh.to_string()));
+        }
+        if let Some(ref requests_per_second) = self.requests_per_second {
+            params.push(("requests_per_second", requests_per_second.to_string()));
+        }
+        if let Some(ref timeout) = self.timeout {
+            params.push(("timeout", timeout.to_owned()));
+        }
+        if let Some(ref wait_for_completion) = self.wait_for_completion {
+            params.push(("wait_for_completion", wait_for_completion.to_string()));
+        }
+        params
+    }
+}
+
+/// Options for the `consistency` url parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Consistency {
+    /// `one`
+    One,
+    /// `quorum`
+    Quorum,
+    /// `all`
+    All,
+}
+
+impl Consistency {
+    /// Get the value of the option as it's sent in the url.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Consistency::One => "one",
+            Consistency::Quorum => "quorum",
+            Consistency::All => "all",
+        }
+    }
+}
 
 pub fn post<'a,
-        I: Into<Body<'a>>>(client: &'a mut Client, req: &'a RequestParams,
+        I: Into<Body<'a>>>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params,
                            body: I) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
     let mut url_fmtd = String::with_capacity(base.len() + 9 + url_qry.len());
     url_fmtd.push_str(base);
@@ -23,4 +87,14 @@ pub fn post<'a,
         client.post(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
+pub fn post_request<'a, B: AsRef<[u8]>>(req: &'a RequestParams, params: &'a Params, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let mut url_fmtd = String::with_capacity(base.len() + 9 + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_reindex");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Post, url_fmtd, req.headers.to_owned()).body(body)
+}
 pub mod rethrottle;
@@ -3,19 +3,41 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
 use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::encode_path_part;
+
+/// Url parameters for the `reindex.rethrottle` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// The throttle to set on this request in sub-requests per second. 0 means set no throttle. As does "unlimited". Otherwise it must be a float.
+    /// Defaults to `0`.
+    pub requests_per_second: Option<f32>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref requests_per_second) = self.requests_per_second {
+            params.push(("requests_per_second", requests_per_second.to_string()));
+        }
+        params
+    }
+}
 
 pub fn post_task_id<'a,
                 I: Into<Body<'a>>>(client: &'a mut Client,
-                                   req: &'a RequestParams, task_id: &'a str,
+                                   req: &'a RequestParams, params: &'a Params, task_id: &'a str,
                                    body: I) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let task_id = &encode_path_part(task_id);
     let mut url_fmtd =
         String::with_capacity(base.len() + 18 + 12 + task_id.len() +
                                   url_qry.len());
@@ -28,3 +50,19 @@ pub fn post_task_id<'a,
         client.post(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
+pub fn post_task_id_request<'a, B: AsRef<[u8]>>(req: &'a RequestParams, params: &'a Params,
+                                                task_id: &'a str, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let task_id = &encode_path_part(task_id);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 18 + 12 + task_id.len() +
+                                  url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_update_by_query/");
+    url_fmtd.push_str(task_id);
+    url_fmtd.push_str("/_rethrottle");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Post, url_fmtd, req.headers.to_owned()).body(body)
+}
@@ -3,20 +3,292 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
 use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::ListPart;
+
+/// Url parameters for the `search` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// True or false to return the _source field or not, or a list of fields to return
+    pub _source: Option<Vec<String>>,
+    /// A list of fields to exclude from the returned _source field
+    pub _source_exclude: Option<Vec<String>>,
+    /// A list of fields to extract and return from the _source field
+    pub _source_include: Option<Vec<String>>,
+    /// Whether to ignore if a wildcard indices expression resolves into no concrete indices. (This includes `_all` string or when no indices have been specified)
+    pub allow_no_indices: Option<bool>,
+    /// Specify whether wildcard and prefix queries should be analyzed (default: false)
+    pub analyze_wildcard: Option<bool>,
+    /// The analyzer to use for the query string
+    pub analyzer: Option<String>,
+    /// The default operator for query string query (AND or OR)
+    /// Defaults to `OR`.
+    pub default_operator: Option<DefaultOperator>,
+    /// The field to use as default where no field prefix is given in the query string
+    pub df: Option<String>,
+    /// Whether to expand wildcard expression to concrete indices that are open, closed or both.
+    /// Defaults to `open`.
+    pub expand_wildcards: Option<ExpandWildcards>,
+    /// Specify whether to return detailed information about score computation as part of a hit
+    pub explain: Option<bool>,
+    /// A comma-separated list of fields to return as the field data representation of a field for each hit
+    pub fielddata_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return as part of a hit
+    pub fields: Option<Vec<String>>,
+    /// Starting offset (default: 0)
+    pub from: Option<i64>,
+    /// Whether specified concrete indices should be ignored when unavailable (missing or closed)
+    pub ignore_unavailable: Option<bool>,
+    /// Specify whether format-based query failures (such as providing text to a numeric field) should be ignored
+    pub lenient: Option<bool>,
+    /// Specify whether query terms should be lowercased
+    pub lowercase_expanded_terms: Option<bool>,
+    /// Specify the node or shard the operation should be performed on (default: random)
+    pub preference: Option<String>,
+    /// Query in the Lucene query string syntax
+    pub q: Option<String>,
+    /// Specify if request cache should be used for this request or not, defaults to index level setting
+    pub request_cache: Option<bool>,
+    /// A comma-separated list of specific routing values
+    pub routing: Option<Vec<String>>,
+    /// Specify how long a consistent view of the index should be maintained for scrolled search
+    pub scroll: Option<String>,
+    /// Search operation type
+    pub search_type: Option<SearchType>,
+    /// Number of hits to return (default: 10)
+    pub size: Option<i64>,
+    /// A comma-separated list of <field>:<direction> pairs
+    pub sort: Option<Vec<String>>,
+    /// Specific 'tag' of the request for logging and statistical purposes
+    pub stats: Option<Vec<String>>,
+    /// Specify which field to use for suggestions
+    pub suggest_field: Option<String>,
+    /// Specify suggest mode
+    /// Defaults to `missing`.
+    pub suggest_mode: Option<SuggestMode>,
+    /// How many suggestions to return in response
+    pub suggest_size: Option<i64>,
+    /// The source text for which the suggestions should be returned
+    pub suggest_text: Option<String>,
+    /// The maximum number of documents to collect for each shard, upon reaching which the query execution will terminate early.
+    pub terminate_after: Option<i64>,
+    /// Explicit operation timeout
+    pub timeout: Option<String>,
+    /// Whether to calculate and return scores even if they are not used for sorting
+    pub track_scores: Option<bool>,
+    /// Specify whether to return document version as part of a hit
+    pub version: Option<bool>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref _source) = self._source {
+            params.push(("_source", _source.join(",")));
+        }
+        if let Some(ref _source_exclude) = self._source_exclude {
+            params.push(("_source_exclude", _source_exclude.join(",")));
+        }
+        if let Some(ref _source_include) = self._source_include {
+            params.push(("_source_include", _source_include.join(",")));
+        }
+        if let Some(ref allow_no_indices) = self.allow_no_indices {
+            params.push(("allow_no_indices", allow_no_indices.to_string()));
+        }
+        if let Some(ref analyze_wildcard) = self.analyze_wildcard {
+            params.push(("analyze_wildcard", analyze_wildcard.to_string()));
+        }
+        if let Some(ref analyzer) = self.analyzer {
+            params.push(("analyzer", analyzer.to_owned()));
+        }
+        if let Some(ref default_operator) = self.default_operator {
+            params.push(("default_operator", default_operator.as_str().to_owned()));
+        }
+        if let Some(ref df) = self.df {
+            params.push(("df", df.to_owned()));
+        }
+        if let Some(ref expand_wildcards) = self.expand_wildcards {
+            params.push(("expand_wildcards", expand_wildcards.as_str().to_owned()));
+        }
+        if let Some(ref explain) = self.explain {
+            params.push(("explain", explain.to_string()));
+        }
+        if let Some(ref fielddata_fields) = self.fielddata_fields {
+            params.push(("fielddata_fields", fielddata_fields.join(",")));
+        }
+        if let Some(ref fields) = self.fields {
+            params.push(("fields", fields.join(",")));
+        }
+        if let Some(ref from) = self.from {
+            params.push(("from", from.to_string()));
+        }
+        if let Some(ref ignore_unavailable) = self.ignore_unavailable {
+            params.push(("ignore_unavailable", ignore_unavailable.to_string()));
+        }
+        if let Some(ref lenient) = self.lenient {
+            params.push(("lenient", lenient.to_string()));
+        }
+        if let Some(ref lowercase_expanded_terms) = self.lowercase_expanded_terms {
+            params.push(("lowercase_expanded_terms", lowercase_expanded_terms.to_string()));
+        }
+        if let Some(ref preference) = self.preference {
+            params.push(("preference", preference.to_owned()));
+        }
+        if let Some(ref q) = self.q {
+            params.push(("q", q.to_owned()));
+        }
+        if let Some(ref request_cache) = self.request_cache {
+            params.push(("request_cache", request_cache.to_string()));
+        }
+        if let Some(ref routing) = self.routing {
+            params.push(("routing", routing.join(",")));
+        }
+        if let Some(ref scroll) = self.scroll {
+            params.push(("scroll", scroll.to_owned()));
+        }
+        if let Some(ref search_type) = self.search_type {
+            params.push(("search_type", search_type.as_str().to_owned()));
+        }
+        if let Some(ref size) = self.size {
+            params.push(("size", size.to_string()));
+        }
+        if let Some(ref sort) = self.sort {
+            params.push(("sort", sort.join(",")));
+        }
+        if let Some(ref stats) = self.stats {
+            params.push(("stats", stats.join(",")));
+        }
+        if let Some(ref suggest_field) = self.suggest_field {
+            params.push(("suggest_field", suggest_field.to_owned()));
+        }
+        if let Some(ref suggest_mode) = self.suggest_mode {
+            params.push(("suggest_mode", suggest_mode.as_str().to_owned()));
+        }
+        if let Some(ref suggest_size) = self.suggest_size {
+            params.push(("suggest_size", suggest_size.to_string()));
+        }
+        if let Some(ref suggest_text) = self.suggest_text {
+            params.push(("suggest_text", suggest_text.to_owned()));
+        }
+        if let Some(ref terminate_after) = self.terminate_after {
+            params.push(("terminate_after", terminate_after.to_string()));
+        }
+        if let Some(ref timeout) = self.timeout {
+            params.push(("timeout", timeout.to_owned()));
+        }
+        if let Some(ref track_scores) = self.track_scores {
+            params.push(("track_scores", track_scores.to_string()));
+        }
+        if let Some(ref version) = self.version {
+            params.push(("version", version.to_string()));
+        }
+        params
+    }
+}
+
+/// Options for the `default_operator` url parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DefaultOperator {
+    /// `AND`
+    And,
+    /// `OR`
+    Or,
+}
+
+impl DefaultOperator {
+    /// Get the value of the option as it's sent in the url.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            DefaultOperator::And => "AND",
+            DefaultOperator::Or => "OR",
+        }
+    }
+}
+
+/// Options for the `expand_wildcards` url parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExpandWildcards {
+    /// `open`
+    Open,
+    /// `closed`
+    Closed,
+    /// `none`
+    None,
+    /// `all`
+    All,
+}
+
+impl ExpandWildcards {
+    /// Get the value of the option as it's sent in the url.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            ExpandWildcards::Open => "open",
+            ExpandWildcards::Closed => "closed",
+            ExpandWildcards::None => "none",
+            ExpandWildcards::All => "all",
+        }
+    }
+}
+
+/// Options for the `search_type` url parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SearchType {
+    /// `query_then_fetch`
+    QueryThenFetch,
+    /// `dfs_query_then_fetch`
+    DfsQueryThenFetch,
+}
+
+impl SearchType {
+    /// Get the value of the option as it's sent in the url.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            SearchType::QueryThenFetch => "query_then_fetch",
+            SearchType::DfsQueryThenFetch => "dfs_query_then_fetch",
+        }
+    }
+}
+
+/// Options for the `suggest_mode` url parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SuggestMode {
+    /// `missing`
+    Missing,
+    /// `popular`
+    Popular,
+    /// `always`
+    Always,
+}
+
+impl SuggestMode {
+    /// Get the value of the option as it's sent in the url.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            SuggestMode::Missing => "missing",
+            SuggestMode::Popular => "popular",
+            SuggestMode::Always => "always",
+        }
+    }
+}
 
 pub fn post_index_type<'a,
-                   I: Into<Body<'a>>>(client: &'a mut Client,
-                                      req: &'a RequestParams, index: &'a str,
-                                      _type: &'a str, body: I)
+                       Index: ListPart, Type: ListPart, I: Into<Body<'a>>>(client: &'a mut Client,
+                                                                           req: &'a RequestParams,
+                                                                           params: &'a Params,
+                                                                           index: Index,
+                                                                           _type: Type, body: I)
  -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &index.to_path_part();
+    let _type = &_type.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 1 + 8 + index.len() +
                                   _type.len() + url_qry.len());
@@ -31,10 +303,35 @@ pub fn post_index_type<'a,
         client.post(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
-pub fn get_index<'a>(client: &'a mut Client, req: &'a RequestParams,
-                 index: &'a str) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+pub fn post_index_type_request<'a,
+                               Index: ListPart, Type: ListPart, B: AsRef<[u8]>>(req: &'a RequestParams,
+                                                                                params: &'a Params,
+                                                                                index: Index,
+                                                                                _type: Type,
+                                                                                body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &index.to_path_part();
+    let _type = &_type.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 1 + 8 + index.len() +
+                                  _type.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(_type);
+    url_fmtd.push_str("/_search");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Post, url_fmtd, req.headers.to_owned()).body(body)
+}
+pub fn get_index<'a, Index: ListPart>(client: &'a mut Client, req: &'a RequestParams,
+                                      params: &'a Params, index: Index)
+ -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &index.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 8 + index.len() +
                                   url_qry.len());
@@ -46,12 +343,30 @@ pub fn get_index<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
+pub fn get_index_request<'a, Index: ListPart>(req: &'a RequestParams, params: &'a Params,
+                                              index: Index)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &index.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 8 + index.len() +
+                                  url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/_search");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
 pub fn post_index<'a,
-              I: Into<Body<'a>>>(client: &'a mut Client,
-                                 req: &'a RequestParams, index: &'a str,
-                                 body: I) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+                  Index: ListPart, I: Into<Body<'a>>>(client: &'a mut Client,
+                                                      req: &'a RequestParams,
+                                                      params: &'a Params, index: Index, body: I)
+ -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &index.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 8 + index.len() +
                                   url_qry.len());
@@ -64,10 +379,34 @@ pub fn post_index<'a,
         client.post(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
-pub fn get_index_type<'a>(client: &'a mut Client, req: &'a RequestParams,
-                      index: &'a str, _type: &'a str) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+pub fn post_index_request<'a,
+                          Index: ListPart, B: AsRef<[u8]>>(req: &'a RequestParams,
+                                                           params: &'a Params, index: Index,
+                                                           body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &index.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 8 + index.len() +
+                                  url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/_search");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Post, url_fmtd, req.headers.to_owned()).body(body)
+}
+pub fn get_index_type<'a,
+                      Index: ListPart, Type: ListPart>(client: &'a mut Client,
+                                                       req: &'a RequestParams,
+                                                       params: &'a Params, index: Index,
+                                                       _type: Type)
+ -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &index.to_path_part();
+    let _type = &_type.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 1 + 8 + index.len() +
                                   _type.len() + url_qry.len());
@@ -81,10 +420,31 @@ pub fn get_index_type<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
+pub fn get_index_type_request<'a,
+                              Index: ListPart, Type: ListPart>(req: &'a RequestParams,
+                                                               params: &'a Params, index: Index,
+                                                               _type: Type)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &index.to_path_part();
+    let _type = &_type.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 1 + 8 + index.len() +
+                                  _type.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(_type);
+    url_fmtd.push_str("/_search");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
 pub fn post<'a,
-        I: Into<Body<'a>>>(client: &'a mut Client, req: &'a RequestParams,
+        I: Into<Body<'a>>>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params,
                            body: I) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
     let mut url_fmtd = String::with_capacity(base.len() + 8 + url_qry.len());
     url_fmtd.push_str(base);
@@ -94,8 +454,18 @@ pub fn post<'a,
         client.post(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
-pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+pub fn post_request<'a, B: AsRef<[u8]>>(req: &'a RequestParams, params: &'a Params, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let mut url_fmtd = String::with_capacity(base.len() + 8 + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_search");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Post, url_fmtd, req.headers.to_owned()).body(body)
+}
+pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params) -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
     let mut url_fmtd = String::with_capacity(base.len() + 8 + url_qry.len());
     url_fmtd.push_str(base);
@@ -104,3 +474,12 @@ pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams) -> Result<Respons
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
+pub fn get_request<'a>(req: &'a RequestParams, params: &'a Params) -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let mut url_fmtd = String::with_capacity(base.len() + 8 + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_search");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
@@ -3,21 +3,49 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
 use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::encode_path_part;
+
+/// Url parameters for the `snapshot.create` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// Explicit operation timeout for connection to master node
+    pub master_timeout: Option<String>,
+    /// Should this request wait until the operation has completed before returning
+    /// Defaults to `false`.
+    pub wait_for_completion: Option<bool>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref master_timeout) = self.master_timeout {
+            params.push(("master_timeout", master_timeout.to_owned()));
+        }
+        if let Some(ref wait_for_completion) = self.wait_for_completion {
+            params.push(("wait_for_completion", wait_for_completion.to_string()));
+        }
+        params
+    }
+}
 
 pub fn post_repository_snapshot<'a,
                             I: Into<Body<'a>>>(client: &'a mut Client,
-                                               req: &'a RequestParams,
+                                               req: &'a RequestParams, params: &'a Params,
                                                repository: &'a str,
                                                snapshot: &'a str, body: I)
  -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let repository = &encode_path_part(repository);
+    let snapshot = &encode_path_part(snapshot);
     let mut url_fmtd =
         String::with_capacity(base.len() + 11 + 1 + repository.len() +
                                   snapshot.len() + url_qry.len());
@@ -31,14 +59,36 @@ pub fn post_repository_snapshot<'a,
         client.post(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
+pub fn post_repository_snapshot_request<'a, B: AsRef<[u8]>>(req: &'a RequestParams,
+                                                            params: &'a Params,
+                                                            repository: &'a str,
+                                                            snapshot: &'a str, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let repository = &encode_path_part(repository);
+    let snapshot = &encode_path_part(snapshot);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 11 + 1 + repository.len() +
+                                  snapshot.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_snapshot/");
+    url_fmtd.push_str(repository);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(snapshot);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Post, url_fmtd, req.headers.to_owned()).body(body)
+}
 pub fn put_repository_snapshot<'a,
                            I: Into<Body<'a>>>(client: &'a mut Client,
-                                              req: &'a RequestParams,
+                                              req: &'a RequestParams, params: &'a Params,
                                               repository: &'a str,
                                               snapshot: &'a str, body: I)
  -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let repository = &encode_path_part(repository);
+    let snapshot = &encode_path_part(snapshot);
     let mut url_fmtd =
         String::with_capacity(base.len() + 11 + 1 + repository.len() +
                                   snapshot.len() + url_qry.len());
@@ -52,3 +102,23 @@ pub fn put_repository_snapshot<'a,
         client.put(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
+pub fn put_repository_snapshot_request<'a, B: AsRef<[u8]>>(req: &'a RequestParams,
+                                                           params: &'a Params,
+                                                           repository: &'a str,
+                                                           snapshot: &'a str, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let repository = &encode_path_part(repository);
+    let snapshot = &encode_path_part(snapshot);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 11 + 1 + repository.len() +
+                                  snapshot.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_snapshot/");
+    url_fmtd.push_str(repository);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(snapshot);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Put, url_fmtd, req.headers.to_owned()).body(body)
+}
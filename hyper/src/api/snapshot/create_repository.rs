@@ -3,20 +3,51 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
 use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::encode_path_part;
+
+/// Url parameters for the `snapshot.create_repository` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// Explicit operation timeout for connection to master node
+    pub master_timeout: Option<String>,
+    /// Explicit operation timeout
+    pub timeout: Option<String>,
+    /// Whether to verify the repository after creation
+    pub verify: Option<bool>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref master_timeout) = self.master_timeout {
+            params.push(("master_timeout", master_timeout.to_owned()));
+        }
+        if let Some(ref timeout) = self.timeout {
+            params.push(("timeout", timeout.to_owned()));
+        }
+        if let Some(ref verify) = self.verify {
+            params.push(("verify", verify.to_string()));
+        }
+        params
+    }
+}
 
 pub fn post_repository<'a,
                    I: Into<Body<'a>>>(client: &'a mut Client,
-                                      req: &'a RequestParams,
+                                      req: &'a RequestParams, params: &'a Params,
                                       repository: &'a str, body: I)
  -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let repository = &encode_path_part(repository);
     let mut url_fmtd =
         String::with_capacity(base.len() + 11 + repository.len() +
                                   url_qry.len());
@@ -28,13 +59,29 @@ pub fn post_repository<'a,
         client.post(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
+pub fn post_repository_request<'a, B: AsRef<[u8]>>(req: &'a RequestParams, params: &'a Params,
+                                                   repository: &'a str, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let repository = &encode_path_part(repository);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 11 + repository.len() +
+                                  url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_snapshot/");
+    url_fmtd.push_str(repository);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Post, url_fmtd, req.headers.to_owned()).body(body)
+}
 pub fn put_repository<'a,
                   I: Into<Body<'a>>>(client: &'a mut Client,
-                                     req: &'a RequestParams,
+                                     req: &'a RequestParams, params: &'a Params,
                                      repository: &'a str, body: I)
  -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let repository = &encode_path_part(repository);
     let mut url_fmtd =
         String::with_capacity(base.len() + 11 + repository.len() +
                                   url_qry.len());
@@ -46,3 +93,18 @@ pub fn put_repository<'a,
         client.put(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
+pub fn put_repository_request<'a, B: AsRef<[u8]>>(req: &'a RequestParams, params: &'a Params,
+                                                  repository: &'a str, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let repository = &encode_path_part(repository);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 11 + repository.len() +
+                                  url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_snapshot/");
+    url_fmtd.push_str(repository);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Put, url_fmtd, req.headers.to_owned()).body(body)
+}
@@ -3,17 +3,44 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
-use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::ListPart;
 
-pub fn delete_repository<'a>(client: &'a mut Client, req: &'a RequestParams,
-                         repository: &'a str) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+/// Url parameters for the `snapshot.delete_repository` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// Explicit operation timeout for connection to master node
+    pub master_timeout: Option<String>,
+    /// Explicit operation timeout
+    pub timeout: Option<String>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref master_timeout) = self.master_timeout {
+            params.push(("master_timeout", master_timeout.to_owned()));
+        }
+        if let Some(ref timeout) = self.timeout {
+            params.push(("timeout", timeout.to_owned()));
+        }
+        params
+    }
+}
+
+pub fn delete_repository<'a, Repository: ListPart>(client: &'a mut Client,
+                                                   req: &'a RequestParams, params: &'a Params,
+                                                   repository: Repository)
+ -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let repository = &repository.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 11 + repository.len() +
                                   url_qry.len());
@@ -24,3 +51,20 @@ pub fn delete_repository<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.delete(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
+pub fn delete_repository_request<'a,
+                                 Repository: ListPart>(req: &'a RequestParams,
+                                                       params: &'a Params,
+                                                       repository: Repository)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let repository = &repository.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 11 + repository.len() +
+                                  url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_snapshot/");
+    url_fmtd.push_str(repository);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Delete, url_fmtd, req.headers.to_owned())
+}
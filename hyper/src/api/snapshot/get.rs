@@ -3,18 +3,42 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
-use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::ListPart;
+use ::encode_path_part;
 
-pub fn get_repository_snapshot<'a>(client: &'a mut Client, req: &'a RequestParams,
-                               repository: &'a str, snapshot: &'a str)
+/// Url parameters for the `snapshot.get` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// Explicit operation timeout for connection to master node
+    pub master_timeout: Option<String>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref master_timeout) = self.master_timeout {
+            params.push(("master_timeout", master_timeout.to_owned()));
+        }
+        params
+    }
+}
+
+pub fn get_repository_snapshot<'a, Snapshot: ListPart>(client: &'a mut Client,
+                                                       req: &'a RequestParams,
+                                                       params: &'a Params, repository: &'a str,
+                                                       snapshot: Snapshot)
  -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let repository = &encode_path_part(repository);
+    let snapshot = &snapshot.to_path_part();
     let mut url_fmtd =
         String::with_capacity(base.len() + 11 + 1 + repository.len() +
                                   snapshot.len() + url_qry.len());
@@ -27,3 +51,24 @@ pub fn get_repository_snapshot<'a>(client: &'a mut Client, req: &'a RequestParam
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
+pub fn get_repository_snapshot_request<'a,
+                                       Snapshot: ListPart>(req: &'a RequestParams,
+                                                           params: &'a Params,
+                                                           repository: &'a str,
+                                                           snapshot: Snapshot)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let repository = &encode_path_part(repository);
+    let snapshot = &snapshot.to_path_part();
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 11 + 1 + repository.len() +
+                                  snapshot.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_snapshot/");
+    url_fmtd.push_str(repository);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(snapshot);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
@@ -3,19 +3,55 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
 use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::encode_path_part;
+
+/// Url parameters for the `tasks.cancel` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// A comma-separated list of actions that should be cancelled. Leave empty to cancel all.
+    pub actions: Option<Vec<String>>,
+    /// A comma-separated list of node IDs or names to limit the returned information; use `_local` to return information from the node you're connecting to, leave empty to get information from all nodes
+    pub node_id: Option<Vec<String>>,
+    /// Cancel tasks with specified parent node.
+    pub parent_node: Option<String>,
+    /// Cancel tasks with specified parent task id (node_id:task_number). Set to -1 to cancel all.
+    pub parent_task: Option<String>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref actions) = self.actions {
+            params.push(("actions", actions.join(",")));
+        }
+        if let Some(ref node_id) = self.node_id {
+            params.push(("node_id", node_id.join(",")));
+        }
+        if let Some(ref parent_node) = self.parent_node {
+            params.push(("parent_node", parent_node.to_owned()));
+        }
+        if let Some(ref parent_task) = self.parent_task {
+            params.push(("parent_task", parent_task.to_owned()));
+        }
+        params
+    }
+}
 
 pub fn post_task_id<'a,
                 I: Into<Body<'a>>>(client: &'a mut Client,
-                                   req: &'a RequestParams, task_id: &'a str,
+                                   req: &'a RequestParams, params: &'a Params, task_id: &'a str,
                                    body: I) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let task_id = &encode_path_part(task_id);
     let mut url_fmtd =
         String::with_capacity(base.len() + 8 + 8 + task_id.len() +
                                   url_qry.len());
@@ -28,10 +64,26 @@ pub fn post_task_id<'a,
         client.post(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
+pub fn post_task_id_request<'a, B: AsRef<[u8]>>(req: &'a RequestParams, params: &'a Params,
+                                                task_id: &'a str, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let task_id = &encode_path_part(task_id);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 8 + 8 + task_id.len() +
+                                  url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_tasks/");
+    url_fmtd.push_str(task_id);
+    url_fmtd.push_str("/_cancel");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Post, url_fmtd, req.headers.to_owned()).body(body)
+}
 pub fn post<'a,
-        I: Into<Body<'a>>>(client: &'a mut Client, req: &'a RequestParams,
+        I: Into<Body<'a>>>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params,
                            body: I) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
     let mut url_fmtd = String::with_capacity(base.len() + 15 + url_qry.len());
     url_fmtd.push_str(base);
@@ -41,3 +93,13 @@ pub fn post<'a,
         client.post(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
+pub fn post_request<'a, B: AsRef<[u8]>>(req: &'a RequestParams, params: &'a Params, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let mut url_fmtd = String::with_capacity(base.len() + 15 + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_tasks/_cancel");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Post, url_fmtd, req.headers.to_owned()).body(body)
+}
@@ -3,15 +3,84 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
-use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::encode_path_part;
 
-pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+/// Url parameters for the `tasks.list` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// A comma-separated list of actions that should be returned. Leave empty to return all.
+    pub actions: Option<Vec<String>>,
+    /// Return detailed task information (default: false)
+    pub detailed: Option<bool>,
+    /// Group tasks by nodes or parent/child relationships
+    /// Defaults to `nodes`.
+    pub group_by: Option<GroupBy>,
+    /// A comma-separated list of node IDs or names to limit the returned information; use `_local` to return information from the node you're connecting to, leave empty to get information from all nodes
+    pub node_id: Option<Vec<String>>,
+    /// Return tasks with specified parent node.
+    pub parent_node: Option<String>,
+    /// Return tasks with specified parent task id (node_id:task_number). Set to -1 to return all.
+    pub parent_task: Option<String>,
+    /// Wait for the matching tasks to complete (default: false)
+    pub wait_for_completion: Option<bool>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref actions) = self.actions {
+            params.push(("actions", actions.join(",")));
+        }
+        if let Some(ref detailed) = self.detailed {
+            params.push(("detailed", detailed.to_string()));
+        }
+        if let Some(ref group_by) = self.group_by {
+            params.push(("group_by", group_by.as_str().to_owned()));
+        }
+        if let Some(ref node_id) = self.node_id {
+            params.push(("node_id", node_id.join(",")));
+        }
+        if let Some(ref parent_node) = self.parent_node {
+            params.push(("parent_node", parent_node.to_owned()));
+        }
+        if let Some(ref parent_task) = self.parent_task {
+            params.push(("parent_task", parent_task.to_owned()));
+        }
+        if let Some(ref wait_for_completion) = self.wait_for_completion {
+            params.push(("wait_for_completion", wait_for_completion.to_string()));
+        }
+        params
+    }
+}
+
+/// Options for the `group_by` url parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GroupBy {
+    /// `nodes`
+    Nodes,
+    /// `parents`
+    Parents,
+}
+
+impl GroupBy {
+    /// Get the value of the option as it's sent in the url.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            GroupBy::Nodes => "nodes",
+            GroupBy::Parents => "parents",
+        }
+    }
+}
+
+pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params) -> Result<Response>{
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
     let mut url_fmtd = String::with_capacity(base.len() + 7 + url_qry.len());
     url_fmtd.push_str(base);
@@ -20,10 +89,20 @@ pub fn get<'a>(client: &'a mut Client, req: &'a RequestParams) -> Result<Respons
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
-pub fn get_task_id<'a>(client: &'a mut Client, req: &'a RequestParams,
+pub fn get_request<'a>(req: &'a RequestParams, params: &'a Params) -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let mut url_fmtd = String::with_capacity(base.len() + 7 + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_tasks");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
+pub fn get_task_id<'a>(client: &'a mut Client, req: &'a RequestParams, params: &'a Params,
                    task_id: &'a str) -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let task_id = &encode_path_part(task_id);
     let mut url_fmtd =
         String::with_capacity(base.len() + 8 + task_id.len() + url_qry.len());
     url_fmtd.push_str(base);
@@ -33,3 +112,16 @@ pub fn get_task_id<'a>(client: &'a mut Client, req: &'a RequestParams,
     let res = client.get(&url_fmtd).headers(req.headers.to_owned());
     res.send()
 }
+pub fn get_task_id_request<'a>(req: &'a RequestParams, params: &'a Params, task_id: &'a str)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let task_id = &encode_path_part(task_id);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 8 + task_id.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/_tasks/");
+    url_fmtd.push_str(task_id);
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Get, url_fmtd, req.headers.to_owned())
+}
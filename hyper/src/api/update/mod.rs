@@ -3,21 +3,155 @@
 //Autogenerated
 
 use hyper::client::Client;
-#[allow(unused_imports)]
 use hyper::client::Body;
 use hyper::client::response::Response;
 use hyper::error::Result;
+use hyper::method::Method;
 
 use ::RequestParams;
+use ::UrlParams;
+use ::HttpRequest;
+use ::encode_path_part;
+
+/// Url parameters for the `update` endpoint.
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    /// Explicit write consistency setting for the operation
+    pub consistency: Option<Consistency>,
+    /// A comma-separated list of fields to return in the response
+    pub fields: Option<Vec<String>>,
+    /// The script language (default: groovy)
+    pub lang: Option<String>,
+    /// ID of the parent document. Is is only used for routing and when for the upsert request
+    pub parent: Option<String>,
+    /// Refresh the index after performing the operation
+    pub refresh: Option<bool>,
+    /// Specify how many times should the operation be retried when a conflict occurs (default: 0)
+    pub retry_on_conflict: Option<i64>,
+    /// Specific routing value
+    pub routing: Option<String>,
+    /// The URL-encoded script definition (instead of using request body)
+    pub script: Option<String>,
+    /// The id of a stored script
+    pub script_id: Option<String>,
+    /// True if the script referenced in script or script_id should be called to perform inserts - defaults to false
+    pub scripted_upsert: Option<bool>,
+    /// Explicit operation timeout
+    pub timeout: Option<String>,
+    /// Explicit timestamp for the document
+    pub timestamp: Option<String>,
+    /// Expiration time for the document
+    pub ttl: Option<String>,
+    /// Explicit version number for concurrency control
+    pub version: Option<i64>,
+    /// Specific version type
+    pub version_type: Option<VersionType>,
+}
+
+impl UrlParams for Params {
+    fn url_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref consistency) = self.consistency {
+            params.push(("consistency", consistency.as_str().to_owned()));
+        }
+        if let Some(ref fields) = self.fields {
+            params.push(("fields", fields.join(",")));
+        }
+        if let Some(ref lang) = self.lang {
+            params.push(("lang", lang.to_owned()));
+        }
+        if let Some(ref parent) = self.parent {
+            params.push(("parent", parent.to_owned()));
+        }
+        if let Some(ref refresh) = self.refresh {
+            params.push(("refresh", refresh.to_string()));
+        }
+        if let Some(ref retry_on_conflict) = self.retry_on_conflict {
+            params.push(("retry_on_conflict", retry_on_conflict.to_string()));
+        }
+        if let Some(ref routing) = self.routing {
+            params.push(("routing", routing.to_owned()));
+        }
+        if let Some(ref script) = self.script {
+            params.push(("script", script.to_owned()));
+        }
+        if let Some(ref script_id) = self.script_id {
+            params.push(("script_id", script_id.to_owned()));
+        }
+        if let Some(ref scripted_upsert) = self.scripted_upsert {
+            params.push(("scripted_upsert", scripted_upsert.to_string()));
+        }
+        if let Some(ref timeout) = self.timeout {
+            params.push(("timeout", timeout.to_owned()));
+        }
+        if let Some(ref timestamp) = self.timestamp {
+            params.push(("timestamp", timestamp.to_owned()));
+        }
+        if let Some(ref ttl) = self.ttl {
+            params.push(("ttl", ttl.to_owned()));
+        }
+        if let Some(ref version) = self.version {
+            params.push(("version", version.to_string()));
+        }
+        if let Some(ref version_type) = self.version_type {
+            params.push(("version_type", version_type.as_str().to_owned()));
+        }
+        params
+    }
+}
+
+/// Options for the `consistency` url parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Consistency {
+    /// `one`
+    One,
+    /// `quorum`
+    Quorum,
+    /// `all`
+    All,
+}
+
+impl Consistency {
+    /// Get the value of the option as it's sent in the url.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Consistency::One => "one",
+            Consistency::Quorum => "quorum",
+            Consistency::All => "all",
+        }
+    }
+}
+
+/// Options for the `version_type` url parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VersionType {
+    /// `internal`
+    Internal,
+    /// `force`
+    Force,
+}
+
+impl VersionType {
+    /// Get the value of the option as it's sent in the url.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            VersionType::Internal => "internal",
+            VersionType::Force => "force",
+        }
+    }
+}
 
 pub fn post_index_type_id<'a,
                       I: Into<Body<'a>>>(client: &'a mut Client,
-                                         req: &'a RequestParams,
+                                         req: &'a RequestParams, params: &'a Params,
                                          index: &'a str, _type: &'a str,
                                          id: &'a str, body: I)
  -> Result<Response>{
-    let url_qry = &req.get_url_qry();
+    let url_qry = &req.get_url_qry_with(params);
     let base = &req.base_url;
+    let index = &encode_path_part(index);
+    let _type = &encode_path_part(_type);
+    let id = &encode_path_part(id);
     let mut url_fmtd =
         String::with_capacity(base.len() + 1 + 1 + 1 + 8 + index.len() +
                                   _type.len() + id.len() + url_qry.len());
@@ -34,3 +168,26 @@ pub fn post_index_type_id<'a,
         client.post(&url_fmtd).headers(req.headers.to_owned()).body(body.into());
     res.send()
 }
+pub fn post_index_type_id_request<'a, B: AsRef<[u8]>>(req: &'a RequestParams,
+                                                      params: &'a Params, index: &'a str,
+                                                      _type: &'a str, id: &'a str, body: B)
+ -> HttpRequest{
+    let url_qry = &req.get_url_qry_with(params);
+    let base = &req.base_url;
+    let index = &encode_path_part(index);
+    let _type = &encode_path_part(_type);
+    let id = &encode_path_part(id);
+    let mut url_fmtd =
+        String::with_capacity(base.len() + 1 + 1 + 1 + 8 + index.len() +
+                                  _type.len() + id.len() + url_qry.len());
+    url_fmtd.push_str(base);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(index);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(_type);
+    url_fmtd.push_str("/");
+    url_fmtd.push_str(id);
+    url_fmtd.push_str("/_update");
+    url_fmtd.push_str(url_qry);
+    HttpRequest::new(Method::Post, url_fmtd, req.headers.to_owned()).body(body)
+}
@@ -5,7 +5,7 @@
 //! Each API endpoint is represented as its own function,
 //! so each possible http route gets its own function.
 //! The functions are also designed to work well with the `elastic_types`
-//! and `json_str` crates, but deserialisation is the responsibility of the caller.
+//! and `json_str` crates. Deserialisation is the responsibility of the caller, unless the `serde-response` feature is enabled.
 //!
 //! # Usage
 //!
@@ -34,34 +34,92 @@
 //! let mut client = hyper::Client::new();
 //!
 //! //HTTP HEAD /
-//! elastic::ping::head(&mut client, &elastic::RequestParams::default()).unwrap();
+//! elastic::ping::head(&mut client, &elastic::RequestParams::default(), &elastic::ping::Params).unwrap();
 //! # }
 //! ```
 //!
-//! Ping the availability of your cluster:
+//! Search with a query string:
 //!
 //! ```no_run
 //! # extern crate hyper;
 //! # extern crate elastic_hyper as elastic;
 //! # fn main() {
 //! let mut client = hyper::Client::new();
-//! let mut params = elastic::RequestParams::default()
-//! 	.url_params(vec![
-//! 		("q", "'my string'".to_owned())
-//! 	]);
+//! let params = elastic::search::Params {
+//! 	q: Some("'my string'".to_owned()),
+//! 	..Default::default()
+//! };
 //!
 //! //HTTP GET /myindex/mytype/_search?q='my string'
-//! elastic::search::get_index_type(&mut client, &params, "myindex", "mytype").unwrap();
+//! elastic::search::get_index_type(&mut client, &elastic::RequestParams::default(), &params, "myindex", "mytype").unwrap();
 //! # }
 //! ```
 //!
+//! Path parts like `index` and `id` are percent-encoded, so they can contain any characters.
+//! Parts that take a list of values, like `index` for a search, also accept slices such as `&["index1", "index2"]`.
+//!
+//! Each endpoint module has a `Params` struct for the url parameters it supports.
+//! Options are represented as enums, like `elastic::search::SearchType`.
+//! Parameters that apply to every endpoint, like `pretty`, can be set on the `RequestParams` instead.
+//!
+//! # Transports
+//!
+//! Each endpoint function also has a `_request` counterpart that returns an [`HttpRequest`](transport/struct.HttpRequest.html)
+//! instead of sending it, so the same requests can be sent through other http stacks with the
+//! [`Transport`](transport/trait.Transport.html) trait:
+//!
+//! ```no_run
+//! # extern crate hyper;
+//! # extern crate elastic_hyper as elastic;
+//! # fn main() {
+//! use elastic::Transport;
+//!
+//! let mut client = hyper::Client::new();
+//!
+//! //HTTP HEAD /
+//! let req = elastic::ping::head_request(&elastic::RequestParams::default(), &elastic::ping::Params);
+//! client.send(req).unwrap();
+//! # }
+//! ```
+//!
+//! # Clusters
+//!
+//! A [`NodePool`](pool/struct.NodePool.html) spreads requests across several nodes in round-robin order,
+//! skipping nodes that can't be reached and retrying idempotent requests on another node.
+//! See the [`pool`](pool/index.html) module for more details.
+//!
+//! With the `serde-response` feature enabled, the nodes in a pool can be discovered from the cluster itself.
+//! See the [`sniff`](sniff/index.html) module for more details.
+//!
+//! # Responses
+//!
+//! With the `serde-response` feature enabled, responses can be deserialised into typed structures
+//! using the [`ResponseExt`](response/trait.ResponseExt.html) trait:
+//!
+//! ```ignore
+//! [dependencies.elastic_hyper]
+//! version = "*"
+//! features = [ "serde-response" ]
+//! ```
+//!
+//! See the [`response`](response/index.html) module for more details.
+//!
 //! # Links
 //! - [elastic_types](http://kodraus.github.io/rustdoc/elastic_types/index.html)
 //! - [json_str](http://kodraus.github.io/rustdoc/json_str/index.html)
 //! - [Github](https://github.com/KodrAus/elasticsearch-rs)
 
+#![cfg_attr(feature = "serde-response", feature(custom_derive, plugin))]
+#![cfg_attr(feature = "serde-response", plugin(serde_macros))]
+
 extern crate hyper;
 extern crate url;
+#[cfg(feature = "serde-response")]
+extern crate serde;
+#[cfg(feature = "serde-response")]
+extern crate serde_json;
+#[cfg(feature = "serde-response")]
+extern crate elastic_types;
 
 use std::collections::BTreeMap;
 use hyper::header::Headers;
@@ -156,8 +214,30 @@ impl RequestParams {
 	///
 	/// Follows the `application/x-www-form-urlencoded` format.
 	pub fn get_url_qry(&self) -> String {
-		if self.url_params.len() > 0 {
-			let qry = serialize(self.url_params.iter());
+		Self::fmt_url_qry(&self.url_params)
+	}
+
+	/// Get the url params merged with the typed params for an endpoint as a formatted string.
+	///
+	/// Where a parameter is set in both places, the value from `params` is used.
+	pub fn get_url_qry_with<P>(&self, params: &P) -> String
+	where P: UrlParams {
+		let typed = params.url_params();
+		if typed.len() == 0 {
+			return self.get_url_qry();
+		}
+
+		let mut url_params = self.url_params.clone();
+		for (k, v) in typed {
+			url_params.insert(k, v);
+		}
+
+		Self::fmt_url_qry(&url_params)
+	}
+
+	fn fmt_url_qry(url_params: &BTreeMap<&'static str, String>) -> String {
+		if url_params.len() > 0 {
+			let qry = serialize(url_params.iter());
 			let mut url_qry = String::with_capacity(qry.len() + 1);
 
 			url_qry.push('?');
@@ -171,11 +251,35 @@ impl RequestParams {
 	}
 }
 
+/// Strongly-typed url parameters for an endpoint.
+///
+/// Each endpoint module contains a generated `Params` struct that implements this trait.
+pub trait UrlParams {
+	/// Get the set parameters as key-value pairs.
+	fn url_params(&self) -> Vec<(&'static str, String)>;
+}
+
 impl Default for RequestParams {
 	fn default() -> Self {
 		RequestParams::new("http://localhost:9200", Headers::new())
 	}
 }
 
+mod parts;
+pub use parts::{ ListPart, encode_path_part };
+
 mod api;
 pub use api::*;
+
+pub mod transport;
+pub use transport::{ HttpRequest, Transport };
+
+pub mod pool;
+pub use pool::NodePool;
+
+#[cfg(feature = "serde-response")]
+pub mod response;
+#[cfg(feature = "serde-response")]
+pub mod request;
+#[cfg(feature = "serde-response")]
+pub mod sniff;
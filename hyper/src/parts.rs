@@ -0,0 +1,122 @@
+use std::borrow::Cow;
+
+/// Percent-encode a value for use as a url path part.
+///
+/// Every byte except unreserved characters (`A-Z`, `a-z`, `0-9`, `-`, `.`, `_`, `~`),
+/// commas and wildcards (`*`) is encoded, so values can contain characters like `/`, `?`, `#` or spaces.
+/// The generated endpoint functions encode their path parts with this,
+/// so values passed to them shouldn't be encoded beforehand.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate elastic_hyper as elastic;
+/// # fn main() {
+/// assert_eq!("a%2Fb%3Fc%23d%20e", elastic::encode_path_part("a/b?c#d e"));
+/// assert_eq!("logs-*", elastic::encode_path_part("logs-*"));
+/// # }
+/// ```
+pub fn encode_path_part(part: &str) -> Cow<str> {
+	if part.bytes().all(is_safe) {
+		return Cow::Borrowed(part);
+	}
+
+	let mut encoded = String::with_capacity(part.len() * 3);
+	for b in part.bytes() {
+		if is_safe(b) {
+			encoded.push(b as char);
+		}
+		else {
+			encoded.push_str(&format!("%{:02X}", b));
+		}
+	}
+
+	Cow::Owned(encoded)
+}
+
+fn is_safe(b: u8) -> bool {
+	match b {
+		b'-' | b'.' | b'_' | b'~' | b',' | b'*' => true,
+		b => (b >= b'A' && b <= b'Z') || (b >= b'a' && b <= b'z') || (b >= b'0' && b <= b'9')
+	}
+}
+
+/// A url path part that takes a list of values, like `index` or `type`.
+///
+/// List parts accept a single value, like `"myindex"`, or a slice of values, like `&["logs-1", "logs-2"]`.
+/// Each value is percent-encoded, and multiple values are joined with commas.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate elastic_hyper as elastic;
+/// # fn main() {
+/// use elastic::ListPart;
+///
+/// assert_eq!("myindex", "myindex".to_path_part());
+/// assert_eq!("logs-1,logs-2", (&["logs-1", "logs-2"]).to_path_part());
+/// # }
+/// ```
+pub trait ListPart {
+	/// Get the values as a percent-encoded, comma-separated path part.
+	fn to_path_part(&self) -> Cow<str>;
+}
+
+impl<'a> ListPart for &'a str {
+	fn to_path_part(&self) -> Cow<str> {
+		encode_path_part(self)
+	}
+}
+
+impl<'a> ListPart for &'a String {
+	fn to_path_part(&self) -> Cow<str> {
+		encode_path_part(self)
+	}
+}
+
+impl<'a, 'b> ListPart for &'a [&'b str] {
+	fn to_path_part(&self) -> Cow<str> {
+		join_parts(self.iter())
+	}
+}
+
+impl<'a> ListPart for &'a [String] {
+	fn to_path_part(&self) -> Cow<str> {
+		join_parts(self.iter())
+	}
+}
+
+impl<'a, 'b> ListPart for &'a Vec<&'b str> {
+	fn to_path_part(&self) -> Cow<str> {
+		join_parts(self.iter())
+	}
+}
+
+impl<'a> ListPart for &'a Vec<String> {
+	fn to_path_part(&self) -> Cow<str> {
+		join_parts(self.iter())
+	}
+}
+
+macro_rules! impl_list_part_array {
+	($($n:expr),*) => {
+		$(
+			impl<'a, 'b> ListPart for &'a [&'b str; $n] {
+				fn to_path_part(&self) -> Cow<str> {
+					join_parts(self.iter())
+				}
+			}
+		)*
+	}
+}
+
+impl_list_part_array!(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+
+fn join_parts<I, S>(parts: I) -> Cow<'static, str>
+where I: Iterator<Item=S>, S: AsRef<str> {
+	let encoded: Vec<String> = parts
+		.map(|part| encode_path_part(part.as_ref()).into_owned())
+		.collect();
+
+	Cow::Owned(encoded.join(","))
+}
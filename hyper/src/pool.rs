@@ -0,0 +1,290 @@
+//! Spread requests across the nodes in a cluster.
+//!
+//! A [`NodePool`](struct.NodePool.html) holds the base urls for a set of nodes and hands them out in round-robin order.
+//! Nodes that fail with a connection error are marked dead and skipped until their resurrection timeout passes,
+//! when they're given another chance.
+//!
+//! The pool works with the generated endpoint functions by setting the `base_url` on a copy of your `RequestParams`.
+//! Requests built with the `_request` functions can be sent with [`NodePool::send`](struct.NodePool.html#method.send),
+//! which also retries idempotent requests on another node when a node can't be reached.
+//! Requests sent directly with a `hyper::Client` can use [`NodePool::call`](struct.NodePool.html#method.call),
+//! which marks failed nodes dead but doesn't retry, because it can't tell whether the request is safe to send twice.
+//!
+//! # Examples
+//!
+//! Search on the next available node, retrying on another if it's down:
+//!
+//! ```no_run
+//! # extern crate hyper;
+//! # extern crate elastic_hyper as elastic;
+//! # fn main() {
+//! use elastic::NodePool;
+//!
+//! let pool = NodePool::new(vec!["http://es1:9200", "http://es2:9200", "http://es3:9200"]);
+//! let mut client = hyper::Client::new();
+//!
+//! let res = pool.send(&mut client, &elastic::RequestParams::default(), |req| {
+//! 	elastic::search::get_index_request(req, &elastic::search::Params::default(), "myindex")
+//! })
+//! .unwrap();
+//! # }
+//! ```
+//!
+//! Call an endpoint function directly:
+//!
+//! ```no_run
+//! # extern crate hyper;
+//! # extern crate elastic_hyper as elastic;
+//! # fn main() {
+//! # let pool = elastic::NodePool::new(vec!["http://es1:9200"]);
+//! let mut client = hyper::Client::new();
+//!
+//! let res = pool.call(&elastic::RequestParams::default(), |req| {
+//! 	elastic::ping::head(&mut client, req, &elastic::ping::Params)
+//! })
+//! .unwrap();
+//! # }
+//! ```
+
+use std::sync::Mutex;
+use std::sync::atomic::{ AtomicUsize, Ordering };
+use std::time::{ Duration, Instant };
+use hyper::client::response::Response;
+use hyper::error::{ Error, Result };
+use hyper::method::Method;
+use ::{ HttpRequest, RequestParams, Transport };
+
+/// The default time a node stays dead before it's tried again.
+pub const DEFAULT_RESURRECT_AFTER_SECS: u64 = 60;
+
+#[derive(Debug, Clone)]
+struct Node {
+	base_url: String,
+	dead_until: Option<Instant>
+}
+
+/// A set of nodes to send requests to.
+///
+/// Nodes are picked in round-robin order, skipping any that are dead.
+/// If every node is dead, the one that's due to be resurrected first is used, so requests aren't refused outright.
+///
+/// A `NodePool` can be shared between threads.
+#[derive(Debug)]
+pub struct NodePool {
+	nodes: Mutex<Vec<Node>>,
+	next: AtomicUsize,
+	resurrect_after: Duration,
+	max_retries: Option<usize>
+}
+
+impl NodePool {
+	/// Create a pool for the given base urls, like `http://localhost:9200`.
+	///
+	/// # Panics
+	///
+	/// Panics if `base_urls` is empty.
+	pub fn new<I, S>(base_urls: I) -> Self
+	where I: IntoIterator<Item=S>, S: Into<String> {
+		let nodes: Vec<Node> = base_urls
+			.into_iter()
+			.map(|url| Node {
+				base_url: url.into(),
+				dead_until: None
+			})
+			.collect();
+
+		assert!(nodes.len() > 0, "a node pool needs at least one node");
+
+		NodePool {
+			nodes: Mutex::new(nodes),
+			next: AtomicUsize::new(0),
+			resurrect_after: Duration::from_secs(DEFAULT_RESURRECT_AFTER_SECS),
+			max_retries: None
+		}
+	}
+
+	/// Set how long a node stays dead after a connection error before it's tried again.
+	///
+	/// The default is 60 seconds.
+	pub fn resurrect_after(mut self, timeout: Duration) -> Self {
+		self.resurrect_after = timeout;
+
+		self
+	}
+
+	/// Set how many times an idempotent request is retried on another node.
+	///
+	/// By default, a request is tried at most once on each node.
+	pub fn max_retries(mut self, retries: usize) -> Self {
+		self.max_retries = Some(retries);
+
+		self
+	}
+
+	/// The base urls of all nodes in the pool, dead or alive.
+	pub fn nodes(&self) -> Vec<String> {
+		self.lock().iter().map(|n| n.base_url.clone()).collect()
+	}
+
+	/// The base urls of the nodes that aren't currently dead.
+	pub fn live_nodes(&self) -> Vec<String> {
+		let now = Instant::now();
+
+		self.lock()
+			.iter()
+			.filter(|n| is_alive(n, now))
+			.map(|n| n.base_url.clone())
+			.collect()
+	}
+
+	/// Replace the nodes in the pool.
+	///
+	/// Nodes that were already in the pool keep their dead or alive state.
+	/// Requests that are already in flight aren't affected, because they've been given their own copy of the base url.
+	/// An empty set of nodes is ignored, so the pool always has a node to send requests to.
+	pub fn set_nodes<I, S>(&self, base_urls: I)
+	where I: IntoIterator<Item=S>, S: Into<String> {
+		let mut nodes = self.lock();
+
+		let new_nodes: Vec<Node> = base_urls
+			.into_iter()
+			.map(|url| {
+				let base_url = url.into();
+				let dead_until = nodes.iter()
+					.find(|n| n.base_url == base_url)
+					.and_then(|n| n.dead_until);
+
+				Node {
+					base_url: base_url,
+					dead_until: dead_until
+				}
+			})
+			.collect();
+
+		if new_nodes.len() > 0 {
+			*nodes = new_nodes;
+		}
+	}
+
+	/// Get the base url of the next node to send a request to.
+	pub fn next(&self) -> String {
+		let nodes = self.lock();
+
+		let now = Instant::now();
+		let start = self.next.fetch_add(1, Ordering::SeqCst);
+
+		let live = (0..nodes.len())
+			.map(|i| &nodes[(start + i) % nodes.len()])
+			.find(|n| is_alive(n, now));
+
+		let node = match live {
+			Some(node) => node,
+			None => nodes.iter().min_by_key(|n| n.dead_until).expect("pool isn't empty")
+		};
+
+		node.base_url.clone()
+	}
+
+	/// Get a copy of `req` with the `base_url` of the next node.
+	pub fn params(&self, req: &RequestParams) -> RequestParams {
+		let mut req = req.clone();
+		req.base_url = self.next();
+
+		req
+	}
+
+	/// Mark a node as dead, so it's skipped until its resurrection timeout passes.
+	pub fn mark_dead(&self, base_url: &str) {
+		let dead_until = Instant::now() + self.resurrect_after;
+
+		for node in self.lock().iter_mut().filter(|n| n.base_url == base_url) {
+			node.dead_until = Some(dead_until);
+		}
+	}
+
+	/// Mark a node as alive.
+	pub fn mark_alive(&self, base_url: &str) {
+		for node in self.lock().iter_mut().filter(|n| n.base_url == base_url) {
+			node.dead_until = None;
+		}
+	}
+
+	/// Build a request for the next node and send it.
+	///
+	/// The `build` closure is given a copy of `req` with the `base_url` of the node to use,
+	/// and should return the request from one of the `_request` endpoint functions.
+	/// A connection error from the transport, as decided by `Transport::is_connection_error`, marks the node dead.
+	/// Requests with an idempotent method (`GET`, `HEAD`, `PUT` or `DELETE`) are then built and sent again for another node.
+	/// Other errors mean the node was reached, so they're returned straight away.
+	///
+	/// Returns the last error if every attempt fails.
+	pub fn send<T, F>(&self, transport: &mut T, req: &RequestParams, mut build: F) -> ::std::result::Result<T::Response, T::Error>
+	where T: Transport, F: FnMut(&RequestParams) -> HttpRequest {
+		let max_retries = self.max_retries.unwrap_or_else(|| self.lock().len().saturating_sub(1));
+		let mut retries = 0;
+
+		loop {
+			let node_req = self.params(req);
+
+			let http_req = build(&node_req);
+			let idempotent = is_idempotent(&http_req.method);
+
+			match transport.send(http_req) {
+				Ok(res) => {
+					self.mark_alive(&node_req.base_url);
+					return Ok(res);
+				},
+				Err(e) => {
+					if !transport.is_connection_error(&e) {
+						return Err(e);
+					}
+
+					self.mark_dead(&node_req.base_url);
+
+					if !idempotent || retries >= max_retries {
+						return Err(e);
+					}
+
+					retries += 1;
+				}
+			}
+		}
+	}
+
+	/// Call an endpoint function on the next node.
+	///
+	/// The `call` closure is given a copy of `req` with the `base_url` of the node to use.
+	/// If it fails with an io error, the node is marked dead.
+	/// The request isn't retried.
+	pub fn call<F>(&self, req: &RequestParams, mut call: F) -> Result<Response>
+	where F: FnMut(&RequestParams) -> Result<Response> {
+		let node_req = self.params(req);
+		let res = call(&node_req);
+
+		match res {
+			Ok(_) => self.mark_alive(&node_req.base_url),
+			Err(Error::Io(_)) => self.mark_dead(&node_req.base_url),
+			Err(_) => ()
+		}
+
+		res
+	}
+
+	fn lock(&self) -> ::std::sync::MutexGuard<Vec<Node>> {
+		self.nodes.lock().unwrap_or_else(|e| e.into_inner())
+	}
+}
+
+fn is_alive(node: &Node, now: Instant) -> bool {
+	match node.dead_until {
+		Some(dead_until) => dead_until <= now,
+		None => true
+	}
+}
+
+fn is_idempotent(method: &Method) -> bool {
+	match *method {
+		Method::Get | Method::Head | Method::Put | Method::Delete => true,
+		_ => false
+	}
+}
@@ -0,0 +1,277 @@
+use serde;
+use serde::{ Serialize, Serializer };
+use serde_json;
+use serde_json::Error as JsonError;
+use ::response::{ BulkAction, BulkItem, BulkResponse };
+
+/// A request body for the Bulk API.
+///
+/// Operations are rendered in the newline-delimited format expected by Elasticsearch as they're added.
+/// Each operation is a line of action metadata, optionally followed by a line for the document source.
+///
+/// # Examples
+///
+/// Build a bulk request with a few operations:
+///
+/// ```no_run
+/// # #![feature(plugin, custom_derive)]
+/// # #![plugin(serde_macros)]
+/// # extern crate hyper;
+/// # extern crate serde;
+/// # extern crate elastic_hyper as elastic;
+/// # fn main() {
+/// use elastic::request::{ BulkRequest, BulkMeta };
+/// use elastic::response::{ ResponseExt, BulkResponse };
+///
+/// #[derive(Serialize)]
+/// struct MyType {
+/// 	title: String
+/// }
+///
+/// let mut req = BulkRequest::new();
+/// req.index(BulkMeta::new().index("myindex").doc_type("mytype").id("1"), &MyType { title: "doc".to_owned() }).unwrap();
+/// req.delete(BulkMeta::new().index("myindex").doc_type("mytype").id("2"));
+///
+/// let mut client = hyper::Client::new();
+/// let res: BulkResponse = elastic::bulk::post(
+/// 	&mut client, &elastic::RequestParams::default(), &elastic::bulk::Params::default(),
+/// 	&req.body()
+/// )
+/// .unwrap()
+/// .into_response()
+/// .unwrap();
+///
+/// //Retry just the operations that failed
+/// let retry = req.failed(&res);
+/// # }
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct BulkRequest {
+	ops: Vec<String>
+}
+
+impl BulkRequest {
+	/// Create a new, empty bulk request.
+	pub fn new() -> BulkRequest {
+		BulkRequest {
+			ops: Vec::new()
+		}
+	}
+
+	/// Index a document, replacing it if it already exists.
+	pub fn index<T>(&mut self, meta: BulkMeta, doc: &T) -> Result<&mut BulkRequest, JsonError> where
+	T: Serialize {
+		self.push_op(BulkAction::Index, &meta, Some(doc))
+	}
+
+	/// Index a document, failing if it already exists.
+	pub fn create<T>(&mut self, meta: BulkMeta, doc: &T) -> Result<&mut BulkRequest, JsonError> where
+	T: Serialize {
+		self.push_op(BulkAction::Create, &meta, Some(doc))
+	}
+
+	/// Update a document.
+	///
+	/// The `update` is the body of an update request, like `{ "doc": { ... } }` or `{ "script": ... }`.
+	pub fn update<T>(&mut self, meta: BulkMeta, update: &T) -> Result<&mut BulkRequest, JsonError> where
+	T: Serialize {
+		self.push_op(BulkAction::Update, &meta, Some(update))
+	}
+
+	/// Delete a document.
+	pub fn delete(&mut self, meta: BulkMeta) -> &mut BulkRequest {
+		self.push_op::<()>(BulkAction::Delete, &meta, None).unwrap()
+	}
+
+	/// The number of operations in the request.
+	pub fn len(&self) -> usize {
+		self.ops.len()
+	}
+
+	/// Whether or not the request has any operations.
+	pub fn is_empty(&self) -> bool {
+		self.ops.is_empty()
+	}
+
+	/// The size of the request body in bytes.
+	pub fn byte_len(&self) -> usize {
+		self.ops.iter().fold(0, |len, op| len + op.len())
+	}
+
+	/// Get the request body.
+	///
+	/// The body always ends with a newline.
+	pub fn body(&self) -> String {
+		let mut body = String::with_capacity(self.byte_len());
+		for op in &self.ops {
+			body.push_str(op);
+		}
+
+		body
+	}
+
+	/// Get a new request containing the operations that failed in the given response.
+	///
+	/// Items in a bulk response are in the same order as the operations in the request.
+	pub fn failed(&self, res: &BulkResponse) -> BulkRequest {
+		self.filter(res, |item| !item.is_ok())
+	}
+
+	/// Get a new request containing the operations whose response items match a predicate.
+	pub fn filter<F>(&self, res: &BulkResponse, f: F) -> BulkRequest where
+	F: Fn(&BulkItem) -> bool {
+		BulkRequest {
+			ops: self.ops
+				.iter()
+				.zip(res.items.iter())
+				.filter(|&(_, item)| f(item))
+				.map(|(op, _)| op.clone())
+				.collect()
+		}
+	}
+
+	fn push_op<T>(&mut self, action: BulkAction, meta: &BulkMeta, doc: Option<&T>) -> Result<&mut BulkRequest, JsonError> where
+	T: Serialize {
+		let mut op = try!(serde_json::to_string(&BulkActionLine {
+			action: action,
+			meta: meta
+		}));
+		op.push('\n');
+
+		if let Some(doc) = doc {
+			op.push_str(&try!(serde_json::to_string(doc)));
+			op.push('\n');
+		}
+
+		self.ops.push(op);
+
+		Ok(self)
+	}
+}
+
+/// Metadata for an operation in a bulk request.
+///
+/// The `index` and `doc_type` can be omitted if they're given in the url of the request.
+#[derive(Debug, Default, Clone)]
+pub struct BulkMeta {
+	index: Option<String>,
+	doc_type: Option<String>,
+	id: Option<String>,
+	routing: Option<String>,
+	parent: Option<String>,
+	version: Option<u64>
+}
+
+impl BulkMeta {
+	/// Create a new, empty set of metadata.
+	pub fn new() -> BulkMeta {
+		BulkMeta::default()
+	}
+
+	/// Set the `_index` for the operation.
+	pub fn index<I: Into<String>>(mut self, index: I) -> BulkMeta {
+		self.index = Some(index.into());
+		self
+	}
+
+	/// Set the `_type` for the operation.
+	pub fn doc_type<I: Into<String>>(mut self, doc_type: I) -> BulkMeta {
+		self.doc_type = Some(doc_type.into());
+		self
+	}
+
+	/// Set the `_id` for the operation.
+	pub fn id<I: Into<String>>(mut self, id: I) -> BulkMeta {
+		self.id = Some(id.into());
+		self
+	}
+
+	/// Set the `_routing` value for the operation.
+	pub fn routing<I: Into<String>>(mut self, routing: I) -> BulkMeta {
+		self.routing = Some(routing.into());
+		self
+	}
+
+	/// Set the `_parent` id for the operation.
+	pub fn parent<I: Into<String>>(mut self, parent: I) -> BulkMeta {
+		self.parent = Some(parent.into());
+		self
+	}
+
+	/// Set the expected `_version` for the operation.
+	pub fn version(mut self, version: u64) -> BulkMeta {
+		self.version = Some(version);
+		self
+	}
+}
+
+struct BulkActionLine<'a> {
+	action: BulkAction,
+	meta: &'a BulkMeta
+}
+
+impl <'a> Serialize for BulkActionLine<'a> {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_map(BulkActionLineVisitor {
+			line: self
+		})
+	}
+}
+
+struct BulkActionLineVisitor<'a> {
+	line: &'a BulkActionLine<'a>
+}
+
+impl <'a> serde::ser::MapVisitor for BulkActionLineVisitor<'a> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> where
+	S: Serializer {
+		try!(serializer.serialize_map_elt(self.line.action.as_str(), self.line.meta));
+
+		Ok(None)
+	}
+}
+
+impl Serialize for BulkMeta {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_struct("meta", BulkMetaVisitor {
+			meta: self
+		})
+	}
+}
+
+struct BulkMetaVisitor<'a> {
+	meta: &'a BulkMeta
+}
+
+impl <'a> serde::ser::MapVisitor for BulkMetaVisitor<'a> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> where
+	S: Serializer {
+		if let Some(ref index) = self.meta.index {
+			try!(serializer.serialize_struct_elt("_index", index));
+		}
+
+		if let Some(ref doc_type) = self.meta.doc_type {
+			try!(serializer.serialize_struct_elt("_type", doc_type));
+		}
+
+		if let Some(ref id) = self.meta.id {
+			try!(serializer.serialize_struct_elt("_id", id));
+		}
+
+		if let Some(ref routing) = self.meta.routing {
+			try!(serializer.serialize_struct_elt("_routing", routing));
+		}
+
+		if let Some(ref parent) = self.meta.parent {
+			try!(serializer.serialize_struct_elt("_parent", parent));
+		}
+
+		if let Some(version) = self.meta.version {
+			try!(serializer.serialize_struct_elt("_version", version));
+		}
+
+		Ok(None)
+	}
+}
@@ -0,0 +1,392 @@
+use std::cmp;
+use std::error;
+use std::fmt;
+use std::mem;
+use std::thread;
+use std::time::{ Duration, Instant };
+use hyper::client::Client;
+use serde::Serialize;
+use serde_json::Error as JsonError;
+use ::{ RequestParams, bulk };
+use ::response::{ ApiError, BulkItem, BulkResponse, ResponseExt };
+use super::{ BulkRequest, BulkMeta };
+
+const REJECTED_EXECUTION: &'static str = "es_rejected_execution_exception";
+
+/// Statistics for a single flush of a `BulkProcessor`.
+#[derive(Debug)]
+pub struct BulkStats {
+	/// The number of operations that were flushed.
+	pub docs: usize,
+	/// The size of the flushed request body in bytes.
+	pub bytes: usize,
+	/// The number of operations that succeeded.
+	pub succeeded: usize,
+	/// The number of times rejected operations were retried.
+	pub retries: u32,
+	/// The time taken to complete the flush, including any retries.
+	pub elapsed: Duration,
+	/// The items for operations that failed, including rejected operations that ran out of retries.
+	pub failed: Vec<BulkItem>
+}
+
+/// Accepts documents one at a time and sends them to the Bulk API in batches.
+///
+/// The pending batch is flushed when any of the following are reached:
+///
+/// - the maximum number of operations, set by `max_docs`
+/// - the maximum body size in bytes, set by `max_bytes`
+/// - the flush interval, set by `flush_interval`
+///
+/// There's no background thread, so the flush interval is checked whenever an operation is added.
+/// A processor that might sit idle should also call `flush_if_due` periodically,
+/// so pending operations aren't held for longer than the interval.
+///
+/// Call `flush` to send the pending batch, and `close` when you're done.
+/// If a flush fails, its operations are kept and sent with the next flush.
+/// If it fails while retrying rejected operations, the statistics for the operations that were already handled
+/// are still passed to `on_flush`, and only the operations being retried are kept.
+/// Dropping a processor without calling `close` discards any pending operations.
+///
+/// Operations that Elasticsearch rejects because its bulk queue is full (`es_rejected_execution_exception` or HTTP `429`)
+/// are retried with an exponential backoff, up to a maximum delay.
+///
+/// # Examples
+///
+/// ```no_run
+/// # #![feature(plugin, custom_derive)]
+/// # #![plugin(serde_macros)]
+/// # extern crate hyper;
+/// # extern crate serde;
+/// # extern crate elastic_hyper as elastic;
+/// # fn main() {
+/// use std::time::Duration;
+/// use elastic::request::{ BulkProcessor, BulkMeta };
+///
+/// #[derive(Serialize)]
+/// struct MyType {
+/// 	title: String
+/// }
+///
+/// let mut processor = BulkProcessor::new(hyper::Client::new(), elastic::RequestParams::default())
+/// 	.max_docs(500)
+/// 	.flush_interval(Duration::from_secs(5))
+/// 	.on_flush(|stats| println!("flushed {} docs, {} failed", stats.docs, stats.failed.len()));
+///
+/// for i in 0..10000 {
+/// 	let doc = MyType { title: format!("doc {}", i) };
+/// 	processor.index(BulkMeta::new().index("myindex").doc_type("mytype"), &doc).unwrap();
+/// }
+///
+/// processor.close().unwrap();
+/// # }
+/// ```
+pub struct BulkProcessor {
+	client: Client,
+	req: RequestParams,
+	pending: BulkRequest,
+	last_flush: Instant,
+	max_docs: usize,
+	max_bytes: usize,
+	flush_interval: Option<Duration>,
+	max_retries: u32,
+	backoff: Duration,
+	max_backoff: Duration,
+	on_flush: Option<Box<FnMut(&BulkStats)>>
+}
+
+impl BulkProcessor {
+	/// Create a new processor that sends requests using the given client and params.
+	///
+	/// By default, batches are flushed at `1000` operations or `5MB`, and rejected operations are retried `3` times
+	/// with an initial backoff of `100ms` and a maximum backoff of `30s`.
+	pub fn new(client: Client, req: RequestParams) -> BulkProcessor {
+		BulkProcessor {
+			client: client,
+			req: req,
+			pending: BulkRequest::new(),
+			last_flush: Instant::now(),
+			max_docs: 1000,
+			max_bytes: 5 * 1024 * 1024,
+			flush_interval: None,
+			max_retries: 3,
+			backoff: Duration::from_millis(100),
+			max_backoff: Duration::from_secs(30),
+			on_flush: None
+		}
+	}
+
+	/// Set the maximum number of operations in a batch.
+	pub fn max_docs(mut self, max_docs: usize) -> BulkProcessor {
+		self.max_docs = max_docs;
+		self
+	}
+
+	/// Set the maximum size of a batch body in bytes.
+	pub fn max_bytes(mut self, max_bytes: usize) -> BulkProcessor {
+		self.max_bytes = max_bytes;
+		self
+	}
+
+	/// Set the maximum time between flushes.
+	pub fn flush_interval(mut self, interval: Duration) -> BulkProcessor {
+		self.flush_interval = Some(interval);
+		self
+	}
+
+	/// Set the number of times rejected operations are retried.
+	pub fn max_retries(mut self, max_retries: u32) -> BulkProcessor {
+		self.max_retries = max_retries;
+		self
+	}
+
+	/// Set the initial backoff for retries. The backoff doubles for each retry.
+	pub fn backoff(mut self, backoff: Duration) -> BulkProcessor {
+		self.backoff = backoff;
+		self
+	}
+
+	/// Set the maximum time to wait between retries.
+	pub fn max_backoff(mut self, max_backoff: Duration) -> BulkProcessor {
+		self.max_backoff = max_backoff;
+		self
+	}
+
+	/// Set a callback that's given the statistics for each flush.
+	pub fn on_flush<F>(mut self, f: F) -> BulkProcessor where
+	F: FnMut(&BulkStats) + 'static {
+		self.on_flush = Some(Box::new(f));
+		self
+	}
+
+	/// Index a document, replacing it if it already exists.
+	pub fn index<T>(&mut self, meta: BulkMeta, doc: &T) -> Result<(), ApiError> where
+	T: Serialize {
+		try!(self.pending.index(meta, doc).map_err(|e: JsonError| ApiError::from(e)));
+		self.flush_if_full()
+	}
+
+	/// Index a document, failing if it already exists.
+	pub fn create<T>(&mut self, meta: BulkMeta, doc: &T) -> Result<(), ApiError> where
+	T: Serialize {
+		try!(self.pending.create(meta, doc).map_err(|e: JsonError| ApiError::from(e)));
+		self.flush_if_full()
+	}
+
+	/// Update a document.
+	pub fn update<T>(&mut self, meta: BulkMeta, update: &T) -> Result<(), ApiError> where
+	T: Serialize {
+		try!(self.pending.update(meta, update).map_err(|e: JsonError| ApiError::from(e)));
+		self.flush_if_full()
+	}
+
+	/// Delete a document.
+	pub fn delete(&mut self, meta: BulkMeta) -> Result<(), ApiError> {
+		self.pending.delete(meta);
+		self.flush_if_full()
+	}
+
+	/// The number of operations waiting to be flushed.
+	pub fn pending(&self) -> usize {
+		self.pending.len()
+	}
+
+	/// Send any pending operations.
+	///
+	/// If the request fails, the operations that weren't sent are kept and sent with the next flush.
+	pub fn flush(&mut self) -> Result<(), ApiError> {
+		self.last_flush = Instant::now();
+
+		if self.pending.is_empty() {
+			return Ok(());
+		}
+
+		let pending = mem::replace(&mut self.pending, BulkRequest::new());
+		let stats = match self.send(pending) {
+			Ok(stats) => stats,
+			Err((err, unsent)) => {
+				self.pending = unsent;
+				return Err(err);
+			}
+		};
+
+		self.report(&stats);
+
+		Ok(())
+	}
+
+	/// Flush the pending operations if the flush interval has passed since the last flush.
+	///
+	/// Operations are only checked against the interval as they're added,
+	/// so call this periodically to flush a processor that isn't receiving new operations.
+	pub fn flush_if_due(&mut self) -> Result<(), ApiError> {
+		if self.is_due() {
+			self.flush()
+		}
+		else {
+			Ok(())
+		}
+	}
+
+	/// Flush any pending operations and consume the processor.
+	///
+	/// If the flush fails, the operations that weren't sent are returned with the error.
+	pub fn close(mut self) -> Result<(), BulkCloseError> {
+		match self.flush() {
+			Ok(()) => Ok(()),
+			Err(err) => Err(BulkCloseError {
+				error: err,
+				unsent: mem::replace(&mut self.pending, BulkRequest::new())
+			})
+		}
+	}
+
+	fn is_due(&self) -> bool {
+		match self.flush_interval {
+			Some(interval) => self.last_flush.elapsed() >= interval,
+			None => false
+		}
+	}
+
+	fn flush_if_full(&mut self) -> Result<(), ApiError> {
+		if self.is_due() || self.pending.len() >= self.max_docs || self.pending.byte_len() >= self.max_bytes {
+			self.flush()
+		}
+		else {
+			Ok(())
+		}
+	}
+
+	//Returns the operations that haven't been sent yet along with any error
+	fn send(&mut self, mut pending: BulkRequest) -> Result<BulkStats, (ApiError, BulkRequest)> {
+		let start = Instant::now();
+
+		let mut stats = BulkStats {
+			docs: pending.len(),
+			bytes: pending.byte_len(),
+			succeeded: 0,
+			retries: 0,
+			elapsed: Duration::from_millis(0),
+			failed: Vec::new()
+		};
+
+		loop {
+			let res = match bulk::post(&mut self.client, &self.req, &bulk::Params::default(), &pending.body()) {
+				Ok(res) => res,
+				Err(err) => return Err(self.fail(ApiError::from(err), pending, stats, start))
+			};
+
+			let res: BulkResponse = match res.into_response() {
+				Ok(res) => res,
+				//The whole request was rejected, so retry all of it
+				Err(ApiError::Server(ref err)) if (err.status == 429 || err.error.ty == REJECTED_EXECUTION) && stats.retries < self.max_retries => {
+					self.wait(stats.retries);
+					stats.retries += 1;
+
+					continue;
+				},
+				Err(err) => return Err(self.fail(err, pending, stats, start))
+			};
+
+			let retry = pending.filter(&res, is_rejected);
+			let can_retry = retry.len() > 0 && stats.retries < self.max_retries;
+
+			for item in res.items {
+				if item.is_ok() {
+					stats.succeeded += 1;
+				}
+				else if !(can_retry && is_rejected(&item)) {
+					stats.failed.push(item);
+				}
+			}
+
+			if !can_retry {
+				break;
+			}
+
+			self.wait(stats.retries);
+			stats.retries += 1;
+
+			pending = retry;
+		}
+
+		stats.elapsed = start.elapsed();
+
+		Ok(stats)
+	}
+
+	//Reports the operations that were handled before a retry failed, and returns the rest to be sent with the next flush
+	fn fail(&mut self, err: ApiError, unsent: BulkRequest, mut stats: BulkStats, start: Instant) -> (ApiError, BulkRequest) {
+		if unsent.len() < stats.docs {
+			stats.docs -= unsent.len();
+			stats.bytes = stats.bytes.saturating_sub(unsent.byte_len());
+			stats.elapsed = start.elapsed();
+
+			self.report(&stats);
+		}
+
+		(err, unsent)
+	}
+
+	fn report(&mut self, stats: &BulkStats) {
+		if let Some(ref mut on_flush) = self.on_flush {
+			on_flush(stats);
+		}
+	}
+
+	fn wait(&self, retries: u32) {
+		thread::sleep(self.retry_delay(retries));
+	}
+
+	//The backoff doubles for each retry, without going over the maximum
+	fn retry_delay(&self, retries: u32) -> Duration {
+		let max = millis(self.max_backoff);
+
+		let delay = 1u64.checked_shl(retries)
+			.and_then(|factor| millis(self.backoff).checked_mul(factor))
+			.map(|delay| cmp::min(delay, max))
+			.unwrap_or(max);
+
+		Duration::from_millis(delay)
+	}
+}
+
+/// An error closing a `BulkProcessor`.
+///
+/// Contains the operations that couldn't be sent, so they can be retried or logged.
+#[derive(Debug)]
+pub struct BulkCloseError {
+	/// The error that stopped the final flush.
+	pub error: ApiError,
+	/// The operations that weren't sent.
+	pub unsent: BulkRequest
+}
+
+impl fmt::Display for BulkCloseError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{} ({} operations weren't sent)", self.error, self.unsent.len())
+	}
+}
+
+impl error::Error for BulkCloseError {
+	fn description(&self) -> &str {
+		"Error flushing the bulk processor"
+	}
+
+	fn cause(&self) -> Option<&error::Error> {
+		Some(&self.error)
+	}
+}
+
+fn millis(duration: Duration) -> u64 {
+	duration.as_secs()
+		.saturating_mul(1000)
+		.saturating_add(duration.subsec_nanos() as u64 / 1_000_000)
+}
+
+fn is_rejected(item: &BulkItem) -> bool {
+	match item.error {
+		Some(ref err) if err.ty == REJECTED_EXECUTION => true,
+		_ => item.status == 429
+	}
+}
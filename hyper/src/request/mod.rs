@@ -0,0 +1,9 @@
+//! Builders for request bodies.
+//!
+//! Requires the `serde-response` feature.
+
+mod bulk;
+mod bulk_processor;
+
+pub use self::bulk::*;
+pub use self::bulk_processor::*;
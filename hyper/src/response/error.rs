@@ -0,0 +1,109 @@
+use std::error;
+use std::fmt;
+use std::io::Error as IoError;
+use serde_json::Error as JsonError;
+use hyper::error::Error as HttpError;
+
+/// An error returned by an API request.
+///
+/// Either the request couldn't be sent, Elasticsearch returned an error response,
+/// or the response body couldn't be deserialised.
+#[derive(Debug)]
+pub enum ApiError {
+	/// The request couldn't be sent.
+	Request(HttpError),
+	/// An error response returned by Elasticsearch.
+	Server(ServerError),
+	/// The response body couldn't be deserialised.
+	Parse(JsonError)
+}
+
+impl fmt::Display for ApiError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match *self {
+			ApiError::Request(ref err) => write!(f, "Request error: {}", err),
+			ApiError::Server(ref err) => write!(f, "Server error: {}", err),
+			ApiError::Parse(ref err) => write!(f, "Parse error: {}", err)
+		}
+	}
+}
+
+impl error::Error for ApiError {
+	fn description(&self) -> &str {
+		match *self {
+			ApiError::Request(_) => "Error sending the request",
+			ApiError::Server(_) => "Elasticsearch returned an error",
+			ApiError::Parse(_) => "Error parsing the response body"
+		}
+	}
+
+	fn cause(&self) -> Option<&error::Error> {
+		match *self {
+			ApiError::Request(ref err) => Some(err),
+			ApiError::Server(_) => None,
+			ApiError::Parse(ref err) => Some(err)
+		}
+	}
+}
+
+impl From<JsonError> for ApiError {
+	fn from(err: JsonError) -> ApiError {
+		ApiError::Parse(err)
+	}
+}
+
+impl From<HttpError> for ApiError {
+	fn from(err: HttpError) -> ApiError {
+		ApiError::Request(err)
+	}
+}
+
+impl From<IoError> for ApiError {
+	fn from(err: IoError) -> ApiError {
+		ApiError::Parse(JsonError::from(err))
+	}
+}
+
+/// The body of an Elasticsearch error response.
+///
+/// Responses take the form `{ "error": { "type": ..., "reason": ..., "root_cause": [...] }, "status": ... }`.
+#[derive(Debug, Deserialize)]
+pub struct ServerError {
+	/// The HTTP status code for the error.
+	pub status: u16,
+	/// The cause of the error.
+	pub error: ErrorCause
+}
+
+impl fmt::Display for ServerError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{} {}", self.status, self.error)
+	}
+}
+
+/// The cause of an Elasticsearch error.
+#[derive(Debug, Deserialize)]
+pub struct ErrorCause {
+	/// The kind of error, like `index_not_found_exception`.
+	#[serde(rename="type")]
+	pub ty: String,
+	/// A description of the error.
+	pub reason: Option<String>,
+	/// The index the error relates to, if any.
+	pub index: Option<String>,
+	/// The shard the error relates to, if any.
+	pub shard: Option<String>,
+	/// The errors that ultimately caused this one.
+	pub root_cause: Option<Vec<ErrorCause>>,
+	/// The error that directly caused this one.
+	pub caused_by: Option<Box<ErrorCause>>
+}
+
+impl fmt::Display for ErrorCause {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self.reason {
+			Some(ref reason) => write!(f, "{}: {}", self.ty, reason),
+			None => write!(f, "{}", self.ty)
+		}
+	}
+}
@@ -0,0 +1,104 @@
+//! Typed responses for Elasticsearch endpoints.
+//!
+//! Requires the `serde-response` feature.
+//! Responses can be deserialised by calling `into_response` on a `hyper` response:
+//!
+//! ```no_run
+//! # extern crate hyper;
+//! # extern crate elastic_hyper as elastic;
+//! # fn main() {
+//! use elastic::response::*;
+//!
+//! let mut client = hyper::Client::new();
+//!
+//! //HTTP GET /myindex/_count
+//! let res: CountResponse = elastic::count::get_index(
+//! 	&mut client, &elastic::RequestParams::default(), &elastic::count::Params::default(), "myindex"
+//! )
+//! .unwrap()
+//! .into_response()
+//! .unwrap();
+//! # }
+//! ```
+//!
+//! If Elasticsearch returns an error, it's parsed into an `ApiError::Server`.
+//!
+//! Search results can be paged through lazily with a `ScrollIter`.
+
+mod error;
+mod responses;
+mod scroll;
+
+pub use self::error::*;
+pub use self::responses::*;
+pub use self::scroll::*;
+pub use elastic_types::response::{ SearchResponse, SearchHits, Hit, Shards, AggregationResults, AggregationResult, Bucket, MetricValue, Stats };
+
+use std::io::Read;
+use hyper::client::response::Response;
+use hyper::status::StatusCode;
+use serde::Deserialize;
+use serde_json;
+use serde_json::Value;
+
+/// Deserialise typed responses from a `hyper` response.
+pub trait ResponseExt {
+	/// Deserialise the response body as `T`.
+	///
+	/// Unsuccessful responses with an Elasticsearch error body are returned as an `ApiError::Server`.
+	fn into_response<T>(self) -> Result<T, ApiError> where
+	T: Deserialize;
+}
+
+impl ResponseExt for Response {
+	fn into_response<T>(self) -> Result<T, ApiError> where
+	T: Deserialize {
+		let status = self.status;
+
+		from_reader(status, self)
+	}
+}
+
+/// Deserialise a response body with the given status as `T`.
+///
+/// Not every unsuccessful status is treated as an error.
+/// For example, getting a document that doesn't exist returns a `404` with a regular body where `found` is `false`.
+/// The body is only returned as an `ApiError::Server` if it contains an `error` field.
+pub fn from_reader<T, R>(status: StatusCode, body: R) -> Result<T, ApiError> where
+T: Deserialize,
+R: Read {
+	let value: Value = try!(serde_json::from_reader(body));
+
+	if !status.is_success() {
+		if let Some(err) = value.find("error") {
+			return Err(ApiError::Server(try!(server_error(status, err, value.find("status")))));
+		}
+	}
+
+	serde_json::value::from_value(value).map_err(|e| e.into())
+}
+
+fn server_error(status: StatusCode, err: &Value, err_status: Option<&Value>) -> Result<ServerError, ApiError> {
+	let status = match err_status.and_then(|s| s.as_u64()) {
+		Some(s) => s as u16,
+		None => status.to_u16()
+	};
+
+	let error = match *err {
+		//Older versions of Elasticsearch return the error as a string
+		Value::String(ref reason) => ErrorCause {
+			ty: String::new(),
+			reason: Some(reason.to_owned()),
+			index: None,
+			shard: None,
+			root_cause: None,
+			caused_by: None
+		},
+		ref err => try!(serde_json::value::from_value(err.clone()))
+	};
+
+	Ok(ServerError {
+		status: status,
+		error: error
+	})
+}
@@ -0,0 +1,247 @@
+use std::collections::BTreeMap;
+use std::iter::Filter;
+use std::slice::Iter;
+use serde;
+use serde::{ Deserialize, Deserializer };
+use elastic_types::response::Shards;
+use super::ErrorCause;
+
+/// A response from the Get API.
+#[derive(Debug, Deserialize)]
+pub struct GetResponse<T> where
+T: Deserialize {
+	/// The index of the document.
+	#[serde(rename="_index")]
+	pub index: String,
+	/// The type of the document.
+	#[serde(rename="_type")]
+	pub doc_type: String,
+	/// The id of the document.
+	#[serde(rename="_id")]
+	pub id: String,
+	/// The version of the document, if it was found.
+	#[serde(rename="_version")]
+	pub version: Option<u64>,
+	/// Whether or not the document exists.
+	pub found: bool,
+	/// The source document data, if it was found.
+	#[serde(rename="_source")]
+	pub source: Option<T>,
+	/// The routing value of the document.
+	#[serde(rename="_routing")]
+	pub routing: Option<String>
+}
+
+/// A response from the Index API.
+#[derive(Debug, Deserialize)]
+pub struct IndexResponse {
+	/// The index the document was added to.
+	#[serde(rename="_index")]
+	pub index: String,
+	/// The type of the document.
+	#[serde(rename="_type")]
+	pub doc_type: String,
+	/// The id of the document.
+	#[serde(rename="_id")]
+	pub id: String,
+	/// The version of the document after indexing.
+	#[serde(rename="_version")]
+	pub version: u64,
+	/// Metadata on shard activity.
+	#[serde(rename="_shards")]
+	pub shards: Shards,
+	/// Whether the document was created, rather than updated.
+	pub created: bool
+}
+
+/// A response from the Delete API.
+#[derive(Debug, Deserialize)]
+pub struct DeleteResponse {
+	/// The index of the document.
+	#[serde(rename="_index")]
+	pub index: String,
+	/// The type of the document.
+	#[serde(rename="_type")]
+	pub doc_type: String,
+	/// The id of the document.
+	#[serde(rename="_id")]
+	pub id: String,
+	/// The version of the document after deletion.
+	#[serde(rename="_version")]
+	pub version: u64,
+	/// Metadata on shard activity.
+	#[serde(rename="_shards")]
+	pub shards: Shards,
+	/// Whether or not the document existed.
+	pub found: bool
+}
+
+/// A response from the Count API.
+#[derive(Debug, Deserialize)]
+pub struct CountResponse {
+	/// The number of documents matching the query.
+	pub count: u64,
+	/// Metadata on shard activity.
+	#[serde(rename="_shards")]
+	pub shards: Shards
+}
+
+/// A response from the Bulk API.
+#[derive(Debug, Deserialize)]
+pub struct BulkResponse {
+	/// The time taken to complete the request in ms.
+	pub took: u64,
+	/// Whether or not any of the items failed.
+	pub errors: bool,
+	/// The result of each operation, in the order they were sent.
+	pub items: Vec<BulkItem>
+}
+
+/// An iterator over a subset of the items in a `BulkResponse`.
+pub type BulkItems<'a> = Filter<Iter<'a, BulkItem>, fn(&&BulkItem) -> bool>;
+
+impl BulkResponse {
+	/// Get the items for operations that succeeded.
+	pub fn items_ok(&self) -> BulkItems {
+		fn is_ok(item: &&BulkItem) -> bool {
+			item.is_ok()
+		}
+
+		self.items.iter().filter(is_ok as fn(&&BulkItem) -> bool)
+	}
+
+	/// Get the items for operations that failed.
+	pub fn items_err(&self) -> BulkItems {
+		fn is_err(item: &&BulkItem) -> bool {
+			!item.is_ok()
+		}
+
+		self.items.iter().filter(is_err as fn(&&BulkItem) -> bool)
+	}
+}
+
+/// The kind of operation in a bulk request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum BulkAction {
+	/// Index a document, replacing it if it already exists.
+	Index,
+	/// Index a document, failing if it already exists.
+	Create,
+	/// Partially update a document.
+	Update,
+	/// Delete a document.
+	Delete
+}
+
+impl BulkAction {
+	/// Get the name of the action as it appears in a bulk request.
+	pub fn as_str(&self) -> &'static str {
+		match *self {
+			BulkAction::Index => "index",
+			BulkAction::Create => "create",
+			BulkAction::Update => "update",
+			BulkAction::Delete => "delete"
+		}
+	}
+}
+
+impl Deserialize for BulkAction {
+	fn deserialize<D>(deserializer: &mut D) -> Result<BulkAction, D::Error> where
+	D: Deserializer {
+		struct BulkActionVisitor;
+		impl serde::de::Visitor for BulkActionVisitor {
+			type Value = BulkAction;
+
+			fn visit_str<E>(&mut self, v: &str) -> Result<BulkAction, E> where
+			E: serde::de::Error {
+				match v {
+					"index" => Ok(BulkAction::Index),
+					"create" => Ok(BulkAction::Create),
+					"update" => Ok(BulkAction::Update),
+					"delete" => Ok(BulkAction::Delete),
+					_ => Err(E::invalid_value(&format!("unknown bulk action '{}'", v)))
+				}
+			}
+		}
+
+		deserializer.deserialize(BulkActionVisitor)
+	}
+}
+
+/// The result of a single operation in a bulk request.
+///
+/// In the response body each item is an object keyed by the action, like `{ "index": { ... } }`.
+#[derive(Debug)]
+pub struct BulkItem {
+	/// The kind of operation.
+	pub action: BulkAction,
+	/// The index of the document.
+	pub index: String,
+	/// The type of the document.
+	pub doc_type: String,
+	/// The id of the document.
+	pub id: String,
+	/// The version of the document after the operation, if it succeeded.
+	pub version: Option<u64>,
+	/// The HTTP status code for the operation.
+	pub status: u16,
+	/// Whether or not the document was found, for `delete` operations.
+	pub found: Option<bool>,
+	/// The error for the operation, if it failed.
+	pub error: Option<ErrorCause>
+}
+
+impl BulkItem {
+	/// Whether or not the operation succeeded.
+	///
+	/// Deleting a document that doesn't exist isn't a failure,
+	/// even though the item has a `404` status, because the document is gone either way.
+	pub fn is_ok(&self) -> bool {
+		if self.error.is_some() {
+			return false;
+		}
+
+		match (self.action, self.found) {
+			(BulkAction::Delete, Some(false)) => true,
+			_ => self.status < 300
+		}
+	}
+}
+
+#[derive(Deserialize)]
+struct BulkItemResult {
+	#[serde(rename="_index")]
+	index: String,
+	#[serde(rename="_type")]
+	doc_type: String,
+	#[serde(rename="_id")]
+	id: String,
+	#[serde(rename="_version")]
+	version: Option<u64>,
+	status: u16,
+	found: Option<bool>,
+	error: Option<ErrorCause>
+}
+
+impl Deserialize for BulkItem {
+	fn deserialize<D>(deserializer: &mut D) -> Result<BulkItem, D::Error> where
+	D: Deserializer {
+		let mut item: BTreeMap<BulkAction, BulkItemResult> = try!(BTreeMap::deserialize(deserializer));
+
+		let (action, result) = match item.keys().next().cloned() {
+			Some(action) => (action, item.remove(&action).unwrap()),
+			None => return Err(serde::de::Error::invalid_value("expected a bulk item keyed by its action"))
+		};
+
+		Ok(BulkItem {
+			action: action,
+			index: result.index,
+			doc_type: result.doc_type,
+			id: result.id,
+			version: result.version,
+			status: result.status,
+			found: result.found,
+			error: result.error
+		})
+	}
+}
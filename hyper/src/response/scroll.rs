@@ -0,0 +1,152 @@
+use std::vec::IntoIter;
+use std::collections::BTreeMap;
+use hyper::client::{ Client, Body };
+use serde::Deserialize;
+use serde_json;
+use ::{ RequestParams, search, scroll, clear_scroll };
+use super::{ ApiError, ResponseExt, SearchResponse, Hit };
+
+/// Lazily iterate through the hits for a search using the Scroll API.
+///
+/// The scroll keep-alive is renewed each time a new page of hits is fetched.
+/// The scroll is cleared when all hits have been returned, when an error is encountered, or when the iterator is dropped.
+///
+/// # Examples
+///
+/// ```no_run
+/// # #![feature(plugin, custom_derive)]
+/// # #![plugin(serde_macros)]
+/// # extern crate hyper;
+/// # extern crate serde;
+/// # extern crate elastic_hyper as elastic;
+/// # fn main() {
+/// use elastic::response::ScrollIter;
+///
+/// #[derive(Deserialize)]
+/// struct MyType {
+/// 	title: String
+/// }
+///
+/// let mut client = hyper::Client::new();
+/// let req = elastic::RequestParams::default();
+///
+/// let hits = ScrollIter::<MyType>::new(&mut client, &req, "myindex", "1m",
+/// 	r#"{ "size": 100, "query": { "match_all": {} } }"#
+/// ).unwrap();
+///
+/// for hit in hits {
+/// 	let hit = hit.unwrap();
+/// 	println!("{}", hit.id);
+/// }
+/// # }
+/// ```
+pub struct ScrollIter<'a, T> where
+T: Deserialize {
+	client: &'a mut Client,
+	req: &'a RequestParams,
+	keep_alive: String,
+	scroll_id: Option<String>,
+	hits: IntoIter<Hit<T>>,
+	done: bool
+}
+
+impl <'a, T> ScrollIter<'a, T> where
+T: Deserialize {
+	/// Start a scrolled search on an index.
+	///
+	/// The `keep_alive` is how long the search context is maintained between pages, like `"1m"`.
+	/// The `size` in the search body is the number of hits fetched for each page.
+	pub fn new<'b, I>(client: &'a mut Client, req: &'a RequestParams, index: &'b str, keep_alive: &str, body: I) -> Result<ScrollIter<'a, T>, ApiError> where
+	'a: 'b,
+	I: Into<Body<'b>> {
+		let params = search::Params {
+			scroll: Some(keep_alive.to_owned()),
+			..Default::default()
+		};
+
+		let res: SearchResponse<T> = try!(try!(search::post_index(&mut *client, req, &params, index, body)).into_response());
+
+		let mut iter = ScrollIter {
+			client: client,
+			req: req,
+			keep_alive: keep_alive.to_owned(),
+			scroll_id: None,
+			hits: Vec::new().into_iter(),
+			done: false
+		};
+
+		iter.set_page(res);
+
+		Ok(iter)
+	}
+
+	fn set_page(&mut self, res: SearchResponse<T>) {
+		self.scroll_id = res.scroll_id;
+
+		if res.hits.hits.len() == 0 {
+			self.done = true;
+			self.clear();
+		}
+
+		self.hits = res.hits.hits.into_iter();
+	}
+
+	fn next_page(&mut self) -> Result<(), ApiError> {
+		let scroll_id = match self.scroll_id {
+			Some(ref scroll_id) => scroll_id.to_owned(),
+			None => {
+				self.done = true;
+				return Ok(());
+			}
+		};
+
+		let mut body = BTreeMap::new();
+		body.insert("scroll", self.keep_alive.to_owned());
+		body.insert("scroll_id", scroll_id);
+		let body = try!(serde_json::to_string(&body));
+
+		let res = try!(scroll::post(&mut *self.client, self.req, &scroll::Params::default(), &body));
+		let res: SearchResponse<T> = try!(res.into_response());
+
+		self.set_page(res);
+
+		Ok(())
+	}
+
+	fn clear(&mut self) {
+		if let Some(scroll_id) = self.scroll_id.take() {
+			let _ = clear_scroll::delete_scroll_id(&mut *self.client, self.req, &clear_scroll::Params, &scroll_id);
+		}
+	}
+}
+
+impl <'a, T> Iterator for ScrollIter<'a, T> where
+T: Deserialize {
+	type Item = Result<Hit<T>, ApiError>;
+
+	fn next(&mut self) -> Option<Result<Hit<T>, ApiError>> {
+		loop {
+			if let Some(hit) = self.hits.next() {
+				return Some(Ok(hit));
+			}
+
+			if self.done {
+				return None;
+			}
+
+			if let Err(err) = self.next_page() {
+				self.done = true;
+				self.clear();
+
+				return Some(Err(err));
+			}
+		}
+	}
+}
+
+impl <'a, T> Drop for ScrollIter<'a, T> where
+T: Deserialize {
+	fn drop(&mut self) {
+		self.clear();
+	}
+}
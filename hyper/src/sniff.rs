@@ -0,0 +1,258 @@
+//! Discover the nodes in a cluster.
+//!
+//! Requires the `serde-response` feature.
+//!
+//! A [`Sniffer`](struct.Sniffer.html) calls the Nodes Info API (`GET /_nodes/http`) and replaces the nodes
+//! in a [`NodePool`](../pool/struct.NodePool.html) with the `http.publish_address` of each node that can serve requests.
+//! Dedicated master nodes are left out.
+//!
+//! Sniffing can be done once at startup, and on an interval in the background,
+//! so the pool follows nodes joining and leaving the cluster.
+//! Replacing the nodes doesn't interrupt requests that are already in flight.
+//!
+//! Sniffed nodes keep the scheme of the nodes already in the pool, so a pool of `https` nodes stays on `https`.
+//! The scheme can also be set with [`Sniffer::scheme`](struct.Sniffer.html#method.scheme).
+//!
+//! # Examples
+//!
+//! Sniff at startup, then every 5 minutes in the background:
+//!
+//! ```no_run
+//! # extern crate hyper;
+//! # extern crate elastic_hyper as elastic;
+//! # fn main() {
+//! use std::sync::Arc;
+//! use std::time::Duration;
+//! use elastic::NodePool;
+//! use elastic::sniff::Sniffer;
+//!
+//! let pool = Arc::new(NodePool::new(vec!["http://es1:9200"]));
+//! let sniffer = Sniffer::new(elastic::RequestParams::default())
+//! 	.interval(Duration::from_secs(300));
+//!
+//! //Keep sniffing until the handle is dropped
+//! let handle = sniffer.spawn(pool.clone(), hyper::Client::new());
+//! # }
+//! ```
+
+use std::error::Error as StdError;
+use std::fmt;
+use std::io::Read;
+use std::sync::Arc;
+use std::sync::atomic::{ AtomicBool, Ordering };
+use std::thread;
+use std::time::{ Duration, Instant };
+use serde_json;
+use serde_json::Value;
+use ::{ NodePool, RequestParams, Transport };
+
+/// The default time between sniffs in the background.
+pub const DEFAULT_SNIFF_INTERVAL_SECS: u64 = 300;
+
+/// Sniffs the nodes in a cluster with the Nodes Info API.
+#[derive(Debug, Clone)]
+pub struct Sniffer {
+	req: RequestParams,
+	interval: Duration,
+	scheme: Option<String>
+}
+
+impl Sniffer {
+	/// Create a sniffer that sends requests with the given parameters.
+	///
+	/// The `base_url` is ignored, because requests are sent to the nodes in the pool.
+	pub fn new(req: RequestParams) -> Self {
+		Sniffer {
+			req: req,
+			interval: Duration::from_secs(DEFAULT_SNIFF_INTERVAL_SECS),
+			scheme: None
+		}
+	}
+
+	/// Set the time between sniffs in the background.
+	///
+	/// The default is 5 minutes.
+	pub fn interval(mut self, interval: Duration) -> Self {
+		self.interval = interval;
+
+		self
+	}
+
+	/// Set the scheme for the base urls of sniffed nodes, like `https`.
+	///
+	/// By default, the scheme of the nodes already in the pool is kept, or `http` if it can't be found.
+	pub fn scheme<S: Into<String>>(mut self, scheme: S) -> Self {
+		self.scheme = Some(scheme.into());
+
+		self
+	}
+
+	/// Sniff the nodes in the cluster and replace the nodes in the pool.
+	///
+	/// The request is sent through the pool, so it's retried on another node if one can't be reached.
+	/// If no nodes are found, the pool is left as it is and `SniffError::NoNodes` is returned.
+	///
+	/// Returns the base urls of the nodes that were found.
+	pub fn sniff<T>(&self, pool: &NodePool, transport: &mut T) -> Result<Vec<String>, SniffError<T::Error>>
+	where T: Transport, T::Response: Read {
+		let params = ::nodes::info::Params::default();
+
+		let mut res = try!(pool
+			.send(transport, &self.req, |req| ::nodes::info::get_metric_request(req, &params, "http"))
+			.map_err(SniffError::Transport));
+
+		let info: Value = try!(serde_json::from_reader(&mut res).map_err(SniffError::Json));
+
+		let scheme = match self.scheme {
+			Some(ref scheme) => scheme.clone(),
+			None => pool_scheme(pool)
+		};
+
+		let nodes = nodes_from_info(&info, &scheme);
+		if nodes.len() == 0 {
+			return Err(SniffError::NoNodes);
+		}
+
+		pool.set_nodes(nodes.iter().cloned());
+
+		Ok(nodes)
+	}
+
+	/// Sniff the nodes in the cluster on a background thread.
+	///
+	/// The first sniff happens straight away, then once every interval.
+	/// Errors are ignored, so the pool keeps its current nodes until the next successful sniff.
+	/// The thread stops when the returned handle is dropped.
+	pub fn spawn<T>(self, pool: Arc<NodePool>, mut transport: T) -> SnifferHandle
+	where T: Transport + Send + 'static, T::Response: Read {
+		let stop = Arc::new(AtomicBool::new(false));
+
+		let thread_stop = stop.clone();
+		thread::spawn(move || {
+			loop {
+				let _ = self.sniff(&pool, &mut transport);
+
+				let next = Instant::now() + self.interval;
+
+				//Sleep in short steps so the thread stops soon after the handle is dropped
+				loop {
+					if thread_stop.load(Ordering::SeqCst) {
+						return;
+					}
+
+					let now = Instant::now();
+					if now >= next {
+						break;
+					}
+
+					thread::sleep(::std::cmp::min(next - now, Duration::from_millis(100)));
+				}
+			}
+		});
+
+		SnifferHandle {
+			stop: stop
+		}
+	}
+}
+
+/// A handle to a sniffer running in the background.
+///
+/// Dropping the handle stops the sniffer.
+#[derive(Debug)]
+pub struct SnifferHandle {
+	stop: Arc<AtomicBool>
+}
+
+impl Drop for SnifferHandle {
+	fn drop(&mut self) {
+		self.stop.store(true, Ordering::SeqCst);
+	}
+}
+
+/// Get the base urls from a Nodes Info API response.
+///
+/// The base urls use the given `scheme`, like `http` or `https`.
+/// Nodes without an `http.publish_address` and dedicated master nodes are left out.
+/// Both the `roles` from Elasticsearch `5.x` and the `attributes` from earlier versions are understood.
+pub fn nodes_from_info(info: &Value, scheme: &str) -> Vec<String> {
+	let nodes = match info.find("nodes").and_then(|n| n.as_object()) {
+		Some(nodes) => nodes,
+		None => return Vec::new()
+	};
+
+	nodes
+		.values()
+		.filter(|node| !is_dedicated_master(node))
+		.filter_map(|node| {
+			node.find("http")
+				.and_then(|http| http.find("publish_address"))
+				.and_then(|addr| addr.as_string())
+				.map(|addr| format!("{}://{}", scheme, parse_publish_address(addr)))
+		})
+		.collect()
+}
+
+//Take the scheme from the first node in the pool with one, like `https` from `https://es1:9200`
+fn pool_scheme(pool: &NodePool) -> String {
+	pool.nodes()
+		.iter()
+		.filter_map(|url| url.find("://").map(|i| url[..i].to_owned()))
+		.next()
+		.unwrap_or_else(|| "http".to_owned())
+}
+
+fn is_dedicated_master(node: &Value) -> bool {
+	if let Some(roles) = node.find("roles").and_then(|r| r.as_array()) {
+		return roles.len() > 0 && roles.iter().all(|r| r.as_string() == Some("master"));
+	}
+
+	attribute(node, "data") == Some("false") && attribute(node, "client") != Some("true")
+}
+
+fn attribute<'a>(node: &'a Value, name: &str) -> Option<&'a str> {
+	node.find("attributes")
+		.and_then(|attrs| attrs.find(name))
+		.and_then(|value| value.as_string())
+}
+
+//Publish addresses look like `127.0.0.1:9200`, `hostname/127.0.0.1:9200` or `inet[/127.0.0.1:9200]`
+fn parse_publish_address(addr: &str) -> &str {
+	let addr = addr.trim_left_matches("inet[").trim_right_matches(']');
+
+	match addr.rfind('/') {
+		Some(i) => &addr[i + 1..],
+		None => addr
+	}
+}
+
+/// An error sniffing the nodes in a cluster.
+#[derive(Debug)]
+pub enum SniffError<E> {
+	/// The Nodes Info request couldn't be sent.
+	Transport(E),
+	/// The Nodes Info response couldn't be parsed.
+	Json(serde_json::Error),
+	/// The Nodes Info response didn't contain any nodes to send requests to.
+	NoNodes
+}
+
+impl<E: fmt::Display> fmt::Display for SniffError<E> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match *self {
+			SniffError::Transport(ref e) => write!(f, "error sending nodes info request: {}", e),
+			SniffError::Json(ref e) => write!(f, "error parsing nodes info response: {}", e),
+			SniffError::NoNodes => write!(f, "no nodes found")
+		}
+	}
+}
+
+impl<E: StdError> StdError for SniffError<E> {
+	fn description(&self) -> &str {
+		match *self {
+			SniffError::Transport(ref e) => e.description(),
+			SniffError::Json(ref e) => e.description(),
+			SniffError::NoNodes => "no nodes found"
+		}
+	}
+}
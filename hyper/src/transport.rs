@@ -0,0 +1,144 @@
+//! Transport-agnostic requests.
+//!
+//! Each endpoint function in the api modules has a `_request` counterpart that builds
+//! an `HttpRequest` instead of sending it with a `hyper::Client`.
+//! An `HttpRequest` is a plain value, so it can be sent by anything that implements `Transport`,
+//! like a different http stack, an asynchronous client or a test double.
+//!
+//! # Examples
+//!
+//! Build a search request and send it with `hyper`:
+//!
+//! ```no_run
+//! # extern crate hyper;
+//! # extern crate elastic_hyper as elastic;
+//! # fn main() {
+//! use elastic::Transport;
+//!
+//! let req = elastic::search::post_index_request(
+//! 	&elastic::RequestParams::default(), &elastic::search::Params::default(),
+//! 	"myindex",
+//! 	"{ \"query\": { \"match_all\": {} } }"
+//! );
+//!
+//! let mut client = hyper::Client::new();
+//! let res = client.send(req).unwrap();
+//! # }
+//! ```
+//!
+//! Record requests instead of sending them:
+//!
+//! ```
+//! # extern crate hyper;
+//! # extern crate elastic_hyper as elastic;
+//! # fn main() {
+//! use elastic::{ HttpRequest, Transport };
+//!
+//! struct Recorder(Vec<HttpRequest>);
+//!
+//! impl Transport for Recorder {
+//! 	type Response = ();
+//! 	type Error = ();
+//!
+//! 	fn send(&mut self, req: HttpRequest) -> Result<(), ()> {
+//! 		self.0.push(req);
+//! 		Ok(())
+//! 	}
+//!
+//! 	fn is_connection_error(&self, _: &()) -> bool {
+//! 		false
+//! 	}
+//! }
+//!
+//! let mut transport = Recorder(Vec::new());
+//!
+//! let req = elastic::ping::head_request(&elastic::RequestParams::default(), &elastic::ping::Params);
+//! transport.send(req).unwrap();
+//!
+//! assert_eq!("http://localhost:9200/", transport.0[0].url);
+//! # }
+//! ```
+
+use hyper::client::Client;
+use hyper::client::response::Response;
+use hyper::error::{ Error, Result };
+use hyper::header::Headers;
+use hyper::method::Method;
+
+/// A request for an endpoint that hasn't been sent yet.
+///
+/// The `url` is absolute, including the `base_url` and query string from the `RequestParams`.
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+	/// The http method.
+	pub method: Method,
+	/// The absolute url to send the request to.
+	pub url: String,
+	/// The headers to send with the request.
+	pub headers: Headers,
+	/// The request body, if the method takes one.
+	pub body: Option<Vec<u8>>
+}
+
+impl HttpRequest {
+	/// Create a new request without a body.
+	pub fn new<U: Into<String>>(method: Method, url: U, headers: Headers) -> Self {
+		HttpRequest {
+			method: method,
+			url: url.into(),
+			headers: headers,
+			body: None
+		}
+	}
+
+	/// Set the body for the request.
+	pub fn body<B: AsRef<[u8]>>(mut self, body: B) -> Self {
+		self.body = Some(body.as_ref().to_vec());
+
+		self
+	}
+}
+
+/// A way to send an `HttpRequest`.
+///
+/// `Transport` is implemented for `hyper::Client`, which sends requests synchronously.
+/// Other implementations are free to return something other than a response,
+/// like a future that completes when the response arrives.
+pub trait Transport {
+	/// The result of sending a request.
+	type Response;
+	/// The error returned if a request can't be sent.
+	type Error;
+
+	/// Send a request.
+	fn send(&mut self, req: HttpRequest) -> ::std::result::Result<Self::Response, Self::Error>;
+
+	/// Whether an error means the node couldn't be reached, rather than that it sent back something unexpected.
+	///
+	/// A `NodePool` only marks a node dead and tries another one for connection errors,
+	/// so errors like unparseable responses shouldn't be counted.
+	fn is_connection_error(&self, err: &Self::Error) -> bool;
+}
+
+impl Transport for Client {
+	type Response = Response;
+	type Error = Error;
+
+	fn send(&mut self, req: HttpRequest) -> Result<Response> {
+		let HttpRequest { method, url, headers, body } = req;
+
+		let res = self.request(method, &url[..]).headers(headers);
+
+		match body {
+			Some(ref body) => res.body(&body[..]).send(),
+			None => res.send()
+		}
+	}
+
+	fn is_connection_error(&self, err: &Error) -> bool {
+		match *err {
+			Error::Io(_) => true,
+			_ => false
+		}
+	}
+}
@@ -0,0 +1,87 @@
+#![cfg(feature="serde-response")]
+
+#![feature(custom_derive, plugin)]
+#![plugin(serde_macros)]
+
+extern crate hyper;
+extern crate serde;
+extern crate elastic_hyper;
+
+use hyper::status::StatusCode;
+use elastic_hyper::request::*;
+use elastic_hyper::response::*;
+
+#[derive(Serialize)]
+struct MyType {
+	title: String
+}
+
+fn doc(title: &str) -> MyType {
+	MyType {
+		title: title.to_owned()
+	}
+}
+
+#[test]
+fn bulk_request_renders_operations() {
+	let mut req = BulkRequest::new();
+	req.index(BulkMeta::new().index("myindex").doc_type("mytype").id("1"), &doc("a")).unwrap();
+	req.create(BulkMeta::new().id("2").routing("r").parent("p"), &doc("b")).unwrap();
+	req.delete(BulkMeta::new().id("3").version(4));
+
+	let expected = concat!(
+		r#"{"index":{"_index":"myindex","_type":"mytype","_id":"1"}}"#, "\n",
+		r#"{"title":"a"}"#, "\n",
+		r#"{"create":{"_id":"2","_routing":"r","_parent":"p"}}"#, "\n",
+		r#"{"title":"b"}"#, "\n",
+		r#"{"delete":{"_id":"3","_version":4}}"#, "\n"
+	);
+
+	assert_eq!(3, req.len());
+	assert_eq!(expected.len(), req.byte_len());
+	assert_eq!(expected, req.body());
+}
+
+#[test]
+fn empty_bulk_request_has_empty_body() {
+	let req = BulkRequest::new();
+
+	assert!(req.is_empty());
+	assert_eq!("", req.body());
+}
+
+#[test]
+fn bulk_request_keeps_failed_operations() {
+	let mut req = BulkRequest::new();
+	req.index(BulkMeta::new().id("1"), &doc("a")).unwrap();
+	req.index(BulkMeta::new().id("2"), &doc("b")).unwrap();
+
+	let body = r#"{"took":3,"errors":true,"items":[
+		{"index":{"_index":"myindex","_type":"mytype","_id":"1","_version":1,"status":201}},
+		{"index":{"_index":"myindex","_type":"mytype","_id":"2","status":429,"error":{"type":"es_rejected_execution_exception","reason":"rejected"}}}
+	]}"#;
+	let res: BulkResponse = from_reader(StatusCode::Ok, body.as_bytes()).unwrap();
+
+	assert_eq!(1, res.items_ok().count());
+	assert_eq!("2", res.items_err().next().unwrap().id);
+
+	let retry = req.failed(&res);
+
+	assert_eq!(1, retry.len());
+	assert_eq!(concat!(r#"{"index":{"_id":"2"}}"#, "\n", r#"{"title":"b"}"#, "\n"), retry.body());
+}
+
+#[test]
+fn bulk_request_does_not_keep_deletes_of_missing_documents() {
+	let mut req = BulkRequest::new();
+	req.delete(BulkMeta::new().id("1"));
+	req.delete(BulkMeta::new().id("2"));
+
+	let body = r#"{"took":3,"errors":false,"items":[
+		{"delete":{"_index":"myindex","_type":"mytype","_id":"1","_version":2,"found":true,"status":200}},
+		{"delete":{"_index":"myindex","_type":"mytype","_id":"2","_version":1,"found":false,"status":404}}
+	]}"#;
+	let res: BulkResponse = from_reader(StatusCode::Ok, body.as_bytes()).unwrap();
+
+	assert!(req.failed(&res).is_empty());
+}
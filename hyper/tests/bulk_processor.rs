@@ -0,0 +1,381 @@
+#![cfg(feature="serde-response")]
+
+#![feature(custom_derive, plugin)]
+#![plugin(serde_macros)]
+
+extern crate hyper;
+extern crate serde;
+extern crate serde_json;
+extern crate elastic_hyper;
+
+use std::cell::RefCell;
+use std::io::{ BufRead, BufReader, Read, Write };
+use std::net::{ TcpListener, TcpStream };
+use std::rc::Rc;
+use std::sync::{ Arc, Mutex };
+use std::thread;
+use std::time::Duration;
+use serde_json::Value;
+use elastic_hyper::RequestParams;
+use elastic_hyper::request::*;
+
+#[derive(Serialize)]
+struct MyType {
+	title: String
+}
+
+fn doc(title: &str) -> MyType {
+	MyType {
+		title: title.to_owned()
+	}
+}
+
+/// The titles of the documents in each bulk request received by a stand-in.
+type Received = Arc<Mutex<Vec<Vec<String>>>>;
+
+/// A loopback stand-in for a node that answers bulk requests.
+///
+/// The `respond` closure is given the number of the request and the titles of its documents,
+/// and returns the status and body for the response.
+/// Returns the base url for the stand-in and the requests it has received.
+fn stand_in<F>(respond: F) -> (String, Received)
+where F: Fn(usize, &[String]) -> (u16, String) + Send + Sync + 'static {
+	let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+	let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+	let received: Received = Arc::new(Mutex::new(Vec::new()));
+	let respond = Arc::new(respond);
+
+	let server_received = received.clone();
+	thread::spawn(move || {
+		for stream in listener.incoming() {
+			let received = server_received.clone();
+			let respond = respond.clone();
+
+			match stream {
+				Ok(stream) => { thread::spawn(move || serve(stream, received, &*respond)); },
+				Err(_) => break
+			}
+		}
+	});
+
+	(base_url, received)
+}
+
+fn serve<F>(stream: TcpStream, received: Received, respond: &F)
+where F: Fn(usize, &[String]) -> (u16, String) {
+	let mut writer = stream.try_clone().unwrap();
+	let mut reader = BufReader::new(stream);
+
+	loop {
+		let mut request_line = String::new();
+		if reader.read_line(&mut request_line).unwrap_or(0) == 0 {
+			return;
+		}
+
+		assert!(request_line.starts_with("POST /_bulk "), "unexpected request {}", request_line);
+
+		let mut len = 0;
+		loop {
+			let mut header = String::new();
+			reader.read_line(&mut header).unwrap();
+
+			let header = header.trim().to_lowercase();
+			if header.len() == 0 {
+				break;
+			}
+
+			if header.starts_with("content-length:") {
+				len = header["content-length:".len()..].trim().parse().unwrap();
+			}
+		}
+
+		let mut req_body = vec![0; len];
+		reader.read_exact(&mut req_body).unwrap();
+
+		let titles = titles(&String::from_utf8(req_body).unwrap());
+
+		let (status, body) = {
+			let mut received = received.lock().unwrap();
+			let res = respond(received.len(), &titles);
+			received.push(titles);
+
+			res
+		};
+
+		let res = format!("HTTP/1.1 {} Stand-in\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}", status, body.len(), body);
+		if writer.write_all(res.as_bytes()).is_err() {
+			return;
+		}
+	}
+}
+
+//Every other line in the body is a document
+fn titles(body: &str) -> Vec<String> {
+	body.lines()
+		.enumerate()
+		.filter(|&(i, _)| i % 2 == 1)
+		.map(|(_, line)| {
+			let doc: Value = serde_json::from_str(line).unwrap();
+			doc.find("title").and_then(|t| t.as_string()).unwrap().to_owned()
+		})
+		.collect()
+}
+
+//A bulk response where the documents matching `reject` are rejected because the bulk queue is full
+fn bulk_response<F>(titles: &[String], reject: F) -> (u16, String)
+where F: Fn(&str) -> bool {
+	let items: Vec<String> = titles
+		.iter()
+		.map(|title| match reject(title) {
+			true => format!(r#"{{"index":{{"_index":"myindex","_type":"mytype","_id":"{}","status":429,"error":{{"type":"es_rejected_execution_exception","reason":"rejected"}}}}}}"#, title),
+			false => format!(r#"{{"index":{{"_index":"myindex","_type":"mytype","_id":"{}","_version":1,"status":201}}}}"#, title)
+		})
+		.collect();
+
+	let errors = titles.iter().any(|title| reject(title));
+
+	(200, format!(r#"{{"took":1,"errors":{},"items":[{}]}}"#, errors, items.join(",")))
+}
+
+#[derive(Debug, PartialEq)]
+struct Flushed {
+	docs: usize,
+	succeeded: usize,
+	retries: u32,
+	failed: Vec<String>
+}
+
+fn processor(base_url: &str, flushed: &Rc<RefCell<Vec<Flushed>>>) -> BulkProcessor {
+	let flushed = flushed.clone();
+
+	let mut req = RequestParams::default();
+	req.base_url = base_url.to_owned();
+
+	BulkProcessor::new(hyper::Client::new(), req)
+		.backoff(Duration::from_millis(1))
+		.on_flush(move |stats| flushed.borrow_mut().push(Flushed {
+			docs: stats.docs,
+			succeeded: stats.succeeded,
+			retries: stats.retries,
+			failed: stats.failed.iter().map(|item| item.id.clone()).collect()
+		}))
+}
+
+fn meta() -> BulkMeta {
+	BulkMeta::new().index("myindex").doc_type("mytype")
+}
+
+fn received(titles: &[&[&str]]) -> Vec<Vec<String>> {
+	titles.iter().map(|req| req.iter().map(|t| t.to_string()).collect()).collect()
+}
+
+#[test]
+fn bulk_processor_flushes_when_max_docs_is_reached() {
+	let (base_url, requests) = stand_in(|_, titles| bulk_response(titles, |_| false));
+	let flushed = Rc::new(RefCell::new(Vec::new()));
+
+	let mut processor = processor(&base_url, &flushed).max_docs(2);
+	for title in &["a", "b", "c", "d", "e"] {
+		processor.index(meta(), &doc(title)).unwrap();
+	}
+
+	assert_eq!(received(&[&["a", "b"], &["c", "d"]]), *requests.lock().unwrap());
+	assert_eq!(1, processor.pending());
+
+	processor.close().unwrap();
+
+	assert_eq!(received(&[&["a", "b"], &["c", "d"], &["e"]]), *requests.lock().unwrap());
+	assert_eq!(vec![2, 2, 1], flushed.borrow().iter().map(|f| f.docs).collect::<Vec<_>>());
+}
+
+#[test]
+fn bulk_processor_flushes_when_max_bytes_is_reached() {
+	let (base_url, requests) = stand_in(|_, titles| bulk_response(titles, |_| false));
+	let flushed = Rc::new(RefCell::new(Vec::new()));
+
+	//Each operation is 45 bytes, so the batch is flushed when the second one is added
+	let mut processor = processor(&base_url, &flushed).max_bytes(70);
+	for title in &["a", "b", "c"] {
+		processor.index(BulkMeta::new().index("myindex"), &doc(title)).unwrap();
+	}
+
+	assert_eq!(received(&[&["a", "b"]]), *requests.lock().unwrap());
+	assert_eq!(1, processor.pending());
+}
+
+#[test]
+fn bulk_processor_flushes_idle_batch_when_interval_passes() {
+	let (base_url, requests) = stand_in(|_, titles| bulk_response(titles, |_| false));
+	let flushed = Rc::new(RefCell::new(Vec::new()));
+
+	let mut processor = processor(&base_url, &flushed).flush_interval(Duration::from_millis(50));
+	processor.index(meta(), &doc("a")).unwrap();
+
+	processor.flush_if_due().unwrap();
+	assert_eq!(0, requests.lock().unwrap().len());
+
+	thread::sleep(Duration::from_millis(60));
+	processor.flush_if_due().unwrap();
+
+	assert_eq!(received(&[&["a"]]), *requests.lock().unwrap());
+	assert_eq!(0, processor.pending());
+}
+
+#[test]
+fn bulk_processor_retries_rejected_items() {
+	let (base_url, requests) = stand_in(|i, titles| bulk_response(titles, |title| i == 0 && title == "b"));
+	let flushed = Rc::new(RefCell::new(Vec::new()));
+
+	let mut processor = processor(&base_url, &flushed);
+	for title in &["a", "b", "c"] {
+		processor.index(meta(), &doc(title)).unwrap();
+	}
+	processor.close().unwrap();
+
+	assert_eq!(received(&[&["a", "b", "c"], &["b"]]), *requests.lock().unwrap());
+
+	let expected = Flushed {
+		docs: 3,
+		succeeded: 3,
+		retries: 1,
+		failed: Vec::new()
+	};
+	assert_eq!(vec![expected], *flushed.borrow());
+}
+
+#[test]
+fn bulk_processor_fails_rejected_items_after_max_retries() {
+	let (base_url, requests) = stand_in(|_, titles| bulk_response(titles, |title| title == "b"));
+	let flushed = Rc::new(RefCell::new(Vec::new()));
+
+	let mut processor = processor(&base_url, &flushed).max_retries(2);
+	for title in &["a", "b"] {
+		processor.index(meta(), &doc(title)).unwrap();
+	}
+	processor.close().unwrap();
+
+	assert_eq!(received(&[&["a", "b"], &["b"], &["b"]]), *requests.lock().unwrap());
+
+	let expected = Flushed {
+		docs: 2,
+		succeeded: 1,
+		retries: 2,
+		failed: vec!["b".to_owned()]
+	};
+	assert_eq!(vec![expected], *flushed.borrow());
+}
+
+#[test]
+fn bulk_processor_retries_rejected_request() {
+	let (base_url, requests) = stand_in(|i, titles| match i {
+		0 => (429, r#"{"error":{"type":"es_rejected_execution_exception","reason":"rejected"},"status":429}"#.to_owned()),
+		_ => bulk_response(titles, |_| false)
+	});
+	let flushed = Rc::new(RefCell::new(Vec::new()));
+
+	let mut processor = processor(&base_url, &flushed);
+	for title in &["a", "b"] {
+		processor.index(meta(), &doc(title)).unwrap();
+	}
+	processor.close().unwrap();
+
+	assert_eq!(received(&[&["a", "b"], &["a", "b"]]), *requests.lock().unwrap());
+	assert_eq!(1, flushed.borrow()[0].retries);
+	assert_eq!(2, flushed.borrow()[0].succeeded);
+}
+
+#[test]
+fn bulk_processor_caps_backoff_for_many_retries() {
+	let (base_url, requests) = stand_in(|_, titles| bulk_response(titles, |_| true));
+	let flushed = Rc::new(RefCell::new(Vec::new()));
+
+	let mut processor = processor(&base_url, &flushed)
+		.max_retries(40)
+		.max_backoff(Duration::from_millis(1));
+
+	processor.index(meta(), &doc("a")).unwrap();
+	processor.close().unwrap();
+
+	assert_eq!(41, requests.lock().unwrap().len());
+	assert_eq!(vec!["a".to_owned()], flushed.borrow()[0].failed);
+}
+
+#[test]
+fn bulk_processor_keeps_batch_when_flush_fails() {
+	let (base_url, requests) = stand_in(|i, titles| match i {
+		0 => (500, r#"{"error":{"type":"exception","reason":"failed"},"status":500}"#.to_owned()),
+		_ => bulk_response(titles, |_| false)
+	});
+	let flushed = Rc::new(RefCell::new(Vec::new()));
+
+	let mut processor = processor(&base_url, &flushed);
+	for title in &["a", "b"] {
+		processor.index(meta(), &doc(title)).unwrap();
+	}
+
+	assert!(processor.flush().is_err());
+	assert_eq!(2, processor.pending());
+	assert_eq!(0, flushed.borrow().len());
+
+	processor.close().unwrap();
+
+	assert_eq!(received(&[&["a", "b"], &["a", "b"]]), *requests.lock().unwrap());
+}
+
+#[test]
+fn bulk_processor_reports_handled_items_when_retry_fails() {
+	let (base_url, requests) = stand_in(|i, titles| match i {
+		0 => {
+			let items = vec![
+				r#"{"index":{"_index":"myindex","_type":"mytype","_id":"a","_version":1,"status":201}}"#,
+				r#"{"index":{"_index":"myindex","_type":"mytype","_id":"b","status":429,"error":{"type":"es_rejected_execution_exception","reason":"rejected"}}}"#,
+				r#"{"index":{"_index":"myindex","_type":"mytype","_id":"c","status":400,"error":{"type":"mapper_parsing_exception","reason":"failed to parse"}}}"#
+			];
+
+			(200, format!(r#"{{"took":1,"errors":true,"items":[{}]}}"#, items.join(",")))
+		},
+		1 => (500, r#"{"error":{"type":"exception","reason":"failed"},"status":500}"#.to_owned()),
+		_ => bulk_response(titles, |_| false)
+	});
+	let flushed = Rc::new(RefCell::new(Vec::new()));
+
+	let mut processor = processor(&base_url, &flushed);
+	for title in &["a", "b", "c"] {
+		processor.index(meta(), &doc(title)).unwrap();
+	}
+
+	assert!(processor.flush().is_err());
+	assert_eq!(1, processor.pending());
+
+	let expected = Flushed {
+		docs: 2,
+		succeeded: 1,
+		retries: 1,
+		failed: vec!["c".to_owned()]
+	};
+	assert_eq!(vec![expected], *flushed.borrow());
+
+	processor.close().unwrap();
+
+	assert_eq!(received(&[&["a", "b", "c"], &["b"], &["b"]]), *requests.lock().unwrap());
+}
+
+#[test]
+fn bulk_processor_returns_unsent_batch_when_close_fails() {
+	let dead = {
+		let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+		format!("http://{}", listener.local_addr().unwrap())
+	};
+	let flushed = Rc::new(RefCell::new(Vec::new()));
+
+	let mut processor = processor(&dead, &flushed);
+	for title in &["a", "b"] {
+		processor.index(meta(), &doc(title)).unwrap();
+	}
+
+	let err = processor.close().unwrap_err();
+
+	assert_eq!(2, err.unsent.len());
+	assert_eq!(0, flushed.borrow().len());
+}
@@ -3,7 +3,8 @@ extern crate url;
 extern crate elastic_hyper;
 
 use hyper::header::*;
-use elastic_hyper::RequestParams;
+use hyper::method::Method;
+use elastic_hyper::{ HttpRequest, RequestParams, Transport, UrlParams };
 
 #[test]
 fn request_params_has_default_content_type() {
@@ -35,4 +36,190 @@ fn empty_request_params_returns_empty_string() {
 	let req = RequestParams::default();
 
 	assert_eq!("", &req.get_url_qry());
-}
\ No newline at end of file
+}
+
+#[test]
+fn request_params_merges_typed_url_params() {
+	let req = RequestParams::default()
+		.url_params(vec![
+			("pretty", "true".to_owned()),
+			("q", "*".to_owned())
+		]);
+
+	let params = elastic_hyper::search::Params {
+		q: Some("title:doc".to_owned()),
+		search_type: Some(elastic_hyper::search::SearchType::DfsQueryThenFetch),
+		..Default::default()
+	};
+
+	assert_eq!("?pretty=true&q=title%3Adoc&search_type=dfs_query_then_fetch", &req.get_url_qry_with(&params));
+}
+
+#[test]
+fn empty_typed_url_params_returns_request_params() {
+	let req = RequestParams::default()
+		.url_params(vec![
+			("pretty", "true".to_owned())
+		]);
+
+	assert_eq!("?pretty=true", &req.get_url_qry_with(&elastic_hyper::search::Params::default()));
+}
+
+#[test]
+fn typed_url_params_joins_lists() {
+	let params = elastic_hyper::search::Params {
+		routing: Some(vec![ "a".to_owned(), "b".to_owned() ]),
+		size: Some(5),
+		..Default::default()
+	};
+
+	assert_eq!(vec![ ("routing", "a,b".to_owned()), ("size", "5".to_owned()) ], params.url_params());
+}
+
+#[test]
+fn request_fn_builds_url_and_headers() {
+	let req = RequestParams::default()
+		.url_params(vec![
+			("pretty", "true".to_owned())
+		]);
+
+	let params = elastic_hyper::search::Params {
+		q: Some("*".to_owned()),
+		..Default::default()
+	};
+
+	let http_req = elastic_hyper::search::get_index_type_request(&req, &params, "myindex", "mytype");
+
+	assert_eq!(Method::Get, http_req.method);
+	assert_eq!("http://localhost:9200/myindex/mytype/_search?pretty=true&q=*", http_req.url);
+	assert_eq!(Some(&ContentType::json()), http_req.headers.get::<ContentType>());
+	assert_eq!(None, http_req.body);
+}
+
+#[test]
+fn request_fn_with_body_copies_body() {
+	let body = "{\"query\":{}}".to_owned();
+
+	let http_req = elastic_hyper::search::post_index_request(
+		&RequestParams::default(), &elastic_hyper::search::Params::default(),
+		"myindex",
+		&body
+	);
+
+	assert_eq!(Method::Post, http_req.method);
+	assert_eq!("http://localhost:9200/myindex/_search", http_req.url);
+	assert_eq!(Some(body.into_bytes()), http_req.body);
+}
+
+#[test]
+fn request_fn_uses_custom_base_url() {
+	let req = RequestParams::new("http://es1:9201", Headers::new());
+
+	let http_req = elastic_hyper::indices::close::post_index_request(
+		&req, &elastic_hyper::indices::close::Params::default(),
+		"myindex",
+		""
+	);
+
+	assert_eq!("http://es1:9201/myindex/_close", http_req.url);
+	assert_eq!(Some(Vec::new()), http_req.body);
+}
+
+#[test]
+fn request_fn_encodes_awkward_ids() {
+	let http_req = elastic_hyper::get::get_index_type_id_request(
+		&RequestParams::default(), &elastic_hyper::get::Params::default(),
+		"myindex", "mytype", "a/b?c#d e"
+	);
+
+	assert_eq!("http://localhost:9200/myindex/mytype/a%2Fb%3Fc%23d%20e", http_req.url);
+}
+
+#[test]
+fn request_fn_encodes_unicode_ids() {
+	let http_req = elastic_hyper::get::get_index_type_id_request(
+		&RequestParams::default(), &elastic_hyper::get::Params::default(),
+		"myindex", "mytype", "caf\u{e9}"
+	);
+
+	assert_eq!("http://localhost:9200/myindex/mytype/caf%C3%A9", http_req.url);
+}
+
+#[test]
+fn request_fn_encodes_parts_but_not_url_params() {
+	let params = elastic_hyper::get::Params {
+		routing: Some("r1".to_owned()),
+		..Default::default()
+	};
+
+	let http_req = elastic_hyper::get::get_index_type_id_request(&RequestParams::default(), &params, "myindex", "mytype", "1&2");
+
+	assert_eq!("http://localhost:9200/myindex/mytype/1%262?routing=r1", http_req.url);
+}
+
+#[test]
+fn request_fn_joins_list_parts() {
+	let http_req = elastic_hyper::search::get_index_request(
+		&RequestParams::default(), &elastic_hyper::search::Params::default(),
+		&["logs-1", "logs-2"]
+	);
+
+	assert_eq!("http://localhost:9200/logs-1,logs-2/_search", http_req.url);
+}
+
+#[test]
+fn request_fn_joins_list_parts_from_strings() {
+	let indices = vec!["logs 1".to_owned(), "logs/2".to_owned()];
+	let types = vec!["mytype"];
+
+	let http_req = elastic_hyper::search::get_index_type_request(
+		&RequestParams::default(), &elastic_hyper::search::Params::default(),
+		&indices, &types
+	);
+
+	assert_eq!("http://localhost:9200/logs%201,logs%2F2/mytype/_search", http_req.url);
+}
+
+#[test]
+fn request_fn_keeps_wildcards_in_list_parts() {
+	let http_req = elastic_hyper::search::get_index_request(
+		&RequestParams::default(), &elastic_hyper::search::Params::default(),
+		"logs-*"
+	);
+
+	assert_eq!("http://localhost:9200/logs-*/_search", http_req.url);
+}
+
+struct RecordingTransport {
+	sent: Vec<HttpRequest>
+}
+
+impl Transport for RecordingTransport {
+	type Response = usize;
+	type Error = ();
+
+	fn send(&mut self, req: HttpRequest) -> Result<usize, ()> {
+		self.sent.push(req);
+
+		Ok(self.sent.len())
+	}
+
+	fn is_connection_error(&self, _: &()) -> bool {
+		false
+	}
+}
+
+#[test]
+fn request_values_can_be_sent_through_any_transport() {
+	let mut transport = RecordingTransport { sent: Vec::new() };
+	let req = RequestParams::default();
+
+	transport.send(elastic_hyper::ping::head_request(&req, &elastic_hyper::ping::Params)).unwrap();
+	let sent = transport.send(elastic_hyper::indices::delete::delete_index_request(&req, &Default::default(), "myindex")).unwrap();
+
+	assert_eq!(2, sent);
+	assert_eq!(Method::Head, transport.sent[0].method);
+	assert_eq!("http://localhost:9200/", transport.sent[0].url);
+	assert_eq!(Method::Delete, transport.sent[1].method);
+	assert_eq!("http://localhost:9200/myindex", transport.sent[1].url);
+}
@@ -0,0 +1,205 @@
+extern crate hyper;
+extern crate elastic_hyper;
+
+use std::collections::HashSet;
+use std::thread;
+use std::time::Duration;
+use elastic_hyper::{ HttpRequest, NodePool, RequestParams, Transport };
+
+/// A transport that fails for some nodes and records the url of every request it's sent.
+///
+/// Nodes that are `down` fail with a connection error, and nodes that are `broken` fail with any other error.
+struct FakeTransport {
+	down: HashSet<&'static str>,
+	broken: HashSet<&'static str>,
+	sent: Vec<String>
+}
+
+impl FakeTransport {
+	fn new(down: Vec<&'static str>) -> Self {
+		FakeTransport {
+			down: down.into_iter().collect(),
+			broken: HashSet::new(),
+			sent: Vec::new()
+		}
+	}
+
+	fn broken(mut self, broken: Vec<&'static str>) -> Self {
+		self.broken = broken.into_iter().collect();
+		self
+	}
+}
+
+impl Transport for FakeTransport {
+	type Response = String;
+	type Error = String;
+
+	fn send(&mut self, req: HttpRequest) -> Result<String, String> {
+		self.sent.push(req.url.clone());
+
+		if self.down.iter().any(|node| req.url.starts_with(node)) {
+			Err(req.url)
+		}
+		else if self.broken.iter().any(|node| req.url.starts_with(node)) {
+			Err(format!("bad response from {}", req.url))
+		}
+		else {
+			Ok(req.url)
+		}
+	}
+
+	fn is_connection_error(&self, err: &String) -> bool {
+		!err.starts_with("bad response")
+	}
+}
+
+fn ping(req: &RequestParams) -> HttpRequest {
+	elastic_hyper::ping::head_request(req, &elastic_hyper::ping::Params)
+}
+
+fn index(req: &RequestParams) -> HttpRequest {
+	elastic_hyper::index::post_index_type_request(req, &Default::default(), "myindex", "mytype", "{}")
+}
+
+#[test]
+fn nodes_are_used_in_round_robin_order() {
+	let pool = NodePool::new(vec!["http://es1:9200", "http://es2:9200", "http://es3:9200"]);
+
+	let used: Vec<String> = (0..4).map(|_| pool.next()).collect();
+
+	assert_eq!(vec!["http://es1:9200", "http://es2:9200", "http://es3:9200", "http://es1:9200"], used);
+}
+
+#[test]
+fn params_uses_next_base_url() {
+	let pool = NodePool::new(vec!["http://es1:9200", "http://es2:9200"]);
+	let req = RequestParams::default().url_params(vec![ ("pretty", "true".to_owned()) ]);
+
+	let first = pool.params(&req);
+	let second = pool.params(&req);
+
+	assert_eq!("http://es1:9200", first.base_url);
+	assert_eq!("http://es2:9200", second.base_url);
+	assert_eq!("?pretty=true", second.get_url_qry());
+}
+
+#[test]
+fn dead_nodes_are_skipped() {
+	let pool = NodePool::new(vec!["http://es1:9200", "http://es2:9200", "http://es3:9200"]);
+
+	pool.mark_dead("http://es2:9200");
+
+	let used: Vec<String> = (0..4).map(|_| pool.next()).collect();
+
+	assert_eq!(vec!["http://es1:9200", "http://es3:9200", "http://es3:9200", "http://es1:9200"], used);
+	assert_eq!(vec!["http://es1:9200", "http://es3:9200"], pool.live_nodes());
+}
+
+#[test]
+fn dead_nodes_are_resurrected_after_timeout() {
+	let pool = NodePool::new(vec!["http://es1:9200", "http://es2:9200"])
+		.resurrect_after(Duration::from_millis(0));
+
+	pool.mark_dead("http://es1:9200");
+
+	assert_eq!(2, pool.live_nodes().len());
+	assert_eq!("http://es1:9200", pool.next());
+}
+
+#[test]
+fn a_dead_node_is_used_if_all_nodes_are_dead() {
+	let pool = NodePool::new(vec!["http://es1:9200", "http://es2:9200"]);
+
+	pool.mark_dead("http://es2:9200");
+	thread::sleep(Duration::from_millis(5));
+	pool.mark_dead("http://es1:9200");
+
+	assert_eq!(0, pool.live_nodes().len());
+	assert_eq!("http://es2:9200", pool.next());
+}
+
+#[test]
+fn send_marks_failed_node_dead_and_retries_idempotent_request() {
+	let pool = NodePool::new(vec!["http://es1:9200", "http://es2:9200"]);
+	let mut transport = FakeTransport::new(vec!["http://es1:9200"]);
+
+	let res = pool.send(&mut transport, &RequestParams::default(), ping);
+
+	assert_eq!(Ok("http://es2:9200/".to_owned()), res);
+	assert_eq!(vec!["http://es1:9200/", "http://es2:9200/"], transport.sent);
+	assert_eq!(vec!["http://es2:9200"], pool.live_nodes());
+}
+
+#[test]
+fn send_does_not_mark_node_dead_or_retry_for_non_connection_error() {
+	let pool = NodePool::new(vec!["http://es1:9200", "http://es2:9200"]);
+	let mut transport = FakeTransport::new(Vec::new()).broken(vec!["http://es1:9200"]);
+
+	let res = pool.send(&mut transport, &RequestParams::default(), ping);
+
+	assert_eq!(Err("bad response from http://es1:9200/".to_owned()), res);
+	assert_eq!(1, transport.sent.len());
+	assert_eq!(vec!["http://es1:9200", "http://es2:9200"], pool.live_nodes());
+}
+
+#[test]
+fn send_does_not_retry_non_idempotent_request() {
+	let pool = NodePool::new(vec!["http://es1:9200", "http://es2:9200"]);
+	let mut transport = FakeTransport::new(vec!["http://es1:9200"]);
+
+	let res = pool.send(&mut transport, &RequestParams::default(), index);
+
+	assert_eq!(Err("http://es1:9200/myindex/mytype".to_owned()), res);
+	assert_eq!(1, transport.sent.len());
+}
+
+#[test]
+fn send_stops_after_max_retries() {
+	let pool = NodePool::new(vec!["http://es1:9200", "http://es2:9200", "http://es3:9200"])
+		.max_retries(1);
+	let mut transport = FakeTransport::new(vec!["http://es1:9200", "http://es2:9200", "http://es3:9200"]);
+
+	let res = pool.send(&mut transport, &RequestParams::default(), ping);
+
+	assert!(res.is_err());
+	assert_eq!(2, transport.sent.len());
+}
+
+#[test]
+fn send_marks_dead_node_alive_on_success() {
+	let pool = NodePool::new(vec!["http://es1:9200"]);
+	let mut transport = FakeTransport::new(Vec::new());
+
+	pool.mark_dead("http://es1:9200");
+	pool.send(&mut transport, &RequestParams::default(), ping).unwrap();
+
+	assert_eq!(vec!["http://es1:9200"], pool.live_nodes());
+}
+
+#[test]
+#[should_panic]
+fn empty_pool_panics() {
+	let nodes: Vec<String> = Vec::new();
+
+	NodePool::new(nodes);
+}
+
+#[test]
+fn set_nodes_replaces_nodes_and_keeps_dead_state() {
+	let pool = NodePool::new(vec!["http://es1:9200", "http://es2:9200"]);
+
+	pool.mark_dead("http://es2:9200");
+	pool.set_nodes(vec!["http://es2:9200", "http://es3:9200"]);
+
+	assert_eq!(vec!["http://es2:9200", "http://es3:9200"], pool.nodes());
+	assert_eq!(vec!["http://es3:9200"], pool.live_nodes());
+}
+
+#[test]
+fn set_nodes_ignores_empty_nodes() {
+	let pool = NodePool::new(vec!["http://es1:9200"]);
+
+	pool.set_nodes(Vec::<String>::new());
+
+	assert_eq!(vec!["http://es1:9200"], pool.nodes());
+}
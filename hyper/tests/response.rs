@@ -0,0 +1,115 @@
+#![cfg(feature="serde-response")]
+
+#![feature(custom_derive, plugin)]
+#![plugin(serde_macros)]
+
+extern crate hyper;
+extern crate serde;
+extern crate elastic_hyper;
+
+use hyper::status::StatusCode;
+use elastic_hyper::response::*;
+
+#[derive(Debug, Deserialize)]
+struct MyType {
+	title: String
+}
+
+#[test]
+fn parse_get_response_found() {
+	let body = r#"{"_index":"myindex","_type":"mytype","_id":"1","_version":1,"found":true,"_source":{"title":"doc"}}"#;
+
+	let res: GetResponse<MyType> = from_reader(StatusCode::Ok, body.as_bytes()).unwrap();
+
+	assert!(res.found);
+	assert_eq!(Some(1), res.version);
+	assert_eq!("doc", res.source.unwrap().title);
+}
+
+#[test]
+fn parse_get_response_not_found() {
+	let body = r#"{"_index":"myindex","_type":"mytype","_id":"1","found":false}"#;
+
+	let res: GetResponse<MyType> = from_reader(StatusCode::NotFound, body.as_bytes()).unwrap();
+
+	assert!(!res.found);
+	assert!(res.source.is_none());
+}
+
+#[test]
+fn parse_index_response() {
+	let body = r#"{"_index":"myindex","_type":"mytype","_id":"1","_version":2,"_shards":{"total":2,"successful":1,"failed":0},"created":false}"#;
+
+	let res: IndexResponse = from_reader(StatusCode::Ok, body.as_bytes()).unwrap();
+
+	assert_eq!(2, res.version);
+	assert_eq!(1, res.shards.successful);
+	assert!(!res.created);
+}
+
+#[test]
+fn parse_count_response() {
+	let body = r#"{"count":42,"_shards":{"total":5,"successful":5,"failed":0}}"#;
+
+	let res: CountResponse = from_reader(StatusCode::Ok, body.as_bytes()).unwrap();
+
+	assert_eq!(42, res.count);
+}
+
+#[test]
+fn parse_bulk_response() {
+	let body = r#"{"took":3,"errors":true,"items":[
+		{"index":{"_index":"myindex","_type":"mytype","_id":"1","_version":1,"status":201}},
+		{"delete":{"_index":"myindex","_type":"mytype","_id":"2","status":404,"error":{"type":"document_missing_exception","reason":"[mytype][2]: document missing"}}}
+	]}"#;
+
+	let res: BulkResponse = from_reader(StatusCode::Ok, body.as_bytes()).unwrap();
+
+	assert!(res.errors);
+	assert_eq!(BulkAction::Index, res.items[0].action);
+	assert!(res.items[0].is_ok());
+	assert_eq!(BulkAction::Delete, res.items[1].action);
+	assert!(!res.items[1].is_ok());
+}
+
+#[test]
+fn parse_bulk_response_with_delete_of_missing_document() {
+	let body = r#"{"took":3,"errors":false,"items":[
+		{"delete":{"_index":"myindex","_type":"mytype","_id":"1","_version":2,"found":true,"status":200}},
+		{"delete":{"_index":"myindex","_type":"mytype","_id":"2","_version":1,"found":false,"status":404}}
+	]}"#;
+
+	let res: BulkResponse = from_reader(StatusCode::Ok, body.as_bytes()).unwrap();
+
+	assert_eq!(Some(true), res.items[0].found);
+	assert!(res.items[0].is_ok());
+	assert_eq!(Some(false), res.items[1].found);
+	assert!(res.items[1].is_ok());
+}
+
+#[test]
+fn parse_api_error() {
+	let body = r#"{"error":{"root_cause":[{"type":"index_not_found_exception","reason":"no such index","index":"myindex"}],"type":"index_not_found_exception","reason":"no such index","index":"myindex"},"status":404}"#;
+
+	let res: Result<CountResponse, ApiError> = from_reader(StatusCode::NotFound, body.as_bytes());
+
+	match res {
+		Err(ApiError::Server(err)) => {
+			assert_eq!(404, err.status);
+			assert_eq!("index_not_found_exception", err.error.ty);
+			assert_eq!(Some("myindex".to_owned()), err.error.index);
+			assert_eq!(1, err.error.root_cause.unwrap().len());
+		},
+		r => panic!("expected a server error, got {:?}", r)
+	}
+}
+
+#[test]
+fn parse_invalid_body_is_parse_error() {
+	let res: Result<CountResponse, ApiError> = from_reader(StatusCode::Ok, "not json".as_bytes());
+
+	match res {
+		Err(ApiError::Parse(_)) => (),
+		r => panic!("expected a parse error, got {:?}", r)
+	}
+}
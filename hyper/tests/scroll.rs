@@ -0,0 +1,189 @@
+#![cfg(feature="serde-response")]
+
+#![feature(custom_derive, plugin)]
+#![plugin(serde_macros)]
+
+extern crate hyper;
+extern crate serde;
+extern crate elastic_hyper;
+
+use std::io::{ BufRead, BufReader, Read, Write };
+use std::net::{ TcpListener, TcpStream };
+use std::sync::{ Arc, Mutex };
+use std::thread;
+use elastic_hyper::RequestParams;
+use elastic_hyper::response::ScrollIter;
+
+#[derive(Debug, Deserialize)]
+struct MyType {
+	title: String
+}
+
+/// The method, path and body of each request received by a stand-in.
+type Received = Arc<Mutex<Vec<String>>>;
+
+/// A loopback stand-in for a node that answers searches with canned pages of hits.
+///
+/// Search and scroll requests are given the next page in order, and clearing a scroll always succeeds.
+/// Returns the base url for the stand-in and the requests it has received.
+fn stand_in(pages: Vec<(u16, String)>) -> (String, Received) {
+	let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+	let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+	let received: Received = Arc::new(Mutex::new(Vec::new()));
+	let pages = Arc::new(Mutex::new(pages.into_iter()));
+
+	let server_received = received.clone();
+	thread::spawn(move || {
+		for stream in listener.incoming() {
+			let received = server_received.clone();
+			let pages = pages.clone();
+
+			match stream {
+				Ok(stream) => { thread::spawn(move || serve(stream, received, pages)); },
+				Err(_) => break
+			}
+		}
+	});
+
+	(base_url, received)
+}
+
+fn serve(stream: TcpStream, received: Received, pages: Arc<Mutex<::std::vec::IntoIter<(u16, String)>>>) {
+	let mut writer = stream.try_clone().unwrap();
+	let mut reader = BufReader::new(stream);
+
+	loop {
+		let mut request_line = String::new();
+		if reader.read_line(&mut request_line).unwrap_or(0) == 0 {
+			return;
+		}
+
+		let (method, path) = {
+			let mut parts = request_line.trim().split(' ');
+			(parts.next().unwrap().to_owned(), parts.next().unwrap().to_owned())
+		};
+
+		let mut len = 0;
+		loop {
+			let mut header = String::new();
+			reader.read_line(&mut header).unwrap();
+
+			let header = header.trim().to_lowercase();
+			if header.len() == 0 {
+				break;
+			}
+
+			if header.starts_with("content-length:") {
+				len = header["content-length:".len()..].trim().parse().unwrap();
+			}
+		}
+
+		let mut req_body = vec![0; len];
+		reader.read_exact(&mut req_body).unwrap();
+
+		let req = format!("{} {} {}", method, path, String::from_utf8(req_body).unwrap());
+		received.lock().unwrap().push(req.trim().to_owned());
+
+		let (status, body) = match method.as_str() {
+			"DELETE" => (200, r#"{"succeeded":true}"#.to_owned()),
+			_ => pages.lock().unwrap().next().expect("no more pages")
+		};
+
+		let res = format!("HTTP/1.1 {} Stand-in\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}", status, body.len(), body);
+		if writer.write_all(res.as_bytes()).is_err() {
+			return;
+		}
+	}
+}
+
+fn page(scroll_id: &str, ids: &[&str]) -> (u16, String) {
+	let hits: Vec<String> = ids
+		.iter()
+		.map(|id| format!(r#"{{"_index":"myindex","_type":"mytype","_id":"{}","_score":1.0,"_source":{{"title":"doc {}"}}}}"#, id, id))
+		.collect();
+
+	(200, format!(
+		r#"{{"took":1,"timed_out":false,"_shards":{{"total":1,"successful":1,"failed":0}},"_scroll_id":"{}","hits":{{"total":3,"hits":[{}]}}}}"#,
+		scroll_id,
+		hits.join(",")
+	))
+}
+
+fn params(base_url: &str) -> RequestParams {
+	let mut req = RequestParams::default();
+	req.base_url = base_url.to_owned();
+
+	req
+}
+
+const QUERY: &'static str = r#"{"size":2,"query":{"match_all":{}}}"#;
+
+#[test]
+fn scroll_iter_pages_through_hits() {
+	let (base_url, requests) = stand_in(vec![
+		page("s1", &["1", "2"]),
+		page("s2", &["3"]),
+		page("s3", &[])
+	]);
+
+	let mut client = hyper::Client::new();
+	let req = params(&base_url);
+
+	let hits: Vec<_> = ScrollIter::<MyType>::new(&mut client, &req, "myindex", "1m", QUERY)
+		.unwrap()
+		.map(|hit| hit.unwrap())
+		.collect();
+
+	assert_eq!(vec!["1", "2", "3"], hits.iter().map(|hit| hit.id.as_str()).collect::<Vec<_>>());
+	assert_eq!("doc 3", hits[2].source.as_ref().unwrap().title);
+
+	let expected = vec![
+		format!("POST /myindex/_search?scroll=1m {}", QUERY),
+		r#"POST /_search/scroll {"scroll":"1m","scroll_id":"s1"}"#.to_owned(),
+		r#"POST /_search/scroll {"scroll":"1m","scroll_id":"s2"}"#.to_owned(),
+		"DELETE /_search/scroll/s3".to_owned()
+	];
+	assert_eq!(expected, *requests.lock().unwrap());
+}
+
+#[test]
+fn scroll_iter_clears_scroll_on_error() {
+	let (base_url, requests) = stand_in(vec![
+		page("s1", &["1"]),
+		(500, r#"{"error":{"type":"search_context_missing_exception","reason":"No search context found"},"status":500}"#.to_owned())
+	]);
+
+	let mut client = hyper::Client::new();
+	let req = params(&base_url);
+
+	let mut hits = ScrollIter::<MyType>::new(&mut client, &req, "myindex", "1m", QUERY).unwrap();
+
+	assert_eq!("1", hits.next().unwrap().unwrap().id);
+	assert!(hits.next().unwrap().is_err());
+	assert!(hits.next().is_none());
+
+	assert_eq!(Some(&"DELETE /_search/scroll/s1".to_owned()), requests.lock().unwrap().last());
+	assert_eq!(3, requests.lock().unwrap().len());
+}
+
+#[test]
+fn scroll_iter_clears_scroll_when_dropped() {
+	let (base_url, requests) = stand_in(vec![
+		page("s1", &["1", "2"])
+	]);
+
+	let mut client = hyper::Client::new();
+	let req = params(&base_url);
+
+	{
+		let mut hits = ScrollIter::<MyType>::new(&mut client, &req, "myindex", "1m", QUERY).unwrap();
+		assert_eq!("1", hits.next().unwrap().unwrap().id);
+	}
+
+	let expected = vec![
+		format!("POST /myindex/_search?scroll=1m {}", QUERY),
+		"DELETE /_search/scroll/s1".to_owned()
+	];
+	assert_eq!(expected, *requests.lock().unwrap());
+}
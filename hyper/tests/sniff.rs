@@ -0,0 +1,218 @@
+#![cfg(feature="serde-response")]
+
+extern crate hyper;
+extern crate serde_json;
+extern crate elastic_hyper;
+
+use std::io::{ BufRead, BufReader, Read, Write };
+use std::net::{ TcpListener, TcpStream };
+use std::sync::Arc;
+use std::thread;
+use std::time::{ Duration, Instant };
+use elastic_hyper::{ NodePool, RequestParams };
+use elastic_hyper::sniff::*;
+
+/// A loopback stand-in for a node that answers every request with the same Nodes Info response.
+///
+/// The response body is built from the stand-in's own address.
+/// Returns the base url for the stand-in.
+fn stand_in<F>(body: F) -> String
+where F: FnOnce(&str) -> String {
+	let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+	let addr = listener.local_addr().unwrap().to_string();
+	let base_url = format!("http://{}", addr);
+	let body = body(&addr);
+
+	thread::spawn(move || {
+		for stream in listener.incoming() {
+			let body = body.clone();
+
+			match stream {
+				Ok(stream) => { thread::spawn(move || serve(stream, &body)); },
+				Err(_) => break
+			}
+		}
+	});
+
+	base_url
+}
+
+fn serve(stream: TcpStream, body: &str) {
+	let mut writer = stream.try_clone().unwrap();
+	let mut reader = BufReader::new(stream);
+
+	loop {
+		let mut request_line = String::new();
+		if reader.read_line(&mut request_line).unwrap_or(0) == 0 {
+			return;
+		}
+
+		assert!(request_line.starts_with("GET /_nodes/http "), "unexpected request {}", request_line);
+
+		let mut len = 0;
+		loop {
+			let mut header = String::new();
+			reader.read_line(&mut header).unwrap();
+
+			let header = header.trim().to_lowercase();
+			if header.len() == 0 {
+				break;
+			}
+
+			if header.starts_with("content-length:") {
+				len = header["content-length:".len()..].trim().parse().unwrap();
+			}
+		}
+
+		let mut req_body = vec![0; len];
+		reader.read_exact(&mut req_body).unwrap();
+
+		let res = format!("HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}", body.len(), body);
+		if writer.write_all(res.as_bytes()).is_err() {
+			return;
+		}
+	}
+}
+
+fn nodes_info(addrs: &[&str]) -> String {
+	let nodes: Vec<String> = addrs
+		.iter()
+		.enumerate()
+		.map(|(i, addr)| format!(r#""node{}": {{ "roles": [ "master", "data" ], "http": {{ "publish_address": "{}" }} }}"#, i, addr))
+		.collect();
+
+	format!(r#"{{ "cluster_name": "test", "nodes": {{ {} }} }}"#, nodes.join(", "))
+}
+
+#[test]
+fn nodes_from_info_reads_publish_addresses() {
+	let info: serde_json::Value = serde_json::from_str(r#"{
+		"nodes": {
+			"a": { "roles": [ "master", "data", "ingest" ], "http": { "publish_address": "10.0.0.1:9200" } },
+			"b": { "roles": [ "data" ], "http": { "publish_address": "es2/10.0.0.2:9200" } },
+			"c": { "roles": [ "master" ], "http": { "publish_address": "10.0.0.3:9200" } },
+			"d": { "roles": [ "ingest" ] }
+		}
+	}"#).unwrap();
+
+	assert_eq!(vec!["http://10.0.0.1:9200", "http://10.0.0.2:9200"], nodes_from_info(&info, "http"));
+}
+
+#[test]
+fn nodes_from_info_reads_attributes_from_older_versions() {
+	let info: serde_json::Value = serde_json::from_str(r#"{
+		"nodes": {
+			"a": { "http_address": "inet[/10.0.0.1:9200]", "http": { "publish_address": "inet[/10.0.0.1:9200]" } },
+			"b": { "attributes": { "data": "false", "client": "true" }, "http": { "publish_address": "inet[/10.0.0.2:9200]" } },
+			"c": { "attributes": { "data": "false", "master": "true" }, "http": { "publish_address": "inet[/10.0.0.3:9200]" } }
+		}
+	}"#).unwrap();
+
+	assert_eq!(vec!["http://10.0.0.1:9200", "http://10.0.0.2:9200"], nodes_from_info(&info, "http"));
+}
+
+#[test]
+fn nodes_from_info_uses_scheme() {
+	let info: serde_json::Value = serde_json::from_str(&nodes_info(&[ "10.0.0.1:9200" ])).unwrap();
+
+	assert_eq!(vec!["https://10.0.0.1:9200"], nodes_from_info(&info, "https"));
+}
+
+#[test]
+fn sniff_replaces_nodes_in_pool() {
+	let base_url = stand_in(|_| nodes_info(&[ "127.0.0.1:9201", "localhost/127.0.0.1:9202" ]));
+	let pool = NodePool::new(vec![base_url]);
+
+	let nodes = Sniffer::new(RequestParams::default())
+		.sniff(&pool, &mut hyper::Client::new())
+		.unwrap();
+
+	assert_eq!(vec!["http://127.0.0.1:9201", "http://127.0.0.1:9202"], nodes);
+	assert_eq!(nodes, pool.nodes());
+}
+
+#[test]
+fn sniff_uses_configured_scheme() {
+	let base_url = stand_in(|_| nodes_info(&[ "127.0.0.1:9201" ]));
+	let pool = NodePool::new(vec![base_url]);
+
+	let nodes = Sniffer::new(RequestParams::default())
+		.scheme("https")
+		.sniff(&pool, &mut hyper::Client::new())
+		.unwrap();
+
+	assert_eq!(vec!["https://127.0.0.1:9201"], nodes);
+	assert_eq!(nodes, pool.nodes());
+}
+
+#[test]
+fn sniff_retries_on_another_node() {
+	let dead = {
+		let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+		format!("http://{}", listener.local_addr().unwrap())
+	};
+	let base_url = stand_in(|_| nodes_info(&[ "127.0.0.1:9201" ]));
+	let pool = NodePool::new(vec![dead, base_url]);
+
+	let nodes = Sniffer::new(RequestParams::default())
+		.sniff(&pool, &mut hyper::Client::new())
+		.unwrap();
+
+	assert_eq!(vec!["http://127.0.0.1:9201"], nodes);
+}
+
+#[test]
+fn sniff_without_nodes_keeps_pool() {
+	let base_url = stand_in(|_| r#"{ "cluster_name": "test", "nodes": {} }"#.to_owned());
+	let pool = NodePool::new(vec![base_url.clone()]);
+
+	let res = Sniffer::new(RequestParams::default()).sniff(&pool, &mut hyper::Client::new());
+
+	match res {
+		Err(SniffError::NoNodes) => (),
+		r => panic!("expected no nodes, got {:?}", r.map(|_| ()))
+	}
+	assert_eq!(vec![base_url], pool.nodes());
+}
+
+#[test]
+fn sniff_in_background_starts_straight_away() {
+	let base_url = stand_in(|addr| nodes_info(&[ addr, "127.0.0.1:9201" ]));
+	let pool = Arc::new(NodePool::new(vec![base_url.clone()]));
+
+	//The interval is long enough that only the first sniff can update the pool
+	let handle = Sniffer::new(RequestParams::default())
+		.interval(Duration::from_secs(300))
+		.spawn(pool.clone(), hyper::Client::new());
+
+	let expected = vec![base_url, "http://127.0.0.1:9201".to_owned()];
+	let timeout = Instant::now() + Duration::from_secs(5);
+	while pool.nodes() != expected && Instant::now() < timeout {
+		thread::sleep(Duration::from_millis(10));
+	}
+
+	drop(handle);
+
+	assert_eq!(expected, pool.nodes());
+}
+
+#[test]
+fn sniff_in_background_updates_pool() {
+	//The stand-in reports itself along with a new node, so later sniffs keep succeeding
+	let base_url = stand_in(|addr| nodes_info(&[ addr, "127.0.0.1:9201" ]));
+	let pool = Arc::new(NodePool::new(vec![base_url.clone()]));
+
+	let handle = Sniffer::new(RequestParams::default())
+		.interval(Duration::from_millis(10))
+		.spawn(pool.clone(), hyper::Client::new());
+
+	let expected = vec![base_url, "http://127.0.0.1:9201".to_owned()];
+	let timeout = Instant::now() + Duration::from_secs(5);
+	while pool.nodes() != expected && Instant::now() < timeout {
+		thread::sleep(Duration::from_millis(10));
+	}
+
+	drop(handle);
+
+	assert_eq!(expected, pool.nodes());
+}
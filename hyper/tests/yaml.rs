@@ -0,0 +1,116 @@
+#![cfg(feature="test-integration")]
+
+//! Run the Elasticsearch YAML REST tests against the generated endpoint functions.
+//!
+//! These tests need a running node at `localhost:9200`, and delete all indices between tests.
+
+extern crate hyper;
+extern crate serde_json;
+extern crate elastic_hyper;
+extern crate elastic_codegen;
+
+#[path="yaml/api.rs"]
+mod api;
+
+use std::io::Read;
+use std::collections::BTreeMap;
+use hyper::client::Client;
+use serde_json::Value;
+use elastic_hyper::RequestParams;
+use elastic_codegen::test::ast::Version;
+use elastic_codegen::test::parse;
+use elastic_codegen::test::run;
+use elastic_codegen::test::run::{ Runner, Outcome };
+
+struct HyperClient {
+	client: Client,
+	req: RequestParams
+}
+
+impl run::Client for HyperClient {
+	fn call(&mut self, req: &run::Request) -> Result<run::Response, String> {
+		let mut params = self.req.clone();
+		for (name, value) in &req.headers {
+			params.headers.set_raw(name.clone(), vec![value.clone().into_bytes()]);
+		}
+
+		let body = req.body.as_ref().map(|b| b.as_str());
+		let mut res = match try!(api::call(&mut self.client, &params, &req.api, &req.args, body)) {
+			Ok(res) => res,
+			Err(e) => return Err(format!("`{}` request failed: {}", req.api, e))
+		};
+
+		let mut body = String::new();
+		let _ = res.read_to_string(&mut body);
+
+		//Non-json bodies, like from the `cat` endpoints, are kept as strings
+		let body = match body.len() {
+			0 => Value::Null,
+			_ => serde_json::from_str(&body).unwrap_or(Value::String(body))
+		};
+
+		Ok(run::Response {
+			status: res.status.to_u16(),
+			body: body
+		})
+	}
+}
+
+impl HyperClient {
+	fn version(&mut self) -> Version {
+		let req = run::Request {
+			api: "info".to_owned(),
+			args: BTreeMap::new(),
+			body: None,
+			headers: BTreeMap::new()
+		};
+
+		let res = run::Client::call(self, &req).unwrap();
+		let number = res.body
+			.find_path(&["version", "number"])
+			.and_then(|n| n.as_string())
+			.expect("expected a version number from `info`");
+
+		Version::parse(number).unwrap()
+	}
+
+	fn delete_all_indices(&mut self) {
+		let req = run::Request {
+			api: "indices.delete".to_owned(),
+			args: vec![("index".to_owned(), "_all".to_owned())].into_iter().collect(),
+			body: None,
+			headers: BTreeMap::new()
+		};
+
+		let _ = run::Client::call(self, &req);
+	}
+}
+
+#[test]
+fn yaml_tests() {
+	let files = parse::from_dir("../codegen/spec/test").unwrap();
+
+	let mut client = HyperClient {
+		client: Client::new(),
+		req: RequestParams::default()
+	};
+
+	let version = client.version();
+	let mut runner = Runner::new(client).version(version);
+
+	let mut failed = Vec::new();
+	for file in &files {
+		for test in &file.tests {
+			let result = runner.run_test(file, test);
+			println!("{}", result);
+
+			if let Outcome::Failed(_) = result.outcome {
+				failed.push(result);
+			}
+
+			runner.client().delete_all_indices();
+		}
+	}
+
+	assert!(failed.len() == 0, "{} yaml tests failed", failed.len());
+}
@@ -0,0 +1,1914 @@
+//! Call the generated endpoint functions by api name.
+
+//Autogenerated
+
+use std::collections::BTreeMap;
+use hyper::client::Client;
+use hyper::client::response::Response;
+use hyper::error::Result;
+use elastic_hyper::*;
+
+/// Call the endpoint function for an api, like `cluster.health`.
+///
+/// Args that are url parts pick the function to call, and the rest are sent as url params.
+/// Returns `Err` if the api or a url param isn't known, or if no url takes the given parts.
+pub fn call(client: &mut Client, req: &RequestParams, api: &str, args: &BTreeMap<String, String>, body: Option<&str>) -> ::std::result::Result<Result<Response>, String> {
+    match api {
+        "bulk" => {
+            let (parts, req) = try!(split_args(req, args, &["index", "type"]));
+            let params = &bulk::Params::default();
+
+            if has_parts(&parts, &["index", "type"]) {
+                return Ok(match body {
+                    Some(body) => bulk::post_index_type(client, &req, params, &parts["index"], &parts["type"], body),
+                    None => bulk::post_index_type(client, &req, params, &parts["index"], &parts["type"], "")
+                });
+            }
+            if has_parts(&parts, &["index"]) {
+                return Ok(match body {
+                    Some(body) => bulk::post_index(client, &req, params, &parts["index"], body),
+                    None => bulk::post_index(client, &req, params, &parts["index"], "")
+                });
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(match body {
+                    Some(body) => bulk::post(client, &req, params, body),
+                    None => bulk::post(client, &req, params, "")
+                });
+            }
+
+            Err(format!("no url for `bulk` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "cat.aliases" => {
+            let (parts, req) = try!(split_args(req, args, &["name"]));
+            let params = &cat::aliases::Params::default();
+
+            if has_parts(&parts, &["name"]) {
+                return Ok(cat::aliases::get_name(client, &req, params, &parts["name"]));
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(cat::aliases::get(client, &req, params));
+            }
+
+            Err(format!("no url for `cat.aliases` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "cat.allocation" => {
+            let (parts, req) = try!(split_args(req, args, &["node_id"]));
+            let params = &cat::allocation::Params::default();
+
+            if has_parts(&parts, &["node_id"]) {
+                return Ok(cat::allocation::get_node_id(client, &req, params, &parts["node_id"]));
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(cat::allocation::get(client, &req, params));
+            }
+
+            Err(format!("no url for `cat.allocation` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "cat.count" => {
+            let (parts, req) = try!(split_args(req, args, &["index"]));
+            let params = &cat::count::Params::default();
+
+            if has_parts(&parts, &["index"]) {
+                return Ok(cat::count::get_index(client, &req, params, &parts["index"]));
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(cat::count::get(client, &req, params));
+            }
+
+            Err(format!("no url for `cat.count` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "cat.fielddata" => {
+            let (parts, req) = try!(split_args(req, args, &["fields"]));
+            let params = &cat::fielddata::Params::default();
+
+            if has_parts(&parts, &["fields"]) {
+                return Ok(cat::fielddata::get_fields(client, &req, params, &parts["fields"]));
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(cat::fielddata::get(client, &req, params));
+            }
+
+            Err(format!("no url for `cat.fielddata` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "cat.health" => {
+            let (parts, req) = try!(split_args(req, args, &[]));
+            let params = &cat::health::Params::default();
+
+            if has_parts(&parts, &[]) {
+                return Ok(cat::health::get(client, &req, params));
+            }
+
+            Err(format!("no url for `cat.health` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "cat.help" => {
+            let (parts, req) = try!(split_args(req, args, &[]));
+            let params = &cat::help::Params::default();
+
+            if has_parts(&parts, &[]) {
+                return Ok(cat::help::get(client, &req, params));
+            }
+
+            Err(format!("no url for `cat.help` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "cat.indices" => {
+            let (parts, req) = try!(split_args(req, args, &["index"]));
+            let params = &cat::indices::Params::default();
+
+            if has_parts(&parts, &["index"]) {
+                return Ok(cat::indices::get_index(client, &req, params, &parts["index"]));
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(cat::indices::get(client, &req, params));
+            }
+
+            Err(format!("no url for `cat.indices` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "cat.master" => {
+            let (parts, req) = try!(split_args(req, args, &[]));
+            let params = &cat::master::Params::default();
+
+            if has_parts(&parts, &[]) {
+                return Ok(cat::master::get(client, &req, params));
+            }
+
+            Err(format!("no url for `cat.master` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "cat.nodeattrs" => {
+            let (parts, req) = try!(split_args(req, args, &[]));
+            let params = &cat::nodeattrs::Params::default();
+
+            if has_parts(&parts, &[]) {
+                return Ok(cat::nodeattrs::get(client, &req, params));
+            }
+
+            Err(format!("no url for `cat.nodeattrs` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "cat.nodes" => {
+            let (parts, req) = try!(split_args(req, args, &[]));
+            let params = &cat::nodes::Params::default();
+
+            if has_parts(&parts, &[]) {
+                return Ok(cat::nodes::get(client, &req, params));
+            }
+
+            Err(format!("no url for `cat.nodes` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "cat.pending_tasks" => {
+            let (parts, req) = try!(split_args(req, args, &[]));
+            let params = &cat::pending_tasks::Params::default();
+
+            if has_parts(&parts, &[]) {
+                return Ok(cat::pending_tasks::get(client, &req, params));
+            }
+
+            Err(format!("no url for `cat.pending_tasks` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "cat.plugins" => {
+            let (parts, req) = try!(split_args(req, args, &[]));
+            let params = &cat::plugins::Params::default();
+
+            if has_parts(&parts, &[]) {
+                return Ok(cat::plugins::get(client, &req, params));
+            }
+
+            Err(format!("no url for `cat.plugins` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "cat.recovery" => {
+            let (parts, req) = try!(split_args(req, args, &["index"]));
+            let params = &cat::recovery::Params::default();
+
+            if has_parts(&parts, &["index"]) {
+                return Ok(cat::recovery::get_index(client, &req, params, &parts["index"]));
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(cat::recovery::get(client, &req, params));
+            }
+
+            Err(format!("no url for `cat.recovery` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "cat.repositories" => {
+            let (parts, req) = try!(split_args(req, args, &[]));
+            let params = &cat::repositories::Params::default();
+
+            if has_parts(&parts, &[]) {
+                return Ok(cat::repositories::get(client, &req, params));
+            }
+
+            Err(format!("no url for `cat.repositories` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "cat.segments" => {
+            let (parts, req) = try!(split_args(req, args, &["index"]));
+            let params = &cat::segments::Params::default();
+
+            if has_parts(&parts, &["index"]) {
+                return Ok(cat::segments::get_index(client, &req, params, &parts["index"]));
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(cat::segments::get(client, &req, params));
+            }
+
+            Err(format!("no url for `cat.segments` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "cat.shards" => {
+            let (parts, req) = try!(split_args(req, args, &["index"]));
+            let params = &cat::shards::Params::default();
+
+            if has_parts(&parts, &["index"]) {
+                return Ok(cat::shards::get_index(client, &req, params, &parts["index"]));
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(cat::shards::get(client, &req, params));
+            }
+
+            Err(format!("no url for `cat.shards` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "cat.snapshots" => {
+            let (parts, req) = try!(split_args(req, args, &["repository"]));
+            let params = &cat::snapshots::Params::default();
+
+            if has_parts(&parts, &["repository"]) {
+                return Ok(cat::snapshots::get_repository(client, &req, params, &parts["repository"]));
+            }
+
+            Err(format!("no url for `cat.snapshots` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "cat.tasks" => {
+            let (parts, req) = try!(split_args(req, args, &[]));
+            let params = &cat::tasks::Params::default();
+
+            if has_parts(&parts, &[]) {
+                return Ok(cat::tasks::get(client, &req, params));
+            }
+
+            Err(format!("no url for `cat.tasks` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "cat.thread_pool" => {
+            let (parts, req) = try!(split_args(req, args, &[]));
+            let params = &cat::thread_pool::Params::default();
+
+            if has_parts(&parts, &[]) {
+                return Ok(cat::thread_pool::get(client, &req, params));
+            }
+
+            Err(format!("no url for `cat.thread_pool` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "clear_scroll" => {
+            let (parts, req) = try!(split_args(req, args, &["scroll_id"]));
+            let params = &clear_scroll::Params::default();
+
+            if has_parts(&parts, &["scroll_id"]) {
+                return Ok(clear_scroll::delete_scroll_id(client, &req, params, &parts["scroll_id"]));
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(clear_scroll::delete(client, &req, params));
+            }
+
+            Err(format!("no url for `clear_scroll` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "cluster.allocation_explain" => {
+            let (parts, req) = try!(split_args(req, args, &[]));
+            let params = &cluster::allocation_explain::Params::default();
+
+            if has_parts(&parts, &[]) {
+                return Ok(match body {
+                    Some(body) => cluster::allocation_explain::post(client, &req, params, body),
+                    None => cluster::allocation_explain::get(client, &req, params)
+                });
+            }
+
+            Err(format!("no url for `cluster.allocation_explain` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "cluster.get_settings" => {
+            let (parts, req) = try!(split_args(req, args, &[]));
+            let params = &cluster::get_settings::Params::default();
+
+            if has_parts(&parts, &[]) {
+                return Ok(cluster::get_settings::get(client, &req, params));
+            }
+
+            Err(format!("no url for `cluster.get_settings` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "cluster.health" => {
+            let (parts, req) = try!(split_args(req, args, &["index"]));
+            let params = &cluster::health::Params::default();
+
+            if has_parts(&parts, &["index"]) {
+                return Ok(cluster::health::get_index(client, &req, params, &parts["index"]));
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(cluster::health::get(client, &req, params));
+            }
+
+            Err(format!("no url for `cluster.health` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "cluster.pending_tasks" => {
+            let (parts, req) = try!(split_args(req, args, &[]));
+            let params = &cluster::pending_tasks::Params::default();
+
+            if has_parts(&parts, &[]) {
+                return Ok(cluster::pending_tasks::get(client, &req, params));
+            }
+
+            Err(format!("no url for `cluster.pending_tasks` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "cluster.put_settings" => {
+            let (parts, req) = try!(split_args(req, args, &[]));
+            let params = &cluster::put_settings::Params::default();
+
+            if has_parts(&parts, &[]) {
+                return Ok(match body {
+                    Some(body) => cluster::put_settings::put(client, &req, params, body),
+                    None => cluster::put_settings::put(client, &req, params, "")
+                });
+            }
+
+            Err(format!("no url for `cluster.put_settings` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "cluster.reroute" => {
+            let (parts, req) = try!(split_args(req, args, &[]));
+            let params = &cluster::reroute::Params::default();
+
+            if has_parts(&parts, &[]) {
+                return Ok(match body {
+                    Some(body) => cluster::reroute::post(client, &req, params, body),
+                    None => cluster::reroute::post(client, &req, params, "")
+                });
+            }
+
+            Err(format!("no url for `cluster.reroute` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "cluster.state" => {
+            let (parts, req) = try!(split_args(req, args, &["index", "metric"]));
+            let params = &cluster::state::Params::default();
+
+            if has_parts(&parts, &["metric", "index"]) {
+                return Ok(cluster::state::get_metric_index(client, &req, params, &parts["metric"], &parts["index"]));
+            }
+            if has_parts(&parts, &["metric"]) {
+                return Ok(cluster::state::get_metric(client, &req, params, &parts["metric"]));
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(cluster::state::get(client, &req, params));
+            }
+
+            Err(format!("no url for `cluster.state` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "cluster.stats" => {
+            let (parts, req) = try!(split_args(req, args, &["node_id"]));
+            let params = &cluster::stats::Params::default();
+
+            if has_parts(&parts, &["node_id"]) {
+                return Ok(cluster::stats::get_node_id(client, &req, params, &parts["node_id"]));
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(cluster::stats::get(client, &req, params));
+            }
+
+            Err(format!("no url for `cluster.stats` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "count" => {
+            let (parts, req) = try!(split_args(req, args, &["index", "type"]));
+            let params = &count::Params::default();
+
+            if has_parts(&parts, &["index", "type"]) {
+                return Ok(match body {
+                    Some(body) => count::post_index_type(client, &req, params, &parts["index"], &parts["type"], body),
+                    None => count::get_index_type(client, &req, params, &parts["index"], &parts["type"])
+                });
+            }
+            if has_parts(&parts, &["index"]) {
+                return Ok(match body {
+                    Some(body) => count::post_index(client, &req, params, &parts["index"], body),
+                    None => count::get_index(client, &req, params, &parts["index"])
+                });
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(match body {
+                    Some(body) => count::post(client, &req, params, body),
+                    None => count::get(client, &req, params)
+                });
+            }
+
+            Err(format!("no url for `count` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "count_percolate" => {
+            let (parts, req) = try!(split_args(req, args, &["id", "index", "type"]));
+            let params = &count_percolate::Params::default();
+
+            if has_parts(&parts, &["index", "type", "id"]) {
+                return Ok(match body {
+                    Some(body) => count_percolate::post_index_type_id(client, &req, params, &parts["index"], &parts["type"], &parts["id"], body),
+                    None => count_percolate::get_index_type_id(client, &req, params, &parts["index"], &parts["type"], &parts["id"])
+                });
+            }
+            if has_parts(&parts, &["index", "type"]) {
+                return Ok(match body {
+                    Some(body) => count_percolate::post_index_type(client, &req, params, &parts["index"], &parts["type"], body),
+                    None => count_percolate::get_index_type(client, &req, params, &parts["index"], &parts["type"])
+                });
+            }
+
+            Err(format!("no url for `count_percolate` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "delete" => {
+            let (parts, req) = try!(split_args(req, args, &["id", "index", "type"]));
+            let params = &delete::Params::default();
+
+            if has_parts(&parts, &["index", "type", "id"]) {
+                return Ok(delete::delete_index_type_id(client, &req, params, &parts["index"], &parts["type"], &parts["id"]));
+            }
+
+            Err(format!("no url for `delete` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "delete_script" => {
+            let (parts, req) = try!(split_args(req, args, &["id", "lang"]));
+            let params = &delete_script::Params::default();
+
+            if has_parts(&parts, &["lang", "id"]) {
+                return Ok(delete_script::delete_lang_id(client, &req, params, &parts["lang"], &parts["id"]));
+            }
+
+            Err(format!("no url for `delete_script` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "delete_template" => {
+            let (parts, req) = try!(split_args(req, args, &["id"]));
+            let params = &delete_template::Params::default();
+
+            if has_parts(&parts, &["id"]) {
+                return Ok(delete_template::delete_id(client, &req, params, &parts["id"]));
+            }
+
+            Err(format!("no url for `delete_template` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "exists" => {
+            let (parts, req) = try!(split_args(req, args, &["id", "index", "type"]));
+            let params = &exists::Params::default();
+
+            if has_parts(&parts, &["index", "type", "id"]) {
+                return Ok(exists::head_index_type_id(client, &req, params, &parts["index"], &parts["type"], &parts["id"]));
+            }
+
+            Err(format!("no url for `exists` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "explain" => {
+            let (parts, req) = try!(split_args(req, args, &["id", "index", "type"]));
+            let params = &explain::Params::default();
+
+            if has_parts(&parts, &["index", "type", "id"]) {
+                return Ok(match body {
+                    Some(body) => explain::post_index_type_id(client, &req, params, &parts["index"], &parts["type"], &parts["id"], body),
+                    None => explain::get_index_type_id(client, &req, params, &parts["index"], &parts["type"], &parts["id"])
+                });
+            }
+
+            Err(format!("no url for `explain` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "field_stats" => {
+            let (parts, req) = try!(split_args(req, args, &["index"]));
+            let params = &field_stats::Params::default();
+
+            if has_parts(&parts, &["index"]) {
+                return Ok(match body {
+                    Some(body) => field_stats::post_index(client, &req, params, &parts["index"], body),
+                    None => field_stats::get_index(client, &req, params, &parts["index"])
+                });
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(match body {
+                    Some(body) => field_stats::post(client, &req, params, body),
+                    None => field_stats::get(client, &req, params)
+                });
+            }
+
+            Err(format!("no url for `field_stats` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "get" => {
+            let (parts, req) = try!(split_args(req, args, &["id", "index", "type"]));
+            let params = &get::Params::default();
+
+            if has_parts(&parts, &["index", "type", "id"]) {
+                return Ok(get::get_index_type_id(client, &req, params, &parts["index"], &parts["type"], &parts["id"]));
+            }
+
+            Err(format!("no url for `get` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "get_script" => {
+            let (parts, req) = try!(split_args(req, args, &["id", "lang"]));
+            let params = &get_script::Params::default();
+
+            if has_parts(&parts, &["lang", "id"]) {
+                return Ok(get_script::get_lang_id(client, &req, params, &parts["lang"], &parts["id"]));
+            }
+
+            Err(format!("no url for `get_script` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "get_source" => {
+            let (parts, req) = try!(split_args(req, args, &["id", "index", "type"]));
+            let params = &get_source::Params::default();
+
+            if has_parts(&parts, &["index", "type", "id"]) {
+                return Ok(get_source::get_index_type_id(client, &req, params, &parts["index"], &parts["type"], &parts["id"]));
+            }
+
+            Err(format!("no url for `get_source` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "get_template" => {
+            let (parts, req) = try!(split_args(req, args, &["id"]));
+            let params = &get_template::Params::default();
+
+            if has_parts(&parts, &["id"]) {
+                return Ok(get_template::get_id(client, &req, params, &parts["id"]));
+            }
+
+            Err(format!("no url for `get_template` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "index" => {
+            let (parts, req) = try!(split_args(req, args, &["id", "index", "type"]));
+            let params = &index::Params::default();
+
+            if has_parts(&parts, &["index", "type", "id"]) {
+                return Ok(match body {
+                    Some(body) => index::post_index_type_id(client, &req, params, &parts["index"], &parts["type"], &parts["id"], body),
+                    None => index::post_index_type_id(client, &req, params, &parts["index"], &parts["type"], &parts["id"], "")
+                });
+            }
+            if has_parts(&parts, &["index", "type"]) {
+                return Ok(match body {
+                    Some(body) => index::post_index_type(client, &req, params, &parts["index"], &parts["type"], body),
+                    None => index::post_index_type(client, &req, params, &parts["index"], &parts["type"], "")
+                });
+            }
+
+            Err(format!("no url for `index` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "indices.analyze" => {
+            let (parts, req) = try!(split_args(req, args, &["index"]));
+            let params = &indices::analyze::Params::default();
+
+            if has_parts(&parts, &["index"]) {
+                return Ok(match body {
+                    Some(body) => indices::analyze::post_index(client, &req, params, &parts["index"], body),
+                    None => indices::analyze::get_index(client, &req, params, &parts["index"])
+                });
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(match body {
+                    Some(body) => indices::analyze::post(client, &req, params, body),
+                    None => indices::analyze::get(client, &req, params)
+                });
+            }
+
+            Err(format!("no url for `indices.analyze` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "indices.clear_cache" => {
+            let (parts, req) = try!(split_args(req, args, &["index"]));
+            let params = &indices::clear_cache::Params::default();
+
+            if has_parts(&parts, &["index"]) {
+                return Ok(match body {
+                    Some(body) => indices::clear_cache::post_index(client, &req, params, &parts["index"], body),
+                    None => indices::clear_cache::get_index(client, &req, params, &parts["index"])
+                });
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(match body {
+                    Some(body) => indices::clear_cache::post(client, &req, params, body),
+                    None => indices::clear_cache::get(client, &req, params)
+                });
+            }
+
+            Err(format!("no url for `indices.clear_cache` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "indices.close" => {
+            let (parts, req) = try!(split_args(req, args, &["index"]));
+            let params = &indices::close::Params::default();
+
+            if has_parts(&parts, &["index"]) {
+                return Ok(match body {
+                    Some(body) => indices::close::post_index(client, &req, params, &parts["index"], body),
+                    None => indices::close::post_index(client, &req, params, &parts["index"], "")
+                });
+            }
+
+            Err(format!("no url for `indices.close` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "indices.create" => {
+            let (parts, req) = try!(split_args(req, args, &["index"]));
+            let params = &indices::create::Params::default();
+
+            if has_parts(&parts, &["index"]) {
+                return Ok(match body {
+                    Some(body) => indices::create::put_index(client, &req, params, &parts["index"], body),
+                    None => indices::create::put_index(client, &req, params, &parts["index"], "")
+                });
+            }
+
+            Err(format!("no url for `indices.create` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "indices.delete" => {
+            let (parts, req) = try!(split_args(req, args, &["index"]));
+            let params = &indices::delete::Params::default();
+
+            if has_parts(&parts, &["index"]) {
+                return Ok(indices::delete::delete_index(client, &req, params, &parts["index"]));
+            }
+
+            Err(format!("no url for `indices.delete` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "indices.delete_alias" => {
+            let (parts, req) = try!(split_args(req, args, &["index", "name"]));
+            let params = &indices::delete_alias::Params::default();
+
+            if has_parts(&parts, &["index", "name"]) {
+                return Ok(indices::delete_alias::delete_index_name(client, &req, params, &parts["index"], &parts["name"]));
+            }
+            if has_parts(&parts, &["index", "name"]) {
+                return Ok(indices::delete_alias::delete_index_name(client, &req, params, &parts["index"], &parts["name"]));
+            }
+
+            Err(format!("no url for `indices.delete_alias` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "indices.delete_template" => {
+            let (parts, req) = try!(split_args(req, args, &["name"]));
+            let params = &indices::delete_template::Params::default();
+
+            if has_parts(&parts, &["name"]) {
+                return Ok(indices::delete_template::delete_name(client, &req, params, &parts["name"]));
+            }
+
+            Err(format!("no url for `indices.delete_template` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "indices.exists" => {
+            let (parts, req) = try!(split_args(req, args, &["index"]));
+            let params = &indices::exists::Params::default();
+
+            if has_parts(&parts, &["index"]) {
+                return Ok(indices::exists::head_index(client, &req, params, &parts["index"]));
+            }
+
+            Err(format!("no url for `indices.exists` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "indices.exists_alias" => {
+            let (parts, req) = try!(split_args(req, args, &["index", "name"]));
+            let params = &indices::exists_alias::Params::default();
+
+            if has_parts(&parts, &["index", "name"]) {
+                return Ok(indices::exists_alias::head_index_name(client, &req, params, &parts["index"], &parts["name"]));
+            }
+            if has_parts(&parts, &["name"]) {
+                return Ok(indices::exists_alias::head_name(client, &req, params, &parts["name"]));
+            }
+            if has_parts(&parts, &["index"]) {
+                return Ok(indices::exists_alias::head_index(client, &req, params, &parts["index"]));
+            }
+
+            Err(format!("no url for `indices.exists_alias` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "indices.exists_template" => {
+            let (parts, req) = try!(split_args(req, args, &["name"]));
+            let params = &indices::exists_template::Params::default();
+
+            if has_parts(&parts, &["name"]) {
+                return Ok(indices::exists_template::head_name(client, &req, params, &parts["name"]));
+            }
+
+            Err(format!("no url for `indices.exists_template` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "indices.exists_type" => {
+            let (parts, req) = try!(split_args(req, args, &["index", "type"]));
+            let params = &indices::exists_type::Params::default();
+
+            if has_parts(&parts, &["index", "type"]) {
+                return Ok(indices::exists_type::head_index_type(client, &req, params, &parts["index"], &parts["type"]));
+            }
+
+            Err(format!("no url for `indices.exists_type` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "indices.flush" => {
+            let (parts, req) = try!(split_args(req, args, &["index"]));
+            let params = &indices::flush::Params::default();
+
+            if has_parts(&parts, &["index"]) {
+                return Ok(match body {
+                    Some(body) => indices::flush::post_index(client, &req, params, &parts["index"], body),
+                    None => indices::flush::get_index(client, &req, params, &parts["index"])
+                });
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(match body {
+                    Some(body) => indices::flush::post(client, &req, params, body),
+                    None => indices::flush::get(client, &req, params)
+                });
+            }
+
+            Err(format!("no url for `indices.flush` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "indices.flush_synced" => {
+            let (parts, req) = try!(split_args(req, args, &["index"]));
+            let params = &indices::flush_synced::Params::default();
+
+            if has_parts(&parts, &["index"]) {
+                return Ok(match body {
+                    Some(body) => indices::flush_synced::post_index(client, &req, params, &parts["index"], body),
+                    None => indices::flush_synced::get_index(client, &req, params, &parts["index"])
+                });
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(match body {
+                    Some(body) => indices::flush_synced::post(client, &req, params, body),
+                    None => indices::flush_synced::get(client, &req, params)
+                });
+            }
+
+            Err(format!("no url for `indices.flush_synced` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "indices.forcemerge" => {
+            let (parts, req) = try!(split_args(req, args, &["index"]));
+            let params = &indices::forcemerge::Params::default();
+
+            if has_parts(&parts, &["index"]) {
+                return Ok(match body {
+                    Some(body) => indices::forcemerge::post_index(client, &req, params, &parts["index"], body),
+                    None => indices::forcemerge::post_index(client, &req, params, &parts["index"], "")
+                });
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(match body {
+                    Some(body) => indices::forcemerge::post(client, &req, params, body),
+                    None => indices::forcemerge::post(client, &req, params, "")
+                });
+            }
+
+            Err(format!("no url for `indices.forcemerge` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "indices.get" => {
+            let (parts, req) = try!(split_args(req, args, &["feature", "index"]));
+            let params = &indices::get::Params::default();
+
+            if has_parts(&parts, &["index", "feature"]) {
+                return Ok(indices::get::get_index_feature(client, &req, params, &parts["index"], &parts["feature"]));
+            }
+            if has_parts(&parts, &["index"]) {
+                return Ok(indices::get::get_index(client, &req, params, &parts["index"]));
+            }
+
+            Err(format!("no url for `indices.get` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "indices.get_alias" => {
+            let (parts, req) = try!(split_args(req, args, &["index", "name"]));
+            let params = &indices::get_alias::Params::default();
+
+            if has_parts(&parts, &["index", "name"]) {
+                return Ok(indices::get_alias::get_index_name(client, &req, params, &parts["index"], &parts["name"]));
+            }
+            if has_parts(&parts, &["name"]) {
+                return Ok(indices::get_alias::get_name(client, &req, params, &parts["name"]));
+            }
+            if has_parts(&parts, &["index"]) {
+                return Ok(indices::get_alias::get_index(client, &req, params, &parts["index"]));
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(indices::get_alias::get(client, &req, params));
+            }
+
+            Err(format!("no url for `indices.get_alias` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "indices.get_field_mapping" => {
+            let (parts, req) = try!(split_args(req, args, &["fields", "index", "type"]));
+            let params = &indices::get_field_mapping::Params::default();
+
+            if has_parts(&parts, &["index", "type", "fields"]) {
+                return Ok(indices::get_field_mapping::get_index_type_fields(client, &req, params, &parts["index"], &parts["type"], &parts["fields"]));
+            }
+            if has_parts(&parts, &["index", "fields"]) {
+                return Ok(indices::get_field_mapping::get_index_fields(client, &req, params, &parts["index"], &parts["fields"]));
+            }
+            if has_parts(&parts, &["type", "fields"]) {
+                return Ok(indices::get_field_mapping::get_type_fields(client, &req, params, &parts["type"], &parts["fields"]));
+            }
+            if has_parts(&parts, &["fields"]) {
+                return Ok(indices::get_field_mapping::get_fields(client, &req, params, &parts["fields"]));
+            }
+
+            Err(format!("no url for `indices.get_field_mapping` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "indices.get_mapping" => {
+            let (parts, req) = try!(split_args(req, args, &["index", "type"]));
+            let params = &indices::get_mapping::Params::default();
+
+            if has_parts(&parts, &["index", "type"]) {
+                return Ok(indices::get_mapping::get_index_type(client, &req, params, &parts["index"], &parts["type"]));
+            }
+            if has_parts(&parts, &["index"]) {
+                return Ok(indices::get_mapping::get_index(client, &req, params, &parts["index"]));
+            }
+            if has_parts(&parts, &["type"]) {
+                return Ok(indices::get_mapping::get_type(client, &req, params, &parts["type"]));
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(indices::get_mapping::get(client, &req, params));
+            }
+
+            Err(format!("no url for `indices.get_mapping` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "indices.get_settings" => {
+            let (parts, req) = try!(split_args(req, args, &["index", "name"]));
+            let params = &indices::get_settings::Params::default();
+
+            if has_parts(&parts, &["index", "name"]) {
+                return Ok(indices::get_settings::get_index_name(client, &req, params, &parts["index"], &parts["name"]));
+            }
+            if has_parts(&parts, &["index"]) {
+                return Ok(indices::get_settings::get_index(client, &req, params, &parts["index"]));
+            }
+            if has_parts(&parts, &["name"]) {
+                return Ok(indices::get_settings::get_name(client, &req, params, &parts["name"]));
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(indices::get_settings::get(client, &req, params));
+            }
+
+            Err(format!("no url for `indices.get_settings` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "indices.get_template" => {
+            let (parts, req) = try!(split_args(req, args, &["name"]));
+            let params = &indices::get_template::Params::default();
+
+            if has_parts(&parts, &["name"]) {
+                return Ok(indices::get_template::get_name(client, &req, params, &parts["name"]));
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(indices::get_template::get(client, &req, params));
+            }
+
+            Err(format!("no url for `indices.get_template` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "indices.get_upgrade" => {
+            let (parts, req) = try!(split_args(req, args, &["index"]));
+            let params = &indices::get_upgrade::Params::default();
+
+            if has_parts(&parts, &["index"]) {
+                return Ok(indices::get_upgrade::get_index(client, &req, params, &parts["index"]));
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(indices::get_upgrade::get(client, &req, params));
+            }
+
+            Err(format!("no url for `indices.get_upgrade` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "indices.open" => {
+            let (parts, req) = try!(split_args(req, args, &["index"]));
+            let params = &indices::open::Params::default();
+
+            if has_parts(&parts, &["index"]) {
+                return Ok(match body {
+                    Some(body) => indices::open::post_index(client, &req, params, &parts["index"], body),
+                    None => indices::open::post_index(client, &req, params, &parts["index"], "")
+                });
+            }
+
+            Err(format!("no url for `indices.open` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "indices.put_alias" => {
+            let (parts, req) = try!(split_args(req, args, &["index", "name"]));
+            let params = &indices::put_alias::Params::default();
+
+            if has_parts(&parts, &["index", "name"]) {
+                return Ok(match body {
+                    Some(body) => indices::put_alias::put_index_name(client, &req, params, &parts["index"], &parts["name"], body),
+                    None => indices::put_alias::put_index_name(client, &req, params, &parts["index"], &parts["name"], "")
+                });
+            }
+            if has_parts(&parts, &["index", "name"]) {
+                return Ok(match body {
+                    Some(body) => indices::put_alias::put_index_name(client, &req, params, &parts["index"], &parts["name"], body),
+                    None => indices::put_alias::put_index_name(client, &req, params, &parts["index"], &parts["name"], "")
+                });
+            }
+
+            Err(format!("no url for `indices.put_alias` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "indices.put_mapping" => {
+            let (parts, req) = try!(split_args(req, args, &["index", "type"]));
+            let params = &indices::put_mapping::Params::default();
+
+            if has_parts(&parts, &["index", "type"]) {
+                return Ok(match body {
+                    Some(body) => indices::put_mapping::put_index_type(client, &req, params, &parts["index"], &parts["type"], body),
+                    None => indices::put_mapping::put_index_type(client, &req, params, &parts["index"], &parts["type"], "")
+                });
+            }
+            if has_parts(&parts, &["index", "type"]) {
+                return Ok(match body {
+                    Some(body) => indices::put_mapping::put_index_type(client, &req, params, &parts["index"], &parts["type"], body),
+                    None => indices::put_mapping::put_index_type(client, &req, params, &parts["index"], &parts["type"], "")
+                });
+            }
+            if has_parts(&parts, &["index", "type"]) {
+                return Ok(match body {
+                    Some(body) => indices::put_mapping::put_index_type(client, &req, params, &parts["index"], &parts["type"], body),
+                    None => indices::put_mapping::put_index_type(client, &req, params, &parts["index"], &parts["type"], "")
+                });
+            }
+            if has_parts(&parts, &["index", "type"]) {
+                return Ok(match body {
+                    Some(body) => indices::put_mapping::put_index_type(client, &req, params, &parts["index"], &parts["type"], body),
+                    None => indices::put_mapping::put_index_type(client, &req, params, &parts["index"], &parts["type"], "")
+                });
+            }
+            if has_parts(&parts, &["type"]) {
+                return Ok(match body {
+                    Some(body) => indices::put_mapping::put_type(client, &req, params, &parts["type"], body),
+                    None => indices::put_mapping::put_type(client, &req, params, &parts["type"], "")
+                });
+            }
+            if has_parts(&parts, &["type"]) {
+                return Ok(match body {
+                    Some(body) => indices::put_mapping::put_type(client, &req, params, &parts["type"], body),
+                    None => indices::put_mapping::put_type(client, &req, params, &parts["type"], "")
+                });
+            }
+
+            Err(format!("no url for `indices.put_mapping` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "indices.put_settings" => {
+            let (parts, req) = try!(split_args(req, args, &["index"]));
+            let params = &indices::put_settings::Params::default();
+
+            if has_parts(&parts, &["index"]) {
+                return Ok(match body {
+                    Some(body) => indices::put_settings::put_index(client, &req, params, &parts["index"], body),
+                    None => indices::put_settings::put_index(client, &req, params, &parts["index"], "")
+                });
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(match body {
+                    Some(body) => indices::put_settings::put(client, &req, params, body),
+                    None => indices::put_settings::put(client, &req, params, "")
+                });
+            }
+
+            Err(format!("no url for `indices.put_settings` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "indices.put_template" => {
+            let (parts, req) = try!(split_args(req, args, &["name"]));
+            let params = &indices::put_template::Params::default();
+
+            if has_parts(&parts, &["name"]) {
+                return Ok(match body {
+                    Some(body) => indices::put_template::put_name(client, &req, params, &parts["name"], body),
+                    None => indices::put_template::put_name(client, &req, params, &parts["name"], "")
+                });
+            }
+
+            Err(format!("no url for `indices.put_template` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "indices.recovery" => {
+            let (parts, req) = try!(split_args(req, args, &["index"]));
+            let params = &indices::recovery::Params::default();
+
+            if has_parts(&parts, &["index"]) {
+                return Ok(indices::recovery::get_index(client, &req, params, &parts["index"]));
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(indices::recovery::get(client, &req, params));
+            }
+
+            Err(format!("no url for `indices.recovery` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "indices.refresh" => {
+            let (parts, req) = try!(split_args(req, args, &["index"]));
+            let params = &indices::refresh::Params::default();
+
+            if has_parts(&parts, &["index"]) {
+                return Ok(match body {
+                    Some(body) => indices::refresh::post_index(client, &req, params, &parts["index"], body),
+                    None => indices::refresh::get_index(client, &req, params, &parts["index"])
+                });
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(match body {
+                    Some(body) => indices::refresh::post(client, &req, params, body),
+                    None => indices::refresh::get(client, &req, params)
+                });
+            }
+
+            Err(format!("no url for `indices.refresh` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "indices.segments" => {
+            let (parts, req) = try!(split_args(req, args, &["index"]));
+            let params = &indices::segments::Params::default();
+
+            if has_parts(&parts, &["index"]) {
+                return Ok(indices::segments::get_index(client, &req, params, &parts["index"]));
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(indices::segments::get(client, &req, params));
+            }
+
+            Err(format!("no url for `indices.segments` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "indices.shard_stores" => {
+            let (parts, req) = try!(split_args(req, args, &["index"]));
+            let params = &indices::shard_stores::Params::default();
+
+            if has_parts(&parts, &["index"]) {
+                return Ok(indices::shard_stores::get_index(client, &req, params, &parts["index"]));
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(indices::shard_stores::get(client, &req, params));
+            }
+
+            Err(format!("no url for `indices.shard_stores` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "indices.stats" => {
+            let (parts, req) = try!(split_args(req, args, &["index", "metric"]));
+            let params = &indices::stats::Params::default();
+
+            if has_parts(&parts, &["index", "metric"]) {
+                return Ok(indices::stats::get_index_metric(client, &req, params, &parts["index"], &parts["metric"]));
+            }
+            if has_parts(&parts, &["metric"]) {
+                return Ok(indices::stats::get_metric(client, &req, params, &parts["metric"]));
+            }
+            if has_parts(&parts, &["index"]) {
+                return Ok(indices::stats::get_index(client, &req, params, &parts["index"]));
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(indices::stats::get(client, &req, params));
+            }
+
+            Err(format!("no url for `indices.stats` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "indices.update_aliases" => {
+            let (parts, req) = try!(split_args(req, args, &[]));
+            let params = &indices::update_aliases::Params::default();
+
+            if has_parts(&parts, &[]) {
+                return Ok(match body {
+                    Some(body) => indices::update_aliases::post(client, &req, params, body),
+                    None => indices::update_aliases::post(client, &req, params, "")
+                });
+            }
+
+            Err(format!("no url for `indices.update_aliases` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "indices.upgrade" => {
+            let (parts, req) = try!(split_args(req, args, &["index"]));
+            let params = &indices::upgrade::Params::default();
+
+            if has_parts(&parts, &["index"]) {
+                return Ok(match body {
+                    Some(body) => indices::upgrade::post_index(client, &req, params, &parts["index"], body),
+                    None => indices::upgrade::post_index(client, &req, params, &parts["index"], "")
+                });
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(match body {
+                    Some(body) => indices::upgrade::post(client, &req, params, body),
+                    None => indices::upgrade::post(client, &req, params, "")
+                });
+            }
+
+            Err(format!("no url for `indices.upgrade` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "indices.validate_query" => {
+            let (parts, req) = try!(split_args(req, args, &["index", "type"]));
+            let params = &indices::validate_query::Params::default();
+
+            if has_parts(&parts, &["index", "type"]) {
+                return Ok(match body {
+                    Some(body) => indices::validate_query::post_index_type(client, &req, params, &parts["index"], &parts["type"], body),
+                    None => indices::validate_query::get_index_type(client, &req, params, &parts["index"], &parts["type"])
+                });
+            }
+            if has_parts(&parts, &["index"]) {
+                return Ok(match body {
+                    Some(body) => indices::validate_query::post_index(client, &req, params, &parts["index"], body),
+                    None => indices::validate_query::get_index(client, &req, params, &parts["index"])
+                });
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(match body {
+                    Some(body) => indices::validate_query::post(client, &req, params, body),
+                    None => indices::validate_query::get(client, &req, params)
+                });
+            }
+
+            Err(format!("no url for `indices.validate_query` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "info" => {
+            let (parts, req) = try!(split_args(req, args, &[]));
+            let params = &info::Params::default();
+
+            if has_parts(&parts, &[]) {
+                return Ok(info::get(client, &req, params));
+            }
+
+            Err(format!("no url for `info` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "ingest.delete_pipeline" => {
+            let (parts, req) = try!(split_args(req, args, &["id"]));
+            let params = &ingest::delete_pipeline::Params::default();
+
+            if has_parts(&parts, &["id"]) {
+                return Ok(ingest::delete_pipeline::delete_id(client, &req, params, &parts["id"]));
+            }
+
+            Err(format!("no url for `ingest.delete_pipeline` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "ingest.get_pipeline" => {
+            let (parts, req) = try!(split_args(req, args, &["id"]));
+            let params = &ingest::get_pipeline::Params::default();
+
+            if has_parts(&parts, &["id"]) {
+                return Ok(ingest::get_pipeline::get_id(client, &req, params, &parts["id"]));
+            }
+
+            Err(format!("no url for `ingest.get_pipeline` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "ingest.put_pipeline" => {
+            let (parts, req) = try!(split_args(req, args, &["id"]));
+            let params = &ingest::put_pipeline::Params::default();
+
+            if has_parts(&parts, &["id"]) {
+                return Ok(match body {
+                    Some(body) => ingest::put_pipeline::put_id(client, &req, params, &parts["id"], body),
+                    None => ingest::put_pipeline::put_id(client, &req, params, &parts["id"], "")
+                });
+            }
+
+            Err(format!("no url for `ingest.put_pipeline` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "ingest.simulate" => {
+            let (parts, req) = try!(split_args(req, args, &["id"]));
+            let params = &ingest::simulate::Params::default();
+
+            if has_parts(&parts, &["id"]) {
+                return Ok(match body {
+                    Some(body) => ingest::simulate::post_id(client, &req, params, &parts["id"], body),
+                    None => ingest::simulate::get_id(client, &req, params, &parts["id"])
+                });
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(match body {
+                    Some(body) => ingest::simulate::post(client, &req, params, body),
+                    None => ingest::simulate::get(client, &req, params)
+                });
+            }
+
+            Err(format!("no url for `ingest.simulate` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "mget" => {
+            let (parts, req) = try!(split_args(req, args, &["index", "type"]));
+            let params = &mget::Params::default();
+
+            if has_parts(&parts, &["index", "type"]) {
+                return Ok(match body {
+                    Some(body) => mget::post_index_type(client, &req, params, &parts["index"], &parts["type"], body),
+                    None => mget::get_index_type(client, &req, params, &parts["index"], &parts["type"])
+                });
+            }
+            if has_parts(&parts, &["index"]) {
+                return Ok(match body {
+                    Some(body) => mget::post_index(client, &req, params, &parts["index"], body),
+                    None => mget::get_index(client, &req, params, &parts["index"])
+                });
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(match body {
+                    Some(body) => mget::post(client, &req, params, body),
+                    None => mget::get(client, &req, params)
+                });
+            }
+
+            Err(format!("no url for `mget` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "mpercolate" => {
+            let (parts, req) = try!(split_args(req, args, &["index", "type"]));
+            let params = &mpercolate::Params::default();
+
+            if has_parts(&parts, &["index", "type"]) {
+                return Ok(match body {
+                    Some(body) => mpercolate::post_index_type(client, &req, params, &parts["index"], &parts["type"], body),
+                    None => mpercolate::get_index_type(client, &req, params, &parts["index"], &parts["type"])
+                });
+            }
+            if has_parts(&parts, &["index"]) {
+                return Ok(match body {
+                    Some(body) => mpercolate::post_index(client, &req, params, &parts["index"], body),
+                    None => mpercolate::get_index(client, &req, params, &parts["index"])
+                });
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(match body {
+                    Some(body) => mpercolate::post(client, &req, params, body),
+                    None => mpercolate::get(client, &req, params)
+                });
+            }
+
+            Err(format!("no url for `mpercolate` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "msearch" => {
+            let (parts, req) = try!(split_args(req, args, &["index", "type"]));
+            let params = &msearch::Params::default();
+
+            if has_parts(&parts, &["index", "type"]) {
+                return Ok(match body {
+                    Some(body) => msearch::post_index_type(client, &req, params, &parts["index"], &parts["type"], body),
+                    None => msearch::get_index_type(client, &req, params, &parts["index"], &parts["type"])
+                });
+            }
+            if has_parts(&parts, &["index"]) {
+                return Ok(match body {
+                    Some(body) => msearch::post_index(client, &req, params, &parts["index"], body),
+                    None => msearch::get_index(client, &req, params, &parts["index"])
+                });
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(match body {
+                    Some(body) => msearch::post(client, &req, params, body),
+                    None => msearch::get(client, &req, params)
+                });
+            }
+
+            Err(format!("no url for `msearch` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "mtermvectors" => {
+            let (parts, req) = try!(split_args(req, args, &["index", "type"]));
+            let params = &mtermvectors::Params::default();
+
+            if has_parts(&parts, &["index", "type"]) {
+                return Ok(match body {
+                    Some(body) => mtermvectors::post_index_type(client, &req, params, &parts["index"], &parts["type"], body),
+                    None => mtermvectors::get_index_type(client, &req, params, &parts["index"], &parts["type"])
+                });
+            }
+            if has_parts(&parts, &["index"]) {
+                return Ok(match body {
+                    Some(body) => mtermvectors::post_index(client, &req, params, &parts["index"], body),
+                    None => mtermvectors::get_index(client, &req, params, &parts["index"])
+                });
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(match body {
+                    Some(body) => mtermvectors::post(client, &req, params, body),
+                    None => mtermvectors::get(client, &req, params)
+                });
+            }
+
+            Err(format!("no url for `mtermvectors` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "nodes.hot_threads" => {
+            let (parts, req) = try!(split_args(req, args, &["node_id"]));
+            let params = &nodes::hot_threads::Params::default();
+
+            if has_parts(&parts, &["node_id"]) {
+                return Ok(nodes::hot_threads::get_node_id(client, &req, params, &parts["node_id"]));
+            }
+            if has_parts(&parts, &["node_id"]) {
+                return Ok(nodes::hot_threads::get_node_id(client, &req, params, &parts["node_id"]));
+            }
+            if has_parts(&parts, &["node_id"]) {
+                return Ok(nodes::hot_threads::get_node_id(client, &req, params, &parts["node_id"]));
+            }
+            if has_parts(&parts, &["node_id"]) {
+                return Ok(nodes::hot_threads::get_node_id(client, &req, params, &parts["node_id"]));
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(nodes::hot_threads::get(client, &req, params));
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(nodes::hot_threads::get(client, &req, params));
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(nodes::hot_threads::get(client, &req, params));
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(nodes::hot_threads::get(client, &req, params));
+            }
+
+            Err(format!("no url for `nodes.hot_threads` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "nodes.info" => {
+            let (parts, req) = try!(split_args(req, args, &["metric", "node_id"]));
+            let params = &nodes::info::Params::default();
+
+            if has_parts(&parts, &["node_id", "metric"]) {
+                return Ok(nodes::info::get_node_id_metric(client, &req, params, &parts["node_id"], &parts["metric"]));
+            }
+            if has_parts(&parts, &["node_id"]) {
+                return Ok(nodes::info::get_node_id(client, &req, params, &parts["node_id"]));
+            }
+            if has_parts(&parts, &["metric"]) {
+                return Ok(nodes::info::get_metric(client, &req, params, &parts["metric"]));
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(nodes::info::get(client, &req, params));
+            }
+
+            Err(format!("no url for `nodes.info` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "nodes.stats" => {
+            let (parts, req) = try!(split_args(req, args, &["index_metric", "metric", "node_id"]));
+            let params = &nodes::stats::Params::default();
+
+            if has_parts(&parts, &["node_id", "metric", "index_metric"]) {
+                return Ok(nodes::stats::get_node_id_metric_index_metric(client, &req, params, &parts["node_id"], &parts["metric"], &parts["index_metric"]));
+            }
+            if has_parts(&parts, &["node_id", "metric"]) {
+                return Ok(nodes::stats::get_node_id_metric(client, &req, params, &parts["node_id"], &parts["metric"]));
+            }
+            if has_parts(&parts, &["metric", "index_metric"]) {
+                return Ok(nodes::stats::get_metric_index_metric(client, &req, params, &parts["metric"], &parts["index_metric"]));
+            }
+            if has_parts(&parts, &["node_id"]) {
+                return Ok(nodes::stats::get_node_id(client, &req, params, &parts["node_id"]));
+            }
+            if has_parts(&parts, &["metric"]) {
+                return Ok(nodes::stats::get_metric(client, &req, params, &parts["metric"]));
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(nodes::stats::get(client, &req, params));
+            }
+
+            Err(format!("no url for `nodes.stats` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "percolate" => {
+            let (parts, req) = try!(split_args(req, args, &["id", "index", "type"]));
+            let params = &percolate::Params::default();
+
+            if has_parts(&parts, &["index", "type", "id"]) {
+                return Ok(match body {
+                    Some(body) => percolate::post_index_type_id(client, &req, params, &parts["index"], &parts["type"], &parts["id"], body),
+                    None => percolate::get_index_type_id(client, &req, params, &parts["index"], &parts["type"], &parts["id"])
+                });
+            }
+            if has_parts(&parts, &["index", "type"]) {
+                return Ok(match body {
+                    Some(body) => percolate::post_index_type(client, &req, params, &parts["index"], &parts["type"], body),
+                    None => percolate::get_index_type(client, &req, params, &parts["index"], &parts["type"])
+                });
+            }
+
+            Err(format!("no url for `percolate` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "ping" => {
+            let (parts, req) = try!(split_args(req, args, &[]));
+            let params = &ping::Params::default();
+
+            if has_parts(&parts, &[]) {
+                return Ok(ping::head(client, &req, params));
+            }
+
+            Err(format!("no url for `ping` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "put_script" => {
+            let (parts, req) = try!(split_args(req, args, &["id", "lang"]));
+            let params = &put_script::Params::default();
+
+            if has_parts(&parts, &["lang", "id"]) {
+                return Ok(match body {
+                    Some(body) => put_script::put_lang_id(client, &req, params, &parts["lang"], &parts["id"], body),
+                    None => put_script::put_lang_id(client, &req, params, &parts["lang"], &parts["id"], "")
+                });
+            }
+
+            Err(format!("no url for `put_script` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "put_template" => {
+            let (parts, req) = try!(split_args(req, args, &["id"]));
+            let params = &put_template::Params::default();
+
+            if has_parts(&parts, &["id"]) {
+                return Ok(match body {
+                    Some(body) => put_template::put_id(client, &req, params, &parts["id"], body),
+                    None => put_template::put_id(client, &req, params, &parts["id"], "")
+                });
+            }
+
+            Err(format!("no url for `put_template` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "reindex" => {
+            let (parts, req) = try!(split_args(req, args, &[]));
+            let params = &reindex::Params::default();
+
+            if has_parts(&parts, &[]) {
+                return Ok(match body {
+                    Some(body) => reindex::post(client, &req, params, body),
+                    None => reindex::post(client, &req, params, "")
+                });
+            }
+
+            Err(format!("no url for `reindex` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "reindex.rethrottle" => {
+            let (parts, req) = try!(split_args(req, args, &["task_id"]));
+            let params = &reindex::rethrottle::Params::default();
+
+            if has_parts(&parts, &["task_id"]) {
+                return Ok(match body {
+                    Some(body) => reindex::rethrottle::post_task_id(client, &req, params, &parts["task_id"], body),
+                    None => reindex::rethrottle::post_task_id(client, &req, params, &parts["task_id"], "")
+                });
+            }
+            if has_parts(&parts, &["task_id"]) {
+                return Ok(match body {
+                    Some(body) => reindex::rethrottle::post_task_id(client, &req, params, &parts["task_id"], body),
+                    None => reindex::rethrottle::post_task_id(client, &req, params, &parts["task_id"], "")
+                });
+            }
+
+            Err(format!("no url for `reindex.rethrottle` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "render_search_template" => {
+            let (parts, req) = try!(split_args(req, args, &["id"]));
+            let params = &render_search_template::Params::default();
+
+            if has_parts(&parts, &["id"]) {
+                return Ok(match body {
+                    Some(body) => render_search_template::post_id(client, &req, params, &parts["id"], body),
+                    None => render_search_template::get_id(client, &req, params, &parts["id"])
+                });
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(match body {
+                    Some(body) => render_search_template::post(client, &req, params, body),
+                    None => render_search_template::get(client, &req, params)
+                });
+            }
+
+            Err(format!("no url for `render_search_template` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "scroll" => {
+            let (parts, req) = try!(split_args(req, args, &["scroll_id"]));
+            let params = &scroll::Params::default();
+
+            if has_parts(&parts, &["scroll_id"]) {
+                return Ok(match body {
+                    Some(body) => scroll::post_scroll_id(client, &req, params, &parts["scroll_id"], body),
+                    None => scroll::get_scroll_id(client, &req, params, &parts["scroll_id"])
+                });
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(match body {
+                    Some(body) => scroll::post(client, &req, params, body),
+                    None => scroll::get(client, &req, params)
+                });
+            }
+
+            Err(format!("no url for `scroll` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "search" => {
+            let (parts, req) = try!(split_args(req, args, &["index", "type"]));
+            let params = &search::Params::default();
+
+            if has_parts(&parts, &["index", "type"]) {
+                return Ok(match body {
+                    Some(body) => search::post_index_type(client, &req, params, &parts["index"], &parts["type"], body),
+                    None => search::get_index_type(client, &req, params, &parts["index"], &parts["type"])
+                });
+            }
+            if has_parts(&parts, &["index"]) {
+                return Ok(match body {
+                    Some(body) => search::post_index(client, &req, params, &parts["index"], body),
+                    None => search::get_index(client, &req, params, &parts["index"])
+                });
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(match body {
+                    Some(body) => search::post(client, &req, params, body),
+                    None => search::get(client, &req, params)
+                });
+            }
+
+            Err(format!("no url for `search` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "search_shards" => {
+            let (parts, req) = try!(split_args(req, args, &["index", "type"]));
+            let params = &search_shards::Params::default();
+
+            if has_parts(&parts, &["index", "type"]) {
+                return Ok(match body {
+                    Some(body) => search_shards::post_index_type(client, &req, params, &parts["index"], &parts["type"], body),
+                    None => search_shards::get_index_type(client, &req, params, &parts["index"], &parts["type"])
+                });
+            }
+            if has_parts(&parts, &["index"]) {
+                return Ok(match body {
+                    Some(body) => search_shards::post_index(client, &req, params, &parts["index"], body),
+                    None => search_shards::get_index(client, &req, params, &parts["index"])
+                });
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(match body {
+                    Some(body) => search_shards::post(client, &req, params, body),
+                    None => search_shards::get(client, &req, params)
+                });
+            }
+
+            Err(format!("no url for `search_shards` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "search_template" => {
+            let (parts, req) = try!(split_args(req, args, &["index", "type"]));
+            let params = &search_template::Params::default();
+
+            if has_parts(&parts, &["index", "type"]) {
+                return Ok(match body {
+                    Some(body) => search_template::post_index_type(client, &req, params, &parts["index"], &parts["type"], body),
+                    None => search_template::get_index_type(client, &req, params, &parts["index"], &parts["type"])
+                });
+            }
+            if has_parts(&parts, &["index"]) {
+                return Ok(match body {
+                    Some(body) => search_template::post_index(client, &req, params, &parts["index"], body),
+                    None => search_template::get_index(client, &req, params, &parts["index"])
+                });
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(match body {
+                    Some(body) => search_template::post(client, &req, params, body),
+                    None => search_template::get(client, &req, params)
+                });
+            }
+
+            Err(format!("no url for `search_template` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "snapshot.create" => {
+            let (parts, req) = try!(split_args(req, args, &["repository", "snapshot"]));
+            let params = &snapshot::create::Params::default();
+
+            if has_parts(&parts, &["repository", "snapshot"]) {
+                return Ok(match body {
+                    Some(body) => snapshot::create::put_repository_snapshot(client, &req, params, &parts["repository"], &parts["snapshot"], body),
+                    None => snapshot::create::put_repository_snapshot(client, &req, params, &parts["repository"], &parts["snapshot"], "")
+                });
+            }
+
+            Err(format!("no url for `snapshot.create` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "snapshot.create_repository" => {
+            let (parts, req) = try!(split_args(req, args, &["repository"]));
+            let params = &snapshot::create_repository::Params::default();
+
+            if has_parts(&parts, &["repository"]) {
+                return Ok(match body {
+                    Some(body) => snapshot::create_repository::put_repository(client, &req, params, &parts["repository"], body),
+                    None => snapshot::create_repository::put_repository(client, &req, params, &parts["repository"], "")
+                });
+            }
+
+            Err(format!("no url for `snapshot.create_repository` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "snapshot.delete" => {
+            let (parts, req) = try!(split_args(req, args, &["repository", "snapshot"]));
+            let params = &snapshot::delete::Params::default();
+
+            if has_parts(&parts, &["repository", "snapshot"]) {
+                return Ok(snapshot::delete::delete_repository_snapshot(client, &req, params, &parts["repository"], &parts["snapshot"]));
+            }
+
+            Err(format!("no url for `snapshot.delete` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "snapshot.delete_repository" => {
+            let (parts, req) = try!(split_args(req, args, &["repository"]));
+            let params = &snapshot::delete_repository::Params::default();
+
+            if has_parts(&parts, &["repository"]) {
+                return Ok(snapshot::delete_repository::delete_repository(client, &req, params, &parts["repository"]));
+            }
+
+            Err(format!("no url for `snapshot.delete_repository` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "snapshot.get" => {
+            let (parts, req) = try!(split_args(req, args, &["repository", "snapshot"]));
+            let params = &snapshot::get::Params::default();
+
+            if has_parts(&parts, &["repository", "snapshot"]) {
+                return Ok(snapshot::get::get_repository_snapshot(client, &req, params, &parts["repository"], &parts["snapshot"]));
+            }
+
+            Err(format!("no url for `snapshot.get` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "snapshot.get_repository" => {
+            let (parts, req) = try!(split_args(req, args, &["repository"]));
+            let params = &snapshot::get_repository::Params::default();
+
+            if has_parts(&parts, &["repository"]) {
+                return Ok(snapshot::get_repository::get_repository(client, &req, params, &parts["repository"]));
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(snapshot::get_repository::get(client, &req, params));
+            }
+
+            Err(format!("no url for `snapshot.get_repository` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "snapshot.restore" => {
+            let (parts, req) = try!(split_args(req, args, &["repository", "snapshot"]));
+            let params = &snapshot::restore::Params::default();
+
+            if has_parts(&parts, &["repository", "snapshot"]) {
+                return Ok(match body {
+                    Some(body) => snapshot::restore::post_repository_snapshot(client, &req, params, &parts["repository"], &parts["snapshot"], body),
+                    None => snapshot::restore::post_repository_snapshot(client, &req, params, &parts["repository"], &parts["snapshot"], "")
+                });
+            }
+
+            Err(format!("no url for `snapshot.restore` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "snapshot.status" => {
+            let (parts, req) = try!(split_args(req, args, &["repository", "snapshot"]));
+            let params = &snapshot::status::Params::default();
+
+            if has_parts(&parts, &["repository", "snapshot"]) {
+                return Ok(snapshot::status::get_repository_snapshot(client, &req, params, &parts["repository"], &parts["snapshot"]));
+            }
+            if has_parts(&parts, &["repository"]) {
+                return Ok(snapshot::status::get_repository(client, &req, params, &parts["repository"]));
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(snapshot::status::get(client, &req, params));
+            }
+
+            Err(format!("no url for `snapshot.status` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "snapshot.verify_repository" => {
+            let (parts, req) = try!(split_args(req, args, &["repository"]));
+            let params = &snapshot::verify_repository::Params::default();
+
+            if has_parts(&parts, &["repository"]) {
+                return Ok(match body {
+                    Some(body) => snapshot::verify_repository::post_repository(client, &req, params, &parts["repository"], body),
+                    None => snapshot::verify_repository::post_repository(client, &req, params, &parts["repository"], "")
+                });
+            }
+
+            Err(format!("no url for `snapshot.verify_repository` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "suggest" => {
+            let (parts, req) = try!(split_args(req, args, &["index"]));
+            let params = &suggest::Params::default();
+
+            if has_parts(&parts, &["index"]) {
+                return Ok(match body {
+                    Some(body) => suggest::post_index(client, &req, params, &parts["index"], body),
+                    None => suggest::get_index(client, &req, params, &parts["index"])
+                });
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(match body {
+                    Some(body) => suggest::post(client, &req, params, body),
+                    None => suggest::get(client, &req, params)
+                });
+            }
+
+            Err(format!("no url for `suggest` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "tasks.cancel" => {
+            let (parts, req) = try!(split_args(req, args, &["task_id"]));
+            let params = &tasks::cancel::Params::default();
+
+            if has_parts(&parts, &["task_id"]) {
+                return Ok(match body {
+                    Some(body) => tasks::cancel::post_task_id(client, &req, params, &parts["task_id"], body),
+                    None => tasks::cancel::post_task_id(client, &req, params, &parts["task_id"], "")
+                });
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(match body {
+                    Some(body) => tasks::cancel::post(client, &req, params, body),
+                    None => tasks::cancel::post(client, &req, params, "")
+                });
+            }
+
+            Err(format!("no url for `tasks.cancel` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "tasks.list" => {
+            let (parts, req) = try!(split_args(req, args, &["task_id"]));
+            let params = &tasks::list::Params::default();
+
+            if has_parts(&parts, &["task_id"]) {
+                return Ok(tasks::list::get_task_id(client, &req, params, &parts["task_id"]));
+            }
+            if has_parts(&parts, &[]) {
+                return Ok(tasks::list::get(client, &req, params));
+            }
+
+            Err(format!("no url for `tasks.list` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "termvectors" => {
+            let (parts, req) = try!(split_args(req, args, &["id", "index", "type"]));
+            let params = &termvectors::Params::default();
+
+            if has_parts(&parts, &["index", "type", "id"]) {
+                return Ok(match body {
+                    Some(body) => termvectors::post_index_type_id(client, &req, params, &parts["index"], &parts["type"], &parts["id"], body),
+                    None => termvectors::get_index_type_id(client, &req, params, &parts["index"], &parts["type"], &parts["id"])
+                });
+            }
+            if has_parts(&parts, &["index", "type"]) {
+                return Ok(match body {
+                    Some(body) => termvectors::post_index_type(client, &req, params, &parts["index"], &parts["type"], body),
+                    None => termvectors::get_index_type(client, &req, params, &parts["index"], &parts["type"])
+                });
+            }
+
+            Err(format!("no url for `termvectors` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "update" => {
+            let (parts, req) = try!(split_args(req, args, &["id", "index", "type"]));
+            let params = &update::Params::default();
+
+            if has_parts(&parts, &["index", "type", "id"]) {
+                return Ok(match body {
+                    Some(body) => update::post_index_type_id(client, &req, params, &parts["index"], &parts["type"], &parts["id"], body),
+                    None => update::post_index_type_id(client, &req, params, &parts["index"], &parts["type"], &parts["id"], "")
+                });
+            }
+
+            Err(format!("no url for `update` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        "update_by_query" => {
+            let (parts, req) = try!(split_args(req, args, &["index", "type"]));
+            let params = &update_by_query::Params::default();
+
+            if has_parts(&parts, &["index", "type"]) {
+                return Ok(match body {
+                    Some(body) => update_by_query::post_index_type(client, &req, params, &parts["index"], &parts["type"], body),
+                    None => update_by_query::post_index_type(client, &req, params, &parts["index"], &parts["type"], "")
+                });
+            }
+            if has_parts(&parts, &["index"]) {
+                return Ok(match body {
+                    Some(body) => update_by_query::post_index(client, &req, params, &parts["index"], body),
+                    None => update_by_query::post_index(client, &req, params, &parts["index"], "")
+                });
+            }
+
+            Err(format!("no url for `update_by_query` takes the parts {:?}", parts.keys().collect::<Vec<_>>()))
+        },
+        _ => Err(format!("unknown api `{}`", api))
+    }
+}
+
+fn param_name(name: &str) -> Option<&'static str> {
+    match name {
+        "_source" => Some("_source"),
+        "_source_exclude" => Some("_source_exclude"),
+        "_source_include" => Some("_source_include"),
+        "actions" => Some("actions"),
+        "active_only" => Some("active_only"),
+        "allow_no_indices" => Some("allow_no_indices"),
+        "analyze_wildcard" => Some("analyze_wildcard"),
+        "analyzer" => Some("analyzer"),
+        "attributes" => Some("attributes"),
+        "bytes" => Some("bytes"),
+        "char_filter" => Some("char_filter"),
+        "completion_fields" => Some("completion_fields"),
+        "conflicts" => Some("conflicts"),
+        "consistency" => Some("consistency"),
+        "create" => Some("create"),
+        "default_operator" => Some("default_operator"),
+        "detailed" => Some("detailed"),
+        "df" => Some("df"),
+        "dry_run" => Some("dry_run"),
+        "error_trace" => Some("error_trace"),
+        "expand_wildcards" => Some("expand_wildcards"),
+        "explain" => Some("explain"),
+        "field" => Some("field"),
+        "field_data" => Some("field_data"),
+        "field_statistics" => Some("field_statistics"),
+        "fielddata" => Some("fielddata"),
+        "fielddata_fields" => Some("fielddata_fields"),
+        "fields" => Some("fields"),
+        "filter" => Some("filter"),
+        "filter_path" => Some("filter_path"),
+        "flat_settings" => Some("flat_settings"),
+        "flush" => Some("flush"),
+        "force" => Some("force"),
+        "format" => Some("format"),
+        "from" => Some("from"),
+        "full_id" => Some("full_id"),
+        "group_by" => Some("group_by"),
+        "groups" => Some("groups"),
+        "h" => Some("h"),
+        "help" => Some("help"),
+        "human" => Some("human"),
+        "ids" => Some("ids"),
+        "ignore_idle_threads" => Some("ignore_idle_threads"),
+        "ignore_unavailable" => Some("ignore_unavailable"),
+        "include_defaults" => Some("include_defaults"),
+        "include_yes_decisions" => Some("include_yes_decisions"),
+        "index" => Some("index"),
+        "interval" => Some("interval"),
+        "lang" => Some("lang"),
+        "lenient" => Some("lenient"),
+        "level" => Some("level"),
+        "local" => Some("local"),
+        "lowercase_expanded_terms" => Some("lowercase_expanded_terms"),
+        "master_timeout" => Some("master_timeout"),
+        "max_num_segments" => Some("max_num_segments"),
+        "metric" => Some("metric"),
+        "min_score" => Some("min_score"),
+        "node_id" => Some("node_id"),
+        "offsets" => Some("offsets"),
+        "only_ancient_segments" => Some("only_ancient_segments"),
+        "only_expunge_deletes" => Some("only_expunge_deletes"),
+        "op_type" => Some("op_type"),
+        "operation_threading" => Some("operation_threading"),
+        "order" => Some("order"),
+        "parent" => Some("parent"),
+        "parent_node" => Some("parent_node"),
+        "parent_task" => Some("parent_task"),
+        "payloads" => Some("payloads"),
+        "percolate_format" => Some("percolate_format"),
+        "percolate_index" => Some("percolate_index"),
+        "percolate_preference" => Some("percolate_preference"),
+        "percolate_routing" => Some("percolate_routing"),
+        "percolate_type" => Some("percolate_type"),
+        "pipeline" => Some("pipeline"),
+        "positions" => Some("positions"),
+        "prefer_local" => Some("prefer_local"),
+        "preference" => Some("preference"),
+        "preserve_existing" => Some("preserve_existing"),
+        "pretty" => Some("pretty"),
+        "pri" => Some("pri"),
+        "q" => Some("q"),
+        "query" => Some("query"),
+        "realtime" => Some("realtime"),
+        "recycler" => Some("recycler"),
+        "refresh" => Some("refresh"),
+        "request" => Some("request"),
+        "request_cache" => Some("request_cache"),
+        "requests_per_second" => Some("requests_per_second"),
+        "retry_on_conflict" => Some("retry_on_conflict"),
+        "rewrite" => Some("rewrite"),
+        "routing" => Some("routing"),
+        "script" => Some("script"),
+        "script_id" => Some("script_id"),
+        "scripted_upsert" => Some("scripted_upsert"),
+        "scroll" => Some("scroll"),
+        "scroll_id" => Some("scroll_id"),
+        "scroll_size" => Some("scroll_size"),
+        "search_timeout" => Some("search_timeout"),
+        "search_type" => Some("search_type"),
+        "size" => Some("size"),
+        "snapshots" => Some("snapshots"),
+        "sort" => Some("sort"),
+        "source" => Some("source"),
+        "stats" => Some("stats"),
+        "status" => Some("status"),
+        "suggest_field" => Some("suggest_field"),
+        "suggest_mode" => Some("suggest_mode"),
+        "suggest_size" => Some("suggest_size"),
+        "suggest_text" => Some("suggest_text"),
+        "term_statistics" => Some("term_statistics"),
+        "terminate_after" => Some("terminate_after"),
+        "text" => Some("text"),
+        "threads" => Some("threads"),
+        "timeout" => Some("timeout"),
+        "timestamp" => Some("timestamp"),
+        "tokenizer" => Some("tokenizer"),
+        "track_scores" => Some("track_scores"),
+        "ts" => Some("ts"),
+        "ttl" => Some("ttl"),
+        "type" => Some("type"),
+        "types" => Some("types"),
+        "update_all_types" => Some("update_all_types"),
+        "v" => Some("v"),
+        "verbose" => Some("verbose"),
+        "verify" => Some("verify"),
+        "version" => Some("version"),
+        "version_type" => Some("version_type"),
+        "wait_for_active_shards" => Some("wait_for_active_shards"),
+        "wait_for_completion" => Some("wait_for_completion"),
+        "wait_for_merge" => Some("wait_for_merge"),
+        "wait_for_nodes" => Some("wait_for_nodes"),
+        "wait_for_relocating_shards" => Some("wait_for_relocating_shards"),
+        "wait_for_status" => Some("wait_for_status"),
+        "wait_if_ongoing" => Some("wait_if_ongoing"),
+        _ => None
+    }
+}
+
+fn split_args(req: &RequestParams, args: &BTreeMap<String, String>, part_names: &[&str]) -> ::std::result::Result<(BTreeMap<String, String>, RequestParams), String> {
+    let mut parts = BTreeMap::new();
+    let mut req = req.clone();
+
+    for (name, value) in args {
+        if part_names.contains(&name.as_str()) {
+            parts.insert(name.clone(), value.clone());
+        }
+        else {
+            let param = try!(param_name(name).ok_or(format!("unknown param `{}`", name)));
+            req.url_params.insert(param, value.clone());
+        }
+    }
+
+    Ok((parts, req))
+}
+
+fn has_parts(parts: &BTreeMap<String, String>, names: &[&str]) -> bool {
+    parts.len() == names.len() && names.iter().all(|name| parts.contains_key(*name))
+}
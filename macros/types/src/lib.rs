@@ -71,16 +71,24 @@ pub fn expand_derive_type_mapping(cx: &mut ExtCtxt, span: Span, meta_item: &Meta
     //Get or build the mapping type
 	let field_mapping;
     if let Some(mapping) = object::get_field_mapping(cx, item) {
+		if !object::get_object_params(cx, &item.attrs).is_empty() {
+			cx.span_err(
+				meta_item.span,
+				"object parameters like `#[elastic(object)]` can't be used with a custom `mapping`. Set them on the mapping type instead");
+		}
+
 		field_mapping = mapping;
 	}
 	else {
-		field_mapping = object::build_field_mapping(cx, item, push);
+		field_mapping = object::build_field_mapping(cx, span, item, push);
 	}
 
 	object::impl_field_mapping(cx, span, &es_ty, &field_mapping, &object_visitor, push);
 	object::impl_type_mapping(cx, &field_mapping, &object_visitor, push);
 
 	object::impl_type(cx, item, &field_mapping, push);
+
+	object::build_field_paths(cx, span, item, &fields, push);
 }
 
 macro_rules! expect_item {
@@ -330,6 +338,162 @@ pub fn expand_derive_date_mapping(cx: &mut ExtCtxt, _: Span, meta_item: &MetaIte
 	}
 }
 
+pub fn expand_derive_geo_point_mapping(cx: &mut ExtCtxt, _: Span, meta_item: &MetaItem, annotatable: &Annotatable, push: &mut FnMut(Annotatable)) {
+	let item = match *annotatable {
+		Annotatable::Item(ref item) => {
+			match item.node {
+				ast::ItemKind::Struct(ref data, ref generics) => {
+					match *data {
+						ast::VariantData::Struct(_, _) => Some((item, generics)),
+						_ => None
+					}
+				},
+				_ => None
+			}
+		},
+		_ => None
+	};
+
+	if item.is_none() {
+		cx.span_err(
+			meta_item.span,
+			"`#[derive(ElasticGeoPointMapping)]` may only be applied to structs with a generic parameter");
+		return;
+	}
+	let (item, generics) = item.unwrap();
+	let ty = item.ident;
+
+	if generics.ty_params.len() == 1 {
+		push(Annotatable::Item(
+			quote_item!(cx,
+				impl <T: ::elastic_types::geo::GeoPointFormat> ::elastic_types::mapping::ElasticFieldMapping<T> for $ty<T> {
+					type Visitor = ::elastic_types::geo::mapping::ElasticGeoPointMappingVisitor<T, $ty<T>>;
+
+					fn data_type() -> &'static str {
+						"geo_point"
+					}
+				}
+			).unwrap()
+		));
+
+		push(Annotatable::Item(
+			quote_item!(cx,
+				impl <T: ::elastic_types::geo::GeoPointFormat> serde::Serialize for $ty<T> {
+					fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+					where S: serde::Serializer {
+						serializer.serialize_struct("mapping", Self::get_visitor())
+					}
+				}
+			).unwrap()
+		));
+	}
+	else {
+		cx.span_err(
+			meta_item.span,
+			"`#[derive(ElasticGeoPointMapping)]` may only be applied to structs with a generic parameter");
+		return;
+	}
+}
+
+pub fn expand_derive_geo_shape_mapping(cx: &mut ExtCtxt, _: Span, meta_item: &MetaItem, annotatable: &Annotatable, push: &mut FnMut(Annotatable)) {
+	let item = expect_item!(cx, meta_item, annotatable);
+	let ty = item.ident;
+
+	push(Annotatable::Item(
+		quote_item!(cx,
+			impl ::elastic_types::mapping::ElasticFieldMapping<()> for $ty {
+				type Visitor = ::elastic_types::geo::mapping::ElasticGeoShapeMappingVisitor<$ty>;
+
+				fn data_type() -> &'static str {
+					"geo_shape"
+				}
+			}
+		).unwrap()
+	));
+
+	impl_mapping_ser(cx, &ty, push);
+}
+
+#[doc(hidden)]
+pub fn expand_derive_ip_mapping(cx: &mut ExtCtxt, _: Span, meta_item: &MetaItem, annotatable: &Annotatable, push: &mut FnMut(Annotatable)) {
+	let item = expect_item!(cx, meta_item, annotatable);
+	let ty = item.ident;
+
+	push(Annotatable::Item(
+		quote_item!(cx,
+			impl ::elastic_types::mapping::ElasticFieldMapping<()> for $ty {
+				type Visitor = ::elastic_types::ip::mapping::ElasticIpMappingVisitor<$ty>;
+
+				fn data_type() -> &'static str {
+					"ip"
+				}
+			}
+		).unwrap()
+	));
+
+	impl_mapping_ser(cx, &ty, push);
+}
+
+#[doc(hidden)]
+pub fn expand_derive_binary_mapping(cx: &mut ExtCtxt, _: Span, meta_item: &MetaItem, annotatable: &Annotatable, push: &mut FnMut(Annotatable)) {
+	let item = expect_item!(cx, meta_item, annotatable);
+	let ty = item.ident;
+
+	push(Annotatable::Item(
+		quote_item!(cx,
+			impl ::elastic_types::mapping::ElasticFieldMapping<()> for $ty {
+				type Visitor = ::elastic_types::binary::mapping::ElasticBinaryMappingVisitor<$ty>;
+
+				fn data_type() -> &'static str {
+					"binary"
+				}
+			}
+		).unwrap()
+	));
+
+	impl_mapping_ser(cx, &ty, push);
+}
+
+#[doc(hidden)]
+pub fn expand_derive_token_count_mapping(cx: &mut ExtCtxt, _: Span, meta_item: &MetaItem, annotatable: &Annotatable, push: &mut FnMut(Annotatable)) {
+	let item = expect_item!(cx, meta_item, annotatable);
+	let ty = item.ident;
+
+	push(Annotatable::Item(
+		quote_item!(cx,
+			impl ::elastic_types::mapping::ElasticFieldMapping<()> for $ty {
+				type Visitor = ::elastic_types::token_count::mapping::ElasticTokenCountMappingVisitor<$ty>;
+
+				fn data_type() -> &'static str {
+					"token_count"
+				}
+			}
+		).unwrap()
+	));
+
+	impl_mapping_ser(cx, &ty, push);
+}
+
+#[doc(hidden)]
+pub fn expand_derive_completion_mapping(cx: &mut ExtCtxt, _: Span, meta_item: &MetaItem, annotatable: &Annotatable, push: &mut FnMut(Annotatable)) {
+	let item = expect_item!(cx, meta_item, annotatable);
+	let ty = item.ident;
+
+	push(Annotatable::Item(
+		quote_item!(cx,
+			impl ::elastic_types::mapping::ElasticFieldMapping<()> for $ty {
+				type Visitor = ::elastic_types::completion::mapping::ElasticCompletionMappingVisitor<$ty>;
+
+				fn data_type() -> &'static str {
+					"completion"
+				}
+			}
+		).unwrap()
+	));
+
+	impl_mapping_ser(cx, &ty, push);
+}
+
 fn impl_mapping_ser(cx: &mut ExtCtxt, ty: &Ident, push: &mut FnMut(Annotatable)) {
 	push(Annotatable::Item(
 		quote_item!(cx,
@@ -487,4 +651,40 @@ pub fn plugin_registrar(reg: &mut Registry) {
 		syntax::ext::base::MultiDecorator(
 			Box::new(expand_derive_date_mapping))
 	);
+
+	reg.register_syntax_extension(
+		syntax::parse::token::intern("derive_ElasticGeoPointMapping"),
+		syntax::ext::base::MultiDecorator(
+			Box::new(expand_derive_geo_point_mapping))
+	);
+
+	reg.register_syntax_extension(
+		syntax::parse::token::intern("derive_ElasticGeoShapeMapping"),
+		syntax::ext::base::MultiDecorator(
+			Box::new(expand_derive_geo_shape_mapping))
+	);
+
+	reg.register_syntax_extension(
+		syntax::parse::token::intern("derive_ElasticIpMapping"),
+		syntax::ext::base::MultiDecorator(
+			Box::new(expand_derive_ip_mapping))
+	);
+
+	reg.register_syntax_extension(
+		syntax::parse::token::intern("derive_ElasticBinaryMapping"),
+		syntax::ext::base::MultiDecorator(
+			Box::new(expand_derive_binary_mapping))
+	);
+
+	reg.register_syntax_extension(
+		syntax::parse::token::intern("derive_ElasticTokenCountMapping"),
+		syntax::ext::base::MultiDecorator(
+			Box::new(expand_derive_token_count_mapping))
+	);
+
+	reg.register_syntax_extension(
+		syntax::parse::token::intern("derive_ElasticCompletionMapping"),
+		syntax::ext::base::MultiDecorator(
+			Box::new(expand_derive_completion_mapping))
+	);
 }
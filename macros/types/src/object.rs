@@ -2,6 +2,7 @@ use syntax::codemap::Span;
 use syntax::parse::token;
 use syntax::ast;
 use syntax::ast::Ident;
+use syntax::ptr::P;
 use syntax::ext::base::{ ExtCtxt, Annotatable };
 use syntax::ext::build::AstBuilder;
 
@@ -16,8 +17,9 @@ pub fn impl_type(cx: &mut ExtCtxt, item: &ast::Item, mapping: &Ident, push: &mut
 }
 
 //Build a field mapping type and return the name
-pub fn build_field_mapping(cx: &mut ExtCtxt, item: &ast::Item, push: &mut FnMut(Annotatable)) -> Ident {
+pub fn build_field_mapping(cx: &mut ExtCtxt, span: Span, item: &ast::Item, push: &mut FnMut(Annotatable)) -> Ident {
 	let name = token::str_to_ident(&format!("{}Mapping", item.ident));
+	let params = get_object_params(cx, &item.attrs);
 
 	push(Annotatable::Item(
 		quote_item!(cx,
@@ -26,9 +28,38 @@ pub fn build_field_mapping(cx: &mut ExtCtxt, item: &ast::Item, push: &mut FnMut(
 		).unwrap()
 	));
 
+	let data_type = match params.data_type {
+		Some(data_type) => cx.expr_str(span, token::intern_and_get_ident(data_type)),
+		None => quote_expr!(cx, ::elastic_types::object::NESTED_DATATYPE)
+	};
+	let dynamic = dynamic_expr(cx, params.dynamic);
+	let enabled = bool_expr(cx, params.enabled);
+	let include_in_parent = bool_expr(cx, params.include_in_parent);
+	let include_in_root = bool_expr(cx, params.include_in_root);
+
 	push(Annotatable::Item(
 		quote_item!(cx,
-			impl ::elastic_types::object::ElasticObjectMapping for $name { }
+			impl ::elastic_types::object::ElasticObjectMapping for $name {
+				fn data_type() -> &'static str {
+					$data_type
+				}
+
+				fn dynamic() -> Option<::elastic_types::object::Dynamic> {
+					$dynamic
+				}
+
+				fn enabled() -> Option<bool> {
+					$enabled
+				}
+
+				fn include_in_parent() -> Option<bool> {
+					$include_in_parent
+				}
+
+				fn include_in_root() -> Option<bool> {
+					$include_in_root
+				}
+			}
 		).unwrap()
 	));
 
@@ -128,6 +159,8 @@ fn impl_properties_visitor_ser(cx: &mut ExtCtxt, span: Span, visitor: &Ident, fi
             });
 
 			let expr = cx.expr_call(span, cx.expr_path(ty), Vec::new());
+			let params = get_object_params(cx, &field.attrs);
+			let expr = wrap_object_field_mapping(cx, span, expr, &params);
 
 			Some(quote_stmt!(cx,
 				try!(serializer.serialize_struct_elt($lit, $expr));
@@ -156,6 +189,55 @@ fn impl_properties_visitor_ser(cx: &mut ExtCtxt, span: Span, visitor: &Ident, fi
 	));
 }
 
+//Build a struct of field paths and implement `ElasticFieldPaths` to get it
+pub fn build_field_paths(cx: &mut ExtCtxt, span: Span, item: &ast::Item, fields: &Vec<(Ident, ast::StructField)>, push: &mut FnMut(Annotatable)) {
+	let name = token::str_to_ident(&format!("{}FieldPaths", item.ident));
+	let ty = item.ident;
+
+	let path_fields: Vec<ast::StructField> = fields.iter().filter_map(|&(_, ref field)| {
+		let field_ty = &field.ty;
+
+		field.ident.map(|ident| ast::StructField {
+			span: span,
+			ident: Some(ident),
+			vis: ast::Visibility::Public,
+			id: ast::DUMMY_NODE_ID,
+			ty: quote_ty!(cx, ::elastic_types::object::FieldPath<$field_ty>),
+			attrs: Vec::new()
+		})
+	})
+	.collect();
+
+	let path_exprs: Vec<ast::Field> = fields.iter().filter_map(|&(ref ser_name, ref field)| {
+		let lit = cx.expr_str(span, ser_name.name.as_str());
+
+		field.ident.map(|ident| cx.field_imm(span, ident, quote_expr!(cx, ::elastic_types::object::FieldPath::under(parent, $lit))))
+	})
+	.collect();
+
+	let paths_item = cx.item_struct(span, name, ast::VariantData::Struct(path_fields, ast::DUMMY_NODE_ID))
+		.map(|mut item| {
+			item.vis = ast::Visibility::Public;
+			item
+		});
+
+	push(Annotatable::Item(paths_item));
+
+	let paths_expr = cx.expr_struct_ident(span, name, path_exprs);
+
+	push(Annotatable::Item(
+		quote_item!(cx,
+			impl ::elastic_types::object::ElasticFieldPaths for $ty {
+				type Paths = $name;
+
+				fn field_paths_under(parent: Option<&str>) -> $name {
+					$paths_expr
+				}
+			}
+		).unwrap()
+	));
+}
+
 pub fn get_type_name(cx: &ExtCtxt, item: &ast::Item) -> Ident {
 	for meta_items in item.attrs().iter().filter_map(super::get_elastic_meta_items) {
         for meta_item in meta_items {
@@ -196,3 +278,148 @@ pub fn get_field_mapping(cx: &ExtCtxt, item: &ast::Item) -> Option<Ident> {
 
     None
 }
+
+//Object parameters set by `#[elastic(..)]` attributes on a type or a field
+#[derive(Default)]
+pub struct ObjectParams {
+	pub data_type: Option<&'static str>,
+	pub dynamic: Option<&'static str>,
+	pub enabled: Option<bool>,
+	pub include_in_parent: Option<bool>,
+	pub include_in_root: Option<bool>
+}
+
+impl ObjectParams {
+	pub fn is_empty(&self) -> bool {
+		self.data_type.is_none() &&
+		self.dynamic.is_none() &&
+		self.enabled.is_none() &&
+		self.include_in_parent.is_none() &&
+		self.include_in_root.is_none()
+	}
+}
+
+pub fn get_object_params(cx: &ExtCtxt, attrs: &[ast::Attribute]) -> ObjectParams {
+	let mut params = ObjectParams::default();
+	let mut nested_only = Vec::new();
+
+	for meta_items in attrs.iter().filter_map(super::get_elastic_meta_items) {
+        for meta_item in meta_items {
+            match meta_item.node {
+                // Parse `#[elastic(object)]`
+                ast::MetaItemKind::Word(ref name) if name == &"object" => {
+                    params.data_type = Some("object");
+                }
+                // Parse `#[elastic(nested)]`
+                ast::MetaItemKind::Word(ref name) if name == &"nested" => {
+                    params.data_type = Some("nested");
+                }
+                // Parse `#[elastic(include_in_parent)]`
+                ast::MetaItemKind::Word(ref name) if name == &"include_in_parent" => {
+                    params.include_in_parent = Some(true);
+                    nested_only.push((meta_item.span, "include_in_parent"));
+                }
+                // Parse `#[elastic(include_in_root)]`
+                ast::MetaItemKind::Word(ref name) if name == &"include_in_root" => {
+                    params.include_in_root = Some(true);
+                    nested_only.push((meta_item.span, "include_in_root"));
+                }
+                // Parse `#[elastic(dynamic="strict")]`
+                ast::MetaItemKind::NameValue(ref name, ref lit) if name == &"dynamic" => {
+                    params.dynamic = match get_str_from_lit(cx, name, lit).as_ref().map(|s| &s[..]) {
+                        Some("true") => Some("True"),
+                        Some("false") => Some("False"),
+                        Some("strict") => Some("Strict"),
+                        _ => {
+                            cx.span_err(lit.span, "annotation `dynamic` must be `true`, `false` or `strict`");
+                            None
+                        }
+                    };
+                }
+                // Parse `#[elastic(enabled="false")]`
+                ast::MetaItemKind::NameValue(ref name, ref lit) if name == &"enabled" => {
+                    params.enabled = match get_str_from_lit(cx, name, lit).as_ref().map(|s| &s[..]) {
+                        Some("true") => Some(true),
+                        Some("false") => Some(false),
+                        _ => {
+                            cx.span_err(lit.span, "annotation `enabled` must be `true` or `false`");
+                            None
+                        }
+                    };
+                }
+                _ => ()
+            }
+        }
+    }
+
+	//Types are nested by default, so only an explicit `object` is checked here
+	if let Some(data_type) = params.data_type {
+		if data_type != "nested" {
+			for (span, name) in nested_only {
+				cx.span_err(span, &format!("annotation `{}` can only be used on `nested` objects", name));
+			}
+		}
+	}
+
+    params
+}
+
+fn get_str_from_lit(cx: &ExtCtxt, name: &str, lit: &ast::Lit) -> Option<String> {
+	super::get_ident_from_lit(cx, name, lit)
+		.ok()
+		.map(|ident| ident.name.as_str().to_string())
+}
+
+fn dynamic_expr(cx: &mut ExtCtxt, dynamic: Option<&'static str>) -> P<ast::Expr> {
+	match dynamic {
+		Some(dynamic) => {
+			let variant = token::str_to_ident(dynamic);
+			quote_expr!(cx, Some(::elastic_types::object::Dynamic::$variant))
+		},
+		None => quote_expr!(cx, None)
+	}
+}
+
+fn bool_expr(cx: &mut ExtCtxt, value: Option<bool>) -> P<ast::Expr> {
+	match value {
+		Some(true) => quote_expr!(cx, Some(true)),
+		Some(false) => quote_expr!(cx, Some(false)),
+		None => quote_expr!(cx, None)
+	}
+}
+
+//Wrap the mapping for a field so its object parameters can be overridden
+fn wrap_object_field_mapping(cx: &mut ExtCtxt, span: Span, mapping: P<ast::Expr>, params: &ObjectParams) -> P<ast::Expr> {
+	if params.is_empty() {
+		return mapping;
+	}
+
+	let mut expr = quote_expr!(cx, ::elastic_types::object::ElasticObjectFieldMapping::new($mapping));
+
+	if let Some(data_type) = params.data_type {
+		let lit = cx.expr_str(span, token::intern_and_get_ident(data_type));
+		expr = quote_expr!(cx, $expr.data_type($lit));
+	}
+
+	if let Some(dynamic) = params.dynamic {
+		let variant = token::str_to_ident(dynamic);
+		expr = quote_expr!(cx, $expr.dynamic(::elastic_types::object::Dynamic::$variant));
+	}
+
+	if let Some(enabled) = params.enabled {
+		let lit = cx.expr_bool(span, enabled);
+		expr = quote_expr!(cx, $expr.enabled($lit));
+	}
+
+	if let Some(include_in_parent) = params.include_in_parent {
+		let lit = cx.expr_bool(span, include_in_parent);
+		expr = quote_expr!(cx, $expr.include_in_parent($lit));
+	}
+
+	if let Some(include_in_root) = params.include_in_root {
+		let lit = cx.expr_bool(span, include_in_root);
+		expr = quote_expr!(cx, $expr.include_in_root($lit));
+	}
+
+	expr
+}
@@ -0,0 +1,166 @@
+use std::sync::{ Arc, Mutex, MutexGuard };
+use std::sync::atomic::{ AtomicUsize, Ordering };
+use std::sync::mpsc::{ channel, Sender };
+use std::time::Duration;
+use elastic_hyper::{ HttpRequest, Transport };
+use ::{ Error, Request, ResponseFuture };
+use ::conn::{ self, Node, Pending, Queued, Timeouts };
+
+/// An asynchronous client for a set of nodes.
+///
+/// Each node gets its own event loop with a persistent connection that's opened on first use,
+/// and reopened if it's closed.
+/// Requests are sent to the node with the fewest responses outstanding.
+///
+/// Requests fail with `Error::Timeout` if a node doesn't accept a connection or respond in time.
+/// Timeouts are set when the client is built, and apply to connections opened after they're set.
+///
+/// Cloning a `Client` is cheap, and clones share the same connections.
+/// The event loops stop once every clone has been dropped and outstanding responses have been read.
+///
+/// # Examples
+///
+/// Send a search request and wait for the response:
+///
+/// ```no_run
+/// # extern crate hyper;
+/// # extern crate serde_json;
+/// # extern crate elastic_hyper;
+/// # extern crate elastic_rotor;
+/// # fn main() {
+/// use hyper::method::Method;
+/// use elastic_rotor::{ Client, Request };
+///
+/// let client = Client::new(vec!["http://localhost:9200"]).unwrap();
+///
+/// let req = Request::new(Method::Post, "/myindex/mytype/_search")
+/// 	.params(&elastic_hyper::RequestParams::default(), &elastic_hyper::search::Params::default())
+/// 	.body(r#"{ "query": { "match_all": {} } }"#);
+///
+/// //Returns straight away
+/// let res = client.send(req);
+///
+/// let hits: serde_json::Value = res.wait_json().unwrap();
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Client {
+	nodes: Vec<NodeHandle>,
+	timeouts: Arc<Mutex<Timeouts>>
+}
+
+#[derive(Clone)]
+struct NodeHandle {
+	node: Node,
+	tx: Sender<Queued>,
+	in_flight: Arc<AtomicUsize>
+}
+
+impl Client {
+	/// Create a client for the given node base urls, like `http://localhost:9200`.
+	///
+	/// Connections aren't opened until a request is sent to a node.
+	pub fn new<I, S>(nodes: I) -> Result<Client, Error>
+	where I: IntoIterator<Item=S>, S: AsRef<str> {
+		let mut handles = Vec::new();
+		let timeouts = Arc::new(Mutex::new(Timeouts::default()));
+
+		for url in nodes {
+			let node = try!(Node::parse(url.as_ref()));
+
+			handles.push(NodeHandle {
+				tx: conn::spawn(node.clone(), timeouts.clone()),
+				node: node,
+				in_flight: Arc::new(AtomicUsize::new(0))
+			});
+		}
+
+		if handles.len() == 0 {
+			return Err(Error::Http("no nodes to connect to".to_owned()));
+		}
+
+		Ok(Client {
+			nodes: handles,
+			timeouts: timeouts
+		})
+	}
+
+	/// Set how long to wait for a node to accept a connection.
+	///
+	/// Requests waiting on the connection fail with `Error::Timeout` if it isn't opened in time.
+	/// The default is 5 seconds.
+	pub fn connect_timeout(self, timeout: Duration) -> Self {
+		self.lock_timeouts().connect = timeout;
+
+		self
+	}
+
+	/// Set how long to wait for a node to respond to a request.
+	///
+	/// If a request hasn't been answered in time, it fails with `Error::Timeout`,
+	/// along with any requests that were pipelined behind it on the same connection.
+	/// The default is 30 seconds.
+	pub fn read_timeout(self, timeout: Duration) -> Self {
+		self.lock_timeouts().read = timeout;
+
+		self
+	}
+
+	/// Set how long writing a request to a node can block.
+	///
+	/// The default is 30 seconds.
+	pub fn write_timeout(self, timeout: Duration) -> Self {
+		self.lock_timeouts().write = timeout;
+
+		self
+	}
+
+	/// The `host:port` addresses of the nodes this client sends requests to.
+	pub fn nodes(&self) -> Vec<&str> {
+		self.nodes.iter().map(|n| &n.node.addr[..]).collect()
+	}
+
+	/// Send a request without waiting for the response.
+	///
+	/// The request is queued on a node's connection and the returned future
+	/// completes when its response has been read.
+	pub fn send(&self, req: Request) -> ResponseFuture {
+		let (tx, rx) = channel();
+
+		let handle = self.nodes.iter()
+			.min_by_key(|n| n.in_flight.load(Ordering::SeqCst))
+			.expect("a client always has at least one node");
+
+		let pending = Pending::new(&req, tx, handle.tx.clone(), handle.in_flight.clone());
+
+		if let Err(e) = handle.tx.send(Queued { req: req, pending: pending }) {
+			// The event loop has stopped, so fail the request straight away
+			e.0.pending.complete(Err(Error::Closed));
+		}
+
+		ResponseFuture::new(rx)
+	}
+
+	fn lock_timeouts(&self) -> MutexGuard<Timeouts> {
+		self.timeouts.lock().unwrap_or_else(|e| e.into_inner())
+	}
+}
+
+/// Send request values from the `elastic_hyper` `_request` functions.
+///
+/// Sending never fails straight away; any errors are returned when the future is waited on.
+impl Transport for Client {
+	type Response = ResponseFuture;
+	type Error = Error;
+
+	fn send(&mut self, req: HttpRequest) -> Result<ResponseFuture, Error> {
+		Ok(Client::send(self, req.into()))
+	}
+
+	fn is_connection_error(&self, err: &Error) -> bool {
+		match *err {
+			Error::Io(_) | Error::Closed | Error::Timeout => true,
+			_ => false
+		}
+	}
+}
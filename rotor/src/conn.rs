@@ -0,0 +1,435 @@
+use std::io::{ self, BufRead, BufReader, BufWriter, Write };
+use std::net::{ Shutdown, TcpStream };
+use std::sync::{ Arc, Mutex };
+use std::sync::atomic::{ AtomicBool, AtomicUsize, Ordering };
+use std::sync::mpsc::{ channel, Receiver, Sender };
+use std::thread;
+use std::time::{ Duration, Instant };
+use hyper::method::Method;
+use ::{ Error, Request, Response };
+use ::request::write_request;
+use ::response::read_response;
+
+/// The address of a node.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node {
+	/// The value sent in the `Host` header.
+	pub host: String,
+	/// The `host:port` address to connect to.
+	pub addr: String
+}
+
+impl Node {
+	/// Parse a node from a base url like `http://localhost:9200`.
+	///
+	/// The scheme is optional, and the port defaults to `9200`.
+	/// Only plain `http` is supported.
+	pub fn parse(url: &str) -> Result<Node, Error> {
+		let rest = if url.starts_with("http://") {
+			&url[7..]
+		}
+		else if url.contains("://") {
+			return Err(Error::Http(format!("unsupported scheme in '{}'", url)));
+		}
+		else {
+			url
+		};
+
+		let authority = rest.split('/').next().unwrap_or("");
+		if authority.len() == 0 {
+			return Err(Error::Http(format!("no host in '{}'", url)));
+		}
+
+		let has_port = match authority.rfind(':') {
+			Some(i) => !authority.ends_with(']') && authority[i + 1..].parse::<u16>().is_ok(),
+			None => false
+		};
+
+		let addr = if has_port {
+			authority.to_owned()
+		}
+		else {
+			format!("{}:9200", authority)
+		};
+
+		Ok(Node {
+			host: authority.to_owned(),
+			addr: addr
+		})
+	}
+}
+
+/// The default time to wait for a connection to a node to open.
+const DEFAULT_CONNECT_TIMEOUT_SECS: u64 = 5;
+
+/// The default time to wait on a node to respond to a request, or to accept one that's being written.
+const DEFAULT_TIMEOUT_SECS: u64 = 30;
+
+/// How long to wait on a node before failing requests to it.
+#[derive(Debug, Clone, Copy)]
+pub struct Timeouts {
+	pub connect: Duration,
+	pub read: Duration,
+	pub write: Duration
+}
+
+impl Default for Timeouts {
+	fn default() -> Self {
+		Timeouts {
+			connect: Duration::from_secs(DEFAULT_CONNECT_TIMEOUT_SECS),
+			read: Duration::from_secs(DEFAULT_TIMEOUT_SECS),
+			write: Duration::from_secs(DEFAULT_TIMEOUT_SECS)
+		}
+	}
+}
+
+/// A request waiting to be written to a connection.
+pub struct Queued {
+	pub req: Request,
+	pub pending: Pending
+}
+
+impl Queued {
+	/// Queue the request again so it's written to a new connection.
+	///
+	/// The node may have handled the request before the connection was lost,
+	/// so only idempotent requests (`GET`, `HEAD`, `PUT` and `DELETE`) are retried, and only once.
+	/// Any other request, or one that's already been retried, fails with `err`.
+	pub fn retry(mut self, err: Error) {
+		if self.pending.retried || !self.pending.idempotent {
+			self.pending.complete(Err(err));
+			return;
+		}
+
+		self.pending.retried = true;
+		self.requeue(err);
+	}
+
+	/// Queue the request again without counting it as a retry.
+	///
+	/// This is for requests the node is known not to have handled,
+	/// like ones that were pipelined behind a `Connection: close` response.
+	pub fn requeue(self, err: Error) {
+		let requeue = self.pending.requeue.clone();
+
+		if let Err(e) = requeue.send(self) {
+			// The event loop has stopped, so there's no connection to retry on
+			e.0.pending.complete(Err(err));
+		}
+	}
+}
+
+/// A request that's waiting for its response.
+pub struct Pending {
+	head: bool,
+	idempotent: bool,
+	retried: bool,
+	written: Instant,
+	tx: Sender<Result<Response, Error>>,
+	requeue: Sender<Queued>,
+	in_flight: Arc<AtomicUsize>
+}
+
+impl Pending {
+	/// Track a request sent to the event loop on `requeue`.
+	///
+	/// The response is sent to `tx`, and the request is sent back to `requeue` if it needs to be retried.
+	pub fn new(req: &Request, tx: Sender<Result<Response, Error>>, requeue: Sender<Queued>, in_flight: Arc<AtomicUsize>) -> Self {
+		in_flight.fetch_add(1, Ordering::SeqCst);
+
+		Pending {
+			head: req.method == Method::Head,
+			idempotent: is_idempotent(&req.method),
+			retried: false,
+			written: Instant::now(),
+			tx: tx,
+			requeue: requeue,
+			in_flight: in_flight
+		}
+	}
+
+	pub fn complete(self, res: Result<Response, Error>) {
+		self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+		// The future may have been dropped, which is fine
+		let _ = self.tx.send(res);
+	}
+}
+
+fn is_idempotent(method: &Method) -> bool {
+	match *method {
+		Method::Get | Method::Head | Method::Put | Method::Delete => true,
+		_ => false
+	}
+}
+
+/// Start the event loop for a node.
+///
+/// Requests sent to the returned channel are written to a single keep-alive connection
+/// as they arrive, without waiting for earlier responses.
+/// If the node closes the connection, a new one is opened for the next request,
+/// and idempotent requests that were written but never answered are retried once on the new connection.
+/// Requests fail with `Error::Timeout` if the node doesn't respond to them within the read timeout.
+/// The loop finishes once every sender has been dropped and all responses have been read.
+pub fn spawn(node: Node, timeouts: Arc<Mutex<Timeouts>>) -> Sender<Queued> {
+	let (tx, rx) = channel();
+
+	thread::spawn(move || {
+		Connection {
+			node: node,
+			timeouts: timeouts,
+			open: None
+		}
+		.run(rx)
+	});
+
+	tx
+}
+
+struct Connection {
+	node: Node,
+	timeouts: Arc<Mutex<Timeouts>>,
+	open: Option<Open>
+}
+
+struct Open {
+	writer: BufWriter<TcpStream>,
+	written: Sender<Queued>,
+	closed: Arc<AtomicBool>
+}
+
+impl Connection {
+	fn run(mut self, rx: Receiver<Queued>) {
+		while let Ok(queued) = rx.recv() {
+			self.write(queued);
+
+			// Pipeline anything else that's already waiting before flushing
+			while let Ok(queued) = rx.try_recv() {
+				self.write(queued);
+			}
+
+			self.flush();
+		}
+
+		self.close();
+	}
+
+	fn write(&mut self, mut queued: Queued) {
+		// The reader marks the connection closed as soon as the node hangs up, even if it's idle
+		let needs_connect = match self.open {
+			Some(ref open) => open.closed.load(Ordering::SeqCst),
+			None => true
+		};
+
+		if needs_connect {
+			self.close();
+
+			let timeouts = *self.timeouts.lock().unwrap_or_else(|e| e.into_inner());
+
+			match connect(&self.node, timeouts) {
+				Ok(open) => self.open = Some(open),
+				Err(e) => {
+					queued.pending.complete(Err(e));
+					return;
+				}
+			}
+		}
+
+		let written = {
+			let open = self.open.as_mut().expect("connection should be open");
+			queued.pending.written = Instant::now();
+
+			match write_request(&queued.req, &self.node.host, &mut open.writer) {
+				Ok(()) => {
+					// If the reader has already stopped it retries anything it's sent
+					if let Err(e) = open.written.send(queued) {
+						e.0.retry(Error::Closed);
+					}
+					Ok(())
+				},
+				Err(e) => Err((queued, e))
+			}
+		};
+
+		if let Err((queued, e)) = written {
+			self.close();
+			queued.retry(e.into());
+		}
+	}
+
+	fn flush(&mut self) {
+		let flushed = match self.open {
+			Some(ref mut open) => open.writer.flush().is_ok(),
+			None => true
+		};
+
+		if !flushed {
+			self.close();
+		}
+	}
+
+	fn close(&mut self) {
+		if let Some(open) = self.open.take() {
+			// Wakes up the reader so it can fail any requests still waiting on this connection
+			let _ = open.writer.get_ref().shutdown(Shutdown::Both);
+		}
+	}
+}
+
+fn connect(node: &Node, timeouts: Timeouts) -> Result<Open, Error> {
+	let stream = try!(connect_timeout(&node.addr, timeouts.connect));
+	let _ = stream.set_nodelay(true);
+	try!(stream.set_read_timeout(Some(timeouts.read)));
+	try!(stream.set_write_timeout(Some(timeouts.write)));
+
+	let read = try!(stream.try_clone());
+	let (tx, rx) = channel();
+	let closed = Arc::new(AtomicBool::new(false));
+
+	let reader_closed = closed.clone();
+	thread::spawn(move || read_loop(read, rx, reader_closed, timeouts.read));
+
+	Ok(Open {
+		writer: BufWriter::new(stream),
+		written: tx,
+		closed: closed
+	})
+}
+
+/// Open a connection to `addr`, giving up after `timeout`.
+///
+/// The connection is opened on its own thread so it can be abandoned if the node doesn't accept it in time.
+fn connect_timeout(addr: &str, timeout: Duration) -> Result<TcpStream, Error> {
+	let (tx, rx) = channel();
+
+	let connected = tx.clone();
+	let addr = addr.to_owned();
+	thread::spawn(move || {
+		let _ = connected.send(TcpStream::connect(&*addr).map_err(|e| e.into()));
+	});
+
+	thread::spawn(move || {
+		thread::sleep(timeout);
+		let _ = tx.send(Err(Error::Timeout));
+	});
+
+	rx.recv().unwrap_or(Err(Error::Timeout))
+}
+
+/// Read responses off a connection in the order their requests were written.
+///
+/// The reader waits on the socket rather than on the next request,
+/// so a connection that's closed by the node while it's idle is noticed before anything else is written to it.
+/// If the oldest unanswered request has waited longer than `timeout`, it and every other request
+/// waiting on the connection fail with `Error::Timeout`.
+/// Once the connection is closed, any idempotent requests that were written to it without being answered are retried.
+fn read_loop(stream: TcpStream, written: Receiver<Queued>, closed: Arc<AtomicBool>, timeout: Duration) {
+	let mut reader = BufReader::new(stream);
+	let mut close_announced = false;
+	let mut next: Option<Queued> = None;
+
+	loop {
+		if next.is_none() {
+			next = written.try_recv().ok();
+		}
+
+		// An idle connection waits a full timeout, so it's only closed if the node hangs up
+		let wait = match next {
+			Some(ref queued) => remaining(queued.pending.written, timeout),
+			None => Some(timeout)
+		};
+
+		match wait {
+			Some(wait) => {
+				let _ = reader.get_ref().set_read_timeout(Some(wait));
+			},
+			None => {
+				closed.store(true, Ordering::SeqCst);
+
+				if let Some(queued) = next.take() {
+					queued.pending.complete(Err(Error::Timeout));
+				}
+
+				// Anything pipelined behind the timed out request would have to wait on it too
+				while let Ok(queued) = written.try_recv() {
+					queued.pending.complete(Err(Error::Timeout));
+				}
+
+				break;
+			}
+		}
+
+		let has_response = match reader.fill_buf() {
+			Ok(buf) => buf.len() > 0,
+			Err(ref e) if is_timeout(e) => continue,
+			Err(_) => false
+		};
+
+		if !has_response {
+			break;
+		}
+
+		let queued = match next.take() {
+			Some(queued) => queued,
+			None => match written.recv() {
+				Ok(queued) => queued,
+				Err(_) => break
+			}
+		};
+
+		// Once the response starts arriving, the timeout applies to each read instead of the whole response
+		let _ = reader.get_ref().set_read_timeout(Some(timeout));
+
+		match read_response(&mut reader, queued.pending.head) {
+			Ok((res, keep_alive)) => {
+				if !keep_alive {
+					closed.store(true, Ordering::SeqCst);
+					close_announced = true;
+					queued.pending.complete(Ok(res));
+					break;
+				}
+
+				queued.pending.complete(Ok(res));
+			},
+			Err(e) => {
+				// Part of the response was read, so the request may have been handled and isn't retried
+				closed.store(true, Ordering::SeqCst);
+				queued.pending.complete(Err(e));
+				break;
+			}
+		}
+	}
+
+	closed.store(true, Ordering::SeqCst);
+	let _ = reader.get_ref().shutdown(Shutdown::Both);
+
+	// Stops once the writer has moved on to a new connection
+	for queued in next.into_iter().chain(written.iter()) {
+		if close_announced {
+			queued.requeue(Error::Closed);
+		}
+		else {
+			queued.retry(Error::Closed);
+		}
+	}
+}
+
+/// How much longer a request written at `since` can wait for its response.
+fn remaining(since: Instant, timeout: Duration) -> Option<Duration> {
+	let elapsed = since.elapsed();
+
+	if elapsed < timeout {
+		Some(timeout - elapsed)
+	}
+	else {
+		None
+	}
+}
+
+/// Whether a socket error means a read or write timed out.
+pub fn is_timeout(err: &io::Error) -> bool {
+	match err.kind() {
+		io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut => true,
+		_ => false
+	}
+}
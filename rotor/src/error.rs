@@ -0,0 +1,68 @@
+use std::error::Error as StdError;
+use std::fmt;
+use std::io;
+use serde_json::Error as JsonError;
+
+/// An error sending a request or receiving its response.
+#[derive(Debug)]
+pub enum Error {
+	/// The connection to the node failed.
+	Io(io::Error),
+	/// The node sent a response that couldn't be parsed as HTTP.
+	Http(String),
+	/// The connection was closed before a response was received.
+	Closed,
+	/// The node didn't accept a connection or respond to a request in time.
+	Timeout,
+	/// The response body couldn't be deserialised.
+	Json(JsonError)
+}
+
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match *self {
+			Error::Io(ref e) => write!(f, "io error: {}", e),
+			Error::Http(ref msg) => write!(f, "invalid http response: {}", msg),
+			Error::Closed => write!(f, "connection closed before a response was received"),
+			Error::Timeout => write!(f, "timed out waiting on the node"),
+			Error::Json(ref e) => write!(f, "json error: {}", e)
+		}
+	}
+}
+
+impl StdError for Error {
+	fn description(&self) -> &str {
+		match *self {
+			Error::Io(ref e) => e.description(),
+			Error::Http(ref msg) => msg,
+			Error::Closed => "connection closed before a response was received",
+			Error::Timeout => "timed out waiting on the node",
+			Error::Json(ref e) => e.description()
+		}
+	}
+
+	fn cause(&self) -> Option<&StdError> {
+		match *self {
+			Error::Io(ref e) => Some(e),
+			Error::Json(ref e) => Some(e),
+			_ => None
+		}
+	}
+}
+
+impl From<io::Error> for Error {
+	fn from(err: io::Error) -> Self {
+		if ::conn::is_timeout(&err) {
+			Error::Timeout
+		}
+		else {
+			Error::Io(err)
+		}
+	}
+}
+
+impl From<JsonError> for Error {
+	fn from(err: JsonError) -> Self {
+		Error::Json(err)
+	}
+}
@@ -1,14 +1,180 @@
-//TODO: Work on a basic persistent HTTP connection pool
-//Figure out what the role of the API should be. What exactly are we requesting?
-//Should we just be constructing a request object to send to the loop?
+//! Elasticsearch Rotor Client
+//!
+//! An asynchronous implementation of the Elasticsearch API with persistent connections.
+//!
+//! Requests are built from the same `RequestParams` and endpoint `Params` as `elastic_hyper`,
+//! and sent to a background event loop that owns a keep-alive connection to each node.
+//! Sending a request doesn't block; it returns a `ResponseFuture` that completes once the response
+//! has been read, and can deserialise the response body.
+//!
+//! Requests to the same node are pipelined, so they're written to the connection as soon as they're sent
+//! without waiting for earlier responses to arrive.
+//! Responses are matched back up to their requests in the order they're read.
+//!
+//! # Usage
+//!
+//! Send a few requests and wait for the responses later:
+//!
+//! ```no_run
+//! # extern crate hyper;
+//! # extern crate serde_json;
+//! # extern crate elastic_hyper;
+//! # extern crate elastic_rotor;
+//! # fn main() {
+//! use hyper::method::Method;
+//! use elastic_rotor::{ Client, Request };
+//!
+//! let client = Client::new(vec!["http://localhost:9200"]).unwrap();
+//! let params = elastic_hyper::RequestParams::default();
+//!
+//! let futures: Vec<_> = vec!["index1", "index2", "index3"]
+//! 	.into_iter()
+//! 	.map(|index| {
+//! 		let req = Request::new(Method::Get, format!("/{}/_search", index))
+//! 			.params(&params, &elastic_hyper::search::Params::default());
+//!
+//! 		client.send(req)
+//! 	})
+//! 	.collect();
+//!
+//! for res in futures {
+//! 	let res: serde_json::Value = res.wait_json().unwrap();
+//! }
+//! # }
+//! ```
+//!
+//! Requests can also be built with the `_request` functions in `elastic_hyper`,
+//! and sent through the client's `Transport` implementation:
+//!
+//! ```no_run
+//! # extern crate elastic_hyper;
+//! # extern crate elastic_rotor;
+//! # fn main() {
+//! use elastic_hyper::Transport;
+//! use elastic_rotor::Client;
+//!
+//! let mut client = Client::new(vec!["http://localhost:9200"]).unwrap();
+//!
+//! let req = elastic_hyper::ping::head_request(&elastic_hyper::RequestParams::default(), &elastic_hyper::ping::Params);
+//! let res = Transport::send(&mut client, req).unwrap().wait().unwrap();
+//! # }
+//! ```
+//!
+//! # Links
+//! - [elastic_hyper](http://kodraus.github.io/rustdoc/elastic_hyper/index.html)
+//! - [Github](https://github.com/KodrAus/elasticsearch-rs)
 
-// elastic::search::post_index_type(headers, "http://localhost:9200", "my_index", "my_type", json_str!({})) -> ElasticRequest
-// Send the request to the loop. Do this automagically?
-// Sending the request should return a promise that deserialises the response. See old kafka client work
+extern crate hyper;
+extern crate serde;
+extern crate serde_json;
+extern crate elastic_hyper;
+
+mod error;
+mod request;
+mod response;
+mod conn;
+mod client;
+
+pub use error::*;
+pub use request::Request;
+pub use response::{ Response, ResponseFuture };
+pub use client::Client;
 
 #[cfg(test)]
 mod test {
-    #[test]
-    fn it_works() {
-    }
+	use std::io::Cursor;
+	use hyper::method::Method;
+	use ::Request;
+	use ::conn::Node;
+	use ::request::write_request;
+	use ::response::read_response;
+
+	#[test]
+	fn parse_node_with_scheme_and_port() {
+		let node = Node::parse("http://es1:9201/").unwrap();
+
+		assert_eq!("es1:9201", node.host);
+		assert_eq!("es1:9201", node.addr);
+	}
+
+	#[test]
+	fn parse_node_without_port() {
+		let node = Node::parse("localhost").unwrap();
+
+		assert_eq!("localhost", node.host);
+		assert_eq!("localhost:9200", node.addr);
+	}
+
+	#[test]
+	fn parse_node_with_https_fails() {
+		assert!(Node::parse("https://localhost:9200").is_err());
+	}
+
+	#[test]
+	fn write_request_with_body() {
+		let req = Request::new(Method::Post, "/myindex/_search")
+			.header("Content-Type", "application/json")
+			.header("Content-Length", "100")
+			.body("{}");
+
+		let mut buf = Vec::new();
+		write_request(&req, "localhost:9200", &mut buf).unwrap();
+
+		let expected = "POST /myindex/_search HTTP/1.1\r\nHost: localhost:9200\r\nContent-Type: application/json\r\nContent-Length: 2\r\n\r\n{}";
+
+		assert_eq!(expected, String::from_utf8(buf).unwrap());
+	}
+
+	#[test]
+	fn read_response_with_content_length() {
+		let mut res = Cursor::new(&b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\n{}HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n"[..]);
+
+		let (first, keep_alive) = read_response(&mut res, false).unwrap();
+		assert_eq!(200, first.status);
+		assert_eq!(b"{}".to_vec(), first.body);
+		assert!(keep_alive);
+
+		let (second, _) = read_response(&mut res, false).unwrap();
+		assert_eq!(404, second.status);
+	}
+
+	#[test]
+	fn read_response_with_chunked_body() {
+		let mut res = Cursor::new(&b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n4\r\n{\"a\"\r\n3;ext=1\r\n:1}\r\n0\r\n\r\n"[..]);
+
+		let (res, keep_alive) = read_response(&mut res, false).unwrap();
+
+		assert_eq!("{\"a\":1}", res.body_str().unwrap());
+		assert!(keep_alive);
+	}
+
+	#[test]
+	fn read_response_to_head_request_has_no_body() {
+		let mut res = Cursor::new(&b"HTTP/1.1 200 OK\r\nContent-Length: 50\r\n\r\nHTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n"[..]);
+
+		let (first, _) = read_response(&mut res, true).unwrap();
+		assert_eq!(0, first.body.len());
+		assert_eq!(Some("50"), first.header("content-length"));
+
+		assert!(read_response(&mut res, true).is_ok());
+	}
+
+	#[test]
+	fn read_response_without_length_closes_connection() {
+		let mut res = Cursor::new(&b"HTTP/1.0 200 OK\r\n\r\nbody"[..]);
+
+		let (res, keep_alive) = read_response(&mut res, false).unwrap();
+
+		assert_eq!(b"body".to_vec(), res.body);
+		assert!(!keep_alive);
+	}
+
+	#[test]
+	fn read_response_with_connection_close() {
+		let mut res = Cursor::new(&b"HTTP/1.1 200 OK\r\nConnection: close\r\nContent-Length: 0\r\n\r\n"[..]);
+
+		let (_, keep_alive) = read_response(&mut res, false).unwrap();
+
+		assert!(!keep_alive);
+	}
 }
@@ -0,0 +1,153 @@
+use std::ascii::AsciiExt;
+use std::io::{ self, Write };
+use hyper::method::Method;
+use elastic_hyper::{ HttpRequest, RequestParams, UrlParams };
+
+/// A request to send to a node.
+///
+/// The path is relative to the node, so `RequestParams::base_url` isn't used.
+/// Url parameters and headers are taken from the same `RequestParams` and endpoint `Params`
+/// that are passed to the `elastic_hyper` functions.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate hyper;
+/// # extern crate elastic_hyper;
+/// # extern crate elastic_rotor;
+/// # fn main() {
+/// use hyper::method::Method;
+/// use elastic_rotor::Request;
+///
+/// let params = elastic_hyper::search::Params {
+/// 	q: Some("doc".to_owned()),
+/// 	..Default::default()
+/// };
+///
+/// let req = Request::new(Method::Get, "/myindex/mytype/_search")
+/// 	.params(&elastic_hyper::RequestParams::default(), &params);
+///
+/// assert_eq!("/myindex/mytype/_search?q=doc", req.path);
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Request {
+	/// The http method.
+	pub method: Method,
+	/// The path and query, starting with `/`.
+	pub path: String,
+	/// Headers to send with the request.
+	///
+	/// The `Host`, `Content-Length` and `Connection` headers are managed by the client.
+	pub headers: Vec<(String, String)>,
+	/// The request body, if there is one.
+	pub body: Option<Vec<u8>>
+}
+
+impl Request {
+	/// Create a new request for the given method and path.
+	pub fn new<P: Into<String>>(method: Method, path: P) -> Self {
+		Request {
+			method: method,
+			path: path.into(),
+			headers: Vec::new(),
+			body: None
+		}
+	}
+
+	/// Add the url parameters and headers from an endpoint's parameters.
+	///
+	/// Parameters set on `params` take precedence over the ones set on `req`.
+	pub fn params<P>(mut self, req: &RequestParams, params: &P) -> Self
+	where P: UrlParams {
+		self.path.push_str(&req.get_url_qry_with(params));
+
+		for header in req.headers.iter() {
+			self.headers.push((header.name().to_owned(), header.value_string()));
+		}
+
+		self
+	}
+
+	/// Add a header to the request.
+	pub fn header<K, V>(mut self, key: K, value: V) -> Self
+	where K: Into<String>, V: Into<String> {
+		self.headers.push((key.into(), value.into()));
+
+		self
+	}
+
+	/// Set the body for the request.
+	pub fn body<B: Into<Vec<u8>>>(mut self, body: B) -> Self {
+		self.body = Some(body.into());
+
+		self
+	}
+}
+
+/// Convert a request value from one of the `elastic_hyper` `_request` functions.
+///
+/// The scheme and host are dropped from the url, because the client picks the node to send to.
+impl From<HttpRequest> for Request {
+	fn from(req: HttpRequest) -> Self {
+		let path = {
+			let after_scheme = match req.url.find("://") {
+				Some(i) => &req.url[i + 3..],
+				None => &req.url[..]
+			};
+
+			match after_scheme.find('/') {
+				Some(i) => after_scheme[i..].to_owned(),
+				None => "/".to_owned()
+			}
+		};
+
+		let headers = req.headers.iter()
+			.map(|header| (header.name().to_owned(), header.value_string()))
+			.collect();
+
+		Request {
+			method: req.method,
+			path: path,
+			headers: headers,
+			body: req.body
+		}
+	}
+}
+
+/// Write a request in HTTP/1.1 wire format.
+pub fn write_request<W: Write>(req: &Request, host: &str, w: &mut W) -> io::Result<()> {
+	try!(write!(w, "{} {} HTTP/1.1\r\n", req.method, req.path));
+	try!(write!(w, "Host: {}\r\n", host));
+
+	for &(ref key, ref value) in &req.headers {
+		if is_managed_header(key) {
+			continue;
+		}
+
+		try!(write!(w, "{}: {}\r\n", key, value));
+	}
+
+	match req.body {
+		Some(ref body) => try!(write!(w, "Content-Length: {}\r\n", body.len())),
+		None => match req.method {
+			Method::Post | Method::Put => try!(write!(w, "Content-Length: 0\r\n")),
+			_ => ()
+		}
+	}
+
+	try!(write!(w, "\r\n"));
+
+	if let Some(ref body) = req.body {
+		try!(w.write_all(body));
+	}
+
+	Ok(())
+}
+
+fn is_managed_header(key: &str) -> bool {
+	key.eq_ignore_ascii_case("host")
+		|| key.eq_ignore_ascii_case("content-length")
+		|| key.eq_ignore_ascii_case("connection")
+		|| key.eq_ignore_ascii_case("transfer-encoding")
+}
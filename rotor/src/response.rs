@@ -0,0 +1,224 @@
+use std::ascii::AsciiExt;
+use std::io::{ BufRead, Read };
+use std::str;
+use std::sync::mpsc::{ Receiver, TryRecvError };
+use serde::Deserialize;
+use serde_json;
+use ::Error;
+
+/// A response received from a node.
+#[derive(Debug, Clone)]
+pub struct Response {
+	/// The http status code.
+	pub status: u16,
+	/// The response headers, in the order they were received.
+	pub headers: Vec<(String, String)>,
+	/// The response body.
+	///
+	/// This is empty for `HEAD` requests.
+	pub body: Vec<u8>
+}
+
+impl Response {
+	/// Get the value of a header.
+	///
+	/// Header names are compared case-insensitively.
+	pub fn header(&self, name: &str) -> Option<&str> {
+		self.headers.iter()
+			.find(|&&(ref key, _)| key.eq_ignore_ascii_case(name))
+			.map(|&(_, ref value)| &value[..])
+	}
+
+	/// Whether or not the status code is in the `2xx` range.
+	pub fn is_success(&self) -> bool {
+		self.status >= 200 && self.status < 300
+	}
+
+	/// Get the response body as a string.
+	pub fn body_str(&self) -> Result<&str, Error> {
+		str::from_utf8(&self.body).map_err(|e| Error::Http(format!("{}", e)))
+	}
+
+	/// Deserialise the response body from json.
+	///
+	/// The body is deserialised regardless of the status code,
+	/// so check `is_success` first if you need to handle errors from Elasticsearch.
+	pub fn json<T: Deserialize>(&self) -> Result<T, Error> {
+		serde_json::from_slice(&self.body).map_err(|e| e.into())
+	}
+}
+
+/// A response that hasn't been received yet.
+///
+/// A `ResponseFuture` is returned as soon as a request is queued,
+/// and is completed by the event loop when the response is read off the connection.
+/// Dropping the future doesn't cancel the request.
+pub struct ResponseFuture {
+	rx: Receiver<Result<Response, Error>>,
+	res: Option<Result<Response, Error>>
+}
+
+impl ResponseFuture {
+	#[doc(hidden)]
+	pub fn new(rx: Receiver<Result<Response, Error>>) -> Self {
+		ResponseFuture {
+			rx: rx,
+			res: None
+		}
+	}
+
+	/// Check whether the response is available without blocking.
+	pub fn is_ready(&mut self) -> bool {
+		if self.res.is_some() {
+			return true;
+		}
+
+		match self.rx.try_recv() {
+			Ok(res) => {
+				self.res = Some(res);
+				true
+			},
+			Err(TryRecvError::Empty) => false,
+			Err(TryRecvError::Disconnected) => {
+				self.res = Some(Err(Error::Closed));
+				true
+			}
+		}
+	}
+
+	/// Block until the response is available.
+	pub fn wait(self) -> Result<Response, Error> {
+		match self.res {
+			Some(res) => res,
+			None => self.rx.recv().unwrap_or(Err(Error::Closed))
+		}
+	}
+
+	/// Block until the response is available and deserialise its body from json.
+	pub fn wait_json<T: Deserialize>(self) -> Result<T, Error> {
+		self.wait().and_then(|res| res.json())
+	}
+}
+
+/// Read a single response off a connection.
+///
+/// Returns the response along with whether or not the connection can be used for another request.
+pub fn read_response<R: BufRead>(r: &mut R, head: bool) -> Result<(Response, bool), Error> {
+	let status_line = try!(read_line(r));
+
+	let (http_10, status) = {
+		let mut parts = status_line.split(' ').filter(|p| p.len() > 0);
+
+		let version = match parts.next() {
+			Some(v) if v.starts_with("HTTP/") => v,
+			_ => return Err(Error::Http(format!("invalid status line '{}'", status_line)))
+		};
+
+		let status = match parts.next().and_then(|s| s.parse::<u16>().ok()) {
+			Some(s) => s,
+			None => return Err(Error::Http(format!("invalid status line '{}'", status_line)))
+		};
+
+		(version == "HTTP/1.0", status)
+	};
+
+	let mut headers = Vec::new();
+	loop {
+		let line = try!(read_line(r));
+		if line.len() == 0 {
+			break;
+		}
+
+		let mut parts = line.splitn(2, ':');
+		match (parts.next(), parts.next()) {
+			(Some(key), Some(value)) => headers.push((key.trim().to_owned(), value.trim().to_owned())),
+			_ => return Err(Error::Http(format!("invalid header '{}'", line)))
+		}
+	}
+
+	let mut res = Response {
+		status: status,
+		headers: headers,
+		body: Vec::new()
+	};
+
+	let mut keep_alive = match res.header("connection") {
+		Some(c) if c.eq_ignore_ascii_case("close") => false,
+		Some(c) if c.eq_ignore_ascii_case("keep-alive") => true,
+		_ => !http_10
+	};
+
+	let chunked = res.header("transfer-encoding")
+		.map(|te| te.to_ascii_lowercase().contains("chunked"))
+		.unwrap_or(false);
+
+	let len = match res.header("content-length") {
+		Some(len) => match len.parse::<usize>() {
+			Ok(len) => Some(len),
+			Err(_) => return Err(Error::Http(format!("invalid content-length '{}'", len)))
+		},
+		None => None
+	};
+
+	if head || status == 204 || status == 304 || (status >= 100 && status < 200) {
+		// No body
+	}
+	else if chunked {
+		res.body = try!(read_chunked(r));
+	}
+	else if let Some(len) = len {
+		let mut body = vec![0; len];
+		try!(r.read_exact(&mut body));
+		res.body = body;
+	}
+	else {
+		try!(r.read_to_end(&mut res.body));
+		keep_alive = false;
+	}
+
+	Ok((res, keep_alive))
+}
+
+fn read_chunked<R: BufRead>(r: &mut R) -> Result<Vec<u8>, Error> {
+	let mut body = Vec::new();
+
+	loop {
+		let line = try!(read_line(r));
+		let size = line.split(';').next().unwrap_or("").trim();
+		let size = match usize::from_str_radix(size, 16) {
+			Ok(size) => size,
+			Err(_) => return Err(Error::Http(format!("invalid chunk size '{}'", line)))
+		};
+
+		if size == 0 {
+			break;
+		}
+
+		let start = body.len();
+		body.resize(start + size, 0);
+		try!(r.read_exact(&mut body[start..]));
+
+		let crlf = try!(read_line(r));
+		if crlf.len() > 0 {
+			return Err(Error::Http("chunk is longer than its size".to_owned()));
+		}
+	}
+
+	// Skip any trailers
+	while try!(read_line(r)).len() > 0 {}
+
+	Ok(body)
+}
+
+fn read_line<R: BufRead>(r: &mut R) -> Result<String, Error> {
+	let mut line = String::new();
+	if try!(r.read_line(&mut line)) == 0 {
+		return Err(Error::Closed);
+	}
+
+	while line.ends_with('\n') || line.ends_with('\r') {
+		line.pop();
+	}
+
+	Ok(line)
+}
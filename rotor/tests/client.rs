@@ -0,0 +1,409 @@
+extern crate hyper;
+extern crate serde_json;
+extern crate elastic_hyper;
+extern crate elastic_rotor;
+
+use std::io::{ BufRead, BufReader, Read, Write };
+use std::net::{ TcpListener, TcpStream };
+use std::sync::Arc;
+use std::sync::atomic::{ AtomicUsize, Ordering };
+use std::sync::mpsc::{ channel, Receiver, Sender };
+use std::thread;
+use std::time::Duration;
+use hyper::method::Method;
+use serde_json::Value;
+use elastic_hyper::Transport;
+use elastic_rotor::{ Client, Error, Request };
+
+/// A loopback stand-in for a node.
+///
+/// Each response echoes the request's method, path, content type and body as json.
+struct StandIn {
+	addr: String,
+	accepted: Arc<AtomicUsize>,
+	hung_up: Receiver<()>
+}
+
+/// When a stand-in closes a connection.
+#[derive(Clone, Copy)]
+enum Close {
+	/// Keep connections open.
+	Never,
+	/// Send `Connection: close` with the nth response and close the connection.
+	After(usize),
+	/// Close the connection after the nth response without saying so, like an idle timeout.
+	HangUpAfter(usize),
+	/// Close the connection after reading the nth request, without responding to it.
+	HangUpOnRequest(usize),
+	/// Keep connections open without responding to any requests, like a node that's stuck.
+	NeverRespond
+}
+
+impl StandIn {
+	fn start(close: Close) -> StandIn {
+		let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+		let addr = format!("http://{}", listener.local_addr().unwrap());
+		let accepted = Arc::new(AtomicUsize::new(0));
+		let (hang_up, hung_up) = channel();
+
+		let counter = accepted.clone();
+		thread::spawn(move || {
+			for stream in listener.incoming() {
+				let stream = match stream {
+					Ok(stream) => stream,
+					Err(_) => break
+				};
+
+				counter.fetch_add(1, Ordering::SeqCst);
+
+				let hang_up = hang_up.clone();
+				thread::spawn(move || serve(stream, close, hang_up));
+			}
+		});
+
+		StandIn {
+			addr: addr,
+			accepted: accepted,
+			hung_up: hung_up
+		}
+	}
+
+	fn accepted(&self) -> usize {
+		self.accepted.load(Ordering::SeqCst)
+	}
+
+	/// Block until the stand-in has closed a connection.
+	fn wait_for_hang_up(&self) {
+		self.hung_up.recv().unwrap();
+	}
+}
+
+fn serve(stream: TcpStream, close: Close, hung_up: Sender<()>) {
+	let mut writer = stream.try_clone().unwrap();
+	let mut reader = BufReader::new(stream);
+	let mut served = 0;
+
+	loop {
+		let mut line = String::new();
+		if reader.read_line(&mut line).unwrap_or(0) == 0 {
+			return;
+		}
+
+		let (method, path) = {
+			let mut parts = line.trim().split(' ');
+			(parts.next().unwrap().to_owned(), parts.next().unwrap().to_owned())
+		};
+
+		let mut len = 0;
+		let mut content_type = String::new();
+		loop {
+			let mut header = String::new();
+			reader.read_line(&mut header).unwrap();
+			let header = header.trim().to_owned();
+
+			if header.len() == 0 {
+				break;
+			}
+
+			let mut parts = header.splitn(2, ':');
+			let key = parts.next().unwrap().trim().to_lowercase();
+			let value = parts.next().unwrap_or("").trim().to_owned();
+
+			if key == "content-length" {
+				len = value.parse().unwrap();
+			}
+			else if key == "content-type" {
+				content_type = value;
+			}
+		}
+
+		let mut body = vec![0; len];
+		reader.read_exact(&mut body).unwrap();
+
+		served += 1;
+		let (close, announce) = match close {
+			Close::Never => (false, false),
+			Close::After(n) => (served == n, true),
+			Close::HangUpAfter(n) => (served == n, false),
+			Close::HangUpOnRequest(n) if served == n => {
+				drop(writer);
+				drop(reader);
+
+				let _ = hung_up.send(());
+				return;
+			},
+			Close::HangUpOnRequest(_) => (false, false),
+			Close::NeverRespond => continue
+		};
+
+		let res_body = format!(
+			r#"{{"method":"{}","path":"{}","content_type":"{}","body":"{}"}}"#,
+			method,
+			path,
+			content_type,
+			String::from_utf8(body).unwrap().replace('"', "\\\"")
+		);
+
+		let mut res = format!("HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n", res_body.len());
+		if close && announce {
+			res.push_str("Connection: close\r\n");
+		}
+		res.push_str("\r\n");
+
+		if method != "HEAD" {
+			res.push_str(&res_body);
+		}
+
+		writer.write_all(res.as_bytes()).unwrap();
+
+		if close {
+			drop(writer);
+			drop(reader);
+
+			let _ = hung_up.send(());
+			return;
+		}
+	}
+}
+
+fn get_str<'a>(value: &'a Value, key: &str) -> &'a str {
+	value.find(key).and_then(|v| v.as_string()).unwrap()
+}
+
+#[test]
+fn send_request_and_deserialise_response() {
+	let node = StandIn::start(Close::Never);
+	let client = Client::new(vec![&node.addr]).unwrap();
+
+	let params = elastic_hyper::search::Params {
+		q: Some("doc".to_owned()),
+		..Default::default()
+	};
+
+	let req = Request::new(Method::Post, "/myindex/mytype/_search")
+		.params(&elastic_hyper::RequestParams::default(), &params)
+		.body(r#"{"query":{}}"#);
+
+	let res: Value = client.send(req).wait_json().unwrap();
+
+	assert_eq!("POST", get_str(&res, "method"));
+	assert_eq!("/myindex/mytype/_search?q=doc", get_str(&res, "path"));
+	assert_eq!("application/json", get_str(&res, "content_type"));
+	assert_eq!(r#"{"query":{}}"#, get_str(&res, "body"));
+}
+
+#[test]
+fn send_request_value_through_transport() {
+	let node = StandIn::start(Close::Never);
+	let mut client = Client::new(vec![&node.addr]).unwrap();
+
+	let req = elastic_hyper::search::post_index_request(
+		&elastic_hyper::RequestParams::default(), &elastic_hyper::search::Params::default(),
+		"myindex",
+		r#"{"query":{}}"#
+	);
+
+	let res: Value = Transport::send(&mut client, req).unwrap().wait_json().unwrap();
+
+	assert_eq!("POST", get_str(&res, "method"));
+	assert_eq!("/myindex/_search", get_str(&res, "path"));
+	assert_eq!(r#"{"query":{}}"#, get_str(&res, "body"));
+}
+
+#[test]
+fn pipeline_requests_on_one_connection() {
+	let node = StandIn::start(Close::Never);
+	let client = Client::new(vec![&node.addr]).unwrap();
+
+	let futures: Vec<_> = (0..20)
+		.map(|i| client.send(Request::new(Method::Get, format!("/index{}/_search", i))))
+		.collect();
+
+	for (i, res) in futures.into_iter().enumerate() {
+		let res: Value = res.wait_json().unwrap();
+
+		assert_eq!(format!("/index{}/_search", i), get_str(&res, "path"));
+	}
+
+	assert_eq!(1, node.accepted());
+}
+
+#[test]
+fn keep_connection_alive_between_requests() {
+	let node = StandIn::start(Close::Never);
+	let client = Client::new(vec![&node.addr]).unwrap();
+
+	for _ in 0..3 {
+		let res = client.send(Request::new(Method::Get, "/")).wait().unwrap();
+
+		assert!(res.is_success());
+	}
+
+	assert_eq!(1, node.accepted());
+}
+
+#[test]
+fn head_request_has_no_body() {
+	let node = StandIn::start(Close::Never);
+	let client = Client::new(vec![&node.addr]).unwrap();
+
+	let head = client.send(Request::new(Method::Head, "/"));
+	let get = client.send(Request::new(Method::Get, "/"));
+
+	assert_eq!(0, head.wait().unwrap().body.len());
+	assert!(get.wait().unwrap().body.len() > 0);
+}
+
+#[test]
+fn reconnect_after_connection_is_closed() {
+	let node = StandIn::start(Close::After(1));
+	let client = Client::new(vec![&node.addr]).unwrap();
+
+	let first = client.send(Request::new(Method::Get, "/first")).wait().unwrap();
+	assert_eq!(Some("close"), first.header("Connection"));
+
+	let second: Value = client.send(Request::new(Method::Get, "/second")).wait_json().unwrap();
+	assert_eq!("/second", get_str(&second, "path"));
+
+	assert_eq!(2, node.accepted());
+}
+
+#[test]
+fn reconnect_after_idle_connection_is_closed() {
+	let node = StandIn::start(Close::HangUpAfter(1));
+	let client = Client::new(vec![&node.addr]).unwrap();
+
+	assert!(client.send(Request::new(Method::Get, "/first")).wait().unwrap().is_success());
+
+	// Wait for the node to hang up while the connection is idle
+	node.wait_for_hang_up();
+
+	let second: Value = client.send(Request::new(Method::Get, "/second")).wait_json().unwrap();
+	assert_eq!("/second", get_str(&second, "path"));
+
+	assert_eq!(2, node.accepted());
+}
+
+#[test]
+fn retry_request_written_to_connection_that_was_hung_up() {
+	let node = StandIn::start(Close::HangUpAfter(1));
+	let client = Client::new(vec![&node.addr]).unwrap();
+
+	// The second request may be written before the hang up is noticed, so it's retried on a new connection
+	for path in &["/first", "/second", "/third"] {
+		let res: Value = client.send(Request::new(Method::Get, *path)).wait_json().unwrap();
+		assert_eq!(*path, get_str(&res, "path"));
+	}
+}
+
+#[test]
+fn retry_requests_pipelined_behind_connection_close() {
+	let node = StandIn::start(Close::After(1));
+	let client = Client::new(vec![&node.addr]).unwrap();
+
+	let futures: Vec<_> = (0..3)
+		.map(|i| client.send(Request::new(Method::Get, format!("/index{}/_search", i))))
+		.collect();
+
+	for (i, res) in futures.into_iter().enumerate() {
+		let res: Value = res.wait_json().unwrap();
+
+		assert_eq!(format!("/index{}/_search", i), get_str(&res, "path"));
+	}
+}
+
+#[test]
+fn retry_idempotent_request_once_when_connection_is_hung_up() {
+	let node = StandIn::start(Close::HangUpOnRequest(1));
+	let client = Client::new(vec![&node.addr]).unwrap();
+
+	match client.send(Request::new(Method::Get, "/")).wait() {
+		Err(Error::Closed) => (),
+		r => panic!("expected the connection to be closed, got {:?}", r)
+	}
+
+	assert_eq!(2, node.accepted());
+}
+
+#[test]
+fn do_not_retry_post_when_connection_is_hung_up() {
+	let node = StandIn::start(Close::HangUpOnRequest(1));
+	let client = Client::new(vec![&node.addr]).unwrap();
+
+	let req = Request::new(Method::Post, "/myindex/mytype").body(r#"{"title":"doc"}"#);
+
+	// The node may have indexed the document before hanging up, so it mustn't be sent again
+	match client.send(req).wait() {
+		Err(Error::Closed) => (),
+		r => panic!("expected the connection to be closed, got {:?}", r)
+	}
+
+	assert_eq!(1, node.accepted());
+}
+
+#[test]
+fn requests_time_out_when_node_does_not_respond() {
+	let node = StandIn::start(Close::NeverRespond);
+	let client = Client::new(vec![&node.addr]).unwrap()
+		.read_timeout(Duration::from_millis(100));
+
+	let futures: Vec<_> = (0..3)
+		.map(|i| client.send(Request::new(Method::Get, format!("/index{}/_search", i))))
+		.collect();
+
+	for res in futures {
+		match res.wait() {
+			Err(Error::Timeout) => (),
+			r => panic!("expected a timeout, got {:?}", r)
+		}
+	}
+}
+
+#[test]
+fn idle_connection_is_kept_open_past_read_timeout() {
+	let node = StandIn::start(Close::Never);
+	let client = Client::new(vec![&node.addr]).unwrap()
+		.read_timeout(Duration::from_millis(20));
+
+	assert!(client.send(Request::new(Method::Get, "/first")).wait().unwrap().is_success());
+
+	thread::sleep(Duration::from_millis(100));
+
+	assert!(client.send(Request::new(Method::Get, "/second")).wait().unwrap().is_success());
+	assert_eq!(1, node.accepted());
+}
+
+#[test]
+fn wait_without_blocking() {
+	let node = StandIn::start(Close::Never);
+	let client = Client::new(vec![&node.addr]).unwrap();
+
+	let mut res = client.send(Request::new(Method::Get, "/"));
+	while !res.is_ready() {
+		thread::yield_now();
+	}
+
+	assert!(res.wait().unwrap().is_success());
+}
+
+#[test]
+fn unreachable_node_fails_request() {
+	let addr = {
+		let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+		format!("http://{}", listener.local_addr().unwrap())
+	};
+
+	let client = Client::new(vec![addr]).unwrap();
+
+	match client.send(Request::new(Method::Get, "/")).wait() {
+		Err(Error::Io(_)) => (),
+		r => panic!("expected an io error, got {:?}", r)
+	}
+}
+
+#[test]
+fn new_client_without_nodes_fails() {
+	let nodes: Vec<&str> = Vec::new();
+
+	assert!(Client::new(nodes).is_err());
+}
@@ -64,14 +64,12 @@ pub mod string_fixtures {
 	#[derive(Default, Clone, ElasticStringMapping)]
 	pub struct MyStringMapping;
 	impl ElasticStringMapping for MyStringMapping {
+		type Analysis = NotIndexed;
+
 		fn boost() -> Option<f32> {
 			Some(1.01)
 		}
 
-		fn index() -> Option<IndexAnalysis> {
-			Some(IndexAnalysis::No)
-		}
-
 		fn doc_values() -> Option<bool> {
 			Some(true)
 		}
@@ -0,0 +1,166 @@
+use std::marker::PhantomData;
+use rustc_serialize::base64::{ ToBase64, FromBase64, STANDARD };
+use serde;
+use serde::{ Serialize, Deserialize, Serializer, Deserializer };
+use super::mapping::{ ElasticBinaryMapping, DefaultBinaryMapping };
+use ::mapping::{ ElasticFieldMapping, ElasticType };
+
+/// An Elasticsearch `binary` with a mapping.
+///
+/// The bytes are serialised as a base64 encoded string.
+///
+/// # Examples
+///
+/// Defining a binary value with a mapping:
+///
+/// ```
+/// use elastic_types::binary::mapping::DefaultBinaryMapping;
+/// use elastic_types::binary::ElasticBinary;
+///
+/// let binary = ElasticBinary::<DefaultBinaryMapping>::new(vec![ 1, 2, 3 ]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ElasticBinary<T> where
+T: ElasticFieldMapping<()> + ElasticBinaryMapping {
+	value: Vec<u8>,
+	phantom: PhantomData<T>
+}
+impl <T> ElasticBinary<T> where
+T: ElasticFieldMapping<()> + ElasticBinaryMapping {
+	/// Creates a new `ElasticBinary` with the given mapping.
+	///
+	/// # Examples
+	///
+	/// Create a new `ElasticBinary` from a `Vec<u8>`:
+	///
+	/// ```
+	/// use elastic_types::binary::mapping::DefaultBinaryMapping;
+	/// use elastic_types::binary::ElasticBinary;
+	///
+	/// let binary = ElasticBinary::<DefaultBinaryMapping>::new(b"some bytes".to_vec());
+	/// ```
+	pub fn new<I>(bytes: I) -> ElasticBinary<T> where I: Into<Vec<u8>> {
+		ElasticBinary {
+			value: bytes.into(),
+			phantom: PhantomData
+		}
+	}
+
+	/// Parse an `ElasticBinary` from a base64 encoded string.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use elastic_types::binary::mapping::DefaultBinaryMapping;
+	/// use elastic_types::binary::ElasticBinary;
+	///
+	/// let binary = ElasticBinary::<DefaultBinaryMapping>::parse("AQID").unwrap();
+	///
+	/// assert_eq!(&[ 1, 2, 3 ], binary.get());
+	/// ```
+	pub fn parse(base64: &str) -> Result<ElasticBinary<T>, String> {
+		base64.from_base64()
+			.map(ElasticBinary::new)
+			.map_err(|e| format!("{}", e))
+	}
+
+	/// Get the value of the binary.
+	pub fn get(&self) -> &[u8] {
+		&self.value
+	}
+
+	/// Set the value of the binary.
+	pub fn set<I>(&mut self, bytes: I) where I: Into<Vec<u8>> {
+		self.value = bytes.into()
+	}
+
+	/// Get the value of the binary as a base64 encoded string.
+	pub fn to_base64(&self) -> String {
+		self.value.to_base64(STANDARD)
+	}
+
+	/// Change the mapping of this binary.
+	pub fn into<TInto>(self) -> ElasticBinary<TInto> where
+	TInto: ElasticFieldMapping<()> + ElasticBinaryMapping {
+		ElasticBinary::<TInto>::new(self.value)
+	}
+}
+
+impl <T> ElasticType<T, ()> for ElasticBinary<T> where
+T: ElasticFieldMapping<()> + ElasticBinaryMapping { }
+
+impl From<Vec<u8>> for ElasticBinary<DefaultBinaryMapping> {
+	fn from(bytes: Vec<u8>) -> Self {
+		ElasticBinary::new(bytes)
+	}
+}
+
+impl <T> AsRef<[u8]> for ElasticBinary<T> where
+T: ElasticFieldMapping<()> + ElasticBinaryMapping {
+	fn as_ref(&self) -> &[u8] {
+		&self.value
+	}
+}
+
+impl <T> Into<Vec<u8>> for ElasticBinary<T> where
+T: ElasticFieldMapping<()> + ElasticBinaryMapping {
+	fn into(self) -> Vec<u8> {
+		self.value
+	}
+}
+
+impl<'a, T> PartialEq<Vec<u8>> for ElasticBinary<T> where
+T: ElasticFieldMapping<()> + ElasticBinaryMapping {
+	fn eq(&self, other: &Vec<u8>) -> bool {
+		PartialEq::eq(&self.value, other)
+	}
+
+	fn ne(&self, other: &Vec<u8>) -> bool {
+		PartialEq::ne(&self.value, other)
+	}
+}
+
+impl<'a, T> PartialEq<ElasticBinary<T>> for Vec<u8> where
+T: ElasticFieldMapping<()> + ElasticBinaryMapping {
+	fn eq(&self, other: &ElasticBinary<T>) -> bool {
+		PartialEq::eq(self, &other.value)
+	}
+
+	fn ne(&self, other: &ElasticBinary<T>) -> bool {
+		PartialEq::ne(self, &other.value)
+	}
+}
+
+//Serialize elastic binary
+impl <T> Serialize for ElasticBinary<T> where
+T: ElasticFieldMapping<()> + ElasticBinaryMapping {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_str(&self.to_base64())
+	}
+}
+
+//Deserialize elastic binary
+impl <T> Deserialize for ElasticBinary<T> where
+T: ElasticFieldMapping<()> + ElasticBinaryMapping {
+	fn deserialize<D>(deserializer: &mut D) -> Result<ElasticBinary<T>, D::Error> where
+	D: Deserializer {
+		#[derive(Default)]
+		struct ElasticBinaryVisitor<T> where
+		T: ElasticFieldMapping<()> + ElasticBinaryMapping {
+			phantom: PhantomData<T>
+		}
+
+		impl <T> serde::de::Visitor for ElasticBinaryVisitor<T> where
+		T: ElasticFieldMapping<()> + ElasticBinaryMapping {
+			type Value = ElasticBinary<T>;
+
+			fn visit_str<E>(&mut self, v: &str) -> Result<ElasticBinary<T>, E> where
+			E: serde::de::Error {
+				ElasticBinary::<T>::parse(v).map_err(|e| E::custom(e))
+			}
+		}
+
+		deserializer.deserialize(ElasticBinaryVisitor::<T>::default())
+	}
+}
@@ -0,0 +1,160 @@
+//! Mapping for the Elasticsearch `binary` type.
+
+use std::marker::PhantomData;
+use serde;
+use serde::Serialize;
+use ::mapping::{ ElasticFieldMapping, ElasticTypeVisitor };
+
+/// Elasticsearch datatype name.
+pub const BINARY_DATATYPE: &'static str = "binary";
+
+/// The base requirements for mapping a `binary` type.
+///
+/// Custom mappings can be defined by implementing `ElasticBinaryMapping`.
+///
+/// # Examples
+///
+/// Define a custom `ElasticBinaryMapping`:
+///
+/// ## Derive Mapping
+///
+/// ```
+/// # #![feature(plugin, custom_derive, custom_attribute)]
+/// # #![plugin(json_str, elastic_types_macros)]
+/// # #[macro_use]
+/// # extern crate elastic_types;
+/// # extern crate serde;
+/// use elastic_types::mapping::prelude::*;
+/// use elastic_types::binary::prelude::*;
+///
+/// #[derive(Debug, Clone, Default, ElasticBinaryMapping)]
+/// pub struct MyBinaryMapping;
+/// impl ElasticBinaryMapping for MyBinaryMapping {
+/// 	//Overload the mapping functions here
+/// 	fn store() -> Option<bool> {
+///			Some(true)
+///		}
+/// }
+/// # fn main() {}
+/// ```
+///
+/// This will produce the following mapping:
+///
+/// ```
+/// # #![feature(plugin, custom_derive, custom_attribute)]
+/// # #![plugin(json_str, elastic_types_macros)]
+/// # #[macro_use]
+/// # extern crate elastic_types;
+/// # extern crate serde;
+/// # extern crate serde_json;
+/// # use elastic_types::mapping::prelude::*;
+/// # use elastic_types::binary::prelude::*;
+/// # #[derive(Debug, Clone, Default, ElasticBinaryMapping)]
+/// # pub struct MyBinaryMapping;
+/// # impl ElasticBinaryMapping for MyBinaryMapping {
+/// # 	//Overload the mapping functions here
+/// # 	fn store() -> Option<bool> {
+///	# 		Some(true)
+///	# 	}
+/// # }
+/// # fn main() {
+/// # let mapping = serde_json::to_string(&MyBinaryMapping).unwrap();
+/// # let json = json_str!(
+/// {
+///     "type": "binary",
+/// 	"store": true
+/// }
+/// # );
+/// # assert_eq!(json, mapping);
+/// # }
+/// ```
+///
+/// ## Manually
+///
+/// ```
+/// # extern crate serde;
+/// # extern crate elastic_types;
+/// # fn main() {
+/// use elastic_types::mapping::prelude::*;
+/// use elastic_types::binary::prelude::*;
+///
+/// #[derive(Debug, Clone, Default)]
+/// pub struct MyBinaryMapping;
+/// impl ElasticBinaryMapping for MyBinaryMapping {
+/// 	//Overload the mapping functions here
+/// 	fn store() -> Option<bool> {
+///			Some(true)
+///		}
+/// }
+///
+/// //We also need to implement the base `ElasticFieldMapping` and `serde::Serialize` for our custom mapping type
+/// impl ElasticFieldMapping<()> for MyBinaryMapping {
+/// 	type Visitor = ElasticBinaryMappingVisitor<MyBinaryMapping>;
+///
+/// 	fn data_type() -> &'static str {
+/// 		BINARY_DATATYPE
+/// 	}
+/// }
+///
+/// impl serde::Serialize for MyBinaryMapping {
+/// 	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+/// 	where S: serde::Serializer {
+/// 		serializer.serialize_struct("mapping", Self::get_visitor())
+/// 	}
+/// }
+/// # }
+/// ```
+pub trait ElasticBinaryMapping where
+Self: ElasticFieldMapping<()> + Sized + Serialize {
+	/// Should the field be stored on disk in a column-stride fashion,
+	/// so that it can later be used for sorting, aggregations, or scripting?
+	/// Accepts `true` or `false` (default).
+	fn doc_values() -> Option<bool> {
+		None
+	}
+
+	/// Whether the field value should be stored and retrievable separately from the `_source` field.
+	/// Accepts `true` or `false` (default).
+	fn store() -> Option<bool> {
+		None
+	}
+}
+
+/// Default mapping for `binary`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DefaultBinaryMapping;
+impl ElasticBinaryMapping for DefaultBinaryMapping { }
+
+impl_binary_mapping!(DefaultBinaryMapping);
+
+/// Base visitor for serialising binary mappings.
+#[derive(Debug, PartialEq, Default)]
+pub struct ElasticBinaryMappingVisitor<T> where T: ElasticBinaryMapping {
+	phantom: PhantomData<T>
+}
+
+impl <T> ElasticTypeVisitor for ElasticBinaryMappingVisitor<T> where
+T: ElasticBinaryMapping {
+	fn new() -> Self {
+		ElasticBinaryMappingVisitor {
+			phantom: PhantomData
+		}
+	}
+}
+impl <T> serde::ser::MapVisitor for ElasticBinaryMappingVisitor<T> where
+T: ElasticBinaryMapping {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error>
+	where S: serde::Serializer {
+		try!(serializer.serialize_struct_elt("type", T::data_type()));
+
+		if let Some(doc_values) = T::doc_values() {
+			try!(serializer.serialize_struct_elt("doc_values", doc_values));
+		}
+
+		if let Some(store) = T::store() {
+			try!(serializer.serialize_struct_elt("store", store));
+		}
+
+		Ok(None)
+	}
+}
@@ -0,0 +1,53 @@
+//! Implementation of the Elasticsearch `binary` type.
+//!
+//! Binary values are sent to Elasticsearch as base64 encoded strings.
+//! They aren't searchable, and are only stored so they can be retrieved.
+//!
+//! # Examples
+//!
+//! For defining your own binary mapping, see [mapping details](mapping/trait.ElasticBinaryMapping.html#derive-mapping).
+//!
+//! Map with a default `binary`:
+//!
+//! ```
+//! # use elastic_types::binary::prelude::*;
+//! # use elastic_types::binary::mapping::DefaultBinaryMapping;
+//! struct MyType {
+//! 	pub field: ElasticBinary<DefaultBinaryMapping>
+//! }
+//! ```
+//!
+//! Map with a custom `binary`:
+//!
+//! ```
+//! # #![feature(plugin, custom_derive)]
+//! # #![plugin(json_str, elastic_types_macros)]
+//! # extern crate serde;
+//! # extern crate elastic_types;
+//! # fn main() {
+//! # use elastic_types::mapping::prelude::*;
+//! # use elastic_types::binary::prelude::*;
+//! # #[derive(Debug, Clone, Default, ElasticBinaryMapping)]
+//! # pub struct MyBinaryMapping;
+//! # impl ElasticBinaryMapping for MyBinaryMapping { }
+//! struct MyType {
+//! 	pub field: ElasticBinary<MyBinaryMapping>
+//! }
+//! # }
+//! ```
+//!
+//! # Links
+//! - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/binary.html)
+
+mod binary;
+
+pub mod mapping;
+pub use self::binary::*;
+
+pub mod prelude {
+	//! Includes non-mapping types for the `binary` type.
+	//!
+	//! This is a convenience module to make it easy to build mappings for multiple types without too many `use` statements.
+
+	pub use super::binary::*;
+}
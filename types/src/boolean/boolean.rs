@@ -2,10 +2,14 @@ use std::marker::PhantomData;
 use serde;
 use serde::{ Serialize, Deserialize, Serializer, Deserializer };
 use super::mapping::{ ElasticBooleanMapping, DefaultBooleanMapping };
-use ::mapping::{ ElasticFieldMapping, ElasticType };
+use ::mapping::{ ElasticFieldMapping, ElasticType, ElasticFieldAnalysis, NotAnalyzed };
 
 impl ElasticType<DefaultBooleanMapping, ()> for bool { }
 
+impl ElasticFieldAnalysis for bool {
+	type Analysis = NotAnalyzed;
+}
+
 /// An Elasticsearch `boolean` with a mapping.
 ///
 /// Where the mapping isn't custom, you can use the standard library `bool` instead.
@@ -102,6 +106,11 @@ T: ElasticFieldMapping<()> + ElasticBooleanMapping {
 impl <T> ElasticType<T, ()> for ElasticBoolean<T> where
 T: ElasticFieldMapping<()> + ElasticBooleanMapping { }
 
+impl <T> ElasticFieldAnalysis for ElasticBoolean<T> where
+T: ElasticFieldMapping<()> + ElasticBooleanMapping {
+	type Analysis = NotAnalyzed;
+}
+
 impl From<bool> for ElasticBoolean<DefaultBooleanMapping> {
 	fn from(boolean: bool) -> Self {
 		ElasticBoolean::new(boolean)
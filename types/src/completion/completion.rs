@@ -0,0 +1,202 @@
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
+use serde;
+use serde::{ Serialize, Deserialize, Serializer, Deserializer };
+use serde_json::Value;
+use super::mapping::{ ElasticCompletionMapping, DefaultCompletionMapping };
+use ::mapping::{ ElasticFieldMapping, ElasticType };
+
+/// An Elasticsearch `completion` with a mapping.
+///
+/// The `output`, `payload`, `weight` and `context` are optional, and are only serialised when they're set.
+/// A `payload` is only returned by suggestions if the mapping enables `payloads`,
+/// and a `context` must match the context mappings.
+///
+/// # Examples
+///
+/// Defining a completion with a mapping:
+///
+/// ```
+/// use elastic_types::completion::mapping::DefaultCompletionMapping;
+/// use elastic_types::completion::ElasticCompletion;
+///
+/// let mut completion = ElasticCompletion::<DefaultCompletionMapping>::new(vec![ "Nevermind", "Nirvana" ]);
+/// completion.output = Some(String::from("Nirvana - Nevermind"));
+/// completion.weight = Some(34);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ElasticCompletion<T> where
+T: ElasticFieldMapping<()> + ElasticCompletionMapping {
+	/// The inputs to match against.
+	pub input: Vec<String>,
+	/// The string to return for suggestions, instead of the matched input.
+	pub output: Option<String>,
+	/// Arbitrary data to return with suggestions.
+	pub payload: Option<Value>,
+	/// A positive weight used to rank suggestions.
+	pub weight: Option<u32>,
+	/// The values of the contexts defined in the mapping, keyed by context name.
+	pub context: BTreeMap<String, Value>,
+	phantom: PhantomData<T>
+}
+impl <T> ElasticCompletion<T> where
+T: ElasticFieldMapping<()> + ElasticCompletionMapping {
+	/// Creates a new `ElasticCompletion` with the given mapping.
+	///
+	/// # Examples
+	///
+	/// Create a new `ElasticCompletion` from a single input:
+	///
+	/// ```
+	/// use elastic_types::completion::mapping::DefaultCompletionMapping;
+	/// use elastic_types::completion::ElasticCompletion;
+	///
+	/// let completion = ElasticCompletion::<DefaultCompletionMapping>::new(vec![ "Nevermind" ]);
+	/// ```
+	pub fn new<I, S>(input: I) -> ElasticCompletion<T> where
+	I: IntoIterator<Item = S>,
+	S: Into<String> {
+		ElasticCompletion {
+			input: input.into_iter().map(|i| i.into()).collect(),
+			output: None,
+			payload: None,
+			weight: None,
+			context: BTreeMap::new(),
+			phantom: PhantomData
+		}
+	}
+
+	/// Change the mapping of this completion.
+	pub fn into<TInto>(self) -> ElasticCompletion<TInto> where
+	TInto: ElasticFieldMapping<()> + ElasticCompletionMapping {
+		ElasticCompletion {
+			input: self.input,
+			output: self.output,
+			payload: self.payload,
+			weight: self.weight,
+			context: self.context,
+			phantom: PhantomData
+		}
+	}
+
+	fn from_value(value: Value) -> Result<ElasticCompletion<T>, String> {
+		let mut map = match value {
+			//A completion can be given as just its inputs
+			Value::String(input) => return Ok(ElasticCompletion::new(vec![ input ])),
+			Value::Array(inputs) => return Ok(ElasticCompletion::new(try!(take_inputs(inputs)))),
+			Value::Object(map) => map,
+			_ => return Err("expected a completion to be a string, array or object".to_owned())
+		};
+
+		let input = match map.remove("input") {
+			Some(Value::String(input)) => vec![ input ],
+			Some(Value::Array(inputs)) => try!(take_inputs(inputs)),
+			_ => return Err("expected a completion to have an 'input'".to_owned())
+		};
+
+		let output = match map.remove("output") {
+			Some(Value::String(output)) => Some(output),
+			Some(Value::Null) | None => None,
+			Some(_) => return Err("expected 'output' to be a string".to_owned())
+		};
+
+		let weight = match map.remove("weight") {
+			Some(Value::U64(weight)) => Some(weight as u32),
+			Some(Value::String(ref weight)) => Some(try!(weight.parse().map_err(|_| format!("expected 'weight' to be a number, but got '{}'", weight)))),
+			Some(Value::Null) | None => None,
+			Some(_) => return Err("expected 'weight' to be a positive number".to_owned())
+		};
+
+		let context = match map.remove("context") {
+			Some(Value::Object(context)) => context,
+			Some(Value::Null) | None => BTreeMap::new(),
+			Some(_) => return Err("expected 'context' to be an object".to_owned())
+		};
+
+		Ok(ElasticCompletion {
+			input: input,
+			output: output,
+			payload: map.remove("payload"),
+			weight: weight,
+			context: context,
+			phantom: PhantomData
+		})
+	}
+}
+
+fn take_inputs(inputs: Vec<Value>) -> Result<Vec<String>, String> {
+	inputs.into_iter()
+		.map(|input| match input {
+			Value::String(input) => Ok(input),
+			_ => Err("expected each 'input' to be a string".to_owned())
+		})
+		.collect()
+}
+
+impl <T> ElasticType<T, ()> for ElasticCompletion<T> where
+T: ElasticFieldMapping<()> + ElasticCompletionMapping { }
+
+impl From<String> for ElasticCompletion<DefaultCompletionMapping> {
+	fn from(input: String) -> Self {
+		ElasticCompletion::new(vec![ input ])
+	}
+}
+
+impl From<Vec<String>> for ElasticCompletion<DefaultCompletionMapping> {
+	fn from(input: Vec<String>) -> Self {
+		ElasticCompletion::new(input)
+	}
+}
+
+//Serialize elastic completion
+impl <T> Serialize for ElasticCompletion<T> where
+T: ElasticFieldMapping<()> + ElasticCompletionMapping {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_struct("completion", ElasticCompletionVisitor {
+			completion: self
+		})
+	}
+}
+
+struct ElasticCompletionVisitor<'a, T: 'a> where
+T: ElasticFieldMapping<()> + ElasticCompletionMapping {
+	completion: &'a ElasticCompletion<T>
+}
+
+impl <'a, T> serde::ser::MapVisitor for ElasticCompletionVisitor<'a, T> where
+T: ElasticFieldMapping<()> + ElasticCompletionMapping {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> where
+	S: Serializer {
+		try!(serializer.serialize_struct_elt("input", &self.completion.input));
+
+		if let Some(ref output) = self.completion.output {
+			try!(serializer.serialize_struct_elt("output", output));
+		}
+
+		if let Some(ref payload) = self.completion.payload {
+			try!(serializer.serialize_struct_elt("payload", payload));
+		}
+
+		if let Some(weight) = self.completion.weight {
+			try!(serializer.serialize_struct_elt("weight", weight));
+		}
+
+		if !self.completion.context.is_empty() {
+			try!(serializer.serialize_struct_elt("context", &self.completion.context));
+		}
+
+		Ok(None)
+	}
+}
+
+//Deserialize elastic completion
+impl <T> Deserialize for ElasticCompletion<T> where
+T: ElasticFieldMapping<()> + ElasticCompletionMapping {
+	fn deserialize<D>(deserializer: &mut D) -> Result<ElasticCompletion<T>, D::Error> where
+	D: Deserializer {
+		let value: Value = try!(Deserialize::deserialize(deserializer));
+
+		ElasticCompletion::<T>::from_value(value).map_err(|e| serde::de::Error::custom(e))
+	}
+}
@@ -0,0 +1,301 @@
+//! Mapping for the Elasticsearch `completion` type.
+
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
+use serde;
+use serde::{ Serializer, Serialize };
+use ::mapping::{ ElasticFieldMapping, ElasticTypeVisitor };
+
+/// Elasticsearch datatype name.
+pub const COMPLETION_DATATYPE: &'static str = "completion";
+
+/// The base requirements for mapping a `completion` type.
+///
+/// Custom mappings can be defined by implementing `ElasticCompletionMapping`.
+///
+/// # Examples
+///
+/// Define a custom `ElasticCompletionMapping`:
+///
+/// ## Derive Mapping
+///
+/// ```
+/// # #![feature(plugin, custom_derive, custom_attribute)]
+/// # #![plugin(json_str, elastic_types_macros)]
+/// # #[macro_use]
+/// # extern crate elastic_types;
+/// # extern crate serde;
+/// use elastic_types::mapping::prelude::*;
+/// use elastic_types::completion::prelude::*;
+///
+/// #[derive(Debug, Clone, Default, ElasticCompletionMapping)]
+/// pub struct MyCompletionMapping;
+/// impl ElasticCompletionMapping for MyCompletionMapping {
+/// 	//Overload the mapping functions here
+/// 	fn payloads() -> Option<bool> {
+///			Some(true)
+///		}
+/// }
+/// # fn main() {}
+/// ```
+///
+/// This will produce the following mapping:
+///
+/// ```
+/// # #![feature(plugin, custom_derive, custom_attribute)]
+/// # #![plugin(json_str, elastic_types_macros)]
+/// # #[macro_use]
+/// # extern crate elastic_types;
+/// # extern crate serde;
+/// # extern crate serde_json;
+/// # use elastic_types::mapping::prelude::*;
+/// # use elastic_types::completion::prelude::*;
+/// # #[derive(Debug, Clone, Default, ElasticCompletionMapping)]
+/// # pub struct MyCompletionMapping;
+/// # impl ElasticCompletionMapping for MyCompletionMapping {
+/// # 	//Overload the mapping functions here
+/// # 	fn payloads() -> Option<bool> {
+///	# 		Some(true)
+///	# 	}
+/// # }
+/// # fn main() {
+/// # let mapping = serde_json::to_string(&MyCompletionMapping).unwrap();
+/// # let json = json_str!(
+/// {
+///     "type": "completion",
+/// 	"payloads": true
+/// }
+/// # );
+/// # assert_eq!(json, mapping);
+/// # }
+/// ```
+///
+/// ## Manually
+///
+/// ```
+/// # extern crate serde;
+/// # extern crate elastic_types;
+/// # fn main() {
+/// use elastic_types::mapping::prelude::*;
+/// use elastic_types::completion::prelude::*;
+///
+/// #[derive(Debug, Clone, Default)]
+/// pub struct MyCompletionMapping;
+/// impl ElasticCompletionMapping for MyCompletionMapping {
+/// 	//Overload the mapping functions here
+/// 	fn payloads() -> Option<bool> {
+///			Some(true)
+///		}
+/// }
+///
+/// //We also need to implement the base `ElasticFieldMapping` and `serde::Serialize` for our custom mapping type
+/// impl ElasticFieldMapping<()> for MyCompletionMapping {
+/// 	type Visitor = ElasticCompletionMappingVisitor<MyCompletionMapping>;
+///
+/// 	fn data_type() -> &'static str {
+/// 		COMPLETION_DATATYPE
+/// 	}
+/// }
+///
+/// impl serde::Serialize for MyCompletionMapping {
+/// 	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+/// 	where S: serde::Serializer {
+/// 		serializer.serialize_struct("mapping", Self::get_visitor())
+/// 	}
+/// }
+/// # }
+/// ```
+pub trait ElasticCompletionMapping where
+Self: ElasticFieldMapping<()> + Sized + Serialize {
+	/// The index analyzer to use, defaults to `simple`.
+	fn analyzer() -> Option<&'static str> {
+		None
+	}
+
+	/// The search analyzer to use, defaults to the value of `analyzer`.
+	fn search_analyzer() -> Option<&'static str> {
+		None
+	}
+
+	/// Enables the storing of payloads, defaults to `false`.
+	fn payloads() -> Option<bool> {
+		None
+	}
+
+	/// Preserves the separators, defaults to `true`.
+	/// If disabled, you could find a field starting with `Foo Fighters`, if you suggest for `foof`.
+	fn preserve_separators() -> Option<bool> {
+		None
+	}
+
+	/// Enables position increments, defaults to `true`.
+	/// If disabled and using a stopwords analyzer, you could get a field starting with `The Beatles`, if you suggest for `b`.
+	fn preserve_position_increments() -> Option<bool> {
+		None
+	}
+
+	/// Limits the length of a single input, defaults to `50` UTF-16 code points.
+	fn max_input_length() -> Option<u32> {
+		None
+	}
+
+	/// The contexts that suggestions can be filtered by, keyed by name.
+	fn context() -> Option<BTreeMap<&'static str, CompletionContext>> {
+		None
+	}
+}
+
+/// Default mapping for `completion`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DefaultCompletionMapping;
+impl ElasticCompletionMapping for DefaultCompletionMapping { }
+
+impl_completion_mapping!(DefaultCompletionMapping);
+
+/// Base visitor for serialising completion mappings.
+#[derive(Debug, PartialEq, Default)]
+pub struct ElasticCompletionMappingVisitor<T> where T: ElasticCompletionMapping {
+	phantom: PhantomData<T>
+}
+
+impl <T> ElasticTypeVisitor for ElasticCompletionMappingVisitor<T> where
+T: ElasticCompletionMapping {
+	fn new() -> Self {
+		ElasticCompletionMappingVisitor {
+			phantom: PhantomData
+		}
+	}
+}
+impl <T> serde::ser::MapVisitor for ElasticCompletionMappingVisitor<T> where
+T: ElasticCompletionMapping {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error>
+	where S: serde::Serializer {
+		try!(serializer.serialize_struct_elt("type", T::data_type()));
+
+		if let Some(analyzer) = T::analyzer() {
+			try!(serializer.serialize_struct_elt("analyzer", analyzer));
+		}
+
+		if let Some(search_analyzer) = T::search_analyzer() {
+			try!(serializer.serialize_struct_elt("search_analyzer", search_analyzer));
+		}
+
+		if let Some(payloads) = T::payloads() {
+			try!(serializer.serialize_struct_elt("payloads", payloads));
+		}
+
+		if let Some(preserve_separators) = T::preserve_separators() {
+			try!(serializer.serialize_struct_elt("preserve_separators", preserve_separators));
+		}
+
+		if let Some(preserve_position_increments) = T::preserve_position_increments() {
+			try!(serializer.serialize_struct_elt("preserve_position_increments", preserve_position_increments));
+		}
+
+		if let Some(max_input_length) = T::max_input_length() {
+			try!(serializer.serialize_struct_elt("max_input_length", max_input_length));
+		}
+
+		if let Some(context) = T::context() {
+			try!(serializer.serialize_struct_elt("context", context));
+		}
+
+		Ok(None)
+	}
+}
+
+/// A context mapping for a `completion` field.
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/suggester-context.html)
+#[derive(Debug, Clone)]
+pub enum CompletionContext {
+	/// Filter suggestions by one or more categories.
+	Category(CategoryContext),
+	/// Filter suggestions by their distance from a geo point.
+	Geo(GeoContext)
+}
+
+/// A `category` context mapping.
+#[derive(Debug, Default, Clone)]
+pub struct CategoryContext {
+	/// A field in the document to take the category from.
+	pub path: Option<&'static str>,
+	/// The categories to use when none are given in the document.
+	pub default: Option<Vec<&'static str>>
+}
+
+/// A `geo` context mapping.
+#[derive(Debug, Default, Clone)]
+pub struct GeoContext {
+	/// A `geo_point` field in the document to take the location from.
+	pub path: Option<&'static str>,
+	/// The precision of the geohash to index, like `5m` or `2`.
+	pub precision: Option<Vec<&'static str>>,
+	/// Whether to also index the neighbouring geohashes, defaults to `true`.
+	pub neighbors: Option<bool>,
+	/// The location to use when none is given in the document.
+	pub default: Option<&'static str>
+}
+
+impl serde::Serialize for CompletionContext {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+	where S: Serializer
+	{
+		serializer.serialize_struct("context", CompletionContextVisitor::new(&self))
+	}
+}
+
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct CompletionContextVisitor<'a> {
+	data: &'a CompletionContext
+}
+impl <'a> CompletionContextVisitor<'a> {
+	#[doc(hidden)]
+	pub fn new(context: &'a CompletionContext) -> Self {
+		CompletionContextVisitor {
+			data: context
+		}
+	}
+}
+
+impl <'a> serde::ser::MapVisitor for CompletionContextVisitor<'a> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error>
+	where S: serde::Serializer {
+		match *self.data {
+			CompletionContext::Category(ref category) => {
+				try!(serializer.serialize_struct_elt("type", "category"));
+
+				if let Some(path) = category.path {
+					try!(serializer.serialize_struct_elt("path", path));
+				}
+
+				if let Some(ref default) = category.default {
+					try!(serializer.serialize_struct_elt("default", default));
+				}
+			},
+			CompletionContext::Geo(ref geo) => {
+				try!(serializer.serialize_struct_elt("type", "geo"));
+
+				if let Some(ref precision) = geo.precision {
+					try!(serializer.serialize_struct_elt("precision", precision));
+				}
+
+				if let Some(neighbors) = geo.neighbors {
+					try!(serializer.serialize_struct_elt("neighbors", neighbors));
+				}
+
+				if let Some(path) = geo.path {
+					try!(serializer.serialize_struct_elt("path", path));
+				}
+
+				if let Some(default) = geo.default {
+					try!(serializer.serialize_struct_elt("default", default));
+				}
+			}
+		}
+
+		Ok(None)
+	}
+}
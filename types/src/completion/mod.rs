@@ -0,0 +1,53 @@
+//! Implementation of the Elasticsearch `completion` type.
+//!
+//! Completion fields are indexed into an in-memory structure that the completion suggester uses for fast prefix lookups.
+//! Each value has one or more `input`s to match against, and optionally an `output`, `payload` and `weight`.
+//!
+//! # Examples
+//!
+//! For defining your own completion mapping, see [mapping details](mapping/trait.ElasticCompletionMapping.html#derive-mapping).
+//!
+//! Map with a default `completion`:
+//!
+//! ```
+//! # use elastic_types::completion::prelude::*;
+//! # use elastic_types::completion::mapping::DefaultCompletionMapping;
+//! struct MyType {
+//! 	pub field: ElasticCompletion<DefaultCompletionMapping>
+//! }
+//! ```
+//!
+//! Map with a custom `completion`:
+//!
+//! ```
+//! # #![feature(plugin, custom_derive)]
+//! # #![plugin(json_str, elastic_types_macros)]
+//! # extern crate serde;
+//! # extern crate elastic_types;
+//! # fn main() {
+//! # use elastic_types::mapping::prelude::*;
+//! # use elastic_types::completion::prelude::*;
+//! # #[derive(Debug, Clone, Default, ElasticCompletionMapping)]
+//! # pub struct MyCompletionMapping;
+//! # impl ElasticCompletionMapping for MyCompletionMapping { }
+//! struct MyType {
+//! 	pub field: ElasticCompletion<MyCompletionMapping>
+//! }
+//! # }
+//! ```
+//!
+//! # Links
+//! - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-suggesters-completion.html)
+
+mod completion;
+
+pub mod mapping;
+pub use self::completion::*;
+
+pub mod prelude {
+	//! Includes non-mapping types for the `completion` type.
+	//!
+	//! This is a convenience module to make it easy to build mappings for multiple types without too many `use` statements.
+
+	pub use super::completion::*;
+}
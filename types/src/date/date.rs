@@ -6,7 +6,7 @@ use serde::{ Serialize, Deserialize, Serializer, Deserializer };
 use super::{ DT, DefaultFormat };
 use super::format::{ DateFormat, ParseError };
 use super::mapping::{ ElasticDateMapping, DefaultDateMapping };
-use ::mapping::{ ElasticFieldMapping, ElasticType };
+use ::mapping::{ ElasticFieldMapping, ElasticType, ElasticFieldAnalysis, NotAnalyzed };
 
 pub use chrono::{ Datelike, Timelike };
 
@@ -14,6 +14,10 @@ impl ElasticType<DefaultDateMapping<DefaultFormat>, DefaultFormat> for DT {
 
 }
 
+impl ElasticFieldAnalysis for DT {
+	type Analysis = NotAnalyzed;
+}
+
 /// An Elasticsearch `date` type with a required `time` component.
 ///
 /// The [format](format/index.html) is provided as a generic parameter.
@@ -187,6 +191,12 @@ T: ElasticFieldMapping<F> + ElasticDateMapping<F> {
 
 }
 
+impl <F, T> ElasticFieldAnalysis for ElasticDate<F, T> where
+F: DateFormat,
+T: ElasticFieldMapping<F> + ElasticDateMapping<F> {
+	type Analysis = NotAnalyzed;
+}
+
 impl <F, T> Default for ElasticDate<F, T> where
 F: DateFormat,
 T: ElasticFieldMapping<F> + ElasticDateMapping<F> {
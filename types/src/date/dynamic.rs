@@ -0,0 +1,367 @@
+use std::fmt;
+use std::str::FromStr;
+use chrono::format::{ Item, Numeric, Fixed, Pad };
+use super::{ DT, parse_with_items };
+use super::format::{ DateFormat, ParseError };
+use super::formats::*;
+
+/// A date format that's only known at runtime.
+///
+/// The static `DateFormat`s are the best choice when the format is known up front.
+/// A `DynamicDateFormat` is useful when it isn't, like when reading the `format` of a `date` field from a mapping returned by the cluster.
+///
+/// The format is parsed from the same string that's used in the field mapping, which may be:
+///
+/// - The name of a built-in format, like `date_optional_time` or `epoch_millis`
+/// - A Joda-style pattern, like `yyyy-MM-dd'T'HH:mm:ss`
+/// - Multiple formats separated by `||`, like `yyyy-MM-dd||epoch_millis`
+///
+/// Parsing tries each format in order.
+/// Dates are always formatted with the first format.
+///
+/// The following pattern letters are supported:
+///
+/// Letter | Meaning
+/// ------ | -------
+/// `y` | year
+/// `x` | week year
+/// `M` | month of year, as a number for `M` or `MM`, and as text for `MMM` or more
+/// `w` | week of week year
+/// `D` | day of year
+/// `d` | day of month
+/// `e` | day of week, as a number
+/// `E` | day of week, as text
+/// `a` | half day, `AM` or `PM`
+/// `H` | hour of day, `0-23`
+/// `h` | hour of half day, `1-12`
+/// `m` | minute of hour
+/// `s` | second of minute
+/// `S` | fraction of second, which must follow a `.`
+/// `Z` | time zone offset, formatted as `Z` for `UTC`
+///
+/// Text in single quotes, like `'T'`, is a literal, and `''` is a single quote.
+/// Any other character that isn't a letter is also a literal.
+/// `chrono` style specifiers accepted by the `date_fmt` macro, like `%Y-%m-%d`, can also be used.
+///
+/// # Examples
+///
+/// Parse and format a date with a Joda-style pattern:
+///
+/// ```
+/// use elastic_types::date::prelude::*;
+///
+/// let fmt = DynamicDateFormat::new("yyyy-MM-dd'T'HH:mm:ss").unwrap();
+///
+/// let date = fmt.parse("2015-07-03T14:55:02").unwrap();
+///
+/// assert_eq!("2015-07-03T14:55:02", fmt.format(&date));
+/// ```
+///
+/// Use the format of a `date` field from a mapping, which may combine multiple formats:
+///
+/// ```
+/// use elastic_types::date::prelude::*;
+///
+/// let fmt = DynamicDateFormat::new("strict_date_optional_time||epoch_millis").unwrap();
+///
+/// let date = fmt.parse("1435935302478").unwrap();
+///
+/// assert_eq!("2015-07-03T14:55:02.478Z", fmt.format(&date));
+/// ```
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html)
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynamicDateFormat {
+	formats: Vec<Format>
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Format {
+	BuiltIn(String),
+	Pattern(String, Vec<Token>)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+	Item(Item<'static>),
+	Literal(String)
+}
+
+impl DynamicDateFormat {
+	/// Parses a date format from the string used in the field mapping.
+	///
+	/// This will fail if the format is empty or a pattern contains a letter that isn't supported.
+	pub fn new(fmt: &str) -> Result<DynamicDateFormat, ParseError> {
+		let mut formats = Vec::new();
+
+		for fmt in fmt.split("||") {
+			let fmt = fmt.trim();
+
+			if fmt.is_empty() {
+				return Err("Date format was empty".to_string().into());
+			}
+
+			let format = if is_built_in(fmt) {
+				Format::BuiltIn(fmt.to_string())
+			}
+			else {
+				Format::Pattern(fmt.to_string(), try!(tokenise(fmt)))
+			};
+
+			formats.push(format);
+		}
+
+		Ok(DynamicDateFormat {
+			formats: formats
+		})
+	}
+
+	/// The names or patterns of all formats that can be parsed, in the order they're tried.
+	pub fn names(&self) -> Vec<&str> {
+		self.formats.iter().map(|f| f.name()).collect()
+	}
+
+	/// Parses a date string to a `chrono::DateTime<UTC>` result.
+	///
+	/// Each format is tried in order until one succeeds.
+	pub fn parse(&self, date: &str) -> Result<DT, ParseError> {
+		for format in &self.formats {
+			if let Ok(dt) = format.parse(date) {
+				return Ok(dt);
+			}
+		}
+
+		Err(format!("'{}' doesn't match any of the formats '{}'", date, self).into())
+	}
+
+	/// Formats a given `chrono::DateTime<UTC>` as a string using the first format.
+	pub fn format(&self, date: &DT) -> String {
+		self.formats[0].format(date)
+	}
+}
+
+impl FromStr for DynamicDateFormat {
+	type Err = ParseError;
+
+	fn from_str(fmt: &str) -> Result<DynamicDateFormat, ParseError> {
+		DynamicDateFormat::new(fmt)
+	}
+}
+
+impl fmt::Display for DynamicDateFormat {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}", self.names().join("||"))
+	}
+}
+
+impl Format {
+	fn name(&self) -> &str {
+		match *self {
+			Format::BuiltIn(ref name) => name,
+			Format::Pattern(ref pattern, _) => pattern
+		}
+	}
+
+	fn parse(&self, date: &str) -> Result<DT, ParseError> {
+		match *self {
+			Format::BuiltIn(ref name) => parse_built_in(name, date),
+			Format::Pattern(_, ref tokens) => parse_with_items(date, tokens.iter().map(Token::to_item))
+		}
+	}
+
+	fn format(&self, date: &DT) -> String {
+		match *self {
+			Format::BuiltIn(ref name) => format_built_in(name, date),
+			Format::Pattern(_, ref tokens) => date.format_with_items(tokens.iter().map(Token::to_item)).to_string()
+		}
+	}
+}
+
+impl Token {
+	fn to_item<'a>(&'a self) -> Item<'a> {
+		match *self {
+			Token::Item(ref item) => item.clone(),
+			Token::Literal(ref literal) => Item::Literal(literal)
+		}
+	}
+}
+
+//Dispatch to the built-in formats by name
+macro_rules! built_in_formats {
+	($($f:ident),*) => (
+		fn is_built_in(name: &str) -> bool {
+			$(
+				if name == <$f as DateFormat>::name() {
+					return true;
+				}
+			)*
+
+			false
+		}
+
+		fn parse_built_in(name: &str, date: &str) -> Result<DT, ParseError> {
+			$(
+				if name == <$f as DateFormat>::name() {
+					return <$f as DateFormat>::parse(date);
+				}
+			)*
+
+			Err(format!("'{}' is not a built-in format", name).into())
+		}
+
+		fn format_built_in(name: &str, date: &DT) -> String {
+			$(
+				if name == <$f as DateFormat>::name() {
+					return <$f as DateFormat>::format(date);
+				}
+			)*
+
+			String::new()
+		}
+	)
+}
+
+built_in_formats!(
+	BasicDate, BasicDateTime, BasicDateTimeNoMillis, BasicOrdinalDate,
+	BasicOrdinalDateTime, BasicOrdinalDateTimeNoMillis, BasicTime, BasicTimeNoMillis,
+	BasicTTime, BasicTTimeNoMillis, BasicWeekDate, StrictBasicWeekDate,
+	BasicWeekDateTime, StrictBasicWeekDateTime, BasicWeekDateTimeNoMillis, StrictBasicWeekDateTimeNoMillis,
+	Date, StrictDate, DateHour, StrictDateHour,
+	DateHourMinute, StrictDateHourMinute, DateHourMinuteSecond, StrictDateHourMinuteSecond,
+	DateHourMinuteSecondFraction, StrictDateHourMinuteSecondFraction, DateHourMinuteSecondMillis, StrictDateHourMinuteSecondMillis,
+	DateOptionalTime, StrictDateOptionalTime, DateTime, StrictDateTime,
+	DateTimeNoMillis, StrictDateTimeNoMillis, Hour, StrictHour,
+	HourMinute, StrictHourMinute, HourMinuteSecond, StrictHourMinuteSecond,
+	HourMinuteSecondFraction, StrictHourMinuteSecondFraction, HourMinuteSecondMillis, StrictHourMinuteSecondMillis,
+	OrdinalDate, StrictOrdinalDate, OrdinalDateTime, StrictOrdinalDateTime,
+	OrdinalDateTimeNoMillis, StrictOrdinalDateTimeNoMillis, Time, StrictTime,
+	TimeNoMillis, StrictTimeNoMillis, TTime, StrictTTime,
+	TTimeNoMillis, StrictTTimeNoMillis, WeekDate, StrictWeekDate,
+	WeekDateTime, StrictWeekDateTime, WeekDateTimeNoMillis, StrictWeekDateTimeNoMillis,
+	Weekyear, StrictWeekyear, WeekyearWeek, StrictWeekyearWeek,
+	WeekyearWeekDay, StrictWeekyearWeekDay, Year, StrictYear,
+	YearMonth, StrictYearMonth, YearMonthDay, StrictYearMonthDay,
+	EpochMillis, EpochSecond
+);
+
+//Convert a Joda-style pattern into tokens
+fn tokenise(fmt: &str) -> Result<Vec<Token>, ParseError> {
+	let chars: Vec<char> = fmt.chars().collect();
+	let mut tokens = Vec::new();
+	let mut literal = String::new();
+
+	let mut i = 0;
+	while i < chars.len() {
+		let c = chars[i];
+
+		//Count repeated pattern letters, like the 4 in `yyyy`
+		let mut n = 1;
+		while i + n < chars.len() && chars[i + n] == c {
+			n += 1;
+		}
+
+		let item = match c {
+			'\'' => {
+				//A quoted literal, or `''` for a single quote
+				let mut j = i + 1;
+				if j < chars.len() && chars[j] == '\'' {
+					literal.push('\'');
+					i += 2;
+					continue;
+				}
+
+				while j < chars.len() && chars[j] != '\'' {
+					literal.push(chars[j]);
+					j += 1;
+				}
+
+				if j == chars.len() {
+					return Err(format!("unterminated literal in date format '{}'", fmt).into());
+				}
+
+				i = j + 1;
+				continue;
+			},
+			'%' => {
+				let (item, len) = try!(chrono_item(&chars[i..], fmt));
+				i += len;
+
+				push_item(&mut tokens, &mut literal, item);
+				continue;
+			},
+			'S' => {
+				//The fraction of a second includes the `.` that precedes it
+				if !literal.ends_with('.') {
+					return Err(format!("expected a '.' before the fraction of a second in date format '{}'", fmt).into());
+				}
+				literal.pop();
+
+				Item::Fixed(Fixed::Nanosecond3)
+			},
+			'y' => Item::Numeric(Numeric::Year, Pad::Zero),
+			'x' => Item::Numeric(Numeric::IsoYear, Pad::Zero),
+			'M' if n >= 4 => Item::Fixed(Fixed::LongMonthName),
+			'M' if n == 3 => Item::Fixed(Fixed::ShortMonthName),
+			'M' => Item::Numeric(Numeric::Month, Pad::Zero),
+			'w' => Item::Numeric(Numeric::IsoWeek, Pad::Zero),
+			'D' => Item::Numeric(Numeric::Ordinal, Pad::Zero),
+			'd' => Item::Numeric(Numeric::Day, Pad::Zero),
+			'e' => Item::Numeric(Numeric::WeekdayFromMon, Pad::None),
+			'E' if n >= 4 => Item::Fixed(Fixed::LongWeekdayName),
+			'E' => Item::Fixed(Fixed::ShortWeekdayName),
+			'a' => Item::Fixed(Fixed::UpperAMPM),
+			'H' => Item::Numeric(Numeric::Hour, Pad::Zero),
+			'h' => Item::Numeric(Numeric::Hour12, Pad::Zero),
+			'm' => Item::Numeric(Numeric::Minute, Pad::Zero),
+			's' => Item::Numeric(Numeric::Second, Pad::Zero),
+			'Z' => Item::Fixed(Fixed::TimezoneOffsetZ),
+			c if c.is_alphabetic() => return Err(format!("unsupported letter '{}' in date format '{}'", c, fmt).into()),
+			c => {
+				literal.push(c);
+				i += 1;
+				continue;
+			}
+		};
+
+		push_item(&mut tokens, &mut literal, item);
+		i += n;
+	}
+
+	if !literal.is_empty() {
+		tokens.push(Token::Literal(literal));
+	}
+
+	Ok(tokens)
+}
+
+//Push an item, along with any literal that comes before it
+fn push_item(tokens: &mut Vec<Token>, literal: &mut String, item: Item<'static>) {
+	if !literal.is_empty() {
+		tokens.push(Token::Literal(literal.clone()));
+		literal.clear();
+	}
+
+	tokens.push(Token::Item(item));
+}
+
+//Convert a `chrono` style specifier, like `%Y`, into an item and the number of chars it takes up
+fn chrono_item(chars: &[char], fmt: &str) -> Result<(Item<'static>, usize), ParseError> {
+	let item = match chars.get(1).cloned() {
+		Some('Y') => Item::Numeric(Numeric::Year, Pad::Zero),
+		Some('G') => Item::Numeric(Numeric::IsoYear, Pad::Zero),
+		Some('m') => Item::Numeric(Numeric::Month, Pad::Zero),
+		Some('V') => Item::Numeric(Numeric::IsoWeek, Pad::Zero),
+		Some('j') => Item::Numeric(Numeric::Ordinal, Pad::Zero),
+		Some('d') => Item::Numeric(Numeric::Day, Pad::Zero),
+		Some('u') => Item::Numeric(Numeric::WeekdayFromMon, Pad::None),
+		Some('H') => Item::Numeric(Numeric::Hour, Pad::Zero),
+		Some('M') => Item::Numeric(Numeric::Minute, Pad::Zero),
+		Some('S') => Item::Numeric(Numeric::Second, Pad::Zero),
+		Some('z') => Item::Fixed(Fixed::TimezoneOffset),
+		Some('.') if chars.get(2) == Some(&'3') && chars.get(3) == Some(&'f') => return Ok((Item::Fixed(Fixed::Nanosecond3), 4)),
+		_ => return Err(format!("unsupported specifier in date format '{}'", fmt).into())
+	};
+
+	Ok((item, 2))
+}
@@ -1,8 +1,10 @@
 use chrono;
 use chrono::{ DateTime, UTC };
-use chrono::format::{ Parsed, Item };
+use chrono::format::Item;
 use std::error::Error;
 use std::fmt;
+use std::marker::PhantomData;
+use super::parse_with_items;
 
 /// A format used for parsing and formatting dates.
 ///
@@ -13,24 +15,12 @@ where Self : Default + Copy {
 	/// Parses a date string to a `chrono::DateTime<UTC>` result.
 	///
 	/// The date string must match the format specified by `fmt()`.
+	/// Any missing date or time components are set to their defaults, so a format without a date is parsed as a time on `1970-01-01`.
+	/// If the date string contains a timezone offset, the result is converted to `UTC`.
 	fn parse(date: &str) -> Result<DateTime<UTC>, ParseError> {
 		let fmt = Self::fmt();
 
-		let mut parsed = Parsed::new();
-		match chrono::format::parse(&mut parsed, date, fmt.iter().cloned()) {
-			Ok(_) => {
-				//If the parsed result doesn't contain any time, set it to the default
-				if parsed.hour_mod_12.is_none() {
-					let _ = parsed.set_hour(0);
-					let _ = parsed.set_minute(0);
-				}
-
-				//Set the DateTime result
-				let dt = try!(parsed.to_naive_datetime_with_offset(0));
-				Ok(chrono::DateTime::from_utc(dt, chrono::UTC))
-			},
-			Err(e) => Err(e.into())
-		}
+		parse_with_items(date, fmt.iter().cloned())
 	}
 
 	/// Formats a given `chrono::DateTime<UTC>` as a string.
@@ -70,6 +60,82 @@ where Self : Default + Copy {
 	///
 	/// This is the string used when defining the format in the field mapping.
 	fn name() -> &'static str;
+
+	/// The names of all formats that can be parsed.
+	///
+	/// This is just `name()` for a single format.
+	/// Composite formats, like `FormatOr`, return the names of each format in the order they're tried.
+	fn names() -> Vec<&'static str> {
+		vec![Self::name()]
+	}
+}
+
+/// A composite format that parses dates using either of two formats.
+///
+/// Parsing tries format `A` first and falls back to `B` if it fails.
+/// Dates are always formatted with `A`.
+/// In the field mapping, the names of the formats are joined with `||`, like `"basic_date_time||epoch_millis"`.
+///
+/// More than two formats can be combined by nesting `FormatOr`s.
+///
+/// # Examples
+///
+/// Parse a date that may be given as either `basic_date_time` or `epoch_millis`:
+///
+/// ```
+/// use elastic_types::date::{ ElasticDate, FormatOr, BasicDateTime, EpochMillis };
+///
+/// type MyFormat = FormatOr<BasicDateTime, EpochMillis>;
+///
+/// let date = ElasticDate::<MyFormat>::parse("1435935302478").unwrap();
+///
+/// //Dates are formatted as basic_date_time
+/// assert_eq!("20150703T145502.478Z", date.format());
+/// ```
+///
+/// Combine three formats:
+///
+/// ```
+/// use elastic_types::date::{ DateFormat, FormatOr, BasicDateTime, BasicDateTimeNoMillis, EpochMillis };
+///
+/// type MyFormat = FormatOr<BasicDateTime, FormatOr<BasicDateTimeNoMillis, EpochMillis>>;
+///
+/// assert_eq!(vec!["basic_date_time", "basic_date_time_no_millis", "epoch_millis"], MyFormat::names());
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FormatOr<A, B> where
+A: DateFormat,
+B: DateFormat {
+	phantom: PhantomData<(A, B)>
+}
+
+impl <A, B> DateFormat for FormatOr<A, B> where
+A: DateFormat,
+B: DateFormat {
+	fn parse(date: &str) -> Result<DateTime<UTC>, ParseError> {
+		A::parse(date)
+			.or_else(|_| B::parse(date))
+			.map_err(|_| format!("'{}' doesn't match any of the formats '{}'", date, Self::names().join("||")).into())
+	}
+
+	fn format(date: &DateTime<UTC>) -> String {
+		A::format(date)
+	}
+
+	fn fmt<'a>() -> Vec<Item<'a>> {
+		A::fmt()
+	}
+
+	fn name() -> &'static str {
+		A::name()
+	}
+
+	fn names() -> Vec<&'static str> {
+		let mut names = A::names();
+		names.extend(B::names());
+
+		names
+	}
 }
 
 /// Represents an error encountered during parsing.
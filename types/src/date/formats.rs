@@ -1,24 +1,863 @@
 use chrono;
-use chrono::{ DateTime, NaiveDateTime, UTC, Timelike };
-use chrono::format::Item;
+use chrono::{ NaiveDateTime, UTC, Timelike };
+use chrono::format::{ Item, Numeric, Fixed, Pad };
 use std::error::Error;
-use super::{ DateFormat, ParseError };
+use super::{ DateFormat, FormatOr, ParseError };
 
-/// Format for `basic_date_time_no_millis`.
+const YEAR: Item<'static> = Item::Numeric(Numeric::Year, Pad::Zero);
+const MONTH: Item<'static> = Item::Numeric(Numeric::Month, Pad::Zero);
+const DAY: Item<'static> = Item::Numeric(Numeric::Day, Pad::Zero);
+const ORDINAL: Item<'static> = Item::Numeric(Numeric::Ordinal, Pad::Zero);
+const WEEKYEAR: Item<'static> = Item::Numeric(Numeric::IsoYear, Pad::Zero);
+const WEEK: Item<'static> = Item::Numeric(Numeric::IsoWeek, Pad::Zero);
+const WEEKDAY: Item<'static> = Item::Numeric(Numeric::WeekdayFromMon, Pad::None);
+const HOUR: Item<'static> = Item::Numeric(Numeric::Hour, Pad::Zero);
+const MINUTE: Item<'static> = Item::Numeric(Numeric::Minute, Pad::Zero);
+const SECOND: Item<'static> = Item::Numeric(Numeric::Second, Pad::Zero);
+const MILLIS: Item<'static> = Item::Fixed(Fixed::Nanosecond3);
+//Formats as `Z` for UTC, and parses either `Z` or an offset like `+10:00` or `+1000`
+const OFFSET: Item<'static> = Item::Fixed(Fixed::TimezoneOffsetZ);
+const DASH: Item<'static> = Item::Literal("-");
+const COLON: Item<'static> = Item::Literal(":");
+const T: Item<'static> = Item::Literal("T");
+const W: Item<'static> = Item::Literal("W");
+
+//The formats tried by `date_optional_time`, from most to least specific
+type OptionalTimeFormats =
+	FormatOr<DateTime,
+	FormatOr<DateTimeNoMillis,
+	FormatOr<DateHourMinuteSecondFraction,
+	FormatOr<DateHourMinuteSecond,
+	FormatOr<DateHourMinute,
+	FormatOr<DateHour, Date>>>>>>;
+
+//The formats tried by `strict_date_optional_time`, from most to least specific
+type StrictOptionalTimeFormats =
+	FormatOr<StrictDateTime,
+	FormatOr<StrictDateTimeNoMillis,
+	FormatOr<StrictDateHourMinuteSecondFraction,
+	FormatOr<StrictDateHourMinuteSecond,
+	FormatOr<StrictDateHourMinute,
+	FormatOr<StrictDateHour, StrictDate>>>>>>;
+
+/// Format for `basic_date`.
+/// 
+/// Parses and formats dates like `20150703`.
 /// 
 /// # Links
 /// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
 #[derive(Debug, Default, Clone, Copy)]
-pub struct BasicDateTimeNoMillis;
-impl_date_fmt!(BasicDateTimeNoMillis, "%Y%m%dT%H%M%SZ", "basic_date_time_no_millis");
+pub struct BasicDate;
+impl_date_fmt!(BasicDate, [YEAR, MONTH, DAY], "basic_date");
 
 /// Format for `basic_date_time`.
 /// 
+/// Parses and formats dates like `20150703T145502.478Z`.
+/// 
 /// # Links
 /// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
 #[derive(Debug, Default, Clone, Copy)]
 pub struct BasicDateTime;
-impl_date_fmt!(BasicDateTime, "%Y%m%dT%H%M%S%.3fZ", "basic_date_time");
+impl_date_fmt!(BasicDateTime, [YEAR, MONTH, DAY, T, HOUR, MINUTE, SECOND, MILLIS, OFFSET], "basic_date_time");
+
+/// Format for `basic_date_time_no_millis`.
+/// 
+/// Parses and formats dates like `20150703T145502Z`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BasicDateTimeNoMillis;
+impl_date_fmt!(BasicDateTimeNoMillis, [YEAR, MONTH, DAY, T, HOUR, MINUTE, SECOND, OFFSET], "basic_date_time_no_millis");
+
+/// Format for `basic_ordinal_date`.
+/// 
+/// Parses and formats dates like `2015184`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BasicOrdinalDate;
+impl_date_fmt!(BasicOrdinalDate, [YEAR, ORDINAL], "basic_ordinal_date");
+
+/// Format for `basic_ordinal_date_time`.
+/// 
+/// Parses and formats dates like `2015184T145502.478Z`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BasicOrdinalDateTime;
+impl_date_fmt!(BasicOrdinalDateTime, [YEAR, ORDINAL, T, HOUR, MINUTE, SECOND, MILLIS, OFFSET], "basic_ordinal_date_time");
+
+/// Format for `basic_ordinal_date_time_no_millis`.
+/// 
+/// Parses and formats dates like `2015184T145502Z`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BasicOrdinalDateTimeNoMillis;
+impl_date_fmt!(BasicOrdinalDateTimeNoMillis, [YEAR, ORDINAL, T, HOUR, MINUTE, SECOND, OFFSET], "basic_ordinal_date_time_no_millis");
+
+/// Format for `basic_time`.
+/// 
+/// Parses and formats dates like `145502.478Z`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BasicTime;
+impl_date_fmt!(BasicTime, [HOUR, MINUTE, SECOND, MILLIS, OFFSET], "basic_time");
+
+/// Format for `basic_time_no_millis`.
+/// 
+/// Parses and formats dates like `145502Z`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BasicTimeNoMillis;
+impl_date_fmt!(BasicTimeNoMillis, [HOUR, MINUTE, SECOND, OFFSET], "basic_time_no_millis");
+
+/// Format for `basic_t_time`.
+/// 
+/// Parses and formats dates like `T145502.478Z`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BasicTTime;
+impl_date_fmt!(BasicTTime, [T, HOUR, MINUTE, SECOND, MILLIS, OFFSET], "basic_t_time");
+
+/// Format for `basic_t_time_no_millis`.
+/// 
+/// Parses and formats dates like `T145502Z`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BasicTTimeNoMillis;
+impl_date_fmt!(BasicTTimeNoMillis, [T, HOUR, MINUTE, SECOND, OFFSET], "basic_t_time_no_millis");
+
+/// Format for `basic_week_date`.
+/// 
+/// Parses and formats dates like `2015W275`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BasicWeekDate;
+impl_date_fmt!(BasicWeekDate, [WEEKYEAR, W, WEEK, WEEKDAY], "basic_week_date");
+
+/// Format for `strict_basic_week_date`.
+/// 
+/// Parses and formats dates like `2015W275`.
+/// This parses the same dates as [`BasicWeekDate`](struct.BasicWeekDate.html), but each numeric field must be padded to its full width, like `07` for July.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StrictBasicWeekDate;
+impl_date_fmt!(StrictBasicWeekDate, [WEEKYEAR, W, WEEK, WEEKDAY], "strict_basic_week_date", strict);
+
+/// Format for `basic_week_date_time`.
+/// 
+/// Parses and formats dates like `2015W275T145502.478Z`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BasicWeekDateTime;
+impl_date_fmt!(BasicWeekDateTime, [WEEKYEAR, W, WEEK, WEEKDAY, T, HOUR, MINUTE, SECOND, MILLIS, OFFSET], "basic_week_date_time");
+
+/// Format for `strict_basic_week_date_time`.
+/// 
+/// Parses and formats dates like `2015W275T145502.478Z`.
+/// This parses the same dates as [`BasicWeekDateTime`](struct.BasicWeekDateTime.html), but each numeric field must be padded to its full width, like `07` for July.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StrictBasicWeekDateTime;
+impl_date_fmt!(StrictBasicWeekDateTime, [WEEKYEAR, W, WEEK, WEEKDAY, T, HOUR, MINUTE, SECOND, MILLIS, OFFSET], "strict_basic_week_date_time", strict);
+
+/// Format for `basic_week_date_time_no_millis`.
+/// 
+/// Parses and formats dates like `2015W275T145502Z`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BasicWeekDateTimeNoMillis;
+impl_date_fmt!(BasicWeekDateTimeNoMillis, [WEEKYEAR, W, WEEK, WEEKDAY, T, HOUR, MINUTE, SECOND, OFFSET], "basic_week_date_time_no_millis");
+
+/// Format for `strict_basic_week_date_time_no_millis`.
+/// 
+/// Parses and formats dates like `2015W275T145502Z`.
+/// This parses the same dates as [`BasicWeekDateTimeNoMillis`](struct.BasicWeekDateTimeNoMillis.html), but each numeric field must be padded to its full width, like `07` for July.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StrictBasicWeekDateTimeNoMillis;
+impl_date_fmt!(StrictBasicWeekDateTimeNoMillis, [WEEKYEAR, W, WEEK, WEEKDAY, T, HOUR, MINUTE, SECOND, OFFSET], "strict_basic_week_date_time_no_millis", strict);
+
+/// Format for `date`.
+/// 
+/// Parses and formats dates like `2015-07-03`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Date;
+impl_date_fmt!(Date, [YEAR, DASH, MONTH, DASH, DAY], "date");
+
+/// Format for `strict_date`.
+/// 
+/// Parses and formats dates like `2015-07-03`.
+/// This parses the same dates as [`Date`](struct.Date.html), but each numeric field must be padded to its full width, like `07` for July.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StrictDate;
+impl_date_fmt!(StrictDate, [YEAR, DASH, MONTH, DASH, DAY], "strict_date", strict);
+
+/// Format for `date_hour`.
+/// 
+/// Parses and formats dates like `2015-07-03T14`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DateHour;
+impl_date_fmt!(DateHour, [YEAR, DASH, MONTH, DASH, DAY, T, HOUR], "date_hour");
+
+/// Format for `strict_date_hour`.
+/// 
+/// Parses and formats dates like `2015-07-03T14`.
+/// This parses the same dates as [`DateHour`](struct.DateHour.html), but each numeric field must be padded to its full width, like `07` for July.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StrictDateHour;
+impl_date_fmt!(StrictDateHour, [YEAR, DASH, MONTH, DASH, DAY, T, HOUR], "strict_date_hour", strict);
+
+/// Format for `date_hour_minute`.
+/// 
+/// Parses and formats dates like `2015-07-03T14:55`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DateHourMinute;
+impl_date_fmt!(DateHourMinute, [YEAR, DASH, MONTH, DASH, DAY, T, HOUR, COLON, MINUTE], "date_hour_minute");
+
+/// Format for `strict_date_hour_minute`.
+/// 
+/// Parses and formats dates like `2015-07-03T14:55`.
+/// This parses the same dates as [`DateHourMinute`](struct.DateHourMinute.html), but each numeric field must be padded to its full width, like `07` for July.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StrictDateHourMinute;
+impl_date_fmt!(StrictDateHourMinute, [YEAR, DASH, MONTH, DASH, DAY, T, HOUR, COLON, MINUTE], "strict_date_hour_minute", strict);
+
+/// Format for `date_hour_minute_second`.
+/// 
+/// Parses and formats dates like `2015-07-03T14:55:02`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DateHourMinuteSecond;
+impl_date_fmt!(DateHourMinuteSecond, [YEAR, DASH, MONTH, DASH, DAY, T, HOUR, COLON, MINUTE, COLON, SECOND], "date_hour_minute_second");
+
+/// Format for `strict_date_hour_minute_second`.
+/// 
+/// Parses and formats dates like `2015-07-03T14:55:02`.
+/// This parses the same dates as [`DateHourMinuteSecond`](struct.DateHourMinuteSecond.html), but each numeric field must be padded to its full width, like `07` for July.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StrictDateHourMinuteSecond;
+impl_date_fmt!(StrictDateHourMinuteSecond, [YEAR, DASH, MONTH, DASH, DAY, T, HOUR, COLON, MINUTE, COLON, SECOND], "strict_date_hour_minute_second", strict);
+
+/// Format for `date_hour_minute_second_fraction`.
+/// 
+/// Parses and formats dates like `2015-07-03T14:55:02.478`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DateHourMinuteSecondFraction;
+impl_date_fmt!(DateHourMinuteSecondFraction, [YEAR, DASH, MONTH, DASH, DAY, T, HOUR, COLON, MINUTE, COLON, SECOND, MILLIS], "date_hour_minute_second_fraction");
+
+/// Format for `strict_date_hour_minute_second_fraction`.
+/// 
+/// Parses and formats dates like `2015-07-03T14:55:02.478`.
+/// This parses the same dates as [`DateHourMinuteSecondFraction`](struct.DateHourMinuteSecondFraction.html), but each numeric field must be padded to its full width, like `07` for July.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StrictDateHourMinuteSecondFraction;
+impl_date_fmt!(StrictDateHourMinuteSecondFraction, [YEAR, DASH, MONTH, DASH, DAY, T, HOUR, COLON, MINUTE, COLON, SECOND, MILLIS], "strict_date_hour_minute_second_fraction", strict);
+
+/// Format for `date_hour_minute_second_millis`.
+/// 
+/// Parses and formats dates like `2015-07-03T14:55:02.478`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DateHourMinuteSecondMillis;
+impl_date_fmt!(DateHourMinuteSecondMillis, [YEAR, DASH, MONTH, DASH, DAY, T, HOUR, COLON, MINUTE, COLON, SECOND, MILLIS], "date_hour_minute_second_millis");
+
+/// Format for `strict_date_hour_minute_second_millis`.
+/// 
+/// Parses and formats dates like `2015-07-03T14:55:02.478`.
+/// This parses the same dates as [`DateHourMinuteSecondMillis`](struct.DateHourMinuteSecondMillis.html), but each numeric field must be padded to its full width, like `07` for July.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StrictDateHourMinuteSecondMillis;
+impl_date_fmt!(StrictDateHourMinuteSecondMillis, [YEAR, DASH, MONTH, DASH, DAY, T, HOUR, COLON, MINUTE, COLON, SECOND, MILLIS], "strict_date_hour_minute_second_millis", strict);
+
+/// Format for `date_optional_time`.
+/// 
+/// Parses dates like `2015-07-03T14:55:02.478Z`, where the time and offset are optional.
+/// Dates are formatted like `date_time`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DateOptionalTime;
+impl DateFormat for DateOptionalTime {
+	fn fmt<'a>() -> Vec<Item<'a>> {
+		DateTime::fmt()
+	}
+	fn name() -> &'static str {
+		"date_optional_time"
+	}
+
+	fn parse(date: &str) -> Result<chrono::DateTime<UTC>, ParseError> {
+		OptionalTimeFormats::parse(date)
+			.map_err(|_| format!("'{}' doesn't match the format '{}'", date, Self::name()).into())
+	}
+}
+
+/// Format for `strict_date_optional_time`.
+/// 
+/// Parses dates like `2015-07-03T14:55:02.478Z`, where the time and offset are optional.
+/// Dates are formatted like `date_time`.
+/// This parses the same dates as [`DateOptionalTime`](struct.DateOptionalTime.html), but each numeric field must be padded to its full width, like `07` for July.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StrictDateOptionalTime;
+impl DateFormat for StrictDateOptionalTime {
+	fn fmt<'a>() -> Vec<Item<'a>> {
+		DateTime::fmt()
+	}
+	fn name() -> &'static str {
+		"strict_date_optional_time"
+	}
+
+	fn parse(date: &str) -> Result<chrono::DateTime<UTC>, ParseError> {
+		StrictOptionalTimeFormats::parse(date)
+			.map_err(|_| format!("'{}' doesn't match the format '{}'", date, Self::name()).into())
+	}
+}
+
+/// Format for `date_time`.
+/// 
+/// Parses and formats dates like `2015-07-03T14:55:02.478Z`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DateTime;
+impl_date_fmt!(DateTime, [YEAR, DASH, MONTH, DASH, DAY, T, HOUR, COLON, MINUTE, COLON, SECOND, MILLIS, OFFSET], "date_time");
+
+/// Format for `strict_date_time`.
+/// 
+/// Parses and formats dates like `2015-07-03T14:55:02.478Z`.
+/// This parses the same dates as [`DateTime`](struct.DateTime.html), but each numeric field must be padded to its full width, like `07` for July.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StrictDateTime;
+impl_date_fmt!(StrictDateTime, [YEAR, DASH, MONTH, DASH, DAY, T, HOUR, COLON, MINUTE, COLON, SECOND, MILLIS, OFFSET], "strict_date_time", strict);
+
+/// Format for `date_time_no_millis`.
+/// 
+/// Parses and formats dates like `2015-07-03T14:55:02Z`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DateTimeNoMillis;
+impl_date_fmt!(DateTimeNoMillis, [YEAR, DASH, MONTH, DASH, DAY, T, HOUR, COLON, MINUTE, COLON, SECOND, OFFSET], "date_time_no_millis");
+
+/// Format for `strict_date_time_no_millis`.
+/// 
+/// Parses and formats dates like `2015-07-03T14:55:02Z`.
+/// This parses the same dates as [`DateTimeNoMillis`](struct.DateTimeNoMillis.html), but each numeric field must be padded to its full width, like `07` for July.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StrictDateTimeNoMillis;
+impl_date_fmt!(StrictDateTimeNoMillis, [YEAR, DASH, MONTH, DASH, DAY, T, HOUR, COLON, MINUTE, COLON, SECOND, OFFSET], "strict_date_time_no_millis", strict);
+
+/// Format for `hour`.
+/// 
+/// Parses and formats dates like `14`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Hour;
+impl_date_fmt!(Hour, [HOUR], "hour");
+
+/// Format for `strict_hour`.
+/// 
+/// Parses and formats dates like `14`.
+/// This parses the same dates as [`Hour`](struct.Hour.html), but each numeric field must be padded to its full width, like `07` for July.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StrictHour;
+impl_date_fmt!(StrictHour, [HOUR], "strict_hour", strict);
+
+/// Format for `hour_minute`.
+/// 
+/// Parses and formats dates like `14:55`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct HourMinute;
+impl_date_fmt!(HourMinute, [HOUR, COLON, MINUTE], "hour_minute");
+
+/// Format for `strict_hour_minute`.
+/// 
+/// Parses and formats dates like `14:55`.
+/// This parses the same dates as [`HourMinute`](struct.HourMinute.html), but each numeric field must be padded to its full width, like `07` for July.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StrictHourMinute;
+impl_date_fmt!(StrictHourMinute, [HOUR, COLON, MINUTE], "strict_hour_minute", strict);
+
+/// Format for `hour_minute_second`.
+/// 
+/// Parses and formats dates like `14:55:02`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct HourMinuteSecond;
+impl_date_fmt!(HourMinuteSecond, [HOUR, COLON, MINUTE, COLON, SECOND], "hour_minute_second");
+
+/// Format for `strict_hour_minute_second`.
+/// 
+/// Parses and formats dates like `14:55:02`.
+/// This parses the same dates as [`HourMinuteSecond`](struct.HourMinuteSecond.html), but each numeric field must be padded to its full width, like `07` for July.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StrictHourMinuteSecond;
+impl_date_fmt!(StrictHourMinuteSecond, [HOUR, COLON, MINUTE, COLON, SECOND], "strict_hour_minute_second", strict);
+
+/// Format for `hour_minute_second_fraction`.
+/// 
+/// Parses and formats dates like `14:55:02.478`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct HourMinuteSecondFraction;
+impl_date_fmt!(HourMinuteSecondFraction, [HOUR, COLON, MINUTE, COLON, SECOND, MILLIS], "hour_minute_second_fraction");
+
+/// Format for `strict_hour_minute_second_fraction`.
+/// 
+/// Parses and formats dates like `14:55:02.478`.
+/// This parses the same dates as [`HourMinuteSecondFraction`](struct.HourMinuteSecondFraction.html), but each numeric field must be padded to its full width, like `07` for July.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StrictHourMinuteSecondFraction;
+impl_date_fmt!(StrictHourMinuteSecondFraction, [HOUR, COLON, MINUTE, COLON, SECOND, MILLIS], "strict_hour_minute_second_fraction", strict);
+
+/// Format for `hour_minute_second_millis`.
+/// 
+/// Parses and formats dates like `14:55:02.478`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct HourMinuteSecondMillis;
+impl_date_fmt!(HourMinuteSecondMillis, [HOUR, COLON, MINUTE, COLON, SECOND, MILLIS], "hour_minute_second_millis");
+
+/// Format for `strict_hour_minute_second_millis`.
+/// 
+/// Parses and formats dates like `14:55:02.478`.
+/// This parses the same dates as [`HourMinuteSecondMillis`](struct.HourMinuteSecondMillis.html), but each numeric field must be padded to its full width, like `07` for July.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StrictHourMinuteSecondMillis;
+impl_date_fmt!(StrictHourMinuteSecondMillis, [HOUR, COLON, MINUTE, COLON, SECOND, MILLIS], "strict_hour_minute_second_millis", strict);
+
+/// Format for `ordinal_date`.
+/// 
+/// Parses and formats dates like `2015-184`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct OrdinalDate;
+impl_date_fmt!(OrdinalDate, [YEAR, DASH, ORDINAL], "ordinal_date");
+
+/// Format for `strict_ordinal_date`.
+/// 
+/// Parses and formats dates like `2015-184`.
+/// This parses the same dates as [`OrdinalDate`](struct.OrdinalDate.html), but each numeric field must be padded to its full width, like `07` for July.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StrictOrdinalDate;
+impl_date_fmt!(StrictOrdinalDate, [YEAR, DASH, ORDINAL], "strict_ordinal_date", strict);
+
+/// Format for `ordinal_date_time`.
+/// 
+/// Parses and formats dates like `2015-184T14:55:02.478Z`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct OrdinalDateTime;
+impl_date_fmt!(OrdinalDateTime, [YEAR, DASH, ORDINAL, T, HOUR, COLON, MINUTE, COLON, SECOND, MILLIS, OFFSET], "ordinal_date_time");
+
+/// Format for `strict_ordinal_date_time`.
+/// 
+/// Parses and formats dates like `2015-184T14:55:02.478Z`.
+/// This parses the same dates as [`OrdinalDateTime`](struct.OrdinalDateTime.html), but each numeric field must be padded to its full width, like `07` for July.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StrictOrdinalDateTime;
+impl_date_fmt!(StrictOrdinalDateTime, [YEAR, DASH, ORDINAL, T, HOUR, COLON, MINUTE, COLON, SECOND, MILLIS, OFFSET], "strict_ordinal_date_time", strict);
+
+/// Format for `ordinal_date_time_no_millis`.
+/// 
+/// Parses and formats dates like `2015-184T14:55:02Z`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct OrdinalDateTimeNoMillis;
+impl_date_fmt!(OrdinalDateTimeNoMillis, [YEAR, DASH, ORDINAL, T, HOUR, COLON, MINUTE, COLON, SECOND, OFFSET], "ordinal_date_time_no_millis");
+
+/// Format for `strict_ordinal_date_time_no_millis`.
+/// 
+/// Parses and formats dates like `2015-184T14:55:02Z`.
+/// This parses the same dates as [`OrdinalDateTimeNoMillis`](struct.OrdinalDateTimeNoMillis.html), but each numeric field must be padded to its full width, like `07` for July.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StrictOrdinalDateTimeNoMillis;
+impl_date_fmt!(StrictOrdinalDateTimeNoMillis, [YEAR, DASH, ORDINAL, T, HOUR, COLON, MINUTE, COLON, SECOND, OFFSET], "strict_ordinal_date_time_no_millis", strict);
+
+/// Format for `time`.
+/// 
+/// Parses and formats dates like `14:55:02.478Z`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Time;
+impl_date_fmt!(Time, [HOUR, COLON, MINUTE, COLON, SECOND, MILLIS, OFFSET], "time");
+
+/// Format for `strict_time`.
+/// 
+/// Parses and formats dates like `14:55:02.478Z`.
+/// This parses the same dates as [`Time`](struct.Time.html), but each numeric field must be padded to its full width, like `07` for July.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StrictTime;
+impl_date_fmt!(StrictTime, [HOUR, COLON, MINUTE, COLON, SECOND, MILLIS, OFFSET], "strict_time", strict);
+
+/// Format for `time_no_millis`.
+/// 
+/// Parses and formats dates like `14:55:02Z`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TimeNoMillis;
+impl_date_fmt!(TimeNoMillis, [HOUR, COLON, MINUTE, COLON, SECOND, OFFSET], "time_no_millis");
+
+/// Format for `strict_time_no_millis`.
+/// 
+/// Parses and formats dates like `14:55:02Z`.
+/// This parses the same dates as [`TimeNoMillis`](struct.TimeNoMillis.html), but each numeric field must be padded to its full width, like `07` for July.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StrictTimeNoMillis;
+impl_date_fmt!(StrictTimeNoMillis, [HOUR, COLON, MINUTE, COLON, SECOND, OFFSET], "strict_time_no_millis", strict);
+
+/// Format for `t_time`.
+/// 
+/// Parses and formats dates like `T14:55:02.478Z`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TTime;
+impl_date_fmt!(TTime, [T, HOUR, COLON, MINUTE, COLON, SECOND, MILLIS, OFFSET], "t_time");
+
+/// Format for `strict_t_time`.
+/// 
+/// Parses and formats dates like `T14:55:02.478Z`.
+/// This parses the same dates as [`TTime`](struct.TTime.html), but each numeric field must be padded to its full width, like `07` for July.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StrictTTime;
+impl_date_fmt!(StrictTTime, [T, HOUR, COLON, MINUTE, COLON, SECOND, MILLIS, OFFSET], "strict_t_time", strict);
+
+/// Format for `t_time_no_millis`.
+/// 
+/// Parses and formats dates like `T14:55:02Z`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TTimeNoMillis;
+impl_date_fmt!(TTimeNoMillis, [T, HOUR, COLON, MINUTE, COLON, SECOND, OFFSET], "t_time_no_millis");
+
+/// Format for `strict_t_time_no_millis`.
+/// 
+/// Parses and formats dates like `T14:55:02Z`.
+/// This parses the same dates as [`TTimeNoMillis`](struct.TTimeNoMillis.html), but each numeric field must be padded to its full width, like `07` for July.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StrictTTimeNoMillis;
+impl_date_fmt!(StrictTTimeNoMillis, [T, HOUR, COLON, MINUTE, COLON, SECOND, OFFSET], "strict_t_time_no_millis", strict);
+
+/// Format for `week_date`.
+/// 
+/// Parses and formats dates like `2015-W27-5`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct WeekDate;
+impl_date_fmt!(WeekDate, [WEEKYEAR, DASH, W, WEEK, DASH, WEEKDAY], "week_date");
+
+/// Format for `strict_week_date`.
+/// 
+/// Parses and formats dates like `2015-W27-5`.
+/// This parses the same dates as [`WeekDate`](struct.WeekDate.html), but each numeric field must be padded to its full width, like `07` for July.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StrictWeekDate;
+impl_date_fmt!(StrictWeekDate, [WEEKYEAR, DASH, W, WEEK, DASH, WEEKDAY], "strict_week_date", strict);
+
+/// Format for `week_date_time`.
+/// 
+/// Parses and formats dates like `2015-W27-5T14:55:02.478Z`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct WeekDateTime;
+impl_date_fmt!(WeekDateTime, [WEEKYEAR, DASH, W, WEEK, DASH, WEEKDAY, T, HOUR, COLON, MINUTE, COLON, SECOND, MILLIS, OFFSET], "week_date_time");
+
+/// Format for `strict_week_date_time`.
+/// 
+/// Parses and formats dates like `2015-W27-5T14:55:02.478Z`.
+/// This parses the same dates as [`WeekDateTime`](struct.WeekDateTime.html), but each numeric field must be padded to its full width, like `07` for July.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StrictWeekDateTime;
+impl_date_fmt!(StrictWeekDateTime, [WEEKYEAR, DASH, W, WEEK, DASH, WEEKDAY, T, HOUR, COLON, MINUTE, COLON, SECOND, MILLIS, OFFSET], "strict_week_date_time", strict);
+
+/// Format for `week_date_time_no_millis`.
+/// 
+/// Parses and formats dates like `2015-W27-5T14:55:02Z`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct WeekDateTimeNoMillis;
+impl_date_fmt!(WeekDateTimeNoMillis, [WEEKYEAR, DASH, W, WEEK, DASH, WEEKDAY, T, HOUR, COLON, MINUTE, COLON, SECOND, OFFSET], "week_date_time_no_millis");
+
+/// Format for `strict_week_date_time_no_millis`.
+/// 
+/// Parses and formats dates like `2015-W27-5T14:55:02Z`.
+/// This parses the same dates as [`WeekDateTimeNoMillis`](struct.WeekDateTimeNoMillis.html), but each numeric field must be padded to its full width, like `07` for July.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StrictWeekDateTimeNoMillis;
+impl_date_fmt!(StrictWeekDateTimeNoMillis, [WEEKYEAR, DASH, W, WEEK, DASH, WEEKDAY, T, HOUR, COLON, MINUTE, COLON, SECOND, OFFSET], "strict_week_date_time_no_millis", strict);
+
+/// Format for `weekyear`.
+/// 
+/// Parses and formats dates like `2015`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Weekyear;
+impl_date_fmt!(Weekyear, [WEEKYEAR], "weekyear");
+
+/// Format for `strict_weekyear`.
+/// 
+/// Parses and formats dates like `2015`.
+/// This parses the same dates as [`Weekyear`](struct.Weekyear.html), but each numeric field must be padded to its full width, like `07` for July.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StrictWeekyear;
+impl_date_fmt!(StrictWeekyear, [WEEKYEAR], "strict_weekyear", strict);
+
+/// Format for `weekyear_week`.
+/// 
+/// Parses and formats dates like `2015-W27`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct WeekyearWeek;
+impl_date_fmt!(WeekyearWeek, [WEEKYEAR, DASH, W, WEEK], "weekyear_week");
+
+/// Format for `strict_weekyear_week`.
+/// 
+/// Parses and formats dates like `2015-W27`.
+/// This parses the same dates as [`WeekyearWeek`](struct.WeekyearWeek.html), but each numeric field must be padded to its full width, like `07` for July.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StrictWeekyearWeek;
+impl_date_fmt!(StrictWeekyearWeek, [WEEKYEAR, DASH, W, WEEK], "strict_weekyear_week", strict);
+
+/// Format for `weekyear_week_day`.
+/// 
+/// Parses and formats dates like `2015-W27-5`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct WeekyearWeekDay;
+impl_date_fmt!(WeekyearWeekDay, [WEEKYEAR, DASH, W, WEEK, DASH, WEEKDAY], "weekyear_week_day");
+
+/// Format for `strict_weekyear_week_day`.
+/// 
+/// Parses and formats dates like `2015-W27-5`.
+/// This parses the same dates as [`WeekyearWeekDay`](struct.WeekyearWeekDay.html), but each numeric field must be padded to its full width, like `07` for July.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StrictWeekyearWeekDay;
+impl_date_fmt!(StrictWeekyearWeekDay, [WEEKYEAR, DASH, W, WEEK, DASH, WEEKDAY], "strict_weekyear_week_day", strict);
+
+/// Format for `year`.
+/// 
+/// Parses and formats dates like `2015`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Year;
+impl_date_fmt!(Year, [YEAR], "year");
+
+/// Format for `strict_year`.
+/// 
+/// Parses and formats dates like `2015`.
+/// This parses the same dates as [`Year`](struct.Year.html), but each numeric field must be padded to its full width, like `07` for July.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StrictYear;
+impl_date_fmt!(StrictYear, [YEAR], "strict_year", strict);
+
+/// Format for `year_month`.
+/// 
+/// Parses and formats dates like `2015-07`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct YearMonth;
+impl_date_fmt!(YearMonth, [YEAR, DASH, MONTH], "year_month");
+
+/// Format for `strict_year_month`.
+/// 
+/// Parses and formats dates like `2015-07`.
+/// This parses the same dates as [`YearMonth`](struct.YearMonth.html), but each numeric field must be padded to its full width, like `07` for July.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StrictYearMonth;
+impl_date_fmt!(StrictYearMonth, [YEAR, DASH, MONTH], "strict_year_month", strict);
+
+/// Format for `year_month_day`.
+/// 
+/// Parses and formats dates like `2015-07-03`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct YearMonthDay;
+impl_date_fmt!(YearMonthDay, [YEAR, DASH, MONTH, DASH, DAY], "year_month_day");
+
+/// Format for `strict_year_month_day`.
+/// 
+/// Parses and formats dates like `2015-07-03`.
+/// This parses the same dates as [`YearMonthDay`](struct.YearMonthDay.html), but each numeric field must be padded to its full width, like `07` for July.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StrictYearMonthDay;
+impl_date_fmt!(StrictYearMonthDay, [YEAR, DASH, MONTH, DASH, DAY], "strict_year_month_day", strict);
 
 /// Format for `epoch_millis`.
 /// 
@@ -37,7 +876,7 @@ impl DateFormat for EpochMillis {
 		"epoch_millis"
 	}
 
-	fn parse(date: &str) -> Result<DateTime<UTC>, ParseError> {
+	fn parse(date: &str) -> Result<chrono::DateTime<UTC>, ParseError> {
 		let c = try!(date.chars().next().ok_or("Date input was empty".to_string()));
 		let (secs, msecs) = match (date.len(), c) {
 			//For negative timestamps
@@ -69,10 +908,10 @@ impl DateFormat for EpochMillis {
 		let s = try!(secs.map_err(|e| e.description().to_string()));
 		let m = try!(msecs.map_err(|e| e.description().to_string()));
 
-		Ok(DateTime::from_utc(NaiveDateTime::from_num_seconds_from_unix_epoch(s, m * 1000000), UTC))
+		Ok(chrono::DateTime::from_utc(NaiveDateTime::from_num_seconds_from_unix_epoch(s, m * 1000000), UTC))
 	}
 
-	fn format(date: &DateTime<UTC>) -> String {
+	fn format(date: &chrono::DateTime<UTC>) -> String {
 		let mut fmtd = String::with_capacity(13);
 
 		let sec = date.timestamp();
@@ -105,4 +944,31 @@ impl DateFormat for EpochMillis {
 			fmtd
 		}
 	}
-}
\ No newline at end of file
+}
+
+/// Format for `epoch_second`.
+/// 
+/// Takes a string of seconds since the epoch and converts to a `DateTime`.
+/// 
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct EpochSecond;
+impl DateFormat for EpochSecond {
+	fn fmt<'a>() -> Vec<Item<'a>>{
+		Vec::new()
+	}
+	fn name() -> &'static str {
+		"epoch_second"
+	}
+
+	fn parse(date: &str) -> Result<chrono::DateTime<UTC>, ParseError> {
+		let s = try!(date.parse::<i64>().map_err(|e| e.description().to_string()));
+
+		Ok(chrono::DateTime::from_utc(NaiveDateTime::from_num_seconds_from_unix_epoch(s, 0), UTC))
+	}
+
+	fn format(date: &chrono::DateTime<UTC>) -> String {
+		date.timestamp().to_string()
+	}
+}
@@ -198,8 +198,10 @@ Self: ElasticFieldMapping<T> + Sized + Serialize {
 	}
 
 	/// The date format(s) that can be parsed.
-	fn format() -> &'static str {
-		T::name()
+	///
+	/// Multiple formats are separated by `||`.
+	fn format() -> String {
+		T::names().join("||")
 	}
 
 	/// If `true`, malformed numbers are ignored.
@@ -0,0 +1,498 @@
+use std::{ fmt, i32 };
+use std::marker::PhantomData;
+use chrono;
+use chrono::{ Datelike, Timelike, NaiveDate, UTC };
+use serde;
+use serde::{ Serialize, Deserialize, Serializer, Deserializer };
+use super::{ DT, DefaultFormat };
+use super::date::ElasticDate;
+use super::format::{ DateFormat, ParseError };
+
+/// A unit of time in a date math expression.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DateMathUnit {
+	/// `y`
+	Year,
+	/// `M`
+	Month,
+	/// `w`
+	Week,
+	/// `d`
+	Day,
+	/// `h` or `H`
+	Hour,
+	/// `m`
+	Minute,
+	/// `s`
+	Second
+}
+
+impl DateMathUnit {
+	/// Get the unit as it's written in a date math expression.
+	pub fn as_str(&self) -> &'static str {
+		match *self {
+			DateMathUnit::Year => "y",
+			DateMathUnit::Month => "M",
+			DateMathUnit::Week => "w",
+			DateMathUnit::Day => "d",
+			DateMathUnit::Hour => "h",
+			DateMathUnit::Minute => "m",
+			DateMathUnit::Second => "s"
+		}
+	}
+
+	fn parse(unit: char) -> Result<DateMathUnit, ParseError> {
+		match unit {
+			'y' => Ok(DateMathUnit::Year),
+			'M' => Ok(DateMathUnit::Month),
+			'w' => Ok(DateMathUnit::Week),
+			'd' => Ok(DateMathUnit::Day),
+			'h' | 'H' => Ok(DateMathUnit::Hour),
+			'm' => Ok(DateMathUnit::Minute),
+			's' => Ok(DateMathUnit::Second),
+			c => Err(format!("'{}' is not a valid date math unit", c).into())
+		}
+	}
+}
+
+/// An operation in a date math expression.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DateMathOp {
+	/// Add a number of units, like `+1d`.
+	Add(u32, DateMathUnit),
+	/// Subtract a number of units, like `-1d`.
+	Sub(u32, DateMathUnit),
+	/// Round down to the nearest unit, like `/d`.
+	Round(DateMathUnit)
+}
+
+/// The date that a date math expression is relative to.
+#[derive(Debug, Clone)]
+pub enum DateMathAnchor<F> where
+F: DateFormat {
+	/// The current time, written as `now`.
+	Now,
+	/// A fixed date, written in the format `F` followed by `||`.
+	Date(ElasticDate<F>)
+}
+
+/// An Elasticsearch date math expression, like `now-1d/d` or `2016-01-01||+1M/M`.
+///
+/// Date math can be used in place of a date in range queries, and in index names.
+/// The expression is relative to an anchor, which is either `now` or a date in the format `F`.
+///
+/// # Examples
+///
+/// Build an expression for the start of yesterday:
+///
+/// ```
+/// use elastic_types::date::prelude::*;
+///
+/// let math = DateMath::<DefaultFormat>::now().sub(1, DateMathUnit::Day).round(DateMathUnit::Day);
+///
+/// assert_eq!("now-1d/d", math.to_string());
+/// ```
+///
+/// Parse an expression that's anchored to a date, and evaluate it:
+///
+/// ```
+/// use elastic_types::date::prelude::*;
+///
+/// let math = DateMath::<EpochMillis>::parse("1435935302478||+1M/M").unwrap();
+/// let date = math.eval().unwrap();
+///
+/// assert_eq!((2015, 8, 1), (date.year(), date.month(), date.day()));
+/// ```
+///
+/// Use an expression in a range query:
+///
+/// ```
+/// use elastic_types::date::prelude::*;
+/// use elastic_types::query::RangeQuery;
+///
+/// let query = RangeQuery::new("timestamp").gte(DateMath::<DefaultFormat>::now().sub(1, DateMathUnit::Hour));
+/// ```
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/common-options.html#date-math)
+#[derive(Debug, Clone)]
+pub struct DateMath<F = DefaultFormat> where
+F: DateFormat {
+	anchor: DateMathAnchor<F>,
+	ops: Vec<DateMathOp>
+}
+
+impl <F> DateMath<F> where
+F: DateFormat {
+	/// Create an expression relative to the current time.
+	pub fn now() -> DateMath<F> {
+		DateMath {
+			anchor: DateMathAnchor::Now,
+			ops: Vec::new()
+		}
+	}
+
+	/// Create an expression relative to the given date.
+	pub fn date<I>(date: I) -> DateMath<F> where
+	I: Into<ElasticDate<F>> {
+		DateMath {
+			anchor: DateMathAnchor::Date(date.into()),
+			ops: Vec::new()
+		}
+	}
+
+	/// Add a number of units to the date.
+	pub fn add(mut self, value: u32, unit: DateMathUnit) -> DateMath<F> {
+		self.ops.push(DateMathOp::Add(value, unit));
+		self
+	}
+
+	/// Subtract a number of units from the date.
+	pub fn sub(mut self, value: u32, unit: DateMathUnit) -> DateMath<F> {
+		self.ops.push(DateMathOp::Sub(value, unit));
+		self
+	}
+
+	/// Round the date down to the nearest unit.
+	pub fn round(mut self, unit: DateMathUnit) -> DateMath<F> {
+		self.ops.push(DateMathOp::Round(unit));
+		self
+	}
+
+	/// Get the anchor of the expression.
+	pub fn anchor(&self) -> &DateMathAnchor<F> {
+		&self.anchor
+	}
+
+	/// Get the operations in the expression, in the order they're applied.
+	pub fn ops(&self) -> &[DateMathOp] {
+		&self.ops
+	}
+
+	/// Parse a date math expression.
+	///
+	/// The expression must start with either `now` or a date in the format `F` followed by `||`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use elastic_types::date::prelude::*;
+	///
+	/// let math = DateMath::<DefaultFormat>::parse("now+1h/h").unwrap();
+	///
+	/// assert_eq!(&[DateMathOp::Add(1, DateMathUnit::Hour), DateMathOp::Round(DateMathUnit::Hour)], math.ops());
+	/// ```
+	pub fn parse(expr: &str) -> Result<DateMath<F>, ParseError> {
+		let (anchor, ops) = match expr.find("||") {
+			Some(i) => (DateMathAnchor::Date(try!(ElasticDate::parse(&expr[..i]))), &expr[i + 2..]),
+			None if expr.starts_with("now") => (DateMathAnchor::Now, &expr[3..]),
+			None => return Err(format!("'{}' must start with `now` or a date followed by `||`", expr).into())
+		};
+
+		Ok(DateMath {
+			anchor: anchor,
+			ops: try!(parse_ops(ops))
+		})
+	}
+
+	/// Evaluate the expression relative to the current time.
+	///
+	/// Rounding always rounds down, so `now/d` is the start of the current day.
+	/// Returns an error if the result is outside the range of dates that can be represented, like `now+300000y`.
+	pub fn eval(&self) -> Result<ElasticDate<F>, ParseError> {
+		self.eval_from(chrono::UTC::now())
+	}
+
+	/// Evaluate the expression, using the given date for `now`.
+	///
+	/// Expressions anchored to a date ignore `now`.
+	pub fn eval_from(&self, now: DT) -> Result<ElasticDate<F>, ParseError> {
+		let mut date = match self.anchor {
+			DateMathAnchor::Now => now,
+			DateMathAnchor::Date(ref date) => date_value(date)
+		};
+
+		for op in &self.ops {
+			let result = match *op {
+				DateMathOp::Add(value, unit) => add(date, value as i64, unit),
+				DateMathOp::Sub(value, unit) => add(date, -(value as i64), unit),
+				DateMathOp::Round(unit) => round(date, unit)
+			};
+
+			date = try!(result.ok_or_else(|| format!("'{}' is out of the range of supported dates", self)));
+		}
+
+		Ok(ElasticDate::new(date))
+	}
+}
+
+impl <F> fmt::Display for DateMath<F> where
+F: DateFormat {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self.anchor {
+			DateMathAnchor::Now => try!(write!(f, "now")),
+			DateMathAnchor::Date(ref date) => try!(write!(f, "{}||", date.format()))
+		}
+
+		for op in &self.ops {
+			try!(match *op {
+				DateMathOp::Add(value, unit) => write!(f, "+{}{}", value, unit.as_str()),
+				DateMathOp::Sub(value, unit) => write!(f, "-{}{}", value, unit.as_str()),
+				DateMathOp::Round(unit) => write!(f, "/{}", unit.as_str())
+			});
+		}
+
+		Ok(())
+	}
+}
+
+fn parse_ops(ops: &str) -> Result<Vec<DateMathOp>, ParseError> {
+	let mut parsed = Vec::new();
+	let mut chars = ops.chars().peekable();
+
+	while let Some(c) = chars.next() {
+		match c {
+			'/' => {
+				let unit = try!(chars.next().ok_or("expected a unit to round to".to_owned()));
+				parsed.push(DateMathOp::Round(try!(DateMathUnit::parse(unit))));
+			},
+			'+' | '-' => {
+				let mut num = String::new();
+				while let Some(&d) = chars.peek() {
+					if !d.is_digit(10) {
+						break;
+					}
+
+					num.push(d);
+					let _ = chars.next();
+				}
+
+				//The number is optional, so `+d` is the same as `+1d`
+				let value = match num.len() {
+					0 => 1,
+					_ => try!(num.parse::<u32>().map_err(|_| format!("'{}' is not a valid number", num)))
+				};
+
+				let unit = try!(DateMathUnit::parse(try!(chars.next().ok_or("expected a unit to add or subtract".to_owned()))));
+
+				parsed.push(match c {
+					'+' => DateMathOp::Add(value, unit),
+					_ => DateMathOp::Sub(value, unit)
+				});
+			},
+			c => return Err(format!("unexpected '{}' in date math", c).into())
+		}
+	}
+
+	Ok(parsed)
+}
+
+fn date_value<F>(date: &ElasticDate<F>) -> DT where
+F: DateFormat {
+	chrono::DateTime::from_utc(
+		NaiveDate::from_ymd(date.year(), date.month(), date.day()).and_hms_nano(date.hour(), date.minute(), date.second(), date.nanosecond()),
+		UTC
+	)
+}
+
+//Values are at most `u32::MAX` units, so building the `Duration` can't overflow, but adding it to the date can
+fn add(date: DT, value: i64, unit: DateMathUnit) -> Option<DT> {
+	match unit {
+		DateMathUnit::Year => add_months(date, value * 12),
+		DateMathUnit::Month => add_months(date, value),
+		DateMathUnit::Week => date.checked_add(chrono::Duration::weeks(value)),
+		DateMathUnit::Day => date.checked_add(chrono::Duration::days(value)),
+		DateMathUnit::Hour => date.checked_add(chrono::Duration::hours(value)),
+		DateMathUnit::Minute => date.checked_add(chrono::Duration::minutes(value)),
+		DateMathUnit::Second => date.checked_add(chrono::Duration::seconds(value))
+	}
+}
+
+//Add months, clamping the day to the end of the month, so Jan 31 + 1M is the last day of Feb
+fn add_months(date: DT, months: i64) -> Option<DT> {
+	let total = date.year() as i64 * 12 + date.month0() as i64 + months;
+	let year = if total < 0 { (total - 11) / 12 } else { total / 12 };
+	let month = (total - year * 12) as u32 + 1;
+
+	if year < i32::MIN as i64 || year > i32::MAX as i64 {
+		return None;
+	}
+
+	let year = year as i32;
+
+	//If the first of the month is out of range then so is every other day
+	if NaiveDate::from_ymd_opt(year, month, 1).is_none() {
+		return None;
+	}
+
+	let mut day = date.day();
+	while NaiveDate::from_ymd_opt(year, month, day).is_none() {
+		day -= 1;
+	}
+
+	let naive = NaiveDate::from_ymd(year, month, day);
+
+	Some(chrono::DateTime::from_utc(naive.and_hms_nano(date.hour(), date.minute(), date.second(), date.nanosecond()), UTC))
+}
+
+fn round(date: DT, unit: DateMathUnit) -> Option<DT> {
+	let (y, m, d) = (date.year(), date.month(), date.day());
+
+	let naive = match unit {
+		DateMathUnit::Year => NaiveDate::from_ymd(y, 1, 1).and_hms(0, 0, 0),
+		DateMathUnit::Month => NaiveDate::from_ymd(y, m, 1).and_hms(0, 0, 0),
+		DateMathUnit::Week => {
+			let monday = NaiveDate::from_ymd(y, m, d).checked_sub(chrono::Duration::days(date.weekday().num_days_from_monday() as i64));
+
+			match monday {
+				Some(monday) => monday.and_hms(0, 0, 0),
+				None => return None
+			}
+		},
+		DateMathUnit::Day => NaiveDate::from_ymd(y, m, d).and_hms(0, 0, 0),
+		DateMathUnit::Hour => NaiveDate::from_ymd(y, m, d).and_hms(date.hour(), 0, 0),
+		DateMathUnit::Minute => NaiveDate::from_ymd(y, m, d).and_hms(date.hour(), date.minute(), 0),
+		DateMathUnit::Second => NaiveDate::from_ymd(y, m, d).and_hms(date.hour(), date.minute(), date.second())
+	};
+
+	Some(chrono::DateTime::from_utc(naive, UTC))
+}
+
+impl <F> Serialize for DateMath<F> where
+F: DateFormat {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_str(&self.to_string())
+	}
+}
+
+impl <F> Deserialize for DateMath<F> where
+F: DateFormat {
+	fn deserialize<D>(deserializer: &mut D) -> Result<DateMath<F>, D::Error> where
+	D: Deserializer {
+		#[derive(Default)]
+		struct DateMathVisitor<F> where
+		F: DateFormat {
+			phantom: PhantomData<F>
+		}
+
+		impl <F> serde::de::Visitor for DateMathVisitor<F> where
+		F: DateFormat {
+			type Value = DateMath<F>;
+
+			fn visit_str<E>(&mut self, v: &str) -> Result<DateMath<F>, E> where
+			E: serde::de::Error {
+				DateMath::<F>::parse(v).map_err(|err| serde::de::Error::custom(format!("{}", err)))
+			}
+		}
+
+		deserializer.deserialize(DateMathVisitor::<F>::default())
+	}
+}
+
+/// An index name that uses date math, like `<logstash-{now/d}>`.
+///
+/// Elasticsearch resolves the date math in the name when the request is made,
+/// so the same name can be used to search today's index every day.
+/// Date math index names need to be url encoded before they're used in a request.
+/// The `elastic_hyper` endpoints encode their path parts, so they take the plain name from `to_string`.
+/// Use `url_encoded` when building a url by hand.
+///
+/// # Examples
+///
+/// Search the index for the current month, like `logstash-2016.05`:
+///
+/// ```
+/// use elastic_types::date::prelude::*;
+///
+/// let index = DateMathIndex::new("logstash-", &DateMath::<DefaultFormat>::now().round(DateMathUnit::Month))
+/// 	.format("YYYY.MM");
+///
+/// assert_eq!("<logstash-{now/M{YYYY.MM}}>", index.to_string());
+/// assert_eq!("%3Clogstash-%7Bnow%2FM%7BYYYY.MM%7D%7D%3E", index.url_encoded());
+/// ```
+///
+/// The plain name can be passed as the `index` to any `elastic_hyper` endpoint, which encodes it for you.
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/date-math-index-names.html)
+#[derive(Debug, Clone, PartialEq)]
+pub struct DateMathIndex {
+	prefix: String,
+	math: String,
+	format: Option<String>,
+	time_zone: Option<String>
+}
+
+impl DateMathIndex {
+	/// Create a new date math index name from a static prefix and a date math expression.
+	pub fn new<I, F>(prefix: I, math: &DateMath<F>) -> DateMathIndex where
+	I: Into<String>,
+	F: DateFormat {
+		DateMathIndex {
+			prefix: prefix.into(),
+			math: math.to_string(),
+			format: None,
+			time_zone: None
+		}
+	}
+
+	/// Set the format used to render the date in the index name, like `YYYY.MM.dd`.
+	///
+	/// If no format is given, Elasticsearch uses `YYYY.MM.dd`.
+	pub fn format<I>(mut self, format: I) -> DateMathIndex where
+	I: Into<String> {
+		self.format = Some(format.into());
+		self
+	}
+
+	/// Set the time zone used to render the date in the index name, like `+12:00`.
+	///
+	/// The time zone is only used if a format is also given.
+	pub fn time_zone<I>(mut self, time_zone: I) -> DateMathIndex where
+	I: Into<String> {
+		self.time_zone = Some(time_zone.into());
+		self
+	}
+
+	/// Get the index name with special characters url encoded.
+	pub fn url_encoded(&self) -> String {
+		let mut encoded = String::with_capacity(self.prefix.len() + self.math.len() + 16);
+
+		for c in self.to_string().chars() {
+			match c {
+				'<' => encoded.push_str("%3C"),
+				'>' => encoded.push_str("%3E"),
+				'{' => encoded.push_str("%7B"),
+				'}' => encoded.push_str("%7D"),
+				'|' => encoded.push_str("%7C"),
+				'+' => encoded.push_str("%2B"),
+				'/' => encoded.push_str("%2F"),
+				':' => encoded.push_str("%3A"),
+				',' => encoded.push_str("%2C"),
+				' ' => encoded.push_str("%20"),
+				c => encoded.push(c)
+			}
+		}
+
+		encoded
+	}
+}
+
+impl fmt::Display for DateMathIndex {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		try!(write!(f, "<{}{{{}", self.prefix, self.math));
+
+		if let Some(ref format) = self.format {
+			try!(write!(f, "{{{}", format));
+
+			if let Some(ref time_zone) = self.time_zone {
+				try!(write!(f, "|{}", time_zone));
+			}
+
+			try!(write!(f, "}}"));
+		}
+
+		write!(f, "}}>")
+	}
+}
@@ -3,6 +3,8 @@
 //! Dates in Elasticsearch are exposed as a formatted `string` which can contain a `date` and/or a `time` component.
 //!
 //! All dates used by `elastic_types` are expected to be given in `UTC`, and if no time is supplied, then 12:00am will be used instead.
+//! Formats that include a timezone offset, like `DateTime`, will parse dates with any offset and convert them to `UTC`, but always format them in `UTC`.
+//! Every built-in Elasticsearch format has a corresponding type, including the `strict_` variants, like `StrictDateOptionalTime`.
 //! Where performance is paramount, the `EpochMillis` date format will parse and format dates the fastest.
 //!
 //! Because date conversion needs to be done by the `caller`, the `Format` is a first-class citizen in the `ElasticDate` design.
@@ -42,20 +44,34 @@
 //! # }
 //! ```
 //!
+//! Map with a `date` that accepts multiple formats:
+//!
+//! ```
+//! # use elastic_types::date::prelude::*;
+//! struct MyType {
+//! 	pub field: ElasticDate<FormatOr<BasicDateTime, EpochMillis>>
+//! }
+//! ```
+//!
 //! # Links
 //! - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/date.html)
 
 mod format;
 mod date;
 mod formats;
+mod math;
+mod dynamic;
 
 pub mod mapping;
 pub use self::format::*;
 pub use self::date::*;
 pub use self::formats::*;
+pub use self::math::*;
+pub use self::dynamic::*;
 
 use chrono;
-use chrono::UTC;
+use chrono::{ UTC, Duration, Weekday };
+use chrono::format::{ Parsed, Item, Numeric };
 
 /// A re-export of the `chrono::DateTime` struct with `UTC` timezone.
 pub type DT = chrono::DateTime<UTC>;
@@ -72,4 +88,92 @@ pub mod prelude {
 	pub use super::format::*;
 	pub use super::date::*;
 	pub use super::formats::*;
+	pub use super::math::*;
+	pub use super::dynamic::*;
+}
+
+//Parse a date string using the given `Item`s, filling in any missing components with their defaults
+fn parse_with_items<'a, I>(date: &str, items: I) -> Result<DT, ParseError>
+where I: Iterator<Item = Item<'a>> {
+	let mut parsed = Parsed::new();
+	try!(chrono::format::parse(&mut parsed, date, items));
+
+	//If the parsed result doesn't contain any date, set it to the epoch
+	if parsed.year.is_none() && parsed.isoyear.is_none() {
+		let _ = parsed.set_year(1970);
+	}
+
+	//Fill in any missing parts of a calendar date, like for `year_month`
+	if parsed.year.is_some() && parsed.ordinal.is_none() {
+		if parsed.month.is_none() {
+			let _ = parsed.set_month(1);
+		}
+		if parsed.day.is_none() {
+			let _ = parsed.set_day(1);
+		}
+	}
+
+	//Fill in any missing parts of a week date, like for `weekyear_week`
+	if parsed.isoyear.is_some() {
+		if parsed.isoweek.is_none() {
+			let _ = parsed.set_isoweek(1);
+		}
+		if parsed.weekday.is_none() {
+			parsed.weekday = Some(Weekday::Mon);
+		}
+	}
+
+	//If the parsed result doesn't contain any time, set it to the default
+	if parsed.hour_mod_12.is_none() {
+		let _ = parsed.set_hour(0);
+	}
+	if parsed.minute.is_none() {
+		let _ = parsed.set_minute(0);
+	}
+
+	//Shift the date by its offset, if any, so the result is in UTC
+	let offset = parsed.offset.unwrap_or(0);
+	let dt = try!(parsed.to_naive_datetime_with_offset(offset));
+	let dt = dt - Duration::seconds(offset as i64);
+
+	Ok(chrono::DateTime::from_utc(dt, UTC))
+}
+
+//Parse a date string using the given `Item`s, requiring each numeric field to be padded to its full width like `strict_` formats
+fn parse_fixed_width<'a>(date: &str, name: &str, items: Vec<Item<'a>>) -> Result<DT, ParseError> {
+	if !has_fixed_width_fields(date, &items) {
+		return Err(format!("'{}' doesn't match the format '{}', because each field must be padded to its full width", date, name).into());
+	}
+
+	parse_with_items(date, items.into_iter())
+}
+
+//Check each numeric field in a date string has at least as many digits as its full width.
+//Anything else that doesn't match the format is left for the parser to report.
+fn has_fixed_width_fields(date: &str, items: &[Item]) -> bool {
+	let mut rest = date;
+
+	for item in items {
+		match *item {
+			Item::Numeric(ref numeric, _) => {
+				let width = match *numeric {
+					Numeric::Year | Numeric::IsoYear => 4,
+					Numeric::Ordinal => 3,
+					Numeric::WeekdayFromMon => 1,
+					_ => 2
+				};
+
+				if rest.chars().take(width).filter(|c| c.is_digit(10)).count() < width {
+					return false;
+				}
+
+				rest = &rest[width..];
+			},
+			Item::Literal(lit) if rest.starts_with(lit) => rest = &rest[lit.len()..],
+			//Fractions and offsets don't have a fixed width, and they're always at the end of a format
+			_ => return true
+		}
+	}
+
+	true
 }
@@ -0,0 +1,22 @@
+use serde::{ Serializer, Deserializer };
+use super::GeoPoint;
+
+/// A format used for serialising and deserialising `geo_point`s.
+///
+/// Elasticsearch accepts a `geo_point` as an object, a string, a geohash or an array.
+/// Unlike `DateFormat`, these formats change the _shape_ of the json value,
+/// so the format is specified as two functions that work directly with a `serde` `Serializer` and `Deserializer`.
+pub trait GeoPointFormat
+where Self : Default + Copy {
+	/// Deserialises a `GeoPoint` from the given `Deserializer`.
+	///
+	/// The input must match the shape expected by this format.
+	fn parse<D>(deserializer: &mut D) -> Result<GeoPoint, D::Error> where
+	D: Deserializer;
+
+	/// Serialises a `GeoPoint` with the given `Serializer`.
+	///
+	/// The output is in the shape expected by this format.
+	fn format<S>(point: &GeoPoint, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer;
+}
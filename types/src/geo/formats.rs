@@ -0,0 +1,222 @@
+use serde;
+use serde::{ Serialize, Deserialize, Serializer, Deserializer };
+use super::{ GeoPoint, GeoPointFormat };
+
+/// Format for a `geo_point` as an object with `lat` and `lon` properties.
+///
+/// For example: `{ "lat": 41.12, "lon": -71.34 }`.
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/geo-point.html)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GeoPointObject;
+impl GeoPointFormat for GeoPointObject {
+	fn parse<D>(deserializer: &mut D) -> Result<GeoPoint, D::Error> where
+	D: Deserializer {
+		GeoPoint::deserialize(deserializer)
+	}
+
+	fn format<S>(point: &GeoPoint, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		point.serialize(serializer)
+	}
+}
+
+/// Format for a `geo_point` as a string of the form `"lat,lon"`.
+///
+/// For example: `"41.12,-71.34"`.
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/geo-point.html)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GeoPointString;
+impl GeoPointFormat for GeoPointString {
+	fn parse<D>(deserializer: &mut D) -> Result<GeoPoint, D::Error> where
+	D: Deserializer {
+		struct GeoPointStringVisitor;
+		impl serde::de::Visitor for GeoPointStringVisitor {
+			type Value = GeoPoint;
+
+			fn visit_str<E>(&mut self, v: &str) -> Result<GeoPoint, E> where
+			E: serde::de::Error {
+				let mut parts = v.split(',');
+
+				let (lat, lon) = match (parts.next(), parts.next(), parts.next()) {
+					(Some(lat), Some(lon), None) => (lat.trim(), lon.trim()),
+					_ => return Err(E::invalid_value("expected a geo_point of the form 'lat,lon'"))
+				};
+
+				let lat = try!(lat.parse::<f64>().map_err(|_| E::invalid_value("latitude is not a valid number")));
+				let lon = try!(lon.parse::<f64>().map_err(|_| E::invalid_value("longitude is not a valid number")));
+
+				Ok(GeoPoint::new(lat, lon))
+			}
+		}
+
+		deserializer.deserialize(GeoPointStringVisitor)
+	}
+
+	fn format<S>(point: &GeoPoint, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_str(&format!("{},{}", point.lat, point.lon))
+	}
+}
+
+/// Format for a `geo_point` as a [geohash](https://en.wikipedia.org/wiki/Geohash).
+///
+/// For example: `"drm3btev3e86"`.
+///
+/// Points are formatted with a precision of 12 characters, which is the maximum precision Elasticsearch supports.
+/// Parsing a geohash returns the point at the centre of the hashed cell.
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/geo-point.html)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GeoPointHash;
+impl GeoPointFormat for GeoPointHash {
+	fn parse<D>(deserializer: &mut D) -> Result<GeoPoint, D::Error> where
+	D: Deserializer {
+		struct GeoPointHashVisitor;
+		impl serde::de::Visitor for GeoPointHashVisitor {
+			type Value = GeoPoint;
+
+			fn visit_str<E>(&mut self, v: &str) -> Result<GeoPoint, E> where
+			E: serde::de::Error {
+				geohash::decode(v).map_err(|e| E::invalid_value(&e))
+			}
+		}
+
+		deserializer.deserialize(GeoPointHashVisitor)
+	}
+
+	fn format<S>(point: &GeoPoint, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_str(&geohash::encode(point, geohash::MAX_PRECISION))
+	}
+}
+
+/// Format for a `geo_point` as an array of the form `[lon, lat]`.
+///
+/// For example: `[-71.34, 41.12]`.
+///
+/// Note that the order of the coordinates is reversed compared to the other formats,
+/// to conform with [GeoJSON](http://geojson.org).
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/geo-point.html)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GeoPointArray;
+impl GeoPointFormat for GeoPointArray {
+	fn parse<D>(deserializer: &mut D) -> Result<GeoPoint, D::Error> where
+	D: Deserializer {
+		let coords = try!(<[f64; 2]>::deserialize(deserializer));
+
+		Ok(GeoPoint::new(coords[1], coords[0]))
+	}
+
+	fn format<S>(point: &GeoPoint, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		[point.lon, point.lat].serialize(serializer)
+	}
+}
+
+mod geohash {
+	use std::ascii::AsciiExt;
+	use super::GeoPoint;
+
+	pub const MAX_PRECISION: usize = 12;
+
+	const BASE32: &'static [u8] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+	//Interleave longitude and latitude bits, starting with longitude, 5 bits per character
+	pub fn encode(point: &GeoPoint, precision: usize) -> String {
+		let (mut lat_min, mut lat_max) = (-90f64, 90f64);
+		let (mut lon_min, mut lon_max) = (-180f64, 180f64);
+
+		let mut hash = String::with_capacity(precision);
+		let mut even = true;
+		let mut bit = 0;
+		let mut idx = 0;
+
+		while hash.len() < precision {
+			if even {
+				let mid = (lon_min + lon_max) / 2f64;
+				if point.lon >= mid {
+					idx = (idx << 1) | 1;
+					lon_min = mid;
+				}
+				else {
+					idx = idx << 1;
+					lon_max = mid;
+				}
+			}
+			else {
+				let mid = (lat_min + lat_max) / 2f64;
+				if point.lat >= mid {
+					idx = (idx << 1) | 1;
+					lat_min = mid;
+				}
+				else {
+					idx = idx << 1;
+					lat_max = mid;
+				}
+			}
+
+			even = !even;
+			bit += 1;
+
+			if bit == 5 {
+				hash.push(BASE32[idx] as char);
+				bit = 0;
+				idx = 0;
+			}
+		}
+
+		hash
+	}
+
+	pub fn decode(hash: &str) -> Result<GeoPoint, String> {
+		if hash.len() == 0 {
+			return Err("geohash input was empty".to_owned());
+		}
+
+		let (mut lat_min, mut lat_max) = (-90f64, 90f64);
+		let (mut lon_min, mut lon_max) = (-180f64, 180f64);
+
+		let mut even = true;
+
+		for c in hash.bytes() {
+			let idx = try!(BASE32
+				.iter()
+				.position(|b| *b == c.to_ascii_lowercase())
+				.ok_or(format!("'{}' is not a valid geohash character", c as char)));
+
+			for shift in (0..5).rev() {
+				let bit = (idx >> shift) & 1;
+
+				if even {
+					let mid = (lon_min + lon_max) / 2f64;
+					if bit == 1 {
+						lon_min = mid;
+					}
+					else {
+						lon_max = mid;
+					}
+				}
+				else {
+					let mid = (lat_min + lat_max) / 2f64;
+					if bit == 1 {
+						lat_min = mid;
+					}
+					else {
+						lat_max = mid;
+					}
+				}
+
+				even = !even;
+			}
+		}
+
+		Ok(GeoPoint::new((lat_min + lat_max) / 2f64, (lon_min + lon_max) / 2f64))
+	}
+}
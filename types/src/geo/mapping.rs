@@ -0,0 +1,546 @@
+//! Mapping for the Elasticsearch `geo_point` and `geo_shape` types.
+
+use std::marker::PhantomData;
+use serde;
+use serde::{ Serializer, Serialize };
+use super::GeoPointFormat;
+use ::mapping::{ ElasticFieldMapping, ElasticTypeVisitor };
+
+/// Elasticsearch datatype name.
+pub const GEOPOINT_DATATYPE: &'static str = "geo_point";
+
+/// Elasticsearch datatype name.
+pub const GEOSHAPE_DATATYPE: &'static str = "geo_shape";
+
+/// The base requirements for mapping a `geo_point` type.
+///
+/// # Examples
+///
+/// Define a custom `ElasticGeoPointMapping`:
+///
+/// ## Derive Mapping
+///
+/// Currently, deriving mapping only works for structs that take a generic `GeoPointFormat` parameter.
+///
+/// ```
+/// # #![feature(plugin, custom_derive, custom_attribute)]
+/// # #![plugin(json_str, elastic_types_macros)]
+/// # #[macro_use]
+/// # extern crate elastic_types;
+/// # extern crate serde;
+/// use std::marker::PhantomData;
+/// use elastic_types::mapping::prelude::*;
+/// use elastic_types::geo::prelude::*;
+///
+/// #[derive(Default, Clone, Copy, ElasticGeoPointMapping)]
+/// pub struct MyGeoPointMapping<T: GeoPointFormat> {
+/// 	phantom: PhantomData<T>
+/// }
+/// impl <T: GeoPointFormat> ElasticGeoPointMapping<T> for MyGeoPointMapping<T> {
+/// 	//Overload the mapping functions here
+/// 	fn geohash() -> Option<bool> {
+///			Some(true)
+///		}
+/// }
+/// # fn main() {}
+/// ```
+///
+/// This will produce the following mapping:
+///
+/// ```
+/// # #![feature(plugin, custom_derive, custom_attribute)]
+/// # #![plugin(json_str, elastic_types_macros)]
+/// # #[macro_use]
+/// # extern crate elastic_types;
+/// # extern crate serde;
+/// # extern crate serde_json;
+/// # use std::marker::PhantomData;
+/// # use elastic_types::mapping::prelude::*;
+/// # use elastic_types::geo::prelude::*;
+/// # #[derive(Default, Clone, Copy, ElasticGeoPointMapping)]
+/// # pub struct MyGeoPointMapping<T: GeoPointFormat = GeoPointObject> {
+/// # 	phantom: PhantomData<T>
+/// # }
+/// # impl <T: GeoPointFormat> ElasticGeoPointMapping<T> for MyGeoPointMapping<T> {
+/// # 	//Overload the mapping functions here
+/// # 	fn geohash() -> Option<bool> {
+///	# 		Some(true)
+///	# 	}
+/// # }
+/// # fn main() {
+/// # let mapping = serde_json::to_string(&MyGeoPointMapping::<GeoPointObject>::default()).unwrap();
+/// # let json = json_str!(
+/// {
+///     "type": "geo_point",
+/// 	"geohash": true
+/// }
+/// # );
+/// # assert_eq!(json, mapping);
+/// # }
+/// ```
+///
+/// ## Manually
+///
+/// Define a geo point mapping that's valid for any `GeoPointFormat` (equivalent to the auto derive example):
+///
+/// ```
+/// # extern crate serde;
+/// # extern crate elastic_types;
+/// # use std::marker::PhantomData;
+/// # fn main() {
+/// use elastic_types::mapping::prelude::*;
+/// use elastic_types::geo::prelude::*;
+///
+/// #[derive(Default, Clone)]
+/// pub struct MyGeoPointMapping<T: GeoPointFormat> {
+/// 	phantom: PhantomData<T>
+/// }
+///
+/// impl <T: GeoPointFormat> ElasticFieldMapping<T> for MyGeoPointMapping<T> {
+/// 	type Visitor = ElasticGeoPointMappingVisitor<T, MyGeoPointMapping<T>>;
+///
+/// 	fn data_type() -> &'static str {
+/// 		GEOPOINT_DATATYPE
+/// 	}
+/// }
+///
+/// impl <T: GeoPointFormat> ElasticGeoPointMapping<T> for MyGeoPointMapping<T> {
+/// 	//Overload the mapping functions here
+/// 	fn geohash() -> Option<bool> {
+///			Some(true)
+///		}
+/// }
+///
+/// impl <T: GeoPointFormat> serde::Serialize for MyGeoPointMapping<T> {
+/// 	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+/// 	where S: serde::Serializer {
+/// 		serializer.serialize_struct("mapping", Self::get_visitor())
+/// 	}
+/// }
+/// # }
+/// ```
+pub trait ElasticGeoPointMapping<T> where
+T: GeoPointFormat,
+Self: ElasticFieldMapping<T> + Sized + Serialize {
+	/// Should the `geo_point` also be indexed as a geohash in the `.geohash` sub-field? Defaults to `false`,
+	/// unless `geohash_prefix` is `true`.
+	fn geohash() -> Option<bool> {
+		None
+	}
+
+	/// The maximum length of the geohash to use for the `geohash` and `geohash_prefix` options.
+	fn geohash_precision() -> Option<GeoHashPrecision> {
+		None
+	}
+
+	/// Should the `geo_point` also be indexed as a geohash plus all of its prefixes? Defaults to `false`.
+	fn geohash_prefix() -> Option<bool> {
+		None
+	}
+
+	/// If `true`, malformed geo points are ignored.
+	/// If `false` (default), malformed geo points throw an exception and reject the whole document.
+	fn ignore_malformed() -> Option<bool> {
+		None
+	}
+
+	/// Should the `geo_point` also be indexed as `.lat` and `.lon` sub-fields? Accepts `true` and `false` (default).
+	fn lat_lon() -> Option<bool> {
+		None
+	}
+
+	/// Controls the number of extra terms that are indexed for each `lat_lon` point. Defaults to 16.
+	fn precision_step() -> Option<i32> {
+		None
+	}
+}
+
+/// Default mapping for `ElasticGeoPoint`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DefaultGeoPointMapping<T> where
+T: GeoPointFormat {
+	phantom: PhantomData<T>
+}
+impl <T> ElasticGeoPointMapping<T> for DefaultGeoPointMapping<T> where
+T: GeoPointFormat { }
+
+impl_geo_point_mapping!(DefaultGeoPointMapping<T>);
+
+/// Visitor for a `geo_point` map.
+#[derive(Debug, PartialEq)]
+pub struct ElasticGeoPointMappingVisitor<F, T> where
+F: GeoPointFormat,
+T: ElasticGeoPointMapping<F> {
+	phantom_f: PhantomData<F>,
+	phantom_t: PhantomData<T>
+}
+
+impl <F, T> ElasticTypeVisitor for ElasticGeoPointMappingVisitor<F, T> where
+F: GeoPointFormat,
+T: ElasticGeoPointMapping<F> {
+	fn new() -> Self {
+		ElasticGeoPointMappingVisitor {
+			phantom_f: PhantomData,
+			phantom_t: PhantomData
+		}
+	}
+}
+impl <F, T> serde::ser::MapVisitor for ElasticGeoPointMappingVisitor<F, T> where
+F: GeoPointFormat,
+T: ElasticGeoPointMapping<F> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error>
+	where S: Serializer {
+		try!(serializer.serialize_struct_elt("type", T::data_type()));
+
+		if let Some(geohash) = T::geohash() {
+			try!(serializer.serialize_struct_elt("geohash", geohash));
+		};
+
+		if let Some(geohash_precision) = T::geohash_precision() {
+			try!(serializer.serialize_struct_elt("geohash_precision", geohash_precision));
+		};
+
+		if let Some(geohash_prefix) = T::geohash_prefix() {
+			try!(serializer.serialize_struct_elt("geohash_prefix", geohash_prefix));
+		};
+
+		if let Some(ignore_malformed) = T::ignore_malformed() {
+			try!(serializer.serialize_struct_elt("ignore_malformed", ignore_malformed));
+		};
+
+		if let Some(lat_lon) = T::lat_lon() {
+			try!(serializer.serialize_struct_elt("lat_lon", lat_lon));
+		};
+
+		if let Some(precision_step) = T::precision_step() {
+			try!(serializer.serialize_struct_elt("precision_step", precision_step));
+		};
+
+		Ok(None)
+	}
+}
+
+/// The base requirements for mapping a `geo_shape` type.
+///
+/// Custom mappings can be defined by implementing `ElasticGeoShapeMapping`.
+///
+/// # Examples
+///
+/// Define a custom `ElasticGeoShapeMapping`:
+///
+/// ## Derive Mapping
+///
+/// ```
+/// # #![feature(plugin, custom_derive, custom_attribute)]
+/// # #![plugin(json_str, elastic_types_macros)]
+/// # #[macro_use]
+/// # extern crate elastic_types;
+/// # extern crate serde;
+/// use elastic_types::mapping::prelude::*;
+/// use elastic_types::geo::prelude::*;
+///
+/// #[derive(Debug, Clone, Default, ElasticGeoShapeMapping)]
+/// pub struct MyGeoShapeMapping;
+/// impl ElasticGeoShapeMapping for MyGeoShapeMapping {
+/// 	//Overload the mapping functions here
+/// 	fn tree() -> Option<Tree> {
+///			Some(Tree::QuadTree)
+///		}
+/// }
+/// # fn main() {}
+/// ```
+///
+/// This will produce the following mapping:
+///
+/// ```
+/// # #![feature(plugin, custom_derive, custom_attribute)]
+/// # #![plugin(json_str, elastic_types_macros)]
+/// # #[macro_use]
+/// # extern crate elastic_types;
+/// # extern crate serde;
+/// # extern crate serde_json;
+/// # use elastic_types::mapping::prelude::*;
+/// # use elastic_types::geo::prelude::*;
+/// # #[derive(Debug, Clone, Default, ElasticGeoShapeMapping)]
+/// # pub struct MyGeoShapeMapping;
+/// # impl ElasticGeoShapeMapping for MyGeoShapeMapping {
+/// # 	//Overload the mapping functions here
+/// # 	fn tree() -> Option<Tree> {
+///	# 		Some(Tree::QuadTree)
+///	# 	}
+/// # }
+/// # fn main() {
+/// # let mapping = serde_json::to_string(&MyGeoShapeMapping).unwrap();
+/// # let json = json_str!(
+/// {
+///     "type": "geo_shape",
+/// 	"tree": "quadtree"
+/// }
+/// # );
+/// # assert_eq!(json, mapping);
+/// # }
+/// ```
+///
+/// ## Manually
+///
+/// ```
+/// # extern crate serde;
+/// # extern crate elastic_types;
+/// # fn main() {
+/// use elastic_types::mapping::prelude::*;
+/// use elastic_types::geo::prelude::*;
+///
+/// #[derive(Debug, Clone, Default)]
+/// pub struct MyGeoShapeMapping;
+/// impl ElasticGeoShapeMapping for MyGeoShapeMapping {
+/// 	//Overload the mapping functions here
+/// 	fn tree() -> Option<Tree> {
+///			Some(Tree::QuadTree)
+///		}
+/// }
+///
+/// //We also need to implement the base `ElasticFieldMapping` and `serde::Serialize` for our custom mapping type
+/// impl ElasticFieldMapping<()> for MyGeoShapeMapping {
+/// 	type Visitor = ElasticGeoShapeMappingVisitor<MyGeoShapeMapping>;
+///
+/// 	fn data_type() -> &'static str {
+/// 		GEOSHAPE_DATATYPE
+/// 	}
+/// }
+///
+/// impl serde::Serialize for MyGeoShapeMapping {
+/// 	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+/// 	where S: serde::Serializer {
+/// 		serializer.serialize_struct("mapping", Self::get_visitor())
+/// 	}
+/// }
+/// # }
+/// ```
+pub trait ElasticGeoShapeMapping where
+Self: ElasticFieldMapping<()> + Sized + Serialize {
+	/// Name of the PrefixTree implementation to be used: `geohash` for `GeohashPrefixTree` and `quadtree` for `QuadPrefixTree`.
+	/// Defaults to `geohash`.
+	fn tree() -> Option<Tree> {
+		None
+	}
+
+	/// This parameter may be used instead of `tree_levels` to set an appropriate value for the `tree_levels` parameter.
+	/// The value specifies the desired precision and Elasticsearch will calculate the best `tree_levels` value to honor this precision.
+	fn precision() -> Option<Distance> {
+		None
+	}
+
+	/// Maximum number of layers to be used by the PrefixTree.
+	/// This can be used to control the precision of shape representations and therefore how many terms are indexed.
+	/// Defaults to the default value of the chosen PrefixTree implementation.
+	fn tree_levels() -> Option<i32> {
+		None
+	}
+
+	/// The approach for how to represent shapes at indexing and search time.
+	/// It also influences the capabilities available so it is recommended to let Elasticsearch set this parameter automatically.
+	fn strategy() -> Option<Strategy> {
+		None
+	}
+
+	/// Used as a hint to the PrefixTree about how precise it should be.
+	/// Defaults to `0.025` (2.5%) with `0.5` as the maximum supported value.
+	fn distance_error_pct() -> Option<f32> {
+		None
+	}
+
+	/// Optionally define how to interpret vertex order for polygons / multipolygons.
+	/// Defaults to `ccw`, the OGC standard.
+	fn orientation() -> Option<Orientation> {
+		None
+	}
+
+	/// Setting this option to `true` configures the field for points only, which improves performance
+	/// for geo point indexing and queries. Defaults to `false`.
+	fn points_only() -> Option<bool> {
+		None
+	}
+}
+
+/// Default mapping for `geojson::Geometry`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DefaultGeoShapeMapping;
+impl ElasticGeoShapeMapping for DefaultGeoShapeMapping { }
+
+impl_geo_shape_mapping!(DefaultGeoShapeMapping);
+
+/// Base visitor for serialising geo shape mappings.
+#[derive(Debug, PartialEq)]
+pub struct ElasticGeoShapeMappingVisitor<T> where T: ElasticGeoShapeMapping {
+	phantom: PhantomData<T>
+}
+
+impl <T> ElasticTypeVisitor for ElasticGeoShapeMappingVisitor<T> where
+T: ElasticGeoShapeMapping {
+	fn new() -> Self {
+		ElasticGeoShapeMappingVisitor {
+			phantom: PhantomData
+		}
+	}
+}
+impl <T> serde::ser::MapVisitor for ElasticGeoShapeMappingVisitor<T> where
+T: ElasticGeoShapeMapping {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error>
+	where S: serde::Serializer {
+		try!(serializer.serialize_struct_elt("type", T::data_type()));
+
+		if let Some(tree) = T::tree() {
+			try!(serializer.serialize_struct_elt("tree", tree));
+		}
+
+		if let Some(precision) = T::precision() {
+			try!(serializer.serialize_struct_elt("precision", precision));
+		}
+
+		if let Some(tree_levels) = T::tree_levels() {
+			try!(serializer.serialize_struct_elt("tree_levels", tree_levels));
+		}
+
+		if let Some(strategy) = T::strategy() {
+			try!(serializer.serialize_struct_elt("strategy", strategy));
+		}
+
+		if let Some(distance_error_pct) = T::distance_error_pct() {
+			try!(serializer.serialize_struct_elt("distance_error_pct", distance_error_pct));
+		}
+
+		if let Some(orientation) = T::orientation() {
+			try!(serializer.serialize_struct_elt("orientation", orientation));
+		}
+
+		if let Some(points_only) = T::points_only() {
+			try!(serializer.serialize_struct_elt("points_only", points_only));
+		}
+
+		Ok(None)
+	}
+}
+
+/// A unit of measure for a `Distance`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DistanceUnit {
+	/// `in` or `inch`.
+	Inches,
+	/// `yd` or `yards`.
+	Yards,
+	/// `mi` or `miles`.
+	Miles,
+	/// `km` or `kilometers`.
+	Kilometers,
+	/// `m` or `meters`.
+	Meters,
+	/// `cm` or `centimeters`.
+	Centimeters,
+	/// `mm` or `millimeters`.
+	Millimeters
+}
+
+/// A distance value, like `50m`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Distance(pub f64, pub DistanceUnit);
+
+impl serde::Serialize for Distance {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+	where S: Serializer
+	{
+		let unit = match self.1 {
+			DistanceUnit::Inches => "in",
+			DistanceUnit::Yards => "yd",
+			DistanceUnit::Miles => "mi",
+			DistanceUnit::Kilometers => "km",
+			DistanceUnit::Meters => "m",
+			DistanceUnit::Centimeters => "cm",
+			DistanceUnit::Millimeters => "mm"
+		};
+
+		serializer.serialize_str(&format!("{}{}", self.0, unit))
+	}
+}
+
+/// The maximum length of a geohash.
+///
+/// This can be expressed as a number of characters, or a distance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GeoHashPrecision {
+	/// A geohash length, between `1` and `12`.
+	Levels(u8),
+	/// A distance, like `1km`.
+	Distance(Distance)
+}
+
+impl serde::Serialize for GeoHashPrecision {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+	where S: Serializer
+	{
+		match *self {
+			GeoHashPrecision::Levels(levels) => serializer.serialize_u8(levels),
+			GeoHashPrecision::Distance(ref distance) => distance.serialize(serializer)
+		}
+	}
+}
+
+/// Name of the PrefixTree implementation to be used.
+#[derive(Debug, Clone, Copy)]
+pub enum Tree {
+	/// For `GeohashPrefixTree`.
+	Geohash,
+	/// For `QuadPrefixTree`.
+	QuadTree
+}
+
+impl serde::Serialize for Tree {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+	where S: Serializer
+	{
+		serializer.serialize_str(match *self {
+			Tree::Geohash => "geohash",
+			Tree::QuadTree => "quadtree"
+		})
+	}
+}
+
+/// The strategy defines the approach for how to represent shapes at indexing and search time.
+#[derive(Debug, Clone, Copy)]
+pub enum Strategy {
+	/// Recursive strategy supports all shape types.
+	Recursive,
+	/// Term strategy supports point types only.
+	Term
+}
+
+impl serde::Serialize for Strategy {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+	where S: Serializer
+	{
+		serializer.serialize_str(match *self {
+			Strategy::Recursive => "recursive",
+			Strategy::Term => "term"
+		})
+	}
+}
+
+/// This parameter defines one of two coordinate system rules (Right-hand or Left-hand)
+/// each of which can be specified in a few different ways.
+#[derive(Debug, Clone, Copy)]
+pub enum Orientation {
+	/// For `left`, `cw` and `clockwise`.
+	Clockwise,
+	/// For `right`, `ccw` and `counterclockwise`.
+	CounterClockwise
+}
+
+impl serde::Serialize for Orientation {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+	where S: Serializer
+	{
+		serializer.serialize_str(match *self {
+			Orientation::Clockwise => "cw",
+			Orientation::CounterClockwise => "ccw"
+		})
+	}
+}
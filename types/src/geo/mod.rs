@@ -0,0 +1,90 @@
+//! Implementation of the Elasticsearch `geo` types.
+//!
+//! Elasticsearch supports two kinds of geo data:
+//!
+//! - `geo_point`: a single latitude/longitude pair, represented by `ElasticGeoPoint`
+//! - `geo_shape`: an arbitrary [GeoJSON](http://geojson.org) geometry, represented by `ElasticGeoShape`
+//!
+//! Like dates, geo points can be written in a number of different formats.
+//! The format is provided as a generic parameter to `ElasticGeoPoint`, so that points are
+//! serialised and deserialised the same way they're indexed.
+//!
+//! # Examples
+//!
+//! For defining your own geo mappings, see [geo_point mapping details](mapping/trait.ElasticGeoPointMapping.html#derive-mapping)
+//! and [geo_shape mapping details](mapping/trait.ElasticGeoShapeMapping.html#derive-mapping).
+//!
+//! Map with a default `geo_point`:
+//!
+//! ```
+//! # use elastic_types::geo::prelude::*;
+//! struct MyType {
+//! 	pub field: ElasticGeoPoint<DefaultGeoPointFormat>
+//! }
+//! ```
+//!
+//! Map with a default `geo_shape`:
+//!
+//! ```
+//! # extern crate geojson;
+//! # extern crate elastic_types;
+//! # fn main() {
+//! use geojson::Geometry;
+//!
+//! struct MyType {
+//! 	pub field: Geometry
+//! }
+//! # }
+//! ```
+//!
+//! Map with a custom `geo_point`:
+//!
+//! ```
+//! # #![feature(plugin, custom_derive)]
+//! # #![plugin(json_str, elastic_types_macros)]
+//! # extern crate serde;
+//! # extern crate elastic_types;
+//! # use std::marker::PhantomData;
+//! # fn main() {
+//! # use elastic_types::mapping::prelude::*;
+//! # use elastic_types::geo::prelude::*;
+//! # #[derive(Default, Clone, ElasticGeoPointMapping)]
+//! # pub struct MyGeoPointMapping<T: GeoPointFormat = GeoPointString> {
+//! 	phantom: PhantomData<T>
+//! }
+//! # impl <T: GeoPointFormat> ElasticGeoPointMapping<T> for MyGeoPointMapping<T> { }
+//! struct MyType {
+//! 	pub field: ElasticGeoPoint<GeoPointString, MyGeoPointMapping>
+//! }
+//! # }
+//! ```
+//!
+//! # Links
+//! - [Elasticsearch Doc: geo_point](https://www.elastic.co/guide/en/elasticsearch/reference/current/geo-point.html)
+//! - [Elasticsearch Doc: geo_shape](https://www.elastic.co/guide/en/elasticsearch/reference/current/geo-shape.html)
+
+mod format;
+mod formats;
+mod point;
+mod shape;
+
+pub mod mapping;
+pub use self::format::*;
+pub use self::formats::*;
+pub use self::point::*;
+pub use self::shape::*;
+
+/// The default `geo_point` format.
+pub type DefaultGeoPointFormat = GeoPointObject;
+
+pub mod prelude {
+	//! Includes non-mapping types for the `geo_point` and `geo_shape` types.
+	//!
+	//! This is a convenience module to make it easy to build mappings for multiple types without too many `use` statements.
+
+	pub use super::DefaultGeoPointFormat;
+	pub use super::format::*;
+	pub use super::formats::*;
+	pub use super::point::*;
+	pub use super::shape::*;
+}
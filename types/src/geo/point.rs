@@ -0,0 +1,186 @@
+use std::marker::PhantomData;
+use serde::{ Serialize, Deserialize, Serializer, Deserializer };
+use super::DefaultGeoPointFormat;
+use super::format::GeoPointFormat;
+use super::mapping::{ ElasticGeoPointMapping, DefaultGeoPointMapping };
+use ::mapping::{ ElasticFieldMapping, ElasticType };
+
+/// A latitude/longitude coordinate.
+///
+/// This is the value wrapped by an `ElasticGeoPoint`.
+/// It can also be used directly as a field, in which case it's mapped as a `geo_point` with the default mapping
+/// and serialised as an object.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GeoPoint {
+	/// The latitude of the point, between `-90` and `90`.
+	pub lat: f64,
+	/// The longitude of the point, between `-180` and `180`.
+	pub lon: f64
+}
+
+impl GeoPoint {
+	/// Creates a new `GeoPoint` from a latitude and longitude.
+	pub fn new(lat: f64, lon: f64) -> GeoPoint {
+		GeoPoint {
+			lat: lat,
+			lon: lon
+		}
+	}
+}
+
+impl ElasticType<DefaultGeoPointMapping<DefaultGeoPointFormat>, DefaultGeoPointFormat> for GeoPoint {
+
+}
+
+/// An Elasticsearch `geo_point` type with a format.
+///
+/// The [format](format/index.html) is provided as a generic parameter.
+/// This struct wraps up a `GeoPoint`, which is a simple latitude/longitude pair.
+///
+/// # Examples
+///
+/// Defining a geo point using the default format:
+///
+/// ```
+/// use elastic_types::geo::{ ElasticGeoPoint, GeoPoint, DefaultGeoPointFormat };
+///
+/// let point: ElasticGeoPoint<DefaultGeoPointFormat> = ElasticGeoPoint::new(GeoPoint::new(41.12, -71.34));
+/// ```
+///
+/// Defining a geo point using a named format:
+///
+/// ```
+/// use elastic_types::geo::{ ElasticGeoPoint, GeoPoint, GeoPointHash };
+///
+/// let point = ElasticGeoPoint::<GeoPointHash>::new(GeoPoint::new(41.12, -71.34));
+/// ```
+///
+/// Accessing the values of a geo point:
+///
+/// ```
+/// use elastic_types::geo::{ ElasticGeoPoint, GeoPoint, DefaultGeoPointFormat };
+///
+/// let point = ElasticGeoPoint::<DefaultGeoPointFormat>::new(GeoPoint::new(41.12, -71.34));
+///
+/// //eg: 41.12,-71.34
+/// println!("{},{}", point.lat(), point.lon());
+/// ```
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/geo-point.html)
+#[derive(Debug, Clone, Default)]
+pub struct ElasticGeoPoint<F, T = DefaultGeoPointMapping<F>> where
+F: GeoPointFormat,
+T: ElasticFieldMapping<F> + ElasticGeoPointMapping<F> {
+	value: GeoPoint,
+	phantom_f: PhantomData<F>,
+	phantom_t: PhantomData<T>
+}
+
+impl <F, T> ElasticGeoPoint<F, T> where
+F: GeoPointFormat,
+T: ElasticFieldMapping<F> + ElasticGeoPointMapping<F> {
+	/// Creates a new `ElasticGeoPoint` from the given `GeoPoint`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use elastic_types::geo::{ ElasticGeoPoint, GeoPoint, DefaultGeoPointFormat };
+	///
+	/// let point: ElasticGeoPoint<DefaultGeoPointFormat> = ElasticGeoPoint::new(GeoPoint::new(41.12, -71.34));
+	/// ```
+	pub fn new<I>(point: I) -> ElasticGeoPoint<F, T> where I: Into<GeoPoint> {
+		ElasticGeoPoint {
+			value: point.into(),
+			phantom_f: PhantomData,
+			phantom_t: PhantomData
+		}
+	}
+
+	/// Get the value of the point.
+	pub fn get(&self) -> GeoPoint {
+		self.value
+	}
+
+	/// Set the value of the point.
+	pub fn set<I>(&mut self, point: I) where I: Into<GeoPoint> {
+		self.value = point.into()
+	}
+
+	/// Get the latitude of the point.
+	pub fn lat(&self) -> f64 {
+		self.value.lat
+	}
+
+	/// Get the longitude of the point.
+	pub fn lon(&self) -> f64 {
+		self.value.lon
+	}
+
+	/// Change the format/mapping of this geo point.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use elastic_types::geo::{ ElasticGeoPoint, GeoPoint, GeoPointObject, GeoPointArray };
+	///
+	/// //Get a point formatted as an object
+	/// let point: ElasticGeoPoint<GeoPointObject> = ElasticGeoPoint::new(GeoPoint::new(41.12, -71.34));
+	///
+	/// //Change the format to an array
+	/// let otherpoint: ElasticGeoPoint<GeoPointArray> = point.into();
+	/// ```
+	pub fn into<FInto, TInto>(self) -> ElasticGeoPoint<FInto, TInto> where
+	FInto: GeoPointFormat,
+	TInto: ElasticFieldMapping<FInto> + ElasticGeoPointMapping<FInto> {
+		ElasticGeoPoint::<FInto, TInto>::new(self.value)
+	}
+}
+
+impl <F, T> ElasticType<T, F> for ElasticGeoPoint<F, T> where
+F: GeoPointFormat,
+T: ElasticFieldMapping<F> + ElasticGeoPointMapping<F> {
+
+}
+
+impl <F, T> From<GeoPoint> for ElasticGeoPoint<F, T> where
+F: GeoPointFormat,
+T: ElasticFieldMapping<F> + ElasticGeoPointMapping<F> {
+	fn from(point: GeoPoint) -> ElasticGeoPoint<F, T> {
+		ElasticGeoPoint::<F, T>::new(point)
+	}
+}
+
+impl <F, T> PartialEq<GeoPoint> for ElasticGeoPoint<F, T> where
+F: GeoPointFormat,
+T: ElasticFieldMapping<F> + ElasticGeoPointMapping<F> {
+	fn eq(&self, other: &GeoPoint) -> bool {
+		PartialEq::eq(&self.value, other)
+	}
+
+	fn ne(&self, other: &GeoPoint) -> bool {
+		PartialEq::ne(&self.value, other)
+	}
+}
+
+//Serialize geo point
+impl <F, T> Serialize for ElasticGeoPoint<F, T> where
+F: GeoPointFormat,
+T: ElasticFieldMapping<F> + ElasticGeoPointMapping<F> {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		F::format(&self.value, serializer)
+	}
+}
+
+//Deserialize geo point
+impl <F, T> Deserialize for ElasticGeoPoint<F, T> where
+F: GeoPointFormat,
+T: ElasticFieldMapping<F> + ElasticGeoPointMapping<F> {
+	fn deserialize<D>(deserializer: &mut D) -> Result<ElasticGeoPoint<F, T>, D::Error> where
+	D: Deserializer {
+		let point = try!(F::parse(deserializer));
+
+		Ok(ElasticGeoPoint::<F, T>::new(point))
+	}
+}
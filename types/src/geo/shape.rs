@@ -0,0 +1,118 @@
+use std::marker::PhantomData;
+use geojson::Geometry;
+use serde::{ Serialize, Deserialize, Serializer, Deserializer };
+use super::mapping::{ ElasticGeoShapeMapping, DefaultGeoShapeMapping };
+use ::mapping::{ ElasticFieldMapping, ElasticType };
+
+impl ElasticType<DefaultGeoShapeMapping, ()> for Geometry { }
+
+/// An Elasticsearch `geo_shape` with a mapping.
+///
+/// Where the mapping isn't custom, you can use the `geojson::Geometry` type instead.
+///
+/// # Examples
+///
+/// Defining a geo shape with a mapping:
+///
+/// ```
+/// # extern crate geojson;
+/// # extern crate elastic_types;
+/// # fn main() {
+/// use geojson::{ Geometry, Value };
+/// use elastic_types::geo::mapping::DefaultGeoShapeMapping;
+/// use elastic_types::geo::ElasticGeoShape;
+///
+/// let shape = ElasticGeoShape::<DefaultGeoShapeMapping>::new(
+/// 	Geometry::new(Value::Point(vec![ -71.34, 41.12 ]))
+/// );
+/// # }
+/// ```
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/geo-shape.html)
+#[derive(Debug, Clone)]
+pub struct ElasticGeoShape<T> where
+T: ElasticFieldMapping<()> + ElasticGeoShapeMapping {
+	value: Geometry,
+	phantom: PhantomData<T>
+}
+impl <T> ElasticGeoShape<T> where
+T: ElasticFieldMapping<()> + ElasticGeoShapeMapping {
+	/// Creates a new `ElasticGeoShape` with the given mapping.
+	///
+	/// # Examples
+	///
+	/// Create a new `ElasticGeoShape` from a `geojson::Geometry`:
+	///
+	/// ```
+	/// # extern crate geojson;
+	/// # extern crate elastic_types;
+	/// # fn main() {
+	/// use geojson::{ Geometry, Value };
+	/// use elastic_types::geo::mapping::DefaultGeoShapeMapping;
+	/// use elastic_types::geo::ElasticGeoShape;
+	///
+	/// let shape = ElasticGeoShape::<DefaultGeoShapeMapping>::new(
+	/// 	Geometry::new(Value::LineString(vec![ vec![ -71.34, 41.12 ], vec![ -71.30, 41.15 ] ]))
+	/// );
+	/// # }
+	/// ```
+	pub fn new<I>(geometry: I) -> ElasticGeoShape<T> where I: Into<Geometry> {
+		ElasticGeoShape {
+			value: geometry.into(),
+			phantom: PhantomData
+		}
+	}
+
+	/// Get the value of the geo shape.
+	pub fn get(&self) -> &Geometry {
+		&self.value
+	}
+
+	/// Set the value of the geo shape.
+	pub fn set<I>(&mut self, geometry: I) where I: Into<Geometry> {
+		self.value = geometry.into()
+	}
+
+	/// Change the mapping of this geo shape.
+	pub fn into<TInto>(self) -> ElasticGeoShape<TInto> where
+	TInto: ElasticFieldMapping<()> + ElasticGeoShapeMapping {
+		ElasticGeoShape::<TInto>::new(self.value)
+	}
+}
+
+impl <T> ElasticType<T, ()> for ElasticGeoShape<T> where
+T: ElasticFieldMapping<()> + ElasticGeoShapeMapping { }
+
+impl From<Geometry> for ElasticGeoShape<DefaultGeoShapeMapping> {
+	fn from(geometry: Geometry) -> Self {
+		ElasticGeoShape::new(geometry)
+	}
+}
+
+impl <T> AsRef<Geometry> for ElasticGeoShape<T> where
+T: ElasticFieldMapping<()> + ElasticGeoShapeMapping {
+	fn as_ref(&self) -> &Geometry {
+		&self.value
+	}
+}
+
+//Serialize elastic geo shape
+impl <T> Serialize for ElasticGeoShape<T> where
+T: ElasticFieldMapping<()> + ElasticGeoShapeMapping {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		self.value.serialize(serializer)
+	}
+}
+
+//Deserialize elastic geo shape
+impl <T> Deserialize for ElasticGeoShape<T> where
+T: ElasticFieldMapping<()> + ElasticGeoShapeMapping {
+	fn deserialize<D>(deserializer: &mut D) -> Result<ElasticGeoShape<T>, D::Error> where
+	D: Deserializer {
+		let geometry = try!(Geometry::deserialize(deserializer));
+
+		Ok(ElasticGeoShape::<T>::new(geometry))
+	}
+}
@@ -0,0 +1,525 @@
+use std::collections::BTreeMap;
+use serde;
+use serde::{ Serialize, Serializer, Deserialize, Deserializer };
+use serde_json;
+use serde_json::Value;
+
+/// The analysis components defined for an index.
+///
+/// Each component is registered under a name, which can then be referenced by other components and by field mappings,
+/// like `ElasticStringMapping::analyzer`.
+///
+/// # Examples
+///
+/// Define an autocomplete analyzer that uses a custom tokenizer and char filter:
+///
+/// ```
+/// use elastic_types::index::*;
+///
+/// let analysis = Analysis::new()
+/// 	.analyzer("autocomplete", Analyzer::custom("autocomplete")
+/// 		.char_filter("strip_dashes")
+/// 		.filter("lowercase")
+/// 	)
+/// 	.tokenizer("autocomplete", Tokenizer::edge_ngram(2, 10))
+/// 	.char_filter("strip_dashes", CharFilter::pattern_replace("-", ""));
+/// ```
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis.html)
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Analysis {
+	analyzers: BTreeMap<String, Analyzer>,
+	tokenizers: BTreeMap<String, Tokenizer>,
+	filters: BTreeMap<String, TokenFilter>,
+	char_filters: BTreeMap<String, CharFilter>,
+	normalizers: BTreeMap<String, Normalizer>
+}
+
+impl Analysis {
+	/// Create a new, empty set of analysis components.
+	pub fn new() -> Analysis {
+		Analysis::default()
+	}
+
+	/// Add an analyzer.
+	pub fn analyzer<I>(mut self, name: I, analyzer: Analyzer) -> Analysis where
+	I: Into<String> {
+		self.analyzers.insert(name.into(), analyzer);
+		self
+	}
+
+	/// Add a tokenizer.
+	pub fn tokenizer<I>(mut self, name: I, tokenizer: Tokenizer) -> Analysis where
+	I: Into<String> {
+		self.tokenizers.insert(name.into(), tokenizer);
+		self
+	}
+
+	/// Add a token filter.
+	pub fn filter<I>(mut self, name: I, filter: TokenFilter) -> Analysis where
+	I: Into<String> {
+		self.filters.insert(name.into(), filter);
+		self
+	}
+
+	/// Add a char filter.
+	pub fn char_filter<I>(mut self, name: I, char_filter: CharFilter) -> Analysis where
+	I: Into<String> {
+		self.char_filters.insert(name.into(), char_filter);
+		self
+	}
+
+	/// Add a normalizer.
+	pub fn normalizer<I>(mut self, name: I, normalizer: Normalizer) -> Analysis where
+	I: Into<String> {
+		self.normalizers.insert(name.into(), normalizer);
+		self
+	}
+}
+
+impl Serialize for Analysis {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_struct("analysis", AnalysisVisitor {
+			analysis: self
+		})
+	}
+}
+
+struct AnalysisVisitor<'a> {
+	analysis: &'a Analysis
+}
+
+impl <'a> serde::ser::MapVisitor for AnalysisVisitor<'a> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> where
+	S: Serializer {
+		if !self.analysis.analyzers.is_empty() {
+			try!(serializer.serialize_struct_elt("analyzer", &self.analysis.analyzers));
+		}
+
+		if !self.analysis.tokenizers.is_empty() {
+			try!(serializer.serialize_struct_elt("tokenizer", &self.analysis.tokenizers));
+		}
+
+		if !self.analysis.filters.is_empty() {
+			try!(serializer.serialize_struct_elt("filter", &self.analysis.filters));
+		}
+
+		if !self.analysis.char_filters.is_empty() {
+			try!(serializer.serialize_struct_elt("char_filter", &self.analysis.char_filters));
+		}
+
+		if !self.analysis.normalizers.is_empty() {
+			try!(serializer.serialize_struct_elt("normalizer", &self.analysis.normalizers));
+		}
+
+		Ok(None)
+	}
+}
+
+impl Deserialize for Analysis {
+	fn deserialize<D>(deserializer: &mut D) -> Result<Analysis, D::Error> where
+	D: Deserializer {
+		let mut map: BTreeMap<String, BTreeMap<String, BTreeMap<String, Value>>> = try!(Deserialize::deserialize(deserializer));
+
+		let analysis = Analysis {
+			analyzers: try!(Component::from_maps(map.remove("analyzer"), Some("custom")).map_err(|e| serde::de::Error::custom(e)))
+				.into_iter().map(|(name, c)| (name, Analyzer { component: c })).collect(),
+			tokenizers: try!(Component::from_maps(map.remove("tokenizer"), None).map_err(|e| serde::de::Error::custom(e)))
+				.into_iter().map(|(name, c)| (name, Tokenizer { component: c })).collect(),
+			filters: try!(Component::from_maps(map.remove("filter"), None).map_err(|e| serde::de::Error::custom(e)))
+				.into_iter().map(|(name, c)| (name, TokenFilter { component: c })).collect(),
+			char_filters: try!(Component::from_maps(map.remove("char_filter"), None).map_err(|e| serde::de::Error::custom(e)))
+				.into_iter().map(|(name, c)| (name, CharFilter { component: c })).collect(),
+			normalizers: try!(Component::from_maps(map.remove("normalizer"), Some("custom")).map_err(|e| serde::de::Error::custom(e)))
+				.into_iter().map(|(name, c)| (name, Normalizer { component: c })).collect()
+		};
+
+		Ok(analysis)
+	}
+}
+
+/// An analyzer, which converts text into tokens for indexing and searching.
+///
+/// A `custom` analyzer combines a tokenizer with any number of char filters and token filters.
+/// Built-in analyzers, like `standard` or `english`, can be configured with `new` and `param`.
+///
+/// # Examples
+///
+/// ```
+/// use elastic_types::index::Analyzer;
+///
+/// let custom = Analyzer::custom("whitespace").filter("lowercase");
+///
+/// let standard = Analyzer::new("standard").param("stopwords", "_english_");
+/// ```
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-analyzers.html)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Analyzer {
+	component: Component
+}
+
+impl Analyzer {
+	/// Create an analyzer of the given type, like `standard` or `pattern`.
+	pub fn new<I>(ty: I) -> Analyzer where
+	I: Into<String> {
+		Analyzer {
+			component: Component::new(ty)
+		}
+	}
+
+	/// Create a `custom` analyzer with the given tokenizer.
+	pub fn custom<I>(tokenizer: I) -> Analyzer where
+	I: Into<String> {
+		let tokenizer: String = tokenizer.into();
+
+		Analyzer::new("custom").param("tokenizer", tokenizer)
+	}
+
+	/// Add a token filter to the analyzer by name.
+	///
+	/// Filters are applied in the order they're added.
+	pub fn filter<I>(mut self, filter: I) -> Analyzer where
+	I: Into<String> {
+		self.component.push("filter", filter.into());
+		self
+	}
+
+	/// Add a char filter to the analyzer by name.
+	///
+	/// Char filters are applied in the order they're added.
+	pub fn char_filter<I>(mut self, char_filter: I) -> Analyzer where
+	I: Into<String> {
+		self.component.push("char_filter", char_filter.into());
+		self
+	}
+
+	/// Set a parameter of the analyzer.
+	pub fn param<I, V>(mut self, name: I, value: V) -> Analyzer where
+	I: Into<String>,
+	V: Serialize {
+		self.component.set(name, value);
+		self
+	}
+}
+
+impl Serialize for Analyzer {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		self.component.serialize(serializer)
+	}
+}
+
+/// A normalizer, which is like an analyzer that produces a single token.
+///
+/// Normalizers can only use char filters and token filters that work on a character-by-character basis, like `lowercase`.
+///
+/// # Examples
+///
+/// ```
+/// use elastic_types::index::Normalizer;
+///
+/// let normalizer = Normalizer::custom().filter("lowercase").filter("asciifolding");
+/// ```
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-normalizers.html)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Normalizer {
+	component: Component
+}
+
+impl Normalizer {
+	/// Create a `custom` normalizer.
+	pub fn custom() -> Normalizer {
+		Normalizer {
+			component: Component::new("custom")
+		}
+	}
+
+	/// Add a token filter to the normalizer by name.
+	pub fn filter<I>(mut self, filter: I) -> Normalizer where
+	I: Into<String> {
+		self.component.push("filter", filter.into());
+		self
+	}
+
+	/// Add a char filter to the normalizer by name.
+	pub fn char_filter<I>(mut self, char_filter: I) -> Normalizer where
+	I: Into<String> {
+		self.component.push("char_filter", char_filter.into());
+		self
+	}
+}
+
+impl Serialize for Normalizer {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		self.component.serialize(serializer)
+	}
+}
+
+/// A tokenizer, which splits text into tokens.
+///
+/// # Examples
+///
+/// ```
+/// use elastic_types::index::Tokenizer;
+///
+/// let ngram = Tokenizer::ngram(2, 3).param("token_chars", vec![ "letter", "digit" ]);
+///
+/// let pattern = Tokenizer::new("pattern").param("pattern", ",");
+/// ```
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-tokenizers.html)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tokenizer {
+	component: Component
+}
+
+impl Tokenizer {
+	/// Create a tokenizer of the given type, like `standard` or `pattern`.
+	pub fn new<I>(ty: I) -> Tokenizer where
+	I: Into<String> {
+		Tokenizer {
+			component: Component::new(ty)
+		}
+	}
+
+	/// Create an `ngram` tokenizer.
+	pub fn ngram(min_gram: u32, max_gram: u32) -> Tokenizer {
+		Tokenizer::new("ngram")
+			.param("min_gram", min_gram)
+			.param("max_gram", max_gram)
+	}
+
+	/// Create an `edge_ngram` tokenizer.
+	pub fn edge_ngram(min_gram: u32, max_gram: u32) -> Tokenizer {
+		Tokenizer::new("edge_ngram")
+			.param("min_gram", min_gram)
+			.param("max_gram", max_gram)
+	}
+
+	/// Set a parameter of the tokenizer.
+	pub fn param<I, V>(mut self, name: I, value: V) -> Tokenizer where
+	I: Into<String>,
+	V: Serialize {
+		self.component.set(name, value);
+		self
+	}
+}
+
+impl Serialize for Tokenizer {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		self.component.serialize(serializer)
+	}
+}
+
+/// A token filter, which adds, removes or changes tokens.
+///
+/// # Examples
+///
+/// ```
+/// use elastic_types::index::TokenFilter;
+///
+/// let stop = TokenFilter::stop(vec![ "and", "the" ]);
+///
+/// let synonyms = TokenFilter::synonym(vec![ "i-pod, ipod", "universe, cosmos" ]);
+/// ```
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-tokenfilters.html)
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenFilter {
+	component: Component
+}
+
+impl TokenFilter {
+	/// Create a token filter of the given type, like `stop` or `snowball`.
+	pub fn new<I>(ty: I) -> TokenFilter where
+	I: Into<String> {
+		TokenFilter {
+			component: Component::new(ty)
+		}
+	}
+
+	/// Create a `stop` token filter with the given stopwords.
+	pub fn stop<V, S>(stopwords: V) -> TokenFilter where
+	V: IntoIterator<Item = S>,
+	S: Into<String> {
+		let stopwords: Vec<String> = stopwords.into_iter().map(|s| s.into()).collect();
+
+		TokenFilter::new("stop").param("stopwords", stopwords)
+	}
+
+	/// Create a `synonym` token filter with the given synonym rules, like `"i-pod, ipod"`.
+	pub fn synonym<V, S>(synonyms: V) -> TokenFilter where
+	V: IntoIterator<Item = S>,
+	S: Into<String> {
+		let synonyms: Vec<String> = synonyms.into_iter().map(|s| s.into()).collect();
+
+		TokenFilter::new("synonym").param("synonyms", synonyms)
+	}
+
+	/// Set a parameter of the token filter.
+	pub fn param<I, V>(mut self, name: I, value: V) -> TokenFilter where
+	I: Into<String>,
+	V: Serialize {
+		self.component.set(name, value);
+		self
+	}
+}
+
+impl Serialize for TokenFilter {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		self.component.serialize(serializer)
+	}
+}
+
+/// A char filter, which changes the text before it's tokenised.
+///
+/// # Examples
+///
+/// ```
+/// use elastic_types::index::CharFilter;
+///
+/// let mapping = CharFilter::mapping(vec![ ":) => _happy_", ":( => _sad_" ]);
+///
+/// let html = CharFilter::new("html_strip");
+/// ```
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-charfilters.html)
+#[derive(Debug, Clone, PartialEq)]
+pub struct CharFilter {
+	component: Component
+}
+
+impl CharFilter {
+	/// Create a char filter of the given type, like `html_strip`.
+	pub fn new<I>(ty: I) -> CharFilter where
+	I: Into<String> {
+		CharFilter {
+			component: Component::new(ty)
+		}
+	}
+
+	/// Create a `mapping` char filter with the given mappings, like `"ph => f"`.
+	pub fn mapping<V, S>(mappings: V) -> CharFilter where
+	V: IntoIterator<Item = S>,
+	S: Into<String> {
+		let mappings: Vec<String> = mappings.into_iter().map(|s| s.into()).collect();
+
+		CharFilter::new("mapping").param("mappings", mappings)
+	}
+
+	/// Create a `pattern_replace` char filter.
+	pub fn pattern_replace<P, R>(pattern: P, replacement: R) -> CharFilter where
+	P: Into<String>,
+	R: Into<String> {
+		let (pattern, replacement): (String, String) = (pattern.into(), replacement.into());
+
+		CharFilter::new("pattern_replace")
+			.param("pattern", pattern)
+			.param("replacement", replacement)
+	}
+
+	/// Set a parameter of the char filter.
+	pub fn param<I, V>(mut self, name: I, value: V) -> CharFilter where
+	I: Into<String>,
+	V: Serialize {
+		self.component.set(name, value);
+		self
+	}
+}
+
+impl Serialize for CharFilter {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		self.component.serialize(serializer)
+	}
+}
+
+//The common shape of analysis components; a `type` along with some parameters
+#[derive(Debug, Clone, PartialEq)]
+struct Component {
+	ty: String,
+	params: BTreeMap<String, Value>
+}
+
+impl Component {
+	fn new<I>(ty: I) -> Component where
+	I: Into<String> {
+		Component {
+			ty: ty.into(),
+			params: BTreeMap::new()
+		}
+	}
+
+	fn set<I, V>(&mut self, name: I, value: V) where
+	I: Into<String>,
+	V: Serialize {
+		self.params.insert(name.into(), serde_json::to_value(&value));
+	}
+
+	//Parse components by name, using the default type if one isn't given
+	fn from_maps(maps: Option<BTreeMap<String, BTreeMap<String, Value>>>, default_ty: Option<&str>) -> Result<BTreeMap<String, Component>, String> {
+		let mut components = BTreeMap::new();
+
+		for (name, mut params) in maps.unwrap_or_else(BTreeMap::new) {
+			let ty = match (params.remove("type"), default_ty) {
+				(Some(Value::String(ty)), _) => ty,
+				(None, Some(ty)) => ty.to_owned(),
+				_ => return Err(format!("expected '{}' to have a type", name))
+			};
+
+			components.insert(name, Component {
+				ty: ty,
+				params: params
+			});
+		}
+
+		Ok(components)
+	}
+
+	//Append a value to a list parameter, like the `filter` of an analyzer
+	fn push(&mut self, name: &str, value: String) {
+		let values = self.params.entry(name.to_owned()).or_insert(Value::Array(Vec::new()));
+
+		if let Value::Array(ref mut values) = *values {
+			values.push(Value::String(value));
+		}
+	}
+}
+
+impl Serialize for Component {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_map(ComponentVisitor {
+			component: self
+		})
+	}
+}
+
+struct ComponentVisitor<'a> {
+	component: &'a Component
+}
+
+impl <'a> serde::ser::MapVisitor for ComponentVisitor<'a> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> where
+	S: Serializer {
+		try!(serializer.serialize_map_elt("type", &self.component.ty));
+
+		for (name, value) in &self.component.params {
+			try!(serializer.serialize_map_elt(name, value));
+		}
+
+		Ok(None)
+	}
+}
@@ -0,0 +1,324 @@
+//! Elasticsearch index settings and analysis types.
+//!
+//! Index settings are built up from structs that serialise to the json expected by the
+//! [Create Index API](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-create-index.html).
+//! The mappings for user-defined types can be added alongside the settings, so the whole index can be created in a single request.
+//!
+//! # Examples
+//!
+//! Build the body for creating an index with a custom analyzer:
+//!
+//! ```
+//! use elastic_types::index::*;
+//!
+//! let settings = IndexSettings::new()
+//! 	.number_of_shards(3)
+//! 	.number_of_replicas(1)
+//! 	.refresh_interval("30s")
+//! 	.analysis(Analysis::new()
+//! 		.analyzer("title", Analyzer::custom("standard")
+//! 			.filter("lowercase")
+//! 			.filter("title_stop")
+//! 		)
+//! 		.filter("title_stop", TokenFilter::stop(vec![ "the", "a" ]))
+//! 	);
+//!
+//! let body = CreateIndex::new()
+//! 	.settings(settings)
+//! 	.to_string()
+//! 	.unwrap();
+//! ```
+//!
+//! The resulting string can be passed as the body to `indices::create` in `elastic_hyper`.
+//!
+//! Settings and mappings can also be put in an `IndexTemplate`, which is applied to any new index that matches its pattern.
+//!
+//! # Links
+//! - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/index-modules.html)
+
+mod analysis;
+mod template;
+
+pub use self::analysis::*;
+pub use self::template::*;
+
+use std::collections::BTreeMap;
+use serde;
+use serde::{ Serialize, Serializer, Deserialize, Deserializer };
+use serde_json;
+use serde_json::Value;
+use ::mapping::ElasticFieldMapping;
+use ::mappers::TypeMapper;
+use ::object::ElasticUserTypeMapping;
+
+/// The body of a request to create an index, with its settings and type mappings.
+///
+/// # Examples
+///
+/// Create an index with the mapping for a user-defined type:
+///
+/// ```
+/// # #![feature(plugin, custom_derive)]
+/// # #![plugin(json_str, elastic_types_macros)]
+/// # #[macro_use]
+/// # extern crate elastic_types;
+/// # extern crate serde;
+/// # use serde::{ Serialize, Deserialize };
+/// # use elastic_types::mapping::prelude::*;
+/// # #[derive(Default, Clone, Serialize, Deserialize, ElasticType)]
+/// # pub struct MyType {
+/// # 	pub my_string: String,
+/// # 	pub my_num: i32
+/// # }
+/// # impl serde::Serialize for MyType {
+/// # 	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where S: serde::Serializer {
+/// # 		unimplemented!()
+/// # 	}
+/// # }
+/// # impl serde::Deserialize for MyType {
+/// # 	 fn deserialize<D>(deserializer: &mut D) -> Result<Self, D::Error> where D: serde::Deserializer {
+/// # 		unimplemented!()
+/// # 	}
+/// # }
+/// # fn main() {
+/// use elastic_types::index::*;
+///
+/// let body = CreateIndex::new()
+/// 	.settings(IndexSettings::new().number_of_shards(1))
+/// 	.mapping(MyTypeMapping).unwrap()
+/// 	.to_string()
+/// 	.unwrap();
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CreateIndex {
+	settings: Option<IndexSettings>,
+	mappings: BTreeMap<String, Value>
+}
+
+impl CreateIndex {
+	/// Create a new, empty create index body.
+	pub fn new() -> CreateIndex {
+		CreateIndex::default()
+	}
+
+	/// Set the settings for the index.
+	pub fn settings(mut self, settings: IndexSettings) -> CreateIndex {
+		self.settings = Some(settings);
+		self
+	}
+
+	/// Add the mapping for a user-defined type.
+	///
+	/// The mapping is built with `TypeMapper`, and is keyed by the name of the type.
+	pub fn mapping<M>(mut self, mapping: M) -> Result<CreateIndex, serde_json::Error> where
+	M: ElasticUserTypeMapping {
+		let value = try!(TypeMapper::to_value(mapping));
+
+		self.mappings.insert(<M as ElasticFieldMapping<()>>::name().to_owned(), value);
+		Ok(self)
+	}
+
+	/// Add a mapping for a type as raw json.
+	pub fn mapping_value<I>(mut self, name: I, mapping: Value) -> CreateIndex where
+	I: Into<String> {
+		self.mappings.insert(name.into(), mapping);
+		self
+	}
+
+	/// Serialise the create index body as json.
+	pub fn to_string(&self) -> Result<String, serde_json::Error> {
+		serde_json::to_string(self)
+	}
+}
+
+impl Serialize for CreateIndex {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_struct("create_index", CreateIndexVisitor {
+			index: self
+		})
+	}
+}
+
+struct CreateIndexVisitor<'a> {
+	index: &'a CreateIndex
+}
+
+impl <'a> serde::ser::MapVisitor for CreateIndexVisitor<'a> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> where
+	S: Serializer {
+		if let Some(ref settings) = self.index.settings {
+			try!(serializer.serialize_struct_elt("settings", settings));
+		}
+
+		if !self.index.mappings.is_empty() {
+			try!(serializer.serialize_struct_elt("mappings", &self.index.mappings));
+		}
+
+		Ok(None)
+	}
+}
+
+/// The settings for an index.
+///
+/// Settings can also be deserialised from the responses of the cluster, where they're nested in an `index` object and their values are strings.
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/index-modules.html#index-modules-settings)
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct IndexSettings {
+	/// The number of primary shards.
+	pub number_of_shards: Option<u32>,
+	/// The number of replicas each primary shard has.
+	pub number_of_replicas: Option<u32>,
+	/// How often to refresh the index.
+	pub refresh_interval: Option<String>,
+	/// The analysis components available to fields in the index.
+	pub analysis: Option<Analysis>,
+	/// Any other index settings.
+	pub params: BTreeMap<String, Value>
+}
+
+impl IndexSettings {
+	/// Create a new, empty set of index settings.
+	pub fn new() -> IndexSettings {
+		IndexSettings::default()
+	}
+
+	/// Set the number of primary shards.
+	///
+	/// This can only be set when the index is created.
+	pub fn number_of_shards(mut self, shards: u32) -> IndexSettings {
+		self.number_of_shards = Some(shards);
+		self
+	}
+
+	/// Set the number of replicas each primary shard has.
+	pub fn number_of_replicas(mut self, replicas: u32) -> IndexSettings {
+		self.number_of_replicas = Some(replicas);
+		self
+	}
+
+	/// Set how often to refresh the index, like `1s` or `-1` to disable refreshing.
+	pub fn refresh_interval<I>(mut self, interval: I) -> IndexSettings where
+	I: Into<String> {
+		self.refresh_interval = Some(interval.into());
+		self
+	}
+
+	/// Set the analysis components available to fields in the index.
+	pub fn analysis(mut self, analysis: Analysis) -> IndexSettings {
+		self.analysis = Some(analysis);
+		self
+	}
+
+	/// Set any other index setting, like `max_result_window`.
+	pub fn param<I, V>(mut self, name: I, value: V) -> IndexSettings where
+	I: Into<String>,
+	V: Serialize {
+		self.params.insert(name.into(), serde_json::to_value(&value));
+		self
+	}
+
+	/// Serialise the settings as json.
+	///
+	/// This can be used as the body of the Update Index Settings API.
+	pub fn to_string(&self) -> Result<String, serde_json::Error> {
+		serde_json::to_string(self)
+	}
+}
+
+impl Serialize for IndexSettings {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_map(IndexSettingsVisitor {
+			settings: self
+		})
+	}
+}
+
+struct IndexSettingsVisitor<'a> {
+	settings: &'a IndexSettings
+}
+
+impl <'a> serde::ser::MapVisitor for IndexSettingsVisitor<'a> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> where
+	S: Serializer {
+		if let Some(shards) = self.settings.number_of_shards {
+			try!(serializer.serialize_map_elt("number_of_shards", shards));
+		}
+
+		if let Some(replicas) = self.settings.number_of_replicas {
+			try!(serializer.serialize_map_elt("number_of_replicas", replicas));
+		}
+
+		if let Some(ref interval) = self.settings.refresh_interval {
+			try!(serializer.serialize_map_elt("refresh_interval", interval));
+		}
+
+		for (name, value) in &self.settings.params {
+			try!(serializer.serialize_map_elt(name, value));
+		}
+
+		if let Some(ref analysis) = self.settings.analysis {
+			try!(serializer.serialize_map_elt("analysis", analysis));
+		}
+
+		Ok(None)
+	}
+}
+
+impl Deserialize for IndexSettings {
+	fn deserialize<D>(deserializer: &mut D) -> Result<IndexSettings, D::Error> where
+	D: Deserializer {
+		let map: BTreeMap<String, Value> = try!(Deserialize::deserialize(deserializer));
+
+		IndexSettings::from_map(map).map_err(|e| serde::de::Error::custom(e))
+	}
+}
+
+impl IndexSettings {
+	fn from_map(map: BTreeMap<String, Value>) -> Result<IndexSettings, String> {
+		//Settings from the cluster are nested in `index`, or prefixed with `index.`
+		let mut settings = BTreeMap::new();
+		for (name, value) in map {
+			match (name.as_str(), value) {
+				("index", Value::Object(index)) => settings.extend(index),
+				(name, value) if name.starts_with("index.") => {
+					settings.insert(name["index.".len()..].to_owned(), value);
+				},
+				(name, value) => {
+					settings.insert(name.to_owned(), value);
+				}
+			}
+		}
+
+		let analysis = match settings.remove("analysis") {
+			Some(analysis) => Some(try!(serde_json::from_value(analysis).map_err(|e| e.to_string()))),
+			None => None
+		};
+
+		Ok(IndexSettings {
+			number_of_shards: try!(take_u32(&mut settings, "number_of_shards")),
+			number_of_replicas: try!(take_u32(&mut settings, "number_of_replicas")),
+			refresh_interval: match settings.remove("refresh_interval") {
+				Some(Value::String(interval)) => Some(interval),
+				Some(interval) => Some(interval.to_string()),
+				None => None
+			},
+			analysis: analysis,
+			params: settings
+		})
+	}
+}
+
+//Numeric settings from the cluster are returned as strings
+fn take_u32(map: &mut BTreeMap<String, Value>, key: &str) -> Result<Option<u32>, String> {
+	match map.remove(key) {
+		Some(Value::U64(n)) => Ok(Some(n as u32)),
+		Some(Value::String(ref n)) => n.parse().map(Some).map_err(|_| format!("expected '{}' to be a number, but got '{}'", key, n)),
+		Some(Value::Null) | None => Ok(None),
+		Some(_) => Err(format!("expected '{}' to be a number", key))
+	}
+}
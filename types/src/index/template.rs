@@ -0,0 +1,397 @@
+use std::collections::BTreeMap;
+use serde;
+use serde::{ Serialize, Serializer, Deserialize, Deserializer };
+use serde_json;
+use serde_json::Value;
+use ::mapping::ElasticFieldMapping;
+use ::mappers::TypeMapper;
+use ::object::ElasticUserTypeMapping;
+use super::IndexSettings;
+
+/// An index template, which is applied to new indices with names that match its pattern.
+///
+/// Templates are useful for time-based indices, where a new index is created for each day or month.
+/// The mappings for user-defined types can be added to the template, so each new index gets the same mappings
+/// as the Rust types that are indexed into it.
+///
+/// A template can also be deserialised from the response of the Get Index Template API,
+/// using `IndexTemplates` to get each template by name.
+///
+/// # Examples
+///
+/// Build the body for putting a template with the mapping for a user-defined type:
+///
+/// ```
+/// # #![feature(plugin, custom_derive)]
+/// # #![plugin(json_str, elastic_types_macros)]
+/// # #[macro_use]
+/// # extern crate elastic_types;
+/// # extern crate serde;
+/// # use serde::{ Serialize, Deserialize };
+/// # use elastic_types::mapping::prelude::*;
+/// # #[derive(Default, Clone, Serialize, Deserialize, ElasticType)]
+/// # pub struct MyType {
+/// # 	pub my_string: String,
+/// # 	pub my_num: i32
+/// # }
+/// # impl serde::Serialize for MyType {
+/// # 	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where S: serde::Serializer {
+/// # 		unimplemented!()
+/// # 	}
+/// # }
+/// # impl serde::Deserialize for MyType {
+/// # 	 fn deserialize<D>(deserializer: &mut D) -> Result<Self, D::Error> where D: serde::Deserializer {
+/// # 		unimplemented!()
+/// # 	}
+/// # }
+/// # fn main() {
+/// use elastic_types::index::*;
+///
+/// let body = IndexTemplate::new("logs-*")
+/// 	.order(1)
+/// 	.settings(IndexSettings::new().number_of_shards(1))
+/// 	.alias("logs", Alias::new())
+/// 	.mapping(MyTypeMapping).unwrap()
+/// 	.to_string()
+/// 	.unwrap();
+/// # }
+/// ```
+///
+/// The resulting string can be passed as the body to `indices::put_template` in `elastic_hyper`.
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-templates.html)
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct IndexTemplate {
+	/// The pattern matched against the names of new indices, like `logs-*`.
+	pub template: String,
+	/// The order to merge this template in when more than one matches an index.
+	///
+	/// Templates with a higher order are merged last, so override the settings of lower ones.
+	pub order: Option<i32>,
+	/// The settings for matching indices.
+	pub settings: Option<IndexSettings>,
+	/// The aliases to add to matching indices.
+	pub aliases: BTreeMap<String, Alias>,
+	/// The type mappings for matching indices, keyed by type name.
+	pub mappings: BTreeMap<String, Value>
+}
+
+impl IndexTemplate {
+	/// Create a new template for indices matching the given pattern.
+	pub fn new<I>(template: I) -> IndexTemplate where
+	I: Into<String> {
+		IndexTemplate {
+			template: template.into(),
+			..IndexTemplate::default()
+		}
+	}
+
+	/// Set the order of the template.
+	pub fn order(mut self, order: i32) -> IndexTemplate {
+		self.order = Some(order);
+		self
+	}
+
+	/// Set the settings for matching indices.
+	pub fn settings(mut self, settings: IndexSettings) -> IndexTemplate {
+		self.settings = Some(settings);
+		self
+	}
+
+	/// Add an alias to matching indices.
+	pub fn alias<I>(mut self, name: I, alias: Alias) -> IndexTemplate where
+	I: Into<String> {
+		self.aliases.insert(name.into(), alias);
+		self
+	}
+
+	/// Add the mapping for a user-defined type.
+	///
+	/// The mapping is built with `TypeMapper`, and is keyed by the name of the type.
+	pub fn mapping<M>(mut self, mapping: M) -> Result<IndexTemplate, serde_json::Error> where
+	M: ElasticUserTypeMapping {
+		let value = try!(TypeMapper::to_value(mapping));
+
+		self.mappings.insert(<M as ElasticFieldMapping<()>>::name().to_owned(), value);
+		Ok(self)
+	}
+
+	/// Add a mapping for a type as raw json.
+	pub fn mapping_value<I>(mut self, name: I, mapping: Value) -> IndexTemplate where
+	I: Into<String> {
+		self.mappings.insert(name.into(), mapping);
+		self
+	}
+
+	/// Serialise the template as json.
+	pub fn to_string(&self) -> Result<String, serde_json::Error> {
+		serde_json::to_string(self)
+	}
+
+	/// Parse a template from a json value.
+	pub fn from_value(value: Value) -> Result<IndexTemplate, String> {
+		let mut map = match value {
+			Value::Object(map) => map,
+			_ => return Err("expected the template to be an object".to_owned())
+		};
+
+		let template = match map.remove("template") {
+			Some(Value::String(template)) => template,
+			_ => return Err("expected the template to have a 'template' pattern".to_owned())
+		};
+
+		let order = match map.remove("order") {
+			Some(Value::U64(order)) => Some(order as i32),
+			Some(Value::I64(order)) => Some(order as i32),
+			Some(Value::Null) | None => None,
+			Some(_) => return Err("expected 'order' to be a number".to_owned())
+		};
+
+		//The cluster returns empty settings, aliases and mappings for templates that don't set them
+		let settings = match map.remove("settings") {
+			Some(Value::Object(ref settings)) if settings.is_empty() => None,
+			Some(Value::Null) | None => None,
+			Some(settings) => Some(try!(serde_json::from_value(settings).map_err(|e| e.to_string())))
+		};
+
+		let aliases = match map.remove("aliases") {
+			Some(Value::Object(aliases)) => {
+				let mut parsed = BTreeMap::new();
+				for (name, alias) in aliases {
+					parsed.insert(name, try!(Alias::from_value(alias)));
+				}
+
+				parsed
+			},
+			Some(Value::Null) | None => BTreeMap::new(),
+			Some(_) => return Err("expected 'aliases' to be an object".to_owned())
+		};
+
+		let mappings = match map.remove("mappings") {
+			Some(Value::Object(mappings)) => mappings,
+			Some(Value::Null) | None => BTreeMap::new(),
+			Some(_) => return Err("expected 'mappings' to be an object".to_owned())
+		};
+
+		Ok(IndexTemplate {
+			template: template,
+			order: order,
+			settings: settings,
+			aliases: aliases,
+			mappings: mappings
+		})
+	}
+}
+
+impl Serialize for IndexTemplate {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_struct("index_template", IndexTemplateVisitor {
+			template: self
+		})
+	}
+}
+
+struct IndexTemplateVisitor<'a> {
+	template: &'a IndexTemplate
+}
+
+impl <'a> serde::ser::MapVisitor for IndexTemplateVisitor<'a> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> where
+	S: Serializer {
+		try!(serializer.serialize_struct_elt("template", &self.template.template));
+
+		if let Some(order) = self.template.order {
+			try!(serializer.serialize_struct_elt("order", order));
+		}
+
+		if let Some(ref settings) = self.template.settings {
+			try!(serializer.serialize_struct_elt("settings", settings));
+		}
+
+		if !self.template.aliases.is_empty() {
+			try!(serializer.serialize_struct_elt("aliases", &self.template.aliases));
+		}
+
+		if !self.template.mappings.is_empty() {
+			try!(serializer.serialize_struct_elt("mappings", &self.template.mappings));
+		}
+
+		Ok(None)
+	}
+}
+
+impl Deserialize for IndexTemplate {
+	fn deserialize<D>(deserializer: &mut D) -> Result<IndexTemplate, D::Error> where
+	D: Deserializer {
+		let value: Value = try!(Deserialize::deserialize(deserializer));
+
+		IndexTemplate::from_value(value).map_err(|e| serde::de::Error::custom(e))
+	}
+}
+
+/// The response of the Get Index Template API, with each template keyed by name.
+///
+/// # Examples
+///
+/// ```
+/// # #![feature(plugin)]
+/// # #![plugin(json_str)]
+/// # extern crate serde_json;
+/// # extern crate elastic_types;
+/// # fn main() {
+/// use elastic_types::index::IndexTemplates;
+///
+/// let res = json_str!({
+/// 	"logs": {
+/// 		"order": 0,
+/// 		"template": "logs-*",
+/// 		"settings": {
+/// 			"index": {
+/// 				"number_of_shards": "1"
+/// 			}
+/// 		},
+/// 		"mappings": {},
+/// 		"aliases": {}
+/// 	}
+/// });
+///
+/// let templates: IndexTemplates = serde_json::from_str(&res).unwrap();
+///
+/// let logs = templates.get("logs").unwrap();
+/// assert_eq!("logs-*", logs.template);
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct IndexTemplates {
+	/// The templates, keyed by name.
+	pub templates: BTreeMap<String, IndexTemplate>
+}
+
+impl IndexTemplates {
+	/// Get a template by name.
+	pub fn get(&self, name: &str) -> Option<&IndexTemplate> {
+		self.templates.get(name)
+	}
+}
+
+impl Deserialize for IndexTemplates {
+	fn deserialize<D>(deserializer: &mut D) -> Result<IndexTemplates, D::Error> where
+	D: Deserializer {
+		let templates: BTreeMap<String, IndexTemplate> = try!(Deserialize::deserialize(deserializer));
+
+		Ok(IndexTemplates {
+			templates: templates
+		})
+	}
+}
+
+/// An alias added to indices created from a template.
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-aliases.html)
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Alias {
+	/// A query that limits the documents the alias can see.
+	pub filter: Option<Value>,
+	/// The routing value used for both indexing and searching.
+	pub routing: Option<String>,
+	/// The routing value used for indexing.
+	pub index_routing: Option<String>,
+	/// The routing value used for searching.
+	pub search_routing: Option<String>
+}
+
+impl Alias {
+	/// Create a new alias without a filter or routing.
+	pub fn new() -> Alias {
+		Alias::default()
+	}
+
+	/// Set a query that limits the documents the alias can see.
+	pub fn filter<Q>(mut self, filter: Q) -> Alias where
+	Q: Serialize {
+		self.filter = Some(serde_json::to_value(&filter));
+		self
+	}
+
+	/// Set the routing value used for both indexing and searching.
+	pub fn routing<I>(mut self, routing: I) -> Alias where
+	I: Into<String> {
+		self.routing = Some(routing.into());
+		self
+	}
+
+	/// Set the routing value used for indexing.
+	pub fn index_routing<I>(mut self, routing: I) -> Alias where
+	I: Into<String> {
+		self.index_routing = Some(routing.into());
+		self
+	}
+
+	/// Set the routing value used for searching.
+	pub fn search_routing<I>(mut self, routing: I) -> Alias where
+	I: Into<String> {
+		self.search_routing = Some(routing.into());
+		self
+	}
+
+	fn from_value(value: Value) -> Result<Alias, String> {
+		let mut map = match value {
+			Value::Object(map) => map,
+			_ => return Err("expected the alias to be an object".to_owned())
+		};
+
+		Ok(Alias {
+			filter: map.remove("filter"),
+			routing: try!(take_routing(&mut map, "routing")),
+			index_routing: try!(take_routing(&mut map, "index_routing")),
+			search_routing: try!(take_routing(&mut map, "search_routing"))
+		})
+	}
+}
+
+fn take_routing(map: &mut BTreeMap<String, Value>, key: &str) -> Result<Option<String>, String> {
+	match map.remove(key) {
+		Some(Value::String(routing)) => Ok(Some(routing)),
+		Some(Value::Null) | None => Ok(None),
+		Some(_) => Err(format!("expected '{}' to be a string", key))
+	}
+}
+
+impl Serialize for Alias {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_struct("alias", AliasVisitor {
+			alias: self
+		})
+	}
+}
+
+struct AliasVisitor<'a> {
+	alias: &'a Alias
+}
+
+impl <'a> serde::ser::MapVisitor for AliasVisitor<'a> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> where
+	S: Serializer {
+		if let Some(ref filter) = self.alias.filter {
+			try!(serializer.serialize_struct_elt("filter", filter));
+		}
+
+		if let Some(ref routing) = self.alias.routing {
+			try!(serializer.serialize_struct_elt("routing", routing));
+		}
+
+		if let Some(ref routing) = self.alias.index_routing {
+			try!(serializer.serialize_struct_elt("index_routing", routing));
+		}
+
+		if let Some(ref routing) = self.alias.search_routing {
+			try!(serializer.serialize_struct_elt("search_routing", routing));
+		}
+
+		Ok(None)
+	}
+}
@@ -0,0 +1,176 @@
+use std::marker::PhantomData;
+use std::net::Ipv4Addr;
+use std::str::FromStr;
+use serde;
+use serde::{ Serialize, Deserialize, Serializer, Deserializer };
+use super::mapping::{ ElasticIpMapping, DefaultIpMapping };
+use ::mapping::{ ElasticFieldMapping, ElasticType, ElasticFieldAnalysis, NotAnalyzed };
+
+/// An Elasticsearch `ip` with a mapping.
+///
+/// The address is serialised as a dotted string, and can be deserialised from either a string or the numeric value Elasticsearch stores.
+///
+/// # Examples
+///
+/// Defining an ip with a mapping:
+///
+/// ```
+/// use std::net::Ipv4Addr;
+/// use elastic_types::ip::mapping::DefaultIpMapping;
+/// use elastic_types::ip::ElasticIp;
+///
+/// let ip = ElasticIp::<DefaultIpMapping>::new(Ipv4Addr::new(127, 0, 0, 1));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ElasticIp<T> where
+T: ElasticFieldMapping<()> + ElasticIpMapping {
+	value: Ipv4Addr,
+	phantom: PhantomData<T>
+}
+impl <T> ElasticIp<T> where
+T: ElasticFieldMapping<()> + ElasticIpMapping {
+	/// Creates a new `ElasticIp` with the given mapping.
+	///
+	/// # Examples
+	///
+	/// Create a new `ElasticIp` from an `Ipv4Addr`:
+	///
+	/// ```
+	/// use std::net::Ipv4Addr;
+	/// use elastic_types::ip::mapping::DefaultIpMapping;
+	/// use elastic_types::ip::ElasticIp;
+	///
+	/// let ip = ElasticIp::<DefaultIpMapping>::new(Ipv4Addr::new(10, 0, 0, 1));
+	/// ```
+	pub fn new<I>(ip: I) -> ElasticIp<T> where I: Into<Ipv4Addr> {
+		ElasticIp {
+			value: ip.into(),
+			phantom: PhantomData
+		}
+	}
+
+	/// Parse an `ElasticIp` from a dotted string, like `"192.168.1.1"`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use elastic_types::ip::mapping::DefaultIpMapping;
+	/// use elastic_types::ip::ElasticIp;
+	///
+	/// let ip = ElasticIp::<DefaultIpMapping>::parse("192.168.1.1").unwrap();
+	/// ```
+	pub fn parse(ip: &str) -> Result<ElasticIp<T>, String> {
+		Ipv4Addr::from_str(ip)
+			.map(ElasticIp::new)
+			.map_err(|e| format!("{}", e))
+	}
+
+	/// Get the value of the ip.
+	pub fn get(&self) -> Ipv4Addr {
+		self.value
+	}
+
+	/// Set the value of the ip.
+	pub fn set<I>(&mut self, ip: I) where I: Into<Ipv4Addr> {
+		self.value = ip.into()
+	}
+
+	/// Change the mapping of this ip.
+	pub fn into<TInto>(self) -> ElasticIp<TInto> where
+	TInto: ElasticFieldMapping<()> + ElasticIpMapping {
+		ElasticIp::<TInto>::new(self.value)
+	}
+}
+
+impl <T> ElasticType<T, ()> for ElasticIp<T> where
+T: ElasticFieldMapping<()> + ElasticIpMapping { }
+
+impl <T> ElasticFieldAnalysis for ElasticIp<T> where
+T: ElasticFieldMapping<()> + ElasticIpMapping {
+	type Analysis = NotAnalyzed;
+}
+
+impl From<Ipv4Addr> for ElasticIp<DefaultIpMapping> {
+	fn from(ip: Ipv4Addr) -> Self {
+		ElasticIp::new(ip)
+	}
+}
+
+impl <T> AsRef<Ipv4Addr> for ElasticIp<T> where
+T: ElasticFieldMapping<()> + ElasticIpMapping {
+	fn as_ref(&self) -> &Ipv4Addr {
+		&self.value
+	}
+}
+
+impl <T> Into<Ipv4Addr> for ElasticIp<T> where
+T: ElasticFieldMapping<()> + ElasticIpMapping {
+	fn into(self) -> Ipv4Addr {
+		self.value
+	}
+}
+
+impl<'a, T> PartialEq<Ipv4Addr> for ElasticIp<T> where
+T: ElasticFieldMapping<()> + ElasticIpMapping {
+	fn eq(&self, other: &Ipv4Addr) -> bool {
+		PartialEq::eq(&self.value, other)
+	}
+
+	fn ne(&self, other: &Ipv4Addr) -> bool {
+		PartialEq::ne(&self.value, other)
+	}
+}
+
+impl<'a, T> PartialEq<ElasticIp<T>> for Ipv4Addr where
+T: ElasticFieldMapping<()> + ElasticIpMapping {
+	fn eq(&self, other: &ElasticIp<T>) -> bool {
+		PartialEq::eq(self, &other.value)
+	}
+
+	fn ne(&self, other: &ElasticIp<T>) -> bool {
+		PartialEq::ne(self, &other.value)
+	}
+}
+
+//Serialize elastic ip
+impl <T> Serialize for ElasticIp<T> where
+T: ElasticFieldMapping<()> + ElasticIpMapping {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_str(&self.value.to_string())
+	}
+}
+
+//Deserialize elastic ip
+impl <T> Deserialize for ElasticIp<T> where
+T: ElasticFieldMapping<()> + ElasticIpMapping {
+	fn deserialize<D>(deserializer: &mut D) -> Result<ElasticIp<T>, D::Error> where
+	D: Deserializer {
+		#[derive(Default)]
+		struct ElasticIpVisitor<T> where
+		T: ElasticFieldMapping<()> + ElasticIpMapping {
+			phantom: PhantomData<T>
+		}
+
+		impl <T> serde::de::Visitor for ElasticIpVisitor<T> where
+		T: ElasticFieldMapping<()> + ElasticIpMapping {
+			type Value = ElasticIp<T>;
+
+			fn visit_str<E>(&mut self, v: &str) -> Result<ElasticIp<T>, E> where
+			E: serde::de::Error {
+				ElasticIp::<T>::parse(v).map_err(|e| E::custom(e))
+			}
+
+			fn visit_u64<E>(&mut self, v: u64) -> Result<ElasticIp<T>, E> where
+			E: serde::de::Error {
+				if v > u32::max_value() as u64 {
+					return Err(E::custom(format!("'{}' is too large to be an ipv4 address", v)));
+				}
+
+				Ok(ElasticIp::<T>::new(Ipv4Addr::from(v as u32)))
+			}
+		}
+
+		deserializer.deserialize(ElasticIpVisitor::<T>::default())
+	}
+}
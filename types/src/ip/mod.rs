@@ -0,0 +1,52 @@
+//! Implementation of the Elasticsearch `ip` type.
+//!
+//! IPv4 addresses are indexed as numbers, but are sent and returned as dotted strings, like `"192.168.1.1"`.
+//!
+//! # Examples
+//!
+//! For defining your own ip mapping, see [mapping details](mapping/trait.ElasticIpMapping.html#derive-mapping).
+//!
+//! Map with a default `ip`:
+//!
+//! ```
+//! # use elastic_types::ip::prelude::*;
+//! # use elastic_types::ip::mapping::DefaultIpMapping;
+//! struct MyType {
+//! 	pub field: ElasticIp<DefaultIpMapping>
+//! }
+//! ```
+//!
+//! Map with a custom `ip`:
+//!
+//! ```
+//! # #![feature(plugin, custom_derive)]
+//! # #![plugin(json_str, elastic_types_macros)]
+//! # extern crate serde;
+//! # extern crate elastic_types;
+//! # fn main() {
+//! # use elastic_types::mapping::prelude::*;
+//! # use elastic_types::ip::prelude::*;
+//! # #[derive(Debug, Clone, Default, ElasticIpMapping)]
+//! # pub struct MyIpMapping;
+//! # impl ElasticIpMapping for MyIpMapping { }
+//! struct MyType {
+//! 	pub field: ElasticIp<MyIpMapping>
+//! }
+//! # }
+//! ```
+//!
+//! # Links
+//! - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/ip.html)
+
+mod ip;
+
+pub mod mapping;
+pub use self::ip::*;
+
+pub mod prelude {
+	//! Includes non-mapping types for the `ip` type.
+	//!
+	//! This is a convenience module to make it easy to build mappings for multiple types without too many `use` statements.
+
+	pub use super::ip::*;
+}
@@ -279,10 +279,29 @@
 //!  `string`           | `string-ty`
 //!  `boolean`          | `boolean-ty`
 //!  `date`             | `date-ty`
+//!  `geo_point`        | `geo-ty`
+//!  `geo_shape`        | `geo-ty`
+//!  `ip`               | `ip-ty`
+//!  `binary`           | `binary-ty`
+//!  `token_count`      | `token-count-ty`
+//!  `completion`       | `completion-ty`
+//!  Query DSL          | `query-ty`
+//!  index settings     | `index-ty`
 //!  responses          | `response-ty`
 //!
 //! To include all types except for responses, you can use the `no-response-ty` feature.
 //!
+//! # Queries
+//!
+//! The [query](query/index.html) module contains types for building requests with the Query DSL,
+//! so searches can be checked at compile-time instead of written as raw json.
+//!
+//! # Index Settings
+//!
+//! The [index](index/index.html) module contains types for building index settings and custom analyzers,
+//! which can be sent along with the mappings for your types when creating an index.
+//! Index templates can be built the same way, so time-based indices get their mappings from your types.
+//!
 //! # Types
 //!
 //! Types in Elasticsearch are a combination of _source_ and _mapping_.
@@ -314,6 +333,8 @@
 //!  `string`           | `String`                    | `std`     | `ElasticString<M>`            | `()`
 //!  `boolean`          | `bool`                      | `std`     | `ElasticBoolean<M>`           | `()`
 //!  `date`             | `DateTime<UTC>`             | `chrono`  | `ElasticDate<F, M>`           | `DateFormat`
+//!  `geo_point`        | `GeoPoint`                  | -         | `ElasticGeoPoint<F, M>`       | `GeoPointFormat`
+//!  `geo_shape`        | `Geometry`                  | `geojson` | `ElasticGeoShape<M>`          | `()`
 //!  `object`           | -                           | -         | user-defined `struct`         | `()`
 //!
 //! The following sections explain this table.
@@ -351,6 +372,10 @@
 
 #[cfg(feature="date-ty")]
 extern crate chrono;
+#[cfg(feature="geo-ty")]
+extern crate geojson;
+#[cfg(feature="binary-ty")]
+extern crate rustc_serialize;
 extern crate serde;
 extern crate serde_json;
 
@@ -368,5 +393,19 @@ pub mod string;
 pub mod number;
 #[cfg(feature="boolean-ty")]
 pub mod boolean;
+#[cfg(feature="geo-ty")]
+pub mod geo;
+#[cfg(feature="ip-ty")]
+pub mod ip;
+#[cfg(feature="binary-ty")]
+pub mod binary;
+#[cfg(feature="token-count-ty")]
+pub mod token_count;
+#[cfg(feature="completion-ty")]
+pub mod completion;
+#[cfg(feature="query-ty")]
+pub mod query;
+#[cfg(feature="index-ty")]
+pub mod index;
 #[cfg(feature="response-ty")]
 pub mod response;
@@ -222,18 +222,164 @@ macro_rules! impl_date_mapping {
 	)
 }
 
+#[cfg(feature="geo-ty")]
+macro_rules! impl_geo_point_mapping {
+	($t:ty, $f:ty) => (
+		impl $crate::mapping::ElasticFieldMapping<$f> for $t {
+			type Visitor = $crate::geo::mapping::ElasticGeoPointMappingVisitor<$f, $t>;
+
+			fn data_type() -> &'static str {
+				"geo_point"
+			}
+		}
+
+		impl serde::Serialize for $t {
+			fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+			where S: serde::Serializer {
+				serializer.serialize_struct("mapping", Self::get_visitor())
+			}
+		}
+	);
+	($t:ty) => (
+		impl <T: $crate::geo::GeoPointFormat> $crate::mapping::ElasticFieldMapping<T> for $t {
+			type Visitor = $crate::geo::mapping::ElasticGeoPointMappingVisitor<T, $t>;
+
+			fn data_type() -> &'static str {
+				"geo_point"
+			}
+		}
+
+		impl <T: $crate::geo::GeoPointFormat> serde::Serialize for $t {
+			fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+			where S: serde::Serializer {
+				serializer.serialize_struct("mapping", Self::get_visitor())
+			}
+		}
+	)
+}
+
+#[cfg(feature="geo-ty")]
+macro_rules! impl_geo_shape_mapping {
+	($t:ty) => (
+		impl $crate::mapping::ElasticFieldMapping<()> for $t {
+			type Visitor = $crate::geo::mapping::ElasticGeoShapeMappingVisitor<$t>;
+
+			fn data_type() -> &'static str {
+				"geo_shape"
+			}
+		}
+
+		impl serde::Serialize for $t {
+			fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+			where S: serde::Serializer {
+				serializer.serialize_struct("mapping", Self::get_visitor())
+			}
+		}
+	)
+}
+
+#[cfg(feature="ip-ty")]
+macro_rules! impl_ip_mapping {
+	($t:ty) => (
+		impl $crate::mapping::ElasticFieldMapping<()> for $t {
+			type Visitor = $crate::ip::mapping::ElasticIpMappingVisitor<$t>;
+
+			fn data_type() -> &'static str {
+				"ip"
+			}
+		}
+
+		impl serde::Serialize for $t {
+			fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+			where S: serde::Serializer {
+				serializer.serialize_struct("mapping", Self::get_visitor())
+			}
+		}
+	)
+}
+
+#[cfg(feature="binary-ty")]
+macro_rules! impl_binary_mapping {
+	($t:ty) => (
+		impl $crate::mapping::ElasticFieldMapping<()> for $t {
+			type Visitor = $crate::binary::mapping::ElasticBinaryMappingVisitor<$t>;
+
+			fn data_type() -> &'static str {
+				"binary"
+			}
+		}
+
+		impl serde::Serialize for $t {
+			fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+			where S: serde::Serializer {
+				serializer.serialize_struct("mapping", Self::get_visitor())
+			}
+		}
+	)
+}
+
+#[cfg(feature="token-count-ty")]
+macro_rules! impl_token_count_mapping {
+	($t:ty) => (
+		impl $crate::mapping::ElasticFieldMapping<()> for $t {
+			type Visitor = $crate::token_count::mapping::ElasticTokenCountMappingVisitor<$t>;
+
+			fn data_type() -> &'static str {
+				"token_count"
+			}
+		}
+
+		impl serde::Serialize for $t {
+			fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+			where S: serde::Serializer {
+				serializer.serialize_struct("mapping", Self::get_visitor())
+			}
+		}
+	)
+}
+
+#[cfg(feature="completion-ty")]
+macro_rules! impl_completion_mapping {
+	($t:ty) => (
+		impl $crate::mapping::ElasticFieldMapping<()> for $t {
+			type Visitor = $crate::completion::mapping::ElasticCompletionMappingVisitor<$t>;
+
+			fn data_type() -> &'static str {
+				"completion"
+			}
+		}
+
+		impl serde::Serialize for $t {
+			fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+			where S: serde::Serializer {
+				serializer.serialize_struct("mapping", Self::get_visitor())
+			}
+		}
+	)
+}
+
 #[cfg(feature="date-ty")]
 macro_rules! impl_date_fmt {
-	($t:ty, $f:tt, $n:expr) => (
+	($t:ty, [ $($item:expr),* ], $n:expr) => (
+		impl $crate::date::DateFormat for $t {
+			fn fmt<'a>() -> Vec<chrono::format::Item<'a>> {
+				vec![ $($item),* ]
+			}
+
+			fn name() -> &'static str { $n }
+		}
+	);
+	($t:ty, [ $($item:expr),* ], $n:expr, strict) => (
 		impl $crate::date::DateFormat for $t {
 			fn fmt<'a>() -> Vec<chrono::format::Item<'a>> {
-				date_fmt!($f)
-					.iter()
-					.cloned()
-					.collect()
+				vec![ $($item),* ]
 			}
 
 			fn name() -> &'static str { $n }
+
+			fn parse(date: &str) -> Result<chrono::DateTime<chrono::UTC>, $crate::date::ParseError> {
+				$crate::date::parse_fixed_width(date, Self::name(), Self::fmt())
+			}
 		}
 	)
 }
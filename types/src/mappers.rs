@@ -5,6 +5,7 @@
 //!
 //! - `TypeMapper` for mapping user-defined types for the [Put Mapping API](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-put-mapping.html)
 //! - `FieldMapper` for mapping fields on user-defined types. User-defined types as fields are mapped as [Nested objects](https://www.elastic.co/guide/en/elasticsearch/guide/current/nested-objects.html)
+//! by default, but can be mapped as plain `object`s with `#[elastic(object)]` on the type or the field.
 
 use std::error::Error;
 use std::marker::PhantomData;
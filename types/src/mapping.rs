@@ -27,7 +27,12 @@ pub mod prelude {
 		ElasticFieldMapping,
 		ElasticTypeVisitor,
 		NullMapping,
-		IndexAnalysis
+		IndexAnalysis,
+		ElasticFieldAnalysis,
+		StaticIndexAnalysis,
+		Analyzed,
+		NotAnalyzed,
+		NotIndexed
 	};
 
 	pub use ::object::*;
@@ -41,6 +46,16 @@ pub mod prelude {
 	pub use ::number::mapping::*;
 	#[cfg(feature="boolean-ty")]
 	pub use ::boolean::mapping::*;
+	#[cfg(feature="geo-ty")]
+	pub use ::geo::mapping::*;
+	#[cfg(feature="ip-ty")]
+	pub use ::ip::mapping::*;
+	#[cfg(feature="binary-ty")]
+	pub use ::binary::mapping::*;
+	#[cfg(feature="token-count-ty")]
+	pub use ::token_count::mapping::*;
+	#[cfg(feature="completion-ty")]
+	pub use ::completion::mapping::*;
 }
 
 use std::marker::PhantomData;
@@ -158,7 +173,7 @@ impl serde::Serialize for IndexAnalysis {
 }
 
 /// Should the field be searchable? Accepts `not_analyzed` (default) and `no`.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum IndexAnalysis {
 	/// This option applies only to string fields, for which it is the default.
 	/// The string field value is first analyzed to convert the string into terms
@@ -175,6 +190,55 @@ pub enum IndexAnalysis {
 	No
 }
 
+/// The `index` analysis of a mapping, known at compile time.
+///
+/// This lets the queries available for a field depend on how its mapping analyses values.
+pub trait StaticIndexAnalysis {
+	/// Get the `index` parameter for the mapping.
+	fn index() -> Option<IndexAnalysis>;
+}
+
+/// Values are analysed into terms for full text search.
+///
+/// This is the default for `string` mappings.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Analyzed { }
+
+impl StaticIndexAnalysis for Analyzed {
+	fn index() -> Option<IndexAnalysis> {
+		None
+	}
+}
+
+/// Values are indexed unchanged, as a single term.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NotAnalyzed { }
+
+impl StaticIndexAnalysis for NotAnalyzed {
+	fn index() -> Option<IndexAnalysis> {
+		Some(IndexAnalysis::NotAnalyzed)
+	}
+}
+
+/// Values aren't added to the index, so the field can't be queried.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NotIndexed { }
+
+impl StaticIndexAnalysis for NotIndexed {
+	fn index() -> Option<IndexAnalysis> {
+		Some(IndexAnalysis::No)
+	}
+}
+
+/// How values of a field type are indexed, according to its mapping.
+///
+/// Exact value queries like `term` are only available on a `FieldPath` to a field whose `Analysis` is `NotAnalyzed`.
+/// Types like numbers and dates are never analysed, and `string` types use the `Analysis` of their `ElasticStringMapping`.
+pub trait ElasticFieldAnalysis {
+	/// The analysis of values for this type.
+	type Analysis: StaticIndexAnalysis;
+}
+
 /// Mapping for a collection.
 ///
 /// In Elasticsearch, arrays aren't a special type, anything can be indexed as an array.
@@ -212,3 +276,8 @@ M: ElasticFieldMapping<F>,
 F: Default + Clone {
 
 }
+
+impl <T> ElasticFieldAnalysis for Vec<T> where
+T: ElasticFieldAnalysis {
+	type Analysis = T::Analysis;
+}
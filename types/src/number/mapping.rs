@@ -110,7 +110,7 @@ pub const FLOAT_DATATYPE: &'static str = "float";
 use std::marker::PhantomData;
 use serde;
 use serde::{ Serialize, Serializer };
-use ::mapping::{ ElasticType, ElasticFieldMapping, ElasticTypeVisitor, IndexAnalysis };
+use ::mapping::{ ElasticType, ElasticFieldMapping, ElasticTypeVisitor, IndexAnalysis, ElasticFieldAnalysis, NotAnalyzed };
 
 macro_rules! number_mapping {
     ($m:ident, $v:ident, $n:ty) => (
@@ -252,6 +252,9 @@ pub struct DefaultIntegerMapping;
 impl ElasticIntegerMapping for DefaultIntegerMapping { }
 impl_integer_mapping!(DefaultIntegerMapping);
 impl ElasticType<DefaultIntegerMapping, ()> for i32 { }
+impl ElasticFieldAnalysis for i32 {
+	type Analysis = NotAnalyzed;
+}
 
 /// Default mapping for a `long` type.
 #[derive(Debug, Default, Clone, Copy)]
@@ -259,7 +262,13 @@ pub struct DefaultLongMapping;
 impl ElasticLongMapping for DefaultLongMapping { }
 impl_long_mapping!(DefaultLongMapping);
 impl ElasticType<DefaultLongMapping, ()> for i64 { }
+impl ElasticFieldAnalysis for i64 {
+	type Analysis = NotAnalyzed;
+}
 impl ElasticType<DefaultLongMapping, ()> for isize { }
+impl ElasticFieldAnalysis for isize {
+	type Analysis = NotAnalyzed;
+}
 
 /// Default mapping for a `short` type.
 #[derive(Debug, Default, Clone, Copy)]
@@ -267,6 +276,9 @@ pub struct DefaultShortMapping;
 impl ElasticShortMapping for DefaultShortMapping { }
 impl_short_mapping!(DefaultShortMapping);
 impl ElasticType<DefaultShortMapping, ()> for i16 { }
+impl ElasticFieldAnalysis for i16 {
+	type Analysis = NotAnalyzed;
+}
 
 /// Default mapping for a `byte` type.
 #[derive(Debug, Default, Clone, Copy)]
@@ -274,6 +286,9 @@ pub struct DefaultByteMapping;
 impl ElasticByteMapping for DefaultByteMapping { }
 impl_byte_mapping!(DefaultByteMapping);
 impl ElasticType<DefaultByteMapping, ()> for i8 { }
+impl ElasticFieldAnalysis for i8 {
+	type Analysis = NotAnalyzed;
+}
 
 /// Default mapping for a `float` type.
 #[derive(Debug, Default, Clone, Copy)]
@@ -281,6 +296,9 @@ pub struct DefaultFloatMapping;
 impl ElasticFloatMapping for DefaultFloatMapping { }
 impl_float_mapping!(DefaultFloatMapping);
 impl ElasticType<DefaultFloatMapping, ()> for f32 { }
+impl ElasticFieldAnalysis for f32 {
+	type Analysis = NotAnalyzed;
+}
 
 /// Default mapping for a `double` type.
 #[derive(Debug, Default, Clone, Copy)]
@@ -288,3 +306,6 @@ pub struct DefaultDoubleMapping;
 impl ElasticDoubleMapping for DefaultDoubleMapping { }
 impl_double_mapping!(DefaultDoubleMapping);
 impl ElasticType<DefaultDoubleMapping, ()> for f64 { }
+impl ElasticFieldAnalysis for f64 {
+	type Analysis = NotAnalyzed;
+}
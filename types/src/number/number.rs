@@ -1,7 +1,7 @@
 use std::marker::PhantomData;
 use serde::{ Serialize, Deserialize, Serializer, Deserializer };
 use super::mapping::*;
-use ::mapping::{ ElasticType, ElasticFieldMapping };
+use ::mapping::{ ElasticType, ElasticFieldMapping, ElasticFieldAnalysis, NotAnalyzed };
 
 macro_rules! number_type {
     ($t:ident, $m:ident, $n:ident) => (
@@ -38,6 +38,10 @@ macro_rules! number_type {
 
 		impl <M> ElasticType<M, ()> for $t<M> where M: ElasticFieldMapping<()> + $m { }
 
+		impl <M> ElasticFieldAnalysis for $t<M> where M: ElasticFieldMapping<()> + $m {
+			type Analysis = NotAnalyzed;
+		}
+
 		impl <M> From<$n> for $t<M> where M: ElasticFieldMapping<()> + $m {
 			fn from(num: $n) -> Self {
 				$t::<M>::new(num)
@@ -0,0 +1,132 @@
+use std::marker::PhantomData;
+
+/// The path to a field on a mapped type.
+///
+/// The type of the field is carried along as a generic parameter,
+/// so queries built from the path can only accept values of that type.
+/// Field paths are generated by `#[derive(ElasticType)]` and are available through the `ElasticFieldPaths` trait,
+/// which returns a `{Type}FieldPaths` struct with a path for each field.
+/// The path to a field that's an object can give the paths to the fields on that object, like `my_object.my_string`.
+///
+/// # Examples
+///
+/// ```
+/// # #![feature(plugin, custom_derive, custom_attribute)]
+/// # #![plugin(elastic_types_macros)]
+/// # #[macro_use]
+/// # extern crate elastic_types;
+/// # extern crate serde;
+/// # use serde::{ Serialize, Deserialize };
+/// use elastic_types::mapping::prelude::*;
+///
+/// #[derive(Serialize, Deserialize, ElasticType)]
+/// pub struct MyType {
+/// 	pub my_string: String,
+/// 	pub my_num: i32,
+/// 	pub my_object: MyObject
+/// }
+/// # impl serde::Serialize for MyType {
+/// # 	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where S: serde::Serializer {
+/// # 		unimplemented!()
+/// # 	}
+/// # }
+/// # impl serde::Deserialize for MyType {
+/// # 	 fn deserialize<D>(deserializer: &mut D) -> Result<Self, D::Error> where D: serde::Deserializer {
+/// # 		unimplemented!()
+/// # 	}
+/// # }
+///
+/// #[derive(Serialize, Deserialize, ElasticType)]
+/// #[elastic(object)]
+/// pub struct MyObject {
+/// 	pub my_string: String
+/// }
+/// # impl serde::Serialize for MyObject {
+/// # 	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where S: serde::Serializer {
+/// # 		unimplemented!()
+/// # 	}
+/// # }
+/// # impl serde::Deserialize for MyObject {
+/// # 	 fn deserialize<D>(deserializer: &mut D) -> Result<Self, D::Error> where D: serde::Deserializer {
+/// # 		unimplemented!()
+/// # 	}
+/// # }
+/// # fn main() {
+/// let fields = MyType::field_paths();
+///
+/// assert_eq!("my_num", fields.my_num.name());
+/// assert_eq!("my_object.my_string", fields.my_object.field_paths().my_string.name());
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct FieldPath<T> {
+	name: String,
+	phantom: PhantomData<T>
+}
+
+impl <T> FieldPath<T> {
+	/// Create a new path for the field with the given name.
+	pub fn new<I>(name: I) -> FieldPath<T> where
+	I: Into<String> {
+		FieldPath {
+			name: name.into(),
+			phantom: PhantomData
+		}
+	}
+
+	/// Create a path for a field on an object.
+	///
+	/// The path is `{parent}.{name}`, or just `name` if there's no parent.
+	pub fn under(parent: Option<&str>, name: &str) -> FieldPath<T> {
+		match parent {
+			Some(parent) => FieldPath::new(format!("{}.{}", parent, name)),
+			None => FieldPath::new(name)
+		}
+	}
+
+	/// Get the name of the field, as it's serialised.
+	///
+	/// For a field on an object, this is the full path, like `my_object.my_string`.
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+}
+
+impl <T> FieldPath<T> where
+T: ElasticFieldPaths {
+	/// Get the paths to the fields on this object, like `my_object.my_string`.
+	pub fn field_paths(&self) -> T::Paths {
+		T::field_paths_under(Some(self.name()))
+	}
+}
+
+impl <T> Clone for FieldPath<T> {
+	fn clone(&self) -> FieldPath<T> {
+		FieldPath::new(self.name.clone())
+	}
+}
+
+/// A type with paths to its fields, for building queries.
+///
+/// This is implemented by `#[derive(ElasticType)]`, so it doesn't clash with any methods on the type itself.
+pub trait ElasticFieldPaths {
+	/// The struct with a `FieldPath` for each field.
+	type Paths;
+
+	/// Get the paths to the fields on this type.
+	fn field_paths() -> Self::Paths {
+		Self::field_paths_under(None)
+	}
+
+	/// Get the paths to the fields on this type, when it's mapped as an object under `parent`.
+	fn field_paths_under(parent: Option<&str>) -> Self::Paths;
+}
+
+impl <T> ElasticFieldPaths for Vec<T> where
+T: ElasticFieldPaths {
+	type Paths = T::Paths;
+
+	fn field_paths_under(parent: Option<&str>) -> Self::Paths {
+		T::field_paths_under(parent)
+	}
+}
@@ -131,6 +131,93 @@
 //! # }
 //! ```
 //!
+//! ### Map as an Object or Nested
+//!
+//! When a user-defined type is used as a field on another type, it's mapped as `nested` by default.
+//! Nested objects are indexed as separate documents, so they need `nested` queries.
+//! Plain `object`s are cheaper and can be queried with dotted paths, like `my_type.my_string`.
+//!
+//! Use `elastic(object)` or `elastic(nested)` on a type to change how it's mapped wherever it's used as a field.
+//! The `dynamic`, `enabled`, `include_in_parent` and `include_in_root` parameters can be set the same way:
+//!
+//! ```
+//! # #![feature(plugin, custom_derive, custom_attribute)]
+//! # #![plugin(json_str, elastic_types_macros)]
+//! # #[macro_use]
+//! # extern crate elastic_types;
+//! # extern crate serde;
+//! # use serde::{ Serialize, Deserialize };
+//! # use elastic_types::mapping::prelude::*;
+//! #[derive(Default, Clone, Serialize, Deserialize, ElasticType)]
+//! #[elastic(object, dynamic="strict")]
+//! pub struct MyType {
+//! 	pub my_string: String,
+//! 	pub my_num: i32
+//! }
+//! # impl serde::Serialize for MyType {
+//! # 	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where S: serde::Serializer {
+//! # 		unimplemented!()
+//! # 	}
+//! # }
+//! # impl serde::Deserialize for MyType {
+//! # 	 fn deserialize<D>(deserializer: &mut D) -> Result<Self, D::Error> where D: serde::Deserializer {
+//! # 		unimplemented!()
+//! # 	}
+//! # }
+//! # fn main() {
+//! # }
+//! ```
+//!
+//! The same attributes can also be used on a field, to override the mapping of its type for just that field:
+//!
+//! ```
+//! # #![feature(plugin, custom_derive, custom_attribute)]
+//! # #![plugin(json_str, elastic_types_macros)]
+//! # #[macro_use]
+//! # extern crate elastic_types;
+//! # extern crate serde;
+//! # use serde::{ Serialize, Deserialize };
+//! # use elastic_types::mapping::prelude::*;
+//! # #[derive(Default, Clone, Serialize, Deserialize, ElasticType)]
+//! # pub struct MyType {
+//! # 	pub my_string: String
+//! # }
+//! #[derive(Default, Clone, Serialize, Deserialize, ElasticType)]
+//! pub struct MyOtherType {
+//! 	#[elastic(object)]
+//! 	pub my_object: MyType,
+//! 	#[elastic(nested, include_in_parent)]
+//! 	pub my_nested: MyType,
+//! 	#[elastic(enabled="false")]
+//! 	pub my_ignored: MyType
+//! }
+//! # impl serde::Serialize for MyType {
+//! # 	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where S: serde::Serializer {
+//! # 		unimplemented!()
+//! # 	}
+//! # }
+//! # impl serde::Deserialize for MyType {
+//! # 	 fn deserialize<D>(deserializer: &mut D) -> Result<Self, D::Error> where D: serde::Deserializer {
+//! # 		unimplemented!()
+//! # 	}
+//! # }
+//! # impl serde::Serialize for MyOtherType {
+//! # 	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where S: serde::Serializer {
+//! # 		unimplemented!()
+//! # 	}
+//! # }
+//! # impl serde::Deserialize for MyOtherType {
+//! # 	 fn deserialize<D>(deserializer: &mut D) -> Result<Self, D::Error> where D: serde::Deserializer {
+//! # 		unimplemented!()
+//! # 	}
+//! # }
+//! # fn main() {
+//! # }
+//! ```
+//!
+//! These attributes can't be combined with `elastic(mapping="{TypeName}")`.
+//! Override the `ElasticObjectMapping` methods on your mapping type instead.
+//!
 //! ### Ignore or Rename Fields
 //!
 //! The mapping will respect standard `serde` attributes for renaming or ignoring fields:
@@ -277,9 +364,11 @@ pub const NESTED_DATATYPE: &'static str = "nested";
 
 mod object;
 mod user_type;
+mod field;
 
 pub use self::object::*;
 pub use self::user_type::*;
+pub use self::field::*;
 
 use std::marker::PhantomData;
 use serde;
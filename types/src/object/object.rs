@@ -1,6 +1,9 @@
+use std::collections::BTreeMap;
 use std::marker::PhantomData;
 use serde;
 use serde::{ Serialize, Serializer };
+use serde_json;
+use serde_json::Value;
 use super::ElasticObjectProperties;
 use ::mapping::{ ElasticFieldMapping, ElasticTypeVisitor };
 
@@ -29,11 +32,23 @@ Self: ElasticFieldMapping<()> + Sized + Serialize + Default + Clone {
 	fn include_in_all() -> Option<bool> {
 		None
 	}
+
+	/// For `nested` objects, whether all fields in the nested object should also be added to the parent document as standard (flat) fields.
+	/// Defaults to `false`.
+	fn include_in_parent() -> Option<bool> {
+		None
+	}
+
+	/// For `nested` objects, whether all fields in the nested object should also be added to the root document as standard (flat) fields.
+	/// Defaults to `false`.
+	fn include_in_root() -> Option<bool> {
+		None
+	}
 }
 
 /// The dynamic setting may be set at the mapping type level, and on each inner object.
 /// Inner objects inherit the setting from their parent object or from the mapping type.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Dynamic {
 	/// Newly detected fields are added to the mapping. (default).
 	True,
@@ -94,8 +109,165 @@ V: ElasticTypeVisitor {
 			try!(serializer.serialize_struct_elt("include_in_all", include_in_all));
 		};
 
+		if let Some(include_in_parent) = T::include_in_parent() {
+			try!(serializer.serialize_struct_elt("include_in_parent", include_in_parent));
+		};
+
+		if let Some(include_in_root) = T::include_in_root() {
+			try!(serializer.serialize_struct_elt("include_in_root", include_in_root));
+		};
+
 		try!(serializer.serialize_struct_elt("properties", ElasticObjectProperties::<V>::new()));
 
 		Ok(None)
 	}
 }
+
+/// The mapping for a user-defined type when it's used as a field on another type,
+/// with some of its object parameters overridden.
+///
+/// This lets the same type be mapped as an `object` in one place and as `nested` in another.
+/// `#[derive(ElasticType)]` uses this for fields with `#[elastic(object)]`, `#[elastic(nested)]` and the other object attributes,
+/// but it can also be used in a manual properties visitor.
+///
+/// # Examples
+///
+/// ```
+/// # #![feature(plugin, custom_derive)]
+/// # #![plugin(json_str, elastic_types_macros)]
+/// # #[macro_use]
+/// # extern crate elastic_types;
+/// # extern crate serde;
+/// # use serde::{ Serialize, Deserialize };
+/// # use elastic_types::mapping::prelude::*;
+/// # #[derive(Default, Clone, Serialize, Deserialize, ElasticType)]
+/// # pub struct MyType {
+/// # 	pub my_string: String
+/// # }
+/// # impl serde::Serialize for MyType {
+/// # 	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where S: serde::Serializer {
+/// # 		unimplemented!()
+/// # 	}
+/// # }
+/// # impl serde::Deserialize for MyType {
+/// # 	 fn deserialize<D>(deserializer: &mut D) -> Result<Self, D::Error> where D: serde::Deserializer {
+/// # 		unimplemented!()
+/// # 	}
+/// # }
+/// # fn main() {
+/// let mapping = ElasticObjectFieldMapping::new(MyType::mapping())
+/// 	.data_type(NESTED_DATATYPE)
+/// 	.include_in_parent(true);
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ElasticObjectFieldMapping<M> where
+M: Serialize {
+	mapping: M,
+	data_type: Option<&'static str>,
+	dynamic: Option<Dynamic>,
+	enabled: Option<bool>,
+	include_in_all: Option<bool>,
+	include_in_parent: Option<bool>,
+	include_in_root: Option<bool>
+}
+
+impl <M> ElasticObjectFieldMapping<M> where
+M: Serialize {
+	/// Wrap the mapping for a field, without overriding any parameters.
+	pub fn new(mapping: M) -> ElasticObjectFieldMapping<M> {
+		ElasticObjectFieldMapping {
+			mapping: mapping,
+			data_type: None,
+			dynamic: None,
+			enabled: None,
+			include_in_all: None,
+			include_in_parent: None,
+			include_in_root: None
+		}
+	}
+
+	/// Override the type of the field, like `object` or `nested`.
+	pub fn data_type(mut self, data_type: &'static str) -> ElasticObjectFieldMapping<M> {
+		self.data_type = Some(data_type);
+		self
+	}
+
+	/// Override whether new properties are added dynamically.
+	pub fn dynamic(mut self, dynamic: Dynamic) -> ElasticObjectFieldMapping<M> {
+		self.dynamic = Some(dynamic);
+		self
+	}
+
+	/// Override whether the field is parsed and indexed.
+	pub fn enabled(mut self, enabled: bool) -> ElasticObjectFieldMapping<M> {
+		self.enabled = Some(enabled);
+		self
+	}
+
+	/// Override the default `include_in_all` value for properties within the field.
+	pub fn include_in_all(mut self, include_in_all: bool) -> ElasticObjectFieldMapping<M> {
+		self.include_in_all = Some(include_in_all);
+		self
+	}
+
+	/// Override whether the fields of a `nested` object are also added to the parent document.
+	pub fn include_in_parent(mut self, include_in_parent: bool) -> ElasticObjectFieldMapping<M> {
+		self.include_in_parent = Some(include_in_parent);
+		self
+	}
+
+	/// Override whether the fields of a `nested` object are also added to the root document.
+	pub fn include_in_root(mut self, include_in_root: bool) -> ElasticObjectFieldMapping<M> {
+		self.include_in_root = Some(include_in_root);
+		self
+	}
+}
+
+impl <M> serde::Serialize for ElasticObjectFieldMapping<M> where
+M: Serialize {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+	where S: Serializer {
+		let mut mapping = match serde_json::to_value(&self.mapping) {
+			Value::Object(mapping) => mapping,
+			mapping => return mapping.serialize(serializer)
+		};
+
+		//Overridden parameters replace the ones on the mapping, keeping the order of `ElasticObjectMappingVisitor`
+		let mut params = Vec::new();
+		take_param(&mut params, &mut mapping, "type", self.data_type.map(|data_type| Value::String(data_type.to_owned())));
+		take_param(&mut params, &mut mapping, "dynamic", self.dynamic.map(|dynamic| serde_json::to_value(&dynamic)));
+		take_param(&mut params, &mut mapping, "enabled", self.enabled.map(Value::Bool));
+		take_param(&mut params, &mut mapping, "include_in_all", self.include_in_all.map(Value::Bool));
+		take_param(&mut params, &mut mapping, "include_in_parent", self.include_in_parent.map(Value::Bool));
+		take_param(&mut params, &mut mapping, "include_in_root", self.include_in_root.map(Value::Bool));
+		params.extend(mapping);
+
+		serializer.serialize_map(ElasticObjectFieldMappingVisitor {
+			params: &params
+		})
+	}
+}
+
+fn take_param(params: &mut Vec<(String, Value)>, mapping: &mut BTreeMap<String, Value>, key: &str, value: Option<Value>) {
+	let current = mapping.remove(key);
+
+	if let Some(value) = value.or(current) {
+		params.push((key.to_owned(), value));
+	}
+}
+
+struct ElasticObjectFieldMappingVisitor<'a> {
+	params: &'a [(String, Value)]
+}
+
+impl <'a> serde::ser::MapVisitor for ElasticObjectFieldMappingVisitor<'a> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error>
+	where S: Serializer {
+		for &(ref key, ref value) in self.params {
+			try!(serializer.serialize_map_elt(key, value));
+		}
+
+		Ok(None)
+	}
+}
@@ -0,0 +1,936 @@
+use std::collections::BTreeMap;
+use serde;
+use serde::{ Serialize, Serializer };
+use serde_json;
+use serde_json::Value;
+use super::{ Query, FieldVisitor };
+
+/// A named set of aggregations.
+pub type Aggregations = BTreeMap<String, Aggregation>;
+
+/// An aggregation in a search request.
+///
+/// You don't normally need to build this enum directly.
+/// Instead, construct one of the specific aggregation types (like `TermsAggregation`) and use `into()`.
+///
+/// Bucket aggregations, like `terms` or `date_histogram`, can contain sub-aggregations
+/// that are calculated for each bucket.
+///
+/// # Examples
+///
+/// ```
+/// use elastic_types::query::*;
+///
+/// let search = Search::new()
+/// 	.size(0)
+/// 	.aggregation("tags", TermsAggregation::new("tag")
+/// 		.size(10)
+/// 		.aggregation("avg_stars", MetricAggregation::avg("stars"))
+/// 	);
+/// ```
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations.html)
+#[derive(Debug, Clone, PartialEq)]
+pub enum Aggregation {
+	/// A `terms` aggregation.
+	Terms(TermsAggregation),
+	/// A `histogram` aggregation.
+	Histogram(HistogramAggregation),
+	/// A `date_histogram` aggregation.
+	DateHistogram(DateHistogramAggregation),
+	/// A `range` aggregation.
+	Range(RangeAggregation),
+	/// A single-field metric aggregation, like `avg` or `stats`.
+	Metric(MetricAggregation),
+	/// A `cardinality` aggregation.
+	Cardinality(CardinalityAggregation),
+	/// A `percentiles` aggregation.
+	Percentiles(PercentilesAggregation),
+	/// A `top_hits` aggregation.
+	TopHits(TopHitsAggregation),
+	/// A `filter` aggregation.
+	Filter(FilterAggregation),
+	/// A `nested` aggregation.
+	Nested(NestedAggregation)
+}
+
+impl Aggregation {
+	/// Get the name of the aggregation type, like `"terms"` or `"avg"`.
+	pub fn name(&self) -> &'static str {
+		match *self {
+			Aggregation::Terms(_) => "terms",
+			Aggregation::Histogram(_) => "histogram",
+			Aggregation::DateHistogram(_) => "date_histogram",
+			Aggregation::Range(_) => "range",
+			Aggregation::Metric(ref agg) => agg.metric.as_str(),
+			Aggregation::Cardinality(_) => "cardinality",
+			Aggregation::Percentiles(_) => "percentiles",
+			Aggregation::TopHits(_) => "top_hits",
+			Aggregation::Filter(_) => "filter",
+			Aggregation::Nested(_) => "nested"
+		}
+	}
+
+	/// Get the sub-aggregations, if this is a bucket aggregation.
+	pub fn aggs(&self) -> Option<&Aggregations> {
+		match *self {
+			Aggregation::Terms(ref agg) => Some(&agg.aggs),
+			Aggregation::Histogram(ref agg) => Some(&agg.aggs),
+			Aggregation::DateHistogram(ref agg) => Some(&agg.aggs),
+			Aggregation::Range(ref agg) => Some(&agg.aggs),
+			Aggregation::Filter(ref agg) => Some(&agg.aggs),
+			Aggregation::Nested(ref agg) => Some(&agg.aggs),
+			_ => None
+		}
+	}
+}
+
+impl Serialize for Aggregation {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_map(AggregationVisitor {
+			agg: self
+		})
+	}
+}
+
+struct AggregationVisitor<'a> {
+	agg: &'a Aggregation
+}
+
+impl <'a> serde::ser::MapVisitor for AggregationVisitor<'a> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> where
+	S: Serializer {
+		let name = self.agg.name();
+
+		match *self.agg {
+			Aggregation::Terms(ref agg) => try!(serializer.serialize_map_elt(name, agg)),
+			Aggregation::Histogram(ref agg) => try!(serializer.serialize_map_elt(name, agg)),
+			Aggregation::DateHistogram(ref agg) => try!(serializer.serialize_map_elt(name, agg)),
+			Aggregation::Range(ref agg) => try!(serializer.serialize_map_elt(name, agg)),
+			Aggregation::Metric(ref agg) => try!(serializer.serialize_map_elt(name, agg)),
+			Aggregation::Cardinality(ref agg) => try!(serializer.serialize_map_elt(name, agg)),
+			Aggregation::Percentiles(ref agg) => try!(serializer.serialize_map_elt(name, agg)),
+			Aggregation::TopHits(ref agg) => try!(serializer.serialize_map_elt(name, agg)),
+			Aggregation::Filter(ref agg) => try!(serializer.serialize_map_elt(name, &agg.filter)),
+			Aggregation::Nested(ref agg) => try!(serializer.serialize_map_elt(name, agg))
+		}
+
+		if let Some(aggs) = self.agg.aggs() {
+			if aggs.len() > 0 {
+				try!(serializer.serialize_map_elt("aggs", aggs));
+			}
+		}
+
+		Ok(None)
+	}
+}
+
+macro_rules! impl_into_aggregation {
+	($($agg:ident => $variant:ident),*) => {
+		$(
+			impl From<$agg> for Aggregation {
+				fn from(agg: $agg) -> Aggregation {
+					Aggregation::$variant(agg)
+				}
+			}
+		)*
+	}
+}
+
+impl_into_aggregation!(
+	TermsAggregation => Terms,
+	HistogramAggregation => Histogram,
+	DateHistogramAggregation => DateHistogram,
+	RangeAggregation => Range,
+	MetricAggregation => Metric,
+	CardinalityAggregation => Cardinality,
+	PercentilesAggregation => Percentiles,
+	TopHitsAggregation => TopHits,
+	FilterAggregation => Filter,
+	NestedAggregation => Nested
+);
+
+/// The direction to sort buckets in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SortOrder {
+	/// Sort in ascending order.
+	Asc,
+	/// Sort in descending order.
+	Desc
+}
+
+impl Serialize for SortOrder {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_str(match *self {
+			SortOrder::Asc => "asc",
+			SortOrder::Desc => "desc"
+		})
+	}
+}
+
+/// A bucket aggregation with a bucket for each unique value in a field.
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-terms-aggregation.html)
+#[derive(Debug, Clone, PartialEq)]
+pub struct TermsAggregation {
+	field: String,
+	size: Option<u64>,
+	min_doc_count: Option<u64>,
+	order: Option<(String, SortOrder)>,
+	missing: Option<Value>,
+	aggs: Aggregations
+}
+
+impl TermsAggregation {
+	/// Create a new `terms` aggregation on a field.
+	pub fn new<I>(field: I) -> TermsAggregation where
+	I: Into<String> {
+		TermsAggregation {
+			field: field.into(),
+			size: None,
+			min_doc_count: None,
+			order: None,
+			missing: None,
+			aggs: Aggregations::new()
+		}
+	}
+
+	/// Set the maximum number of buckets to return.
+	pub fn size(mut self, size: u64) -> TermsAggregation {
+		self.size = Some(size);
+		self
+	}
+
+	/// Only return buckets with at least the given number of documents.
+	pub fn min_doc_count(mut self, min_doc_count: u64) -> TermsAggregation {
+		self.min_doc_count = Some(min_doc_count);
+		self
+	}
+
+	/// Sort the buckets by a key, like `"_count"`, `"_term"` or the name of a metric sub-aggregation.
+	pub fn order<I: Into<String>>(mut self, key: I, order: SortOrder) -> TermsAggregation {
+		self.order = Some((key.into(), order));
+		self
+	}
+
+	/// Set the value used for documents that don't have the field.
+	pub fn missing<V: Serialize>(mut self, missing: V) -> TermsAggregation {
+		self.missing = Some(serde_json::to_value(&missing));
+		self
+	}
+
+	/// Add a sub-aggregation that's calculated for each bucket.
+	pub fn aggregation<I, A>(mut self, name: I, agg: A) -> TermsAggregation where
+	I: Into<String>,
+	A: Into<Aggregation> {
+		self.aggs.insert(name.into(), agg.into());
+		self
+	}
+}
+
+impl Serialize for TermsAggregation {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_struct("terms", TermsAggregationVisitor {
+			agg: self
+		})
+	}
+}
+
+struct TermsAggregationVisitor<'a> {
+	agg: &'a TermsAggregation
+}
+
+impl <'a> serde::ser::MapVisitor for TermsAggregationVisitor<'a> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> where
+	S: Serializer {
+		try!(serializer.serialize_struct_elt("field", &self.agg.field));
+
+		if let Some(size) = self.agg.size {
+			try!(serializer.serialize_struct_elt("size", size));
+		}
+
+		if let Some(min_doc_count) = self.agg.min_doc_count {
+			try!(serializer.serialize_struct_elt("min_doc_count", min_doc_count));
+		}
+
+		if let Some((ref key, ref order)) = self.agg.order {
+			try!(serializer.serialize_struct_elt("order", SortBody {
+				key: key,
+				order: *order
+			}));
+		}
+
+		if let Some(ref missing) = self.agg.missing {
+			try!(serializer.serialize_struct_elt("missing", missing));
+		}
+
+		Ok(None)
+	}
+}
+
+struct SortBody<'a> {
+	key: &'a str,
+	order: SortOrder
+}
+
+impl <'a> Serialize for SortBody<'a> {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_map(FieldVisitor {
+			field: self.key,
+			body: &self.order
+		})
+	}
+}
+
+/// A bucket aggregation with a bucket for each interval of a numeric field.
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-histogram-aggregation.html)
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistogramAggregation {
+	field: String,
+	interval: f64,
+	min_doc_count: Option<u64>,
+	aggs: Aggregations
+}
+
+impl HistogramAggregation {
+	/// Create a new `histogram` aggregation on a field with a fixed interval.
+	pub fn new<I>(field: I, interval: f64) -> HistogramAggregation where
+	I: Into<String> {
+		HistogramAggregation {
+			field: field.into(),
+			interval: interval,
+			min_doc_count: None,
+			aggs: Aggregations::new()
+		}
+	}
+
+	/// Only return buckets with at least the given number of documents.
+	pub fn min_doc_count(mut self, min_doc_count: u64) -> HistogramAggregation {
+		self.min_doc_count = Some(min_doc_count);
+		self
+	}
+
+	/// Add a sub-aggregation that's calculated for each bucket.
+	pub fn aggregation<I, A>(mut self, name: I, agg: A) -> HistogramAggregation where
+	I: Into<String>,
+	A: Into<Aggregation> {
+		self.aggs.insert(name.into(), agg.into());
+		self
+	}
+}
+
+impl Serialize for HistogramAggregation {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_struct("histogram", HistogramAggregationVisitor {
+			agg: self
+		})
+	}
+}
+
+struct HistogramAggregationVisitor<'a> {
+	agg: &'a HistogramAggregation
+}
+
+impl <'a> serde::ser::MapVisitor for HistogramAggregationVisitor<'a> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> where
+	S: Serializer {
+		try!(serializer.serialize_struct_elt("field", &self.agg.field));
+		try!(serializer.serialize_struct_elt("interval", self.agg.interval));
+
+		if let Some(min_doc_count) = self.agg.min_doc_count {
+			try!(serializer.serialize_struct_elt("min_doc_count", min_doc_count));
+		}
+
+		Ok(None)
+	}
+}
+
+/// A bucket aggregation with a bucket for each interval of a date field.
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-datehistogram-aggregation.html)
+#[derive(Debug, Clone, PartialEq)]
+pub struct DateHistogramAggregation {
+	field: String,
+	interval: String,
+	format: Option<String>,
+	time_zone: Option<String>,
+	min_doc_count: Option<u64>,
+	aggs: Aggregations
+}
+
+impl DateHistogramAggregation {
+	/// Create a new `date_histogram` aggregation on a field.
+	///
+	/// The `interval` can be a calendar unit, like `"month"`, or a time unit, like `"90m"`.
+	pub fn new<I, V>(field: I, interval: V) -> DateHistogramAggregation where
+	I: Into<String>,
+	V: Into<String> {
+		DateHistogramAggregation {
+			field: field.into(),
+			interval: interval.into(),
+			format: None,
+			time_zone: None,
+			min_doc_count: None,
+			aggs: Aggregations::new()
+		}
+	}
+
+	/// Set the date format used for the `key_as_string` of each bucket.
+	pub fn format<I: Into<String>>(mut self, format: I) -> DateHistogramAggregation {
+		self.format = Some(format.into());
+		self
+	}
+
+	/// Set the time zone buckets are calculated in, like `"+01:00"`.
+	pub fn time_zone<I: Into<String>>(mut self, time_zone: I) -> DateHistogramAggregation {
+		self.time_zone = Some(time_zone.into());
+		self
+	}
+
+	/// Only return buckets with at least the given number of documents.
+	pub fn min_doc_count(mut self, min_doc_count: u64) -> DateHistogramAggregation {
+		self.min_doc_count = Some(min_doc_count);
+		self
+	}
+
+	/// Add a sub-aggregation that's calculated for each bucket.
+	pub fn aggregation<I, A>(mut self, name: I, agg: A) -> DateHistogramAggregation where
+	I: Into<String>,
+	A: Into<Aggregation> {
+		self.aggs.insert(name.into(), agg.into());
+		self
+	}
+}
+
+impl Serialize for DateHistogramAggregation {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_struct("date_histogram", DateHistogramAggregationVisitor {
+			agg: self
+		})
+	}
+}
+
+struct DateHistogramAggregationVisitor<'a> {
+	agg: &'a DateHistogramAggregation
+}
+
+impl <'a> serde::ser::MapVisitor for DateHistogramAggregationVisitor<'a> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> where
+	S: Serializer {
+		try!(serializer.serialize_struct_elt("field", &self.agg.field));
+		try!(serializer.serialize_struct_elt("interval", &self.agg.interval));
+
+		if let Some(ref format) = self.agg.format {
+			try!(serializer.serialize_struct_elt("format", format));
+		}
+
+		if let Some(ref time_zone) = self.agg.time_zone {
+			try!(serializer.serialize_struct_elt("time_zone", time_zone));
+		}
+
+		if let Some(min_doc_count) = self.agg.min_doc_count {
+			try!(serializer.serialize_struct_elt("min_doc_count", min_doc_count));
+		}
+
+		Ok(None)
+	}
+}
+
+/// A bucket aggregation with a bucket for each of a set of ranges.
+///
+/// Each range includes the `from` value and excludes the `to` value.
+///
+/// # Examples
+///
+/// ```
+/// use elastic_types::query::RangeAggregation;
+///
+/// let agg = RangeAggregation::new("price")
+/// 	.to(100)
+/// 	.between(100, 200)
+/// 	.from(200);
+/// ```
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-range-aggregation.html)
+#[derive(Debug, Clone, PartialEq)]
+pub struct RangeAggregation {
+	field: String,
+	ranges: Vec<(Option<Value>, Option<Value>)>,
+	aggs: Aggregations
+}
+
+impl RangeAggregation {
+	/// Create a new `range` aggregation on a field, with no ranges.
+	pub fn new<I>(field: I) -> RangeAggregation where
+	I: Into<String> {
+		RangeAggregation {
+			field: field.into(),
+			ranges: Vec::new(),
+			aggs: Aggregations::new()
+		}
+	}
+
+	/// Add a range for values less than the given value.
+	pub fn to<V: Serialize>(mut self, to: V) -> RangeAggregation {
+		self.ranges.push((None, Some(serde_json::to_value(&to))));
+		self
+	}
+
+	/// Add a range for values greater than or equal to the given value.
+	pub fn from<V: Serialize>(mut self, from: V) -> RangeAggregation {
+		self.ranges.push((Some(serde_json::to_value(&from)), None));
+		self
+	}
+
+	/// Add a range for values between the given values.
+	pub fn between<V: Serialize>(mut self, from: V, to: V) -> RangeAggregation {
+		self.ranges.push((Some(serde_json::to_value(&from)), Some(serde_json::to_value(&to))));
+		self
+	}
+
+	/// Add a sub-aggregation that's calculated for each bucket.
+	pub fn aggregation<I, A>(mut self, name: I, agg: A) -> RangeAggregation where
+	I: Into<String>,
+	A: Into<Aggregation> {
+		self.aggs.insert(name.into(), agg.into());
+		self
+	}
+}
+
+impl Serialize for RangeAggregation {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_struct("range", RangeAggregationVisitor {
+			agg: self
+		})
+	}
+}
+
+struct RangeAggregationVisitor<'a> {
+	agg: &'a RangeAggregation
+}
+
+impl <'a> serde::ser::MapVisitor for RangeAggregationVisitor<'a> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> where
+	S: Serializer {
+		let ranges: Vec<RangeBody> = self.agg.ranges
+			.iter()
+			.map(|&(ref from, ref to)| RangeBody {
+				from: from.as_ref(),
+				to: to.as_ref()
+			})
+			.collect();
+
+		try!(serializer.serialize_struct_elt("field", &self.agg.field));
+		try!(serializer.serialize_struct_elt("ranges", ranges));
+
+		Ok(None)
+	}
+}
+
+struct RangeBody<'a> {
+	from: Option<&'a Value>,
+	to: Option<&'a Value>
+}
+
+impl <'a> Serialize for RangeBody<'a> {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_struct("range", RangeBodyVisitor {
+			range: self
+		})
+	}
+}
+
+struct RangeBodyVisitor<'a> {
+	range: &'a RangeBody<'a>
+}
+
+impl <'a> serde::ser::MapVisitor for RangeBodyVisitor<'a> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> where
+	S: Serializer {
+		if let Some(from) = self.range.from {
+			try!(serializer.serialize_struct_elt("from", from));
+		}
+
+		if let Some(to) = self.range.to {
+			try!(serializer.serialize_struct_elt("to", to));
+		}
+
+		Ok(None)
+	}
+}
+
+/// The kind of value calculated by a `MetricAggregation`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Metric {
+	/// The mean of the values.
+	Avg,
+	/// The sum of the values.
+	Sum,
+	/// The lowest value.
+	Min,
+	/// The highest value.
+	Max,
+	/// The count of values.
+	ValueCount,
+	/// The `count`, `min`, `max`, `avg` and `sum` of the values.
+	Stats
+}
+
+impl Metric {
+	/// Get the name of the metric aggregation.
+	pub fn as_str(&self) -> &'static str {
+		match *self {
+			Metric::Avg => "avg",
+			Metric::Sum => "sum",
+			Metric::Min => "min",
+			Metric::Max => "max",
+			Metric::ValueCount => "value_count",
+			Metric::Stats => "stats"
+		}
+	}
+}
+
+/// A metric aggregation that calculates a value from a numeric field.
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics.html)
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricAggregation {
+	metric: Metric,
+	field: String,
+	missing: Option<Value>
+}
+
+impl MetricAggregation {
+	/// Create a new metric aggregation on a field.
+	pub fn new<I>(metric: Metric, field: I) -> MetricAggregation where
+	I: Into<String> {
+		MetricAggregation {
+			metric: metric,
+			field: field.into(),
+			missing: None
+		}
+	}
+
+	/// Create a new `avg` aggregation on a field.
+	pub fn avg<I: Into<String>>(field: I) -> MetricAggregation {
+		MetricAggregation::new(Metric::Avg, field)
+	}
+
+	/// Create a new `sum` aggregation on a field.
+	pub fn sum<I: Into<String>>(field: I) -> MetricAggregation {
+		MetricAggregation::new(Metric::Sum, field)
+	}
+
+	/// Create a new `min` aggregation on a field.
+	pub fn min<I: Into<String>>(field: I) -> MetricAggregation {
+		MetricAggregation::new(Metric::Min, field)
+	}
+
+	/// Create a new `max` aggregation on a field.
+	pub fn max<I: Into<String>>(field: I) -> MetricAggregation {
+		MetricAggregation::new(Metric::Max, field)
+	}
+
+	/// Create a new `value_count` aggregation on a field.
+	pub fn value_count<I: Into<String>>(field: I) -> MetricAggregation {
+		MetricAggregation::new(Metric::ValueCount, field)
+	}
+
+	/// Create a new `stats` aggregation on a field.
+	pub fn stats<I: Into<String>>(field: I) -> MetricAggregation {
+		MetricAggregation::new(Metric::Stats, field)
+	}
+
+	/// Set the value used for documents that don't have the field.
+	pub fn missing<V: Serialize>(mut self, missing: V) -> MetricAggregation {
+		self.missing = Some(serde_json::to_value(&missing));
+		self
+	}
+}
+
+impl Serialize for MetricAggregation {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_struct("metric", MetricAggregationVisitor {
+			agg: self
+		})
+	}
+}
+
+struct MetricAggregationVisitor<'a> {
+	agg: &'a MetricAggregation
+}
+
+impl <'a> serde::ser::MapVisitor for MetricAggregationVisitor<'a> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> where
+	S: Serializer {
+		try!(serializer.serialize_struct_elt("field", &self.agg.field));
+
+		if let Some(ref missing) = self.agg.missing {
+			try!(serializer.serialize_struct_elt("missing", missing));
+		}
+
+		Ok(None)
+	}
+}
+
+/// A metric aggregation that approximates the number of distinct values in a field.
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics-cardinality-aggregation.html)
+#[derive(Debug, Clone, PartialEq)]
+pub struct CardinalityAggregation {
+	field: String,
+	precision_threshold: Option<u64>
+}
+
+impl CardinalityAggregation {
+	/// Create a new `cardinality` aggregation on a field.
+	pub fn new<I>(field: I) -> CardinalityAggregation where
+	I: Into<String> {
+		CardinalityAggregation {
+			field: field.into(),
+			precision_threshold: None
+		}
+	}
+
+	/// Set the count below which the cardinality is expected to be close to accurate.
+	pub fn precision_threshold(mut self, precision_threshold: u64) -> CardinalityAggregation {
+		self.precision_threshold = Some(precision_threshold);
+		self
+	}
+}
+
+impl Serialize for CardinalityAggregation {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_struct("cardinality", CardinalityAggregationVisitor {
+			agg: self
+		})
+	}
+}
+
+struct CardinalityAggregationVisitor<'a> {
+	agg: &'a CardinalityAggregation
+}
+
+impl <'a> serde::ser::MapVisitor for CardinalityAggregationVisitor<'a> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> where
+	S: Serializer {
+		try!(serializer.serialize_struct_elt("field", &self.agg.field));
+
+		if let Some(precision_threshold) = self.agg.precision_threshold {
+			try!(serializer.serialize_struct_elt("precision_threshold", precision_threshold));
+		}
+
+		Ok(None)
+	}
+}
+
+/// A metric aggregation that approximates percentiles of the values in a numeric field.
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics-percentile-aggregation.html)
+#[derive(Debug, Clone, PartialEq)]
+pub struct PercentilesAggregation {
+	field: String,
+	percents: Option<Vec<f64>>
+}
+
+impl PercentilesAggregation {
+	/// Create a new `percentiles` aggregation on a field.
+	///
+	/// By default, the `[ 1, 5, 25, 50, 75, 95, 99 ]` percentiles are calculated.
+	pub fn new<I>(field: I) -> PercentilesAggregation where
+	I: Into<String> {
+		PercentilesAggregation {
+			field: field.into(),
+			percents: None
+		}
+	}
+
+	/// Set the percentiles to calculate.
+	pub fn percents(mut self, percents: Vec<f64>) -> PercentilesAggregation {
+		self.percents = Some(percents);
+		self
+	}
+}
+
+impl Serialize for PercentilesAggregation {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_struct("percentiles", PercentilesAggregationVisitor {
+			agg: self
+		})
+	}
+}
+
+struct PercentilesAggregationVisitor<'a> {
+	agg: &'a PercentilesAggregation
+}
+
+impl <'a> serde::ser::MapVisitor for PercentilesAggregationVisitor<'a> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> where
+	S: Serializer {
+		try!(serializer.serialize_struct_elt("field", &self.agg.field));
+
+		if let Some(ref percents) = self.agg.percents {
+			try!(serializer.serialize_struct_elt("percents", percents));
+		}
+
+		Ok(None)
+	}
+}
+
+/// A metric aggregation that returns the top matching documents in each bucket.
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics-top-hits-aggregation.html)
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TopHitsAggregation {
+	from: Option<u64>,
+	size: Option<u64>,
+	source: Option<Vec<String>>
+}
+
+impl TopHitsAggregation {
+	/// Create a new `top_hits` aggregation.
+	pub fn new() -> TopHitsAggregation {
+		TopHitsAggregation::default()
+	}
+
+	/// Set the offset of the first hit to return.
+	pub fn from(mut self, from: u64) -> TopHitsAggregation {
+		self.from = Some(from);
+		self
+	}
+
+	/// Set the maximum number of hits to return for each bucket.
+	pub fn size(mut self, size: u64) -> TopHitsAggregation {
+		self.size = Some(size);
+		self
+	}
+
+	/// Only include the given fields in the `_source` of each hit.
+	pub fn source<F, T>(mut self, fields: F) -> TopHitsAggregation where
+	F: IntoIterator<Item = T>,
+	T: Into<String> {
+		self.source = Some(fields.into_iter().map(|f| f.into()).collect());
+		self
+	}
+}
+
+impl Serialize for TopHitsAggregation {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_struct("top_hits", TopHitsAggregationVisitor {
+			agg: self
+		})
+	}
+}
+
+struct TopHitsAggregationVisitor<'a> {
+	agg: &'a TopHitsAggregation
+}
+
+impl <'a> serde::ser::MapVisitor for TopHitsAggregationVisitor<'a> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> where
+	S: Serializer {
+		if let Some(from) = self.agg.from {
+			try!(serializer.serialize_struct_elt("from", from));
+		}
+
+		if let Some(size) = self.agg.size {
+			try!(serializer.serialize_struct_elt("size", size));
+		}
+
+		if let Some(ref source) = self.agg.source {
+			try!(serializer.serialize_struct_elt("_source", source));
+		}
+
+		Ok(None)
+	}
+}
+
+/// A single bucket aggregation for the documents that match a query.
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-filter-aggregation.html)
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilterAggregation {
+	filter: Query,
+	aggs: Aggregations
+}
+
+impl FilterAggregation {
+	/// Create a new `filter` aggregation for the given query.
+	pub fn new<Q>(filter: Q) -> FilterAggregation where
+	Q: Into<Query> {
+		FilterAggregation {
+			filter: filter.into(),
+			aggs: Aggregations::new()
+		}
+	}
+
+	/// Add a sub-aggregation that's calculated for the bucket.
+	pub fn aggregation<I, A>(mut self, name: I, agg: A) -> FilterAggregation where
+	I: Into<String>,
+	A: Into<Aggregation> {
+		self.aggs.insert(name.into(), agg.into());
+		self
+	}
+}
+
+/// A single bucket aggregation for the `nested` objects at a path.
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-nested-aggregation.html)
+#[derive(Debug, Clone, PartialEq)]
+pub struct NestedAggregation {
+	path: String,
+	aggs: Aggregations
+}
+
+impl NestedAggregation {
+	/// Create a new `nested` aggregation for the objects at the given path.
+	pub fn new<I>(path: I) -> NestedAggregation where
+	I: Into<String> {
+		NestedAggregation {
+			path: path.into(),
+			aggs: Aggregations::new()
+		}
+	}
+
+	/// Add a sub-aggregation that's calculated for the nested objects.
+	pub fn aggregation<I, A>(mut self, name: I, agg: A) -> NestedAggregation where
+	I: Into<String>,
+	A: Into<Aggregation> {
+		self.aggs.insert(name.into(), agg.into());
+		self
+	}
+}
+
+impl Serialize for NestedAggregation {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_map(FieldVisitor {
+			field: "path",
+			body: &self.path
+		})
+	}
+}
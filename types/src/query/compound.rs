@@ -0,0 +1,880 @@
+use serde;
+use serde::{ Serialize, Serializer };
+use serde_json;
+use serde_json::Value;
+use super::{ Query, FieldVisitor };
+
+/// A query that combines other queries with boolean clauses.
+///
+/// - `must` clauses must match and contribute to the score
+/// - `filter` clauses must match but don't contribute to the score
+/// - `should` clauses may match, and contribute to the score if they do
+/// - `must_not` clauses must not match
+///
+/// # Examples
+///
+/// ```
+/// use elastic_types::query::{ BoolQuery, TermQuery, RangeQuery };
+///
+/// let query = BoolQuery::new()
+/// 	.must(TermQuery::new("user", "kimchy"))
+/// 	.filter(TermQuery::new("tag", "tech"))
+/// 	.must_not(RangeQuery::new("age").gte(10).lte(20))
+/// 	.should(TermQuery::new("tag", "wow"))
+/// 	.should(TermQuery::new("tag", "elasticsearch"))
+/// 	.minimum_should_match("1");
+/// ```
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-bool-query.html)
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct BoolQuery {
+	must: Vec<Query>,
+	filter: Vec<Query>,
+	should: Vec<Query>,
+	must_not: Vec<Query>,
+	minimum_should_match: Option<String>,
+	boost: Option<f32>
+}
+
+impl BoolQuery {
+	/// Create a new, empty `bool` query.
+	pub fn new() -> BoolQuery {
+		BoolQuery::default()
+	}
+
+	/// Add a clause that must match.
+	pub fn must<Q: Into<Query>>(mut self, query: Q) -> BoolQuery {
+		self.must.push(query.into());
+		self
+	}
+
+	/// Add a clause that must match, without contributing to the score.
+	pub fn filter<Q: Into<Query>>(mut self, query: Q) -> BoolQuery {
+		self.filter.push(query.into());
+		self
+	}
+
+	/// Add a clause that should match.
+	pub fn should<Q: Into<Query>>(mut self, query: Q) -> BoolQuery {
+		self.should.push(query.into());
+		self
+	}
+
+	/// Add a clause that must not match.
+	pub fn must_not<Q: Into<Query>>(mut self, query: Q) -> BoolQuery {
+		self.must_not.push(query.into());
+		self
+	}
+
+	/// Set the number or percentage of `should` clauses that must match, like `"1"` or `"50%"`.
+	pub fn minimum_should_match<I: Into<String>>(mut self, minimum_should_match: I) -> BoolQuery {
+		self.minimum_should_match = Some(minimum_should_match.into());
+		self
+	}
+
+	/// Set the boost for this query.
+	pub fn boost(mut self, boost: f32) -> BoolQuery {
+		self.boost = Some(boost);
+		self
+	}
+}
+
+impl Serialize for BoolQuery {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_struct("bool", BoolQueryVisitor {
+			query: self
+		})
+	}
+}
+
+struct BoolQueryVisitor<'a> {
+	query: &'a BoolQuery
+}
+
+impl <'a> serde::ser::MapVisitor for BoolQueryVisitor<'a> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> where
+	S: Serializer {
+		if self.query.must.len() > 0 {
+			try!(serializer.serialize_struct_elt("must", &self.query.must));
+		}
+
+		if self.query.filter.len() > 0 {
+			try!(serializer.serialize_struct_elt("filter", &self.query.filter));
+		}
+
+		if self.query.should.len() > 0 {
+			try!(serializer.serialize_struct_elt("should", &self.query.should));
+		}
+
+		if self.query.must_not.len() > 0 {
+			try!(serializer.serialize_struct_elt("must_not", &self.query.must_not));
+		}
+
+		if let Some(ref minimum_should_match) = self.query.minimum_should_match {
+			try!(serializer.serialize_struct_elt("minimum_should_match", minimum_should_match));
+		}
+
+		if let Some(boost) = self.query.boost {
+			try!(serializer.serialize_struct_elt("boost", boost));
+		}
+
+		Ok(None)
+	}
+}
+
+/// How the scores of matching inner documents are combined into the score of the outer document.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScoreMode {
+	/// Use the mean score of the matching inner documents.
+	Avg,
+	/// Add the scores of the matching inner documents.
+	Sum,
+	/// Use the lowest score of the matching inner documents.
+	Min,
+	/// Use the highest score of the matching inner documents.
+	Max,
+	/// Ignore the scores of the matching inner documents.
+	None
+}
+
+impl Serialize for ScoreMode {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_str(match *self {
+			ScoreMode::Avg => "avg",
+			ScoreMode::Sum => "sum",
+			ScoreMode::Min => "min",
+			ScoreMode::Max => "max",
+			ScoreMode::None => "none"
+		})
+	}
+}
+
+/// A query on `nested` objects, which returns the root documents that contain matching objects.
+///
+/// # Examples
+///
+/// ```
+/// use elastic_types::query::{ NestedQuery, MatchQuery, ScoreMode };
+///
+/// let query = NestedQuery::new("comments", MatchQuery::new("comments.author", "kimchy"))
+/// 	.score_mode(ScoreMode::Max);
+/// ```
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-nested-query.html)
+#[derive(Debug, Clone, PartialEq)]
+pub struct NestedQuery {
+	path: String,
+	query: Box<Query>,
+	score_mode: Option<ScoreMode>
+}
+
+impl NestedQuery {
+	/// Create a new `nested` query on objects at the given path.
+	pub fn new<I, Q>(path: I, query: Q) -> NestedQuery where
+	I: Into<String>,
+	Q: Into<Query> {
+		NestedQuery {
+			path: path.into(),
+			query: Box::new(query.into()),
+			score_mode: None
+		}
+	}
+
+	/// Set how the scores of matching nested objects are combined.
+	pub fn score_mode(mut self, score_mode: ScoreMode) -> NestedQuery {
+		self.score_mode = Some(score_mode);
+		self
+	}
+}
+
+impl Serialize for NestedQuery {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_struct("nested", NestedQueryVisitor {
+			query: self
+		})
+	}
+}
+
+struct NestedQueryVisitor<'a> {
+	query: &'a NestedQuery
+}
+
+impl <'a> serde::ser::MapVisitor for NestedQueryVisitor<'a> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> where
+	S: Serializer {
+		try!(serializer.serialize_struct_elt("path", &self.query.path));
+
+		if let Some(score_mode) = self.query.score_mode {
+			try!(serializer.serialize_struct_elt("score_mode", score_mode));
+		}
+
+		try!(serializer.serialize_struct_elt("query", &self.query.query));
+
+		Ok(None)
+	}
+}
+
+/// A query for parent documents with child documents that match a query.
+///
+/// # Examples
+///
+/// ```
+/// use elastic_types::query::{ HasChildQuery, TermQuery, ScoreMode };
+///
+/// let query = HasChildQuery::new("blog_tag", TermQuery::new("tag", "something"))
+/// 	.score_mode(ScoreMode::Sum)
+/// 	.min_children(2);
+/// ```
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-has-child-query.html)
+#[derive(Debug, Clone, PartialEq)]
+pub struct HasChildQuery {
+	child_type: String,
+	query: Box<Query>,
+	score_mode: Option<ScoreMode>,
+	min_children: Option<u32>,
+	max_children: Option<u32>
+}
+
+impl HasChildQuery {
+	/// Create a new `has_child` query on the given child type.
+	pub fn new<I, Q>(child_type: I, query: Q) -> HasChildQuery where
+	I: Into<String>,
+	Q: Into<Query> {
+		HasChildQuery {
+			child_type: child_type.into(),
+			query: Box::new(query.into()),
+			score_mode: None,
+			min_children: None,
+			max_children: None
+		}
+	}
+
+	/// Set how the scores of matching children are combined.
+	pub fn score_mode(mut self, score_mode: ScoreMode) -> HasChildQuery {
+		self.score_mode = Some(score_mode);
+		self
+	}
+
+	/// Set the minimum number of children that must match.
+	pub fn min_children(mut self, min_children: u32) -> HasChildQuery {
+		self.min_children = Some(min_children);
+		self
+	}
+
+	/// Set the maximum number of children that may match.
+	pub fn max_children(mut self, max_children: u32) -> HasChildQuery {
+		self.max_children = Some(max_children);
+		self
+	}
+}
+
+impl Serialize for HasChildQuery {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_struct("has_child", HasChildQueryVisitor {
+			query: self
+		})
+	}
+}
+
+struct HasChildQueryVisitor<'a> {
+	query: &'a HasChildQuery
+}
+
+impl <'a> serde::ser::MapVisitor for HasChildQueryVisitor<'a> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> where
+	S: Serializer {
+		try!(serializer.serialize_struct_elt("type", &self.query.child_type));
+
+		if let Some(score_mode) = self.query.score_mode {
+			try!(serializer.serialize_struct_elt("score_mode", score_mode));
+		}
+
+		if let Some(min_children) = self.query.min_children {
+			try!(serializer.serialize_struct_elt("min_children", min_children));
+		}
+
+		if let Some(max_children) = self.query.max_children {
+			try!(serializer.serialize_struct_elt("max_children", max_children));
+		}
+
+		try!(serializer.serialize_struct_elt("query", &self.query.query));
+
+		Ok(None)
+	}
+}
+
+/// A query that modifies the scores of documents matching a query with a set of functions.
+///
+/// # Examples
+///
+/// ```
+/// use elastic_types::query::*;
+///
+/// let query = FunctionScoreQuery::new()
+/// 	.query(MatchQuery::new("title", "elasticsearch"))
+/// 	.function(ScoreFunction::field_value_factor(FieldValueFactor::new("likes").factor(1.2)))
+/// 	.function(ScoreFunction::new().filter(TermQuery::new("featured", true)).weight(2.0))
+/// 	.score_mode(FunctionScoreMode::Sum)
+/// 	.boost_mode(BoostMode::Multiply);
+/// ```
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-function-score-query.html)
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct FunctionScoreQuery {
+	query: Option<Box<Query>>,
+	functions: Vec<ScoreFunction>,
+	score_mode: Option<FunctionScoreMode>,
+	boost_mode: Option<BoostMode>,
+	max_boost: Option<f32>,
+	min_score: Option<f32>,
+	boost: Option<f32>
+}
+
+impl FunctionScoreQuery {
+	/// Create a new `function_score` query.
+	///
+	/// If no query is set, the functions are applied to all documents.
+	pub fn new() -> FunctionScoreQuery {
+		FunctionScoreQuery::default()
+	}
+
+	/// Set the query whose matching documents are scored.
+	pub fn query<Q: Into<Query>>(mut self, query: Q) -> FunctionScoreQuery {
+		self.query = Some(Box::new(query.into()));
+		self
+	}
+
+	/// Add a function to score documents with.
+	pub fn function(mut self, function: ScoreFunction) -> FunctionScoreQuery {
+		self.functions.push(function);
+		self
+	}
+
+	/// Set how the scores of the functions are combined.
+	pub fn score_mode(mut self, score_mode: FunctionScoreMode) -> FunctionScoreQuery {
+		self.score_mode = Some(score_mode);
+		self
+	}
+
+	/// Set how the combined function score is combined with the query score.
+	pub fn boost_mode(mut self, boost_mode: BoostMode) -> FunctionScoreQuery {
+		self.boost_mode = Some(boost_mode);
+		self
+	}
+
+	/// Set the maximum combined function score.
+	pub fn max_boost(mut self, max_boost: f32) -> FunctionScoreQuery {
+		self.max_boost = Some(max_boost);
+		self
+	}
+
+	/// Exclude documents with a final score below the given value.
+	pub fn min_score(mut self, min_score: f32) -> FunctionScoreQuery {
+		self.min_score = Some(min_score);
+		self
+	}
+
+	/// Set the boost for this query.
+	pub fn boost(mut self, boost: f32) -> FunctionScoreQuery {
+		self.boost = Some(boost);
+		self
+	}
+}
+
+impl Serialize for FunctionScoreQuery {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_struct("function_score", FunctionScoreQueryVisitor {
+			query: self
+		})
+	}
+}
+
+struct FunctionScoreQueryVisitor<'a> {
+	query: &'a FunctionScoreQuery
+}
+
+impl <'a> serde::ser::MapVisitor for FunctionScoreQueryVisitor<'a> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> where
+	S: Serializer {
+		if let Some(ref query) = self.query.query {
+			try!(serializer.serialize_struct_elt("query", query));
+		}
+
+		if self.query.functions.len() > 0 {
+			try!(serializer.serialize_struct_elt("functions", &self.query.functions));
+		}
+
+		if let Some(score_mode) = self.query.score_mode {
+			try!(serializer.serialize_struct_elt("score_mode", score_mode));
+		}
+
+		if let Some(boost_mode) = self.query.boost_mode {
+			try!(serializer.serialize_struct_elt("boost_mode", boost_mode));
+		}
+
+		if let Some(max_boost) = self.query.max_boost {
+			try!(serializer.serialize_struct_elt("max_boost", max_boost));
+		}
+
+		if let Some(min_score) = self.query.min_score {
+			try!(serializer.serialize_struct_elt("min_score", min_score));
+		}
+
+		if let Some(boost) = self.query.boost {
+			try!(serializer.serialize_struct_elt("boost", boost));
+		}
+
+		Ok(None)
+	}
+}
+
+/// A function used to score documents in a `function_score` query.
+///
+/// A function can optionally be restricted to documents matching a `filter`,
+/// and can have a `weight` that its score is multiplied by.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ScoreFunction {
+	filter: Option<Query>,
+	weight: Option<f32>,
+	function: Option<ScoreFunctionKind>
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum ScoreFunctionKind {
+	FieldValueFactor(FieldValueFactor),
+	RandomScore(u64),
+	ScriptScore(String),
+	Decay(DecayScore)
+}
+
+impl ScoreFunction {
+	/// Create a new function that only applies a `weight`.
+	pub fn new() -> ScoreFunction {
+		ScoreFunction::default()
+	}
+
+	/// Create a new function that scores documents using the value of a field.
+	pub fn field_value_factor(factor: FieldValueFactor) -> ScoreFunction {
+		ScoreFunction::with_function(ScoreFunctionKind::FieldValueFactor(factor))
+	}
+
+	/// Create a new function that scores documents randomly, using the given seed.
+	pub fn random_score(seed: u64) -> ScoreFunction {
+		ScoreFunction::with_function(ScoreFunctionKind::RandomScore(seed))
+	}
+
+	/// Create a new function that scores documents using an inline script.
+	pub fn script_score<I: Into<String>>(script: I) -> ScoreFunction {
+		ScoreFunction::with_function(ScoreFunctionKind::ScriptScore(script.into()))
+	}
+
+	/// Create a new function that scores documents by the distance of a field value from an origin.
+	pub fn decay(decay: DecayScore) -> ScoreFunction {
+		ScoreFunction::with_function(ScoreFunctionKind::Decay(decay))
+	}
+
+	fn with_function(function: ScoreFunctionKind) -> ScoreFunction {
+		ScoreFunction {
+			filter: None,
+			weight: None,
+			function: Some(function)
+		}
+	}
+
+	/// Only apply this function to documents that match the given query.
+	pub fn filter<Q: Into<Query>>(mut self, filter: Q) -> ScoreFunction {
+		self.filter = Some(filter.into());
+		self
+	}
+
+	/// Set the weight the function score is multiplied by.
+	pub fn weight(mut self, weight: f32) -> ScoreFunction {
+		self.weight = Some(weight);
+		self
+	}
+}
+
+impl Serialize for ScoreFunction {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_struct("function", ScoreFunctionVisitor {
+			function: self
+		})
+	}
+}
+
+struct ScoreFunctionVisitor<'a> {
+	function: &'a ScoreFunction
+}
+
+impl <'a> serde::ser::MapVisitor for ScoreFunctionVisitor<'a> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> where
+	S: Serializer {
+		if let Some(ref filter) = self.function.filter {
+			try!(serializer.serialize_struct_elt("filter", filter));
+		}
+
+		match self.function.function {
+			Some(ScoreFunctionKind::FieldValueFactor(ref factor)) => try!(serializer.serialize_struct_elt("field_value_factor", factor)),
+			Some(ScoreFunctionKind::RandomScore(seed)) => try!(serializer.serialize_struct_elt("random_score", RandomScoreBody {
+				seed: seed
+			})),
+			Some(ScoreFunctionKind::ScriptScore(ref script)) => try!(serializer.serialize_struct_elt("script_score", ScriptScoreBody {
+				script: script
+			})),
+			Some(ScoreFunctionKind::Decay(ref decay)) => try!(serializer.serialize_struct_elt(decay.function.as_str(), decay)),
+			None => ()
+		}
+
+		if let Some(weight) = self.function.weight {
+			try!(serializer.serialize_struct_elt("weight", weight));
+		}
+
+		Ok(None)
+	}
+}
+
+struct RandomScoreBody {
+	seed: u64
+}
+
+impl Serialize for RandomScoreBody {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_map(FieldVisitor {
+			field: "seed",
+			body: &self.seed
+		})
+	}
+}
+
+struct ScriptScoreBody<'a> {
+	script: &'a str
+}
+
+impl <'a> Serialize for ScriptScoreBody<'a> {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_map(FieldVisitor {
+			field: "script",
+			body: &self.script
+		})
+	}
+}
+
+/// A score function that uses the value of a numeric field.
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-function-score-query.html#function-field-value-factor)
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldValueFactor {
+	field: String,
+	factor: Option<f32>,
+	modifier: Option<FieldValueFactorModifier>,
+	missing: Option<f64>
+}
+
+impl FieldValueFactor {
+	/// Create a new `field_value_factor` function on a field.
+	pub fn new<I>(field: I) -> FieldValueFactor where
+	I: Into<String> {
+		FieldValueFactor {
+			field: field.into(),
+			factor: None,
+			modifier: None,
+			missing: None
+		}
+	}
+
+	/// Set the factor the field value is multiplied by.
+	pub fn factor(mut self, factor: f32) -> FieldValueFactor {
+		self.factor = Some(factor);
+		self
+	}
+
+	/// Set the modifier applied to the field value.
+	pub fn modifier(mut self, modifier: FieldValueFactorModifier) -> FieldValueFactor {
+		self.modifier = Some(modifier);
+		self
+	}
+
+	/// Set the value used for documents that don't have the field.
+	pub fn missing(mut self, missing: f64) -> FieldValueFactor {
+		self.missing = Some(missing);
+		self
+	}
+}
+
+impl Serialize for FieldValueFactor {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_struct("field_value_factor", FieldValueFactorVisitor {
+			factor: self
+		})
+	}
+}
+
+struct FieldValueFactorVisitor<'a> {
+	factor: &'a FieldValueFactor
+}
+
+impl <'a> serde::ser::MapVisitor for FieldValueFactorVisitor<'a> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> where
+	S: Serializer {
+		try!(serializer.serialize_struct_elt("field", &self.factor.field));
+
+		if let Some(factor) = self.factor.factor {
+			try!(serializer.serialize_struct_elt("factor", factor));
+		}
+
+		if let Some(modifier) = self.factor.modifier {
+			try!(serializer.serialize_struct_elt("modifier", modifier));
+		}
+
+		if let Some(missing) = self.factor.missing {
+			try!(serializer.serialize_struct_elt("missing", missing));
+		}
+
+		Ok(None)
+	}
+}
+
+/// A modifier applied to a field value in a `field_value_factor` function.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FieldValueFactorModifier {
+	/// Don't modify the value.
+	None,
+	/// Take the common logarithm of the value.
+	Log,
+	/// Add 1 to the value and take the common logarithm.
+	Log1p,
+	/// Add 2 to the value and take the common logarithm.
+	Log2p,
+	/// Take the natural logarithm of the value.
+	Ln,
+	/// Add 1 to the value and take the natural logarithm.
+	Ln1p,
+	/// Add 2 to the value and take the natural logarithm.
+	Ln2p,
+	/// Square the value.
+	Square,
+	/// Take the square root of the value.
+	Sqrt,
+	/// Take the reciprocal of the value.
+	Reciprocal
+}
+
+impl Serialize for FieldValueFactorModifier {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_str(match *self {
+			FieldValueFactorModifier::None => "none",
+			FieldValueFactorModifier::Log => "log",
+			FieldValueFactorModifier::Log1p => "log1p",
+			FieldValueFactorModifier::Log2p => "log2p",
+			FieldValueFactorModifier::Ln => "ln",
+			FieldValueFactorModifier::Ln1p => "ln1p",
+			FieldValueFactorModifier::Ln2p => "ln2p",
+			FieldValueFactorModifier::Square => "square",
+			FieldValueFactorModifier::Sqrt => "sqrt",
+			FieldValueFactorModifier::Reciprocal => "reciprocal"
+		})
+	}
+}
+
+/// A score function that decays with the distance of a field value from an origin.
+///
+/// # Examples
+///
+/// ```
+/// use elastic_types::query::{ DecayScore, DecayFunction };
+///
+/// let decay = DecayScore::new(DecayFunction::Gauss, "date", "2013-09-17", "10d")
+/// 	.offset("5d")
+/// 	.decay(0.5);
+/// ```
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-function-score-query.html#function-decay)
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecayScore {
+	function: DecayFunction,
+	field: String,
+	origin: Value,
+	scale: String,
+	offset: Option<String>,
+	decay: Option<f64>
+}
+
+impl DecayScore {
+	/// Create a new decay function on a field.
+	///
+	/// The `origin` is the value where the score is highest,
+	/// and the `scale` is the distance from the origin where the score is equal to `decay`.
+	pub fn new<I, V, C>(function: DecayFunction, field: I, origin: V, scale: C) -> DecayScore where
+	I: Into<String>,
+	V: Serialize,
+	C: Into<String> {
+		DecayScore {
+			function: function,
+			field: field.into(),
+			origin: serde_json::to_value(&origin),
+			scale: scale.into(),
+			offset: None,
+			decay: None
+		}
+	}
+
+	/// Only start decaying the score at the given distance from the origin.
+	pub fn offset<I: Into<String>>(mut self, offset: I) -> DecayScore {
+		self.offset = Some(offset.into());
+		self
+	}
+
+	/// Set the score of documents at `scale` distance from the origin.
+	pub fn decay(mut self, decay: f64) -> DecayScore {
+		self.decay = Some(decay);
+		self
+	}
+}
+
+impl Serialize for DecayScore {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_map(FieldVisitor {
+			field: &self.field,
+			body: &DecayScoreBody {
+				decay: self
+			}
+		})
+	}
+}
+
+struct DecayScoreBody<'a> {
+	decay: &'a DecayScore
+}
+
+impl <'a> Serialize for DecayScoreBody<'a> {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_struct("decay", DecayScoreBodyVisitor {
+			decay: self.decay
+		})
+	}
+}
+
+struct DecayScoreBodyVisitor<'a> {
+	decay: &'a DecayScore
+}
+
+impl <'a> serde::ser::MapVisitor for DecayScoreBodyVisitor<'a> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> where
+	S: Serializer {
+		try!(serializer.serialize_struct_elt("origin", &self.decay.origin));
+		try!(serializer.serialize_struct_elt("scale", &self.decay.scale));
+
+		if let Some(ref offset) = self.decay.offset {
+			try!(serializer.serialize_struct_elt("offset", offset));
+		}
+
+		if let Some(decay) = self.decay.decay {
+			try!(serializer.serialize_struct_elt("decay", decay));
+		}
+
+		Ok(None)
+	}
+}
+
+/// The curve used by a decay score function.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DecayFunction {
+	/// Normal decay.
+	Gauss,
+	/// Linear decay, reaching `0` at twice the `scale`.
+	Linear,
+	/// Exponential decay.
+	Exp
+}
+
+impl DecayFunction {
+	/// Get the name of the decay function.
+	pub fn as_str(&self) -> &'static str {
+		match *self {
+			DecayFunction::Gauss => "gauss",
+			DecayFunction::Linear => "linear",
+			DecayFunction::Exp => "exp"
+		}
+	}
+}
+
+/// How the scores of the functions in a `function_score` query are combined.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FunctionScoreMode {
+	/// Multiply the scores.
+	Multiply,
+	/// Add the scores.
+	Sum,
+	/// Use the mean of the scores.
+	Avg,
+	/// Use the score of the first function with a matching filter.
+	First,
+	/// Use the highest score.
+	Max,
+	/// Use the lowest score.
+	Min
+}
+
+impl Serialize for FunctionScoreMode {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_str(match *self {
+			FunctionScoreMode::Multiply => "multiply",
+			FunctionScoreMode::Sum => "sum",
+			FunctionScoreMode::Avg => "avg",
+			FunctionScoreMode::First => "first",
+			FunctionScoreMode::Max => "max",
+			FunctionScoreMode::Min => "min"
+		})
+	}
+}
+
+/// How the combined function score is combined with the query score in a `function_score` query.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BoostMode {
+	/// Multiply the query score by the function score.
+	Multiply,
+	/// Use the function score and ignore the query score.
+	Replace,
+	/// Add the query score and the function score.
+	Sum,
+	/// Use the mean of the query score and the function score.
+	Avg,
+	/// Use the higher of the query score and the function score.
+	Max,
+	/// Use the lower of the query score and the function score.
+	Min
+}
+
+impl Serialize for BoostMode {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_str(match *self {
+			BoostMode::Multiply => "multiply",
+			BoostMode::Replace => "replace",
+			BoostMode::Sum => "sum",
+			BoostMode::Avg => "avg",
+			BoostMode::Max => "max",
+			BoostMode::Min => "min"
+		})
+	}
+}
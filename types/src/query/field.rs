@@ -0,0 +1,173 @@
+use std::marker::PhantomData;
+use serde::Serialize;
+use ::mapping::{ ElasticFieldAnalysis, NotAnalyzed };
+use ::object::FieldPath;
+use super::{ Query, TermQuery, TermsQuery, RangeQuery, ExistsQuery, PrefixQuery, WildcardQuery, MatchQuery };
+
+/// Queries that can be built from the path to a field on a mapped type.
+///
+/// Values given to these queries must convert into the type of the field,
+/// so they're serialised the same way as the field itself.
+/// For example, a range on an `ElasticDate<EpochMillis>` field only accepts dates,
+/// and they're serialised as `epoch_millis`.
+///
+/// The queries available also depend on how the field's mapping analyses values.
+/// Exact value queries like `term`, `range` and `prefix` need a field that isn't analysed,
+/// so they aren't available on a `string` unless its mapping sets `Analysis` to `NotAnalyzed`.
+/// Full text `match` queries are available on any `string`.
+///
+/// # Examples
+///
+/// ```
+/// # #![feature(plugin, custom_derive, custom_attribute)]
+/// # #![plugin(elastic_types_macros)]
+/// # #[macro_use]
+/// # extern crate elastic_types;
+/// # extern crate serde;
+/// # extern crate chrono;
+/// # use serde::{ Serialize, Deserialize };
+/// use chrono::{ UTC, TimeZone };
+/// use elastic_types::mapping::prelude::*;
+/// use elastic_types::date::prelude::*;
+/// use elastic_types::string::prelude::*;
+/// use elastic_types::query::*;
+///
+/// #[derive(Debug, Clone, Default, ElasticStringMapping)]
+/// pub struct MyTagMapping;
+/// impl ElasticStringMapping for MyTagMapping {
+/// 	type Analysis = NotAnalyzed;
+/// }
+///
+/// #[derive(Serialize, Deserialize, ElasticType)]
+/// pub struct MyType {
+/// 	pub my_date: ElasticDate<EpochMillis>,
+/// 	pub my_string: String,
+/// 	pub my_tag: ElasticString<MyTagMapping>
+/// }
+/// # impl serde::Serialize for MyType {
+/// # 	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where S: serde::Serializer {
+/// # 		unimplemented!()
+/// # 	}
+/// # }
+/// # impl serde::Deserialize for MyType {
+/// # 	 fn deserialize<D>(deserializer: &mut D) -> Result<Self, D::Error> where D: serde::Deserializer {
+/// # 		unimplemented!()
+/// # 	}
+/// # }
+///
+/// # fn main() {
+/// let fields = MyType::field_paths();
+///
+/// let query = BoolQuery::new()
+/// 	.must(fields.my_string.text("some words"))
+/// 	.filter(fields.my_tag.term("some value"))
+/// 	.filter(fields.my_date.range().gte(UTC.ymd(2015, 5, 13).and_hms(0, 0, 0)));
+/// # }
+/// ```
+impl <T> FieldPath<T> {
+	/// Build an `exists` query on this field.
+	pub fn exists(&self) -> ExistsQuery {
+		ExistsQuery::new(self.name())
+	}
+}
+
+impl <T> FieldPath<T> where
+T: Serialize + ElasticFieldAnalysis<Analysis = NotAnalyzed> {
+	/// Build a `term` query for a value in this field.
+	pub fn term<V>(&self, value: V) -> TermQuery where
+	V: Into<T> {
+		TermQuery::new(self.name(), value.into())
+	}
+
+	/// Build a `terms` query for a set of values in this field.
+	pub fn terms<I, V>(&self, values: I) -> TermsQuery where
+	I: IntoIterator<Item = V>,
+	V: Into<T> {
+		TermsQuery::new(self.name(), values.into_iter().map(|v| v.into()))
+	}
+
+	/// Build an unbounded `range` query on this field.
+	pub fn range(&self) -> FieldRangeQuery<T> {
+		FieldRangeQuery {
+			query: RangeQuery::new(self.name()),
+			phantom: PhantomData
+		}
+	}
+}
+
+impl <T> FieldPath<T> where
+T: AsRef<str> {
+	/// Build a full text `match` query on this field.
+	pub fn text<I>(&self, query: I) -> MatchQuery where
+	I: Into<String> {
+		MatchQuery::new(self.name(), query.into())
+	}
+}
+
+impl <T> FieldPath<T> where
+T: AsRef<str> + ElasticFieldAnalysis<Analysis = NotAnalyzed> {
+	/// Build a `prefix` query on this field.
+	pub fn prefix<I>(&self, prefix: I) -> PrefixQuery where
+	I: Into<String> {
+		PrefixQuery::new(self.name(), prefix)
+	}
+
+	/// Build a `wildcard` query on this field.
+	pub fn wildcard<I>(&self, pattern: I) -> WildcardQuery where
+	I: Into<String> {
+		WildcardQuery::new(self.name(), pattern)
+	}
+}
+
+/// A `range` query whose bounds must convert into the type of the field.
+///
+/// This is built from a `FieldPath` and can be converted into a `Query` like a `RangeQuery`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldRangeQuery<T> {
+	query: RangeQuery,
+	phantom: PhantomData<T>
+}
+
+impl <T> FieldRangeQuery<T> where
+T: Serialize {
+	/// Match values greater than the given value.
+	pub fn gt<V: Into<T>>(mut self, value: V) -> FieldRangeQuery<T> {
+		self.query = self.query.gt(value.into());
+		self
+	}
+
+	/// Match values greater than or equal to the given value.
+	pub fn gte<V: Into<T>>(mut self, value: V) -> FieldRangeQuery<T> {
+		self.query = self.query.gte(value.into());
+		self
+	}
+
+	/// Match values less than the given value.
+	pub fn lt<V: Into<T>>(mut self, value: V) -> FieldRangeQuery<T> {
+		self.query = self.query.lt(value.into());
+		self
+	}
+
+	/// Match values less than or equal to the given value.
+	pub fn lte<V: Into<T>>(mut self, value: V) -> FieldRangeQuery<T> {
+		self.query = self.query.lte(value.into());
+		self
+	}
+
+	/// Set the boost for this query.
+	pub fn boost(mut self, boost: f32) -> FieldRangeQuery<T> {
+		self.query = self.query.boost(boost);
+		self
+	}
+
+	/// Get the untyped `RangeQuery`.
+	pub fn into_inner(self) -> RangeQuery {
+		self.query
+	}
+}
+
+impl <T> From<FieldRangeQuery<T>> for Query {
+	fn from(query: FieldRangeQuery<T>) -> Query {
+		Query::Range(query.query)
+	}
+}
@@ -0,0 +1,459 @@
+use serde;
+use serde::{ Serialize, Serializer };
+use serde_json;
+use serde_json::Value;
+use super::{ FieldVisitor, Operator };
+
+/// A full text query for documents matching the analysed text in a field.
+///
+/// # Examples
+///
+/// ```
+/// use elastic_types::query::{ MatchQuery, Operator };
+///
+/// let query = MatchQuery::new("message", "this is a test").operator(Operator::And);
+/// ```
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-match-query.html)
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchQuery {
+	field: String,
+	query: Value,
+	match_type: Option<MatchType>,
+	operator: Option<Operator>,
+	minimum_should_match: Option<String>,
+	fuzziness: Option<String>,
+	analyzer: Option<String>,
+	boost: Option<f32>
+}
+
+impl MatchQuery {
+	/// Create a new `match` query for some text in a field.
+	pub fn new<I, V>(field: I, query: V) -> MatchQuery where
+	I: Into<String>,
+	V: Serialize {
+		MatchQuery {
+			field: field.into(),
+			query: serde_json::to_value(&query),
+			match_type: None,
+			operator: None,
+			minimum_should_match: None,
+			fuzziness: None,
+			analyzer: None,
+			boost: None
+		}
+	}
+
+	/// Set the way the analysed text is matched.
+	pub fn match_type(mut self, match_type: MatchType) -> MatchQuery {
+		self.match_type = Some(match_type);
+		self
+	}
+
+	/// Set the operator used to combine the analysed terms.
+	pub fn operator(mut self, operator: Operator) -> MatchQuery {
+		self.operator = Some(operator);
+		self
+	}
+
+	/// Set the number or percentage of terms that must match, like `"75%"`.
+	pub fn minimum_should_match<I: Into<String>>(mut self, minimum_should_match: I) -> MatchQuery {
+		self.minimum_should_match = Some(minimum_should_match.into());
+		self
+	}
+
+	/// Set the fuzziness allowed when matching terms, like `"AUTO"` or `"2"`.
+	pub fn fuzziness<I: Into<String>>(mut self, fuzziness: I) -> MatchQuery {
+		self.fuzziness = Some(fuzziness.into());
+		self
+	}
+
+	/// Set the analyzer used to analyse the query text.
+	pub fn analyzer<I: Into<String>>(mut self, analyzer: I) -> MatchQuery {
+		self.analyzer = Some(analyzer.into());
+		self
+	}
+
+	/// Set the boost for this query.
+	pub fn boost(mut self, boost: f32) -> MatchQuery {
+		self.boost = Some(boost);
+		self
+	}
+}
+
+impl Serialize for MatchQuery {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_map(FieldVisitor {
+			field: &self.field,
+			body: &MatchQueryBody {
+				query: self
+			}
+		})
+	}
+}
+
+struct MatchQueryBody<'a> {
+	query: &'a MatchQuery
+}
+
+impl <'a> Serialize for MatchQueryBody<'a> {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_struct("match", MatchQueryBodyVisitor {
+			query: self.query
+		})
+	}
+}
+
+struct MatchQueryBodyVisitor<'a> {
+	query: &'a MatchQuery
+}
+
+impl <'a> serde::ser::MapVisitor for MatchQueryBodyVisitor<'a> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> where
+	S: Serializer {
+		try!(serializer.serialize_struct_elt("query", &self.query.query));
+
+		if let Some(match_type) = self.query.match_type {
+			try!(serializer.serialize_struct_elt("type", match_type));
+		}
+
+		if let Some(operator) = self.query.operator {
+			try!(serializer.serialize_struct_elt("operator", operator));
+		}
+
+		if let Some(ref minimum_should_match) = self.query.minimum_should_match {
+			try!(serializer.serialize_struct_elt("minimum_should_match", minimum_should_match));
+		}
+
+		if let Some(ref fuzziness) = self.query.fuzziness {
+			try!(serializer.serialize_struct_elt("fuzziness", fuzziness));
+		}
+
+		if let Some(ref analyzer) = self.query.analyzer {
+			try!(serializer.serialize_struct_elt("analyzer", analyzer));
+		}
+
+		if let Some(boost) = self.query.boost {
+			try!(serializer.serialize_struct_elt("boost", boost));
+		}
+
+		Ok(None)
+	}
+}
+
+/// The way a `match` query matches analysed text.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MatchType {
+	/// Match any of the analysed terms.
+	Boolean,
+	/// Match the analysed terms as a phrase.
+	Phrase,
+	/// Match the analysed terms as a phrase, with a prefix match on the last term.
+	PhrasePrefix
+}
+
+impl Serialize for MatchType {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_str(match *self {
+			MatchType::Boolean => "boolean",
+			MatchType::Phrase => "phrase",
+			MatchType::PhrasePrefix => "phrase_prefix"
+		})
+	}
+}
+
+/// A full text query for documents matching the analysed text in any of a set of fields.
+///
+/// Fields can be boosted with the caret notation, like `"title^3"`.
+///
+/// # Examples
+///
+/// ```
+/// use elastic_types::query::{ MultiMatchQuery, MultiMatchType };
+///
+/// let query = MultiMatchQuery::new("quick brown fox", vec![ "title^2", "body" ])
+/// 	.match_type(MultiMatchType::MostFields);
+/// ```
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-multi-match-query.html)
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiMatchQuery {
+	query: String,
+	fields: Vec<String>,
+	match_type: Option<MultiMatchType>,
+	operator: Option<Operator>,
+	minimum_should_match: Option<String>,
+	tie_breaker: Option<f32>,
+	analyzer: Option<String>,
+	boost: Option<f32>
+}
+
+impl MultiMatchQuery {
+	/// Create a new `multi_match` query for some text in a set of fields.
+	pub fn new<I, F, T>(query: I, fields: F) -> MultiMatchQuery where
+	I: Into<String>,
+	F: IntoIterator<Item = T>,
+	T: Into<String> {
+		MultiMatchQuery {
+			query: query.into(),
+			fields: fields.into_iter().map(|f| f.into()).collect(),
+			match_type: None,
+			operator: None,
+			minimum_should_match: None,
+			tie_breaker: None,
+			analyzer: None,
+			boost: None
+		}
+	}
+
+	/// Set the way the fields are matched and scored.
+	pub fn match_type(mut self, match_type: MultiMatchType) -> MultiMatchQuery {
+		self.match_type = Some(match_type);
+		self
+	}
+
+	/// Set the operator used to combine the analysed terms.
+	pub fn operator(mut self, operator: Operator) -> MultiMatchQuery {
+		self.operator = Some(operator);
+		self
+	}
+
+	/// Set the number or percentage of terms that must match, like `"75%"`.
+	pub fn minimum_should_match<I: Into<String>>(mut self, minimum_should_match: I) -> MultiMatchQuery {
+		self.minimum_should_match = Some(minimum_should_match.into());
+		self
+	}
+
+	/// Set how much the scores of fields other than the best matching one contribute to the score.
+	pub fn tie_breaker(mut self, tie_breaker: f32) -> MultiMatchQuery {
+		self.tie_breaker = Some(tie_breaker);
+		self
+	}
+
+	/// Set the analyzer used to analyse the query text.
+	pub fn analyzer<I: Into<String>>(mut self, analyzer: I) -> MultiMatchQuery {
+		self.analyzer = Some(analyzer.into());
+		self
+	}
+
+	/// Set the boost for this query.
+	pub fn boost(mut self, boost: f32) -> MultiMatchQuery {
+		self.boost = Some(boost);
+		self
+	}
+}
+
+impl Serialize for MultiMatchQuery {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_struct("multi_match", MultiMatchQueryVisitor {
+			query: self
+		})
+	}
+}
+
+struct MultiMatchQueryVisitor<'a> {
+	query: &'a MultiMatchQuery
+}
+
+impl <'a> serde::ser::MapVisitor for MultiMatchQueryVisitor<'a> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> where
+	S: Serializer {
+		try!(serializer.serialize_struct_elt("query", &self.query.query));
+		try!(serializer.serialize_struct_elt("fields", &self.query.fields));
+
+		if let Some(match_type) = self.query.match_type {
+			try!(serializer.serialize_struct_elt("type", match_type));
+		}
+
+		if let Some(operator) = self.query.operator {
+			try!(serializer.serialize_struct_elt("operator", operator));
+		}
+
+		if let Some(ref minimum_should_match) = self.query.minimum_should_match {
+			try!(serializer.serialize_struct_elt("minimum_should_match", minimum_should_match));
+		}
+
+		if let Some(tie_breaker) = self.query.tie_breaker {
+			try!(serializer.serialize_struct_elt("tie_breaker", tie_breaker));
+		}
+
+		if let Some(ref analyzer) = self.query.analyzer {
+			try!(serializer.serialize_struct_elt("analyzer", analyzer));
+		}
+
+		if let Some(boost) = self.query.boost {
+			try!(serializer.serialize_struct_elt("boost", boost));
+		}
+
+		Ok(None)
+	}
+}
+
+/// The way a `multi_match` query matches and scores fields.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MultiMatchType {
+	/// Use the score of the best matching field.
+	BestFields,
+	/// Combine the scores of all matching fields.
+	MostFields,
+	/// Treat the fields as one big field.
+	CrossFields,
+	/// Match each field as a phrase and use the score of the best matching field.
+	Phrase,
+	/// Match each field as a phrase prefix and use the score of the best matching field.
+	PhrasePrefix
+}
+
+impl Serialize for MultiMatchType {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_str(match *self {
+			MultiMatchType::BestFields => "best_fields",
+			MultiMatchType::MostFields => "most_fields",
+			MultiMatchType::CrossFields => "cross_fields",
+			MultiMatchType::Phrase => "phrase",
+			MultiMatchType::PhrasePrefix => "phrase_prefix"
+		})
+	}
+}
+
+/// A query that uses the Lucene query syntax, like `"(content:this OR name:this) AND that"`.
+///
+/// # Examples
+///
+/// ```
+/// use elastic_types::query::QueryStringQuery;
+///
+/// let query = QueryStringQuery::new("this AND that OR thus").default_field("content");
+/// ```
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-query-string-query.html)
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryStringQuery {
+	query: String,
+	default_field: Option<String>,
+	fields: Option<Vec<String>>,
+	default_operator: Option<Operator>,
+	analyzer: Option<String>,
+	allow_leading_wildcard: Option<bool>,
+	lenient: Option<bool>,
+	boost: Option<f32>
+}
+
+impl QueryStringQuery {
+	/// Create a new `query_string` query.
+	pub fn new<I>(query: I) -> QueryStringQuery where
+	I: Into<String> {
+		QueryStringQuery {
+			query: query.into(),
+			default_field: None,
+			fields: None,
+			default_operator: None,
+			analyzer: None,
+			allow_leading_wildcard: None,
+			lenient: None,
+			boost: None
+		}
+	}
+
+	/// Set the field searched when no field is given in the query.
+	pub fn default_field<I: Into<String>>(mut self, default_field: I) -> QueryStringQuery {
+		self.default_field = Some(default_field.into());
+		self
+	}
+
+	/// Set the fields searched when no field is given in the query.
+	pub fn fields<F, T>(mut self, fields: F) -> QueryStringQuery where
+	F: IntoIterator<Item = T>,
+	T: Into<String> {
+		self.fields = Some(fields.into_iter().map(|f| f.into()).collect());
+		self
+	}
+
+	/// Set the operator used when no operator is given between terms.
+	pub fn default_operator(mut self, default_operator: Operator) -> QueryStringQuery {
+		self.default_operator = Some(default_operator);
+		self
+	}
+
+	/// Set the analyzer used to analyse the query text.
+	pub fn analyzer<I: Into<String>>(mut self, analyzer: I) -> QueryStringQuery {
+		self.analyzer = Some(analyzer.into());
+		self
+	}
+
+	/// Set whether `*` and `?` are allowed as the first character of a term.
+	pub fn allow_leading_wildcard(mut self, allow_leading_wildcard: bool) -> QueryStringQuery {
+		self.allow_leading_wildcard = Some(allow_leading_wildcard);
+		self
+	}
+
+	/// Set whether format based failures, like text for a numeric field, are ignored.
+	pub fn lenient(mut self, lenient: bool) -> QueryStringQuery {
+		self.lenient = Some(lenient);
+		self
+	}
+
+	/// Set the boost for this query.
+	pub fn boost(mut self, boost: f32) -> QueryStringQuery {
+		self.boost = Some(boost);
+		self
+	}
+}
+
+impl Serialize for QueryStringQuery {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_struct("query_string", QueryStringQueryVisitor {
+			query: self
+		})
+	}
+}
+
+struct QueryStringQueryVisitor<'a> {
+	query: &'a QueryStringQuery
+}
+
+impl <'a> serde::ser::MapVisitor for QueryStringQueryVisitor<'a> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> where
+	S: Serializer {
+		try!(serializer.serialize_struct_elt("query", &self.query.query));
+
+		if let Some(ref default_field) = self.query.default_field {
+			try!(serializer.serialize_struct_elt("default_field", default_field));
+		}
+
+		if let Some(ref fields) = self.query.fields {
+			try!(serializer.serialize_struct_elt("fields", fields));
+		}
+
+		if let Some(default_operator) = self.query.default_operator {
+			try!(serializer.serialize_struct_elt("default_operator", default_operator));
+		}
+
+		if let Some(ref analyzer) = self.query.analyzer {
+			try!(serializer.serialize_struct_elt("analyzer", analyzer));
+		}
+
+		if let Some(allow_leading_wildcard) = self.query.allow_leading_wildcard {
+			try!(serializer.serialize_struct_elt("allow_leading_wildcard", allow_leading_wildcard));
+		}
+
+		if let Some(lenient) = self.query.lenient {
+			try!(serializer.serialize_struct_elt("lenient", lenient));
+		}
+
+		if let Some(boost) = self.query.boost {
+			try!(serializer.serialize_struct_elt("boost", boost));
+		}
+
+		Ok(None)
+	}
+}
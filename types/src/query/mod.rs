@@ -0,0 +1,354 @@
+//! Elasticsearch Query DSL types.
+//!
+//! Queries are built up from structs that serialise to the json expected by the
+//! [Query DSL](https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl.html).
+//! Each query type can be converted into a `Query`, which is what compound queries and the `Search` body accept.
+//!
+//! # Examples
+//!
+//! Build a `bool` query and serialise it as the body of a search request:
+//!
+//! ```
+//! use elastic_types::query::*;
+//!
+//! let search = Search::new()
+//! 	.query(BoolQuery::new()
+//! 		.must(MatchQuery::new("title", "rust"))
+//! 		.filter(TermQuery::new("published", true))
+//! 		.must_not(RangeQuery::new("stars").lt(10))
+//! 	)
+//! 	.size(20);
+//!
+//! let body = search.to_string().unwrap();
+//! ```
+//!
+//! The resulting string can be passed as the body to any of the `search::post*` endpoints in `elastic_hyper`.
+//!
+//! Types that derive `ElasticType` also get a static `fields` method,
+//! which can be used to build queries that are checked against the type of each field.
+//! See `FieldPath` for details.
+//!
+//! Aggregations can also be added to the search body. See `Aggregation` for details.
+//!
+//! # Links
+//! - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl.html)
+
+mod term;
+mod full_text;
+mod compound;
+mod field;
+mod aggs;
+
+pub use self::term::*;
+pub use self::full_text::*;
+pub use self::compound::*;
+pub use self::field::*;
+pub use self::aggs::*;
+
+use serde;
+use serde::{ Serialize, Serializer };
+use serde_json;
+
+/// A query in the Elasticsearch Query DSL.
+///
+/// You don't normally need to build this enum directly.
+/// Instead, construct one of the specific query types (like `TermQuery`) and use `into()`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Query {
+	/// A `match_all` query.
+	MatchAll(MatchAllQuery),
+	/// A `bool` query.
+	Bool(BoolQuery),
+	/// A `term` query.
+	Term(TermQuery),
+	/// A `terms` query.
+	Terms(TermsQuery),
+	/// A `range` query.
+	Range(RangeQuery),
+	/// An `exists` query.
+	Exists(ExistsQuery),
+	/// A `prefix` query.
+	Prefix(PrefixQuery),
+	/// A `wildcard` query.
+	Wildcard(WildcardQuery),
+	/// A `match` query.
+	Match(MatchQuery),
+	/// A `multi_match` query.
+	MultiMatch(MultiMatchQuery),
+	/// A `query_string` query.
+	QueryString(QueryStringQuery),
+	/// A `nested` query.
+	Nested(NestedQuery),
+	/// A `has_child` query.
+	HasChild(HasChildQuery),
+	/// A `function_score` query.
+	FunctionScore(FunctionScoreQuery)
+}
+
+impl Query {
+	/// Get the name of the query in the Query DSL, like `"term"` or `"bool"`.
+	pub fn name(&self) -> &'static str {
+		match *self {
+			Query::MatchAll(_) => "match_all",
+			Query::Bool(_) => "bool",
+			Query::Term(_) => "term",
+			Query::Terms(_) => "terms",
+			Query::Range(_) => "range",
+			Query::Exists(_) => "exists",
+			Query::Prefix(_) => "prefix",
+			Query::Wildcard(_) => "wildcard",
+			Query::Match(_) => "match",
+			Query::MultiMatch(_) => "multi_match",
+			Query::QueryString(_) => "query_string",
+			Query::Nested(_) => "nested",
+			Query::HasChild(_) => "has_child",
+			Query::FunctionScore(_) => "function_score"
+		}
+	}
+}
+
+impl Serialize for Query {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_map(QueryVisitor {
+			query: self
+		})
+	}
+}
+
+struct QueryVisitor<'a> {
+	query: &'a Query
+}
+
+impl <'a> serde::ser::MapVisitor for QueryVisitor<'a> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> where
+	S: Serializer {
+		let name = self.query.name();
+
+		match *self.query {
+			Query::MatchAll(ref q) => try!(serializer.serialize_map_elt(name, q)),
+			Query::Bool(ref q) => try!(serializer.serialize_map_elt(name, q)),
+			Query::Term(ref q) => try!(serializer.serialize_map_elt(name, q)),
+			Query::Terms(ref q) => try!(serializer.serialize_map_elt(name, q)),
+			Query::Range(ref q) => try!(serializer.serialize_map_elt(name, q)),
+			Query::Exists(ref q) => try!(serializer.serialize_map_elt(name, q)),
+			Query::Prefix(ref q) => try!(serializer.serialize_map_elt(name, q)),
+			Query::Wildcard(ref q) => try!(serializer.serialize_map_elt(name, q)),
+			Query::Match(ref q) => try!(serializer.serialize_map_elt(name, q)),
+			Query::MultiMatch(ref q) => try!(serializer.serialize_map_elt(name, q)),
+			Query::QueryString(ref q) => try!(serializer.serialize_map_elt(name, q)),
+			Query::Nested(ref q) => try!(serializer.serialize_map_elt(name, q)),
+			Query::HasChild(ref q) => try!(serializer.serialize_map_elt(name, q)),
+			Query::FunctionScore(ref q) => try!(serializer.serialize_map_elt(name, q))
+		}
+
+		Ok(None)
+	}
+}
+
+macro_rules! impl_into_query {
+	($($q:ident => $variant:ident),*) => {
+		$(
+			impl From<$q> for Query {
+				fn from(query: $q) -> Query {
+					Query::$variant(query)
+				}
+			}
+		)*
+	}
+}
+
+impl_into_query!(
+	MatchAllQuery => MatchAll,
+	BoolQuery => Bool,
+	TermQuery => Term,
+	TermsQuery => Terms,
+	RangeQuery => Range,
+	ExistsQuery => Exists,
+	PrefixQuery => Prefix,
+	WildcardQuery => Wildcard,
+	MatchQuery => Match,
+	MultiMatchQuery => MultiMatch,
+	QueryStringQuery => QueryString,
+	NestedQuery => Nested,
+	HasChildQuery => HasChild,
+	FunctionScoreQuery => FunctionScore
+);
+
+/// A query that matches all documents.
+///
+/// Serialises as `{ "match_all": {} }`.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct MatchAllQuery {
+	boost: Option<f32>
+}
+
+impl MatchAllQuery {
+	/// Create a new `match_all` query.
+	pub fn new() -> MatchAllQuery {
+		MatchAllQuery::default()
+	}
+
+	/// Set the constant score given to all documents.
+	pub fn boost(mut self, boost: f32) -> MatchAllQuery {
+		self.boost = Some(boost);
+		self
+	}
+}
+
+impl Serialize for MatchAllQuery {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_struct("match_all", MatchAllQueryVisitor {
+			query: self
+		})
+	}
+}
+
+struct MatchAllQueryVisitor<'a> {
+	query: &'a MatchAllQuery
+}
+
+impl <'a> serde::ser::MapVisitor for MatchAllQueryVisitor<'a> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> where
+	S: Serializer {
+		if let Some(boost) = self.query.boost {
+			try!(serializer.serialize_struct_elt("boost", boost));
+		}
+
+		Ok(None)
+	}
+}
+
+/// The body of a search request.
+///
+/// Serialise the body with `to_string` and pass it to one of the `search::post*` endpoints.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Search {
+	query: Option<Query>,
+	from: Option<u64>,
+	size: Option<u64>,
+	min_score: Option<f32>,
+	aggs: Aggregations
+}
+
+impl Search {
+	/// Create a new, empty search body.
+	///
+	/// An empty search matches all documents.
+	pub fn new() -> Search {
+		Search::default()
+	}
+
+	/// Set the query to run.
+	pub fn query<Q: Into<Query>>(mut self, query: Q) -> Search {
+		self.query = Some(query.into());
+		self
+	}
+
+	/// Set the offset of the first hit to return.
+	pub fn from(mut self, from: u64) -> Search {
+		self.from = Some(from);
+		self
+	}
+
+	/// Set the maximum number of hits to return.
+	pub fn size(mut self, size: u64) -> Search {
+		self.size = Some(size);
+		self
+	}
+
+	/// Exclude hits with a score below the given value.
+	pub fn min_score(mut self, min_score: f32) -> Search {
+		self.min_score = Some(min_score);
+		self
+	}
+
+	/// Add an aggregation to calculate over the documents matching the query.
+	pub fn aggregation<I, A>(mut self, name: I, agg: A) -> Search where
+	I: Into<String>,
+	A: Into<Aggregation> {
+		self.aggs.insert(name.into(), agg.into());
+		self
+	}
+
+	/// Serialise the search body as json.
+	pub fn to_string(&self) -> Result<String, serde_json::Error> {
+		serde_json::to_string(self)
+	}
+}
+
+impl Serialize for Search {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_struct("search", SearchVisitor {
+			search: self
+		})
+	}
+}
+
+struct SearchVisitor<'a> {
+	search: &'a Search
+}
+
+impl <'a> serde::ser::MapVisitor for SearchVisitor<'a> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> where
+	S: Serializer {
+		if let Some(from) = self.search.from {
+			try!(serializer.serialize_struct_elt("from", from));
+		}
+
+		if let Some(size) = self.search.size {
+			try!(serializer.serialize_struct_elt("size", size));
+		}
+
+		if let Some(min_score) = self.search.min_score {
+			try!(serializer.serialize_struct_elt("min_score", min_score));
+		}
+
+		if let Some(ref query) = self.search.query {
+			try!(serializer.serialize_struct_elt("query", query));
+		}
+
+		if self.search.aggs.len() > 0 {
+			try!(serializer.serialize_struct_elt("aggs", &self.search.aggs));
+		}
+
+		Ok(None)
+	}
+}
+
+/// The boolean operator used to combine the terms in a full text query.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Operator {
+	/// All terms must match.
+	And,
+	/// Any term may match.
+	Or
+}
+
+impl Serialize for Operator {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_str(match *self {
+			Operator::And => "and",
+			Operator::Or => "or"
+		})
+	}
+}
+
+//Serialise a query body keyed by a field name, like `{ "my_field": { ... } }`
+struct FieldVisitor<'a, T: 'a> {
+	field: &'a str,
+	body: &'a T
+}
+
+impl <'a, T> serde::ser::MapVisitor for FieldVisitor<'a, T> where
+T: Serialize {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> where
+	S: Serializer {
+		try!(serializer.serialize_map_elt(self.field, self.body));
+
+		Ok(None)
+	}
+}
@@ -0,0 +1,440 @@
+use serde;
+use serde::{ Serialize, Serializer };
+use serde_json;
+use serde_json::Value;
+use super::FieldVisitor;
+
+/// A query for documents that contain the exact term in a field.
+///
+/// The term isn't analysed, so this query is best suited to `not_analyzed` fields.
+///
+/// # Examples
+///
+/// ```
+/// use elastic_types::query::TermQuery;
+///
+/// let query = TermQuery::new("user", "kimchy").boost(2.0);
+/// ```
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-term-query.html)
+#[derive(Debug, Clone, PartialEq)]
+pub struct TermQuery {
+	field: String,
+	value: Value,
+	boost: Option<f32>
+}
+
+impl TermQuery {
+	/// Create a new `term` query for a value in a field.
+	pub fn new<I, V>(field: I, value: V) -> TermQuery where
+	I: Into<String>,
+	V: Serialize {
+		TermQuery {
+			field: field.into(),
+			value: serde_json::to_value(&value),
+			boost: None
+		}
+	}
+
+	/// Set the boost for this query.
+	pub fn boost(mut self, boost: f32) -> TermQuery {
+		self.boost = Some(boost);
+		self
+	}
+}
+
+impl Serialize for TermQuery {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_map(FieldVisitor {
+			field: &self.field,
+			body: &ValueBody {
+				value: &self.value,
+				boost: self.boost
+			}
+		})
+	}
+}
+
+/// A query for documents that contain any of the exact terms in a field.
+///
+/// # Examples
+///
+/// ```
+/// use elastic_types::query::TermsQuery;
+///
+/// let query = TermsQuery::new("user", vec![ "kimchy", "elasticsearch" ]);
+/// ```
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-terms-query.html)
+#[derive(Debug, Clone, PartialEq)]
+pub struct TermsQuery {
+	field: String,
+	values: Vec<Value>,
+	boost: Option<f32>
+}
+
+impl TermsQuery {
+	/// Create a new `terms` query for a set of values in a field.
+	pub fn new<I, V, T>(field: I, values: V) -> TermsQuery where
+	I: Into<String>,
+	V: IntoIterator<Item = T>,
+	T: Serialize {
+		TermsQuery {
+			field: field.into(),
+			values: values.into_iter().map(|v| serde_json::to_value(&v)).collect(),
+			boost: None
+		}
+	}
+
+	/// Set the boost for this query.
+	pub fn boost(mut self, boost: f32) -> TermsQuery {
+		self.boost = Some(boost);
+		self
+	}
+}
+
+impl Serialize for TermsQuery {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_map(TermsQueryVisitor {
+			query: self
+		})
+	}
+}
+
+struct TermsQueryVisitor<'a> {
+	query: &'a TermsQuery
+}
+
+impl <'a> serde::ser::MapVisitor for TermsQueryVisitor<'a> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> where
+	S: Serializer {
+		try!(serializer.serialize_map_elt(&self.query.field, &self.query.values));
+
+		if let Some(boost) = self.query.boost {
+			try!(serializer.serialize_map_elt("boost", boost));
+		}
+
+		Ok(None)
+	}
+}
+
+/// A query for documents with a field value in a range.
+///
+/// Bounds can be numbers, dates, or strings.
+/// For date fields, the `format` can be used to tell Elasticsearch how to parse the bounds.
+///
+/// # Examples
+///
+/// ```
+/// use elastic_types::query::RangeQuery;
+///
+/// let query = RangeQuery::new("age").gte(10).lt(20);
+/// ```
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-range-query.html)
+#[derive(Debug, Clone, PartialEq)]
+pub struct RangeQuery {
+	field: String,
+	gt: Option<Value>,
+	gte: Option<Value>,
+	lt: Option<Value>,
+	lte: Option<Value>,
+	format: Option<String>,
+	time_zone: Option<String>,
+	boost: Option<f32>
+}
+
+impl RangeQuery {
+	/// Create a new, unbounded `range` query on a field.
+	pub fn new<I>(field: I) -> RangeQuery where
+	I: Into<String> {
+		RangeQuery {
+			field: field.into(),
+			gt: None,
+			gte: None,
+			lt: None,
+			lte: None,
+			format: None,
+			time_zone: None,
+			boost: None
+		}
+	}
+
+	/// Match values greater than the given value.
+	pub fn gt<V: Serialize>(mut self, value: V) -> RangeQuery {
+		self.gt = Some(serde_json::to_value(&value));
+		self
+	}
+
+	/// Match values greater than or equal to the given value.
+	pub fn gte<V: Serialize>(mut self, value: V) -> RangeQuery {
+		self.gte = Some(serde_json::to_value(&value));
+		self
+	}
+
+	/// Match values less than the given value.
+	pub fn lt<V: Serialize>(mut self, value: V) -> RangeQuery {
+		self.lt = Some(serde_json::to_value(&value));
+		self
+	}
+
+	/// Match values less than or equal to the given value.
+	pub fn lte<V: Serialize>(mut self, value: V) -> RangeQuery {
+		self.lte = Some(serde_json::to_value(&value));
+		self
+	}
+
+	/// Set the date format used to parse the bounds on a date field.
+	pub fn format<I: Into<String>>(mut self, format: I) -> RangeQuery {
+		self.format = Some(format.into());
+		self
+	}
+
+	/// Set the time zone used to convert date bounds to UTC, like `"+01:00"`.
+	pub fn time_zone<I: Into<String>>(mut self, time_zone: I) -> RangeQuery {
+		self.time_zone = Some(time_zone.into());
+		self
+	}
+
+	/// Set the boost for this query.
+	pub fn boost(mut self, boost: f32) -> RangeQuery {
+		self.boost = Some(boost);
+		self
+	}
+}
+
+impl Serialize for RangeQuery {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_map(FieldVisitor {
+			field: &self.field,
+			body: &RangeQueryBody {
+				query: self
+			}
+		})
+	}
+}
+
+struct RangeQueryBody<'a> {
+	query: &'a RangeQuery
+}
+
+impl <'a> Serialize for RangeQueryBody<'a> {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_struct("range", RangeQueryBodyVisitor {
+			query: self.query
+		})
+	}
+}
+
+struct RangeQueryBodyVisitor<'a> {
+	query: &'a RangeQuery
+}
+
+impl <'a> serde::ser::MapVisitor for RangeQueryBodyVisitor<'a> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> where
+	S: Serializer {
+		if let Some(ref gt) = self.query.gt {
+			try!(serializer.serialize_struct_elt("gt", gt));
+		}
+
+		if let Some(ref gte) = self.query.gte {
+			try!(serializer.serialize_struct_elt("gte", gte));
+		}
+
+		if let Some(ref lt) = self.query.lt {
+			try!(serializer.serialize_struct_elt("lt", lt));
+		}
+
+		if let Some(ref lte) = self.query.lte {
+			try!(serializer.serialize_struct_elt("lte", lte));
+		}
+
+		if let Some(ref format) = self.query.format {
+			try!(serializer.serialize_struct_elt("format", format));
+		}
+
+		if let Some(ref time_zone) = self.query.time_zone {
+			try!(serializer.serialize_struct_elt("time_zone", time_zone));
+		}
+
+		if let Some(boost) = self.query.boost {
+			try!(serializer.serialize_struct_elt("boost", boost));
+		}
+
+		Ok(None)
+	}
+}
+
+/// A query for documents that have a non-null value in a field.
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-exists-query.html)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExistsQuery {
+	field: String
+}
+
+impl ExistsQuery {
+	/// Create a new `exists` query on a field.
+	pub fn new<I>(field: I) -> ExistsQuery where
+	I: Into<String> {
+		ExistsQuery {
+			field: field.into()
+		}
+	}
+}
+
+impl Serialize for ExistsQuery {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_struct("exists", ExistsQueryVisitor {
+			query: self
+		})
+	}
+}
+
+struct ExistsQueryVisitor<'a> {
+	query: &'a ExistsQuery
+}
+
+impl <'a> serde::ser::MapVisitor for ExistsQueryVisitor<'a> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> where
+	S: Serializer {
+		try!(serializer.serialize_struct_elt("field", &self.query.field));
+
+		Ok(None)
+	}
+}
+
+/// A query for documents with a field that contains terms starting with a prefix.
+///
+/// The prefix isn't analysed.
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-prefix-query.html)
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrefixQuery {
+	field: String,
+	value: Value,
+	boost: Option<f32>
+}
+
+impl PrefixQuery {
+	/// Create a new `prefix` query for a prefix in a field.
+	pub fn new<I, V>(field: I, prefix: V) -> PrefixQuery where
+	I: Into<String>,
+	V: Into<String> {
+		PrefixQuery {
+			field: field.into(),
+			value: Value::String(prefix.into()),
+			boost: None
+		}
+	}
+
+	/// Set the boost for this query.
+	pub fn boost(mut self, boost: f32) -> PrefixQuery {
+		self.boost = Some(boost);
+		self
+	}
+}
+
+impl Serialize for PrefixQuery {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_map(FieldVisitor {
+			field: &self.field,
+			body: &ValueBody {
+				value: &self.value,
+				boost: self.boost
+			}
+		})
+	}
+}
+
+/// A query for documents with a field that contains terms matching a wildcard pattern.
+///
+/// Supported wildcards are `*`, which matches any character sequence, and `?`, which matches any single character.
+///
+/// # Links
+/// - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-wildcard-query.html)
+#[derive(Debug, Clone, PartialEq)]
+pub struct WildcardQuery {
+	field: String,
+	value: Value,
+	boost: Option<f32>
+}
+
+impl WildcardQuery {
+	/// Create a new `wildcard` query for a pattern in a field.
+	pub fn new<I, V>(field: I, pattern: V) -> WildcardQuery where
+	I: Into<String>,
+	V: Into<String> {
+		WildcardQuery {
+			field: field.into(),
+			value: Value::String(pattern.into()),
+			boost: None
+		}
+	}
+
+	/// Set the boost for this query.
+	pub fn boost(mut self, boost: f32) -> WildcardQuery {
+		self.boost = Some(boost);
+		self
+	}
+}
+
+impl Serialize for WildcardQuery {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_map(FieldVisitor {
+			field: &self.field,
+			body: &ValueBody {
+				value: &self.value,
+				boost: self.boost
+			}
+		})
+	}
+}
+
+//The body of a single value query, like `term`.
+//Uses the short form `"field": value` unless there's a boost.
+struct ValueBody<'a> {
+	value: &'a Value,
+	boost: Option<f32>
+}
+
+impl <'a> Serialize for ValueBody<'a> {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		match self.boost {
+			Some(_) => serializer.serialize_struct("value", ValueBodyVisitor {
+				body: self
+			}),
+			None => self.value.serialize(serializer)
+		}
+	}
+}
+
+struct ValueBodyVisitor<'a> {
+	body: &'a ValueBody<'a>
+}
+
+impl <'a> serde::ser::MapVisitor for ValueBodyVisitor<'a> {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> where
+	S: Serializer {
+		try!(serializer.serialize_struct_elt("value", self.body.value));
+
+		if let Some(boost) = self.body.boost {
+			try!(serializer.serialize_struct_elt("boost", boost));
+		}
+
+		Ok(None)
+	}
+}
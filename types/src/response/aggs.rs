@@ -0,0 +1,338 @@
+use std::collections::BTreeMap;
+use std::collections::btree_map::Iter;
+use serde;
+use serde::{ Deserialize, Deserializer };
+use serde_json;
+use serde_json::Value;
+use super::SearchHits;
+
+/// The results of the aggregations in a search, by name.
+///
+/// # Examples
+///
+/// Get the buckets of a `terms` aggregation with an `avg` sub-aggregation:
+///
+/// ```
+/// # extern crate serde_json;
+/// # extern crate elastic_types;
+/// # fn main() {
+/// use elastic_types::response::AggregationResults;
+///
+/// let aggs: AggregationResults = serde_json::from_str(r#"{
+/// 	"tags": {
+/// 		"buckets": [
+/// 			{ "key": "rust", "doc_count": 10, "avg_stars": { "value": 4.5 } }
+/// 		]
+/// 	}
+/// }"#).unwrap();
+///
+/// for bucket in aggs.get("tags").unwrap().buckets() {
+/// 	let avg = bucket.aggs.get("avg_stars").and_then(|agg| agg.value());
+///
+/// 	println!("{:?}: {} docs, {:?} stars", bucket.key, bucket.doc_count, avg);
+/// }
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct AggregationResults {
+	aggs: BTreeMap<String, AggregationResult>
+}
+
+impl AggregationResults {
+	/// Get the result of the aggregation with the given name.
+	pub fn get(&self, name: &str) -> Option<&AggregationResult> {
+		self.aggs.get(name)
+	}
+
+	/// Iterate through the aggregation results and their names.
+	pub fn iter(&self) -> Iter<String, AggregationResult> {
+		self.aggs.iter()
+	}
+
+	/// Get the number of aggregation results.
+	pub fn len(&self) -> usize {
+		self.aggs.len()
+	}
+
+	/// Whether or not there are any aggregation results.
+	pub fn is_empty(&self) -> bool {
+		self.aggs.is_empty()
+	}
+
+	fn from_map(map: BTreeMap<String, Value>) -> Result<AggregationResults, String> {
+		let mut aggs = BTreeMap::new();
+
+		for (name, value) in map {
+			aggs.insert(name, try!(AggregationResult::from_value(value)));
+		}
+
+		Ok(AggregationResults {
+			aggs: aggs
+		})
+	}
+}
+
+impl Deserialize for AggregationResults {
+	fn deserialize<D>(deserializer: &mut D) -> Result<AggregationResults, D::Error> where
+	D: Deserializer {
+		let map: BTreeMap<String, Value> = try!(Deserialize::deserialize(deserializer));
+
+		AggregationResults::from_map(map).map_err(|e| serde::de::Error::custom(e))
+	}
+}
+
+/// The result of a single aggregation.
+///
+/// Responses don't say which kind of aggregation produced a result,
+/// so the variant is picked based on the shape of the result.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AggregationResult {
+	/// The buckets of a multi-bucket aggregation, like `terms` or `date_histogram`.
+	Buckets(Vec<Bucket>),
+	/// The bucket of a single-bucket aggregation, like `filter` or `nested`.
+	Bucket(Bucket),
+	/// A single metric value, like `avg` or `cardinality`.
+	Value(MetricValue),
+	/// The result of a `stats` aggregation.
+	Stats(Stats),
+	/// The result of a `percentiles` aggregation, as pairs of percentile and value.
+	Percentiles(Vec<(f64, Option<f64>)>),
+	/// The hits of a `top_hits` aggregation.
+	///
+	/// Use `hits` to deserialise the hits.
+	TopHits(Value),
+	/// A result that isn't recognised.
+	Other(Value)
+}
+
+impl AggregationResult {
+	/// Get the buckets of a multi-bucket aggregation.
+	///
+	/// Other aggregations return no buckets.
+	pub fn buckets(&self) -> &[Bucket] {
+		match *self {
+			AggregationResult::Buckets(ref buckets) => buckets,
+			_ => &[]
+		}
+	}
+
+	/// Get the bucket of a single-bucket aggregation.
+	pub fn bucket(&self) -> Option<&Bucket> {
+		match *self {
+			AggregationResult::Bucket(ref bucket) => Some(bucket),
+			_ => None
+		}
+	}
+
+	/// Get the value of a single value metric aggregation.
+	pub fn value(&self) -> Option<f64> {
+		match *self {
+			AggregationResult::Value(ref value) => value.value,
+			_ => None
+		}
+	}
+
+	/// Get the stats of a `stats` aggregation.
+	pub fn stats(&self) -> Option<&Stats> {
+		match *self {
+			AggregationResult::Stats(ref stats) => Some(stats),
+			_ => None
+		}
+	}
+
+	/// Get the value of a percentile in a `percentiles` aggregation.
+	pub fn percentile(&self, percent: f64) -> Option<f64> {
+		match *self {
+			AggregationResult::Percentiles(ref percentiles) => percentiles
+				.iter()
+				.find(|&&(p, _)| p == percent)
+				.and_then(|&(_, value)| value),
+			_ => None
+		}
+	}
+
+	/// Get the hits of a `top_hits` aggregation, with their sources deserialised as `T`.
+	pub fn hits<T>(&self) -> Option<Result<SearchHits<T>, serde_json::Error>> where
+	T: Deserialize {
+		match *self {
+			AggregationResult::TopHits(ref hits) => Some(serde_json::value::from_value(hits.clone())),
+			_ => None
+		}
+	}
+
+	fn from_value(value: Value) -> Result<AggregationResult, String> {
+		let mut map = match value {
+			Value::Object(map) => map,
+			other => return Ok(AggregationResult::Other(other))
+		};
+
+		//Single-bucket aggregations have a `doc_count`, and their other keys are sub-aggregations that could have any name, like `buckets` or `value`.
+		//`significant_terms` also has a `doc_count`, but alongside an array of `buckets`, where a sub-aggregation would be an object.
+		let is_bucket = map.contains_key("doc_count") && match map.get("buckets") {
+			Some(&Value::Array(_)) => false,
+			_ => true
+		};
+
+		if is_bucket {
+			return Bucket::from_value(None, Value::Object(map)).map(AggregationResult::Bucket);
+		}
+
+		if let Some(buckets) = map.remove("buckets") {
+			return match buckets {
+				Value::Array(buckets) => {
+					let buckets: Result<Vec<Bucket>, String> = buckets
+						.into_iter()
+						.map(|bucket| Bucket::from_value(None, bucket))
+						.collect();
+
+					buckets.map(AggregationResult::Buckets)
+				},
+				//Keyed buckets, like `"keyed": true` on a `range` aggregation
+				Value::Object(buckets) => {
+					let buckets: Result<Vec<Bucket>, String> = buckets
+						.into_iter()
+						.map(|(key, bucket)| Bucket::from_value(Some(key), bucket))
+						.collect();
+
+					buckets.map(AggregationResult::Buckets)
+				},
+				_ => Err("expected `buckets` to be an array or object".to_owned())
+			};
+		}
+
+		if let Some(hits) = map.remove("hits") {
+			return Ok(AggregationResult::TopHits(hits));
+		}
+
+		if let Some(Value::Object(values)) = map.remove("values") {
+			let mut percentiles = Vec::new();
+
+			for (percent, value) in values {
+				let percent = try!(percent.parse::<f64>().map_err(|_| format!("'{}' is not a valid percentile", percent)));
+
+				percentiles.push((percent, value.as_f64()));
+			}
+
+			return Ok(AggregationResult::Percentiles(percentiles));
+		}
+
+		if map.contains_key("count") && map.contains_key("avg") {
+			return serde_json::value::from_value(Value::Object(map))
+				.map(AggregationResult::Stats)
+				.map_err(|e| format!("{}", e));
+		}
+
+		if map.contains_key("value") {
+			return serde_json::value::from_value(Value::Object(map))
+				.map(AggregationResult::Value)
+				.map_err(|e| format!("{}", e));
+		}
+
+		Ok(AggregationResult::Other(Value::Object(map)))
+	}
+}
+
+impl Deserialize for AggregationResult {
+	fn deserialize<D>(deserializer: &mut D) -> Result<AggregationResult, D::Error> where
+	D: Deserializer {
+		let value: Value = try!(Deserialize::deserialize(deserializer));
+
+		AggregationResult::from_value(value).map_err(|e| serde::de::Error::custom(e))
+	}
+}
+
+/// A bucket in the result of a bucket aggregation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bucket {
+	/// The key of the bucket.
+	///
+	/// This is the term for a `terms` aggregation, or the start of the interval for a histogram.
+	/// Single-bucket aggregations don't have a key.
+	pub key: Option<Value>,
+	/// The key of the bucket as a formatted string, if available.
+	pub key_as_string: Option<String>,
+	/// The number of documents in the bucket.
+	pub doc_count: u64,
+	/// The start of the range for a `range` aggregation bucket.
+	pub from: Option<f64>,
+	/// The end of the range for a `range` aggregation bucket.
+	pub to: Option<f64>,
+	/// The results of sub-aggregations for this bucket.
+	pub aggs: AggregationResults
+}
+
+//Keys in a bucket that aren't sub-aggregations
+const BUCKET_KEYS: &'static [&'static str] = &[
+	"key", "key_as_string", "doc_count", "from", "from_as_string", "to", "to_as_string", "doc_count_error_upper_bound", "sum_other_doc_count"
+];
+
+impl Bucket {
+	fn from_value(key: Option<String>, value: Value) -> Result<Bucket, String> {
+		let mut map = match value {
+			Value::Object(map) => map,
+			_ => return Err("expected a bucket to be an object".to_owned())
+		};
+
+		let doc_count = try!(map.get("doc_count").and_then(|c| c.as_u64()).ok_or("expected a bucket to have a `doc_count`".to_owned()));
+
+		let mut props = BTreeMap::new();
+
+		//Sub-aggregation results are always objects, so an object under one of the bucket keys is a sub-aggregation with the same name
+		for bucket_key in BUCKET_KEYS {
+			let is_agg = match map.get(*bucket_key) {
+				Some(&Value::Object(_)) => true,
+				_ => false
+			};
+
+			if !is_agg {
+				if let Some(value) = map.remove(*bucket_key) {
+					props.insert(*bucket_key, value);
+				}
+			}
+		}
+
+		let bucket_key = match props.remove("key") {
+			Some(key) => Some(key),
+			None => key.map(Value::String)
+		};
+
+		let key_as_string = props.get("key_as_string").and_then(|k| k.as_string()).map(|k| k.to_owned());
+		let from = props.get("from").and_then(|v| v.as_f64());
+		let to = props.get("to").and_then(|v| v.as_f64());
+
+		Ok(Bucket {
+			key: bucket_key,
+			key_as_string: key_as_string,
+			doc_count: doc_count,
+			from: from,
+			to: to,
+			aggs: try!(AggregationResults::from_map(map))
+		})
+	}
+}
+
+/// The result of a single value metric aggregation.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct MetricValue {
+	/// The value of the metric.
+	///
+	/// This is `None` if there were no values to calculate the metric from.
+	pub value: Option<f64>,
+	/// The value of the metric as a formatted string, if available.
+	pub value_as_string: Option<String>
+}
+
+/// The result of a `stats` aggregation.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Stats {
+	/// The number of values.
+	pub count: u64,
+	/// The lowest value.
+	pub min: Option<f64>,
+	/// The highest value.
+	pub max: Option<f64>,
+	/// The mean of the values.
+	pub avg: Option<f64>,
+	/// The sum of the values.
+	pub sum: Option<f64>
+}
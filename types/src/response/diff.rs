@@ -0,0 +1,404 @@
+use std::collections::BTreeMap;
+use serde::Serialize;
+use serde_json;
+use serde_json::Value;
+use ::mappers::TypeMapper;
+use ::object::ElasticUserTypeMapping;
+use super::{ TypeMapping, FieldMapping };
+
+//Parameters that can be changed on an existing field with the Put Mapping API
+const UPDATABLE_PARAMS: &'static [&'static str] = &[
+	"search_analyzer",
+	"search_quote_analyzer",
+	"ignore_above",
+	"ignore_malformed",
+	"include_in_all",
+	"dynamic"
+];
+
+/// The differences between the expected mapping for a type and the mapping in a live index.
+///
+/// Elasticsearch doesn't return parameters that are set to their defaults,
+/// so an expected parameter that's explicitly set to its default, like `"store": false`, matches a live field without it.
+/// A parameter that's only set on the live field is compared against its default in the same way.
+/// So if a field on a Rust type doesn't set an `analyzer` or `index`, then the live field mustn't set them either,
+/// unless it sets them to their defaults.
+/// Live parameters without a known default, like `null_value`, are accepted.
+///
+/// Each change is classified as either safe to apply with the [Put Mapping API](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-put-mapping.html),
+/// or requiring the index to be rebuilt.
+///
+/// # Examples
+///
+/// Check whether the mapping for a Rust type can be put on an existing index:
+///
+/// ```
+/// # extern crate serde_json;
+/// # extern crate elastic_types;
+/// # fn main() {
+/// use elastic_types::response::*;
+///
+/// let expected = TypeMapping::from_value(serde_json::from_str(r#"{
+/// 	"properties": {
+/// 		"title": { "type": "string", "analyzer": "english" },
+/// 		"views": { "type": "integer" }
+/// 	}
+/// }"#).unwrap()).unwrap();
+///
+/// let actual = TypeMapping::from_value(serde_json::from_str(r#"{
+/// 	"properties": {
+/// 		"title": { "type": "string", "analyzer": "standard" }
+/// 	}
+/// }"#).unwrap()).unwrap();
+///
+/// let diff = expected.diff(&actual);
+///
+/// assert!(diff.requires_reindex());
+/// assert_eq!(vec!["views"], diff.added().iter().map(|c| c.path.as_str()).collect::<Vec<_>>());
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct MappingDiff {
+	/// The changes between the expected and actual mappings, ordered by field path.
+	pub changes: Vec<MappingChange>
+}
+
+impl MappingDiff {
+	/// Compare an expected type mapping with an actual one.
+	pub fn new(expected: &TypeMapping, actual: &TypeMapping) -> MappingDiff {
+		let mut changes = Vec::new();
+		diff_properties("", &expected.properties, &actual.properties, &mut changes);
+
+		changes.sort_by(|a, b| a.path.cmp(&b.path));
+
+		MappingDiff {
+			changes: changes
+		}
+	}
+
+	/// Compare the mapping for a user-defined type with an actual one.
+	///
+	/// The expected mapping is built with `TypeMapper`.
+	pub fn for_type<M>(mapping: M, actual: &TypeMapping) -> Result<MappingDiff, String> where
+	M: ElasticUserTypeMapping {
+		let value = try!(TypeMapper::to_value(mapping).map_err(|e| e.to_string()));
+		let expected = try!(TypeMapping::from_value(value));
+
+		Ok(MappingDiff::new(&expected, actual))
+	}
+
+	/// Whether or not the mappings are the same.
+	pub fn is_empty(&self) -> bool {
+		self.changes.is_empty()
+	}
+
+	/// Whether or not all changes can be applied to the live index with the Put Mapping API.
+	pub fn is_compatible(&self) -> bool {
+		!self.requires_reindex()
+	}
+
+	/// Whether or not any changes require the index to be rebuilt.
+	pub fn requires_reindex(&self) -> bool {
+		self.changes.iter().any(|c| c.compatibility() == Compatibility::RequiresReindex)
+	}
+
+	/// Fields on the expected mapping that aren't on the live mapping.
+	pub fn added(&self) -> Vec<&MappingChange> {
+		self.changes.iter().filter(|c| c.kind == MappingChangeKind::Added).collect()
+	}
+
+	/// Fields on the live mapping that aren't on the expected mapping.
+	pub fn removed(&self) -> Vec<&MappingChange> {
+		self.changes.iter().filter(|c| c.kind == MappingChangeKind::Removed).collect()
+	}
+
+	/// Fields that are on both mappings, but are mapped differently.
+	pub fn conflicts(&self) -> Vec<&MappingChange> {
+		self.changes.iter().filter(|c| c.kind != MappingChangeKind::Added && c.kind != MappingChangeKind::Removed).collect()
+	}
+}
+
+impl TypeMapping {
+	/// Compare this expected type mapping with an actual one.
+	///
+	/// This is the same as `MappingDiff::new(self, actual)`.
+	pub fn diff(&self, actual: &TypeMapping) -> MappingDiff {
+		MappingDiff::new(self, actual)
+	}
+}
+
+/// Whether or not a change can be applied to an existing index.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Compatibility {
+	/// The change can be applied with the Put Mapping API.
+	Safe,
+	/// The change conflicts with the existing mapping, so documents need to be reindexed into a new index.
+	RequiresReindex
+}
+
+/// A difference in the mapping of a single field.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MappingChange {
+	/// The path to the field, like `user.name`.
+	pub path: String,
+	/// The kind of change.
+	pub kind: MappingChangeKind
+}
+
+impl MappingChange {
+	/// Whether or not the change can be applied to an existing index.
+	pub fn compatibility(&self) -> Compatibility {
+		match self.kind {
+			MappingChangeKind::Added | MappingChangeKind::Removed => Compatibility::Safe,
+			MappingChangeKind::ParamChanged { ref param, .. } if UPDATABLE_PARAMS.iter().any(|p| *p == param) => Compatibility::Safe,
+			_ => Compatibility::RequiresReindex
+		}
+	}
+}
+
+/// The kind of difference in the mapping of a field.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MappingChangeKind {
+	/// The field is on the expected mapping, but not the live one.
+	///
+	/// New fields can be added to an existing index.
+	Added,
+	/// The field is on the live mapping, but not the expected one.
+	///
+	/// Fields can't be removed from an index, but the existing mapping doesn't stop new documents from being indexed.
+	Removed,
+	/// The datatype of the field is different, like `string` and `long`.
+	TypeChanged {
+		/// The expected datatype.
+		expected: String,
+		/// The live datatype.
+		actual: String
+	},
+	/// The `analyzer` of the field is different.
+	AnalyzerChanged {
+		/// The expected analyzer, or `default` if the expected field doesn't set one.
+		expected: String,
+		/// The live analyzer, if set.
+		actual: Option<String>
+	},
+	/// The `format` of a `date` field is different.
+	FormatChanged {
+		/// The expected format.
+		expected: String,
+		/// The live format, if set.
+		actual: Option<String>
+	},
+	/// Some other parameter of the field is different, like `index` or `ignore_above`.
+	ParamChanged {
+		/// The name of the parameter.
+		param: String,
+		/// The expected value.
+		expected: Value,
+		/// The live value, if set.
+		actual: Option<Value>
+	}
+}
+
+fn diff_properties(prefix: &str, expected: &BTreeMap<String, FieldMapping>, actual: &BTreeMap<String, FieldMapping>, changes: &mut Vec<MappingChange>) {
+	for (name, expected_field) in expected {
+		let path = format!("{}{}", prefix, name);
+
+		match actual.get(name) {
+			Some(actual_field) => diff_field(&path, expected_field, actual_field, changes),
+			None => changes.push(MappingChange {
+				path: path,
+				kind: MappingChangeKind::Added
+			})
+		}
+	}
+
+	for name in actual.keys().filter(|name| !expected.contains_key(*name)) {
+		changes.push(MappingChange {
+			path: format!("{}{}", prefix, name),
+			kind: MappingChangeKind::Removed
+		});
+	}
+}
+
+fn diff_field(path: &str, expected: &FieldMapping, actual: &FieldMapping, changes: &mut Vec<MappingChange>) {
+	//If the type has changed, then nothing else is comparable
+	if expected.data_type() != actual.data_type() {
+		changes.push(MappingChange {
+			path: path.to_owned(),
+			kind: MappingChangeKind::TypeChanged {
+				expected: expected.data_type().to_owned(),
+				actual: actual.data_type().to_owned()
+			}
+		});
+
+		return;
+	}
+
+	let expected_params = params(expected);
+	let actual_params = params(actual);
+
+	for (param, expected_value) in expected_params.clone() {
+		let actual_value = actual_params.get(&param).cloned();
+
+		if actual_value.as_ref() == Some(&expected_value) {
+			continue;
+		}
+
+		if actual_value.is_none() && default_param(actual, &actual_params, &param).as_ref() == Some(&expected_value) {
+			continue;
+		}
+
+		changes.push(MappingChange {
+			path: path.to_owned(),
+			kind: param_change(&param, expected_value, actual_value)
+		});
+	}
+
+	//A parameter that's only set on the live field conflicts unless it's set to the default for the expected field
+	for (param, actual_value) in actual_params.iter().filter(|&(param, _)| !expected_params.contains_key(param)) {
+		let default = match param.as_str() {
+			"analyzer" => Some(Value::String("default".to_owned())),
+			_ => default_param(expected, &expected_params, param)
+		};
+
+		let expected_value = match default {
+			Some(ref default) if default == actual_value => continue,
+			Some(default) => default,
+			None => continue
+		};
+
+		changes.push(MappingChange {
+			path: path.to_owned(),
+			kind: param_change(param, expected_value, Some(actual_value.clone()))
+		});
+	}
+
+	let prefix = format!("{}.", path);
+
+	if let (Some(expected), Some(actual)) = (expected.properties(), actual.properties()) {
+		diff_properties(&prefix, expected, actual, changes);
+	}
+
+	if let (Some(expected), Some(actual)) = (expected.fields(), actual.fields()) {
+		diff_properties(&prefix, expected, actual, changes);
+	}
+}
+
+fn param_change(param: &str, expected: Value, actual: Option<Value>) -> MappingChangeKind {
+	match (param, expected) {
+		("analyzer", Value::String(expected)) => MappingChangeKind::AnalyzerChanged {
+			expected: expected,
+			actual: actual.and_then(|v| v.as_string().map(|s| s.to_owned()))
+		},
+		("format", Value::String(expected)) => MappingChangeKind::FormatChanged {
+			expected: expected,
+			actual: actual.and_then(|v| v.as_string().map(|s| s.to_owned()))
+		},
+		(_, expected) => MappingChangeKind::ParamChanged {
+			param: param.to_owned(),
+			expected: expected,
+			actual: actual
+		}
+	}
+}
+
+//Get the parameters of a field that aren't properties or multi-fields as a map
+fn params(field: &FieldMapping) -> BTreeMap<String, Value> {
+	match *field {
+		FieldMapping::String(ref f) => {
+			let mut p = f.params.clone();
+			insert(&mut p, "index", &f.index);
+			insert(&mut p, "analyzer", &f.analyzer);
+			insert(&mut p, "search_analyzer", &f.search_analyzer);
+			insert(&mut p, "store", &f.store);
+			insert(&mut p, "doc_values", &f.doc_values);
+			insert(&mut p, "null_value", &f.null_value);
+			p
+		},
+		FieldMapping::Number(ref f) => {
+			let mut p = f.params.clone();
+			insert(&mut p, "index", &f.index);
+			insert(&mut p, "coerce", &f.coerce);
+			insert(&mut p, "store", &f.store);
+			insert(&mut p, "doc_values", &f.doc_values);
+			insert(&mut p, "null_value", &f.null_value);
+			p
+		},
+		FieldMapping::Date(ref f) => {
+			let mut p = f.params.clone();
+			insert(&mut p, "format", &f.format);
+			insert(&mut p, "index", &f.index);
+			insert(&mut p, "store", &f.store);
+			insert(&mut p, "doc_values", &f.doc_values);
+			insert(&mut p, "null_value", &f.null_value);
+			p
+		},
+		FieldMapping::Boolean(ref f) => {
+			let mut p = f.params.clone();
+			insert(&mut p, "index", &f.index);
+			insert(&mut p, "store", &f.store);
+			insert(&mut p, "doc_values", &f.doc_values);
+			insert(&mut p, "null_value", &f.null_value);
+			p
+		},
+		FieldMapping::Object(ref f) | FieldMapping::Nested(ref f) => {
+			let mut p = f.params.clone();
+			insert(&mut p, "dynamic", &f.dynamic);
+			insert(&mut p, "enabled", &f.enabled);
+			insert(&mut p, "include_in_parent", &f.include_in_parent);
+			insert(&mut p, "include_in_root", &f.include_in_root);
+			p
+		},
+		FieldMapping::GeoPoint(ref f) => {
+			let mut p = f.params.clone();
+			insert(&mut p, "geohash", &f.geohash);
+			insert(&mut p, "lat_lon", &f.lat_lon);
+			p
+		},
+		FieldMapping::GeoShape(ref f) => {
+			let mut p = f.params.clone();
+			insert(&mut p, "tree", &f.tree);
+			insert(&mut p, "precision", &f.precision);
+			p
+		},
+		FieldMapping::Other(_, ref p) => p.clone()
+	}
+}
+
+//The value Elasticsearch uses for a parameter that isn't set on a field, if it's known
+fn default_param(field: &FieldMapping, params: &BTreeMap<String, Value>, param: &str) -> Option<Value> {
+	let data_type = field.data_type();
+
+	let is_number = match data_type {
+		"long" | "integer" | "short" | "byte" | "double" | "float" => true,
+		_ => false
+	};
+
+	let is_core = is_number || data_type == "string" || data_type == "date" || data_type == "boolean";
+
+	match param {
+		"index" if data_type == "string" => Some(Value::String("analyzed".to_owned())),
+		"index" if is_core => Some(Value::String("not_analyzed".to_owned())),
+		//Only strings that aren't analyzed have doc values by default
+		"doc_values" if data_type == "string" => Some(Value::Bool(params.get("index") == Some(&Value::String("not_analyzed".to_owned())))),
+		"doc_values" if is_core => Some(Value::Bool(true)),
+		"store" if is_core => Some(Value::Bool(false)),
+		"include_in_all" if is_core => Some(Value::Bool(true)),
+		"boost" if is_core => Some(Value::F64(1.0)),
+		"coerce" if is_number => Some(Value::Bool(true)),
+		"ignore_malformed" if is_number || data_type == "date" => Some(Value::Bool(false)),
+		"format" if data_type == "date" => Some(Value::String("strict_date_optional_time||epoch_millis".to_owned())),
+		"dynamic" | "enabled" if data_type == "object" || data_type == "nested" => Some(Value::Bool(true)),
+		"include_in_parent" | "include_in_root" if data_type == "nested" => Some(Value::Bool(false)),
+		"geohash" | "lat_lon" if data_type == "geo_point" => Some(Value::Bool(false)),
+		"tree" if data_type == "geo_shape" => Some(Value::String("geohash".to_owned())),
+		_ => None
+	}
+}
+
+fn insert<T>(params: &mut BTreeMap<String, Value>, key: &str, value: &Option<T>) where
+T: Serialize {
+	if let Some(ref value) = *value {
+		params.insert(key.to_owned(), serde_json::to_value(value));
+	}
+}
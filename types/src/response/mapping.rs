@@ -0,0 +1,585 @@
+use std::collections::BTreeMap;
+use serde;
+use serde::{ Deserialize, Deserializer };
+use serde_json::Value;
+use ::mapping::IndexAnalysis;
+use ::object::Dynamic;
+#[cfg(feature="date-ty")]
+use ::date::{ DynamicDateFormat, ParseError };
+
+/// The mappings returned by the [Get Mapping API](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-get-mapping.html), by index.
+///
+/// # Examples
+///
+/// Get the mapping for a field:
+///
+/// ```
+/// # extern crate serde_json;
+/// # extern crate elastic_types;
+/// # fn main() {
+/// use elastic_types::response::{ MappingDocument, FieldMapping };
+///
+/// let mapping: MappingDocument = serde_json::from_str(r#"{
+/// 	"myindex": {
+/// 		"mappings": {
+/// 			"mytype": {
+/// 				"properties": {
+/// 					"title": { "type": "string", "analyzer": "english" },
+/// 					"user": {
+/// 						"properties": {
+/// 							"id": { "type": "long" }
+/// 						}
+/// 					}
+/// 				}
+/// 			}
+/// 		}
+/// 	}
+/// }"#).unwrap();
+///
+/// let ty = mapping.get("myindex", "mytype").unwrap();
+///
+/// if let Some(&FieldMapping::String(ref title)) = ty.field("title") {
+/// 	assert_eq!(Some("english".to_owned()), title.analyzer);
+/// }
+///
+/// assert_eq!(Some("long"), ty.field("user.id").map(|f| f.data_type()));
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct MappingDocument {
+	/// The mappings for each index.
+	pub indices: BTreeMap<String, IndexMapping>
+}
+
+impl MappingDocument {
+	/// Get the mapping for a type in an index.
+	pub fn get(&self, index: &str, ty: &str) -> Option<&TypeMapping> {
+		self.indices.get(index).and_then(|index| index.mappings.get(ty))
+	}
+
+	/// Parse a mapping document from a `serde_json::Value`.
+	pub fn from_value(value: Value) -> Result<MappingDocument, String> {
+		let mut indices = BTreeMap::new();
+
+		for (name, index) in try!(into_object(value, "index")) {
+			indices.insert(name, try!(IndexMapping::from_value(index)));
+		}
+
+		Ok(MappingDocument {
+			indices: indices
+		})
+	}
+}
+
+impl Deserialize for MappingDocument {
+	fn deserialize<D>(deserializer: &mut D) -> Result<MappingDocument, D::Error> where
+	D: Deserializer {
+		let value: Value = try!(Deserialize::deserialize(deserializer));
+
+		MappingDocument::from_value(value).map_err(|e| serde::de::Error::custom(e))
+	}
+}
+
+/// The mappings for the types in an index.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct IndexMapping {
+	/// The mappings for each type, including `_default_` if it's set.
+	pub mappings: BTreeMap<String, TypeMapping>
+}
+
+impl IndexMapping {
+	fn from_value(value: Value) -> Result<IndexMapping, String> {
+		let mut index = try!(into_object(value, "index"));
+		let mut mappings = BTreeMap::new();
+
+		if let Some(types) = index.remove("mappings") {
+			for (name, ty) in try!(into_object(types, "mappings")) {
+				mappings.insert(name, try!(TypeMapping::from_value(ty)));
+			}
+		}
+
+		Ok(IndexMapping {
+			mappings: mappings
+		})
+	}
+}
+
+/// The mapping for a user-defined type.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TypeMapping {
+	/// The mappings for the fields on the type.
+	pub properties: BTreeMap<String, FieldMapping>,
+	/// Whether or not new fields are added to the mapping dynamically.
+	pub dynamic: Option<Dynamic>,
+	/// Any other properties on the type, like `_all` or `_source`.
+	pub params: BTreeMap<String, Value>
+}
+
+impl TypeMapping {
+	/// Get the mapping for a field by its path, like `user.id`.
+	///
+	/// The path can go through the properties of `object` and `nested` fields, and the `fields` of multi-fields.
+	pub fn field(&self, path: &str) -> Option<&FieldMapping> {
+		let mut parts = path.split('.');
+
+		let mut field = match parts.next().and_then(|name| self.properties.get(name)) {
+			Some(field) => field,
+			None => return None
+		};
+
+		for name in parts {
+			field = match field.child(name) {
+				Some(child) => child,
+				None => return None
+			};
+		}
+
+		Some(field)
+	}
+
+	/// Parse a type mapping from a `serde_json::Value`.
+	///
+	/// This can be used to read the mapping for a Rust type from `TypeMapper::to_value`, so it can be compared with a live mapping.
+	pub fn from_value(value: Value) -> Result<TypeMapping, String> {
+		let mut ty = try!(into_object(value, "type"));
+
+		Ok(TypeMapping {
+			properties: try!(take_properties(&mut ty, "properties")),
+			dynamic: try!(take_dynamic(&mut ty)),
+			params: ty
+		})
+	}
+}
+
+/// The mapping for a single field.
+///
+/// The variant is picked from the `type` of the field.
+/// Fields with `properties` but no `type` are mapped as an `Object`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldMapping {
+	/// A `string` field.
+	String(StringFieldMapping),
+	/// A numeric field, like `integer` or `double`.
+	Number(NumberFieldMapping),
+	/// A `date` field.
+	Date(DateFieldMapping),
+	/// A `boolean` field.
+	Boolean(BooleanFieldMapping),
+	/// An `object` field.
+	Object(ObjectFieldMapping),
+	/// A `nested` field.
+	Nested(ObjectFieldMapping),
+	/// A `geo_point` field.
+	GeoPoint(GeoPointFieldMapping),
+	/// A `geo_shape` field.
+	GeoShape(GeoShapeFieldMapping),
+	/// A field with a type that isn't recognised, along with its raw mapping.
+	Other(String, BTreeMap<String, Value>)
+}
+
+impl FieldMapping {
+	/// Get the Elasticsearch datatype of the field, like `string` or `nested`.
+	pub fn data_type(&self) -> &str {
+		match *self {
+			FieldMapping::String(_) => "string",
+			FieldMapping::Number(ref n) => n.kind.as_str(),
+			FieldMapping::Date(_) => "date",
+			FieldMapping::Boolean(_) => "boolean",
+			FieldMapping::Object(_) => "object",
+			FieldMapping::Nested(_) => "nested",
+			FieldMapping::GeoPoint(_) => "geo_point",
+			FieldMapping::GeoShape(_) => "geo_shape",
+			FieldMapping::Other(ref ty, _) => ty
+		}
+	}
+
+	/// Get the properties of an `object` or `nested` field.
+	///
+	/// Other fields have no properties.
+	pub fn properties(&self) -> Option<&BTreeMap<String, FieldMapping>> {
+		match *self {
+			FieldMapping::Object(ref o) | FieldMapping::Nested(ref o) => Some(&o.properties),
+			_ => None
+		}
+	}
+
+	/// Get the multi-fields of a field.
+	///
+	/// Fields that can't have multi-fields return none.
+	pub fn fields(&self) -> Option<&BTreeMap<String, FieldMapping>> {
+		match *self {
+			FieldMapping::String(ref s) => Some(&s.fields),
+			FieldMapping::Number(ref n) => Some(&n.fields),
+			FieldMapping::Date(ref d) => Some(&d.fields),
+			FieldMapping::Boolean(ref b) => Some(&b.fields),
+			_ => None
+		}
+	}
+
+	fn child(&self, name: &str) -> Option<&FieldMapping> {
+		self.properties()
+			.and_then(|p| p.get(name))
+			.or_else(|| self.fields().and_then(|f| f.get(name)))
+	}
+
+	/// Parse a field mapping from a `serde_json::Value`.
+	pub fn from_value(value: Value) -> Result<FieldMapping, String> {
+		let mut field = try!(into_object(value, "field"));
+
+		let ty = match field.remove("type") {
+			Some(Value::String(ty)) => ty,
+			Some(_) => return Err("expected the type of a field to be a string".to_owned()),
+			None if field.contains_key("properties") => "object".to_owned(),
+			None => return Err("expected a field to have a type or properties".to_owned())
+		};
+
+		let field = match ty.as_str() {
+			"string" => FieldMapping::String(try!(StringFieldMapping::from_map(field))),
+			"date" => FieldMapping::Date(try!(DateFieldMapping::from_map(field))),
+			"boolean" => FieldMapping::Boolean(try!(BooleanFieldMapping::from_map(field))),
+			"object" => FieldMapping::Object(try!(ObjectFieldMapping::from_map(field))),
+			"nested" => FieldMapping::Nested(try!(ObjectFieldMapping::from_map(field))),
+			"geo_point" => FieldMapping::GeoPoint(try!(GeoPointFieldMapping::from_map(field))),
+			"geo_shape" => FieldMapping::GeoShape(try!(GeoShapeFieldMapping::from_map(field))),
+			ty => match NumberKind::parse(ty) {
+				Some(kind) => FieldMapping::Number(try!(NumberFieldMapping::from_map(kind, field))),
+				None => FieldMapping::Other(ty.to_owned(), field)
+			}
+		};
+
+		Ok(field)
+	}
+}
+
+impl Deserialize for FieldMapping {
+	fn deserialize<D>(deserializer: &mut D) -> Result<FieldMapping, D::Error> where
+	D: Deserializer {
+		let value: Value = try!(Deserialize::deserialize(deserializer));
+
+		FieldMapping::from_value(value).map_err(|e| serde::de::Error::custom(e))
+	}
+}
+
+/// The mapping for a `string` field.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct StringFieldMapping {
+	/// How the field is indexed.
+	pub index: Option<IndexAnalysis>,
+	/// The analyzer used at index and search time.
+	pub analyzer: Option<String>,
+	/// The analyzer used at search time, if it's different to `analyzer`.
+	pub search_analyzer: Option<String>,
+	/// Whether or not the field value is stored separately from `_source`.
+	pub store: Option<bool>,
+	/// Whether or not the field is stored on disk in a column-stride fashion.
+	pub doc_values: Option<bool>,
+	/// The value used in place of an explicit `null`.
+	pub null_value: Option<String>,
+	/// The multi-fields of the field.
+	pub fields: BTreeMap<String, FieldMapping>,
+	/// Any other properties of the field.
+	pub params: BTreeMap<String, Value>
+}
+
+impl StringFieldMapping {
+	fn from_map(mut field: BTreeMap<String, Value>) -> Result<StringFieldMapping, String> {
+		Ok(StringFieldMapping {
+			index: try!(take_index(&mut field)),
+			analyzer: try!(take_string(&mut field, "analyzer")),
+			search_analyzer: try!(take_string(&mut field, "search_analyzer")),
+			store: try!(take_bool(&mut field, "store")),
+			doc_values: try!(take_bool(&mut field, "doc_values")),
+			null_value: try!(take_string(&mut field, "null_value")),
+			fields: try!(take_properties(&mut field, "fields")),
+			params: field
+		})
+	}
+}
+
+/// The kind of a numeric field.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NumberKind {
+	/// `long`
+	Long,
+	/// `integer`
+	Integer,
+	/// `short`
+	Short,
+	/// `byte`
+	Byte,
+	/// `double`
+	Double,
+	/// `float`
+	Float
+}
+
+impl NumberKind {
+	/// Get the Elasticsearch datatype for the kind of number.
+	pub fn as_str(&self) -> &'static str {
+		match *self {
+			NumberKind::Long => "long",
+			NumberKind::Integer => "integer",
+			NumberKind::Short => "short",
+			NumberKind::Byte => "byte",
+			NumberKind::Double => "double",
+			NumberKind::Float => "float"
+		}
+	}
+
+	fn parse(ty: &str) -> Option<NumberKind> {
+		match ty {
+			"long" => Some(NumberKind::Long),
+			"integer" => Some(NumberKind::Integer),
+			"short" => Some(NumberKind::Short),
+			"byte" => Some(NumberKind::Byte),
+			"double" => Some(NumberKind::Double),
+			"float" => Some(NumberKind::Float),
+			_ => None
+		}
+	}
+}
+
+/// The mapping for a numeric field.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumberFieldMapping {
+	/// The kind of number.
+	pub kind: NumberKind,
+	/// How the field is indexed.
+	pub index: Option<IndexAnalysis>,
+	/// Whether or not strings are converted to numbers.
+	pub coerce: Option<bool>,
+	/// Whether or not the field value is stored separately from `_source`.
+	pub store: Option<bool>,
+	/// Whether or not the field is stored on disk in a column-stride fashion.
+	pub doc_values: Option<bool>,
+	/// The value used in place of an explicit `null`.
+	pub null_value: Option<Value>,
+	/// The multi-fields of the field.
+	pub fields: BTreeMap<String, FieldMapping>,
+	/// Any other properties of the field.
+	pub params: BTreeMap<String, Value>
+}
+
+impl NumberFieldMapping {
+	fn from_map(kind: NumberKind, mut field: BTreeMap<String, Value>) -> Result<NumberFieldMapping, String> {
+		Ok(NumberFieldMapping {
+			kind: kind,
+			index: try!(take_index(&mut field)),
+			coerce: try!(take_bool(&mut field, "coerce")),
+			store: try!(take_bool(&mut field, "store")),
+			doc_values: try!(take_bool(&mut field, "doc_values")),
+			null_value: field.remove("null_value"),
+			fields: try!(take_properties(&mut field, "fields")),
+			params: field
+		})
+	}
+}
+
+/// The mapping for a `date` field.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct DateFieldMapping {
+	/// The date format(s) that can be parsed, separated by `||`.
+	pub format: Option<String>,
+	/// How the field is indexed.
+	pub index: Option<IndexAnalysis>,
+	/// Whether or not the field value is stored separately from `_source`.
+	pub store: Option<bool>,
+	/// Whether or not the field is stored on disk in a column-stride fashion.
+	pub doc_values: Option<bool>,
+	/// The value used in place of an explicit `null`.
+	pub null_value: Option<String>,
+	/// The multi-fields of the field.
+	pub fields: BTreeMap<String, FieldMapping>,
+	/// Any other properties of the field.
+	pub params: BTreeMap<String, Value>
+}
+
+impl DateFieldMapping {
+	/// Get a format that can parse and format dates for the field.
+	///
+	/// If the field has no `format`, the Elasticsearch default of `strict_date_optional_time||epoch_millis` is used.
+	#[cfg(feature="date-ty")]
+	pub fn date_format(&self) -> Result<DynamicDateFormat, ParseError> {
+		let format = self.format.as_ref().map(|f| f.as_str()).unwrap_or("strict_date_optional_time||epoch_millis");
+
+		DynamicDateFormat::new(format)
+	}
+
+	fn from_map(mut field: BTreeMap<String, Value>) -> Result<DateFieldMapping, String> {
+		Ok(DateFieldMapping {
+			format: try!(take_string(&mut field, "format")),
+			index: try!(take_index(&mut field)),
+			store: try!(take_bool(&mut field, "store")),
+			doc_values: try!(take_bool(&mut field, "doc_values")),
+			null_value: try!(take_string(&mut field, "null_value")),
+			fields: try!(take_properties(&mut field, "fields")),
+			params: field
+		})
+	}
+}
+
+/// The mapping for a `boolean` field.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct BooleanFieldMapping {
+	/// How the field is indexed.
+	pub index: Option<IndexAnalysis>,
+	/// Whether or not the field value is stored separately from `_source`.
+	pub store: Option<bool>,
+	/// Whether or not the field is stored on disk in a column-stride fashion.
+	pub doc_values: Option<bool>,
+	/// The value used in place of an explicit `null`.
+	pub null_value: Option<bool>,
+	/// The multi-fields of the field.
+	pub fields: BTreeMap<String, FieldMapping>,
+	/// Any other properties of the field.
+	pub params: BTreeMap<String, Value>
+}
+
+impl BooleanFieldMapping {
+	fn from_map(mut field: BTreeMap<String, Value>) -> Result<BooleanFieldMapping, String> {
+		Ok(BooleanFieldMapping {
+			index: try!(take_index(&mut field)),
+			store: try!(take_bool(&mut field, "store")),
+			doc_values: try!(take_bool(&mut field, "doc_values")),
+			null_value: try!(take_bool(&mut field, "null_value")),
+			fields: try!(take_properties(&mut field, "fields")),
+			params: field
+		})
+	}
+}
+
+/// The mapping for an `object` or `nested` field.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ObjectFieldMapping {
+	/// The mappings for the fields on the object.
+	pub properties: BTreeMap<String, FieldMapping>,
+	/// Whether or not new fields are added to the mapping dynamically.
+	pub dynamic: Option<Dynamic>,
+	/// Whether or not the object is parsed and indexed.
+	pub enabled: Option<bool>,
+	/// For `nested` fields, whether or not the fields are also added to the parent document.
+	pub include_in_parent: Option<bool>,
+	/// For `nested` fields, whether or not the fields are also added to the root document.
+	pub include_in_root: Option<bool>,
+	/// Any other properties of the field.
+	pub params: BTreeMap<String, Value>
+}
+
+impl ObjectFieldMapping {
+	fn from_map(mut field: BTreeMap<String, Value>) -> Result<ObjectFieldMapping, String> {
+		Ok(ObjectFieldMapping {
+			properties: try!(take_properties(&mut field, "properties")),
+			dynamic: try!(take_dynamic(&mut field)),
+			enabled: try!(take_bool(&mut field, "enabled")),
+			include_in_parent: try!(take_bool(&mut field, "include_in_parent")),
+			include_in_root: try!(take_bool(&mut field, "include_in_root")),
+			params: field
+		})
+	}
+}
+
+/// The mapping for a `geo_point` field.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GeoPointFieldMapping {
+	/// Whether or not the point is also indexed as a geohash.
+	pub geohash: Option<bool>,
+	/// Whether or not the point is also indexed as `.lat` and `.lon` fields.
+	pub lat_lon: Option<bool>,
+	/// Any other properties of the field.
+	pub params: BTreeMap<String, Value>
+}
+
+impl GeoPointFieldMapping {
+	fn from_map(mut field: BTreeMap<String, Value>) -> Result<GeoPointFieldMapping, String> {
+		Ok(GeoPointFieldMapping {
+			geohash: try!(take_bool(&mut field, "geohash")),
+			lat_lon: try!(take_bool(&mut field, "lat_lon")),
+			params: field
+		})
+	}
+}
+
+/// The mapping for a `geo_shape` field.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GeoShapeFieldMapping {
+	/// The prefix tree implementation, like `geohash` or `quadtree`.
+	pub tree: Option<String>,
+	/// The precision of the tree, like `50m`.
+	pub precision: Option<String>,
+	/// Any other properties of the field.
+	pub params: BTreeMap<String, Value>
+}
+
+impl GeoShapeFieldMapping {
+	fn from_map(mut field: BTreeMap<String, Value>) -> Result<GeoShapeFieldMapping, String> {
+		Ok(GeoShapeFieldMapping {
+			tree: try!(take_string(&mut field, "tree")),
+			precision: try!(take_string(&mut field, "precision")),
+			params: field
+		})
+	}
+}
+
+fn into_object(value: Value, kind: &str) -> Result<BTreeMap<String, Value>, String> {
+	match value {
+		Value::Object(map) => Ok(map),
+		_ => Err(format!("expected the {} mapping to be an object", kind))
+	}
+}
+
+fn take_properties(map: &mut BTreeMap<String, Value>, key: &str) -> Result<BTreeMap<String, FieldMapping>, String> {
+	let mut properties = BTreeMap::new();
+
+	if let Some(value) = map.remove(key) {
+		for (name, field) in try!(into_object(value, key)) {
+			let field = try!(FieldMapping::from_value(field).map_err(|e| format!("{} for '{}'", e, name)));
+
+			properties.insert(name, field);
+		}
+	}
+
+	Ok(properties)
+}
+
+fn take_string(map: &mut BTreeMap<String, Value>, key: &str) -> Result<Option<String>, String> {
+	match map.remove(key) {
+		Some(Value::String(s)) => Ok(Some(s)),
+		Some(Value::Null) | None => Ok(None),
+		//Numbers and bools are converted to strings, like a `null_value` of `0`
+		Some(Value::Array(_)) | Some(Value::Object(_)) => Err(format!("expected '{}' to be a string", key)),
+		Some(value) => Ok(Some(value.to_string()))
+	}
+}
+
+//Booleans in mappings may be returned as strings, like `"true"`
+fn take_bool(map: &mut BTreeMap<String, Value>, key: &str) -> Result<Option<bool>, String> {
+	match map.remove(key) {
+		Some(Value::Bool(b)) => Ok(Some(b)),
+		Some(Value::String(ref s)) if s == "true" => Ok(Some(true)),
+		Some(Value::String(ref s)) if s == "false" => Ok(Some(false)),
+		Some(Value::Null) | None => Ok(None),
+		Some(_) => Err(format!("expected '{}' to be a boolean", key))
+	}
+}
+
+fn take_index(map: &mut BTreeMap<String, Value>) -> Result<Option<IndexAnalysis>, String> {
+	match try!(take_string(map, "index")) {
+		Some(ref index) if index == "analyzed" => Ok(Some(IndexAnalysis::Analyzed)),
+		Some(ref index) if index == "not_analyzed" => Ok(Some(IndexAnalysis::NotAnalyzed)),
+		Some(ref index) if index == "no" => Ok(Some(IndexAnalysis::No)),
+		Some(index) => Err(format!("'{}' is not a valid value for 'index'", index)),
+		None => Ok(None)
+	}
+}
+
+fn take_dynamic(map: &mut BTreeMap<String, Value>) -> Result<Option<Dynamic>, String> {
+	match map.remove("dynamic") {
+		Some(Value::Bool(true)) => Ok(Some(Dynamic::True)),
+		Some(Value::Bool(false)) => Ok(Some(Dynamic::False)),
+		Some(Value::String(ref s)) if s == "true" => Ok(Some(Dynamic::True)),
+		Some(Value::String(ref s)) if s == "false" => Ok(Some(Dynamic::False)),
+		Some(Value::String(ref s)) if s == "strict" => Ok(Some(Dynamic::Strict)),
+		Some(Value::Null) | None => Ok(None),
+		Some(_) => Err("expected 'dynamic' to be `true`, `false` or `strict`".to_owned())
+	}
+}
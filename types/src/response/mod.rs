@@ -1,5 +1,13 @@
 //! Elasticsearch response types
 
+mod aggs;
+mod mapping;
+mod diff;
+
+pub use self::aggs::*;
+pub use self::mapping::*;
+pub use self::diff::*;
+
 use serde::Deserialize;
 
 /// A successful response from a Query DSL query.
@@ -13,8 +21,13 @@ T: Deserialize {
     /// Metadata on shard activity.
     #[serde(rename="_shards")]
     pub shards: Shards,
+    /// The id of the scroll context, if the search was scrolled.
+    #[serde(rename="_scroll_id")]
+    pub scroll_id: Option<String>,
     /// Document results.
-    pub hits: SearchHits<T>
+    pub hits: SearchHits<T>,
+    /// The results of aggregations, if any were requested.
+    pub aggregations: Option<AggregationResults>
 }
 
 /// Metadata on shard activity for a Query DSL query.
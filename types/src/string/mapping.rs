@@ -4,7 +4,7 @@ use std::collections::BTreeMap;
 use std::marker::PhantomData;
 use serde;
 use serde::{ Serializer, Serialize };
-use ::mapping::{ ElasticFieldMapping, ElasticTypeVisitor, IndexAnalysis };
+use ::mapping::{ ElasticFieldMapping, ElasticTypeVisitor, StaticIndexAnalysis, Analyzed };
 
 /// Elasticsearch datatype name.
 pub const STRING_DATATYPE: &'static str = "string";
@@ -107,6 +107,12 @@ pub const STRING_DATATYPE: &'static str = "string";
 /// ```
 pub trait ElasticStringMapping where
 Self: ElasticFieldMapping<()> + Sized + Serialize {
+	/// Should the field be searchable? Accepts `Analyzed` (default), `NotAnalyzed` and `NotIndexed`.
+	///
+	/// This sets the `index` parameter, and decides the queries available on a `FieldPath` to the field.
+	/// Exact value queries like `term` are only available for `NotAnalyzed` strings.
+	type Analysis: StaticIndexAnalysis = Analyzed;
+
 	/// Field-level index time boosting. Accepts a floating point number, defaults to `1.0`.
 	fn boost() -> Option<f32> {
 		None
@@ -127,11 +133,6 @@ Self: ElasticFieldMapping<()> + Sized + Serialize {
 		None
 	}
 
-	/// Should the field be searchable? Accepts `not_analyzed` (default) and `no`.
-	fn index() -> Option<IndexAnalysis> {
-		None
-	}
-
 	/// Whether the field value should be stored and retrievable separately from the `_source` field.
 	/// Accepts `true` or `false` (default).
 	fn store() -> Option<bool> {
@@ -249,7 +250,7 @@ T: ElasticStringMapping {
 			try!(serializer.serialize_struct_elt("include_in_all", include_in_all));
 		}
 
-		if let Some(index) = T::index() {
+		if let Some(index) = T::Analysis::index() {
 			try!(serializer.serialize_struct_elt("index", index));
 		}
 
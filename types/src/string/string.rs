@@ -2,10 +2,14 @@ use std::marker::PhantomData;
 use serde;
 use serde::{ Serialize, Deserialize, Serializer, Deserializer };
 use super::mapping::{ ElasticStringMapping, DefaultStringMapping };
-use ::mapping::{ ElasticFieldMapping, ElasticType };
+use ::mapping::{ ElasticFieldMapping, ElasticType, ElasticFieldAnalysis, Analyzed };
 
 impl ElasticType<DefaultStringMapping, ()> for String { }
 
+impl ElasticFieldAnalysis for String {
+	type Analysis = Analyzed;
+}
+
 /// An Elasticsearch `string` with a mapping.
 ///
 /// Where the mapping isn't custom, you can use the standard library `String` instead.
@@ -102,12 +106,25 @@ T: ElasticFieldMapping<()> + ElasticStringMapping {
 impl <T> ElasticType<T, ()> for ElasticString<T> where
 T: ElasticFieldMapping<()> + ElasticStringMapping { }
 
-impl From<String> for ElasticString<DefaultStringMapping> {
+impl <T> ElasticFieldAnalysis for ElasticString<T> where
+T: ElasticFieldMapping<()> + ElasticStringMapping {
+	type Analysis = T::Analysis;
+}
+
+impl <T> From<String> for ElasticString<T> where
+T: ElasticFieldMapping<()> + ElasticStringMapping {
 	fn from(string: String) -> Self {
 		ElasticString::new(string)
 	}
 }
 
+impl <'a, T> From<&'a str> for ElasticString<T> where
+T: ElasticFieldMapping<()> + ElasticStringMapping {
+	fn from(string: &'a str) -> Self {
+		ElasticString::new(string)
+	}
+}
+
 impl <T> AsRef<str> for ElasticString<T> where
 T: ElasticFieldMapping<()> + ElasticStringMapping {
 	fn as_ref(&self) -> &str {
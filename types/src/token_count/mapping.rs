@@ -0,0 +1,224 @@
+//! Mapping for the Elasticsearch `token_count` type.
+
+use std::marker::PhantomData;
+use serde;
+use serde::Serialize;
+use ::mapping::{ ElasticFieldMapping, ElasticTypeVisitor, IndexAnalysis };
+
+/// Elasticsearch datatype name.
+pub const TOKENCOUNT_DATATYPE: &'static str = "token_count";
+
+/// The base requirements for mapping a `token_count` type.
+///
+/// Custom mappings can be defined by implementing `ElasticTokenCountMapping`.
+/// Elasticsearch requires an `analyzer` for token counts, so custom mappings should provide one.
+///
+/// # Examples
+///
+/// Define a custom `ElasticTokenCountMapping`:
+///
+/// ## Derive Mapping
+///
+/// ```
+/// # #![feature(plugin, custom_derive, custom_attribute)]
+/// # #![plugin(json_str, elastic_types_macros)]
+/// # #[macro_use]
+/// # extern crate elastic_types;
+/// # extern crate serde;
+/// use elastic_types::mapping::prelude::*;
+/// use elastic_types::token_count::prelude::*;
+///
+/// #[derive(Debug, Clone, Default, ElasticTokenCountMapping)]
+/// pub struct MyTokenCountMapping;
+/// impl ElasticTokenCountMapping for MyTokenCountMapping {
+/// 	//Overload the mapping functions here
+/// 	fn analyzer() -> Option<&'static str> {
+///			Some("whitespace")
+///		}
+/// }
+/// # fn main() {}
+/// ```
+///
+/// This will produce the following mapping:
+///
+/// ```
+/// # #![feature(plugin, custom_derive, custom_attribute)]
+/// # #![plugin(json_str, elastic_types_macros)]
+/// # #[macro_use]
+/// # extern crate elastic_types;
+/// # extern crate serde;
+/// # extern crate serde_json;
+/// # use elastic_types::mapping::prelude::*;
+/// # use elastic_types::token_count::prelude::*;
+/// # #[derive(Debug, Clone, Default, ElasticTokenCountMapping)]
+/// # pub struct MyTokenCountMapping;
+/// # impl ElasticTokenCountMapping for MyTokenCountMapping {
+/// # 	//Overload the mapping functions here
+/// # 	fn analyzer() -> Option<&'static str> {
+///	# 		Some("whitespace")
+///	# 	}
+/// # }
+/// # fn main() {
+/// # let mapping = serde_json::to_string(&MyTokenCountMapping).unwrap();
+/// # let json = json_str!(
+/// {
+///     "type": "token_count",
+/// 	"analyzer": "whitespace"
+/// }
+/// # );
+/// # assert_eq!(json, mapping);
+/// # }
+/// ```
+///
+/// ## Manually
+///
+/// ```
+/// # extern crate serde;
+/// # extern crate elastic_types;
+/// # fn main() {
+/// use elastic_types::mapping::prelude::*;
+/// use elastic_types::token_count::prelude::*;
+///
+/// #[derive(Debug, Clone, Default)]
+/// pub struct MyTokenCountMapping;
+/// impl ElasticTokenCountMapping for MyTokenCountMapping {
+/// 	//Overload the mapping functions here
+/// 	fn analyzer() -> Option<&'static str> {
+///			Some("whitespace")
+///		}
+/// }
+///
+/// //We also need to implement the base `ElasticFieldMapping` and `serde::Serialize` for our custom mapping type
+/// impl ElasticFieldMapping<()> for MyTokenCountMapping {
+/// 	type Visitor = ElasticTokenCountMappingVisitor<MyTokenCountMapping>;
+///
+/// 	fn data_type() -> &'static str {
+/// 		TOKENCOUNT_DATATYPE
+/// 	}
+/// }
+///
+/// impl serde::Serialize for MyTokenCountMapping {
+/// 	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+/// 	where S: serde::Serializer {
+/// 		serializer.serialize_struct("mapping", Self::get_visitor())
+/// 	}
+/// }
+/// # }
+/// ```
+pub trait ElasticTokenCountMapping where
+Self: ElasticFieldMapping<()> + Sized + Serialize {
+	/// The analyzer which should be used to analyze the string value.
+	/// Required. For best performance, use an analyzer without token filters.
+	fn analyzer() -> Option<&'static str> {
+		None
+	}
+
+	/// Field-level index time boosting. Accepts a floating point number, defaults to `1.0`.
+	fn boost() -> Option<f32> {
+		None
+	}
+
+	/// Should the field be stored on disk in a column-stride fashion,
+	/// so that it can later be used for sorting, aggregations, or scripting?
+	/// Accepts `true` (default) or `false`.
+	fn doc_values() -> Option<bool> {
+		None
+	}
+
+	/// Whether or not the field value should be included in the `_all` field?
+	/// Accepts `true` or `false`.
+	/// Defaults to `false`, because the analyzed value is a number.
+	fn include_in_all() -> Option<bool> {
+		None
+	}
+
+	/// Should the field be searchable? Accepts `not_analyzed` (default) and `no`.
+	fn index() -> Option<IndexAnalysis> {
+		None
+	}
+
+	/// Accepts a numeric value of the same type as the field which is substituted for any explicit null values.
+	/// Defaults to `null`, which means the field is treated as missing.
+	fn null_value() -> Option<u32> {
+		None
+	}
+
+	/// Controls the number of extra terms that are indexed to make range queries faster.
+	/// Defaults to `32`.
+	fn precision_step() -> Option<u32> {
+		None
+	}
+
+	/// Whether the field value should be stored and retrievable separately from the `_source` field.
+	/// Accepts `true` or `false` (default).
+	fn store() -> Option<bool> {
+		None
+	}
+}
+
+/// Default mapping for `token_count`, using the `standard` analyzer.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DefaultTokenCountMapping;
+impl ElasticTokenCountMapping for DefaultTokenCountMapping {
+	fn analyzer() -> Option<&'static str> {
+		Some("standard")
+	}
+}
+
+impl_token_count_mapping!(DefaultTokenCountMapping);
+
+/// Base visitor for serialising token count mappings.
+#[derive(Debug, PartialEq, Default)]
+pub struct ElasticTokenCountMappingVisitor<T> where T: ElasticTokenCountMapping {
+	phantom: PhantomData<T>
+}
+
+impl <T> ElasticTypeVisitor for ElasticTokenCountMappingVisitor<T> where
+T: ElasticTokenCountMapping {
+	fn new() -> Self {
+		ElasticTokenCountMappingVisitor {
+			phantom: PhantomData
+		}
+	}
+}
+impl <T> serde::ser::MapVisitor for ElasticTokenCountMappingVisitor<T> where
+T: ElasticTokenCountMapping {
+	fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error>
+	where S: serde::Serializer {
+		try!(serializer.serialize_struct_elt("type", T::data_type()));
+
+		if let Some(analyzer) = T::analyzer() {
+			try!(serializer.serialize_struct_elt("analyzer", analyzer));
+		}
+
+		if let Some(boost) = T::boost() {
+			try!(serializer.serialize_struct_elt("boost", boost));
+		}
+
+		if let Some(doc_values) = T::doc_values() {
+			try!(serializer.serialize_struct_elt("doc_values", doc_values));
+		}
+
+		if let Some(include_in_all) = T::include_in_all() {
+			try!(serializer.serialize_struct_elt("include_in_all", include_in_all));
+		}
+
+		if let Some(index) = T::index() {
+			try!(serializer.serialize_struct_elt("index", index));
+		}
+
+		if let Some(precision_step) = T::precision_step() {
+			try!(serializer.serialize_struct_elt("precision_step", precision_step));
+		}
+
+		if let Some(store) = T::store() {
+			try!(serializer.serialize_struct_elt("store", store));
+		}
+
+		if let Some(null_value) = T::null_value() {
+			try!(serializer.serialize_struct_elt("null_value", null_value));
+		}
+
+		Ok(None)
+	}
+}
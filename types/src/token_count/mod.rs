@@ -0,0 +1,55 @@
+//! Implementation of the Elasticsearch `token_count` type.
+//!
+//! A `token_count` field accepts a string value, analyzes it and indexes the number of tokens in the string.
+//! The `_source` keeps the original string, so that's what gets serialised and deserialised.
+//! Token counts are commonly used as a multi-field of a `string`,
+//! but can also be mapped as a field of their own.
+//!
+//! # Examples
+//!
+//! For defining your own token count mapping, see [mapping details](mapping/trait.ElasticTokenCountMapping.html#derive-mapping).
+//!
+//! Map with a default `token_count`:
+//!
+//! ```
+//! # use elastic_types::token_count::prelude::*;
+//! # use elastic_types::token_count::mapping::DefaultTokenCountMapping;
+//! struct MyType {
+//! 	pub field: ElasticTokenCount<DefaultTokenCountMapping>
+//! }
+//! ```
+//!
+//! Map with a custom `token_count`:
+//!
+//! ```
+//! # #![feature(plugin, custom_derive)]
+//! # #![plugin(json_str, elastic_types_macros)]
+//! # extern crate serde;
+//! # extern crate elastic_types;
+//! # fn main() {
+//! # use elastic_types::mapping::prelude::*;
+//! # use elastic_types::token_count::prelude::*;
+//! # #[derive(Debug, Clone, Default, ElasticTokenCountMapping)]
+//! # pub struct MyTokenCountMapping;
+//! # impl ElasticTokenCountMapping for MyTokenCountMapping { }
+//! struct MyType {
+//! 	pub field: ElasticTokenCount<MyTokenCountMapping>
+//! }
+//! # }
+//! ```
+//!
+//! # Links
+//! - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/token-count.html)
+
+mod token_count;
+
+pub mod mapping;
+pub use self::token_count::*;
+
+pub mod prelude {
+	//! Includes non-mapping types for the `token_count` type.
+	//!
+	//! This is a convenience module to make it easy to build mappings for multiple types without too many `use` statements.
+
+	pub use super::token_count::*;
+}
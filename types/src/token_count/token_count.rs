@@ -0,0 +1,164 @@
+use std::marker::PhantomData;
+use serde;
+use serde::{ Serialize, Deserialize, Serializer, Deserializer };
+use super::mapping::{ ElasticTokenCountMapping, DefaultTokenCountMapping };
+use ::mapping::{ ElasticFieldMapping, ElasticType };
+
+/// An Elasticsearch `token_count` with a mapping.
+///
+/// The value is the string whose tokens are counted by Elasticsearch when it's indexed.
+///
+/// # Examples
+///
+/// Defining a token count with a mapping:
+///
+/// ```
+/// use elastic_types::token_count::mapping::DefaultTokenCountMapping;
+/// use elastic_types::token_count::ElasticTokenCount;
+///
+/// let count = ElasticTokenCount::<DefaultTokenCountMapping>::new("John Smith");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ElasticTokenCount<T> where
+T: ElasticFieldMapping<()> + ElasticTokenCountMapping {
+	value: String,
+	phantom: PhantomData<T>
+}
+impl <T> ElasticTokenCount<T> where
+T: ElasticFieldMapping<()> + ElasticTokenCountMapping {
+	/// Creates a new `ElasticTokenCount` with the given mapping.
+	///
+	/// # Examples
+	///
+	/// Create a new `ElasticTokenCount` from a `String`:
+	///
+	/// ```
+	/// use elastic_types::token_count::mapping::DefaultTokenCountMapping;
+	/// use elastic_types::token_count::ElasticTokenCount;
+	///
+	/// let count = ElasticTokenCount::<DefaultTokenCountMapping>::new(String::from("Rachel Alice Williams"));
+	/// ```
+	pub fn new<I>(string: I) -> ElasticTokenCount<T> where I: Into<String> {
+		ElasticTokenCount {
+			value: string.into(),
+			phantom: PhantomData
+		}
+	}
+
+	/// Get the string whose tokens are counted.
+	pub fn get(&self) -> &str {
+		&self.value
+	}
+
+	/// Set the string whose tokens are counted.
+	pub fn set<I>(&mut self, string: I) where I: Into<String> {
+		self.value = string.into()
+	}
+
+	/// Change the mapping of this token count.
+	pub fn into<TInto>(self) -> ElasticTokenCount<TInto> where
+	TInto: ElasticFieldMapping<()> + ElasticTokenCountMapping {
+		ElasticTokenCount::<TInto>::new(self.value)
+	}
+}
+
+impl <T> ElasticType<T, ()> for ElasticTokenCount<T> where
+T: ElasticFieldMapping<()> + ElasticTokenCountMapping { }
+
+impl From<String> for ElasticTokenCount<DefaultTokenCountMapping> {
+	fn from(string: String) -> Self {
+		ElasticTokenCount::new(string)
+	}
+}
+
+impl <T> AsRef<str> for ElasticTokenCount<T> where
+T: ElasticFieldMapping<()> + ElasticTokenCountMapping {
+	fn as_ref(&self) -> &str {
+		&self.value
+	}
+}
+
+impl <T> Into<String> for ElasticTokenCount<T> where
+T: ElasticFieldMapping<()> + ElasticTokenCountMapping {
+	fn into(self) -> String {
+		self.value
+	}
+}
+
+impl<'a, T> PartialEq<String> for ElasticTokenCount<T> where
+T: ElasticFieldMapping<()> + ElasticTokenCountMapping {
+	fn eq(&self, other: &String) -> bool {
+		PartialEq::eq(&self.value, other)
+	}
+
+	fn ne(&self, other: &String) -> bool {
+		PartialEq::ne(&self.value, other)
+	}
+}
+
+impl<'a, T> PartialEq<ElasticTokenCount<T>> for String where
+T: ElasticFieldMapping<()> + ElasticTokenCountMapping {
+	fn eq(&self, other: &ElasticTokenCount<T>) -> bool {
+		PartialEq::eq(self, &other.value)
+	}
+
+	fn ne(&self, other: &ElasticTokenCount<T>) -> bool {
+		PartialEq::ne(self, &other.value)
+	}
+}
+
+impl<'a, T> PartialEq<&'a str> for ElasticTokenCount<T> where
+T: ElasticFieldMapping<()> + ElasticTokenCountMapping {
+	fn eq(&self, other: & &'a str) -> bool {
+		PartialEq::eq(&self.value[..], *other)
+	}
+
+	fn ne(&self, other: & &'a str) -> bool {
+		PartialEq::ne(&self.value[..], *other)
+	}
+}
+
+impl<'a, T> PartialEq<ElasticTokenCount<T>> for &'a str where
+T: ElasticFieldMapping<()> + ElasticTokenCountMapping {
+	fn eq(&self, other: &ElasticTokenCount<T>) -> bool {
+		PartialEq::eq(*self, &other.value[..])
+	}
+
+	fn ne(&self, other: &ElasticTokenCount<T>) -> bool {
+		PartialEq::ne(*self, &other.value[..])
+	}
+}
+
+//Serialize elastic token count
+impl <T> Serialize for ElasticTokenCount<T> where
+T: ElasticFieldMapping<()> + ElasticTokenCountMapping {
+	fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where
+	S: Serializer {
+		serializer.serialize_str(&self.value)
+	}
+}
+
+//Deserialize elastic token count
+impl <T> Deserialize for ElasticTokenCount<T> where
+T: ElasticFieldMapping<()> + ElasticTokenCountMapping {
+	fn deserialize<D>(deserializer: &mut D) -> Result<ElasticTokenCount<T>, D::Error> where
+	D: Deserializer {
+		#[derive(Default)]
+		struct ElasticTokenCountVisitor<T> where
+		T: ElasticFieldMapping<()> + ElasticTokenCountMapping {
+			phantom: PhantomData<T>
+		}
+
+		impl <T> serde::de::Visitor for ElasticTokenCountVisitor<T> where
+		T: ElasticFieldMapping<()> + ElasticTokenCountMapping {
+			type Value = ElasticTokenCount<T>;
+
+			fn visit_str<E>(&mut self, v: &str) -> Result<ElasticTokenCount<T>, E> where
+			E: serde::de::Error {
+				Ok(ElasticTokenCount::<T>::new(v))
+			}
+		}
+
+		deserializer.deserialize(ElasticTokenCountVisitor::<T>::default())
+	}
+}
@@ -0,0 +1,36 @@
+#![feature(custom_derive, custom_attribute, plugin)]
+#![plugin(serde_macros)]
+#![plugin(json_str)]
+
+extern crate serde;
+extern crate serde_json;
+extern crate elastic_types;
+
+use elastic_types::mapping::prelude::*;
+use ::binary_fixtures::*;
+
+#[test]
+fn serialise_mapping_default() {
+	let mapping = DefaultBinaryMapping::default();
+	let ser = serde_json::to_string(&mapping).unwrap();
+
+	let expected = json_str!({
+		"type": "binary"
+	});
+
+	assert_eq!(expected, ser);
+}
+
+#[test]
+fn serialise_mapping_custom() {
+	let mapping = MyBinaryMapping;
+	let ser = serde_json::to_string(&mapping).unwrap();
+
+	let expected = json_str!({
+		"type": "binary",
+		"doc_values": true,
+		"store": true
+	});
+
+	assert_eq!(expected, ser);
+}
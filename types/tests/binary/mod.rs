@@ -0,0 +1,35 @@
+#![feature(custom_derive, custom_attribute, plugin)]
+#![plugin(serde_macros)]
+#![plugin(json_str)]
+
+pub mod mapping;
+
+extern crate serde;
+extern crate serde_json;
+extern crate elastic_types;
+
+use elastic_types::binary::mapping::*;
+use elastic_types::binary::prelude::*;
+
+#[test]
+fn serialise_elastic_binary() {
+	let binary: ElasticBinary<DefaultBinaryMapping> = ElasticBinary::new(b"hello world".to_vec());
+
+	let ser = serde_json::to_string(&binary).unwrap();
+
+	assert_eq!(r#""aGVsbG8gd29ybGQ=""#, ser);
+}
+
+#[test]
+fn deserialise_elastic_binary() {
+	let binary: ElasticBinary<DefaultBinaryMapping> = serde_json::from_str(r#""aGVsbG8gd29ybGQ=""#).unwrap();
+
+	assert_eq!(b"hello world".to_vec(), binary);
+}
+
+#[test]
+fn deserialise_elastic_binary_invalid() {
+	let binary: Result<ElasticBinary<DefaultBinaryMapping>, _> = serde_json::from_str(r#""not base64!""#);
+
+	assert!(binary.is_err());
+}
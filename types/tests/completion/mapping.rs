@@ -0,0 +1,52 @@
+#![feature(custom_derive, custom_attribute, plugin)]
+#![plugin(serde_macros)]
+#![plugin(json_str)]
+
+extern crate serde;
+extern crate serde_json;
+extern crate elastic_types;
+
+use elastic_types::mapping::prelude::*;
+use ::completion_fixtures::*;
+
+#[test]
+fn serialise_mapping_default() {
+	let mapping = DefaultCompletionMapping::default();
+	let ser = serde_json::to_string(&mapping).unwrap();
+
+	let expected = json_str!({
+		"type": "completion"
+	});
+
+	assert_eq!(expected, ser);
+}
+
+#[test]
+fn serialise_mapping_custom() {
+	let mapping = MyCompletionMapping;
+	let ser = serde_json::to_string(&mapping).unwrap();
+
+	let expected = json_str!({
+		"type": "completion",
+		"analyzer": "my_analyzer",
+		"search_analyzer": "my_search_analyzer",
+		"payloads": true,
+		"preserve_separators": false,
+		"preserve_position_increments": true,
+		"max_input_length": 512,
+		"context": {
+			"genre": {
+				"type": "category",
+				"path": "genre_field",
+				"default": [ "rock" ]
+			},
+			"location": {
+				"type": "geo",
+				"precision": [ "5m" ],
+				"neighbors": true
+			}
+		}
+	});
+
+	assert_eq!(expected, ser);
+}
@@ -0,0 +1,88 @@
+#![feature(custom_derive, custom_attribute, plugin)]
+#![plugin(serde_macros)]
+#![plugin(json_str)]
+
+pub mod mapping;
+
+extern crate serde;
+extern crate serde_json;
+extern crate elastic_types;
+
+use elastic_types::completion::mapping::*;
+use elastic_types::completion::prelude::*;
+
+#[test]
+fn serialise_elastic_completion_inputs() {
+	let completion: ElasticCompletion<DefaultCompletionMapping> = ElasticCompletion::new(vec![ "Nevermind", "Nirvana" ]);
+
+	let ser = serde_json::to_string(&completion).unwrap();
+
+	let expected = json_str!({
+		"input": [ "Nevermind", "Nirvana" ]
+	});
+
+	assert_eq!(expected, ser);
+}
+
+#[test]
+fn serialise_elastic_completion() {
+	let mut completion: ElasticCompletion<DefaultCompletionMapping> = ElasticCompletion::new(vec![ "Nevermind", "Nirvana" ]);
+	completion.output = Some(String::from("Nirvana - Nevermind"));
+	completion.payload = Some(serde_json::from_str(r#"{"artist_id":2321}"#).unwrap());
+	completion.weight = Some(34);
+	completion.context.insert(String::from("genre"), serde_json::Value::String(String::from("rock")));
+
+	let ser = serde_json::to_string(&completion).unwrap();
+
+	let expected = json_str!({
+		"input": [ "Nevermind", "Nirvana" ],
+		"output": "Nirvana - Nevermind",
+		"payload": {
+			"artist_id": 2321
+		},
+		"weight": 34,
+		"context": {
+			"genre": "rock"
+		}
+	});
+
+	assert_eq!(expected, ser);
+}
+
+#[test]
+fn deserialise_elastic_completion() {
+	let completion: ElasticCompletion<DefaultCompletionMapping> = serde_json::from_str(&json_str!({
+		"input": [ "Nevermind", "Nirvana" ],
+		"output": "Nirvana - Nevermind",
+		"payload": {
+			"artist_id": 2321
+		},
+		"weight": 34,
+		"context": {
+			"genre": "rock"
+		}
+	})).unwrap();
+
+	assert_eq!(vec![ "Nevermind", "Nirvana" ], completion.input);
+	assert_eq!(Some(String::from("Nirvana - Nevermind")), completion.output);
+	assert!(completion.payload.is_some());
+	assert_eq!(Some(34), completion.weight);
+	assert!(completion.context.contains_key("genre"));
+}
+
+#[test]
+fn deserialise_elastic_completion_single_input() {
+	let completion: ElasticCompletion<DefaultCompletionMapping> = serde_json::from_str(r#"{"input":"Nevermind","weight":"34"}"#).unwrap();
+
+	assert_eq!(vec![ "Nevermind" ], completion.input);
+	assert_eq!(Some(34), completion.weight);
+}
+
+#[test]
+fn deserialise_elastic_completion_inputs_only() {
+	let string: ElasticCompletion<DefaultCompletionMapping> = serde_json::from_str(r#""Nevermind""#).unwrap();
+	let array: ElasticCompletion<DefaultCompletionMapping> = serde_json::from_str(r#"["Nevermind","Nirvana"]"#).unwrap();
+
+	assert_eq!(vec![ "Nevermind" ], string.input);
+	assert_eq!(vec![ "Nevermind", "Nirvana" ], array.input);
+}
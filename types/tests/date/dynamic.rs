@@ -0,0 +1,109 @@
+extern crate chrono;
+extern crate elastic_types;
+
+use chrono::offset::TimeZone;
+use elastic_types::date::prelude::*;
+use elastic_types::date::mapping::{ ElasticDateMapping, DefaultDateMapping };
+
+#[test]
+fn can_parse_es_pattern() {
+	let fmt = DynamicDateFormat::new("yyyy-MM-dd'T'HH:mm:ss.SSSZ").unwrap();
+
+	let date = fmt.parse("2015-07-03T14:55:02.478Z").unwrap();
+
+	assert_eq!(chrono::UTC.ymd(2015, 7, 3).and_hms_milli(14, 55, 2, 478), date);
+	assert_eq!("2015-07-03T14:55:02.478Z", fmt.format(&date));
+}
+
+#[test]
+fn can_parse_es_pattern_with_offset() {
+	let fmt = DynamicDateFormat::new("yyyy-MM-dd'T'HH:mm:ssZ").unwrap();
+
+	let date = fmt.parse("2015-07-03T14:55:02+10:00").unwrap();
+
+	assert_eq!(chrono::UTC.ymd(2015, 7, 3).and_hms(4, 55, 2), date);
+	assert_eq!("2015-07-03T04:55:02Z", fmt.format(&date));
+}
+
+#[test]
+fn can_parse_es_pattern_with_quotes() {
+	let fmt = DynamicDateFormat::new("'day' D 'of' yyyy, 'at' HH''mm").unwrap();
+
+	let date = fmt.parse("day 184 of 2015, at 14'55").unwrap();
+
+	assert_eq!(chrono::UTC.ymd(2015, 7, 3).and_hms(14, 55, 0), date);
+	assert_eq!("day 184 of 2015, at 14'55", fmt.format(&date));
+}
+
+#[test]
+fn can_parse_es_pattern_with_text() {
+	let fmt = DynamicDateFormat::new("EEE, dd MMM yyyy hh:mm a").unwrap();
+
+	let date = fmt.parse("Fri, 03 Jul 2015 02:55 PM").unwrap();
+
+	assert_eq!(chrono::UTC.ymd(2015, 7, 3).and_hms(14, 55, 0), date);
+	assert_eq!("Fri, 03 Jul 2015 02:55 PM", fmt.format(&date));
+}
+
+#[test]
+fn can_parse_chrono_pattern() {
+	let fmt = DynamicDateFormat::new("%Y/%m/%d %H:%M:%S%.3f").unwrap();
+
+	let date = fmt.parse("2015/07/03 14:55:02.478").unwrap();
+
+	assert_eq!(chrono::UTC.ymd(2015, 7, 3).and_hms_milli(14, 55, 2, 478), date);
+	assert_eq!("2015/07/03 14:55:02.478", fmt.format(&date));
+}
+
+#[test]
+fn can_parse_built_in_format() {
+	let fmt = DynamicDateFormat::new("basic_date_time").unwrap();
+
+	let date = fmt.parse("20150703T145502.478Z").unwrap();
+
+	assert_eq!(BasicDateTime::parse("20150703T145502.478Z").unwrap(), date);
+	assert_eq!("20150703T145502.478Z", fmt.format(&date));
+}
+
+#[test]
+fn can_parse_multiple_formats() {
+	let fmt = DynamicDateFormat::new("yyyy-MM-dd || epoch_millis").unwrap();
+
+	let date = fmt.parse("1435935302478").unwrap();
+
+	assert_eq!(chrono::UTC.ymd(2015, 7, 3).and_hms_milli(14, 55, 2, 478), date);
+	assert_eq!("2015-07-03", fmt.format(&date));
+	assert_eq!(vec!["yyyy-MM-dd", "epoch_millis"], fmt.names());
+	assert_eq!("yyyy-MM-dd||epoch_millis", fmt.to_string());
+}
+
+#[test]
+fn can_parse_format_from_mapping() {
+	let fmt = DynamicDateFormat::new(&<DefaultDateMapping<FormatOr<DateOptionalTime, EpochMillis>> as ElasticDateMapping<_>>::format()).unwrap();
+
+	let date = fmt.parse("2015-07-03").unwrap();
+
+	assert_eq!(chrono::UTC.ymd(2015, 7, 3).and_hms(0, 0, 0), date);
+}
+
+#[test]
+fn parse_fails_if_no_format_matches() {
+	let fmt = DynamicDateFormat::new("yyyy-MM-dd||epoch_millis").unwrap();
+
+	assert!(fmt.parse("not a date").is_err());
+}
+
+#[test]
+fn new_fails_for_unsupported_letter() {
+	assert!(DynamicDateFormat::new("yyyy-MM-dd G").is_err());
+}
+
+#[test]
+fn new_fails_for_unterminated_literal() {
+	assert!(DynamicDateFormat::new("yyyy-MM-dd'T").is_err());
+}
+
+#[test]
+fn new_fails_for_empty_format() {
+	assert!(DynamicDateFormat::new("yyyy||").is_err());
+}
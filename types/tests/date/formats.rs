@@ -169,3 +169,1678 @@ fn epoch_millis_short_minus() {
 	let fmtd = date.format();
 	assert_eq!("-5100", &fmtd);
 }
+
+#[test]
+fn format_or_parses_with_first_format() {
+	let date = ElasticDate::<FormatOr<BasicDateTime, EpochMillis>>::parse("20150703T145502.478Z").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 14u32, 55u32, 2u32, 478u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+}
+
+#[test]
+fn format_or_falls_back_to_second_format() {
+	let date = ElasticDate::<FormatOr<BasicDateTime, EpochMillis>>::parse("1435935302478").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 14u32, 55u32, 2u32, 478u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	//Dates are always formatted with the first format
+	let fmtd = date.format();
+	assert_eq!("20150703T145502.478Z", &fmtd);
+}
+
+#[test]
+fn format_or_fails_when_no_format_matches() {
+	let date = ElasticDate::<FormatOr<BasicDateTime, BasicDateTimeNoMillis>>::parse("not a date");
+
+	assert!(date.is_err());
+}
+
+#[test]
+fn format_or_names_are_in_order() {
+	type Fmt = FormatOr<BasicDateTime, FormatOr<BasicDateTimeNoMillis, EpochMillis>>;
+
+	assert_eq!("basic_date_time", Fmt::name());
+	assert_eq!(vec!["basic_date_time", "basic_date_time_no_millis", "epoch_millis"], Fmt::names());
+}
+
+#[test]
+fn basic_date() {
+	let date = ElasticDate::<BasicDate>::parse("20150703").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 0u32, 0u32, 0u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("20150703", &fmtd);
+}
+
+#[test]
+fn basic_ordinal_date() {
+	let date = ElasticDate::<BasicOrdinalDate>::parse("2015184").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 0u32, 0u32, 0u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015184", &fmtd);
+}
+
+#[test]
+fn basic_ordinal_date_time() {
+	let date = ElasticDate::<BasicOrdinalDateTime>::parse("2015184T145502.478Z").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 14u32, 55u32, 2u32, 478u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015184T145502.478Z", &fmtd);
+}
+
+#[test]
+fn basic_ordinal_date_time_no_millis() {
+	let date = ElasticDate::<BasicOrdinalDateTimeNoMillis>::parse("2015184T145502Z").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 14u32, 55u32, 2u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015184T145502Z", &fmtd);
+}
+
+#[test]
+fn basic_time() {
+	let date = ElasticDate::<BasicTime>::parse("145502.478Z").unwrap();
+
+	assert_eq!(
+		(1970i32, 1u32, 1u32, 14u32, 55u32, 2u32, 478u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("145502.478Z", &fmtd);
+}
+
+#[test]
+fn basic_time_no_millis() {
+	let date = ElasticDate::<BasicTimeNoMillis>::parse("145502Z").unwrap();
+
+	assert_eq!(
+		(1970i32, 1u32, 1u32, 14u32, 55u32, 2u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("145502Z", &fmtd);
+}
+
+#[test]
+fn basic_t_time() {
+	let date = ElasticDate::<BasicTTime>::parse("T145502.478Z").unwrap();
+
+	assert_eq!(
+		(1970i32, 1u32, 1u32, 14u32, 55u32, 2u32, 478u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("T145502.478Z", &fmtd);
+}
+
+#[test]
+fn basic_t_time_no_millis() {
+	let date = ElasticDate::<BasicTTimeNoMillis>::parse("T145502Z").unwrap();
+
+	assert_eq!(
+		(1970i32, 1u32, 1u32, 14u32, 55u32, 2u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("T145502Z", &fmtd);
+}
+
+#[test]
+fn basic_week_date() {
+	let date = ElasticDate::<BasicWeekDate>::parse("2015W275").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 0u32, 0u32, 0u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015W275", &fmtd);
+
+	let date = ElasticDate::<StrictBasicWeekDate>::parse("2015W275").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 0u32, 0u32, 0u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015W275", &fmtd);
+}
+
+#[test]
+fn basic_week_date_time() {
+	let date = ElasticDate::<BasicWeekDateTime>::parse("2015W275T145502.478Z").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 14u32, 55u32, 2u32, 478u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015W275T145502.478Z", &fmtd);
+
+	let date = ElasticDate::<StrictBasicWeekDateTime>::parse("2015W275T145502.478Z").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 14u32, 55u32, 2u32, 478u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015W275T145502.478Z", &fmtd);
+}
+
+#[test]
+fn basic_week_date_time_no_millis() {
+	let date = ElasticDate::<BasicWeekDateTimeNoMillis>::parse("2015W275T145502Z").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 14u32, 55u32, 2u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015W275T145502Z", &fmtd);
+
+	let date = ElasticDate::<StrictBasicWeekDateTimeNoMillis>::parse("2015W275T145502Z").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 14u32, 55u32, 2u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015W275T145502Z", &fmtd);
+}
+
+#[test]
+fn date() {
+	let date = ElasticDate::<Date>::parse("2015-07-03").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 0u32, 0u32, 0u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-07-03", &fmtd);
+
+	let date = ElasticDate::<StrictDate>::parse("2015-07-03").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 0u32, 0u32, 0u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-07-03", &fmtd);
+}
+
+#[test]
+fn date_hour() {
+	let date = ElasticDate::<DateHour>::parse("2015-07-03T14").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 14u32, 0u32, 0u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-07-03T14", &fmtd);
+
+	let date = ElasticDate::<StrictDateHour>::parse("2015-07-03T14").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 14u32, 0u32, 0u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-07-03T14", &fmtd);
+}
+
+#[test]
+fn date_hour_minute() {
+	let date = ElasticDate::<DateHourMinute>::parse("2015-07-03T14:55").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 14u32, 55u32, 0u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-07-03T14:55", &fmtd);
+
+	let date = ElasticDate::<StrictDateHourMinute>::parse("2015-07-03T14:55").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 14u32, 55u32, 0u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-07-03T14:55", &fmtd);
+}
+
+#[test]
+fn date_hour_minute_second() {
+	let date = ElasticDate::<DateHourMinuteSecond>::parse("2015-07-03T14:55:02").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 14u32, 55u32, 2u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-07-03T14:55:02", &fmtd);
+
+	let date = ElasticDate::<StrictDateHourMinuteSecond>::parse("2015-07-03T14:55:02").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 14u32, 55u32, 2u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-07-03T14:55:02", &fmtd);
+}
+
+#[test]
+fn date_hour_minute_second_fraction() {
+	let date = ElasticDate::<DateHourMinuteSecondFraction>::parse("2015-07-03T14:55:02.478").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 14u32, 55u32, 2u32, 478u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-07-03T14:55:02.478", &fmtd);
+
+	let date = ElasticDate::<StrictDateHourMinuteSecondFraction>::parse("2015-07-03T14:55:02.478").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 14u32, 55u32, 2u32, 478u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-07-03T14:55:02.478", &fmtd);
+}
+
+#[test]
+fn date_hour_minute_second_millis() {
+	let date = ElasticDate::<DateHourMinuteSecondMillis>::parse("2015-07-03T14:55:02.478").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 14u32, 55u32, 2u32, 478u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-07-03T14:55:02.478", &fmtd);
+
+	let date = ElasticDate::<StrictDateHourMinuteSecondMillis>::parse("2015-07-03T14:55:02.478").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 14u32, 55u32, 2u32, 478u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-07-03T14:55:02.478", &fmtd);
+}
+
+#[test]
+fn date_optional_time() {
+	let date = ElasticDate::<DateOptionalTime>::parse("2015-07-03T14:55:02.478Z").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 14u32, 55u32, 2u32, 478u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-07-03T14:55:02.478Z", &fmtd);
+
+	let date = ElasticDate::<StrictDateOptionalTime>::parse("2015-07-03T14:55:02.478Z").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 14u32, 55u32, 2u32, 478u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-07-03T14:55:02.478Z", &fmtd);
+}
+
+#[test]
+fn date_time() {
+	let date = ElasticDate::<DateTime>::parse("2015-07-03T14:55:02.478Z").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 14u32, 55u32, 2u32, 478u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-07-03T14:55:02.478Z", &fmtd);
+
+	let date = ElasticDate::<StrictDateTime>::parse("2015-07-03T14:55:02.478Z").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 14u32, 55u32, 2u32, 478u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-07-03T14:55:02.478Z", &fmtd);
+}
+
+#[test]
+fn date_time_no_millis() {
+	let date = ElasticDate::<DateTimeNoMillis>::parse("2015-07-03T14:55:02Z").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 14u32, 55u32, 2u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-07-03T14:55:02Z", &fmtd);
+
+	let date = ElasticDate::<StrictDateTimeNoMillis>::parse("2015-07-03T14:55:02Z").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 14u32, 55u32, 2u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-07-03T14:55:02Z", &fmtd);
+}
+
+#[test]
+fn hour() {
+	let date = ElasticDate::<Hour>::parse("14").unwrap();
+
+	assert_eq!(
+		(1970i32, 1u32, 1u32, 14u32, 0u32, 0u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("14", &fmtd);
+
+	let date = ElasticDate::<StrictHour>::parse("14").unwrap();
+
+	assert_eq!(
+		(1970i32, 1u32, 1u32, 14u32, 0u32, 0u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("14", &fmtd);
+}
+
+#[test]
+fn hour_minute() {
+	let date = ElasticDate::<HourMinute>::parse("14:55").unwrap();
+
+	assert_eq!(
+		(1970i32, 1u32, 1u32, 14u32, 55u32, 0u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("14:55", &fmtd);
+
+	let date = ElasticDate::<StrictHourMinute>::parse("14:55").unwrap();
+
+	assert_eq!(
+		(1970i32, 1u32, 1u32, 14u32, 55u32, 0u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("14:55", &fmtd);
+}
+
+#[test]
+fn hour_minute_second() {
+	let date = ElasticDate::<HourMinuteSecond>::parse("14:55:02").unwrap();
+
+	assert_eq!(
+		(1970i32, 1u32, 1u32, 14u32, 55u32, 2u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("14:55:02", &fmtd);
+
+	let date = ElasticDate::<StrictHourMinuteSecond>::parse("14:55:02").unwrap();
+
+	assert_eq!(
+		(1970i32, 1u32, 1u32, 14u32, 55u32, 2u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("14:55:02", &fmtd);
+}
+
+#[test]
+fn hour_minute_second_fraction() {
+	let date = ElasticDate::<HourMinuteSecondFraction>::parse("14:55:02.478").unwrap();
+
+	assert_eq!(
+		(1970i32, 1u32, 1u32, 14u32, 55u32, 2u32, 478u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("14:55:02.478", &fmtd);
+
+	let date = ElasticDate::<StrictHourMinuteSecondFraction>::parse("14:55:02.478").unwrap();
+
+	assert_eq!(
+		(1970i32, 1u32, 1u32, 14u32, 55u32, 2u32, 478u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("14:55:02.478", &fmtd);
+}
+
+#[test]
+fn hour_minute_second_millis() {
+	let date = ElasticDate::<HourMinuteSecondMillis>::parse("14:55:02.478").unwrap();
+
+	assert_eq!(
+		(1970i32, 1u32, 1u32, 14u32, 55u32, 2u32, 478u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("14:55:02.478", &fmtd);
+
+	let date = ElasticDate::<StrictHourMinuteSecondMillis>::parse("14:55:02.478").unwrap();
+
+	assert_eq!(
+		(1970i32, 1u32, 1u32, 14u32, 55u32, 2u32, 478u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("14:55:02.478", &fmtd);
+}
+
+#[test]
+fn ordinal_date() {
+	let date = ElasticDate::<OrdinalDate>::parse("2015-184").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 0u32, 0u32, 0u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-184", &fmtd);
+
+	let date = ElasticDate::<StrictOrdinalDate>::parse("2015-184").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 0u32, 0u32, 0u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-184", &fmtd);
+}
+
+#[test]
+fn ordinal_date_time() {
+	let date = ElasticDate::<OrdinalDateTime>::parse("2015-184T14:55:02.478Z").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 14u32, 55u32, 2u32, 478u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-184T14:55:02.478Z", &fmtd);
+
+	let date = ElasticDate::<StrictOrdinalDateTime>::parse("2015-184T14:55:02.478Z").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 14u32, 55u32, 2u32, 478u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-184T14:55:02.478Z", &fmtd);
+}
+
+#[test]
+fn ordinal_date_time_no_millis() {
+	let date = ElasticDate::<OrdinalDateTimeNoMillis>::parse("2015-184T14:55:02Z").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 14u32, 55u32, 2u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-184T14:55:02Z", &fmtd);
+
+	let date = ElasticDate::<StrictOrdinalDateTimeNoMillis>::parse("2015-184T14:55:02Z").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 14u32, 55u32, 2u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-184T14:55:02Z", &fmtd);
+}
+
+#[test]
+fn time() {
+	let date = ElasticDate::<Time>::parse("14:55:02.478Z").unwrap();
+
+	assert_eq!(
+		(1970i32, 1u32, 1u32, 14u32, 55u32, 2u32, 478u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("14:55:02.478Z", &fmtd);
+
+	let date = ElasticDate::<StrictTime>::parse("14:55:02.478Z").unwrap();
+
+	assert_eq!(
+		(1970i32, 1u32, 1u32, 14u32, 55u32, 2u32, 478u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("14:55:02.478Z", &fmtd);
+}
+
+#[test]
+fn time_no_millis() {
+	let date = ElasticDate::<TimeNoMillis>::parse("14:55:02Z").unwrap();
+
+	assert_eq!(
+		(1970i32, 1u32, 1u32, 14u32, 55u32, 2u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("14:55:02Z", &fmtd);
+
+	let date = ElasticDate::<StrictTimeNoMillis>::parse("14:55:02Z").unwrap();
+
+	assert_eq!(
+		(1970i32, 1u32, 1u32, 14u32, 55u32, 2u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("14:55:02Z", &fmtd);
+}
+
+#[test]
+fn t_time() {
+	let date = ElasticDate::<TTime>::parse("T14:55:02.478Z").unwrap();
+
+	assert_eq!(
+		(1970i32, 1u32, 1u32, 14u32, 55u32, 2u32, 478u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("T14:55:02.478Z", &fmtd);
+
+	let date = ElasticDate::<StrictTTime>::parse("T14:55:02.478Z").unwrap();
+
+	assert_eq!(
+		(1970i32, 1u32, 1u32, 14u32, 55u32, 2u32, 478u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("T14:55:02.478Z", &fmtd);
+}
+
+#[test]
+fn t_time_no_millis() {
+	let date = ElasticDate::<TTimeNoMillis>::parse("T14:55:02Z").unwrap();
+
+	assert_eq!(
+		(1970i32, 1u32, 1u32, 14u32, 55u32, 2u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("T14:55:02Z", &fmtd);
+
+	let date = ElasticDate::<StrictTTimeNoMillis>::parse("T14:55:02Z").unwrap();
+
+	assert_eq!(
+		(1970i32, 1u32, 1u32, 14u32, 55u32, 2u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("T14:55:02Z", &fmtd);
+}
+
+#[test]
+fn week_date() {
+	let date = ElasticDate::<WeekDate>::parse("2015-W27-5").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 0u32, 0u32, 0u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-W27-5", &fmtd);
+
+	let date = ElasticDate::<StrictWeekDate>::parse("2015-W27-5").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 0u32, 0u32, 0u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-W27-5", &fmtd);
+}
+
+#[test]
+fn week_date_time() {
+	let date = ElasticDate::<WeekDateTime>::parse("2015-W27-5T14:55:02.478Z").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 14u32, 55u32, 2u32, 478u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-W27-5T14:55:02.478Z", &fmtd);
+
+	let date = ElasticDate::<StrictWeekDateTime>::parse("2015-W27-5T14:55:02.478Z").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 14u32, 55u32, 2u32, 478u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-W27-5T14:55:02.478Z", &fmtd);
+}
+
+#[test]
+fn week_date_time_no_millis() {
+	let date = ElasticDate::<WeekDateTimeNoMillis>::parse("2015-W27-5T14:55:02Z").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 14u32, 55u32, 2u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-W27-5T14:55:02Z", &fmtd);
+
+	let date = ElasticDate::<StrictWeekDateTimeNoMillis>::parse("2015-W27-5T14:55:02Z").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 14u32, 55u32, 2u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-W27-5T14:55:02Z", &fmtd);
+}
+
+#[test]
+fn weekyear() {
+	let date = ElasticDate::<Weekyear>::parse("2015").unwrap();
+
+	assert_eq!(
+		(2014i32, 12u32, 29u32, 0u32, 0u32, 0u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015", &fmtd);
+
+	let date = ElasticDate::<StrictWeekyear>::parse("2015").unwrap();
+
+	assert_eq!(
+		(2014i32, 12u32, 29u32, 0u32, 0u32, 0u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015", &fmtd);
+}
+
+#[test]
+fn weekyear_week() {
+	let date = ElasticDate::<WeekyearWeek>::parse("2015-W27").unwrap();
+
+	assert_eq!(
+		(2015i32, 6u32, 29u32, 0u32, 0u32, 0u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-W27", &fmtd);
+
+	let date = ElasticDate::<StrictWeekyearWeek>::parse("2015-W27").unwrap();
+
+	assert_eq!(
+		(2015i32, 6u32, 29u32, 0u32, 0u32, 0u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-W27", &fmtd);
+}
+
+#[test]
+fn weekyear_week_day() {
+	let date = ElasticDate::<WeekyearWeekDay>::parse("2015-W27-5").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 0u32, 0u32, 0u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-W27-5", &fmtd);
+
+	let date = ElasticDate::<StrictWeekyearWeekDay>::parse("2015-W27-5").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 0u32, 0u32, 0u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-W27-5", &fmtd);
+}
+
+#[test]
+fn year() {
+	let date = ElasticDate::<Year>::parse("2015").unwrap();
+
+	assert_eq!(
+		(2015i32, 1u32, 1u32, 0u32, 0u32, 0u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015", &fmtd);
+
+	let date = ElasticDate::<StrictYear>::parse("2015").unwrap();
+
+	assert_eq!(
+		(2015i32, 1u32, 1u32, 0u32, 0u32, 0u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015", &fmtd);
+}
+
+#[test]
+fn year_month() {
+	let date = ElasticDate::<YearMonth>::parse("2015-07").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 1u32, 0u32, 0u32, 0u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-07", &fmtd);
+
+	let date = ElasticDate::<StrictYearMonth>::parse("2015-07").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 1u32, 0u32, 0u32, 0u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-07", &fmtd);
+}
+
+#[test]
+fn year_month_day() {
+	let date = ElasticDate::<YearMonthDay>::parse("2015-07-03").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 0u32, 0u32, 0u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-07-03", &fmtd);
+
+	let date = ElasticDate::<StrictYearMonthDay>::parse("2015-07-03").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 0u32, 0u32, 0u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-07-03", &fmtd);
+}
+
+#[test]
+fn date_optional_time_without_time() {
+	let date = ElasticDate::<DateOptionalTime>::parse("2015-07-03").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 0u32, 0u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second()
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-07-03T00:00:00.000Z", &fmtd);
+}
+
+#[test]
+fn date_optional_time_without_offset() {
+	let date = ElasticDate::<StrictDateOptionalTime>::parse("2015-07-03T14:55").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 14u32, 55u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second()
+		)
+	);
+}
+
+#[test]
+fn date_time_with_offset() {
+	let date = ElasticDate::<DateTime>::parse("2015-07-03T14:55:02.478+10:00").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 4u32, 55u32, 2u32, 478u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("2015-07-03T04:55:02.478Z", &fmtd);
+}
+
+#[test]
+fn basic_date_time_with_offset() {
+	let date = ElasticDate::<BasicDateTime>::parse("20150703T145502.478-0530").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 20u32, 25u32, 2u32, 478u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second(),
+			date.nanosecond() / 1000000
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("20150703T202502.478Z", &fmtd);
+}
+
+#[test]
+fn time_with_offset_crosses_day() {
+	let date = ElasticDate::<Time>::parse("01:30:00.000+02:00").unwrap();
+
+	assert_eq!(
+		(1969i32, 12u32, 31u32, 23u32, 30u32, 0u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second()
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("23:30:00.000Z", &fmtd);
+}
+
+#[test]
+fn epoch_second() {
+	let date = ElasticDate::<EpochSecond>::parse("1435935302").unwrap();
+
+	assert_eq!(
+		(2015i32, 7u32, 3u32, 14u32, 55u32, 2u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second()
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("1435935302", &fmtd);
+}
+
+#[test]
+fn epoch_second_minus() {
+	let date = ElasticDate::<EpochSecond>::parse("-8031171898").unwrap();
+
+	assert_eq!(
+		(1715i32, 7u32, 3u32, 14u32, 55u32, 2u32),
+		(
+			date.year(),
+			date.month(),
+			date.day(),
+			date.hour(),
+			date.minute(),
+			date.second()
+		)
+	);
+
+	let fmtd = date.format();
+	assert_eq!("-8031171898", &fmtd);
+}
+
+#[test]
+fn mapping_names() {
+	assert_eq!("date_optional_time", DateOptionalTime::name());
+	assert_eq!("strict_date_optional_time", StrictDateOptionalTime::name());
+	assert_eq!("strict_week_date_time_no_millis", StrictWeekDateTimeNoMillis::name());
+	assert_eq!("basic_t_time", BasicTTime::name());
+}
+
+#[test]
+fn strict_formats_require_padded_fields() {
+	assert!(ElasticDate::<Date>::parse("2015-7-3").is_ok());
+
+	assert!(ElasticDate::<StrictDate>::parse("2015-7-3").is_err());
+	assert!(ElasticDate::<StrictDate>::parse("2015-07-3").is_err());
+	assert!(ElasticDate::<StrictDateHourMinute>::parse("2015-07-03T4:55").is_err());
+	assert!(ElasticDate::<StrictDateTime>::parse("2015-07-03T14:5:02.478Z").is_err());
+	assert!(ElasticDate::<StrictOrdinalDate>::parse("2015-84").is_err());
+	assert!(ElasticDate::<StrictWeekDate>::parse("2015-W7-5").is_err());
+	assert!(ElasticDate::<StrictDateOptionalTime>::parse("2015-7-03").is_err());
+	assert!(ElasticDate::<StrictDateOptionalTime>::parse("2015-07-03T4").is_err());
+
+	assert!(ElasticDate::<StrictDateOptionalTime>::parse("2015-07-03").is_ok());
+	assert!(ElasticDate::<StrictDateTime>::parse("2015-07-03T14:55:02.478+10:00").is_ok());
+	assert!(ElasticDate::<StrictBasicWeekDateTime>::parse("2015W275T145502.478Z").is_ok());
+}
@@ -23,6 +23,19 @@ fn serialise_mapping_default() {
 	assert_eq!(expected, ser);
 }
 
+#[test]
+fn serialise_mapping_multiple_formats() {
+	let mapping = DefaultDateMapping::<FormatOr<BasicDateTime, FormatOr<BasicDateTimeNoMillis, EpochMillis>>>::default();
+	let ser = serde_json::to_string(&mapping).unwrap();
+
+	let expected = json_str!({
+		"type": "date",
+		"format": "basic_date_time||basic_date_time_no_millis||epoch_millis"
+	});
+
+	assert_eq!(expected, ser);
+}
+
 #[test]
 fn serialise_mapping_custom() {
 	let mapping: MyDateMapping = MyDateMapping::default();
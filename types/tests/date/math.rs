@@ -0,0 +1,144 @@
+extern crate serde_json;
+extern crate chrono;
+extern crate elastic_types;
+
+use chrono::offset::TimeZone;
+use elastic_types::date::DT;
+use elastic_types::date::prelude::*;
+
+fn now() -> DT {
+	chrono::UTC.ymd(2015, 1, 31).and_hms_milli(14, 55, 2, 478)
+}
+
+#[test]
+fn can_build_date_math() {
+	let math = DateMath::<BasicDateTime>::now().add(1, DateMathUnit::Month).round(DateMathUnit::Day);
+
+	assert_eq!("now+1M/d", math.to_string());
+}
+
+#[test]
+fn can_build_date_math_with_anchor() {
+	let anchor = ElasticDate::<BasicDateTime>::parse("20150703T145502.478Z").unwrap();
+	let math = DateMath::date(anchor).sub(2, DateMathUnit::Week);
+
+	assert_eq!("20150703T145502.478Z||-2w", math.to_string());
+}
+
+#[test]
+fn can_parse_date_math() {
+	let math = DateMath::<BasicDateTime>::parse("now-1d/d").unwrap();
+
+	assert_eq!(&[DateMathOp::Sub(1, DateMathUnit::Day), DateMathOp::Round(DateMathUnit::Day)], math.ops());
+	assert_eq!("now-1d/d", math.to_string());
+}
+
+#[test]
+fn can_parse_date_math_with_anchor() {
+	let math = DateMath::<BasicDateTime>::parse("20150703T145502.478Z||+1M/M").unwrap();
+
+	assert_eq!("20150703T145502.478Z||+1M/M", math.to_string());
+}
+
+#[test]
+fn can_parse_date_math_without_number() {
+	let math = DateMath::<BasicDateTime>::parse("now+h").unwrap();
+
+	assert_eq!(&[DateMathOp::Add(1, DateMathUnit::Hour)], math.ops());
+}
+
+#[test]
+fn invalid_date_math_is_err() {
+	assert!(DateMath::<BasicDateTime>::parse("yesterday").is_err());
+	assert!(DateMath::<BasicDateTime>::parse("now+1q").is_err());
+	assert!(DateMath::<BasicDateTime>::parse("now/").is_err());
+}
+
+#[test]
+fn can_eval_date_math() {
+	let date = DateMath::<BasicDateTime>::parse("now-1d/d").unwrap().eval_from(now()).unwrap();
+
+	assert_eq!("20150130T000000.000Z", date.format());
+}
+
+#[test]
+fn can_eval_date_math_rounding() {
+	let cases = vec![
+		("now/y", "20150101T000000.000Z"),
+		("now/M", "20150101T000000.000Z"),
+		("now/w", "20150126T000000.000Z"),
+		("now/d", "20150131T000000.000Z"),
+		("now/h", "20150131T140000.000Z"),
+		("now/m", "20150131T145500.000Z"),
+		("now/s", "20150131T145502.000Z")
+	];
+
+	for (expr, expected) in cases {
+		let date = DateMath::<BasicDateTime>::parse(expr).unwrap().eval_from(now()).unwrap();
+
+		assert_eq!(expected, &date.format());
+	}
+}
+
+#[test]
+fn can_eval_date_math_months_clamped_to_end_of_month() {
+	let date = DateMath::<BasicDateTime>::parse("now+1M").unwrap().eval_from(now()).unwrap();
+
+	assert_eq!("20150228T145502.478Z", date.format());
+}
+
+#[test]
+fn can_eval_date_math_with_anchor() {
+	let date = DateMath::<BasicDateTime>::parse("20150703T145502.478Z||-1y+2h").unwrap().eval_from(now()).unwrap();
+
+	assert_eq!("20140703T165502.478Z", date.format());
+}
+
+#[test]
+fn eval_date_math_out_of_range_is_err() {
+	let cases = vec![
+		"now+300000y",
+		"now-300000y",
+		"now+4000000000M",
+		"now+4000000000d",
+		"now-4000000000w",
+		"now+4000000000h"
+	];
+
+	for expr in cases {
+		assert!(DateMath::<BasicDateTime>::parse(expr).unwrap().eval_from(now()).is_err(), "{} should be out of range", expr);
+	}
+}
+
+#[test]
+fn serialise_date_math() {
+	let math = DateMath::<BasicDateTime>::now().sub(1, DateMathUnit::Hour);
+	let ser = serde_json::to_string(&math).unwrap();
+
+	assert_eq!(r#""now-1h""#, ser);
+}
+
+#[test]
+fn deserialise_date_math() {
+	let math: DateMath<BasicDateTime> = serde_json::from_str(r#""now-1h/h""#).unwrap();
+
+	assert_eq!("now-1h/h", math.to_string());
+}
+
+#[test]
+fn can_build_date_math_index() {
+	let index = DateMathIndex::new("logstash-", &DateMath::<BasicDateTime>::now().round(DateMathUnit::Day));
+
+	assert_eq!("<logstash-{now/d}>", index.to_string());
+	assert_eq!("%3Clogstash-%7Bnow%2Fd%7D%3E", index.url_encoded());
+}
+
+#[test]
+fn can_build_date_math_index_with_format_and_time_zone() {
+	let index = DateMathIndex::new("logstash-", &DateMath::<BasicDateTime>::now().sub(1, DateMathUnit::Day).round(DateMathUnit::Day))
+		.format("YYYY.MM.dd")
+		.time_zone("+12:00");
+
+	assert_eq!("<logstash-{now-1d/d{YYYY.MM.dd|+12:00}}>", index.to_string());
+	assert_eq!("%3Clogstash-%7Bnow-1d%2Fd%7BYYYY.MM.dd%7C%2B12%3A00%7D%7D%3E", index.url_encoded());
+}
@@ -4,6 +4,8 @@
 
 pub mod mapping;
 pub mod formats;
+pub mod math;
+pub mod dynamic;
 
 extern crate serde;
 extern crate serde_json;
@@ -0,0 +1,72 @@
+#![feature(custom_derive, custom_attribute, plugin)]
+#![plugin(serde_macros)]
+#![plugin(json_str)]
+
+extern crate serde;
+extern crate serde_json;
+extern crate elastic_types;
+
+use elastic_types::mapping::prelude::*;
+use elastic_types::geo::prelude::*;
+use ::geo_fixtures::*;
+
+#[test]
+fn serialise_geo_point_mapping_default() {
+	let mapping = DefaultGeoPointMapping::<DefaultGeoPointFormat>::default();
+	let ser = serde_json::to_string(&mapping).unwrap();
+
+	let expected = json_str!({
+		"type": "geo_point"
+	});
+
+	assert_eq!(expected, ser);
+}
+
+#[test]
+fn serialise_geo_point_mapping_custom() {
+	let mapping: MyGeoPointMapping = MyGeoPointMapping::default();
+	let ser = serde_json::to_string(&mapping).unwrap();
+
+	let expected = json_str!({
+		"type": "geo_point",
+		"geohash": false,
+		"geohash_precision": "50m",
+		"geohash_prefix": true,
+		"ignore_malformed": true,
+		"lat_lon": true,
+		"precision_step": 6
+	});
+
+	assert_eq!(expected, ser);
+}
+
+#[test]
+fn serialise_geo_shape_mapping_default() {
+	let mapping = DefaultGeoShapeMapping;
+	let ser = serde_json::to_string(&mapping).unwrap();
+
+	let expected = json_str!({
+		"type": "geo_shape"
+	});
+
+	assert_eq!(expected, ser);
+}
+
+#[test]
+fn serialise_geo_shape_mapping_custom() {
+	let mapping = MyGeoShapeMapping;
+	let ser = serde_json::to_string(&mapping).unwrap();
+
+	let expected = json_str!({
+		"type": "geo_shape",
+		"tree": "geohash",
+		"precision": "50m",
+		"tree_levels": 8,
+		"strategy": "recursive",
+		"distance_error_pct": 0.5,
+		"orientation": "cw",
+		"points_only": false
+	});
+
+	assert_eq!(expected, ser);
+}
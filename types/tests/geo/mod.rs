@@ -0,0 +1,129 @@
+#![feature(custom_derive, custom_attribute, plugin)]
+#![plugin(serde_macros)]
+#![plugin(json_str)]
+
+pub mod mapping;
+
+extern crate serde;
+extern crate serde_json;
+extern crate geojson;
+extern crate elastic_types;
+
+use geojson::{ Geometry, Value };
+use elastic_types::geo::mapping::*;
+use elastic_types::geo::prelude::*;
+
+fn assert_point_eq(expected: GeoPoint, actual: GeoPoint) {
+	assert!((expected.lat - actual.lat).abs() < 0.000001, "expected lat {} but got {}", expected.lat, actual.lat);
+	assert!((expected.lon - actual.lon).abs() < 0.000001, "expected lon {} but got {}", expected.lon, actual.lon);
+}
+
+#[test]
+fn serialise_geo_point_object() {
+	let point = ElasticGeoPoint::<GeoPointObject>::new(GeoPoint::new(41.12, -71.34));
+
+	let ser = serde_json::to_string(&point).unwrap();
+
+	assert_eq!(json_str!({"lat":41.12,"lon":-71.34}), ser);
+}
+
+#[test]
+fn deserialise_geo_point_object() {
+	let point: ElasticGeoPoint<GeoPointObject> = serde_json::from_str(&json_str!({"lat":41.12,"lon":-71.34})).unwrap();
+
+	assert_eq!(GeoPoint::new(41.12, -71.34), point.get());
+}
+
+#[test]
+fn serialise_geo_point_string() {
+	let point = ElasticGeoPoint::<GeoPointString>::new(GeoPoint::new(41.12, -71.34));
+
+	let ser = serde_json::to_string(&point).unwrap();
+
+	assert_eq!("\"41.12,-71.34\"", ser);
+}
+
+#[test]
+fn deserialise_geo_point_string() {
+	let point: ElasticGeoPoint<GeoPointString> = serde_json::from_str("\"41.12, -71.34\"").unwrap();
+
+	assert_eq!(GeoPoint::new(41.12, -71.34), point.get());
+}
+
+#[test]
+fn deserialise_geo_point_string_invalid() {
+	let point: Result<ElasticGeoPoint<GeoPointString>, _> = serde_json::from_str("\"41.12\"");
+
+	assert!(point.is_err());
+}
+
+#[test]
+fn serialise_geo_point_hash() {
+	let point = ElasticGeoPoint::<GeoPointHash>::new(GeoPoint::new(41.12, -71.34));
+
+	let ser = serde_json::to_string(&point).unwrap();
+
+	assert_eq!("\"drm3btev3e86\"", ser);
+}
+
+#[test]
+fn deserialise_geo_point_hash() {
+	let point: ElasticGeoPoint<GeoPointHash> = serde_json::from_str("\"drm3btev3e86\"").unwrap();
+
+	assert_point_eq(GeoPoint::new(41.12, -71.34), point.get());
+}
+
+#[test]
+fn deserialise_geo_point_hash_invalid() {
+	let point: Result<ElasticGeoPoint<GeoPointHash>, _> = serde_json::from_str("\"drm3bta\"");
+
+	assert!(point.is_err());
+}
+
+#[test]
+fn serialise_geo_point_array() {
+	let point = ElasticGeoPoint::<GeoPointArray>::new(GeoPoint::new(41.12, -71.34));
+
+	let ser = serde_json::to_string(&point).unwrap();
+
+	assert_eq!("[-71.34,41.12]", ser);
+}
+
+#[test]
+fn deserialise_geo_point_array() {
+	let point: ElasticGeoPoint<GeoPointArray> = serde_json::from_str("[-71.34,41.12]").unwrap();
+
+	assert_eq!(GeoPoint::new(41.12, -71.34), point.get());
+}
+
+#[test]
+fn change_geo_point_format() {
+	let point = ElasticGeoPoint::<GeoPointObject>::new(GeoPoint::new(41.12, -71.34));
+
+	let point: ElasticGeoPoint<GeoPointArray> = point.into();
+
+	let ser = serde_json::to_string(&point).unwrap();
+
+	assert_eq!("[-71.34,41.12]", ser);
+}
+
+#[test]
+fn serialise_elastic_geo_shape() {
+	let shape = ElasticGeoShape::<DefaultGeoShapeMapping>::new(
+		Geometry::new(Value::Point(vec![ 1.0, 1.0 ]))
+	);
+
+	let ser = serde_json::to_string(&shape).unwrap();
+
+	assert_eq!(json_str!({"coordinates":[1.0,1.0],"type":"Point"}), ser);
+}
+
+#[test]
+fn deserialise_elastic_geo_shape() {
+	let shape: ElasticGeoShape<DefaultGeoShapeMapping> = serde_json::from_str(&json_str!({
+		"type": "Point",
+		"coordinates": [ 1.0, 1.0 ]
+	})).unwrap();
+
+	assert_eq!(Value::Point(vec![ 1.0, 1.0 ]), shape.get().value);
+}
@@ -0,0 +1,297 @@
+#![feature(custom_derive, custom_attribute, plugin)]
+#![plugin(serde_macros)]
+#![plugin(json_str)]
+
+extern crate serde;
+extern crate serde_json;
+extern crate elastic_types;
+
+use elastic_types::mapping::prelude::*;
+use elastic_types::index::*;
+use ::object_fixtures::*;
+
+#[test]
+fn serialise_empty_create_index() {
+	let ser = CreateIndex::new().to_string().unwrap();
+
+	assert_eq!("{}", ser);
+}
+
+#[test]
+fn serialise_index_settings() {
+	let ser = IndexSettings::new()
+		.number_of_shards(3)
+		.number_of_replicas(2)
+		.refresh_interval("30s")
+		.param("max_result_window", 20000)
+		.to_string()
+		.unwrap();
+
+	let expected = json_str!({
+		"number_of_shards": 3,
+		"number_of_replicas": 2,
+		"refresh_interval": "30s",
+		"max_result_window": 20000
+	});
+
+	assert_eq!(expected, ser);
+}
+
+#[test]
+fn serialise_analysis() {
+	let analysis = Analysis::new()
+		.analyzer("autocomplete", Analyzer::custom("autocomplete")
+			.char_filter("strip_dashes")
+			.filter("lowercase")
+			.filter("title_stop")
+		)
+		.analyzer("english_stop", Analyzer::new("standard").param("stopwords", "_english_"))
+		.tokenizer("autocomplete", Tokenizer::edge_ngram(2, 10).param("token_chars", vec![ "letter" ]))
+		.filter("title_stop", TokenFilter::stop(vec![ "the", "a" ]))
+		.filter("title_synonyms", TokenFilter::synonym(vec![ "i-pod, ipod" ]))
+		.char_filter("strip_dashes", CharFilter::pattern_replace("-", ""))
+		.char_filter("emoticons", CharFilter::mapping(vec![ ":) => _happy_" ]))
+		.normalizer("keyword", Normalizer::custom().filter("lowercase"));
+
+	let ser = IndexSettings::new().analysis(analysis).to_string().unwrap();
+
+	let expected = json_str!({
+		"analysis": {
+			"analyzer": {
+				"autocomplete": {
+					"type": "custom",
+					"char_filter": [ "strip_dashes" ],
+					"filter": [ "lowercase", "title_stop" ],
+					"tokenizer": "autocomplete"
+				},
+				"english_stop": {
+					"type": "standard",
+					"stopwords": "_english_"
+				}
+			},
+			"tokenizer": {
+				"autocomplete": {
+					"type": "edge_ngram",
+					"max_gram": 10,
+					"min_gram": 2,
+					"token_chars": [ "letter" ]
+				}
+			},
+			"filter": {
+				"title_stop": {
+					"type": "stop",
+					"stopwords": [ "the", "a" ]
+				},
+				"title_synonyms": {
+					"type": "synonym",
+					"synonyms": [ "i-pod, ipod" ]
+				}
+			},
+			"char_filter": {
+				"emoticons": {
+					"type": "mapping",
+					"mappings": [ ":) => _happy_" ]
+				},
+				"strip_dashes": {
+					"type": "pattern_replace",
+					"pattern": "-",
+					"replacement": ""
+				}
+			},
+			"normalizer": {
+				"keyword": {
+					"type": "custom",
+					"filter": [ "lowercase" ]
+				}
+			}
+		}
+	});
+
+	assert_eq!(expected, ser);
+}
+
+#[test]
+fn serialise_create_index_with_mapping() {
+	let ser = CreateIndex::new()
+		.settings(IndexSettings::new().number_of_shards(1))
+		.mapping(MyTypeMapping).unwrap()
+		.to_string()
+		.unwrap();
+
+	let expected = format!(
+		"{}{}{}",
+		r#"{"settings":{"number_of_shards":1},"mappings":{"my_type":"#,
+		TypeMapper::to_string(MyTypeMapping).unwrap(),
+		"}}"
+	);
+
+	assert_eq!(expected, ser);
+}
+
+#[test]
+fn serialise_create_index_with_mapping_value() {
+	let ser = CreateIndex::new()
+		.mapping_value("my_type", serde_json::from_str(r#"{"properties":{}}"#).unwrap())
+		.to_string()
+		.unwrap();
+
+	let expected = json_str!({
+		"mappings": {
+			"my_type": {
+				"properties": {}
+			}
+		}
+	});
+
+	assert_eq!(expected, ser);
+}
+
+#[test]
+fn serialise_index_template() {
+	let ser = IndexTemplate::new("logs-*")
+		.order(1)
+		.settings(IndexSettings::new().number_of_shards(1))
+		.alias("logs", Alias::new())
+		.alias("errors", Alias::new()
+			.filter(serde_json::from_str::<serde_json::Value>(r#"{"term":{"level":"error"}}"#).unwrap())
+			.routing("1")
+		)
+		.mapping_value("my_type", serde_json::from_str(r#"{"properties":{}}"#).unwrap())
+		.to_string()
+		.unwrap();
+
+	let expected = json_str!({
+		"template": "logs-*",
+		"order": 1,
+		"settings": {
+			"number_of_shards": 1
+		},
+		"aliases": {
+			"errors": {
+				"filter": {
+					"term": {
+						"level": "error"
+					}
+				},
+				"routing": "1"
+			},
+			"logs": {}
+		},
+		"mappings": {
+			"my_type": {
+				"properties": {}
+			}
+		}
+	});
+
+	assert_eq!(expected, ser);
+}
+
+#[test]
+fn serialise_index_template_with_mapping() {
+	let ser = IndexTemplate::new("logs-*")
+		.mapping(MyTypeMapping).unwrap()
+		.to_string()
+		.unwrap();
+
+	let expected = format!(
+		"{}{}{}",
+		r#"{"template":"logs-*","mappings":{"my_type":"#,
+		TypeMapper::to_string(MyTypeMapping).unwrap(),
+		"}}"
+	);
+
+	assert_eq!(expected, ser);
+}
+
+#[test]
+fn index_template_round_trips() {
+	let template = IndexTemplate::new("logs-*")
+		.order(2)
+		.settings(IndexSettings::new()
+			.number_of_shards(3)
+			.refresh_interval("30s")
+			.analysis(Analysis::new()
+				.analyzer("title", Analyzer::custom("standard").filter("lowercase"))
+				.filter("title_stop", TokenFilter::stop(vec![ "the", "a" ]))
+			)
+		)
+		.alias("logs", Alias::new().search_routing("1,2"))
+		.mapping(MyTypeMapping).unwrap();
+
+	let ser = template.to_string().unwrap();
+	let de: IndexTemplate = serde_json::from_str(&ser).unwrap();
+
+	assert_eq!(template, de);
+}
+
+#[test]
+fn deserialise_get_template_response() {
+	let res = json_str!({
+		"logs": {
+			"order": 1,
+			"template": "logs-*",
+			"settings": {
+				"index": {
+					"number_of_shards": "3",
+					"number_of_replicas": "1",
+					"refresh_interval": "30s",
+					"analysis": {
+						"analyzer": {
+							"title": {
+								"filter": [ "lowercase" ],
+								"tokenizer": "standard"
+							}
+						}
+					}
+				}
+			},
+			"mappings": {
+				"my_type": {
+					"properties": {
+						"title": {
+							"type": "string"
+						}
+					}
+				}
+			},
+			"aliases": {
+				"errors": {
+					"filter": {
+						"term": {
+							"level": "error"
+						}
+					}
+				}
+			}
+		},
+		"metrics": {
+			"order": 0,
+			"template": "metrics-*",
+			"settings": {},
+			"mappings": {},
+			"aliases": {}
+		}
+	});
+
+	let templates: IndexTemplates = serde_json::from_str(&res).unwrap();
+
+	let logs = templates.get("logs").unwrap();
+	let settings = logs.settings.as_ref().unwrap();
+
+	assert_eq!("logs-*", logs.template);
+	assert_eq!(Some(1), logs.order);
+	assert_eq!(Some(3), settings.number_of_shards);
+	assert_eq!(Some(1), settings.number_of_replicas);
+	assert_eq!(Some("30s".to_owned()), settings.refresh_interval);
+	assert_eq!(
+		Some(Analysis::new().analyzer("title", Analyzer::custom("standard").filter("lowercase"))),
+		settings.analysis
+	);
+	assert!(logs.mappings.contains_key("my_type"));
+	assert!(logs.aliases.get("errors").unwrap().filter.is_some());
+
+	let metrics = templates.get("metrics").unwrap();
+
+	assert_eq!(IndexTemplate::new("metrics-*").order(0), *metrics);
+}
@@ -0,0 +1,54 @@
+#![feature(custom_derive, custom_attribute, plugin)]
+#![plugin(serde_macros)]
+#![plugin(json_str)]
+
+pub mod mapping;
+
+extern crate serde;
+extern crate serde_json;
+extern crate elastic_types;
+
+use std::net::Ipv4Addr;
+use elastic_types::ip::mapping::*;
+use elastic_types::ip::prelude::*;
+
+#[test]
+fn can_change_ip_mapping() {
+	fn takes_custom_mapping(_: ElasticIp<::ip_fixtures::MyIpMapping>) -> bool {
+		true
+	}
+
+	let ip: ElasticIp<DefaultIpMapping> = ElasticIp::new(Ipv4Addr::new(127, 0, 0, 1));
+
+	assert!(takes_custom_mapping(ip.into()));
+}
+
+#[test]
+fn serialise_elastic_ip() {
+	let ip: ElasticIp<DefaultIpMapping> = ElasticIp::new(Ipv4Addr::new(192, 168, 1, 1));
+
+	let ser = serde_json::to_string(&ip).unwrap();
+
+	assert_eq!(r#""192.168.1.1""#, ser);
+}
+
+#[test]
+fn deserialise_elastic_ip() {
+	let ip: ElasticIp<DefaultIpMapping> = serde_json::from_str(r#""192.168.1.1""#).unwrap();
+
+	assert_eq!(Ipv4Addr::new(192, 168, 1, 1), ip);
+}
+
+#[test]
+fn deserialise_elastic_ip_from_number() {
+	let ip: ElasticIp<DefaultIpMapping> = serde_json::from_str("3232235777").unwrap();
+
+	assert_eq!(Ipv4Addr::new(192, 168, 1, 1), ip);
+}
+
+#[test]
+fn deserialise_elastic_ip_invalid() {
+	let ip: Result<ElasticIp<DefaultIpMapping>, _> = serde_json::from_str(r#""192.168.1""#);
+
+	assert!(ip.is_err());
+}
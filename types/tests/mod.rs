@@ -7,6 +7,7 @@
 extern crate serde;
 extern crate serde_json;
 extern crate chrono;
+extern crate geojson;
 #[macro_use]
 extern crate elastic_types;
 
@@ -63,14 +64,12 @@ pub mod string_fixtures {
 	#[derive(Default, Clone, ElasticStringMapping)]
 	pub struct MyStringMapping;
 	impl ElasticStringMapping for MyStringMapping {
+		type Analysis = NotIndexed;
+
 		fn boost() -> Option<f32> {
 			Some(1.01)
 		}
 
-		fn index() -> Option<IndexAnalysis> {
-			Some(IndexAnalysis::No)
-		}
-
 		fn doc_values() -> Option<bool> {
 			Some(true)
 		}
@@ -150,6 +149,12 @@ pub mod string_fixtures {
 			Some(TermVector::No)
 		}
 	}
+
+	#[derive(Default, Clone, ElasticStringMapping)]
+	pub struct MyNotAnalyzedStringMapping;
+	impl ElasticStringMapping for MyNotAnalyzedStringMapping {
+		type Analysis = NotAnalyzed;
+	}
 }
 
 pub mod boolean_fixtures {
@@ -480,6 +485,233 @@ pub mod object_fixtures {
 		pub my_strings: Vec<String>,
 		pub my_dates: Vec<ElasticDate<DefaultFormat>>
 	}
+
+	#[derive(Serialize, Deserialize, ElasticType)]
+	#[elastic(object, dynamic="strict")]
+	pub struct MyObjectType {
+		pub my_string: String
+	}
+
+	#[derive(Serialize, Deserialize, ElasticType)]
+	pub struct MyTypeWithObjects {
+		pub my_object: MyObjectType,
+		#[elastic(nested, include_in_parent)]
+		pub my_nested: MyObjectType,
+		#[elastic(enabled="false", dynamic="true")]
+		pub my_disabled: MyObjectType
+	}
+}
+
+pub mod geo_fixtures {
+	use std::marker::PhantomData;
+	use serde;
+	use elastic_types::mapping::prelude::*;
+	use elastic_types::geo::prelude::*;
+
+	//A custom geo point mapping
+	#[derive(Default, Clone, Copy, ElasticGeoPointMapping)]
+	pub struct MyGeoPointMapping<T: GeoPointFormat = GeoPointString> {
+		phantom: PhantomData<T>
+	}
+	impl <T: GeoPointFormat> ElasticGeoPointMapping<T> for MyGeoPointMapping<T> {
+		fn geohash() -> Option<bool> {
+			Some(false)
+		}
+
+		fn geohash_precision() -> Option<GeoHashPrecision> {
+			Some(GeoHashPrecision::Distance(Distance(50.0, DistanceUnit::Meters)))
+		}
+
+		fn geohash_prefix() -> Option<bool> {
+			Some(true)
+		}
+
+		fn ignore_malformed() -> Option<bool> {
+			Some(true)
+		}
+
+		fn lat_lon() -> Option<bool> {
+			Some(true)
+		}
+
+		fn precision_step() -> Option<i32> {
+			Some(6)
+		}
+	}
+
+	//A custom geo shape mapping
+	#[derive(Default, Clone, ElasticGeoShapeMapping)]
+	pub struct MyGeoShapeMapping;
+	impl ElasticGeoShapeMapping for MyGeoShapeMapping {
+		fn tree() -> Option<Tree> {
+			Some(Tree::Geohash)
+		}
+
+		fn precision() -> Option<Distance> {
+			Some(Distance(50.0, DistanceUnit::Meters))
+		}
+
+		fn tree_levels() -> Option<i32> {
+			Some(8)
+		}
+
+		fn strategy() -> Option<Strategy> {
+			Some(Strategy::Recursive)
+		}
+
+		fn distance_error_pct() -> Option<f32> {
+			Some(0.5)
+		}
+
+		fn orientation() -> Option<Orientation> {
+			Some(Orientation::Clockwise)
+		}
+
+		fn points_only() -> Option<bool> {
+			Some(false)
+		}
+	}
+}
+
+pub mod ip_fixtures {
+	use std::net::Ipv4Addr;
+	use elastic_types::mapping::prelude::*;
+
+	#[derive(Default, Clone, ElasticIpMapping)]
+	pub struct MyIpMapping;
+	impl ElasticIpMapping for MyIpMapping {
+		fn boost() -> Option<f32> {
+			Some(1.01)
+		}
+
+		fn doc_values() -> Option<bool> {
+			Some(true)
+		}
+
+		fn include_in_all() -> Option<bool> {
+			Some(false)
+		}
+
+		fn index() -> Option<IndexAnalysis> {
+			Some(IndexAnalysis::No)
+		}
+
+		fn precision_step() -> Option<i32> {
+			Some(8)
+		}
+
+		fn store() -> Option<bool> {
+			Some(true)
+		}
+
+		fn null_value() -> Option<Ipv4Addr> {
+			Some(Ipv4Addr::new(127, 0, 0, 1))
+		}
+	}
+}
+
+pub mod binary_fixtures {
+	use elastic_types::mapping::prelude::*;
+
+	#[derive(Default, Clone, ElasticBinaryMapping)]
+	pub struct MyBinaryMapping;
+	impl ElasticBinaryMapping for MyBinaryMapping {
+		fn doc_values() -> Option<bool> {
+			Some(true)
+		}
+
+		fn store() -> Option<bool> {
+			Some(true)
+		}
+	}
+}
+
+pub mod token_count_fixtures {
+	use elastic_types::mapping::prelude::*;
+
+	#[derive(Default, Clone, ElasticTokenCountMapping)]
+	pub struct MyTokenCountMapping;
+	impl ElasticTokenCountMapping for MyTokenCountMapping {
+		fn analyzer() -> Option<&'static str> {
+			Some("my_analyzer")
+		}
+
+		fn boost() -> Option<f32> {
+			Some(1.01)
+		}
+
+		fn doc_values() -> Option<bool> {
+			Some(true)
+		}
+
+		fn include_in_all() -> Option<bool> {
+			Some(false)
+		}
+
+		fn index() -> Option<IndexAnalysis> {
+			Some(IndexAnalysis::No)
+		}
+
+		fn precision_step() -> Option<u32> {
+			Some(8)
+		}
+
+		fn store() -> Option<bool> {
+			Some(true)
+		}
+
+		fn null_value() -> Option<u32> {
+			Some(0)
+		}
+	}
+}
+
+pub mod completion_fixtures {
+	use std::collections::BTreeMap;
+	use elastic_types::mapping::prelude::*;
+
+	#[derive(Default, Clone, ElasticCompletionMapping)]
+	pub struct MyCompletionMapping;
+	impl ElasticCompletionMapping for MyCompletionMapping {
+		fn analyzer() -> Option<&'static str> {
+			Some("my_analyzer")
+		}
+
+		fn search_analyzer() -> Option<&'static str> {
+			Some("my_search_analyzer")
+		}
+
+		fn payloads() -> Option<bool> {
+			Some(true)
+		}
+
+		fn preserve_separators() -> Option<bool> {
+			Some(false)
+		}
+
+		fn preserve_position_increments() -> Option<bool> {
+			Some(true)
+		}
+
+		fn max_input_length() -> Option<u32> {
+			Some(512)
+		}
+
+		fn context() -> Option<BTreeMap<&'static str, CompletionContext>> {
+			let mut context = BTreeMap::new();
+			context.insert("genre", CompletionContext::Category(CategoryContext {
+				path: Some("genre_field"),
+				default: Some(vec![ "rock" ])
+			}));
+			context.insert("location", CompletionContext::Geo(GeoContext {
+				precision: Some(vec![ "5m" ]),
+				neighbors: Some(true),
+				..Default::default()
+			}));
+
+			Some(context)
+		}
+	}
 }
 
 pub mod object;
@@ -487,3 +719,11 @@ pub mod date;
 pub mod string;
 pub mod number;
 pub mod boolean;
+pub mod geo;
+pub mod ip;
+pub mod binary;
+pub mod token_count;
+pub mod completion;
+pub mod query;
+pub mod index;
+pub mod response;
@@ -176,6 +176,64 @@ fn serialise_mapping_type_as_nested() {
 	assert_eq!(expected, ser);
 }
 
+#[test]
+fn serialise_mapping_type_as_object() {
+	let ser = serde_json::to_string(&MyObjectTypeMapping).unwrap();
+
+	let expected = json_str!({
+		"type": "object",
+		"dynamic": "strict",
+		"properties": {
+			"my_string": {
+				"type": "string"
+			}
+		}
+	});
+
+	assert_eq!(expected, ser);
+}
+
+#[test]
+fn serialise_mapping_fields_as_object_or_nested() {
+	let ser = TypeMapper::to_string(MyTypeWithObjectsMapping).unwrap();
+
+	let expected = json_str!({
+		"properties": {
+			"my_object": {
+				"type": "object",
+				"dynamic": "strict",
+				"properties": {
+					"my_string": {
+						"type": "string"
+					}
+				}
+			},
+			"my_nested": {
+				"type": "nested",
+				"dynamic": "strict",
+				"include_in_parent": true,
+				"properties": {
+					"my_string": {
+						"type": "string"
+					}
+				}
+			},
+			"my_disabled": {
+				"type": "object",
+				"dynamic": true,
+				"enabled": false,
+				"properties": {
+					"my_string": {
+						"type": "string"
+					}
+				}
+			}
+		}
+	});
+
+	assert_eq!(expected, ser);
+}
+
 #[test]
 fn serialise_mapping_dynamic() {
 	let d_opts: Vec<String> = vec![
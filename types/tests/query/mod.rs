@@ -0,0 +1,481 @@
+#![feature(custom_derive, custom_attribute, plugin)]
+#![plugin(serde_macros)]
+#![plugin(json_str)]
+
+extern crate serde;
+extern crate serde_json;
+extern crate chrono;
+extern crate elastic_types;
+
+use chrono::{ UTC, TimeZone };
+use elastic_types::mapping::prelude::*;
+use elastic_types::string::prelude::*;
+use elastic_types::query::*;
+use ::object_fixtures::*;
+use ::string_fixtures::*;
+
+#[derive(Serialize, Deserialize, ElasticType)]
+pub struct MyTaggedType {
+	pub my_tag: ElasticString<MyNotAnalyzedStringMapping>,
+	pub my_text: String
+}
+
+//Generated field paths mustn't clash with methods on the type
+impl MyTaggedType {
+	pub fn fields() -> Vec<&'static str> {
+		vec!["my_tag", "my_text"]
+	}
+}
+
+#[test]
+fn serialise_match_all_query() {
+	let query: Query = MatchAllQuery::new().into();
+
+	let ser = serde_json::to_string(&query).unwrap();
+
+	assert_eq!(json_str!({"match_all":{}}), ser);
+}
+
+#[test]
+fn serialise_term_query() {
+	let query: Query = TermQuery::new("user", "kimchy").into();
+
+	let ser = serde_json::to_string(&query).unwrap();
+
+	assert_eq!(json_str!({"term":{"user":"kimchy"}}), ser);
+}
+
+#[test]
+fn serialise_term_query_with_boost() {
+	let query: Query = TermQuery::new("stars", 5).boost(2.0).into();
+
+	let ser = serde_json::to_string(&query).unwrap();
+
+	let expected = json_str!({
+		"term": {
+			"stars": {
+				"value": 5,
+				"boost": 2.0
+			}
+		}
+	});
+
+	assert_eq!(expected, ser);
+}
+
+#[test]
+fn serialise_terms_query() {
+	let query: Query = TermsQuery::new("user", vec![ "kimchy", "elasticsearch" ]).into();
+
+	let ser = serde_json::to_string(&query).unwrap();
+
+	assert_eq!(json_str!({"terms":{"user":["kimchy","elasticsearch"]}}), ser);
+}
+
+#[test]
+fn serialise_range_query() {
+	let query: Query = RangeQuery::new("born")
+		.gte("01/01/2012")
+		.lte("2013")
+		.format("dd/MM/yyyy||yyyy")
+		.into();
+
+	let ser = serde_json::to_string(&query).unwrap();
+
+	let expected = json_str!({
+		"range": {
+			"born": {
+				"gte": "01/01/2012",
+				"lte": "2013",
+				"format": "dd/MM/yyyy||yyyy"
+			}
+		}
+	});
+
+	assert_eq!(expected, ser);
+}
+
+#[test]
+fn serialise_exists_prefix_wildcard_queries() {
+	let exists: Query = ExistsQuery::new("user").into();
+	let prefix: Query = PrefixQuery::new("user", "ki").into();
+	let wildcard: Query = WildcardQuery::new("user", "ki*y").boost(2.0).into();
+
+	assert_eq!(json_str!({"exists":{"field":"user"}}), serde_json::to_string(&exists).unwrap());
+	assert_eq!(json_str!({"prefix":{"user":"ki"}}), serde_json::to_string(&prefix).unwrap());
+	assert_eq!(json_str!({"wildcard":{"user":{"value":"ki*y","boost":2.0}}}), serde_json::to_string(&wildcard).unwrap());
+}
+
+#[test]
+fn serialise_match_query() {
+	let query: Query = MatchQuery::new("message", "this is a test")
+		.operator(Operator::And)
+		.fuzziness("AUTO")
+		.into();
+
+	let ser = serde_json::to_string(&query).unwrap();
+
+	let expected = json_str!({
+		"match": {
+			"message": {
+				"query": "this is a test",
+				"operator": "and",
+				"fuzziness": "AUTO"
+			}
+		}
+	});
+
+	assert_eq!(expected, ser);
+}
+
+#[test]
+fn serialise_multi_match_query() {
+	let query: Query = MultiMatchQuery::new("brown fox", vec![ "subject^3", "message" ])
+		.match_type(MultiMatchType::BestFields)
+		.tie_breaker(0.5)
+		.into();
+
+	let ser = serde_json::to_string(&query).unwrap();
+
+	let expected = json_str!({
+		"multi_match": {
+			"query": "brown fox",
+			"fields": ["subject^3","message"],
+			"type": "best_fields",
+			"tie_breaker": 0.5
+		}
+	});
+
+	assert_eq!(expected, ser);
+}
+
+#[test]
+fn serialise_query_string_query() {
+	let query: Query = QueryStringQuery::new("this AND that")
+		.default_field("content")
+		.default_operator(Operator::Or)
+		.into();
+
+	let ser = serde_json::to_string(&query).unwrap();
+
+	let expected = json_str!({
+		"query_string": {
+			"query": "this AND that",
+			"default_field": "content",
+			"default_operator": "or"
+		}
+	});
+
+	assert_eq!(expected, ser);
+}
+
+#[test]
+fn serialise_bool_query() {
+	let query: Query = BoolQuery::new()
+		.must(TermQuery::new("user", "kimchy"))
+		.filter(TermQuery::new("tag", "tech"))
+		.must_not(RangeQuery::new("age").gte(10).lte(20))
+		.should(TermQuery::new("tag", "wow"))
+		.minimum_should_match("1")
+		.into();
+
+	let ser = serde_json::to_string(&query).unwrap();
+
+	let expected = json_str!({
+		"bool": {
+			"must": [ {"term":{"user":"kimchy"}} ],
+			"filter": [ {"term":{"tag":"tech"}} ],
+			"should": [ {"term":{"tag":"wow"}} ],
+			"must_not": [ {"range":{"age":{"gte":10,"lte":20}}} ],
+			"minimum_should_match": "1"
+		}
+	});
+
+	assert_eq!(expected, ser);
+}
+
+#[test]
+fn serialise_nested_query() {
+	let query: Query = NestedQuery::new("comments", MatchQuery::new("comments.author", "kimchy"))
+		.score_mode(ScoreMode::Avg)
+		.into();
+
+	let ser = serde_json::to_string(&query).unwrap();
+
+	let expected = json_str!({
+		"nested": {
+			"path": "comments",
+			"score_mode": "avg",
+			"query": {
+				"match": {
+					"comments.author": {
+						"query": "kimchy"
+					}
+				}
+			}
+		}
+	});
+
+	assert_eq!(expected, ser);
+}
+
+#[test]
+fn serialise_has_child_query() {
+	let query: Query = HasChildQuery::new("blog_tag", TermQuery::new("tag", "something"))
+		.score_mode(ScoreMode::Sum)
+		.min_children(2)
+		.into();
+
+	let ser = serde_json::to_string(&query).unwrap();
+
+	let expected = json_str!({
+		"has_child": {
+			"type": "blog_tag",
+			"score_mode": "sum",
+			"min_children": 2,
+			"query": {
+				"term": {
+					"tag": "something"
+				}
+			}
+		}
+	});
+
+	assert_eq!(expected, ser);
+}
+
+#[test]
+fn serialise_function_score_query() {
+	let query: Query = FunctionScoreQuery::new()
+		.query(MatchAllQuery::new())
+		.function(ScoreFunction::field_value_factor(FieldValueFactor::new("likes").factor(1.5).modifier(FieldValueFactorModifier::Log1p)))
+		.function(ScoreFunction::new().filter(TermQuery::new("featured", true)).weight(2.0))
+		.function(ScoreFunction::decay(DecayScore::new(DecayFunction::Gauss, "date", "2013-09-17", "10d").decay(0.5)))
+		.function(ScoreFunction::random_score(10))
+		.score_mode(FunctionScoreMode::Sum)
+		.boost_mode(BoostMode::Replace)
+		.into();
+
+	let ser = serde_json::to_string(&query).unwrap();
+
+	let expected = json_str!({
+		"function_score": {
+			"query": { "match_all": {} },
+			"functions": [
+				{ "field_value_factor": { "field": "likes", "factor": 1.5, "modifier": "log1p" } },
+				{ "filter": { "term": { "featured": true } }, "weight": 2.0 },
+				{ "gauss": { "date": { "origin": "2013-09-17", "scale": "10d", "decay": 0.5 } } },
+				{ "random_score": { "seed": 10 } }
+			],
+			"score_mode": "sum",
+			"boost_mode": "replace"
+		}
+	});
+
+	assert_eq!(expected, ser);
+}
+
+#[test]
+fn serialise_search() {
+	let search = Search::new()
+		.query(TermQuery::new("user", "kimchy"))
+		.from(10)
+		.size(20);
+
+	let ser = search.to_string().unwrap();
+
+	let expected = json_str!({
+		"from": 10,
+		"size": 20,
+		"query": {
+			"term": {
+				"user": "kimchy"
+			}
+		}
+	});
+
+	assert_eq!(expected, ser);
+}
+
+#[test]
+fn get_field_paths() {
+	let fields = MyOtherType::field_paths();
+
+	assert_eq!("my_date", fields.my_date.name());
+	assert_eq!("my_renamed_type", fields.my_type.name());
+	assert_eq!("my_strings", fields.my_strings.name());
+}
+
+#[test]
+fn get_field_paths_on_objects() {
+	let fields = MyTypeWithObjects::field_paths();
+
+	assert_eq!("my_object.my_string", fields.my_object.field_paths().my_string.name());
+	assert_eq!("my_nested.my_string", fields.my_nested.field_paths().my_string.name());
+
+	let fields = MyOtherType::field_paths();
+
+	assert_eq!("my_renamed_type.my_num1", fields.my_type.field_paths().my_num1.name());
+}
+
+#[test]
+fn get_field_paths_on_type_with_fields_method() {
+	assert_eq!(vec!["my_tag", "my_text"], MyTaggedType::fields());
+	assert_eq!("my_tag", MyTaggedType::field_paths().my_tag.name());
+}
+
+#[test]
+fn serialise_field_term_query_on_object_field() {
+	let query: Query = MyOtherType::field_paths().my_type.field_paths().my_num1.term(5).into();
+
+	let ser = serde_json::to_string(&query).unwrap();
+
+	assert_eq!(json_str!({"term":{"my_renamed_type.my_num1":5}}), ser);
+}
+
+#[test]
+fn serialise_field_term_query() {
+	let query: Query = MyTaggedType::field_paths().my_tag.term("kimchy").into();
+
+	let ser = serde_json::to_string(&query).unwrap();
+
+	assert_eq!(json_str!({"term":{"my_tag":"kimchy"}}), ser);
+}
+
+#[test]
+fn serialise_field_term_query_on_number() {
+	let query: Query = MyType::field_paths().my_num1.term(5).into();
+
+	let ser = serde_json::to_string(&query).unwrap();
+
+	assert_eq!(json_str!({"term":{"my_num1":5}}), ser);
+}
+
+#[test]
+fn serialise_field_match_query_on_analyzed_string() {
+	let query: Query = MyTaggedType::field_paths().my_text.text("kimchy elasticsearch").into();
+
+	let ser = serde_json::to_string(&query).unwrap();
+
+	let expected = json_str!({
+		"match": {
+			"my_text": {
+				"query": "kimchy elasticsearch"
+			}
+		}
+	});
+
+	assert_eq!(expected, ser);
+}
+
+#[test]
+fn serialise_field_date_range_query_in_field_format() {
+	let query: Query = MyOtherType::field_paths().my_date
+		.range()
+		.gte(UTC.ymd(2015, 05, 13).and_hms(0, 0, 0))
+		.into();
+
+	let ser = serde_json::to_string(&query).unwrap();
+
+	let expected = json_str!({
+		"range": {
+			"my_date": {
+				"gte": "20150513T000000.000Z"
+			}
+		}
+	});
+
+	assert_eq!(expected, ser);
+}
+
+#[test]
+fn serialise_search_with_aggregations() {
+	let search = Search::new()
+		.size(0)
+		.aggregation("tags", TermsAggregation::new("tag")
+			.size(10)
+			.order("avg_stars", SortOrder::Desc)
+			.aggregation("avg_stars", MetricAggregation::avg("stars"))
+		)
+		.aggregation("per_month", DateHistogramAggregation::new("date", "month")
+			.format("yyyy-MM")
+			.aggregation("stars", MetricAggregation::stats("stars"))
+		);
+
+	let ser = search.to_string().unwrap();
+
+	let expected = json_str!({
+		"size": 0,
+		"aggs": {
+			"per_month": {
+				"date_histogram": {
+					"field": "date",
+					"interval": "month",
+					"format": "yyyy-MM"
+				},
+				"aggs": {
+					"stars": {
+						"stats": {
+							"field": "stars"
+						}
+					}
+				}
+			},
+			"tags": {
+				"terms": {
+					"field": "tag",
+					"size": 10,
+					"order": {
+						"avg_stars": "desc"
+					}
+				},
+				"aggs": {
+					"avg_stars": {
+						"avg": {
+							"field": "stars"
+						}
+					}
+				}
+			}
+		}
+	});
+
+	assert_eq!(expected, ser);
+}
+
+#[test]
+fn serialise_single_bucket_aggregations() {
+	let filter: Aggregation = FilterAggregation::new(TermQuery::new("type", "t-shirt"))
+		.aggregation("avg_price", MetricAggregation::avg("price"))
+		.into();
+
+	let nested: Aggregation = NestedAggregation::new("resellers")
+		.aggregation("min_price", MetricAggregation::min("resellers.price"))
+		.into();
+
+	let filter_expected = json_str!({
+		"filter": { "term": { "type": "t-shirt" } },
+		"aggs": { "avg_price": { "avg": { "field": "price" } } }
+	});
+
+	let nested_expected = json_str!({
+		"nested": { "path": "resellers" },
+		"aggs": { "min_price": { "min": { "field": "resellers.price" } } }
+	});
+
+	assert_eq!(filter_expected, serde_json::to_string(&filter).unwrap());
+	assert_eq!(nested_expected, serde_json::to_string(&nested).unwrap());
+}
+
+#[test]
+fn serialise_metric_aggregations() {
+	let range: Aggregation = RangeAggregation::new("price").to(100).between(100, 200).from(200).into();
+	let cardinality: Aggregation = CardinalityAggregation::new("author").precision_threshold(100).into();
+	let percentiles: Aggregation = PercentilesAggregation::new("load_time").percents(vec![ 95.0, 99.0 ]).into();
+	let top_hits: Aggregation = TopHitsAggregation::new().size(1).source(vec![ "title" ]).into();
+
+	assert_eq!(json_str!({"range":{"field":"price","ranges":[{"to":100},{"from":100,"to":200},{"from":200}]}}), serde_json::to_string(&range).unwrap());
+	assert_eq!(json_str!({"cardinality":{"field":"author","precision_threshold":100}}), serde_json::to_string(&cardinality).unwrap());
+	assert_eq!(json_str!({"percentiles":{"field":"load_time","percents":[95.0,99.0]}}), serde_json::to_string(&percentiles).unwrap());
+	assert_eq!(json_str!({"top_hits":{"size":1,"_source":["title"]}}), serde_json::to_string(&top_hits).unwrap());
+}
@@ -0,0 +1,378 @@
+extern crate serde_json;
+extern crate elastic_types;
+
+use serde_json::Value;
+use elastic_types::mapping::prelude::*;
+use elastic_types::response::*;
+use ::object_fixtures::*;
+
+fn type_mapping(json: &str) -> TypeMapping {
+	TypeMapping::from_value(serde_json::from_str(json).unwrap()).unwrap()
+}
+
+fn expected() -> TypeMapping {
+	type_mapping(&json_str!({
+		"properties": {
+			"title": {
+				"type": "string",
+				"analyzer": "english",
+				"fields": {
+					"raw": { "type": "string", "index": "not_analyzed", "ignore_above": 256 }
+				}
+			},
+			"created": { "type": "date", "format": "basic_date_time" },
+			"views": { "type": "integer" },
+			"user": {
+				"type": "nested",
+				"properties": {
+					"name": { "type": "string" },
+					"id": { "type": "long" }
+				}
+			}
+		}
+	}))
+}
+
+#[test]
+fn same_mappings_have_no_changes() {
+	let diff = expected().diff(&expected());
+
+	assert!(diff.is_empty());
+	assert!(diff.is_compatible());
+}
+
+#[test]
+fn unset_expected_params_are_ignored() {
+	let actual = type_mapping(&json_str!({
+		"properties": {
+			"title": {
+				"type": "string",
+				"analyzer": "english",
+				"search_analyzer": "standard",
+				"fields": {
+					"raw": { "type": "string", "index": "not_analyzed", "ignore_above": 256 }
+				}
+			},
+			"created": { "type": "date", "format": "basic_date_time", "doc_values": true },
+			"views": { "type": "integer", "null_value": 0 },
+			"user": {
+				"type": "nested",
+				"properties": {
+					"name": { "type": "string" },
+					"id": { "type": "long" }
+				}
+			}
+		}
+	}));
+
+	assert!(expected().diff(&actual).is_empty());
+}
+
+#[test]
+fn added_and_removed_fields_are_safe() {
+	let actual = type_mapping(&json_str!({
+		"properties": {
+			"title": {
+				"type": "string",
+				"analyzer": "english"
+			},
+			"created": { "type": "date", "format": "basic_date_time" },
+			"user": {
+				"type": "nested",
+				"properties": {
+					"name": { "type": "string" },
+					"email": { "type": "string" }
+				}
+			}
+		}
+	}));
+
+	let diff = expected().diff(&actual);
+
+	let added: Vec<&str> = diff.added().iter().map(|c| c.path.as_str()).collect();
+	let removed: Vec<&str> = diff.removed().iter().map(|c| c.path.as_str()).collect();
+
+	assert_eq!(vec!["title.raw", "user.id", "views"], added);
+	assert_eq!(vec!["user.email"], removed);
+	assert!(diff.conflicts().is_empty());
+	assert!(diff.is_compatible());
+}
+
+#[test]
+fn type_changes_require_reindex() {
+	let actual = type_mapping(&json_str!({
+		"properties": {
+			"title": {
+				"type": "string",
+				"analyzer": "english",
+				"fields": {
+					"raw": { "type": "string", "index": "not_analyzed", "ignore_above": 256 }
+				}
+			},
+			"created": { "type": "date", "format": "basic_date_time" },
+			"views": { "type": "long" },
+			"user": {
+				"type": "object",
+				"properties": {
+					"name": { "type": "string" },
+					"id": { "type": "long" }
+				}
+			}
+		}
+	}));
+
+	let diff = expected().diff(&actual);
+
+	assert_eq!(
+		vec![
+			MappingChange {
+				path: "user".to_owned(),
+				kind: MappingChangeKind::TypeChanged { expected: "nested".to_owned(), actual: "object".to_owned() }
+			},
+			MappingChange {
+				path: "views".to_owned(),
+				kind: MappingChangeKind::TypeChanged { expected: "integer".to_owned(), actual: "long".to_owned() }
+			}
+		],
+		diff.changes
+	);
+	assert!(diff.requires_reindex());
+}
+
+#[test]
+fn analyzer_and_format_changes_require_reindex() {
+	let actual = type_mapping(&json_str!({
+		"properties": {
+			"title": {
+				"type": "string",
+				"fields": {
+					"raw": { "type": "string", "index": "not_analyzed", "ignore_above": 256 }
+				}
+			},
+			"created": { "type": "date", "format": "epoch_millis" },
+			"views": { "type": "integer" },
+			"user": {
+				"type": "nested",
+				"properties": {
+					"name": { "type": "string" },
+					"id": { "type": "long" }
+				}
+			}
+		}
+	}));
+
+	let diff = expected().diff(&actual);
+
+	assert_eq!(
+		vec![
+			MappingChange {
+				path: "created".to_owned(),
+				kind: MappingChangeKind::FormatChanged { expected: "basic_date_time".to_owned(), actual: Some("epoch_millis".to_owned()) }
+			},
+			MappingChange {
+				path: "title".to_owned(),
+				kind: MappingChangeKind::AnalyzerChanged { expected: "english".to_owned(), actual: None }
+			}
+		],
+		diff.changes
+	);
+	assert_eq!(Compatibility::RequiresReindex, diff.changes[0].compatibility());
+	assert!(diff.requires_reindex());
+}
+
+#[test]
+fn updatable_param_changes_are_safe() {
+	let actual = type_mapping(&json_str!({
+		"properties": {
+			"title": {
+				"type": "string",
+				"analyzer": "english",
+				"fields": {
+					"raw": { "type": "string", "index": "not_analyzed", "ignore_above": 100 }
+				}
+			},
+			"created": { "type": "date", "format": "basic_date_time" },
+			"views": { "type": "integer" },
+			"user": {
+				"type": "nested",
+				"properties": {
+					"name": { "type": "string" },
+					"id": { "type": "long" }
+				}
+			}
+		}
+	}));
+
+	let diff = expected().diff(&actual);
+
+	assert_eq!(
+		vec![
+			MappingChange {
+				path: "title.raw".to_owned(),
+				kind: MappingChangeKind::ParamChanged { param: "ignore_above".to_owned(), expected: Value::U64(256), actual: Some(Value::U64(100)) }
+			}
+		],
+		diff.changes
+	);
+	assert!(diff.is_compatible());
+}
+
+#[test]
+fn index_changes_require_reindex() {
+	let actual = type_mapping(&json_str!({
+		"properties": {
+			"title": {
+				"type": "string",
+				"analyzer": "english",
+				"fields": {
+					"raw": { "type": "string", "index": "analyzed", "ignore_above": 256 }
+				}
+			},
+			"created": { "type": "date", "format": "basic_date_time" },
+			"views": { "type": "integer" },
+			"user": {
+				"type": "nested",
+				"properties": {
+					"name": { "type": "string" },
+					"id": { "type": "long" }
+				}
+			}
+		}
+	}));
+
+	let diff = expected().diff(&actual);
+
+	assert_eq!(1, diff.conflicts().len());
+	assert!(diff.requires_reindex());
+}
+
+#[test]
+fn expected_params_set_to_defaults_match_missing_params() {
+	let expected = type_mapping(&json_str!({
+		"properties": {
+			"title": { "type": "string", "index": "analyzed", "store": false, "doc_values": false },
+			"tag": { "type": "string", "index": "not_analyzed", "doc_values": true },
+			"views": { "type": "integer", "index": "not_analyzed", "store": false, "doc_values": true, "coerce": true },
+			"created": { "type": "date", "format": "strict_date_optional_time||epoch_millis" },
+			"user": {
+				"type": "nested",
+				"dynamic": true,
+				"include_in_parent": false,
+				"properties": {
+					"id": { "type": "long" }
+				}
+			}
+		}
+	}));
+
+	let actual = type_mapping(&json_str!({
+		"properties": {
+			"title": { "type": "string" },
+			"tag": { "type": "string", "index": "not_analyzed" },
+			"views": { "type": "integer" },
+			"created": { "type": "date" },
+			"user": {
+				"type": "nested",
+				"properties": {
+					"id": { "type": "long" }
+				}
+			}
+		}
+	}));
+
+	let diff = expected.diff(&actual);
+
+	assert!(diff.is_empty(), "{:?}", diff);
+}
+
+#[test]
+fn expected_params_that_are_not_defaults_are_changes() {
+	let expected = type_mapping(&json_str!({
+		"properties": {
+			"title": { "type": "string", "doc_values": true, "store": true }
+		}
+	}));
+
+	let actual = type_mapping(&json_str!({
+		"properties": {
+			"title": { "type": "string" }
+		}
+	}));
+
+	let diff = expected.diff(&actual);
+
+	let params: Vec<_> = diff.changes.iter().map(|c| match c.kind {
+		MappingChangeKind::ParamChanged { ref param, .. } => param.as_str(),
+		_ => panic!("expected a param change")
+	}).collect();
+
+	assert_eq!(vec!["doc_values", "store"], params);
+}
+
+#[test]
+fn live_params_that_are_not_defaults_are_changes() {
+	let expected = type_mapping(&json_str!({
+		"properties": {
+			"title": { "type": "string" },
+			"tag": { "type": "string" }
+		}
+	}));
+
+	let actual = type_mapping(&json_str!({
+		"properties": {
+			"title": { "type": "string", "analyzer": "english" },
+			"tag": { "type": "string", "index": "not_analyzed" }
+		}
+	}));
+
+	let diff = expected.diff(&actual);
+
+	assert_eq!(
+		vec![
+			MappingChange {
+				path: "tag".to_owned(),
+				kind: MappingChangeKind::ParamChanged { param: "index".to_owned(), expected: Value::String("analyzed".to_owned()), actual: Some(Value::String("not_analyzed".to_owned())) }
+			},
+			MappingChange {
+				path: "title".to_owned(),
+				kind: MappingChangeKind::AnalyzerChanged { expected: "default".to_owned(), actual: Some("english".to_owned()) }
+			}
+		],
+		diff.changes
+	);
+	assert!(diff.requires_reindex());
+}
+
+#[test]
+fn live_params_set_to_defaults_match_missing_params() {
+	let expected = type_mapping(&json_str!({
+		"properties": {
+			"title": { "type": "string" },
+			"views": { "type": "integer" }
+		}
+	}));
+
+	let actual = type_mapping(&json_str!({
+		"properties": {
+			"title": { "type": "string", "index": "analyzed", "analyzer": "default", "store": false },
+			"views": { "type": "integer", "index": "not_analyzed", "doc_values": true }
+		}
+	}));
+
+	let diff = expected.diff(&actual);
+
+	assert!(diff.is_empty(), "{:?}", diff);
+}
+
+#[test]
+fn diff_for_derived_type() {
+	let actual = TypeMapping::from_value(TypeMapper::to_value(MyTypeMapping).unwrap()).unwrap();
+
+	let diff = MappingDiff::for_type(MyTypeMapping, &actual).unwrap();
+
+	assert!(diff.is_empty());
+
+	let diff = MappingDiff::for_type(MyTypeMapping, &TypeMapping::default()).unwrap();
+
+	assert_eq!(9, diff.added().len());
+}
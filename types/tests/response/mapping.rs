@@ -0,0 +1,210 @@
+extern crate serde_json;
+extern crate elastic_types;
+
+use serde_json::Value;
+use elastic_types::mapping::prelude::*;
+use elastic_types::response::*;
+
+fn get_mapping() -> MappingDocument {
+	serde_json::from_str(&json_str!({
+		"myindex": {
+			"mappings": {
+				"mytype": {
+					"dynamic": "strict",
+					"_all": { "enabled": false },
+					"properties": {
+						"title": {
+							"type": "string",
+							"analyzer": "english",
+							"fields": {
+								"raw": { "type": "string", "index": "not_analyzed" }
+							}
+						},
+						"views": { "type": "integer", "null_value": 0 },
+						"rating": { "type": "float", "coerce": "false" },
+						"created": { "type": "date", "format": "yyyy-MM-dd||epoch_millis" },
+						"published": { "type": "boolean", "index": "no" },
+						"user": {
+							"properties": {
+								"name": { "type": "string" }
+							}
+						},
+						"comments": {
+							"type": "nested",
+							"include_in_parent": true,
+							"dynamic": false,
+							"properties": {
+								"body": { "type": "string" }
+							}
+						},
+						"location": { "type": "geo_point", "geohash": true },
+						"area": { "type": "geo_shape", "tree": "quadtree", "precision": "50m" },
+						"ip": { "type": "ip" }
+					}
+				}
+			}
+		}
+	})).unwrap()
+}
+
+#[test]
+fn deserialise_mapping_document() {
+	let mapping = get_mapping();
+
+	assert_eq!(vec!["myindex"], mapping.indices.keys().map(|k| k.as_str()).collect::<Vec<&str>>());
+
+	let ty = mapping.get("myindex", "mytype").unwrap();
+
+	assert_eq!(Some(Dynamic::Strict), ty.dynamic);
+	assert!(ty.params.contains_key("_all"));
+	assert_eq!(10, ty.properties.len());
+}
+
+#[test]
+fn deserialise_string_field_mapping() {
+	let mapping = get_mapping();
+	let ty = mapping.get("myindex", "mytype").unwrap();
+
+	match ty.field("title") {
+		Some(&FieldMapping::String(ref title)) => {
+			assert_eq!(Some("english".to_owned()), title.analyzer);
+			assert_eq!(1, title.fields.len());
+		},
+		f => panic!("expected a string field, got {:?}", f)
+	}
+
+	match ty.field("title.raw") {
+		Some(&FieldMapping::String(ref raw)) => assert_eq!(Some(IndexAnalysis::NotAnalyzed), raw.index),
+		f => panic!("expected a string field, got {:?}", f)
+	}
+}
+
+#[test]
+fn deserialise_number_field_mapping() {
+	let mapping = get_mapping();
+	let ty = mapping.get("myindex", "mytype").unwrap();
+
+	match ty.field("views") {
+		Some(&FieldMapping::Number(ref views)) => {
+			assert_eq!(NumberKind::Integer, views.kind);
+			assert_eq!(Some(Value::U64(0)), views.null_value);
+		},
+		f => panic!("expected a number field, got {:?}", f)
+	}
+
+	match ty.field("rating") {
+		Some(&FieldMapping::Number(ref rating)) => {
+			assert_eq!(NumberKind::Float, rating.kind);
+			assert_eq!(Some(false), rating.coerce);
+		},
+		f => panic!("expected a number field, got {:?}", f)
+	}
+}
+
+#[test]
+fn deserialise_date_field_mapping() {
+	let mapping = get_mapping();
+	let ty = mapping.get("myindex", "mytype").unwrap();
+
+	match ty.field("created") {
+		Some(&FieldMapping::Date(ref created)) => {
+			assert_eq!(Some("yyyy-MM-dd||epoch_millis".to_owned()), created.format);
+
+			let fmt = created.date_format().unwrap();
+			let date = fmt.parse("2015-07-03").unwrap();
+
+			assert_eq!("2015-07-03", fmt.format(&date));
+		},
+		f => panic!("expected a date field, got {:?}", f)
+	}
+}
+
+#[test]
+fn deserialise_boolean_field_mapping() {
+	let mapping = get_mapping();
+	let ty = mapping.get("myindex", "mytype").unwrap();
+
+	match ty.field("published") {
+		Some(&FieldMapping::Boolean(ref published)) => assert_eq!(Some(IndexAnalysis::No), published.index),
+		f => panic!("expected a boolean field, got {:?}", f)
+	}
+}
+
+#[test]
+fn deserialise_object_field_mapping() {
+	let mapping = get_mapping();
+	let ty = mapping.get("myindex", "mytype").unwrap();
+
+	assert_eq!(Some("object"), ty.field("user").map(|f| f.data_type()));
+	assert_eq!(Some("string"), ty.field("user.name").map(|f| f.data_type()));
+}
+
+#[test]
+fn deserialise_nested_field_mapping() {
+	let mapping = get_mapping();
+	let ty = mapping.get("myindex", "mytype").unwrap();
+
+	match ty.field("comments") {
+		Some(&FieldMapping::Nested(ref comments)) => {
+			assert_eq!(Some(true), comments.include_in_parent);
+			assert_eq!(Some(Dynamic::False), comments.dynamic);
+		},
+		f => panic!("expected a nested field, got {:?}", f)
+	}
+
+	assert_eq!(Some("string"), ty.field("comments.body").map(|f| f.data_type()));
+}
+
+#[test]
+fn deserialise_geo_field_mappings() {
+	let mapping = get_mapping();
+	let ty = mapping.get("myindex", "mytype").unwrap();
+
+	match ty.field("location") {
+		Some(&FieldMapping::GeoPoint(ref location)) => assert_eq!(Some(true), location.geohash),
+		f => panic!("expected a geo_point field, got {:?}", f)
+	}
+
+	match ty.field("area") {
+		Some(&FieldMapping::GeoShape(ref area)) => {
+			assert_eq!(Some("quadtree".to_owned()), area.tree);
+			assert_eq!(Some("50m".to_owned()), area.precision);
+		},
+		f => panic!("expected a geo_shape field, got {:?}", f)
+	}
+}
+
+#[test]
+fn deserialise_unknown_field_mapping() {
+	let mapping = get_mapping();
+	let ty = mapping.get("myindex", "mytype").unwrap();
+
+	assert_eq!(Some("ip"), ty.field("ip").map(|f| f.data_type()));
+}
+
+#[test]
+fn missing_fields_are_none() {
+	let mapping = get_mapping();
+	let ty = mapping.get("myindex", "mytype").unwrap();
+
+	assert!(ty.field("missing").is_none());
+	assert!(ty.field("views.missing").is_none());
+	assert!(mapping.get("myindex", "missing").is_none());
+}
+
+#[test]
+fn deserialise_invalid_field_mapping_fails() {
+	let res = serde_json::from_str::<MappingDocument>(&json_str!({
+		"myindex": {
+			"mappings": {
+				"mytype": {
+					"properties": {
+						"title": { "type": "string", "index": "sometimes" }
+					}
+				}
+			}
+		}
+	}));
+
+	assert!(res.is_err());
+}
@@ -0,0 +1,143 @@
+#![feature(custom_derive, custom_attribute, plugin)]
+#![plugin(serde_macros)]
+#![plugin(json_str)]
+
+pub mod mapping;
+pub mod diff;
+
+extern crate serde;
+extern crate serde_json;
+extern crate elastic_types;
+
+use serde_json::Value;
+use elastic_types::response::*;
+
+#[derive(Debug, Deserialize)]
+struct MyType {
+	title: String
+}
+
+#[test]
+fn deserialise_search_response_without_aggregations() {
+	let res: SearchResponse<MyType> = serde_json::from_str(&json_str!({
+		"took": 1,
+		"timed_out": false,
+		"_shards": { "total": 5, "successful": 5, "failed": 0 },
+		"hits": {
+			"total": 1,
+			"hits": [
+				{ "_index": "myindex", "_type": "mytype", "_id": "1", "_score": 1.0, "_source": { "title": "doc" } }
+			]
+		}
+	})).unwrap();
+
+	assert!(res.aggregations.is_none());
+	assert_eq!("doc", res.hits.hits[0].source.as_ref().unwrap().title);
+}
+
+#[test]
+fn deserialise_bucket_aggregations() {
+	let res: SearchResponse<MyType> = serde_json::from_str(&json_str!({
+		"took": 1,
+		"timed_out": false,
+		"_shards": { "total": 5, "successful": 5, "failed": 0 },
+		"hits": { "total": 3, "hits": [] },
+		"aggregations": {
+			"tags": {
+				"doc_count_error_upper_bound": 0,
+				"sum_other_doc_count": 0,
+				"buckets": [
+					{ "key": "rust", "doc_count": 2, "avg_stars": { "value": 4.5 } },
+					{ "key": "es", "doc_count": 1, "avg_stars": { "value": null } }
+				]
+			},
+			"published": {
+				"doc_count": 2,
+				"per_month": {
+					"buckets": [
+						{ "key_as_string": "2015-05", "key": 1430438400000, "doc_count": 2 }
+					]
+				}
+			}
+		}
+	})).unwrap();
+
+	let aggs = res.aggregations.unwrap();
+
+	let tags = aggs.get("tags").unwrap().buckets();
+	assert_eq!(2, tags.len());
+	assert_eq!(Some(Value::String("rust".to_owned())), tags[0].key);
+	assert_eq!(2, tags[0].doc_count);
+	assert_eq!(Some(4.5), tags[0].aggs.get("avg_stars").unwrap().value());
+	assert_eq!(None, tags[1].aggs.get("avg_stars").unwrap().value());
+
+	let published = aggs.get("published").unwrap().bucket().unwrap();
+	assert_eq!(2, published.doc_count);
+
+	let per_month = published.aggs.get("per_month").unwrap().buckets();
+	assert_eq!(Some("2015-05".to_owned()), per_month[0].key_as_string);
+}
+
+#[test]
+fn deserialise_single_bucket_aggregation_with_sub_aggregations_named_like_results() {
+	let aggs: AggregationResults = serde_json::from_str(&json_str!({
+		"published": {
+			"doc_count": 2,
+			"hits": { "value": 2 },
+			"values": { "value": 3 },
+			"value": { "value": 4 },
+			"buckets": {
+				"buckets": [
+					{ "key": "rust", "doc_count": 2 }
+				]
+			},
+			"from": { "value": 5 }
+		},
+		"significant_tags": {
+			"doc_count": 3,
+			"bg_count": 10,
+			"buckets": [
+				{ "key": "rust", "doc_count": 2, "score": 0.5, "bg_count": 2 }
+			]
+		}
+	})).unwrap();
+
+	let published = aggs.get("published").unwrap().bucket().unwrap();
+	assert_eq!(2, published.doc_count);
+	assert_eq!(None, published.from);
+	assert_eq!(Some(2.0), published.aggs.get("hits").unwrap().value());
+	assert_eq!(Some(3.0), published.aggs.get("values").unwrap().value());
+	assert_eq!(Some(4.0), published.aggs.get("value").unwrap().value());
+	assert_eq!(Some(5.0), published.aggs.get("from").unwrap().value());
+	assert_eq!(1, published.aggs.get("buckets").unwrap().buckets().len());
+
+	let significant_tags = aggs.get("significant_tags").unwrap().buckets();
+	assert_eq!(Some(Value::String("rust".to_owned())), significant_tags[0].key);
+}
+
+#[test]
+fn deserialise_metric_aggregations() {
+	let aggs: AggregationResults = serde_json::from_str(&json_str!({
+		"stars": { "count": 3, "min": 1.0, "max": 5.0, "avg": 3.0, "sum": 9.0 },
+		"authors": { "value": 2 },
+		"load_time": { "values": { "95.0": 60.0, "99.0": 150.0 } },
+		"top": {
+			"hits": {
+				"total": 1,
+				"hits": [
+					{ "_index": "myindex", "_type": "mytype", "_id": "1", "_score": 1.0, "_source": { "title": "doc" } }
+				]
+			}
+		}
+	})).unwrap();
+
+	let stats = aggs.get("stars").unwrap().stats().unwrap();
+	assert_eq!(3, stats.count);
+	assert_eq!(Some(9.0), stats.sum);
+
+	assert_eq!(Some(2.0), aggs.get("authors").unwrap().value());
+	assert_eq!(Some(150.0), aggs.get("load_time").unwrap().percentile(99.0));
+
+	let top = aggs.get("top").unwrap().hits::<MyType>().unwrap().unwrap();
+	assert_eq!("doc", top.hits[0].source.as_ref().unwrap().title);
+}
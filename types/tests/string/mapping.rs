@@ -21,6 +21,19 @@ fn serialise_mapping_default() {
 	assert_eq!(expected, ser);
 }
 
+#[test]
+fn serialise_mapping_not_analyzed() {
+	let mapping = MyNotAnalyzedStringMapping;
+	let ser = serde_json::to_string(&mapping).unwrap();
+
+	let expected = json_str!({
+		"type": "string",
+		"index": "not_analyzed"
+	});
+
+	assert_eq!(expected, ser);
+}
+
 #[test]
 fn serialise_mapping_custom() {
 	let mapping = MyStringMapping;
@@ -0,0 +1,43 @@
+#![feature(custom_derive, custom_attribute, plugin)]
+#![plugin(serde_macros)]
+#![plugin(json_str)]
+
+extern crate serde;
+extern crate serde_json;
+extern crate elastic_types;
+
+use elastic_types::mapping::prelude::*;
+use ::token_count_fixtures::*;
+
+#[test]
+fn serialise_mapping_default() {
+	let mapping = DefaultTokenCountMapping::default();
+	let ser = serde_json::to_string(&mapping).unwrap();
+
+	let expected = json_str!({
+		"type": "token_count",
+		"analyzer": "standard"
+	});
+
+	assert_eq!(expected, ser);
+}
+
+#[test]
+fn serialise_mapping_custom() {
+	let mapping = MyTokenCountMapping;
+	let ser = serde_json::to_string(&mapping).unwrap();
+
+	let expected = json_str!({
+		"type": "token_count",
+		"analyzer": "my_analyzer",
+		"boost": 1.01,
+		"doc_values": true,
+		"include_in_all": false,
+		"index": "no",
+		"precision_step": 8,
+		"store": true,
+		"null_value": 0
+	});
+
+	assert_eq!(expected, ser);
+}
@@ -0,0 +1,28 @@
+#![feature(custom_derive, custom_attribute, plugin)]
+#![plugin(serde_macros)]
+#![plugin(json_str)]
+
+pub mod mapping;
+
+extern crate serde;
+extern crate serde_json;
+extern crate elastic_types;
+
+use elastic_types::token_count::mapping::*;
+use elastic_types::token_count::prelude::*;
+
+#[test]
+fn serialise_elastic_token_count() {
+	let count: ElasticTokenCount<DefaultTokenCountMapping> = ElasticTokenCount::new("John Smith");
+
+	let ser = serde_json::to_string(&count).unwrap();
+
+	assert_eq!(r#""John Smith""#, ser);
+}
+
+#[test]
+fn deserialise_elastic_token_count() {
+	let count: ElasticTokenCount<DefaultTokenCountMapping> = serde_json::from_str(r#""John Smith""#).unwrap();
+
+	assert_eq!("John Smith", count);
+}